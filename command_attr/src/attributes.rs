@@ -5,7 +5,7 @@ use syn::parse::{Error, Result};
 use syn::spanned::Spanned;
 use syn::{Attribute, Ident, Lit, LitStr, Meta, NestedMeta, Path};
 
-use crate::structures::{Checks, Colour, HelpBehaviour, OnlyIn, Permissions};
+use crate::structures::{Colour, HelpBehaviour, OnlyIn, Permissions};
 use crate::util::{AsOption, LitExt};
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -253,13 +253,6 @@ impl AttributeOption for HelpBehaviour {
     }
 }
 
-impl AttributeOption for Checks {
-    #[inline]
-    fn parse(values: Values) -> Result<Self> {
-        <Vec<Ident> as AttributeOption>::parse(values).map(Checks)
-    }
-}
-
 impl AttributeOption for Permissions {
     fn parse(values: Values) -> Result<Self> {
         let perms = <Vec<Ident> as AttributeOption>::parse(values)?;
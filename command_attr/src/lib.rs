@@ -56,7 +56,7 @@ macro_rules! match_options {
 ///
 /// | Syntax                                                                         | Description                                                                                              | Argument explanation                                                                                                                                                                                                              |
 /// | ------------------------------------------------------------------------------ | -------------------------------------------------------------------------------------------------------- | --------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------- |
-/// | `#[checks(identifiers)]`                                                       | Preconditions that must met before the command's execution.                                              | `identifiers` is a comma separated list of identifiers referencing functions marked by the `#[check]` macro                                                                                                                       |
+/// | `#[checks(identifiers)]`                                                       | Preconditions that must met before the command's execution.                                              | `identifiers` is a comma separated list of identifiers referencing functions marked by the `#[check]` macro. Checks separated by `\|` instead pass if any of them passes                                                          |
 /// | `#[aliases(names)]`                                                            | Alternative names to refer to this command.                                                              | `names` is a comma separated list of desired aliases.                                                                                                                                                                             |
 /// | `#[description(desc)]` <br /> `#[description = desc]`                          | The command's description or summary.                                                                    | `desc` is a string describing the command.                                                                                                                                                                                        |
 /// | `#[usage(use)]` <br /> `#[usage = use]`                                        | The command's intended usage.                                                                            | `use` is a string stating the schema for the command's usage.                                                                                                                                                                     |
@@ -109,6 +109,12 @@ pub fn command(attr: TokenStream, input: TokenStream) -> TokenStream {
             continue;
         }
 
+        // Checks accept `|` between identifiers, which isn't valid meta syntax.
+        if attribute.path.is_ident("checks") {
+            options.checks = propagate_err!(attribute.parse_args());
+            continue;
+        }
+
         let span = attribute.span();
         let values = propagate_err!(parse_values(attribute));
 
@@ -131,7 +137,6 @@ pub fn command(attr: TokenStream, input: TokenStream) -> TokenStream {
             },
             _ => {
                 match_options!(name, values, options, span => [
-                    checks;
                     bucket;
                     aliases;
                     delimiters;
@@ -591,7 +596,7 @@ pub fn help(attr: TokenStream, input: TokenStream) -> TokenStream {
 /// | `#[owners_only]` <br /> `#[owners_only(b)]`           | If this command is exclusive to owners.                                          | `b` is a boolean. If no boolean is provided, the value is assumed to be `true`.                             |
 /// | `#[owner_privilege]` <br /> `#[owner_privilege(b)]`   | If owners can bypass certain options.                                            | `b` is a boolean. If no boolean is provided, the value is assumed to be `true`.                             |
 /// | `#[help_available]` <br /> `#[help_available(b)]`     | If the group should be displayed in the help message.                            | `b` is a boolean. If no boolean is provided, the value is assumed to be `true`.                             |
-/// | `#[checks(identifiers)]`                              | Preconditions that must met before the command's execution.                      | `identifiers` is a comma separated list of identifiers referencing functions marked by the `#[check]` macro |
+/// | `#[checks(identifiers)]`                              | Preconditions that must met before the command's execution.                      | `identifiers` is a comma separated list of identifiers referencing functions marked by the `#[check]` macro. Checks separated by `\|` instead pass if any of them passes |
 /// | `#[required_permissions(perms)]`                      | Set of permissions the user must possess. <br /> In order for this attribute to work, "Presence Intent" and "Server Member Intent" options in bot application must be enabled and all intent flags must be enabled during client creation. | `perms` is a comma separated list of permission names.<br /> These can be found at [Discord's official documentation](https://discord.com/developers/docs/topics/permissions). |
 /// | `#[default_command(cmd)]`                             | A command to execute if none of the group's prefixes are given.                  | `cmd` is an identifier referencing a function marked by the `#[command]` macro                              |
 /// | `#[description(desc)]` <br /> `#[description = desc]` | The group's description or summary.                                              | `desc` is a string describing the group.                                                                    |
@@ -627,6 +632,11 @@ pub fn group(attr: TokenStream, input: TokenStream) -> TokenStream {
             continue;
         }
 
+        if attribute.path.is_ident("checks") {
+            options.checks = propagate_err!(attribute.parse_args());
+            continue;
+        }
+
         let span = attribute.span();
         let values = propagate_err!(parse_values(attribute));
 
@@ -660,7 +670,6 @@ pub fn group(attr: TokenStream, input: TokenStream) -> TokenStream {
                 help_available;
                 allowed_roles;
                 required_permissions;
-                checks;
                 default_command;
                 commands;
                 sub_groups
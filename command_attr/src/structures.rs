@@ -429,14 +429,60 @@ impl ToTokens for Colour {
     }
 }
 
+/// The checks of a command or group, all of which must pass.
+///
+/// Each entry is a group of checks separated by `|`, of which any one must pass. A plain check is
+/// a group of one.
 #[derive(Debug, Default)]
-pub struct Checks(pub Vec<Ident>);
+pub struct Checks(pub Vec<Vec<Ident>>);
+
+impl Parse for Checks {
+    fn parse(input: ParseStream<'_>) -> Result<Self> {
+        let groups = Punctuated::<Punctuated<Ident, Token![|]>, Token![,]>::parse_terminated_with(
+            input,
+            Punctuated::parse_separated_nonempty,
+        )?;
+
+        if groups.is_empty() {
+            return Err(input.error("list cannot be empty"));
+        }
+
+        Ok(Checks(groups.into_iter().map(|group| group.into_iter().collect()).collect()))
+    }
+}
 
 impl ToTokens for Checks {
     fn to_tokens(&self, stream: &mut TokenStream2) {
-        let v = self.0.iter().map(|i| i.with_suffix(CHECK));
+        let v = self.0.iter().map(|group| {
+            if let [check] = &group[..] {
+                let check = check.with_suffix(CHECK);
+                return quote!(&#check);
+            }
 
-        stream.extend(quote!(&[#(&#v),*]));
+            let name = group.iter().map(ToString::to_string).collect::<Vec<_>>().join(" | ");
+            let checks: Vec<_> = group.iter().map(|i| i.with_suffix(CHECK)).collect();
+            let len = group.len();
+            let check = quote!(serenity::framework::standard::Check);
+
+            quote!({
+                static ANY_OF_CHECKS: [&#check; #len] = [#(&#checks),*];
+                static ANY_OF_CHECK: #check = #check {
+                    name: #name,
+                    function: |ctx, msg, args, options| {
+                        serenity::framework::standard::any_of_checks(
+                            &ANY_OF_CHECKS, ctx, msg, args, options,
+                        )
+                    },
+                    // Displayed if any of the checks is, but only checked in help if all of them
+                    // allow it, as a check that isn't run would count as failed.
+                    display_in_help: #(#checks.display_in_help)||*,
+                    check_in_help: #(#checks.check_in_help)&&*,
+                };
+                &ANY_OF_CHECK
+            })
+        });
+
+        stream.extend(quote!(&[#(#v),*]));
     }
 }
 
@@ -643,3 +689,36 @@ impl GroupOptions {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_checks() {
+        let checks: Checks = syn::parse_str("Owner | Admin, Guild").unwrap();
+        let names: Vec<Vec<String>> = checks
+            .0
+            .iter()
+            .map(|group| group.iter().map(ToString::to_string).collect())
+            .collect();
+        assert_eq!(names, [vec!["Owner", "Admin"], vec!["Guild"]]);
+
+        assert!(syn::parse_str::<Checks>("").is_err());
+        assert!(syn::parse_str::<Checks>("Owner |, Guild").is_err());
+        assert!(syn::parse_str::<Checks>("\"Owner\"").is_err());
+    }
+
+    #[test]
+    fn checks_to_tokens() {
+        let checks: Checks = syn::parse_str("Owner, Guild").unwrap();
+        let tokens = checks.to_token_stream().to_string();
+        assert_eq!(tokens, quote!(&[&OWNER_CHECK, &GUILD_CHECK]).to_string());
+
+        let checks: Checks = syn::parse_str("Owner | Admin").unwrap();
+        let tokens = checks.to_token_stream().to_string();
+        assert!(tokens.contains("any_of_checks"));
+        assert!(tokens.contains(&quote!([&OWNER_CHECK, &ADMIN_CHECK]).to_string()));
+        assert!(tokens.contains("\"Owner | Admin\""));
+    }
+}
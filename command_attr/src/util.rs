@@ -172,7 +172,7 @@ pub fn create_declaration_validations(fun: &mut CommandFun, dec_for: DeclarFor)
     let args2: Type = parse_quote!(&mut serenity::framework::standard::Args);
     let options: Type = parse_quote!(&serenity::framework::standard::CommandOptions);
     let hoptions: Type = parse_quote!(&'static serenity::framework::standard::HelpOptions);
-    let groups: Type = parse_quote!(&[&'static serenity::framework::standard::CommandGroup]);
+    let groups: Type = parse_quote!(&[serenity::framework::standard::GroupRef]);
     let owners: Type = parse_quote!(std::collections::HashSet<serenity::model::id::UserId>);
 
     let mut index = 0;
@@ -21,11 +21,15 @@ use serenity::framework::standard::{
     help_commands,
     Args,
     BucketBuilder,
+    BuiltGroup,
+    CommandBuilder,
     CommandGroup,
     CommandOptions,
     CommandResult,
     Configuration,
     DispatchError,
+    GroupBuilder,
+    GroupRef,
     HelpOptions,
     Reason,
     StandardFramework,
@@ -34,7 +38,7 @@ use serenity::gateway::ShardManager;
 use serenity::http::Http;
 use serenity::model::channel::Message;
 use serenity::model::gateway::Ready;
-use serenity::model::id::UserId;
+use serenity::model::id::{ChannelId, UserId};
 use serenity::model::permissions::Permissions;
 use serenity::prelude::*;
 use serenity::utils::{content_safe, ContentSafeOptions};
@@ -48,6 +52,15 @@ impl TypeMapKey for ShardManagerContainer {
     type Value = Arc<ShardManager>;
 }
 
+struct FrameworkContainer;
+
+impl TypeMapKey for FrameworkContainer {
+    type Value = Arc<StandardFramework>;
+}
+
+// Groups which can be toggled at runtime by the owners via `~disable_group` and `~enable_group`.
+static TOGGLEABLE_GROUPS: &[&CommandGroup] = &[&EMOJI_GROUP, &MATH_GROUP];
+
 struct CommandCounter;
 
 impl TypeMapKey for CommandCounter {
@@ -94,7 +107,7 @@ struct Math;
 #[only_in(guilds)]
 // Summary only appears when listing multiple groups.
 #[summary = "Commands for server owners"]
-#[commands(slow_mode)]
+#[commands(slow_mode, disable_group, enable_group)]
 struct Owner;
 
 // The framework provides two built-in help commands for you to use. But you can also make your own
@@ -130,7 +143,7 @@ async fn my_help(
     msg: &Message,
     args: Args,
     help_options: &'static HelpOptions,
-    groups: &[&'static CommandGroup],
+    groups: &[GroupRef],
     owners: HashSet<UserId>,
 ) -> CommandResult {
     let _ = help_commands::with_embeds(context, msg, args, help_options, groups, owners).await;
@@ -211,6 +224,30 @@ fn _dispatch_error_no_macro<'fut>(
     .boxed()
 }
 
+// Commands can also be defined at runtime, without the macros, e.g. to load them from a config
+// file. Here, each `name=reply` pair of the `CUSTOM_COMMANDS` environment variable (separated by
+// `;`) becomes a command replying with a fixed text, invoked like `~custom name`. Built groups
+// aren't listed by the help command.
+fn custom_commands() -> BuiltGroup {
+    let config = env::var("CUSTOM_COMMANDS").unwrap_or_else(|_| "rules=Be nice!".to_string());
+
+    let mut group = GroupBuilder::new("Custom")
+        .prefix("custom")
+        .summary("Commands defined by the `CUSTOM_COMMANDS` environment variable");
+    for (name, reply) in config.split(';').filter_map(|entry| entry.split_once('=')) {
+        let command = CommandBuilder::new(name).description(format!("Replies with \"{reply}\"."));
+        let reply = reply.to_string();
+        group = group.command(command.handler(move |ctx, msg, _| {
+            let reply = reply.clone();
+            Box::pin(async move {
+                msg.channel_id.say(&ctx.http, reply).await?;
+                Ok(())
+            })
+        }));
+    }
+    group.build()
+}
+
 #[tokio::main]
 async fn main() {
     // Configure the client with your Discord bot token in the environment.
@@ -280,7 +317,11 @@ async fn main() {
         .group(&GENERAL_GROUP)
         .group(&EMOJI_GROUP)
         .group(&MATH_GROUP)
-        .group(&OWNER_GROUP);
+        .group(&OWNER_GROUP)
+        .built_group(custom_commands());
+
+    // Keep a handle to the framework, so groups can be added and removed while the bot is running.
+    let framework = Arc::new(framework);
 
     framework.configure(
         Configuration::new().with_whitespace(true)
@@ -303,8 +344,9 @@ async fn main() {
     let intents = GatewayIntents::all();
     let mut client = Client::builder(&token, intents)
         .event_handler(Handler)
-        .framework(framework)
+        .framework(Arc::clone(&framework))
         .type_map_insert::<CommandCounter>(HashMap::default())
+        .type_map_insert::<FrameworkContainer>(framework)
         .await
         .expect("Err creating client");
 
@@ -430,13 +472,19 @@ async fn about_role(ctx: &Context, msg: &Message, args: Args) -> CommandResult {
 #[command]
 // Lets us also call `~math *` instead of just `~math multiply`.
 #[aliases("*")]
-async fn multiply(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+async fn multiply(ctx: &Context, msg: &Message, args: Args) -> CommandResult {
+    reply_product(&ctx.http, msg.channel_id, args).await
+}
+
+// The logic of `multiply` only needs `Http` rather than the whole `Context`, which makes it
+// possible to test it with a mock transport. See the tests at the bottom of this file.
+async fn reply_product(http: &Http, channel_id: ChannelId, mut args: Args) -> CommandResult {
     let first = args.single::<f64>()?;
     let second = args.single::<f64>()?;
 
     let res = first * second;
 
-    msg.channel_id.say(&ctx.http, &res.to_string()).await?;
+    channel_id.say(http, &res.to_string()).await?;
 
     Ok(())
 }
@@ -571,6 +619,60 @@ async fn slow_mode(ctx: &Context, msg: &Message, mut args: Args) -> CommandResul
     Ok(())
 }
 
+// Removes a group from the running framework, e.g. `~disable_group emoji`. Commands of the group
+// which are currently executing will still finish.
+#[command]
+#[num_args(1)]
+async fn disable_group(ctx: &Context, msg: &Message, args: Args) -> CommandResult {
+    let name = args.rest();
+    let framework = {
+        let data = ctx.data.read().await;
+        Arc::clone(data.get::<FrameworkContainer>().expect("Expected framework in TypeMap."))
+    };
+
+    // Group names default to the name of the struct, so `emoji` refers to the `Emoji` group.
+    let group =
+        framework.groups().into_iter().find(|group| group.name().eq_ignore_ascii_case(name));
+    let content = match group.and_then(|group| framework.remove_group(group.name())) {
+        Some(group) => format!("Disabled the `{}` group.", group.name()),
+        None => format!("There is no enabled group named `{name}`."),
+    };
+
+    msg.channel_id.say(&ctx.http, content).await?;
+
+    Ok(())
+}
+
+// Adds a previously removed group back to the running framework, e.g. `~enable_group emoji`.
+#[command]
+#[num_args(1)]
+async fn enable_group(ctx: &Context, msg: &Message, args: Args) -> CommandResult {
+    let name = args.rest();
+    let framework = {
+        let data = ctx.data.read().await;
+        Arc::clone(data.get::<FrameworkContainer>().expect("Expected framework in TypeMap."))
+    };
+
+    let group =
+        TOGGLEABLE_GROUPS.iter().copied().find(|group| group.name.eq_ignore_ascii_case(name));
+    let content = match group {
+        Some(group) => {
+            framework.add_group_dynamic(group);
+            format!("Enabled the `{}` group.", group.name)
+        },
+        // Built groups are rebuilt from scratch, which also picks up changes to their definition.
+        None if name.eq_ignore_ascii_case("custom") => {
+            framework.add_built_group_dynamic(custom_commands());
+            "Enabled the `Custom` group.".to_string()
+        },
+        None => format!("There is no toggleable group named `{name}`."),
+    };
+
+    msg.channel_id.say(&ctx.http, content).await?;
+
+    Ok(())
+}
+
 // A command can have sub-commands, just like in command lines tools. Imagine `cargo help` and
 // `cargo help run`.
 #[command("upper")]
@@ -590,3 +692,46 @@ async fn sub(ctx: &Context, msg: &Message, _args: Args) -> CommandResult {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use serenity::framework::standard::Delimiter;
+    use serenity::http::testing::MockTransport;
+    use serenity::http::{HttpBuilder, LightMethod, Route};
+    use serenity::json::json;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn multiply() {
+        let mock = Arc::new(MockTransport::new());
+        let http = HttpBuilder::new("token").transport(mock.clone()).build();
+
+        // The message returned by Discord after sending it.
+        mock.push_json(json!({
+            "id": "2",
+            "channel_id": "1",
+            "author": {"id": "3", "username": "bot", "discriminator": "0", "avatar": null},
+            "content": "12",
+            "timestamp": "2024-01-01T00:00:00+00:00",
+            "edited_timestamp": null,
+            "type": 0,
+        }));
+
+        let channel_id = ChannelId::new(1);
+        let args = Args::new("3 4", &[Delimiter::Single(' ')]);
+        reply_product(&http, channel_id, args).await.unwrap();
+
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, LightMethod::Post);
+        assert_eq!(
+            requests[0].path,
+            Route::ChannelMessages {
+                channel_id
+            }
+            .path()
+        );
+        assert_eq!(requests[0].body_json().unwrap().unwrap()["content"], "12");
+    }
+}
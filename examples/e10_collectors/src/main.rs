@@ -11,9 +11,9 @@ use serenity::framework::standard::macros::{command, group, help};
 use serenity::framework::standard::{
     help_commands,
     Args,
-    CommandGroup,
     CommandResult,
     Configuration,
+    GroupRef,
     HelpOptions,
     StandardFramework,
 };
@@ -33,7 +33,7 @@ async fn my_help(
     msg: &Message,
     args: Args,
     help_options: &'static HelpOptions,
-    groups: &[&'static CommandGroup],
+    groups: &[GroupRef],
     owners: HashSet<UserId>,
 ) -> CommandResult {
     let _ = help_commands::with_embeds(context, msg, args, help_options, groups, owners).await;
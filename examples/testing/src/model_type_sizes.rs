@@ -88,7 +88,15 @@ pub fn print_ranking() {
             "GuildScheduledEventUserRemoveEvent",
             std::mem::size_of::<GuildScheduledEventUserRemoveEvent>(),
         ),
+        ("GuildSoundboardSoundCreateEvent", std::mem::size_of::<GuildSoundboardSoundCreateEvent>()),
+        ("GuildSoundboardSoundDeleteEvent", std::mem::size_of::<GuildSoundboardSoundDeleteEvent>()),
+        ("GuildSoundboardSoundUpdateEvent", std::mem::size_of::<GuildSoundboardSoundUpdateEvent>()),
+        (
+            "GuildSoundboardSoundsUpdateEvent",
+            std::mem::size_of::<GuildSoundboardSoundsUpdateEvent>(),
+        ),
         ("GuildStickersUpdateEvent", std::mem::size_of::<GuildStickersUpdateEvent>()),
+        ("GuildTemplate", std::mem::size_of::<GuildTemplate>()),
         ("GuildUpdateEvent", std::mem::size_of::<GuildUpdateEvent>()),
         ("GuildWelcomeChannel", std::mem::size_of::<GuildWelcomeChannel>()),
         ("GuildWelcomeScreen", std::mem::size_of::<GuildWelcomeScreen>()),
@@ -171,6 +179,7 @@ pub fn print_ranking() {
         ("StageInstanceDeleteEvent", std::mem::size_of::<StageInstanceDeleteEvent>()),
         ("StageInstanceId", std::mem::size_of::<StageInstanceId>()),
         ("StageInstanceUpdateEvent", std::mem::size_of::<StageInstanceUpdateEvent>()),
+        ("SoundboardSound", std::mem::size_of::<SoundboardSound>()),
         ("Sticker", std::mem::size_of::<Sticker>()),
         ("StickerId", std::mem::size_of::<StickerId>()),
         ("StickerItem", std::mem::size_of::<StickerItem>()),
@@ -181,6 +190,10 @@ pub fn print_ranking() {
         ("TargetId", std::mem::size_of::<TargetId>()),
         ("Team", std::mem::size_of::<Team>()),
         ("TeamMember", std::mem::size_of::<TeamMember>()),
+        ("TemplateChannel", std::mem::size_of::<TemplateChannel>()),
+        ("TemplateGuild", std::mem::size_of::<TemplateGuild>()),
+        ("TemplatePermissionOverwrite", std::mem::size_of::<TemplatePermissionOverwrite>()),
+        ("TemplateRole", std::mem::size_of::<TemplateRole>()),
         ("ThreadCreateEvent", std::mem::size_of::<ThreadCreateEvent>()),
         ("ThreadDeleteEvent", std::mem::size_of::<ThreadDeleteEvent>()),
         ("ThreadListSyncEvent", std::mem::size_of::<ThreadListSyncEvent>()),
@@ -8,6 +8,9 @@ use crate::internal::prelude::*;
 use crate::model::prelude::*;
 
 /// A builder for constructing an invite link with custom OAuth2 scopes.
+///
+/// With the `unstable_discord_api` feature, this can also build the install link for either
+/// [`InstallationContext`], see [`Self::application_defaults`].
 #[derive(Debug, Clone, Default)]
 #[must_use]
 pub struct CreateBotAuthParameters {
@@ -16,6 +19,8 @@ pub struct CreateBotAuthParameters {
     permissions: Permissions,
     guild_id: Option<GuildId>,
     disable_guild_select: bool,
+    #[cfg(feature = "unstable_discord_api")]
+    integration_type: Option<InstallationContext>,
 }
 
 impl CreateBotAuthParameters {
@@ -27,7 +32,7 @@ impl CreateBotAuthParameters {
     /// Builds the url with the provided data.
     #[must_use]
     pub fn build(self) -> String {
-        let mut valid_data = ArrayVec::<_, 5>::new();
+        let mut valid_data = ArrayVec::<_, 6>::new();
         let bits = self.permissions.bits();
 
         if let Some(client_id) = self.client_id {
@@ -53,6 +58,11 @@ impl CreateBotAuthParameters {
             valid_data.push(("disable_guild_select", self.disable_guild_select.to_string()));
         }
 
+        #[cfg(feature = "unstable_discord_api")]
+        if let Some(integration_type) = self.integration_type {
+            valid_data.push(("integration_type", u8::from(integration_type).to_string()));
+        }
+
         let url = Url::parse_with_params("https://discord.com/api/oauth2/authorize", &valid_data)
             .expect("failed to construct URL");
 
@@ -95,6 +105,51 @@ impl CreateBotAuthParameters {
         self
     }
 
+    /// Specify the scopes and permissions from the given install parameters, such as
+    /// [`CurrentApplicationInfo::install_params`].
+    pub fn install_params(mut self, install_params: &InstallParams) -> Self {
+        self.scopes.clone_from(&install_params.scopes);
+        self.permissions = install_params.permissions;
+        self
+    }
+
+    /// Specify whether the application is installed to a guild or to the authorizing user.
+    #[cfg(feature = "unstable_discord_api")]
+    pub fn integration_type(mut self, integration_type: InstallationContext) -> Self {
+        self.integration_type = Some(integration_type);
+        self
+    }
+
+    /// Sets the client Id, integration type, and the default scopes and permissions that the
+    /// application configured for the given installation context.
+    ///
+    /// The defaults are read from [`CurrentApplicationInfo::integration_types_config`], falling
+    /// back to [`CurrentApplicationInfo::install_params`] for guild installs. Scopes and
+    /// permissions are left unchanged if neither is set.
+    #[cfg(feature = "unstable_discord_api")]
+    pub fn application_defaults(
+        mut self,
+        application: &CurrentApplicationInfo,
+        integration_type: InstallationContext,
+    ) -> Self {
+        let install_params = application
+            .integration_types_config
+            .get(&integration_type)
+            .and_then(|config| config.oauth2_install_params.as_ref())
+            .or_else(|| {
+                application
+                    .install_params
+                    .as_ref()
+                    .filter(|_| integration_type == InstallationContext::Guild)
+            });
+
+        if let Some(install_params) = install_params {
+            self = self.install_params(install_params);
+        }
+
+        self.client_id(application.id).integration_type(integration_type)
+    }
+
     /// Specify the Id of the guild to prefill the dropdown picker for the user.
     pub fn guild_id(mut self, guild_id: impl Into<GuildId>) -> Self {
         self.guild_id = Some(guild_id.into());
@@ -107,3 +162,72 @@ impl CreateBotAuthParameters {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encodes_scopes_and_permissions() {
+        let url = CreateBotAuthParameters::new()
+            .client_id(ApplicationId::new(1))
+            .scopes(&[Scope::Bot, Scope::ApplicationsCommands])
+            .permissions(Permissions::SEND_MESSAGES | Permissions::EMBED_LINKS)
+            .guild_id(GuildId::new(2))
+            .disable_guild_select(true)
+            .build();
+
+        assert_eq!(
+            url,
+            "https://discord.com/api/oauth2/authorize?client_id=1\
+             &scope=bot+applications.commands&permissions=18432&guild=2&disable_guild_select=true"
+        );
+    }
+
+    #[test]
+    fn omits_empty_parameters() {
+        let url = CreateBotAuthParameters::new().client_id(ApplicationId::new(1)).build();
+
+        assert_eq!(url, "https://discord.com/api/oauth2/authorize?client_id=1");
+    }
+
+    #[test]
+    #[cfg(feature = "unstable_discord_api")]
+    fn user_install_defaults() {
+        let application: CurrentApplicationInfo = crate::json::from_value(crate::json::json!({
+            "id": "1",
+            "name": "app",
+            "icon": null,
+            "description": "",
+            "bot_public": true,
+            "bot_require_code_grant": false,
+            "verify_key": "",
+            "team": null,
+            "install_params": {"scopes": ["bot"], "permissions": "2048"},
+            "integration_types_config": {
+                "1": {
+                    "oauth2_install_params": {"scopes": ["applications.commands"], "permissions": "0"},
+                },
+            },
+        }))
+        .unwrap();
+
+        let url = CreateBotAuthParameters::new()
+            .application_defaults(&application, InstallationContext::User)
+            .build();
+        assert_eq!(
+            url,
+            "https://discord.com/api/oauth2/authorize?client_id=1\
+             &scope=applications.commands&integration_type=1"
+        );
+
+        let url = CreateBotAuthParameters::new()
+            .application_defaults(&application, InstallationContext::Guild)
+            .build();
+        assert_eq!(
+            url,
+            "https://discord.com/api/oauth2/authorize?client_id=1\
+             &scope=bot&permissions=2048&integration_type=0"
+        );
+    }
+}
@@ -0,0 +1,105 @@
+#[cfg(feature = "http")]
+use super::Builder;
+use super::{CreateAttachment, ImageData};
+#[cfg(feature = "http")]
+use crate::http::CacheHttp;
+#[cfg(feature = "http")]
+use crate::internal::prelude::*;
+#[cfg(feature = "http")]
+use crate::model::prelude::*;
+#[cfg(feature = "http")]
+use crate::model::ImageFormat;
+
+/// The maximum size of emoji images in bytes.
+#[cfg(feature = "http")]
+const IMAGE_MAX_SIZE: usize = 256 * 1024;
+
+/// A builder to create an emoji owned by an application.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/emoji#create-application-emoji)
+#[derive(Clone, Debug, Serialize)]
+#[must_use]
+pub struct CreateApplicationEmoji {
+    name: String,
+    image: ImageData,
+}
+
+impl CreateApplicationEmoji {
+    /// Creates a new builder with the given emoji name and image.
+    ///
+    /// The name must be between 2 and 32 characters, and the image at most 256 KiB.
+    pub fn new(name: impl Into<String>, image: &CreateAttachment) -> Self {
+        Self {
+            name: name.into(),
+            image: ImageData::new(image),
+        }
+    }
+
+    /// Set the emoji's name, replacing the current value as set in [`Self::new`].
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    /// Set the emoji's image, replacing the current value as set in [`Self::new`].
+    pub fn image(mut self, image: &CreateAttachment) -> Self {
+        self.image = ImageData::new(image);
+        self
+    }
+}
+
+#[cfg(feature = "http")]
+#[async_trait::async_trait]
+impl Builder for CreateApplicationEmoji {
+    type Context<'ctx> = ApplicationId;
+    type Built = Emoji;
+
+    /// Creates the emoji for the given application.
+    ///
+    /// # Errors
+    ///
+    /// If the provided name is less than 2 characters, returns [`ModelError::NameTooShort`]. If it
+    /// is more than 32 characters, returns [`ModelError::NameTooLong`].
+    ///
+    /// Returns a [`ModelError::InvalidImage`] if the image is not a PNG, JPEG, GIF, or WebP image
+    /// of at most 256 KiB.
+    ///
+    /// Returns [`Error::Http`] if the application already has an emoji with the same name, if
+    /// the image is too big, or if the application ran out of emoji slots.
+    async fn execute(
+        self,
+        cache_http: impl CacheHttp,
+        ctx: Self::Context<'_>,
+    ) -> Result<Self::Built> {
+        if self.name.chars().count() < 2 {
+            return Err(Error::Model(ModelError::NameTooShort));
+        } else if self.name.chars().count() > 32 {
+            return Err(Error::Model(ModelError::NameTooLong));
+        }
+
+        let formats = [ImageFormat::Png, ImageFormat::Jpeg, ImageFormat::Gif, ImageFormat::WebP];
+        self.image.check(&formats, IMAGE_MAX_SIZE)?;
+
+        cache_http.http().create_emoji_of_application(ctx, &self).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json::{json, to_value};
+
+    #[test]
+    fn create_application_emoji_serialize() {
+        let image = CreateAttachment::bytes(b"GIF89a".as_slice(), "crab.gif");
+        let builder = CreateApplicationEmoji::new("crab", &image);
+
+        assert_eq!(
+            to_value(builder).unwrap(),
+            json!({
+                "name": "crab",
+                "image": "data:image/png;base64,R0lGODlh",
+            })
+        );
+    }
+}
@@ -2,16 +2,13 @@ use std::path::Path;
 
 use tokio::fs::File;
 use tokio::io::AsyncReadExt;
-#[cfg(feature = "http")]
-use url::Url;
 
 use crate::all::Message;
+use crate::error::{Error, Result};
 #[cfg(feature = "http")]
-use crate::error::Error;
-use crate::error::Result;
-#[cfg(feature = "http")]
-use crate::http::Http;
+use crate::http::{AttachmentStream, Http};
 use crate::model::id::AttachmentId;
+use crate::model::{ImageError, ImageFormat, ModelError};
 
 /// A builder for creating a new attachment from a file path, file data, or URL.
 ///
@@ -26,6 +23,10 @@ pub struct CreateAttachment {
 
     #[serde(skip)]
     pub data: Vec<u8>,
+    /// Data that is streamed while uploading instead of being held in [`Self::data`].
+    #[cfg(feature = "http")]
+    #[serde(skip)]
+    pub(crate) stream: Option<AttachmentStream>,
 }
 
 impl CreateAttachment {
@@ -36,27 +37,53 @@ impl CreateAttachment {
             filename: filename.into(),
             description: None,
             id: 0,
+            #[cfg(feature = "http")]
+            stream: None,
         }
     }
 
-    /// Builds an [`CreateAttachment`] by reading a local file.
+    /// Builds an [`CreateAttachment`] by reading a local file, using the file name of the path as
+    /// the filename of the attachment.
+    ///
+    /// Use [`Self::path_with_filename`] for files with meaningless names, such as temporary
+    /// files.
     ///
     /// # Errors
     ///
-    /// [`Error::Io`] if reading the file fails.
+    /// [`Error::Io`] if reading the file fails, or if the path has no file name.
+    ///
+    /// [`ModelError::NonUtf8Filename`] if the file name is not valid UTF-8.
     pub async fn path(path: impl AsRef<Path>) -> Result<CreateAttachment> {
-        let mut file = File::open(path.as_ref()).await?;
-        let mut data = Vec::new();
-        file.read_to_end(&mut data).await?;
-
-        let filename = path.as_ref().file_name().ok_or_else(|| {
+        let path = path.as_ref();
+        let filename = path.file_name().ok_or_else(|| {
             std::io::Error::new(
                 std::io::ErrorKind::Other,
                 "attachment path must not be a directory",
             )
         })?;
 
-        Ok(CreateAttachment::bytes(data, filename.to_string_lossy().to_string()))
+        let filename = filename
+            .to_str()
+            .ok_or_else(|| Error::Model(ModelError::NonUtf8Filename(filename.to_owned())))?;
+
+        Self::path_with_filename(path, filename).await
+    }
+
+    /// Builds an [`CreateAttachment`] by reading a local file, using the given filename for the
+    /// attachment instead of the file name of the path.
+    ///
+    /// # Errors
+    ///
+    /// [`Error::Io`] if reading the file fails.
+    pub async fn path_with_filename(
+        path: impl AsRef<Path>,
+        filename: impl Into<String>,
+    ) -> Result<CreateAttachment> {
+        let mut file = File::open(path.as_ref()).await?;
+        let mut data = Vec::new();
+        file.read_to_end(&mut data).await?;
+
+        Ok(CreateAttachment::bytes(data, filename))
     }
 
     /// Builds an [`CreateAttachment`] by reading from a file handler.
@@ -73,36 +100,96 @@ impl CreateAttachment {
 
     /// Builds an [`CreateAttachment`] by downloading attachment data from a URL.
     ///
+    /// The file is downloaded via [`Http::download`].
+    ///
     /// # Errors
     ///
-    /// [`Error::Url`] if the URL is invalid, [`Error::Http`] if downloading the data fails.
+    /// [`Error::Url`] if the URL is invalid or has no file name, [`Error::Http`] if downloading
+    /// the data fails.
     #[cfg(feature = "http")]
     pub async fn url(http: impl AsRef<Http>, url: &str) -> Result<CreateAttachment> {
-        let url = Url::parse(url).map_err(|_| Error::Url(url.to_string()))?;
+        let file = http.as_ref().download(url).await?;
+        let filename = file.filename.ok_or_else(|| Error::Url(url.to_string()))?;
 
-        let response = http.as_ref().client.get(url.clone()).send().await?;
-        let data = response.bytes().await?.to_vec();
+        Ok(CreateAttachment::bytes(file.bytes, filename))
+    }
 
-        let filename = url
-            .path_segments()
-            .and_then(Iterator::last)
-            .ok_or_else(|| Error::Url(url.to_string()))?;
+    /// Builds an [`CreateAttachment`] by downloading attachment data from a URL, using the given
+    /// filename for the attachment instead of the last segment of the URL path.
+    ///
+    /// # Errors
+    ///
+    /// [`Error::Url`] if the URL is invalid, [`Error::Http`] if downloading the data fails.
+    #[cfg(feature = "http")]
+    pub async fn url_with_filename(
+        http: impl AsRef<Http>,
+        url: &str,
+        filename: impl Into<String>,
+    ) -> Result<CreateAttachment> {
+        let file = http.as_ref().download(url).await?;
 
-        Ok(CreateAttachment::bytes(data, filename))
+        Ok(CreateAttachment::bytes(file.bytes, filename))
+    }
+
+    /// Builds an [`CreateAttachment`] which streams the attachment data from a URL while it's
+    /// being uploaded, instead of downloading the whole file into memory first.
+    ///
+    /// Only the response headers are awaited here. As the data is never held in memory,
+    /// [`Self::data`] stays empty, so this is only suitable for uploads such as message
+    /// attachments, not for images sent as base64, e.g. avatars.
+    ///
+    /// **Note**: The data can only be sent once. If the upload has to be retried, e.g. after
+    /// hitting a ratelimit, it fails with [`HttpError::AttachmentStreamConsumed`].
+    ///
+    /// # Errors
+    ///
+    /// [`Error::Url`] if the URL is invalid, [`Error::Http`] if the download can't be started.
+    ///
+    /// [`HttpError::AttachmentStreamConsumed`]: crate::http::HttpError::AttachmentStreamConsumed
+    #[cfg(feature = "http")]
+    pub async fn url_streamed(
+        http: impl AsRef<Http>,
+        url: &str,
+        filename: impl Into<String>,
+    ) -> Result<CreateAttachment> {
+        let response = http.as_ref().start_download(url).await?;
+        let len = response.content_length();
+        let body = reqwest::Body::wrap_stream(response.bytes_stream());
+
+        let mut attachment = CreateAttachment::bytes(Vec::new(), filename);
+        attachment.stream = Some(AttachmentStream::new(body, len));
+        Ok(attachment)
     }
 
     /// Converts the stored data to the base64 representation.
     ///
     /// This is used in the library internally because Discord expects image data as base64 in many
     /// places.
-    #[must_use]
-    pub fn to_base64(&self) -> String {
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ImageError::Streamed`] if the attachment was created with [`Self::url_streamed`],
+    /// as its data is not held in memory.
+    pub fn to_base64(&self) -> Result<String> {
+        if self.is_streamed() {
+            return Err(Error::Model(ModelError::InvalidImage(ImageError::Streamed)));
+        }
+
         let mut encoded = {
             use base64::Engine;
             base64::prelude::BASE64_STANDARD.encode(&self.data)
         };
         encoded.insert_str(0, "data:image/png;base64,");
-        encoded
+        Ok(encoded)
+    }
+
+    /// Whether the data is streamed while uploading instead of being held in [`Self::data`].
+    fn is_streamed(&self) -> bool {
+        #[cfg(feature = "http")]
+        let streamed = self.stream.is_some();
+        #[cfg(not(feature = "http"))]
+        let streamed = false;
+        streamed
     }
 
     /// Sets a description for the file (max 1024 characters).
@@ -112,6 +199,68 @@ impl CreateAttachment {
     }
 }
 
+/// Base64 image data as sent by builders, e.g. for avatars, which remembers the format and size
+/// of the original data so it can be validated when the builder is executed.
+#[derive(Clone, Debug, Serialize)]
+#[serde(transparent)]
+pub(crate) struct ImageData {
+    base64: String,
+    #[serde(skip)]
+    format: Option<ImageFormat>,
+    #[serde(skip)]
+    size: usize,
+    #[serde(skip)]
+    streamed: bool,
+}
+
+impl ImageData {
+    pub(crate) fn new(attachment: &CreateAttachment) -> Self {
+        Self {
+            base64: attachment.to_base64().unwrap_or_default(),
+            format: ImageFormat::detect(&attachment.data),
+            size: attachment.data.len(),
+            streamed: attachment.is_streamed(),
+        }
+    }
+
+    /// Checks that the image is in one of the given formats and at most `max_size` bytes large.
+    pub(crate) fn check(
+        &self,
+        formats: &[ImageFormat],
+        max_size: usize,
+    ) -> std::result::Result<(), ModelError> {
+        self.check_in_memory()?;
+        check_image(self.format, self.size, formats, max_size)
+    }
+
+    /// Checks that the image data was held in memory, without checking its format or size.
+    pub(crate) fn check_in_memory(&self) -> std::result::Result<(), ModelError> {
+        if self.streamed {
+            return Err(ModelError::InvalidImage(ImageError::Streamed));
+        }
+        Ok(())
+    }
+}
+
+/// Checks image data with the given detected format and size, see [`ImageData::check`].
+pub(crate) fn check_image(
+    format: Option<ImageFormat>,
+    size: usize,
+    formats: &[ImageFormat],
+    max_size: usize,
+) -> std::result::Result<(), ModelError> {
+    let error = match format {
+        None => ImageError::UnknownFormat,
+        Some(format) if !formats.contains(&format) => ImageError::UnsupportedFormat(format),
+        Some(_) if size > max_size => ImageError::TooLarge {
+            size,
+            max: max_size,
+        },
+        Some(_) => return Ok(()),
+    };
+    Err(ModelError::InvalidImage(error))
+}
+
 #[derive(Debug, Clone, serde::Serialize, PartialEq)]
 struct ExistingAttachment {
     id: AttachmentId,
@@ -263,6 +412,10 @@ impl EditAttachments {
                     std::mem::take(&mut attachment.data),
                     attachment.filename.clone(),
                 );
+                #[cfg(feature = "http")]
+                {
+                    cloned_attachment.stream = attachment.stream.take();
+                }
 
                 // Assign placeholder IDs so Discord can match metadata to file contents
                 attachment.id = id_placeholder;
@@ -280,3 +433,114 @@ impl EditAttachments {
         self.new_and_existing_attachments.is_empty()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+
+    fn temp_file(name: &str, data: &[u8]) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("serenity-attachment-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(name);
+        std::fs::write(&path, data).unwrap();
+        path
+    }
+
+    #[tokio::test]
+    async fn path_filenames() {
+        for name in ["with spaces.txt", "\u{fc}nic\u{f6}de \u{1f980}.png", "no_extension"] {
+            let path = temp_file(name, b"data");
+            let attachment = CreateAttachment::path(&path).await.unwrap();
+            assert_eq!(attachment.filename, name);
+            assert_eq!(attachment.data, b"data");
+            std::fs::remove_file(path).unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn path_with_filename() {
+        let path = temp_file(".tmpXYZ", b"png");
+        let attachment = CreateAttachment::path_with_filename(&path, "image.png").await.unwrap();
+        assert_eq!(attachment.filename, "image.png");
+        assert_eq!(attachment.data, b"png");
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn path_errors() {
+        let dir = std::env::temp_dir();
+        assert!(matches!(CreateAttachment::path(dir.join("..")).await, Err(Error::Io(_))));
+
+        #[cfg(unix)]
+        {
+            use std::ffi::OsStr;
+            use std::os::unix::ffi::OsStrExt;
+
+            let name = OsStr::from_bytes(b"invalid\xff.txt");
+            assert!(matches!(
+                CreateAttachment::path(dir.join(name)).await,
+                Err(Error::Model(ModelError::NonUtf8Filename(n))) if n == name
+            ));
+        }
+    }
+
+    const PNG: &[u8] = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR";
+    const JPEG: &[u8] = b"\xff\xd8\xff\xe0\0\x10JFIF\0";
+    const GIF: &[u8] = b"GIF89a\x01\0\x01\0\0\0\0;";
+    const WEBP: &[u8] = b"RIFF\x1a\0\0\0WEBPVP8L";
+
+    #[test]
+    fn image_formats() {
+        assert_eq!(ImageFormat::detect(PNG), Some(ImageFormat::Png));
+        assert_eq!(ImageFormat::detect(JPEG), Some(ImageFormat::Jpeg));
+        assert_eq!(ImageFormat::detect(GIF), Some(ImageFormat::Gif));
+        assert_eq!(ImageFormat::detect(b"GIF87a"), Some(ImageFormat::Gif));
+        assert_eq!(ImageFormat::detect(WEBP), Some(ImageFormat::WebP));
+        assert_eq!(ImageFormat::detect(b"RIFF\x1a\0\0\0WAVE"), None);
+        assert_eq!(ImageFormat::detect(b"\x89PN"), None);
+        assert_eq!(ImageFormat::detect(b""), None);
+    }
+
+    #[test]
+    fn image_data_check() {
+        let formats = [ImageFormat::Png, ImageFormat::Jpeg, ImageFormat::Gif];
+        let image = |data: &[u8]| ImageData::new(&CreateAttachment::bytes(data, "image"));
+
+        for data in [PNG, JPEG, GIF] {
+            assert!(image(data).check(&formats, 1024).is_ok());
+        }
+        assert!(matches!(
+            image(WEBP).check(&formats, 1024),
+            Err(ModelError::InvalidImage(ImageError::UnsupportedFormat(ImageFormat::WebP)))
+        ));
+        assert!(matches!(
+            image(b"not an image").check(&formats, 1024),
+            Err(ModelError::InvalidImage(ImageError::UnknownFormat))
+        ));
+        assert!(matches!(
+            image(PNG).check(&formats, 8),
+            Err(ModelError::InvalidImage(ImageError::TooLarge {
+                size: 16,
+                max: 8
+            }))
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "http")]
+    fn streamed_image() {
+        let mut attachment = CreateAttachment::bytes(Vec::new(), "image.png");
+        attachment.stream = Some(AttachmentStream::new(reqwest::Body::from(PNG), None));
+
+        assert!(matches!(
+            attachment.to_base64(),
+            Err(Error::Model(ModelError::InvalidImage(ImageError::Streamed)))
+        ));
+        assert!(matches!(
+            ImageData::new(&attachment).check(&[ImageFormat::Png], 1024),
+            Err(ModelError::InvalidImage(ImageError::Streamed))
+        ));
+    }
+}
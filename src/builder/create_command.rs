@@ -43,6 +43,55 @@ impl CreateCommandOption {
         })
     }
 
+    /// Creates a new subcommand with the given name and description.
+    ///
+    /// Parameters of the subcommand can be added with [`Self::add_sub_option`].
+    pub fn sub_command(name: impl Into<String>, description: impl Into<String>) -> Self {
+        Self::new(CommandOptionType::SubCommand, name, description)
+    }
+
+    /// Creates a new subcommand group with the given name and description.
+    ///
+    /// Subcommands of the group, created with [`Self::sub_command`], can be added with
+    /// [`Self::add_sub_option`].
+    ///
+    /// # Examples
+    ///
+    /// Building a `/config logging set` command:
+    ///
+    /// ```rust
+    /// use serenity::builder::{CreateCommand, CreateCommandOption};
+    /// use serenity::model::application::CommandOptionType;
+    ///
+    /// let command = CreateCommand::new("config").description("Configures the bot").add_option(
+    ///     CreateCommandOption::sub_command_group("logging", "Logging settings").add_sub_option(
+    ///         CreateCommandOption::sub_command("set", "Sets the logging channel").add_sub_option(
+    ///             CreateCommandOption::new(CommandOptionType::Channel, "channel", "The channel"),
+    ///         ),
+    ///     ),
+    /// );
+    /// assert!(command.validate().is_ok());
+    /// ```
+    pub fn sub_command_group(name: impl Into<String>, description: impl Into<String>) -> Self {
+        Self::new(CommandOptionType::SubCommandGroup, name, description)
+    }
+
+    /// Checks that this option, as a top-level option of a command, and its nested options are
+    /// valid.
+    ///
+    /// Subcommand groups may only contain subcommands and may not be nested. Subcommands may only
+    /// be nested in subcommand groups and may only contain regular options, which themselves
+    /// cannot have nested options. Every level may have up to 25 options, and every name must be
+    /// 1-32 lowercase characters without spaces.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidCommandNesting`], [`ModelError::InvalidCommandName`], or
+    /// [`ModelError::CommandOptionAmount`] naming the path of the offending option.
+    pub fn validate_nesting(&self) -> Result<()> {
+        validate_options(&[&self.0], None, "").map_err(Error::Model)
+    }
+
     /// Sets the `CommandOptionType`, replacing the current value as set in [`Self::new`].
     pub fn kind(mut self, kind: CommandOptionType) -> Self {
         self.0.kind = kind;
@@ -463,6 +512,100 @@ impl CreateCommand {
         self.nsfw = nsfw;
         self
     }
+
+    /// Checks the name of the command and the nesting, amount, and names of its options.
+    ///
+    /// This is called before the command is sent to Discord, e.g. by [`Self::execute`]. Refer to
+    /// [`CreateCommandOption::validate_nesting`] for the checked constraints. The name of user and
+    /// message commands is not checked, as these may contain uppercase characters and spaces.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidCommandNesting`], [`ModelError::InvalidCommandName`], or
+    /// [`ModelError::CommandOptionAmount`] naming the path of the offending command or option.
+    pub fn validate(&self) -> Result<()> {
+        if matches!(self.kind, None | Some(CommandType::ChatInput)) && !is_valid_name(&self.name) {
+            return Err(Error::Model(ModelError::InvalidCommandName {
+                path: self.name.clone(),
+            }));
+        }
+
+        let options: Vec<_> = self.options.iter().map(|option| &option.0).collect();
+        validate_options(&options, None, &self.name).map_err(Error::Model)
+    }
+}
+
+/// Whether the name is 1-32 characters long and contains no uppercase characters, whitespace, or
+/// punctuation other than `-` and `_`.
+fn is_valid_name(name: &str) -> bool {
+    (1..=32).contains(&name.chars().count())
+        && name.chars().all(|c| {
+            !(c.is_uppercase()
+                || c.is_whitespace()
+                || c.is_control()
+                || (c.is_ascii_punctuation() && c != '-' && c != '_'))
+        })
+}
+
+/// Validates the options nested in the option of the `parent` kind at `path`, where [`None`]
+/// denotes the command itself.
+fn validate_options(
+    options: &[&CommandOption],
+    parent: Option<CommandOptionType>,
+    path: &str,
+) -> StdResult<(), ModelError> {
+    fn is_sub_command(option: &CommandOption) -> bool {
+        matches!(option.kind, CommandOptionType::SubCommand | CommandOptionType::SubCommandGroup)
+    }
+
+    if options.len() > 25 {
+        return Err(ModelError::CommandOptionAmount {
+            path: path.to_string(),
+        });
+    }
+
+    // Commands may either have subcommands or regular options, but not both.
+    let mixed = parent.is_none()
+        && options.iter().any(|option| is_sub_command(option))
+        && options.iter().any(|option| !is_sub_command(option));
+
+    for option in options {
+        let option_path = if path.is_empty() {
+            option.name.clone()
+        } else {
+            format!("{path} {}", option.name)
+        };
+
+        if !is_valid_name(&option.name) {
+            return Err(ModelError::InvalidCommandName {
+                path: option_path,
+            });
+        }
+
+        let allowed = match option.kind {
+            CommandOptionType::SubCommandGroup => parent.is_none(),
+            CommandOptionType::SubCommand => {
+                matches!(parent, None | Some(CommandOptionType::SubCommandGroup))
+            },
+            _ => parent != Some(CommandOptionType::SubCommandGroup) && !mixed,
+        };
+        if !allowed {
+            return Err(ModelError::InvalidCommandNesting {
+                path: option_path,
+            });
+        }
+
+        let sub_options: Vec<_> = option.options.iter().collect();
+        if is_sub_command(option) {
+            validate_options(&sub_options, Some(option.kind), &option_path)?;
+        } else if let Some(sub_option) = sub_options.first() {
+            return Err(ModelError::InvalidCommandNesting {
+                path: format!("{option_path} {}", sub_option.name),
+            });
+        }
+    }
+
+    Ok(())
 }
 
 #[cfg(feature = "http")]
@@ -480,6 +623,8 @@ impl Builder for CreateCommand {
     ///
     /// # Errors
     ///
+    /// Returns an [`Error::Model`] if the command fails [validation][`CreateCommand::validate`].
+    ///
     /// Returns [`Error::Http`] if invalid data is given. See [Discord's docs] for more details.
     ///
     /// May also return [`Error::Json`] if there is an error in deserializing the API response.
@@ -490,6 +635,8 @@ impl Builder for CreateCommand {
         cache_http: impl CacheHttp,
         ctx: Self::Context<'_>,
     ) -> Result<Self::Built> {
+        self.validate()?;
+
         let http = cache_http.http();
         match ctx {
             (Some(guild_id), Some(cmd_id)) => {
@@ -501,3 +648,113 @@ impl Builder for CreateCommand {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn string(name: &str) -> CreateCommandOption {
+        CreateCommandOption::new(CommandOptionType::String, name, "A string")
+    }
+
+    fn nesting_error(path: &str) -> String {
+        Error::Model(ModelError::InvalidCommandNesting {
+            path: path.into(),
+        })
+        .to_string()
+    }
+
+    #[test]
+    fn valid_nesting() {
+        let command = CreateCommand::new("config")
+            .description("Configures the bot")
+            .add_option(
+                CreateCommandOption::sub_command_group("logging", "Logging settings")
+                    .add_sub_option(
+                        CreateCommandOption::sub_command("set", "Sets logging")
+                            .add_sub_option(string("channel")),
+                    )
+                    .add_sub_option(CreateCommandOption::sub_command("clear", "Clears logging")),
+            )
+            .add_option(CreateCommandOption::sub_command("reset", "Resets everything"));
+        assert!(command.validate().is_ok());
+
+        let command = CreateCommand::new("echo").add_option(string("text"));
+        assert!(command.validate().is_ok());
+    }
+
+    #[test]
+    fn invalid_nesting() {
+        let nested_group = CreateCommand::new("config").add_option(
+            CreateCommandOption::sub_command_group("a", "A").add_sub_option(
+                CreateCommandOption::sub_command_group("b", "B")
+                    .add_sub_option(CreateCommandOption::sub_command("c", "C")),
+            ),
+        );
+        let err = nested_group.validate().unwrap_err();
+        assert_eq!(err.to_string(), nesting_error("config a b"));
+
+        let nested_sub_command = CreateCommand::new("config").add_option(
+            CreateCommandOption::sub_command("a", "A")
+                .add_sub_option(CreateCommandOption::sub_command("b", "B")),
+        );
+        let err = nested_sub_command.validate().unwrap_err();
+        assert_eq!(err.to_string(), nesting_error("config a b"));
+
+        let group = CreateCommandOption::sub_command_group("a", "A").add_sub_option(string("b"));
+        let option_in_group = CreateCommand::new("config").add_option(group);
+        let err = option_in_group.validate().unwrap_err();
+        assert_eq!(err.to_string(), nesting_error("config a b"));
+
+        let nested_option = CreateCommand::new("config")
+            .add_option(string("a").add_sub_option(string("b")));
+        let err = nested_option.validate().unwrap_err();
+        assert_eq!(err.to_string(), nesting_error("config a b"));
+
+        let mixed = CreateCommand::new("config")
+            .add_option(CreateCommandOption::sub_command("a", "A"))
+            .add_option(string("b"));
+        let err = mixed.validate().unwrap_err();
+        assert_eq!(err.to_string(), nesting_error("config b"));
+
+        let option = CreateCommandOption::sub_command("a", "A")
+            .add_sub_option(CreateCommandOption::sub_command("b", "B"));
+        let err = option.validate_nesting().unwrap_err();
+        assert_eq!(err.to_string(), nesting_error("a b"));
+    }
+
+    #[test]
+    fn names() {
+        assert!(is_valid_name("set-channel_2"));
+        assert!(is_valid_name("über"));
+        assert!(!is_valid_name(""));
+        assert!(!is_valid_name("Set"));
+        assert!(!is_valid_name("set channel"));
+        assert!(!is_valid_name("set!"));
+        assert!(!is_valid_name(&"a".repeat(33)));
+
+        let command = CreateCommand::new("config")
+            .add_option(CreateCommandOption::sub_command("Set", "Sets"));
+        assert!(matches!(
+            command.validate(),
+            Err(Error::Model(ModelError::InvalidCommandName { path })) if path == "config Set"
+        ));
+
+        let command = CreateCommand::new("Open Profile").kind(CommandType::User);
+        assert!(command.validate().is_ok());
+        let command = CreateCommand::new("Open Profile");
+        assert!(command.validate().is_err());
+    }
+
+    #[test]
+    fn option_amount() {
+        let group = (0..26).fold(CreateCommandOption::sub_command_group("a", "A"), |group, i| {
+            group.add_sub_option(CreateCommandOption::sub_command(format!("s{i}"), "S"))
+        });
+        let command = CreateCommand::new("config").add_option(group);
+        assert!(matches!(
+            command.validate(),
+            Err(Error::Model(ModelError::CommandOptionAmount { path })) if path == "config a"
+        ));
+    }
+}
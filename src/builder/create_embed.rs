@@ -14,6 +14,9 @@
 //! [`ExecuteWebhook::embeds`]: crate::builder::ExecuteWebhook::embeds
 //! [here]: https://discord.com/developers/docs/resources/channel#embed-object
 
+use std::fmt;
+
+use crate::constants;
 #[cfg(feature = "http")]
 use crate::internal::prelude::*;
 use crate::model::prelude::*;
@@ -203,6 +206,81 @@ impl CreateEmbed {
         super::check_overflow(length, crate::constants::EMBED_MAX_LENGTH)
             .map_err(|overflow| Error::Model(ModelError::EmbedTooLarge(overflow)))
     }
+
+    /// Checks the embed against all limits documented by Discord, collecting every violation.
+    ///
+    /// Lengths are counted in unicode scalar values, the same way Discord counts them. The
+    /// violations returned by this method always have an [`EmbedViolation::embed`] of `Some(0)`;
+    /// see [`CreateMessage::validate_embeds`] to validate all embeds of a message.
+    ///
+    /// This is opt-in, as sending an embed only checks the total length, leaving the remaining
+    /// limits to Discord.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`EmbedValidationError`] listing every exceeded limit.
+    ///
+    /// [`CreateMessage::validate_embeds`]: super::CreateMessage::validate_embeds
+    pub fn validate(&self) -> Result<(), EmbedValidationError> {
+        let mut violations = Vec::new();
+        self.collect_violations(0, &mut violations);
+        EmbedValidationError::from_violations(violations)
+    }
+
+    /// Pushes all violations of this embed, as the embed at `index` of a message, and returns its
+    /// total length.
+    pub(super) fn collect_violations(
+        &self,
+        index: usize,
+        violations: &mut Vec<EmbedViolation>,
+    ) -> usize {
+        let mut total = 0;
+        let mut check = |limit: EmbedLimit, text: &str| {
+            let length = text.chars().count();
+            total += length;
+            if length > limit.max() {
+                violations.push(EmbedViolation {
+                    embed: Some(index),
+                    limit,
+                    length,
+                });
+            }
+        };
+
+        if let Some(title) = &self.0.title {
+            check(EmbedLimit::Title, title);
+        }
+        if let Some(description) = &self.0.description {
+            check(EmbedLimit::Description, description);
+        }
+        for (i, field) in self.0.fields.iter().enumerate() {
+            check(EmbedLimit::FieldName(i), &field.name);
+            check(EmbedLimit::FieldValue(i), &field.value);
+        }
+        if let Some(footer) = &self.0.footer {
+            check(EmbedLimit::FooterText, &footer.text);
+        }
+        if let Some(author) = &self.0.author {
+            check(EmbedLimit::AuthorName, &author.name);
+        }
+
+        if self.0.fields.len() > EmbedLimit::FieldCount.max() {
+            violations.push(EmbedViolation {
+                embed: Some(index),
+                limit: EmbedLimit::FieldCount,
+                length: self.0.fields.len(),
+            });
+        }
+        if total > EmbedLimit::Total.max() {
+            violations.push(EmbedViolation {
+                embed: Some(index),
+                limit: EmbedLimit::Total,
+                length: total,
+            });
+        }
+
+        total
+    }
 }
 
 impl Default for CreateEmbed {
@@ -232,6 +310,126 @@ impl From<Embed> for CreateEmbed {
     }
 }
 
+/// A limit of an embed, as checked by [`CreateEmbed::validate`].
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/message#embed-object-embed-limits).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum EmbedLimit {
+    /// The title is limited to 256 characters.
+    Title,
+    /// The description is limited to 4096 characters.
+    Description,
+    /// An embed is limited to 25 fields.
+    FieldCount,
+    /// The name of the field at the given index is limited to 256 characters.
+    FieldName(usize),
+    /// The value of the field at the given index is limited to 1024 characters.
+    FieldValue(usize),
+    /// The footer text is limited to 2048 characters.
+    FooterText,
+    /// The author name is limited to 256 characters.
+    AuthorName,
+    /// The title, description, field names and values, footer text and author name are limited
+    /// to 6000 characters in total.
+    Total,
+    /// The texts counted by [`Self::Total`] are limited to 6000 characters across all embeds of
+    /// a message.
+    MessageTotal,
+    /// A message is limited to 10 embeds.
+    EmbedCount,
+}
+
+impl EmbedLimit {
+    /// The maximum length or count allowed by this limit.
+    #[must_use]
+    pub fn max(self) -> usize {
+        match self {
+            Self::Title | Self::FieldName(_) | Self::AuthorName => 256,
+            Self::Description => 4096,
+            Self::FieldCount => 25,
+            Self::FieldValue(_) => 1024,
+            Self::FooterText => 2048,
+            Self::Total | Self::MessageTotal => constants::EMBED_MAX_LENGTH,
+            Self::EmbedCount => constants::EMBED_MAX_COUNT,
+        }
+    }
+}
+
+impl fmt::Display for EmbedLimit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Title => f.write_str("title"),
+            Self::Description => f.write_str("description"),
+            Self::FieldCount => f.write_str("field count"),
+            Self::FieldName(i) => write!(f, "name of field {i}"),
+            Self::FieldValue(i) => write!(f, "value of field {i}"),
+            Self::FooterText => f.write_str("footer text"),
+            Self::AuthorName => f.write_str("author name"),
+            Self::Total => f.write_str("total length"),
+            Self::MessageTotal => f.write_str("total length of all embeds"),
+            Self::EmbedCount => f.write_str("embed count"),
+        }
+    }
+}
+
+/// A single exceeded [`EmbedLimit`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub struct EmbedViolation {
+    /// The index of the offending embed within the message, or [`None`] if the violation concerns
+    /// all embeds of the message.
+    pub embed: Option<usize>,
+    /// The exceeded limit.
+    pub limit: EmbedLimit,
+    /// The actual length or count, in unicode scalar values for texts.
+    pub length: usize,
+}
+
+impl fmt::Display for EmbedViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(embed) = self.embed {
+            write!(f, "embed {embed}: ")?;
+        }
+        write!(f, "{} is {} but must be at most {}", self.limit, self.length, self.limit.max())
+    }
+}
+
+/// Error returned by [`CreateEmbed::validate`], containing all violated limits.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct EmbedValidationError {
+    /// The violations, never empty.
+    pub violations: Vec<EmbedViolation>,
+}
+
+impl EmbedValidationError {
+    pub(super) fn from_violations(violations: Vec<EmbedViolation>) -> Result<(), Self> {
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(Self {
+                violations,
+            })
+        }
+    }
+}
+
+impl fmt::Display for EmbedValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Embed limits exceeded: ")?;
+        for (i, violation) in self.violations.iter().enumerate() {
+            if i != 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{violation}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for EmbedValidationError {}
+
 /// A builder to create the author data of an embed. See [`CreateEmbed::author`]
 #[derive(Clone, Debug, Serialize)]
 #[must_use]
@@ -282,6 +480,29 @@ impl From<User> for CreateEmbedAuthor {
     }
 }
 
+#[cfg(feature = "model")]
+impl From<&User> for CreateEmbedAuthor {
+    /// Uses the user's [display name] as the name and their [avatar] as the icon.
+    ///
+    /// [display name]: User::display_name
+    /// [avatar]: User::face
+    fn from(user: &User) -> Self {
+        Self::new(user.display_name()).icon_url(user.face())
+    }
+}
+
+#[cfg(feature = "model")]
+impl From<&Member> for CreateEmbedAuthor {
+    /// Uses the member's [display name] as the name and their [guild avatar] as the icon, falling
+    /// back to the user's avatar.
+    ///
+    /// [display name]: Member::display_name
+    /// [guild avatar]: Member::face
+    fn from(member: &Member) -> Self {
+        Self::new(member.display_name()).icon_url(member.face())
+    }
+}
+
 /// A builder to create the footer data for an embed. See [`CreateEmbed::footer`]
 #[derive(Clone, Debug, Serialize)]
 #[must_use]
@@ -318,3 +539,121 @@ impl From<EmbedFooter> for CreateEmbedFooter {
         Self(footer)
     }
 }
+
+#[cfg(feature = "model")]
+impl From<&CurrentUser> for CreateEmbedFooter {
+    /// Uses the current user's [display name] as the text and their [avatar] as the icon.
+    ///
+    /// [display name]: User::display_name
+    /// [avatar]: User::face
+    fn from(user: &CurrentUser) -> Self {
+        Self::new(user.display_name()).icon_url(user.face())
+    }
+}
+
+#[cfg(all(test, feature = "model"))]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::builder::CreateMessage;
+
+    const ANIMATED: &str = "a_fb211703bcc04ee612c88d494df0272f";
+
+    fn user() -> User {
+        let mut user = User::default();
+        user.id = UserId::new(210);
+        user.name = "test".to_string();
+        user.global_name = Some("Test User".to_string());
+        user.avatar = Some(ImageHash::from_str(ANIMATED).unwrap());
+        user
+    }
+
+    #[test]
+    fn author_from_user() {
+        let author = CreateEmbedAuthor::from(&user()).0;
+
+        assert_eq!(author.name, "Test User");
+        let expected = "/avatars/210/a_fb211703bcc04ee612c88d494df0272f.gif?size=1024";
+        assert!(author.icon_url.unwrap().ends_with(expected));
+    }
+
+    #[test]
+    fn author_from_member() {
+        let mut member = Member::default();
+        member.guild_id = GuildId::new(381880193251409931);
+        member.user = user();
+        member.nick = Some("nickname".to_string());
+
+        // Falls back to the user's avatar without a guild avatar.
+        let author = CreateEmbedAuthor::from(&member).0;
+        assert_eq!(author.name, "nickname");
+        let expected = "/avatars/210/a_fb211703bcc04ee612c88d494df0272f.gif?size=1024";
+        assert!(author.icon_url.unwrap().ends_with(expected));
+
+        member.avatar = Some(ImageHash::from_str(ANIMATED).unwrap());
+        let author = CreateEmbedAuthor::from(&member).0;
+        let expected = "/guilds/381880193251409931/users/210/avatars/\
+            a_fb211703bcc04ee612c88d494df0272f.gif?size=1024";
+        assert!(author.icon_url.unwrap().ends_with(expected));
+    }
+
+    #[test]
+    fn validate_embed() {
+        assert!(CreateEmbed::new().title("title").validate().is_ok());
+
+        // Multi-byte characters count once, as Discord counts unicode scalar values.
+        let title = "\u{e9}".repeat(256);
+        assert!(CreateEmbed::new().title(title).validate().is_ok());
+
+        let embed = CreateEmbed::new()
+            .title("a".repeat(257))
+            .fields((0..26).map(|i| (i.to_string(), "value", false)))
+            .field("name", "v".repeat(1025), false);
+        let violations = embed.validate().unwrap_err().violations;
+
+        let limits: Vec<_> = violations.iter().map(|v| v.limit).collect();
+        assert_eq!(limits, [EmbedLimit::Title, EmbedLimit::FieldValue(26), EmbedLimit::FieldCount]);
+        assert_eq!(violations[0].length, 257);
+        assert_eq!(violations[2].length, 27);
+        assert!(violations.iter().all(|v| v.embed == Some(0)));
+    }
+
+    #[test]
+    fn validate_message_embeds() {
+        let embeds = (0..11).map(|_| CreateEmbed::new().description("d".repeat(1000))).collect();
+        let message = CreateMessage::new()
+            .embeds(embeds)
+            .add_embed(CreateEmbed::new().footer(CreateEmbedFooter::new("f".repeat(2049))));
+        let violations = message.validate_embeds().unwrap_err().violations;
+
+        assert_eq!(violations, [
+            EmbedViolation {
+                embed: Some(11),
+                limit: EmbedLimit::FooterText,
+                length: 2049,
+            },
+            EmbedViolation {
+                embed: None,
+                limit: EmbedLimit::EmbedCount,
+                length: 12,
+            },
+            EmbedViolation {
+                embed: None,
+                limit: EmbedLimit::MessageTotal,
+                length: 13049,
+            },
+        ]);
+    }
+
+    #[test]
+    fn footer_from_current_user() {
+        let mut current_user = CurrentUser::default();
+        *current_user = user();
+        let footer = CreateEmbedFooter::from(&current_user).0;
+
+        assert_eq!(footer.text, "Test User");
+        let expected = "/avatars/210/a_fb211703bcc04ee612c88d494df0272f.gif?size=1024";
+        assert!(footer.icon_url.unwrap().ends_with(expected));
+    }
+}
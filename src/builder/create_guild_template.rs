@@ -0,0 +1,71 @@
+#[cfg(feature = "http")]
+use super::Builder;
+#[cfg(feature = "http")]
+use crate::http::CacheHttp;
+#[cfg(feature = "http")]
+use crate::internal::prelude::*;
+#[cfg(feature = "http")]
+use crate::model::prelude::*;
+
+/// A builder to create a template from a guild.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/guild-template#create-guild-template)
+#[derive(Clone, Debug, Serialize)]
+#[must_use]
+pub struct CreateGuildTemplate {
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+}
+
+impl CreateGuildTemplate {
+    /// Creates a builder with the given name. All other fields are left empty.
+    ///
+    /// **Note**: Must be between 1 and 100 characters long.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            description: None,
+        }
+    }
+
+    /// The name of the template.
+    ///
+    /// **Note**: Must be between 1 and 100 characters long.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    /// The description of the template.
+    ///
+    /// **Note**: Must be at most 120 characters long.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+}
+
+#[cfg(feature = "http")]
+#[async_trait::async_trait]
+impl Builder for CreateGuildTemplate {
+    type Context<'ctx> = GuildId;
+    type Built = GuildTemplate;
+
+    /// Creates a template from the current state of the guild.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission, or if invalid data is given.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    async fn execute(
+        self,
+        cache_http: impl CacheHttp,
+        ctx: Self::Context<'_>,
+    ) -> Result<Self::Built> {
+        cache_http.http().create_guild_template(ctx, &self).await
+    }
+}
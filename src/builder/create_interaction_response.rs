@@ -88,6 +88,20 @@ impl serde::Serialize for CreateInteractionResponse {
             Self::Message(x) => map.serialize_entry("data", &x)?,
             Self::Defer(x) => map.serialize_entry("data", &x)?,
             Self::Acknowledge => map.serialize_entry("data", &None::<()>)?,
+            Self::UpdateMessage(x) if x.attachments.is_none() => {
+                // Updating a message clears its attachments unless they are explicitly kept.
+                #[derive(Serialize)]
+                struct ClearAttachments<'a> {
+                    #[serde(flatten)]
+                    message: &'a CreateInteractionResponseMessage,
+                    attachments: EditAttachments,
+                }
+
+                map.serialize_entry("data", &ClearAttachments {
+                    message: x,
+                    attachments: EditAttachments::new(),
+                })?;
+            },
             Self::UpdateMessage(x) => map.serialize_entry("data", &x)?,
             Self::Autocomplete(x) => map.serialize_entry("data", &x)?,
             Self::Modal(x) => map.serialize_entry("data", &x)?,
@@ -148,7 +162,9 @@ impl Builder for CreateInteractionResponse {
         let files = match &mut self {
             CreateInteractionResponse::Message(msg)
             | CreateInteractionResponse::Defer(msg)
-            | CreateInteractionResponse::UpdateMessage(msg) => msg.attachments.take_files(),
+            | CreateInteractionResponse::UpdateMessage(msg) => {
+                msg.attachments.as_mut().map_or_else(Vec::new, EditAttachments::take_files)
+            },
             _ => Vec::new(),
         };
 
@@ -179,7 +195,8 @@ pub struct CreateInteractionResponseMessage {
     flags: Option<InteractionResponseFlags>,
     #[serde(skip_serializing_if = "Option::is_none")]
     components: Option<Vec<CreateActionRow>>,
-    attachments: EditAttachments,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    attachments: Option<EditAttachments>,
 }
 
 impl CreateInteractionResponseMessage {
@@ -200,14 +217,15 @@ impl CreateInteractionResponseMessage {
 
     /// Appends a file to the message.
     pub fn add_file(mut self, file: CreateAttachment) -> Self {
-        self.attachments = self.attachments.add(file);
+        let attachments = self.attachments.take().unwrap_or_default();
+        self.attachments = Some(attachments.add(file));
         self
     }
 
     /// Appends a list of files to the message.
     pub fn add_files(mut self, files: impl IntoIterator<Item = CreateAttachment>) -> Self {
         for file in files {
-            self.attachments = self.attachments.add(file);
+            self = self.add_file(file);
         }
         self
     }
@@ -217,10 +235,22 @@ impl CreateInteractionResponseMessage {
     /// Calling this multiple times will overwrite the file list. To append files, call
     /// [`Self::add_file`] or [`Self::add_files`] instead.
     pub fn files(mut self, files: impl IntoIterator<Item = CreateAttachment>) -> Self {
-        self.attachments = EditAttachments::new();
+        self.attachments = Some(EditAttachments::new());
         self.add_files(files)
     }
 
+    /// Sets attachments, see [`EditAttachments`] for more details.
+    ///
+    /// When responding with [`CreateInteractionResponse::UpdateMessage`], the existing attachments
+    /// of the message are removed unless this is set, e.g. to [`EditAttachments::keep_all`].
+    ///
+    /// Calling this overwrites the files set via [`Self::add_file`], [`Self::add_files`] or
+    /// [`Self::files`], which are appended to it when called afterwards.
+    pub fn attachments(mut self, attachments: EditAttachments) -> Self {
+        self.attachments = Some(attachments);
+        self
+    }
+
     /// Set the content of the message.
     ///
     /// **Note**: Message contents must be under 2000 unicode code points.
@@ -433,3 +463,43 @@ impl CreateModal {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json::{json, to_value};
+
+    #[test]
+    fn update_message_attachments() {
+        let message = CreateInteractionResponseMessage::new().content("hi");
+        assert_eq!(
+            to_value(CreateInteractionResponse::Message(message.clone())).unwrap(),
+            json!({"type": 4, "data": {"content": "hi"}})
+        );
+        assert_eq!(
+            to_value(CreateInteractionResponse::UpdateMessage(message.clone())).unwrap(),
+            json!({"type": 7, "data": {"content": "hi", "attachments": []}})
+        );
+
+        let attachments = EditAttachments::new().keep(AttachmentId::new(1));
+        assert_eq!(
+            to_value(CreateInteractionResponse::UpdateMessage(message.attachments(attachments)))
+                .unwrap(),
+            json!({"type": 7, "data": {"content": "hi", "attachments": [{"id": "1"}]}})
+        );
+    }
+
+    #[test]
+    fn defer_ephemeral() {
+        let message = CreateInteractionResponseMessage::new().ephemeral(true);
+        assert_eq!(
+            to_value(CreateInteractionResponse::Defer(message)).unwrap(),
+            json!({"type": 5, "data": {"flags": 64}})
+        );
+        assert_eq!(
+            to_value(CreateInteractionResponse::Defer(CreateInteractionResponseMessage::new()))
+                .unwrap(),
+            json!({"type": 5, "data": {}})
+        );
+    }
+}
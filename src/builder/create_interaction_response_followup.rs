@@ -1,3 +1,4 @@
+use super::create_poll::Ready;
 #[cfg(feature = "http")]
 use super::{check_overflow, Builder};
 use super::{
@@ -5,6 +6,7 @@ use super::{
     CreateAllowedMentions,
     CreateAttachment,
     CreateEmbed,
+    CreatePoll,
     EditAttachments,
 };
 #[cfg(feature = "http")]
@@ -32,6 +34,8 @@ pub struct CreateInteractionResponseFollowup {
     components: Option<Vec<CreateActionRow>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     flags: Option<MessageFlags>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    poll: Option<CreatePoll<Ready>>,
     attachments: EditAttachments,
 }
 
@@ -160,6 +164,14 @@ impl CreateInteractionResponseFollowup {
         self
     }
     super::button_and_select_menu_convenience_methods!(self.components);
+
+    /// Sets the [`Poll`] for this message.
+    ///
+    /// **Note**: Polls cannot be edited, so this can only be used when creating a followup.
+    pub fn poll(mut self, poll: CreatePoll<Ready>) -> Self {
+        self.poll = Some(poll);
+        self
+    }
 }
 
 #[cfg(feature = "http")]
@@ -176,15 +188,18 @@ impl Builder for CreateInteractionResponseFollowup {
     ///
     /// # Errors
     ///
-    /// Returns [`Error::Model`] if the content is too long. May also return [`Error::Http`] if the
-    /// API returns an error, or [`Error::Json`] if there is an error in deserializing the
-    /// response.
+    /// Returns [`Error::Model`] if the content is too long, or a [`ModelError::CannotEditPoll`] if
+    /// a poll is set when editing a followup. May also return [`Error::Http`] if the API returns an
+    /// error, or [`Error::Json`] if there is an error in deserializing the response.
     async fn execute(
         mut self,
         cache_http: impl CacheHttp,
         ctx: Self::Context<'_>,
     ) -> Result<Self::Built> {
         self.check_length()?;
+        if ctx.0.is_some() && self.poll.is_some() {
+            return Err(Error::Model(ModelError::CannotEditPoll));
+        }
 
         let files = self.attachments.take_files();
 
@@ -199,3 +214,45 @@ impl Builder for CreateInteractionResponseFollowup {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::CreatePollAnswer;
+    use crate::json::{json, to_value};
+
+    #[test]
+    fn followup_with_poll() {
+        let poll = CreatePoll::new()
+            .question("Cats or Dogs?")
+            .answers(vec![
+                CreatePollAnswer::new().text("Cats!"),
+                CreatePollAnswer::new().text("Dogs!"),
+            ])
+            .duration(std::time::Duration::from_secs(60 * 60 * 24));
+        let builder = CreateInteractionResponseFollowup::new().content("Vote!").poll(poll);
+
+        assert_eq!(
+            to_value(builder).unwrap(),
+            json!({
+                "content": "Vote!",
+                "embeds": [],
+                "poll": {
+                    "question": {"text": "Cats or Dogs?"},
+                    "answers": [
+                        {"poll_media": {"text": "Cats!", "emoji": null}},
+                        {"poll_media": {"text": "Dogs!", "emoji": null}},
+                    ],
+                    "duration": 24,
+                    "allow_multiselect": false,
+                    "layout_type": null,
+                },
+                "attachments": [],
+            })
+        );
+        assert_eq!(
+            to_value(CreateInteractionResponseFollowup::new()).unwrap(),
+            json!({"embeds": [], "attachments": []})
+        );
+    }
+}
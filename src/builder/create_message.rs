@@ -8,6 +8,9 @@ use super::{
     CreateEmbed,
     CreatePoll,
     EditAttachments,
+    EmbedLimit,
+    EmbedValidationError,
+    EmbedViolation,
 };
 #[cfg(feature = "http")]
 use crate::constants;
@@ -103,6 +106,41 @@ impl CreateMessage {
         Ok(())
     }
 
+    /// Checks all embeds of the message against the limits documented by Discord, collecting
+    /// every violation. See [`CreateEmbed::validate`] for details.
+    ///
+    /// Besides the limits of each embed, this checks the number of embeds and their combined
+    /// length.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`EmbedValidationError`] listing every exceeded limit.
+    pub fn validate_embeds(&self) -> Result<(), EmbedValidationError> {
+        let mut violations = Vec::new();
+
+        let mut total = 0;
+        for (i, embed) in self.embeds.iter().enumerate() {
+            total += embed.collect_violations(i, &mut violations);
+        }
+
+        if self.embeds.len() > EmbedLimit::EmbedCount.max() {
+            violations.push(EmbedViolation {
+                embed: None,
+                limit: EmbedLimit::EmbedCount,
+                length: self.embeds.len(),
+            });
+        }
+        if total > EmbedLimit::MessageTotal.max() {
+            violations.push(EmbedViolation {
+                embed: None,
+                limit: EmbedLimit::MessageTotal,
+                length: total,
+            });
+        }
+
+        EmbedValidationError::from_violations(violations)
+    }
+
     /// Set the content of the message.
     ///
     /// **Note**: Message contents must be under 2000 unicode code points.
@@ -214,6 +252,15 @@ impl CreateMessage {
         self
     }
 
+    /// Forwards the given message, replacing any reference set via [`Self::reference_message`].
+    ///
+    /// The message is sent as a snapshot of the forwarded one. Discord rejects forwards that set
+    /// any content, embeds, files, stickers, polls or components.
+    pub fn forward(mut self, message: &Message) -> Self {
+        self.message_reference = Some(MessageReference::forward(message));
+        self
+    }
+
     /// Sets the components of this message.
     pub fn components(mut self, components: Vec<CreateActionRow>) -> Self {
         self.components = Some(components);
@@ -227,6 +274,20 @@ impl CreateMessage {
         self
     }
 
+    /// Suppress or unsuppress embeds in the message, keeping any other flags already set.
+    ///
+    /// Setting this at send time avoids having to wait for the embed to appear and then editing
+    /// the message, as described in [`EditMessage::suppress_embeds`].
+    ///
+    /// [`EditMessage::suppress_embeds`]: super::EditMessage::suppress_embeds
+    pub fn suppress_embeds(mut self, suppress: bool) -> Self {
+        let mut flags = self.flags.unwrap_or_else(MessageFlags::empty);
+        flags.set(MessageFlags::SUPPRESS_EMBEDS, suppress);
+
+        self.flags = Some(flags);
+        self
+    }
+
     /// Sets a single sticker ID to include in the message.
     ///
     /// **Note**: This will replace all existing stickers. Use [`Self::add_sticker_id()`] to keep
@@ -364,3 +425,29 @@ impl Builder for CreateMessage {
         Ok(message)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json::{json, to_value};
+
+    #[test]
+    fn suppress_embeds_keeps_flags() {
+        let builder = CreateMessage::new().flags(MessageFlags::SUPPRESS_NOTIFICATIONS);
+
+        let builder = builder.suppress_embeds(true);
+        assert_eq!(
+            builder.flags,
+            Some(MessageFlags::SUPPRESS_NOTIFICATIONS | MessageFlags::SUPPRESS_EMBEDS)
+        );
+
+        let builder = builder.suppress_embeds(false);
+        assert_eq!(builder.flags, Some(MessageFlags::SUPPRESS_NOTIFICATIONS));
+
+        let builder = CreateMessage::new().suppress_embeds(true);
+        assert_eq!(
+            to_value(builder).unwrap()["flags"],
+            json!(MessageFlags::SUPPRESS_EMBEDS.bits())
+        );
+    }
+}
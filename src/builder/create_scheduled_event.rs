@@ -1,11 +1,13 @@
 #[cfg(feature = "http")]
 use super::Builder;
-use super::CreateAttachment;
+use super::{CreateAttachment, ImageData};
 #[cfg(feature = "http")]
 use crate::http::CacheHttp;
 #[cfg(feature = "http")]
 use crate::internal::prelude::*;
 use crate::model::prelude::*;
+#[cfg(feature = "http")]
+use crate::model::ImageFormat;
 
 /// [Discord docs](https://discord.com/developers/docs/resources/guild-scheduled-event#create-guild-scheduled-event)
 #[derive(Clone, Debug, Serialize)]
@@ -24,7 +26,7 @@ pub struct CreateScheduledEvent<'a> {
     description: Option<String>,
     entity_type: ScheduledEventType,
     #[serde(skip_serializing_if = "Option::is_none")]
-    image: Option<String>,
+    image: Option<ImageData>,
 
     #[serde(skip)]
     audit_log_reason: Option<&'a str>,
@@ -111,7 +113,7 @@ impl<'a> CreateScheduledEvent<'a> {
 
     /// Sets the cover image for the scheduled event.
     pub fn image(mut self, image: &CreateAttachment) -> Self {
-        self.image = Some(image.to_base64());
+        self.image = Some(ImageData::new(image));
         self
     }
 
@@ -122,6 +124,14 @@ impl<'a> CreateScheduledEvent<'a> {
     }
 }
 
+/// Checks that a cover image is a PNG, JPEG, or GIF image of at most 10 MiB.
+#[cfg(feature = "http")]
+pub(super) fn check_cover_image(image: &ImageData) -> StdResult<(), ModelError> {
+    const MAX_SIZE: usize = 10 * 1024 * 1024;
+
+    image.check(&[ImageFormat::Png, ImageFormat::Jpeg, ImageFormat::Gif], MAX_SIZE)
+}
+
 #[cfg(feature = "http")]
 #[async_trait::async_trait]
 impl Builder for CreateScheduledEvent<'_> {
@@ -137,12 +147,19 @@ impl Builder for CreateScheduledEvent<'_> {
     /// If the `cache` is enabled, returns a [`ModelError::InvalidPermissions`] if the current user
     /// lacks permission. Otherwise returns [`Error::Http`], as well as if invalid data is given.
     ///
+    /// Returns a [`ModelError::InvalidImage`] if the cover image is not a PNG, JPEG, or GIF image
+    /// of at most 10 MiB.
+    ///
     /// [Create Events]: Permissions::CREATE_EVENTS
     async fn execute(
         self,
         cache_http: impl CacheHttp,
         ctx: Self::Context<'_>,
     ) -> Result<Self::Built> {
+        if let Some(image) = &self.image {
+            check_cover_image(image)?;
+        }
+
         #[cfg(feature = "cache")]
         crate::utils::user_has_guild_perms(&cache_http, ctx, Permissions::CREATE_EVENTS)?;
 
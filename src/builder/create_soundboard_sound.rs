@@ -0,0 +1,195 @@
+#[cfg(feature = "http")]
+use super::Builder;
+use super::CreateAttachment;
+#[cfg(feature = "http")]
+use crate::http::CacheHttp;
+#[cfg(feature = "http")]
+use crate::internal::prelude::*;
+use crate::model::prelude::*;
+
+/// The maximum size of sound files in bytes.
+#[cfg(feature = "http")]
+const SOUND_MAX_SIZE: usize = 512 * 1024;
+
+/// A builder to create a soundboard sound in a guild.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/soundboard#create-guild-soundboard-sound)
+#[derive(Clone, Debug, Serialize)]
+#[must_use]
+pub struct CreateSoundboardSound<'a> {
+    name: String,
+    sound: SoundData,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    volume: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    emoji_id: Option<EmojiId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    emoji_name: Option<String>,
+
+    #[serde(skip)]
+    audit_log_reason: Option<&'a str>,
+}
+
+impl<'a> CreateSoundboardSound<'a> {
+    /// Creates a new builder with the given name and sound file.
+    ///
+    /// **Note**: The name must be between 2 and 32 characters long, and the sound must be an MP3
+    /// or Ogg file of at most 512 KiB.
+    pub fn new(name: impl Into<String>, sound: &CreateAttachment) -> Self {
+        Self {
+            name: name.into(),
+            sound: SoundData::new(sound),
+            volume: None,
+            emoji_id: None,
+            emoji_name: None,
+            audit_log_reason: None,
+        }
+    }
+
+    /// Set the name of the sound, replacing the current value as set in [`Self::new`].
+    ///
+    /// **Note**: Must be between 2 and 32 characters long.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    /// Set the sound file, replacing the current value as set in [`Self::new`].
+    pub fn sound(mut self, sound: &CreateAttachment) -> Self {
+        self.sound = SoundData::new(sound);
+        self
+    }
+
+    /// Set the volume of the sound, from 0 to 1. Defaults to 1.
+    pub fn volume(mut self, volume: f64) -> Self {
+        self.volume = Some(volume);
+        self
+    }
+
+    /// Set a custom emoji to show with the sound.
+    pub fn emoji_id(mut self, emoji_id: EmojiId) -> Self {
+        self.emoji_id = Some(emoji_id);
+        self
+    }
+
+    /// Set a standard emoji to show with the sound, as its unicode character.
+    pub fn emoji_name(mut self, emoji_name: impl Into<String>) -> Self {
+        self.emoji_name = Some(emoji_name.into());
+        self
+    }
+
+    /// Sets the request's audit log reason.
+    pub fn audit_log_reason(mut self, reason: &'a str) -> Self {
+        self.audit_log_reason = Some(reason);
+        self
+    }
+}
+
+#[cfg(feature = "http")]
+#[async_trait::async_trait]
+impl Builder for CreateSoundboardSound<'_> {
+    type Context<'ctx> = GuildId;
+    type Built = SoundboardSound;
+
+    /// Creates a new soundboard sound in the guild with the data set.
+    ///
+    /// **Note**: Requires the [Create Guild Expressions] permission.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, returns a [`ModelError::InvalidPermissions`] if the current user
+    /// lacks permission. Otherwise returns [`Error::Http`], as well as if invalid data is given.
+    ///
+    /// Returns a [`ModelError::InvalidSound`] if the sound is not an MP3 or Ogg file of at most
+    /// 512 KiB.
+    ///
+    /// [Create Guild Expressions]: Permissions::CREATE_GUILD_EXPRESSIONS
+    async fn execute(
+        self,
+        cache_http: impl CacheHttp,
+        ctx: Self::Context<'_>,
+    ) -> Result<Self::Built> {
+        if !self.sound.is_valid() {
+            return Err(Error::Model(ModelError::InvalidSound));
+        }
+
+        #[cfg(feature = "cache")]
+        crate::utils::user_has_guild_perms(
+            &cache_http,
+            ctx,
+            Permissions::CREATE_GUILD_EXPRESSIONS,
+        )?;
+
+        cache_http.http().create_soundboard_sound(ctx, &self, self.audit_log_reason).await
+    }
+}
+
+/// A sound file as a data URI, which remembers whether the original data is a supported sound.
+#[cfg_attr(not(feature = "http"), allow(dead_code))]
+#[derive(Clone, Debug, Serialize)]
+#[serde(transparent)]
+struct SoundData {
+    uri: String,
+    #[serde(skip)]
+    size: usize,
+    #[serde(skip)]
+    mime: Option<&'static str>,
+}
+
+impl SoundData {
+    fn new(attachment: &CreateAttachment) -> Self {
+        use base64::Engine;
+
+        let data = &attachment.data;
+        let mime = match data.as_slice() {
+            [b'I', b'D', b'3', ..] => Some("audio/mpeg"),
+            [0xFF, frame, ..] if frame & 0xE0 == 0xE0 => Some("audio/mpeg"),
+            [b'O', b'g', b'g', b'S', ..] => Some("audio/ogg"),
+            _ => None,
+        };
+        let encoded = base64::prelude::BASE64_STANDARD.encode(data);
+
+        Self {
+            uri: format!("data:{};base64,{encoded}", mime.unwrap_or("application/octet-stream")),
+            size: data.len(),
+            mime,
+        }
+    }
+
+    #[cfg(feature = "http")]
+    fn is_valid(&self) -> bool {
+        self.mime.is_some() && self.size <= SOUND_MAX_SIZE
+    }
+}
+
+#[cfg(all(test, feature = "http"))]
+mod tests {
+    use super::*;
+    use crate::json::{json, to_value};
+
+    fn sound(data: &[u8]) -> SoundData {
+        SoundData::new(&CreateAttachment::bytes(data, "sound"))
+    }
+
+    #[test]
+    fn sound_data() {
+        assert_eq!(sound(b"ID3\x04").uri, "data:audio/mpeg;base64,SUQzBA==");
+        assert_eq!(sound(b"OggS").uri, "data:audio/ogg;base64,T2dnUw==");
+        assert!(sound(b"\xff\xfb\x90\x00").is_valid());
+        assert!(!sound(b"RIFF\0\0\0\0WAVE").is_valid());
+        assert!(!sound(&[b'O', b'g', b'g', b'S'].repeat(SOUND_MAX_SIZE)).is_valid());
+
+        let builder = CreateSoundboardSound::new("quack", &CreateAttachment::bytes(*b"OggS", "a"))
+            .volume(0.5)
+            .emoji_name("🦆");
+        assert_eq!(
+            to_value(builder).unwrap(),
+            json!({
+                "name": "quack",
+                "sound": "data:audio/ogg;base64,T2dnUw==",
+                "volume": 0.5,
+                "emoji_name": "🦆",
+            })
+        );
+    }
+}
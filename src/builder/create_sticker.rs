@@ -1,5 +1,7 @@
 #[cfg(feature = "http")]
 use super::Builder;
+#[cfg(feature = "http")]
+use super::check_image;
 use super::CreateAttachment;
 #[cfg(feature = "http")]
 use crate::http::CacheHttp;
@@ -7,6 +9,12 @@ use crate::http::CacheHttp;
 use crate::internal::prelude::*;
 #[cfg(feature = "http")]
 use crate::model::prelude::*;
+#[cfg(feature = "http")]
+use crate::model::{ImageError, ImageFormat};
+
+/// The maximum size of sticker files in bytes.
+#[cfg(feature = "http")]
+const FILE_MAX_SIZE: usize = 512 * 1024;
 
 /// A builder to create a guild sticker
 ///
@@ -59,7 +67,7 @@ impl<'a> CreateSticker<'a> {
 
     /// Set the sticker file. Replaces the current value as set in [`Self::new`].
     ///
-    /// **Note**: Must be a PNG, APNG, or Lottie JSON file, max 500 KB.
+    /// **Note**: Must be a PNG, APNG, GIF, or Lottie JSON file, max 512 KiB.
     pub fn file(mut self, file: CreateAttachment) -> Self {
         self.file = file;
         self
@@ -72,6 +80,28 @@ impl<'a> CreateSticker<'a> {
     }
 }
 
+#[cfg(feature = "http")]
+impl CreateSticker<'_> {
+    fn check_file(&self) -> StdResult<(), ModelError> {
+        let data = &self.file.data;
+        let format = ImageFormat::detect(data);
+
+        // Lottie animations are JSON, of which only the size is checked.
+        let is_lottie =
+            format.is_none() && data.iter().find(|b| !b.is_ascii_whitespace()) == Some(&b'{');
+        if is_lottie && data.len() > FILE_MAX_SIZE {
+            return Err(ModelError::InvalidImage(ImageError::TooLarge {
+                size: data.len(),
+                max: FILE_MAX_SIZE,
+            }));
+        } else if is_lottie {
+            return Ok(());
+        }
+
+        check_image(format, data.len(), &[ImageFormat::Png, ImageFormat::Gif], FILE_MAX_SIZE)
+    }
+}
+
 #[cfg(feature = "http")]
 #[async_trait::async_trait]
 impl Builder for CreateSticker<'_> {
@@ -87,12 +117,17 @@ impl Builder for CreateSticker<'_> {
     /// If the `cache` is enabled, returns a [`ModelError::InvalidPermissions`] if the current user
     /// lacks permission. Otherwise returns [`Error::Http`], as well as if invalid data is given.
     ///
+    /// Returns a [`ModelError::InvalidImage`] if the file is not a PNG, APNG, GIF, or Lottie JSON
+    /// file of at most 512 KiB.
+    ///
     /// [Create Guild Expressions]: Permissions::CREATE_GUILD_EXPRESSIONS
     async fn execute(
         self,
         cache_http: impl CacheHttp,
         ctx: Self::Context<'_>,
     ) -> Result<Self::Built> {
+        self.check_file()?;
+
         #[cfg(feature = "cache")]
         crate::utils::user_has_guild_perms(
             &cache_http,
@@ -104,3 +139,26 @@ impl Builder for CreateSticker<'_> {
         cache_http.http().create_sticker(ctx, map, self.file, self.audit_log_reason).await
     }
 }
+
+#[cfg(all(test, feature = "http"))]
+mod tests {
+    use super::*;
+
+    fn sticker(data: &[u8]) -> CreateSticker<'static> {
+        CreateSticker::new("crab", CreateAttachment::bytes(data, "crab"))
+    }
+
+    #[test]
+    fn check_file() {
+        assert!(sticker(b"\x89PNG\r\n\x1a\n").check_file().is_ok());
+        assert!(sticker(b" {\"v\": \"5.5.2\"}").check_file().is_ok());
+        assert!(matches!(
+            sticker(b"\xff\xd8\xff\xe0").check_file(),
+            Err(ModelError::InvalidImage(ImageError::UnsupportedFormat(ImageFormat::Jpeg)))
+        ));
+        assert!(matches!(
+            sticker(&vec![b'{'; FILE_MAX_SIZE + 1]).check_file(),
+            Err(ModelError::InvalidImage(ImageError::TooLarge { .. }))
+        ));
+    }
+}
@@ -1,6 +1,6 @@
 #[cfg(feature = "http")]
 use super::Builder;
-use super::CreateAttachment;
+use super::{CreateAttachment, ImageData};
 #[cfg(feature = "http")]
 use crate::http::CacheHttp;
 #[cfg(feature = "http")]
@@ -14,7 +14,7 @@ use crate::model::prelude::*;
 pub struct CreateWebhook<'a> {
     name: String,
     #[serde(skip_serializing_if = "Option::is_none")]
-    avatar: Option<String>,
+    avatar: Option<ImageData>,
 
     #[serde(skip)]
     audit_log_reason: Option<&'a str>,
@@ -40,7 +40,7 @@ impl<'a> CreateWebhook<'a> {
 
     /// Set the webhook's default avatar.
     pub fn avatar(mut self, avatar: &CreateAttachment) -> Self {
-        self.avatar = Some(avatar.to_base64());
+        self.avatar = Some(ImageData::new(avatar));
         self
     }
 
@@ -64,6 +64,9 @@ impl Builder for CreateWebhook<'_> {
     /// If the provided name is less than 2 characters, returns [`ModelError::NameTooShort`]. If it
     /// is more than 100 characters, returns [`ModelError::NameTooLong`].
     ///
+    /// Returns a [`ModelError::InvalidImage`] if the avatar is a streamed attachment, see
+    /// [`CreateAttachment::url_streamed`].
+    ///
     /// Returns a [`Error::Http`] if the current user lacks permission, or if invalid data is
     /// given.
     ///
@@ -79,6 +82,9 @@ impl Builder for CreateWebhook<'_> {
         } else if self.name.len() > 100 {
             return Err(Error::Model(ModelError::NameTooLong));
         }
+        if let Some(avatar) = &self.avatar {
+            avatar.check_in_memory()?;
+        }
 
         cache_http.http().create_webhook(ctx, &self, self.audit_log_reason).await
     }
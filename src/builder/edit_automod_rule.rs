@@ -114,6 +114,23 @@ impl Default for EditAutoModRule<'_> {
     }
 }
 
+impl From<&Rule> for EditAutoModRule<'_> {
+    /// Creates a builder with every field set to the values of an existing rule, e.g. to copy it
+    /// to another guild or to edit it starting from its current state.
+    fn from(rule: &Rule) -> Self {
+        Self {
+            name: Some(rule.name.clone()),
+            event_type: rule.event_type,
+            trigger: Some(rule.trigger.clone()),
+            actions: Some(rule.actions.clone()),
+            enabled: Some(rule.enabled),
+            exempt_roles: Some(rule.exempt_roles.clone()),
+            exempt_channels: Some(rule.exempt_channels.clone()),
+            audit_log_reason: None,
+        }
+    }
+}
+
 #[cfg(feature = "http")]
 #[async_trait::async_trait]
 impl Builder for EditAutoModRule<'_> {
@@ -144,3 +161,68 @@ impl Builder for EditAutoModRule<'_> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json::{from_value, json, to_value, Value};
+
+    fn assert_round_trip(trigger_type: u8, trigger_metadata: Value) {
+        let actions = json!([
+            {"type": 1, "metadata": {"custom_message": "blocked"}},
+            {"type": 2, "metadata": {"channel_id": "30"}},
+            {"type": 3, "metadata": {"duration_seconds": 60}},
+        ]);
+        let rule: Rule = from_value(json!({
+            "id": "10",
+            "guild_id": "20",
+            "name": "rule",
+            "creator_id": "60",
+            "event_type": 1,
+            "trigger_type": trigger_type,
+            "trigger_metadata": trigger_metadata.clone(),
+            "actions": actions.clone(),
+            "enabled": true,
+            "exempt_roles": ["40"],
+            "exempt_channels": ["50"],
+        }))
+        .unwrap();
+
+        assert_eq!(
+            to_value(EditAutoModRule::from(&rule)).unwrap(),
+            json!({
+                "name": "rule",
+                "event_type": 1,
+                "trigger_type": trigger_type,
+                "trigger_metadata": trigger_metadata,
+                "actions": actions,
+                "enabled": true,
+                "exempt_roles": ["40"],
+                "exempt_channels": ["50"],
+            })
+        );
+    }
+
+    #[test]
+    fn from_keyword_rule() {
+        assert_round_trip(
+            1,
+            json!({"keyword_filter": ["foo"], "regex_patterns": ["b[a4]r"], "allow_list": ["baz"]}),
+        );
+    }
+
+    #[test]
+    fn from_spam_rule() {
+        assert_round_trip(3, json!({}));
+    }
+
+    #[test]
+    fn from_keyword_preset_rule() {
+        assert_round_trip(4, json!({"presets": [1, 3], "allow_list": ["baz"]}));
+    }
+
+    #[test]
+    fn from_mention_spam_rule() {
+        assert_round_trip(5, json!({"mention_total_limit": 5}));
+    }
+}
@@ -0,0 +1,160 @@
+#[cfg(feature = "http")]
+use super::Builder;
+use super::{CreateAttachment, ImageData};
+#[cfg(feature = "http")]
+use crate::http::CacheHttp;
+#[cfg(feature = "http")]
+use crate::internal::prelude::*;
+#[cfg(feature = "http")]
+use crate::model::prelude::*;
+#[cfg(feature = "http")]
+use crate::model::ImageFormat;
+
+/// The maximum size of avatars and banners in bytes.
+#[cfg(feature = "http")]
+const IMAGE_MAX_SIZE: usize = 10 * 1024 * 1024;
+
+/// A builder to edit the current user's member in a guild, to be used in conjunction with
+/// [`GuildId::edit_current_member`].
+///
+/// Fields which are not set are left unchanged, while deleted fields are reset to the user's
+/// global profile.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/guild#modify-current-member)
+///
+/// [`GuildId::edit_current_member`]: crate::model::id::GuildId::edit_current_member
+#[derive(Clone, Debug, Default, Serialize)]
+#[must_use]
+pub struct EditCurrentMember<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    nick: Option<Option<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    avatar: Option<Option<ImageData>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    banner: Option<Option<ImageData>>,
+
+    #[serde(skip)]
+    audit_log_reason: Option<&'a str>,
+}
+
+impl<'a> EditCurrentMember<'a> {
+    /// Equivalent to [`Self::default`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the nickname of the current user in the guild.
+    ///
+    /// **Note**: Requires the [Change Nickname] permission.
+    ///
+    /// [Change Nickname]: crate::model::permissions::Permissions::CHANGE_NICKNAME
+    pub fn nick(mut self, nick: impl Into<String>) -> Self {
+        self.nick = Some(Some(nick.into()));
+        self
+    }
+
+    /// Deletes the nickname of the current user in the guild, showing the global name again.
+    pub fn delete_nick(mut self) -> Self {
+        self.nick = Some(None);
+        self
+    }
+
+    /// Sets the guild specific avatar of the current user.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::builder::{CreateAttachment, EditCurrentMember};
+    /// # use serenity::http::Http;
+    /// # use serenity::model::id::GuildId;
+    /// #
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let http: Http = unimplemented!();
+    /// # let guild_id = GuildId::new(1);
+    /// let avatar = CreateAttachment::path("./guild_avatar.png").await?;
+    /// let builder = EditCurrentMember::new().nick("Guild Bot").avatar(&avatar);
+    /// guild_id.edit_current_member(&http, builder).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn avatar(mut self, avatar: &CreateAttachment) -> Self {
+        self.avatar = Some(Some(ImageData::new(avatar)));
+        self
+    }
+
+    /// Deletes the guild specific avatar of the current user, showing the global avatar again.
+    pub fn delete_avatar(mut self) -> Self {
+        self.avatar = Some(None);
+        self
+    }
+
+    /// Sets the guild specific banner of the current user.
+    pub fn banner(mut self, banner: &CreateAttachment) -> Self {
+        self.banner = Some(Some(ImageData::new(banner)));
+        self
+    }
+
+    /// Deletes the guild specific banner of the current user, showing the global banner again.
+    pub fn delete_banner(mut self) -> Self {
+        self.banner = Some(None);
+        self
+    }
+
+    /// Sets the request's audit log reason.
+    pub fn audit_log_reason(mut self, reason: &'a str) -> Self {
+        self.audit_log_reason = Some(reason);
+        self
+    }
+}
+
+#[cfg(feature = "http")]
+#[async_trait::async_trait]
+impl Builder for EditCurrentMember<'_> {
+    type Context<'ctx> = GuildId;
+    type Built = Member;
+
+    /// Edits the current user's member in the guild with the fields set.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidImage`] if the avatar or banner is not a PNG, JPEG, or GIF
+    /// image of at most 10 MiB.
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission, or if invalid data is given.
+    async fn execute(
+        self,
+        cache_http: impl CacheHttp,
+        ctx: Self::Context<'_>,
+    ) -> Result<Self::Built> {
+        let formats = [ImageFormat::Png, ImageFormat::Jpeg, ImageFormat::Gif];
+        for image in [&self.avatar, &self.banner].into_iter().flatten().flatten() {
+            image.check(&formats, IMAGE_MAX_SIZE)?;
+        }
+
+        cache_http.http().edit_member_me(ctx, &self, self.audit_log_reason).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json::{json, to_value};
+
+    #[test]
+    fn omitted_and_deleted_fields() {
+        assert_eq!(to_value(EditCurrentMember::new()).unwrap(), json!({}));
+
+        let builder = EditCurrentMember::new().delete_nick().delete_avatar().delete_banner();
+        assert_eq!(
+            to_value(builder).unwrap(),
+            json!({"nick": null, "avatar": null, "banner": null})
+        );
+
+        let avatar = CreateAttachment::bytes(vec![1, 2, 3], "avatar.png");
+        let builder = EditCurrentMember::new().nick("Bot").avatar(&avatar).audit_log_reason("why");
+        assert_eq!(
+            to_value(builder).unwrap(),
+            json!({"nick": "Bot", "avatar": avatar.to_base64().unwrap()})
+        );
+    }
+}
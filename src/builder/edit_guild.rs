@@ -1,6 +1,6 @@
 #[cfg(feature = "http")]
 use super::Builder;
-use super::CreateAttachment;
+use super::{CreateAttachment, ImageData};
 #[cfg(feature = "http")]
 use crate::http::CacheHttp;
 #[cfg(feature = "http")]
@@ -27,7 +27,7 @@ pub struct EditGuild<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     afk_timeout: Option<AfkTimeout>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    icon: Option<Option<String>>,
+    icon: Option<Option<ImageData>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     owner_id: Option<UserId>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -101,7 +101,7 @@ impl<'a> EditGuild<'a> {
     /// # }
     /// ```
     pub fn icon(mut self, icon: Option<&CreateAttachment>) -> Self {
-        self.icon = Some(icon.map(CreateAttachment::to_base64));
+        self.icon = Some(icon.map(ImageData::new));
         self
     }
 
@@ -189,7 +189,8 @@ impl<'a> EditGuild<'a> {
         self
     }
 
-    /// Set the channel ID where welcome messages and boost events will be posted.
+    /// Set the channel ID where welcome messages and boost events will be posted. Pass [`None`] to
+    /// disable these messages.
     pub fn system_channel_id(mut self, channel_id: Option<ChannelId>) -> Self {
         self.system_channel_id = Some(channel_id);
         self
@@ -213,9 +214,12 @@ impl<'a> EditGuild<'a> {
 
     /// Set the preferred locale used in Server Discovery and update messages from Discord.
     ///
-    /// If this is not set, the locale will default to "en-US";
+    /// The locale must be one of the [locales supported by Discord], e.g. `"en-US"` or `"de"`.
+    /// Pass [`None`] to reset it to the default of `"en-US"`.
     ///
     /// **Note**: This feature is for Community guilds only.
+    ///
+    /// [locales supported by Discord]: https://discord.com/developers/docs/reference#locales
     pub fn preferred_locale(mut self, locale: Option<String>) -> Self {
         self.preferred_locale = Some(locale);
         self
@@ -305,7 +309,7 @@ impl<'a> EditGuild<'a> {
         self
     }
 
-    /// Whether the guild's boost progress bar should be enabled
+    /// Whether the guild's boost progress bar should be enabled.
     pub fn premium_progress_bar_enabled(mut self, premium_progress_bar_enabled: bool) -> Self {
         self.premium_progress_bar_enabled = Some(premium_progress_bar_enabled);
         self
@@ -327,15 +331,68 @@ impl Builder for EditGuild<'_> {
     /// If the `cache` is enabled, returns a [`ModelError::InvalidPermissions`] if the current user
     /// lacks permission. Otherwise returns [`Error::Http`], as well as if invalid data is given.
     ///
+    /// Returns a [`ModelError::InvalidImage`] if the icon is a streamed attachment, see
+    /// [`CreateAttachment::url_streamed`].
+    ///
     /// [Manage Guild]: Permissions::MANAGE_GUILD
     async fn execute(
         self,
         cache_http: impl CacheHttp,
         ctx: Self::Context<'_>,
     ) -> Result<Self::Built> {
+        if let Some(Some(icon)) = &self.icon {
+            icon.check_in_memory()?;
+        }
+
         #[cfg(feature = "cache")]
         crate::utils::user_has_guild_perms(&cache_http, ctx, Permissions::MANAGE_GUILD)?;
 
         cache_http.http().edit_guild(ctx, &self, self.audit_log_reason).await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json::{json, to_value};
+
+    #[test]
+    fn omitted_fields() {
+        assert_eq!(to_value(EditGuild::new()).unwrap(), json!({}));
+    }
+
+    #[test]
+    fn channels() {
+        let builder = EditGuild::new()
+            .afk_channel(Some(ChannelId::new(1)))
+            .system_channel_id(Some(ChannelId::new(2)));
+        assert_eq!(
+            to_value(builder).unwrap(),
+            json!({"afk_channel_id": "1", "system_channel_id": "2"})
+        );
+
+        let builder = EditGuild::new().afk_channel(None).system_channel_id(None);
+        assert_eq!(
+            to_value(builder).unwrap(),
+            json!({"afk_channel_id": null, "system_channel_id": null})
+        );
+    }
+
+    #[test]
+    fn preferred_locale() {
+        let builder = EditGuild::new().preferred_locale(Some("de".into()));
+        assert_eq!(to_value(builder).unwrap(), json!({"preferred_locale": "de"}));
+
+        let builder = EditGuild::new().preferred_locale(None);
+        assert_eq!(to_value(builder).unwrap(), json!({"preferred_locale": null}));
+    }
+
+    #[test]
+    fn premium_progress_bar() {
+        let builder = EditGuild::new().premium_progress_bar_enabled(true);
+        assert_eq!(to_value(builder).unwrap(), json!({"premium_progress_bar_enabled": true}));
+
+        let builder = EditGuild::new().premium_progress_bar_enabled(false);
+        assert_eq!(to_value(builder).unwrap(), json!({"premium_progress_bar_enabled": false}));
+    }
+}
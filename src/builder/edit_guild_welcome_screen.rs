@@ -59,6 +59,17 @@ impl<'a> EditGuildWelcomeScreen<'a> {
     }
 }
 
+#[cfg(feature = "http")]
+impl EditGuildWelcomeScreen<'_> {
+    fn check_length(&self) -> Result<()> {
+        if self.welcome_channels.len() > crate::constants::WELCOME_CHANNEL_MAX_COUNT {
+            return Err(Error::Model(ModelError::WelcomeChannelAmount));
+        }
+
+        Ok(())
+    }
+}
+
 #[cfg(feature = "http")]
 #[async_trait::async_trait]
 impl Builder for EditGuildWelcomeScreen<'_> {
@@ -71,7 +82,8 @@ impl Builder for EditGuildWelcomeScreen<'_> {
     ///
     /// # Errors
     ///
-    /// Returns [`Error::Http`] if the current user lacks permission.
+    /// Returns [`Error::Http`] if the current user lacks permission. Returns a
+    /// [`ModelError::WelcomeChannelAmount`] if more than 5 welcome channels were set.
     ///
     /// [Manage Guild]: Permissions::MANAGE_GUILD
     async fn execute(
@@ -79,6 +91,8 @@ impl Builder for EditGuildWelcomeScreen<'_> {
         cache_http: impl CacheHttp,
         ctx: Self::Context<'_>,
     ) -> Result<Self::Built> {
+        self.check_length()?;
+
         cache_http.http().edit_guild_welcome_screen(ctx, &self, self.audit_log_reason).await
     }
 }
@@ -14,6 +14,12 @@ use crate::http::CacheHttp;
 use crate::internal::prelude::*;
 use crate::model::prelude::*;
 
+/// Polls cannot be edited, so unlike [`CreateInteractionResponseFollowup`], this builder does not
+/// accept a [`CreatePoll`].
+///
+/// [`CreateInteractionResponseFollowup`]: super::CreateInteractionResponseFollowup
+/// [`CreatePoll`]: super::CreatePoll
+///
 /// [Discord docs](https://discord.com/developers/docs/interactions/receiving-and-responding#edit-original-interaction-response)
 #[derive(Clone, Debug, Default, Serialize)]
 #[must_use]
@@ -105,7 +111,10 @@ impl Builder for EditInteractionResponse {
     type Context<'ctx> = &'ctx str;
     type Built = Message;
 
-    /// Edits the initial interaction response. Does not work for ephemeral messages.
+    /// Edits the initial interaction response.
+    ///
+    /// The message flags can't be changed by editing, so an ephemeral response, e.g. one deferred
+    /// with [`CommandInteraction::defer_ephemeral`], stays ephemeral.
     ///
     /// The `application_id` used will usually be the bot's [`UserId`], except if the bot is very
     /// old.
@@ -130,3 +139,16 @@ impl Builder for EditInteractionResponse {
         cache_http.http().edit_original_interaction_response(ctx, &self, files).await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json::{json, to_value};
+
+    #[test]
+    fn keeps_flags() {
+        // Flags are not part of an edit, which keeps a deferred ephemeral response ephemeral.
+        let builder = EditInteractionResponse::new().content("done");
+        assert_eq!(to_value(builder).unwrap(), json!({"content": "done"}));
+    }
+}
@@ -123,6 +123,10 @@ impl EditMessage {
     /// Suppress or unsuppress embeds in the message, this includes those generated by Discord
     /// themselves.
     ///
+    /// **Note**: This replaces all flags of the message, so any other flags that were set on it are
+    /// cleared. Use [`Self::flags`] with the message's current flags to preserve them, or
+    /// [`ChannelId::suppress_embeds`] to do so without a builder.
+    ///
     /// If this is sent directly after posting the message, there is a small chance Discord hasn't
     /// yet fully parsed the contained links and generated the embeds, so this embed suppression
     /// request has no effect. To mitigate this, you can defer the embed suppression until the
@@ -176,6 +180,9 @@ impl EditMessage {
     super::button_and_select_menu_convenience_methods!(self.components);
 
     /// Sets the flags for the message.
+    ///
+    /// Discord expects all of the message's flags to be sent, not only the changed ones. Only
+    /// [`MessageFlags::SUPPRESS_EMBEDS`] can currently be changed.
     pub fn flags(mut self, flags: MessageFlags) -> Self {
         self.flags = Some(flags);
         self
@@ -230,8 +237,8 @@ impl Builder for EditMessage {
     /// 6000 code points.
     ///
     /// **Note**: Requires that the current user be the author of the message. Other users can only
-    /// call [`Self::suppress_embeds`], but additionally require the [Manage Messages] permission
-    /// to do so.
+    /// call [`Self::suppress_embeds`] or [`Self::flags`], but additionally require the
+    /// [Manage Messages] permission to do so.
     ///
     /// **Note**: If any embeds or attachments are set, they will overwrite the existing contents
     /// of the message, deleting existing embeds and attachments. Preserving them requires calling
@@ -257,7 +264,11 @@ impl Builder for EditMessage {
         #[cfg(feature = "cache")]
         if let Some(user_id) = ctx.2 {
             if let Some(cache) = cache_http.cache() {
-                let reference_builder = EditMessage::new().suppress_embeds(true);
+                // Other users may only change the flags of the message.
+                let reference_builder = EditMessage {
+                    flags: self.flags,
+                    ..Default::default()
+                };
 
                 if user_id != cache.current_user().id && self != reference_builder {
                     return Err(Error::Model(ModelError::InvalidUser));
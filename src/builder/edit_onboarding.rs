@@ -0,0 +1,405 @@
+#[cfg(feature = "http")]
+use super::Builder;
+#[cfg(feature = "http")]
+use crate::http::CacheHttp;
+#[cfg(feature = "http")]
+use crate::internal::prelude::*;
+use crate::model::prelude::*;
+
+/// A builder to edit the onboarding flow of a guild.
+///
+/// Discord replaces the whole onboarding configuration with the given one, so prompts that are
+/// left out will be deleted. An existing [`Onboarding`] can be converted into builders with
+/// [`EditOnboarding::from`] to edit or replicate it.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/guild#modify-guild-onboarding)
+#[derive(Clone, Debug, Default, Serialize)]
+#[must_use]
+pub struct EditOnboarding<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    prompts: Option<Vec<CreateOnboardingPrompt>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default_channel_ids: Option<Vec<ChannelId>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mode: Option<OnboardingMode>,
+
+    #[serde(skip)]
+    audit_log_reason: Option<&'a str>,
+}
+
+impl<'a> EditOnboarding<'a> {
+    /// Equivalent to [`Self::default`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The prompts shown during onboarding and in customize community.
+    pub fn prompts(mut self, prompts: Vec<CreateOnboardingPrompt>) -> Self {
+        self.prompts = Some(prompts);
+        self
+    }
+
+    /// Adds a prompt to the ones shown during onboarding.
+    pub fn add_prompt(mut self, prompt: CreateOnboardingPrompt) -> Self {
+        self.prompts.get_or_insert_with(Vec::new).push(prompt);
+        self
+    }
+
+    /// The channels that members get opted into automatically.
+    pub fn default_channel_ids(
+        mut self,
+        channel_ids: impl IntoIterator<Item = impl Into<ChannelId>>,
+    ) -> Self {
+        self.default_channel_ids = Some(channel_ids.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Whether onboarding is enabled or not.
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = Some(enabled);
+        self
+    }
+
+    /// The criteria used to determine whether onboarding is configured correctly.
+    pub fn mode(mut self, mode: OnboardingMode) -> Self {
+        self.mode = Some(mode);
+        self
+    }
+
+    /// Sets the request's audit log reason.
+    pub fn audit_log_reason(mut self, reason: &'a str) -> Self {
+        self.audit_log_reason = Some(reason);
+        self
+    }
+
+    /// Checks that enough channels are set to enable onboarding in the given mode.
+    ///
+    /// Only the fields that are set on the builder can be checked, so nothing is checked unless
+    /// both [`Self::enabled`] and [`Self::mode`] are set.
+    #[cfg(feature = "http")]
+    fn check_default_channels(&self) -> Result<()> {
+        let (Some(true), Some(mode)) = (self.enabled, self.mode) else {
+            return Ok(());
+        };
+
+        let mut channel_ids = match &self.default_channel_ids {
+            Some(channel_ids) => channel_ids.clone(),
+            None => return Ok(()),
+        };
+        match mode {
+            OnboardingMode::Default => {},
+            OnboardingMode::Advanced => match &self.prompts {
+                Some(prompts) => channel_ids.extend(
+                    prompts.iter().flat_map(|p| &p.options).flat_map(|o| &o.channel_ids),
+                ),
+                None => return Ok(()),
+            },
+            OnboardingMode::Unknown(_) => return Ok(()),
+        }
+        channel_ids.sort_unstable();
+        channel_ids.dedup();
+
+        if channel_ids.len() < crate::constants::ONBOARDING_DEFAULT_CHANNEL_MIN_COUNT {
+            return Err(Error::Model(ModelError::OnboardingDefaultChannelAmount));
+        }
+
+        Ok(())
+    }
+}
+
+impl From<Onboarding> for EditOnboarding<'_> {
+    fn from(onboarding: Onboarding) -> Self {
+        Self {
+            prompts: Some(onboarding.prompts.into_iter().map(Into::into).collect()),
+            default_channel_ids: Some(onboarding.default_channel_ids),
+            enabled: Some(onboarding.enabled),
+            mode: Some(onboarding.mode),
+            audit_log_reason: None,
+        }
+    }
+}
+
+#[cfg(feature = "http")]
+#[async_trait::async_trait]
+impl Builder for EditOnboarding<'_> {
+    type Context<'ctx> = GuildId;
+    type Built = Onboarding;
+
+    /// Edits the guild's onboarding flow.
+    ///
+    /// **Note**: Requires the [Manage Guild] and [Manage Roles] permissions.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, returns a [`ModelError::InvalidPermissions`] if the current user
+    /// lacks permission. Returns a [`ModelError::OnboardingDefaultChannelAmount`] if onboarding
+    /// is enabled with fewer than 7 channels. Otherwise returns [`Error::Http`].
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    /// [Manage Roles]: Permissions::MANAGE_ROLES
+    async fn execute(
+        self,
+        cache_http: impl CacheHttp,
+        ctx: Self::Context<'_>,
+    ) -> Result<Self::Built> {
+        self.check_default_channels()?;
+
+        #[cfg(feature = "cache")]
+        crate::utils::user_has_guild_perms(
+            &cache_http,
+            ctx,
+            Permissions::MANAGE_GUILD | Permissions::MANAGE_ROLES,
+        )?;
+
+        cache_http.http().edit_guild_onboarding(ctx, &self, self.audit_log_reason).await
+    }
+}
+
+/// A builder for creating an [`OnboardingPrompt`].
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/guild#guild-onboarding-object-onboarding-prompt-structure)
+#[derive(Clone, Debug, Serialize)]
+#[must_use]
+pub struct CreateOnboardingPrompt {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<OnboardingPromptId>,
+    #[serde(rename = "type")]
+    kind: OnboardingPromptType,
+    title: String,
+    options: Vec<CreatePromptOption>,
+    single_select: bool,
+    required: bool,
+    in_onboarding: bool,
+}
+
+impl CreateOnboardingPrompt {
+    pub fn new(kind: OnboardingPromptType, title: impl Into<String>) -> Self {
+        Self {
+            id: None,
+            kind,
+            title: title.into(),
+            options: Vec::new(),
+            single_select: false,
+            required: false,
+            in_onboarding: true,
+        }
+    }
+
+    /// The Id of an existing prompt to edit.
+    pub fn id(mut self, id: impl Into<OnboardingPromptId>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// The options available within the prompt.
+    pub fn options(mut self, options: Vec<CreatePromptOption>) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Adds an option to the prompt.
+    pub fn add_option(mut self, option: CreatePromptOption) -> Self {
+        self.options.push(option);
+        self
+    }
+
+    /// Whether users are limited to selecting one option for the prompt.
+    pub fn single_select(mut self, single_select: bool) -> Self {
+        self.single_select = single_select;
+        self
+    }
+
+    /// Whether the prompt is required before a user completes the onboarding flow.
+    pub fn required(mut self, required: bool) -> Self {
+        self.required = required;
+        self
+    }
+
+    /// Whether the prompt is present in the onboarding flow. If `false`, the prompt will only
+    /// appear in the Channels & Roles tab.
+    pub fn in_onboarding(mut self, in_onboarding: bool) -> Self {
+        self.in_onboarding = in_onboarding;
+        self
+    }
+}
+
+impl From<OnboardingPrompt> for CreateOnboardingPrompt {
+    fn from(prompt: OnboardingPrompt) -> Self {
+        Self {
+            id: Some(prompt.id),
+            kind: prompt.kind,
+            title: prompt.title,
+            options: prompt.options.into_iter().map(Into::into).collect(),
+            single_select: prompt.single_select,
+            required: prompt.required,
+            in_onboarding: prompt.in_onboarding,
+        }
+    }
+}
+
+/// A builder for creating a [`PromptOption`].
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/guild#guild-onboarding-object-prompt-option-structure)
+#[derive(Clone, Debug, Serialize)]
+#[must_use]
+pub struct CreatePromptOption {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<PromptOptionId>,
+    channel_ids: Vec<ChannelId>,
+    role_ids: Vec<RoleId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    emoji_id: Option<EmojiId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    emoji_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    emoji_animated: Option<bool>,
+    title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+}
+
+impl CreatePromptOption {
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            id: None,
+            channel_ids: Vec::new(),
+            role_ids: Vec::new(),
+            emoji_id: None,
+            emoji_name: None,
+            emoji_animated: None,
+            title: title.into(),
+            description: None,
+        }
+    }
+
+    /// The Id of an existing option to edit.
+    pub fn id(mut self, id: impl Into<PromptOptionId>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// The channels a member is added to when selecting this option.
+    pub fn channel_ids(
+        mut self,
+        channel_ids: impl IntoIterator<Item = impl Into<ChannelId>>,
+    ) -> Self {
+        self.channel_ids = channel_ids.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// The roles assigned to a member when selecting this option.
+    pub fn role_ids(mut self, role_ids: impl IntoIterator<Item = impl Into<RoleId>>) -> Self {
+        self.role_ids = role_ids.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// The emoji shown next to the option.
+    pub fn emoji(mut self, emoji: impl Into<ReactionType>) -> Self {
+        match emoji.into() {
+            ReactionType::Custom {
+                animated,
+                id,
+                name,
+            } => {
+                self.emoji_id = Some(id);
+                self.emoji_name = name;
+                self.emoji_animated = Some(animated);
+            },
+            ReactionType::Unicode(unicode_emoji) => {
+                self.emoji_id = None;
+                self.emoji_name = Some(unicode_emoji);
+                self.emoji_animated = None;
+            },
+        }
+        self
+    }
+
+    /// The description of the option.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+}
+
+impl From<PromptOption> for CreatePromptOption {
+    fn from(option: PromptOption) -> Self {
+        let mut builder = Self {
+            id: Some(option.id),
+            channel_ids: option.channel_ids,
+            role_ids: option.role_ids,
+            emoji_id: None,
+            emoji_name: None,
+            emoji_animated: None,
+            title: option.title,
+            description: option.description,
+        };
+        if let Some(emoji) = option.emoji {
+            builder = builder.emoji(emoji);
+        }
+        builder
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json::{json, to_value};
+
+    #[test]
+    fn prompt_payload() {
+        let option = CreatePromptOption::new("Rust")
+            .channel_ids([ChannelId::new(1)])
+            .emoji(ReactionType::Unicode("🦀".into()));
+        let prompt = CreateOnboardingPrompt::new(OnboardingPromptType::Dropdown, "Pick a language")
+            .single_select(true)
+            .add_option(option);
+
+        assert_eq!(
+            to_value(prompt).unwrap(),
+            json!({
+                "type": 1,
+                "title": "Pick a language",
+                "single_select": true,
+                "required": false,
+                "in_onboarding": true,
+                "options": [{
+                    "channel_ids": ["1"],
+                    "role_ids": [],
+                    "emoji_name": "🦀",
+                    "title": "Rust",
+                }],
+            })
+        );
+    }
+
+    #[cfg(feature = "http")]
+    #[test]
+    fn default_channel_amount() {
+        let channels = |ids: std::ops::Range<u64>| ids.map(ChannelId::new).collect::<Vec<_>>();
+        let too_few = |builder: EditOnboarding<'_>| {
+            matches!(
+                builder.check_default_channels(),
+                Err(Error::Model(ModelError::OnboardingDefaultChannelAmount))
+            )
+        };
+
+        let builder = EditOnboarding::new().enabled(true).mode(OnboardingMode::Default);
+        assert!(too_few(builder.clone().default_channel_ids(channels(1..7))));
+        assert!(!too_few(builder.clone().default_channel_ids(channels(1..8))));
+        assert!(!too_few(builder.default_channel_ids(channels(1..7)).enabled(false)));
+
+        let builder = EditOnboarding::new()
+            .enabled(true)
+            .mode(OnboardingMode::Advanced)
+            .default_channel_ids(channels(1..5));
+        let prompt = |ids| {
+            let option = CreatePromptOption::new("Games").channel_ids(channels(ids));
+            CreateOnboardingPrompt::new(OnboardingPromptType::MultipleChoice, "Topics")
+                .add_option(option)
+        };
+        assert!(too_few(builder.clone().add_prompt(prompt(3..6))));
+        assert!(!too_few(builder.add_prompt(prompt(3..8))));
+    }
+}
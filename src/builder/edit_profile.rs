@@ -1,12 +1,18 @@
 #[cfg(feature = "http")]
 use super::Builder;
-use super::CreateAttachment;
+use super::{CreateAttachment, ImageData};
 #[cfg(feature = "http")]
 use crate::http::CacheHttp;
 #[cfg(feature = "http")]
 use crate::internal::prelude::*;
 #[cfg(feature = "http")]
 use crate::model::user::CurrentUser;
+#[cfg(feature = "http")]
+use crate::model::ImageFormat;
+
+/// The maximum size of avatars and banners in bytes.
+#[cfg(feature = "http")]
+const IMAGE_MAX_SIZE: usize = 10 * 1024 * 1024;
 
 /// A builder to edit the current user's settings, to be used in conjunction with
 /// [`CurrentUser::edit`].
@@ -18,9 +24,9 @@ pub struct EditProfile {
     #[serde(skip_serializing_if = "Option::is_none")]
     username: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    avatar: Option<Option<String>>,
+    avatar: Option<Option<ImageData>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    banner: Option<Option<String>>,
+    banner: Option<Option<ImageData>>,
 }
 
 impl EditProfile {
@@ -47,7 +53,7 @@ impl EditProfile {
     /// # }
     /// ```
     pub fn avatar(mut self, avatar: &CreateAttachment) -> Self {
-        self.avatar = Some(Some(avatar.to_base64()));
+        self.avatar = Some(Some(ImageData::new(avatar)));
         self
     }
 
@@ -69,7 +75,7 @@ impl EditProfile {
 
     /// Sets the banner of the current user.
     pub fn banner(mut self, banner: &CreateAttachment) -> Self {
-        self.banner = Some(Some(banner.to_base64()));
+        self.banner = Some(Some(ImageData::new(banner)));
         self
     }
 
@@ -90,6 +96,9 @@ impl Builder for EditProfile {
     ///
     /// # Errors
     ///
+    /// Returns a [`ModelError::InvalidImage`] if the avatar or banner is not a PNG, JPEG, or GIF
+    /// image of at most 10 MiB.
+    ///
     /// Returns an [`Error::Http`] if an invalid value is set. May also return an [`Error::Json`]
     /// if there is an error in deserializing the API response.
     async fn execute(
@@ -97,6 +106,11 @@ impl Builder for EditProfile {
         cache_http: impl CacheHttp,
         _ctx: Self::Context<'_>,
     ) -> Result<Self::Built> {
+        let formats = [ImageFormat::Png, ImageFormat::Jpeg, ImageFormat::Gif];
+        for image in [&self.avatar, &self.banner].into_iter().flatten().flatten() {
+            image.check(&formats, IMAGE_MAX_SIZE)?;
+        }
+
         cache_http.http().edit_profile(&self).await
     }
 }
@@ -1,11 +1,17 @@
 #[cfg(feature = "http")]
 use super::Builder;
-use super::CreateAttachment;
+use super::{CreateAttachment, ImageData};
 #[cfg(feature = "http")]
-use crate::http::CacheHttp;
+use crate::http::{CacheHttp, HttpError};
 #[cfg(feature = "http")]
 use crate::internal::prelude::*;
 use crate::model::prelude::*;
+#[cfg(feature = "http")]
+use crate::model::ImageFormat;
+
+/// The maximum size of role icons in bytes.
+#[cfg(feature = "http")]
+const ICON_MAX_SIZE: usize = 256 * 1024;
 
 /// A builder to create or edit a [`Role`] for use via a number of model methods.
 ///
@@ -56,7 +62,7 @@ pub struct EditRole<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     hoist: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    icon: Option<Option<String>>,
+    icon: Option<Option<ImageData>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     unicode_emoji: Option<Option<String>>,
 
@@ -129,17 +135,58 @@ impl<'a> EditRole<'a> {
         self
     }
 
-    /// Set the role icon to a unicode emoji.
+    /// Set the role icon to a unicode emoji, or remove it if [`None`].
+    ///
+    /// A role can only have either a unicode emoji or an [image icon](Self::icon), so setting an
+    /// emoji also removes the image icon.
+    ///
+    /// **Note**: The guild must have the `ROLE_ICONS` feature, otherwise a
+    /// [`ModelError::MissingGuildFeature`] is returned. If the guild is cached, this is checked
+    /// before sending the request.
     pub fn unicode_emoji(mut self, unicode_emoji: Option<String>) -> Self {
+        if unicode_emoji.is_some() {
+            self.icon = Some(None);
+        }
         self.unicode_emoji = Some(unicode_emoji);
-        self.icon = Some(None);
         self
     }
 
-    /// Set the role icon to a custom image.
+    /// Set the role icon to a custom image, or remove it if [`None`].
+    ///
+    /// The image is uploaded as a base64 data URI, like avatars. A role can only have either an
+    /// image icon or a [unicode emoji](Self::unicode_emoji), so setting an image also removes the
+    /// unicode emoji.
+    ///
+    /// **Note**: The guild must have the `ROLE_ICONS` feature. See [`Self::unicode_emoji`].
+    ///
+    /// # Examples
+    ///
+    /// Upload a role icon and show it in an embed:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::builder::{CreateAttachment, CreateEmbed, EditRole};
+    /// # use serenity::http::Http;
+    /// # use serenity::model::id::{GuildId, RoleId};
+    /// #
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let http: Http = unimplemented!();
+    /// # let guild_id: GuildId = unimplemented!();
+    /// # let role_id: RoleId = unimplemented!();
+    /// let icon = CreateAttachment::path("./role_icon.png").await?;
+    /// let role = guild_id.edit_role(&http, role_id, EditRole::new().icon(Some(&icon))).await?;
+    ///
+    /// let mut embed = CreateEmbed::new().title(&role.name);
+    /// if let Some(url) = role.icon_url() {
+    ///     embed = embed.thumbnail(url);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
     pub fn icon(mut self, icon: Option<&CreateAttachment>) -> Self {
-        self.icon = Some(icon.map(CreateAttachment::to_base64));
-        self.unicode_emoji = Some(None);
+        if icon.is_some() {
+            self.unicode_emoji = Some(None);
+        }
+        self.icon = Some(icon.map(ImageData::new));
         self
     }
 
@@ -148,6 +195,39 @@ impl<'a> EditRole<'a> {
         self.audit_log_reason = Some(reason);
         self
     }
+
+    /// Whether this builder sets an image or unicode emoji role icon.
+    #[cfg(feature = "http")]
+    fn sets_icon(&self) -> bool {
+        matches!(self.icon, Some(Some(_))) || matches!(self.unicode_emoji, Some(Some(_)))
+    }
+
+    /// Checks that a guild with the given features supports the role icon set by this builder, if
+    /// any.
+    #[cfg(feature = "cache")]
+    fn check_icon_feature(&self, features: &[String]) -> StdResult<(), ModelError> {
+        if self.sets_icon() && !features.iter().any(|f| f == "ROLE_ICONS") {
+            return Err(ModelError::MissingGuildFeature("ROLE_ICONS"));
+        }
+        Ok(())
+    }
+
+    /// Turns the error Discord returns when a role icon is set in a guild without the
+    /// `ROLE_ICONS` feature into a [`ModelError::MissingGuildFeature`].
+    #[cfg(feature = "http")]
+    fn icon_feature_error(&self, why: Error) -> Error {
+        // "This server needs more boosts to perform this action"
+        const NEEDS_MORE_BOOSTS: isize = 50101;
+
+        match why {
+            Error::Http(HttpError::UnsuccessfulRequest(response))
+                if self.sets_icon() && response.error.code == NEEDS_MORE_BOOSTS =>
+            {
+                Error::Model(ModelError::MissingGuildFeature("ROLE_ICONS"))
+            },
+            why => why,
+        }
+    }
 }
 
 #[cfg(feature = "http")]
@@ -165,6 +245,12 @@ impl Builder for EditRole<'_> {
     /// If the `cache` is enabled, returns a [`ModelError::InvalidPermissions`] if the current user
     /// lacks permission. Otherwise returns [`Error::Http`], as well as if invalid data is given.
     ///
+    /// Returns a [`ModelError::MissingGuildFeature`] if a role icon is set but the guild lacks the
+    /// `ROLE_ICONS` feature.
+    ///
+    /// Returns a [`ModelError::InvalidImage`] if the icon is not a PNG or JPEG image of at most
+    /// 256 KiB.
+    ///
     /// [Manage Roles]: Permissions::MANAGE_ROLES
     async fn execute(
         self,
@@ -173,16 +259,25 @@ impl Builder for EditRole<'_> {
     ) -> Result<Self::Built> {
         let (guild_id, role_id) = ctx;
 
+        if let Some(Some(icon)) = &self.icon {
+            icon.check(&[ImageFormat::Png, ImageFormat::Jpeg], ICON_MAX_SIZE)?;
+        }
+
         #[cfg(feature = "cache")]
-        crate::utils::user_has_guild_perms(&cache_http, guild_id, Permissions::MANAGE_ROLES)?;
+        {
+            crate::utils::user_has_guild_perms(&cache_http, guild_id, Permissions::MANAGE_ROLES)?;
+
+            if let Some(guild) = cache_http.cache().and_then(|c| c.guild(guild_id)) {
+                self.check_icon_feature(&guild.features)?;
+            }
+        }
 
         let http = cache_http.http();
         let role = match role_id {
-            Some(role_id) => {
-                http.edit_role(guild_id, role_id, &self, self.audit_log_reason).await?
-            },
-            None => http.create_role(guild_id, &self, self.audit_log_reason).await?,
+            Some(role_id) => http.edit_role(guild_id, role_id, &self, self.audit_log_reason).await,
+            None => http.create_role(guild_id, &self, self.audit_log_reason).await,
         };
+        let role = role.map_err(|why| self.icon_feature_error(why))?;
 
         if let Some(position) = self.position {
             http.edit_role_position(guild_id, role.id, position, self.audit_log_reason).await?;
@@ -190,3 +285,77 @@ impl Builder for EditRole<'_> {
         Ok(role)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json::{json, to_value};
+
+    #[test]
+    fn icon_and_unicode_emoji_are_exclusive() {
+        let icon = CreateAttachment::bytes(vec![1, 2, 3], "icon.png");
+
+        let builder = EditRole::new().unicode_emoji(Some("🦀".into())).icon(Some(&icon));
+        assert_eq!(
+            to_value(builder).unwrap(),
+            json!({"icon": "data:image/png;base64,AQID", "unicode_emoji": null})
+        );
+
+        let builder = EditRole::new().icon(Some(&icon)).unicode_emoji(Some("🦀".into()));
+        assert_eq!(to_value(builder).unwrap(), json!({"icon": null, "unicode_emoji": "🦀"}));
+
+        // Removing one of them leaves the other untouched.
+        let builder = EditRole::new().unicode_emoji(Some("🦀".into())).icon(None);
+        assert_eq!(to_value(builder).unwrap(), json!({"icon": null, "unicode_emoji": "🦀"}));
+    }
+
+    #[test]
+    #[cfg(feature = "cache")]
+    fn icon_requires_role_icons_feature() {
+        let icon = CreateAttachment::bytes(vec![1, 2, 3], "icon.png");
+        let features = vec!["ROLE_ICONS".to_string()];
+
+        let builders =
+            [EditRole::new().icon(Some(&icon)), EditRole::new().unicode_emoji(Some("🦀".into()))];
+        for builder in builders {
+            assert!(builder.check_icon_feature(&features).is_ok());
+            assert!(matches!(
+                builder.check_icon_feature(&[]),
+                Err(ModelError::MissingGuildFeature("ROLE_ICONS"))
+            ));
+        }
+
+        // Removing an icon or editing other fields does not require the feature.
+        assert!(EditRole::new().icon(None).check_icon_feature(&[]).is_ok());
+        assert!(EditRole::new().name("role").check_icon_feature(&[]).is_ok());
+    }
+
+    #[test]
+    #[cfg(feature = "http")]
+    fn icon_feature_error() {
+        use reqwest::{Method, StatusCode};
+
+        use crate::http::{DiscordJsonError, ErrorResponse};
+
+        fn http_error(code: isize) -> Error {
+            Error::Http(HttpError::UnsuccessfulRequest(ErrorResponse {
+                status_code: StatusCode::BAD_REQUEST,
+                url: String::from("https://discord.com/api/v10/guilds/1/roles/2"),
+                method: Method::PATCH,
+                error: DiscordJsonError {
+                    code,
+                    message: String::new(),
+                    errors: vec![],
+                },
+            }))
+        }
+
+        let builder = EditRole::new().unicode_emoji(Some("🦀".into()));
+        assert!(matches!(
+            builder.icon_feature_error(http_error(50101)),
+            Error::Model(ModelError::MissingGuildFeature("ROLE_ICONS"))
+        ));
+        assert!(matches!(builder.icon_feature_error(http_error(50013)), Error::Http(_)));
+        assert!(matches!(EditRole::new().icon_feature_error(http_error(50101)), Error::Http(_)));
+    }
+}
@@ -1,6 +1,6 @@
 #[cfg(feature = "http")]
 use super::Builder;
-use super::CreateAttachment;
+use super::{CreateAttachment, ImageData};
 #[cfg(feature = "http")]
 use crate::http::CacheHttp;
 #[cfg(feature = "http")]
@@ -30,7 +30,7 @@ pub struct EditScheduledEvent<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     status: Option<ScheduledEventStatus>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    image: Option<String>,
+    image: Option<ImageData>,
 
     #[serde(skip)]
     audit_log_reason: Option<&'a str>,
@@ -155,7 +155,7 @@ impl<'a> EditScheduledEvent<'a> {
 
     /// Sets the cover image for the scheduled event.
     pub fn image(mut self, image: &CreateAttachment) -> Self {
-        self.image = Some(image.to_base64());
+        self.image = Some(ImageData::new(image));
         self
     }
 
@@ -182,6 +182,9 @@ impl Builder for EditScheduledEvent<'_> {
     /// If the `cache` is enabled, returns a [`ModelError::InvalidPermissions`] if the current user
     /// lacks permission. Otherwise returns [`Error::Http`], as well as if invalid data is given.
     ///
+    /// Returns a [`ModelError::InvalidImage`] if the cover image is not a PNG, JPEG, or GIF image
+    /// of at most 10 MiB.
+    ///
     /// [Create Events]: Permissions::CREATE_EVENTS
     /// [Manage Events]: Permissions::MANAGE_EVENTS
     async fn execute(
@@ -189,6 +192,10 @@ impl Builder for EditScheduledEvent<'_> {
         cache_http: impl CacheHttp,
         ctx: Self::Context<'_>,
     ) -> Result<Self::Built> {
+        if let Some(image) = &self.image {
+            super::create_scheduled_event::check_cover_image(image)?;
+        }
+
         cache_http.http().edit_scheduled_event(ctx.0, ctx.1, &self, self.audit_log_reason).await
     }
 }
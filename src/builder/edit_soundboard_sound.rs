@@ -0,0 +1,99 @@
+#[cfg(feature = "http")]
+use super::Builder;
+#[cfg(feature = "http")]
+use crate::http::CacheHttp;
+#[cfg(feature = "http")]
+use crate::internal::prelude::*;
+use crate::model::prelude::*;
+
+/// A builder to edit a soundboard sound in a guild.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/soundboard#modify-guild-soundboard-sound)
+#[derive(Clone, Debug, Default, Serialize)]
+#[must_use]
+pub struct EditSoundboardSound<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    volume: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    emoji_id: Option<Option<EmojiId>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    emoji_name: Option<Option<String>>,
+
+    #[serde(skip)]
+    audit_log_reason: Option<&'a str>,
+}
+
+impl<'a> EditSoundboardSound<'a> {
+    /// Equivalent to [`Self::default`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The name of the sound to set.
+    ///
+    /// **Note**: Must be between 2 and 32 characters long.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// The volume of the sound to set, from 0 to 1.
+    pub fn volume(mut self, volume: f64) -> Self {
+        self.volume = Some(volume);
+        self
+    }
+
+    /// Set a custom emoji to show with the sound.
+    pub fn emoji_id(mut self, emoji_id: EmojiId) -> Self {
+        self.emoji_id = Some(Some(emoji_id));
+        self
+    }
+
+    /// Set a standard emoji to show with the sound, as its unicode character.
+    pub fn emoji_name(mut self, emoji_name: impl Into<String>) -> Self {
+        self.emoji_name = Some(Some(emoji_name.into()));
+        self
+    }
+
+    /// Removes the emoji shown with the sound.
+    pub fn delete_emoji(mut self) -> Self {
+        self.emoji_id = Some(None);
+        self.emoji_name = Some(None);
+        self
+    }
+
+    /// Sets the request's audit log reason.
+    pub fn audit_log_reason(mut self, reason: &'a str) -> Self {
+        self.audit_log_reason = Some(reason);
+        self
+    }
+}
+
+#[cfg(feature = "http")]
+#[async_trait::async_trait]
+impl Builder for EditSoundboardSound<'_> {
+    type Context<'ctx> = (GuildId, SoundId);
+    type Built = SoundboardSound;
+
+    /// Edits the soundboard sound.
+    ///
+    /// **Note**: If the sound was created by the current user, requires either the [Create Guild
+    /// Expressions] or the [Manage Guild Expressions] permission. Otherwise, the [Manage Guild
+    /// Expressions] permission is required.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission, or if invalid data is given.
+    ///
+    /// [Create Guild Expressions]: Permissions::CREATE_GUILD_EXPRESSIONS
+    /// [Manage Guild Expressions]: Permissions::MANAGE_GUILD_EXPRESSIONS
+    async fn execute(
+        self,
+        cache_http: impl CacheHttp,
+        ctx: Self::Context<'_>,
+    ) -> Result<Self::Built> {
+        cache_http.http().edit_soundboard_sound(ctx.0, ctx.1, &self, self.audit_log_reason).await
+    }
+}
@@ -1,6 +1,6 @@
 #[cfg(feature = "http")]
 use super::Builder;
-use super::CreateAttachment;
+use super::{CreateAttachment, ImageData};
 #[cfg(feature = "http")]
 use crate::http::CacheHttp;
 #[cfg(feature = "http")]
@@ -14,7 +14,7 @@ pub struct EditWebhook<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    avatar: Option<Option<String>>,
+    avatar: Option<Option<ImageData>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     channel_id: Option<ChannelId>,
 
@@ -44,7 +44,7 @@ impl<'a> EditWebhook<'a> {
 
     /// Set the webhook's default avatar.
     pub fn avatar(mut self, avatar: &CreateAttachment) -> Self {
-        self.avatar = Some(Some(avatar.to_base64()));
+        self.avatar = Some(Some(ImageData::new(avatar)));
         self
     }
 
@@ -74,12 +74,19 @@ impl Builder for EditWebhook<'_> {
     ///
     /// Returns [`Error::Http`] if the content is malformed, or if the token is invalid.
     ///
+    /// Returns a [`ModelError::InvalidImage`] if the avatar is a streamed attachment, see
+    /// [`CreateAttachment::url_streamed`].
+    ///
     /// Returns [`Error::Json`] if there is an error in deserialising Discord's response.
     async fn execute(
         self,
         cache_http: impl CacheHttp,
         ctx: Self::Context<'_>,
     ) -> Result<Self::Built> {
+        if let Some(Some(avatar)) = &self.avatar {
+            avatar.check_in_memory()?;
+        }
+
         match ctx.1 {
             Some(token) => {
                 cache_http
@@ -1,3 +1,4 @@
+use super::create_poll::Ready;
 #[cfg(feature = "http")]
 use super::{check_overflow, Builder};
 use super::{
@@ -5,6 +6,7 @@ use super::{
     CreateAllowedMentions,
     CreateAttachment,
     CreateEmbed,
+    CreatePoll,
     EditAttachments,
 };
 #[cfg(feature = "http")]
@@ -75,6 +77,10 @@ pub struct ExecuteWebhook {
     flags: Option<MessageFlags>,
     #[serde(skip_serializing_if = "Option::is_none")]
     thread_name: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    applied_tags: Vec<ForumTagId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    poll: Option<CreatePoll<Ready>>,
     attachments: EditAttachments,
 
     #[serde(skip)]
@@ -103,6 +109,15 @@ impl ExecuteWebhook {
         Ok(())
     }
 
+    #[cfg(feature = "http")]
+    fn check_thread(&self) -> Result<()> {
+        if self.thread_id.is_some() && self.thread_name.is_some() {
+            return Err(Error::Model(ModelError::WebhookThreadConflict));
+        }
+
+        Ok(())
+    }
+
     /// Override the default avatar of the webhook with an image URL.
     ///
     /// # Examples
@@ -165,6 +180,8 @@ impl ExecuteWebhook {
     ///
     /// **Note**: If the given thread is archived, it will automatically be unarchived.
     ///
+    /// Cannot be combined with [`Self::thread_name`].
+    ///
     /// # Examples
     ///
     /// Execute a webhook with message content of `test`, in a thread with Id `12345678`:
@@ -332,9 +349,37 @@ impl ExecuteWebhook {
         self
     }
 
-    /// Name of thread to create (requires the webhook channel to be a forum channel)
-    pub fn thread_name(mut self, thread_name: String) -> Self {
-        self.thread_name = Some(thread_name);
+    /// Creates a new post with the given name, requires the webhook channel to be a forum or
+    /// media channel. Tags can be applied to the post with [`Self::add_applied_tag`].
+    ///
+    /// Cannot be combined with [`Self::in_thread`].
+    ///
+    /// **Note**: The Id of the created post is only known if the webhook is executed with `wait`
+    /// set to `true`, as the [`Message::channel_id`] of the returned message.
+    pub fn thread_name(mut self, thread_name: impl Into<String>) -> Self {
+        self.thread_name = Some(thread_name.into());
+        self
+    }
+
+    /// Adds a tag to apply to the post created with [`Self::thread_name`].
+    pub fn add_applied_tag(mut self, applied_tag: impl Into<ForumTagId>) -> Self {
+        self.applied_tags.push(applied_tag.into());
+        self
+    }
+
+    /// Sets the tags to apply to the post created with [`Self::thread_name`], replacing the
+    /// current value as set in [`Self::add_applied_tag`].
+    pub fn set_applied_tags(
+        mut self,
+        applied_tags: impl IntoIterator<Item = impl Into<ForumTagId>>,
+    ) -> Self {
+        self.applied_tags = applied_tags.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the [`Poll`] for this message.
+    pub fn poll(mut self, poll: CreatePoll<Ready>) -> Self {
+        self.poll = Some(poll);
         self
     }
 }
@@ -349,6 +394,9 @@ impl Builder for ExecuteWebhook {
     ///
     /// # Errors
     ///
+    /// Returns a [`ModelError::WebhookThreadConflict`] if both [`Self::in_thread`] and
+    /// [`Self::thread_name`] were set.
+    ///
     /// Returns [`Error::Http`] if the content is malformed, if the token is invalid, or if
     /// execution is attempted in a thread not belonging to the webhook's [`Channel`].
     ///
@@ -359,6 +407,7 @@ impl Builder for ExecuteWebhook {
         ctx: Self::Context<'_>,
     ) -> Result<Self::Built> {
         self.check_length()?;
+        self.check_thread()?;
 
         let files = self.attachments.take_files();
 
@@ -370,3 +419,37 @@ impl Builder for ExecuteWebhook {
         http.execute_webhook(ctx.0, self.thread_id, ctx.1, ctx.2, files, &self).await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json::{json, to_value};
+
+    #[test]
+    fn forum_post() {
+        let builder =
+            ExecuteWebhook::new().content("hi").thread_name("logs").set_applied_tags([1, 2]);
+        assert_eq!(
+            to_value(builder).unwrap(),
+            json!({
+                "content": "hi",
+                "tts": false,
+                "embeds": [],
+                "thread_name": "logs",
+                "applied_tags": ["1", "2"],
+                "attachments": [],
+            })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "http")]
+    fn thread_conflict() {
+        let builder = ExecuteWebhook::new().in_thread(1);
+        assert!(builder.check_thread().is_ok());
+        assert!(matches!(
+            builder.thread_name("logs").check_thread(),
+            Err(Error::Model(ModelError::WebhookThreadConflict))
+        ));
+    }
+}
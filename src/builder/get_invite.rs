@@ -0,0 +1,98 @@
+#[cfg(feature = "http")]
+use super::Builder;
+#[cfg(feature = "http")]
+use crate::http::CacheHttp;
+#[cfg(feature = "http")]
+use crate::internal::prelude::*;
+use crate::model::prelude::*;
+
+/// Builds a request to retrieve an [`Invite`] by its code, e.g. to preview a guild before joining
+/// it.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use serenity::http::Http;
+/// #
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// # let http: Http = unimplemented!();
+/// use serenity::builder::GetInvite;
+/// use serenity::model::invite::Invite;
+///
+/// let builder = GetInvite::new("https://discord.gg/serenity-rs").with_counts(true);
+/// let invite = Invite::get_with(&http, builder).await?;
+/// if let Some(guild) = invite.guild {
+///     println!("{} has {:?} members", guild.name, invite.approximate_member_count);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/invite#get-invite)
+#[derive(Clone, Copy, Debug)]
+#[must_use]
+pub struct GetInvite<'a> {
+    code: &'a str,
+    with_counts: bool,
+    with_expiration: bool,
+    scheduled_event_id: Option<ScheduledEventId>,
+}
+
+impl<'a> GetInvite<'a> {
+    /// Creates a new builder for the given invite code or URL. The code is extracted from URLs
+    /// using [`utils::parse_invite`], if the `utils` feature is enabled.
+    ///
+    /// [`utils::parse_invite`]: crate::utils::parse_invite
+    pub fn new(code: &'a str) -> Self {
+        Self {
+            code,
+            with_counts: false,
+            with_expiration: false,
+            scheduled_event_id: None,
+        }
+    }
+
+    /// Whether to include [`Invite::approximate_member_count`] and
+    /// [`Invite::approximate_presence_count`].
+    pub fn with_counts(mut self, with_counts: bool) -> Self {
+        self.with_counts = with_counts;
+        self
+    }
+
+    /// Whether to include [`Invite::expires_at`].
+    pub fn with_expiration(mut self, with_expiration: bool) -> Self {
+        self.with_expiration = with_expiration;
+        self
+    }
+
+    /// Includes the given scheduled event of the invite's guild in
+    /// [`Invite::scheduled_event`].
+    pub fn scheduled_event(mut self, scheduled_event_id: impl Into<ScheduledEventId>) -> Self {
+        self.scheduled_event_id = Some(scheduled_event_id.into());
+        self
+    }
+}
+
+#[cfg(feature = "http")]
+#[async_trait::async_trait]
+impl Builder for GetInvite<'_> {
+    type Context<'ctx> = ();
+    type Built = Invite;
+
+    /// Gets the invite.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the invite is invalid or expired, or if the current user is
+    /// banned from the invite's guild.
+    async fn execute(
+        self,
+        cache_http: impl CacheHttp,
+        _: Self::Context<'_>,
+    ) -> Result<Self::Built> {
+        cache_http
+            .http()
+            .get_invite(self.code, self.with_counts, self.with_expiration, self.scheduled_event_id)
+            .await
+    }
+}
@@ -39,6 +39,7 @@ pub(crate) fn check_overflow(len: usize, max: usize) -> StdResult<(), usize> {
 mod add_member;
 mod bot_auth_parameters;
 mod create_allowed_mentions;
+mod create_application_emoji;
 mod create_attachment;
 mod create_channel;
 mod create_command;
@@ -47,27 +48,33 @@ mod create_components;
 mod create_embed;
 mod create_forum_post;
 mod create_forum_tag;
+mod create_guild_template;
 mod create_interaction_response;
 mod create_interaction_response_followup;
 mod create_invite;
 mod create_message;
 pub mod create_poll;
 mod create_scheduled_event;
+mod create_soundboard_sound;
 mod create_stage_instance;
 mod create_sticker;
 mod create_thread;
 mod create_webhook;
 mod edit_automod_rule;
 mod edit_channel;
+mod edit_current_member;
 mod edit_guild;
+mod edit_guild_template;
 mod edit_guild_welcome_screen;
 mod edit_guild_widget;
 mod edit_interaction_response;
 mod edit_member;
 mod edit_message;
+mod edit_onboarding;
 mod edit_profile;
 mod edit_role;
 mod edit_scheduled_event;
+mod edit_soundboard_sound;
 mod edit_stage_instance;
 mod edit_sticker;
 mod edit_thread;
@@ -76,11 +83,13 @@ mod edit_webhook;
 mod edit_webhook_message;
 mod execute_webhook;
 mod get_entitlements;
+mod get_invite;
 mod get_messages;
 
 pub use add_member::*;
 pub use bot_auth_parameters::*;
 pub use create_allowed_mentions::*;
+pub use create_application_emoji::*;
 pub use create_attachment::*;
 pub use create_channel::*;
 pub use create_command::*;
@@ -89,27 +98,33 @@ pub use create_components::*;
 pub use create_embed::*;
 pub use create_forum_post::*;
 pub use create_forum_tag::*;
+pub use create_guild_template::*;
 pub use create_interaction_response::*;
 pub use create_interaction_response_followup::*;
 pub use create_invite::*;
 pub use create_message::*;
 pub use create_poll::{CreatePoll, CreatePollAnswer};
 pub use create_scheduled_event::*;
+pub use create_soundboard_sound::*;
 pub use create_stage_instance::*;
 pub use create_sticker::*;
 pub use create_thread::*;
 pub use create_webhook::*;
 pub use edit_automod_rule::*;
 pub use edit_channel::*;
+pub use edit_current_member::*;
 pub use edit_guild::*;
+pub use edit_guild_template::*;
 pub use edit_guild_welcome_screen::*;
 pub use edit_guild_widget::*;
 pub use edit_interaction_response::*;
 pub use edit_member::*;
 pub use edit_message::*;
+pub use edit_onboarding::*;
 pub use edit_profile::*;
 pub use edit_role::*;
 pub use edit_scheduled_event::*;
+pub use edit_soundboard_sound::*;
 pub use edit_stage_instance::*;
 pub use edit_sticker::*;
 pub use edit_thread::*;
@@ -118,6 +133,7 @@ pub use edit_webhook::*;
 pub use edit_webhook_message::*;
 pub use execute_webhook::*;
 pub use get_entitlements::*;
+pub use get_invite::*;
 pub use get_messages::*;
 
 macro_rules! button_and_select_menu_convenience_methods {
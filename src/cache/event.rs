@@ -3,6 +3,9 @@ use std::collections::HashSet;
 use super::{Cache, CacheUpdate};
 use crate::model::channel::{GuildChannel, Message};
 use crate::model::event::{
+    AutoModRuleCreateEvent,
+    AutoModRuleDeleteEvent,
+    AutoModRuleUpdateEvent,
     ChannelCreateEvent,
     ChannelDeleteEvent,
     ChannelPinsUpdateEvent,
@@ -25,17 +28,58 @@ use crate::model::event::{
     ReadyEvent,
     ThreadCreateEvent,
     ThreadDeleteEvent,
+    ThreadListSyncEvent,
+    ThreadMemberUpdateEvent,
+    ThreadMembersUpdateEvent,
     ThreadUpdateEvent,
     UserUpdateEvent,
     VoiceChannelStatusUpdateEvent,
     VoiceStateUpdateEvent,
 };
 use crate::model::gateway::ShardInfo;
+use crate::model::guild::automod::Rule;
 use crate::model::guild::{Guild, GuildMemberFlags, Member, Role};
 use crate::model::id::ShardId;
 use crate::model::user::{CurrentUser, OnlineStatus};
 use crate::model::voice::VoiceState;
 
+impl CacheUpdate for AutoModRuleCreateEvent {
+    type Output = ();
+
+    fn update(&mut self, cache: &Cache) -> Option<()> {
+        if let Some(mut rules) = cache.automod_rules.get_mut(&self.rule.guild_id) {
+            rules.insert(self.rule.id, self.rule.clone());
+        } else {
+            let rules = std::iter::once((self.rule.id, self.rule.clone())).collect();
+            cache.automod_rules.insert(self.rule.guild_id, rules);
+        }
+
+        None
+    }
+}
+
+impl CacheUpdate for AutoModRuleUpdateEvent {
+    type Output = Rule;
+
+    fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
+        if let Some(mut rules) = cache.automod_rules.get_mut(&self.rule.guild_id) {
+            rules.insert(self.rule.id, self.rule.clone())
+        } else {
+            let rules = std::iter::once((self.rule.id, self.rule.clone())).collect();
+            cache.automod_rules.insert(self.rule.guild_id, rules);
+            None
+        }
+    }
+}
+
+impl CacheUpdate for AutoModRuleDeleteEvent {
+    type Output = Rule;
+
+    fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
+        cache.automod_rules.get_mut(&self.rule.guild_id)?.remove(&self.rule.id)
+    }
+}
+
 impl CacheUpdate for ChannelCreateEvent {
     type Output = GuildChannel;
 
@@ -98,11 +142,12 @@ impl CacheUpdate for GuildCreateEvent {
 
     fn update(&mut self, cache: &Cache) -> Option<()> {
         cache.unavailable_guilds.remove(&self.guild.id);
+        cache.stale_guilds.remove(&self.guild.id);
         let mut guild = self.guild.clone();
 
         for (user_id, member) in &mut guild.members {
             cache.update_user_entry(&member.user);
-            if let Some(u) = cache.user(user_id) {
+            if let Some(u) = cache.user_(*user_id) {
                 member.user = u.clone();
             }
         }
@@ -121,13 +166,17 @@ impl CacheUpdate for GuildDeleteEvent {
 
     fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
         if self.guild.unavailable {
-            cache.unavailable_guilds.insert(self.guild.id, ());
-            cache.guilds.remove(&self.guild.id);
+            cache.mark_guild_unavailable(self.guild.id);
 
             return None;
         }
 
-        match cache.guilds.remove(&self.guild.id) {
+        cache.automod_rules.remove(&self.guild.id);
+        cache.joined_threads.remove(&self.guild.id);
+        cache.unavailable_guilds.remove(&self.guild.id);
+
+        let guild = cache.guilds.remove(&self.guild.id);
+        match guild.or_else(|| cache.stale_guilds.remove(&self.guild.id)) {
             Some(guild) => {
                 for channel_id in guild.1.channels.keys() {
                     // Remove the channel from the cache.
@@ -162,7 +211,7 @@ impl CacheUpdate for GuildMemberAddEvent {
     fn update(&mut self, cache: &Cache) -> Option<()> {
         let user_id = self.member.user.id;
         cache.update_user_entry(&self.member.user);
-        if let Some(u) = cache.user(user_id) {
+        if let Some(u) = cache.user_(user_id) {
             self.member.user = u.clone();
         }
 
@@ -207,8 +256,12 @@ impl CacheUpdate for GuildMemberUpdateEvent {
                 member.deaf.clone_from(&self.deaf);
                 member.mute.clone_from(&self.mute);
                 member.avatar.clone_from(&self.avatar);
+                member.banner.clone_from(&self.banner);
                 member.communication_disabled_until.clone_from(&self.communication_disabled_until);
                 member.unusual_dm_activity_until.clone_from(&self.unusual_dm_activity_until);
+                if let Some(flags) = self.flags {
+                    member.flags = flags;
+                }
 
                 item
             } else {
@@ -228,8 +281,9 @@ impl CacheUpdate for GuildMemberUpdateEvent {
                     premium_since: self.premium_since,
                     permissions: None,
                     avatar: self.avatar,
+                    banner: self.banner,
                     communication_disabled_until: self.communication_disabled_until,
-                    flags: GuildMemberFlags::default(),
+                    flags: self.flags.unwrap_or_default(),
                     unusual_dm_activity_until: self.unusual_dm_activity_until,
                 });
             }
@@ -374,10 +428,9 @@ impl CacheUpdate for MessageCreateEvent {
 
         let mut removed_msg = None;
 
-        if messages.len() == max {
-            if let Some(id) = queue.pop_front() {
-                removed_msg = messages.remove(&id);
-            }
+        while messages.len() >= max {
+            let Some(id) = queue.pop_front() else { break };
+            removed_msg = messages.remove(&id);
         }
 
         queue.push_back(self.message.id);
@@ -389,7 +442,8 @@ impl CacheUpdate for MessageCreateEvent {
 
 fn update_channel_last_message_id(message: &Message, channel: &mut GuildChannel, cache: &Cache) {
     if let Some(last_message_id) = channel.last_message_id {
-        let most_recent_timestamp = cache.message(channel.id, last_message_id).map(|m| m.timestamp);
+        let most_recent_timestamp =
+            cache.message_(channel.id, last_message_id).map(|m| m.timestamp);
         if let Some(most_recent_timestamp) = most_recent_timestamp {
             if message.timestamp > most_recent_timestamp {
                 channel.last_message_id = Some(message.id);
@@ -424,7 +478,7 @@ impl CacheUpdate for PresenceUpdateEvent {
             cache.update_user_entry(&user);
         }
 
-        if let Some(user) = cache.user(self.presence.user.id) {
+        if let Some(user) = cache.user_(self.presence.user.id) {
             self.presence.user.update_with_user(&user);
         }
 
@@ -451,6 +505,7 @@ impl CacheUpdate for PresenceUpdateEvent {
                         premium_since: None,
                         permissions: None,
                         avatar: None,
+                        banner: None,
                         communication_disabled_until: None,
                         flags: GuildMemberFlags::default(),
                         unusual_dm_activity_until: None,
@@ -470,8 +525,7 @@ impl CacheUpdate for ReadyEvent {
         let ready = self.ready.clone();
 
         for unavailable in ready.guilds {
-            cache.guilds.remove(&unavailable.id);
-            cache.unavailable_guilds.insert(unavailable.id, ());
+            cache.mark_guild_unavailable(unavailable.id);
         }
 
         // We may be removed from some guilds between disconnect and ready, so handle that.
@@ -480,18 +534,20 @@ impl CacheUpdate for ReadyEvent {
             self.ready.guilds.iter().map(|status| status.id).collect::<HashSet<_>>();
         let shard_data = self.ready.shard.unwrap_or_else(|| ShardInfo::new(ShardId(1), 1));
 
-        for guild_entry in cache.guilds.iter() {
-            let guild = guild_entry.key();
+        let cached_guild_ids = cache.guilds.iter().map(|entry| *entry.key());
+        let stale_guild_ids = cache.stale_guilds.iter().map(|entry| *entry.key());
+        for guild in cached_guild_ids.chain(stale_guild_ids) {
             // Only handle data for our shard.
-            if crate::utils::shard_id(*guild, shard_data.total) == shard_data.id.0
-                && !ready_guilds_hashset.contains(guild)
+            if crate::utils::shard_id(guild, shard_data.total) == shard_data.id.0
+                && !ready_guilds_hashset.contains(&guild)
             {
-                guilds_to_remove.push(*guild);
+                guilds_to_remove.push(guild);
             }
         }
         if !guilds_to_remove.is_empty() {
             for guild in guilds_to_remove {
                 cache.guilds.remove(&guild);
+                cache.stale_guilds.remove(&guild);
             }
         }
 
@@ -512,6 +568,11 @@ impl CacheUpdate for ThreadCreateEvent {
     fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
         let (guild_id, thread_id) = (self.thread.guild_id, self.thread.id);
 
+        // The thread member of the current user is included when it was added to the thread.
+        if self.thread.member.is_some() {
+            cache.set_thread_joined(guild_id, thread_id, true);
+        }
+
         cache.guilds.get_mut(&guild_id).and_then(|mut g| {
             if let Some(i) = g.threads.iter().position(|e| e.id == thread_id) {
                 Some(std::mem::replace(&mut g.threads[i], self.thread.clone()))
@@ -546,12 +607,61 @@ impl CacheUpdate for ThreadDeleteEvent {
     fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
         let (guild_id, thread_id) = (self.thread.guild_id, self.thread.id);
 
+        cache.set_thread_joined(guild_id, thread_id, false);
         cache.guilds.get_mut(&guild_id).and_then(|mut g| {
             g.threads.iter().position(|e| e.id == thread_id).map(|i| g.threads.remove(i))
         })
     }
 }
 
+impl CacheUpdate for ThreadListSyncEvent {
+    type Output = ();
+
+    fn update(&mut self, cache: &Cache) -> Option<()> {
+        // Without channel ids, all active threads of the guild are synced.
+        if self.channel_ids.is_none() {
+            cache.joined_threads.remove(&self.guild_id);
+        }
+
+        for thread in &self.threads {
+            let joined = self.members.iter().any(|member| member.id == thread.id);
+            cache.set_thread_joined(self.guild_id, thread.id, joined);
+        }
+
+        None
+    }
+}
+
+impl CacheUpdate for ThreadMemberUpdateEvent {
+    type Output = ();
+
+    fn update(&mut self, cache: &Cache) -> Option<()> {
+        // This event is only sent for the thread member of the current user.
+        if let Some(guild_id) = self.member.guild_id {
+            cache.set_thread_joined(guild_id, self.member.id, true);
+        }
+
+        None
+    }
+}
+
+impl CacheUpdate for ThreadMembersUpdateEvent {
+    type Output = ();
+
+    fn update(&mut self, cache: &Cache) -> Option<()> {
+        let user_id = cache.current_user().id;
+
+        if self.added_members.iter().any(|member| member.user_id == user_id) {
+            cache.set_thread_joined(self.guild_id, self.id, true);
+        }
+        if self.removed_member_ids.contains(&user_id) {
+            cache.set_thread_joined(self.guild_id, self.id, false);
+        }
+
+        None
+    }
+}
+
 impl CacheUpdate for UserUpdateEvent {
     type Output = CurrentUser;
 
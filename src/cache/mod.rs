@@ -41,13 +41,16 @@ use tracing::instrument;
 
 pub use self::cache_update::CacheUpdate;
 pub use self::settings::Settings;
+pub use self::statistics::{CacheStatistics, LookupStatistics};
 use crate::model::prelude::*;
 
 mod cache_update;
 mod event;
 mod settings;
+mod statistics;
 mod wrappers;
 
+use statistics::StatisticsCounters;
 #[cfg(feature = "temp_cache")]
 pub(crate) use wrappers::MaybeOwnedArc;
 use wrappers::{BuildHasher, MaybeMap, ReadOnlyMapRef};
@@ -114,6 +117,7 @@ type MappedGuildRef<'a, T> = CacheRef<'a, GuildId, T, Guild>;
 
 pub type MemberRef<'a> = MappedGuildRef<'a, Member>;
 pub type GuildRoleRef<'a> = MappedGuildRef<'a, Role>;
+pub type VoiceStateRef<'a> = MappedGuildRef<'a, VoiceState>;
 pub type UserRef<'a> = CacheRef<'a, UserId, User, Never>;
 pub type GuildRef<'a> = CacheRef<'a, GuildId, Guild, Never>;
 pub type SettingsRef<'a> = CacheRef<'a, Never, Settings, Never>;
@@ -123,6 +127,8 @@ pub type GuildRolesRef<'a> = MappedGuildRef<'a, HashMap<RoleId, Role>>;
 pub type GuildChannelsRef<'a> = MappedGuildRef<'a, HashMap<ChannelId, GuildChannel>>;
 pub type MessageRef<'a> = CacheRef<'a, ChannelId, Message, HashMap<MessageId, Message>>;
 pub type ChannelMessagesRef<'a> = CacheRef<'a, ChannelId, HashMap<MessageId, Message>, Never>;
+pub type AutoModRulesRef<'a> = CacheRef<'a, GuildId, HashMap<RuleId, Rule>, Never>;
+pub type JoinedThreadsRef<'a> = CacheRef<'a, GuildId, HashSet<ChannelId>, Never>;
 
 #[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
 #[derive(Debug)]
@@ -141,10 +147,16 @@ pub(crate) struct CachedShardData {
 /// - channels: [`ChannelCreateEvent`], [`ChannelUpdateEvent`], [`GuildCreateEvent`]
 /// - guilds: [`GuildCreateEvent`]
 /// - unavailable_guilds: [`ReadyEvent`], [`GuildDeleteEvent`]
+/// - last known state of unavailable guilds: [`ReadyEvent`], [`GuildDeleteEvent`], if enabled
+///   via [`Settings::cache_unavailable_guilds`]
 /// - users: [`GuildMemberAddEvent`], [`GuildMemberRemoveEvent`], [`GuildMembersChunkEvent`],
 ///   [`PresenceUpdateEvent`], [`ReadyEvent`]
 /// - presences: [`PresenceUpdateEvent`], [`ReadyEvent`]
 /// - messages: [`MessageCreateEvent`]
+/// - automod_rules: [`AutoModRuleCreateEvent`], [`AutoModRuleUpdateEvent`],
+///   [`AutoModRuleDeleteEvent`], if enabled via [`Settings::cache_automod_rules`]
+/// - joined_threads: [`ThreadCreateEvent`], [`ThreadDeleteEvent`], [`ThreadListSyncEvent`],
+///   [`ThreadMemberUpdateEvent`], [`ThreadMembersUpdateEvent`]
 ///
 /// The documentation of each event contains the required gateway intents.
 ///
@@ -176,6 +188,11 @@ pub struct Cache {
     /// The TTL for each value is configured in CacheSettings.
     #[cfg(feature = "temp_cache")]
     pub(crate) temp_users: MokaCache<UserId, MaybeOwnedArc<User>, BuildHasher>,
+    /// Cache of webhooks by channel and name, found or created via webhook_or_create.
+    ///
+    /// The TTL for each value is configured in CacheSettings.
+    #[cfg(feature = "temp_cache")]
+    pub(crate) temp_webhooks: MokaCache<(ChannelId, String), MaybeOwnedArc<Webhook>, BuildHasher>,
 
     // Channels cache:
     /// A map of channel ids to the guilds in which the channel data is stored.
@@ -191,6 +208,13 @@ pub struct Cache {
     /// Additionally, guilds are always unavailable for bot users when a Ready is received. Guilds
     /// are "sent in" over time through the receiving of [`Event::GuildCreate`]s.
     pub(crate) unavailable_guilds: MaybeMap<GuildId, ()>,
+    /// The last known state of guilds which became unavailable after being cached, kept until
+    /// they become available again or are removed.
+    pub(crate) stale_guilds: MaybeMap<GuildId, Guild>,
+    /// A map of guilds to their auto moderation rules, disabled by default.
+    pub(crate) automod_rules: MaybeMap<GuildId, HashMap<RuleId, Rule>>,
+    /// A map of guilds to the threads the current user has joined in them.
+    pub(crate) joined_threads: MaybeMap<GuildId, HashSet<ChannelId>>,
 
     // Users cache:
     // ---
@@ -232,6 +256,8 @@ pub struct Cache {
     pub(crate) user: RwLock<CurrentUser>,
     /// The settings for the cache.
     settings: RwLock<Settings>,
+    /// Hit and miss counters of lookups, see [`Self::statistics`].
+    statistics: StatisticsCounters,
 }
 
 impl Cache {
@@ -274,11 +300,19 @@ impl Cache {
             temp_messages: temp_cache(settings.time_to_live),
             #[cfg(feature = "temp_cache")]
             temp_users: temp_cache(settings.time_to_live),
+            #[cfg(feature = "temp_cache")]
+            temp_webhooks: temp_cache(settings.time_to_live),
 
             channels: MaybeMap(settings.cache_channels.then(DashMap::default)),
 
             guilds: MaybeMap(settings.cache_guilds.then(DashMap::default)),
             unavailable_guilds: MaybeMap(settings.cache_guilds.then(DashMap::default)),
+            stale_guilds: MaybeMap(
+                (settings.cache_guilds && settings.cache_unavailable_guilds)
+                    .then(DashMap::default),
+            ),
+            automod_rules: MaybeMap(settings.cache_automod_rules.then(DashMap::default)),
+            joined_threads: MaybeMap(settings.cache_guilds.then(DashMap::default)),
 
             users: MaybeMap(settings.cache_users.then(DashMap::default)),
 
@@ -292,6 +326,7 @@ impl Cache {
             }),
             user: RwLock::new(CurrentUser::default()),
             settings: RwLock::new(settings),
+            statistics: StatisticsCounters::default(),
         }
     }
 
@@ -375,7 +410,7 @@ impl Cache {
     #[inline]
     #[deprecated = "Use Cache::guild and Guild::channels instead"]
     pub fn channel<C: Into<ChannelId>>(&self, id: C) -> Option<GuildChannelRef<'_>> {
-        self.channel_(id.into())
+        self.statistics.channels.record(self.channel_(id.into()))
     }
 
     fn channel_(&self, id: ChannelId) -> Option<GuildChannelRef<'_>> {
@@ -433,7 +468,7 @@ impl Cache {
     /// ```
     #[inline]
     pub fn guild<G: Into<GuildId>>(&self, id: G) -> Option<GuildRef<'_>> {
-        self.guild_(id.into())
+        self.statistics.guilds.record(self.guild_(id.into()))
     }
 
     fn guild_(&self, id: GuildId) -> Option<GuildRef<'_>> {
@@ -495,7 +530,7 @@ impl Cache {
         guild_id: impl Into<GuildId>,
         user_id: impl Into<UserId>,
     ) -> Option<MemberRef<'_>> {
-        self.member_(guild_id.into(), user_id.into())
+        self.statistics.members.record(self.member_(guild_id.into(), user_id.into()))
     }
 
     fn member_(&self, guild_id: GuildId, user_id: UserId) -> Option<MemberRef<'_>> {
@@ -503,6 +538,46 @@ impl Cache {
         Some(CacheRef::from_mapped_ref(member))
     }
 
+    /// Retrieves the voice state of a user in a guild from the cache.
+    ///
+    /// Returns [`None`] if the guild is not cached, or if the user is not connected to a voice
+    /// channel of the guild. Voice states are only cached with the [`GUILD_VOICE_STATES`] intent.
+    ///
+    /// [`GUILD_VOICE_STATES`]: crate::model::gateway::GatewayIntents::GUILD_VOICE_STATES
+    pub fn voice_state(
+        &self,
+        guild_id: impl Into<GuildId>,
+        user_id: impl Into<UserId>,
+    ) -> Option<VoiceStateRef<'_>> {
+        let user_id = user_id.into();
+        let state =
+            self.guilds.get(&guild_id.into())?.try_map(|g| g.voice_states.get(&user_id)).ok()?;
+        Some(CacheRef::from_mapped_ref(state))
+    }
+
+    /// Retrieves the voice channel a user is connected to in a guild from the cache.
+    ///
+    /// Shorthand for getting the channel of [`Self::voice_state`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::cache::Cache;
+    /// # use serenity::model::id::{GuildId, UserId};
+    /// #
+    /// # let cache = Cache::default();
+    /// if let Some(channel_id) = cache.voice_channel_of(GuildId::new(1), UserId::new(2)) {
+    ///     println!("The user is in {channel_id}");
+    /// }
+    /// ```
+    pub fn voice_channel_of(
+        &self,
+        guild_id: impl Into<GuildId>,
+        user_id: impl Into<UserId>,
+    ) -> Option<ChannelId> {
+        self.voice_state(guild_id, user_id)?.channel_id
+    }
+
     #[inline]
     #[deprecated = "Use Cache::guild and Guild::roles instead"]
     pub fn guild_roles(&self, guild_id: impl Into<GuildId>) -> Option<GuildRolesRef<'_>> {
@@ -514,12 +589,62 @@ impl Cache {
         Some(CacheRef::from_mapped_ref(roles))
     }
 
+    /// Returns the auto moderation rules of a guild.
+    ///
+    /// Returns [`None`] if no rules of the guild are cached, or if caching rules is disabled via
+    /// [`Settings::cache_automod_rules`].
+    pub fn automod_rules(&self, guild_id: impl Into<GuildId>) -> Option<AutoModRulesRef<'_>> {
+        self.automod_rules.get(&guild_id.into()).map(CacheRef::from_ref)
+    }
+
+    /// Returns the threads of a guild the current user has joined.
+    ///
+    /// Returns [`None`] if the current user has not joined any threads of the guild since the
+    /// cache was created, or if caching guilds is disabled via [`Settings::cache_guilds`].
+    pub fn joined_threads(&self, guild_id: impl Into<GuildId>) -> Option<JoinedThreadsRef<'_>> {
+        self.joined_threads.get(&guild_id.into()).map(CacheRef::from_ref)
+    }
+
+    /// Records whether the current user is a member of the given thread.
+    pub(crate) fn set_thread_joined(&self, guild_id: GuildId, thread_id: ChannelId, joined: bool) {
+        if joined {
+            if let Some(mut threads) = self.joined_threads.get_mut(&guild_id) {
+                threads.insert(thread_id);
+            } else {
+                self.joined_threads.insert(guild_id, HashSet::from([thread_id]));
+            }
+        } else if let Some(mut threads) = self.joined_threads.get_mut(&guild_id) {
+            threads.remove(&thread_id);
+        }
+    }
+
     /// This method clones and returns all unavailable guilds.
     #[inline]
     pub fn unavailable_guilds(&self) -> ReadOnlyMapRef<'_, GuildId, ()> {
         self.unavailable_guilds.as_read_only()
     }
 
+    /// Gets the last known state of a guild which is currently unavailable, e.g. due to an
+    /// outage.
+    ///
+    /// Unavailable guilds are no longer returned by [`Self::guild`], as their data may be
+    /// outdated. Once the guild becomes available again, its fresh state is returned by
+    /// [`Self::guild`] instead.
+    ///
+    /// Returns [`None`] if the guild is not unavailable, if it was never cached before becoming
+    /// unavailable, or if this is disabled via [`Settings::cache_unavailable_guilds`].
+    pub fn unavailable_guild(&self, id: impl Into<GuildId>) -> Option<GuildRef<'_>> {
+        self.stale_guilds.get(&id.into()).map(CacheRef::from_ref)
+    }
+
+    /// Marks a guild as unavailable, keeping its last known state if enabled.
+    pub(crate) fn mark_guild_unavailable(&self, id: GuildId) {
+        self.unavailable_guilds.insert(id, ());
+        if let Some((_, guild)) = self.guilds.remove(&id) {
+            self.stale_guilds.insert(id, guild);
+        }
+    }
+
     /// This method returns all channels from a guild of with the given `guild_id`.
     #[inline]
     #[deprecated = "Use Cache::guild and Guild::channels instead"]
@@ -572,7 +697,7 @@ impl Cache {
         C: Into<ChannelId>,
         M: Into<MessageId>,
     {
-        self.message_(channel_id.into(), message_id.into())
+        self.statistics.messages.record(self.message_(channel_id.into(), message_id.into()))
     }
 
     fn message_(&self, channel_id: ChannelId, message_id: MessageId) -> Option<MessageRef<'_>> {
@@ -614,7 +739,7 @@ impl Cache {
         G: Into<GuildId>,
         R: Into<RoleId>,
     {
-        self.role_(guild_id.into(), role_id.into())
+        self.statistics.roles.record(self.role_(guild_id.into(), role_id.into()))
     }
 
     fn role_(&self, guild_id: GuildId, role_id: RoleId) -> Option<GuildRoleRef<'_>> {
@@ -640,11 +765,95 @@ impl Cache {
         CacheRef::from_guard(self.settings.read())
     }
 
+    /// Returns how many lookups through [`Self::guild`], [`Self::channel`], [`Self::member`],
+    /// [`Self::message`], [`Self::role`] and [`Self::user`] found or missed their item since the
+    /// cache was created.
+    ///
+    /// Lookups the cache makes while handling gateway events are not counted. Lookups made by
+    /// other serenity methods on your behalf, such as permission checks before an HTTP request,
+    /// are counted like your own.
+    ///
+    /// # Examples
+    ///
+    /// Printing the hit rate of guild lookups:
+    ///
+    /// ```rust
+    /// use serenity::cache::Cache;
+    ///
+    /// let cache = Cache::new();
+    /// assert!(cache.guild(1).is_none());
+    ///
+    /// let guilds = cache.statistics().guilds;
+    /// assert_eq!(guilds.misses, 1);
+    /// println!("Guild hit rate: {:.1}%", guilds.hit_rate().unwrap_or(0.0) * 100.0);
+    /// ```
+    #[must_use]
+    pub fn statistics(&self) -> CacheStatistics {
+        self.statistics.get()
+    }
+
     /// Sets the maximum amount of messages per channel to cache.
     ///
-    /// By default, no messages will be cached.
+    /// By default, no messages will be cached. If the maximum is lowered, the oldest messages of
+    /// channels holding more messages are removed immediately.
     pub fn set_max_messages(&self, max: usize) {
-        self.settings.write().max_messages = max;
+        self.update_settings(|settings| settings.max_messages = max);
+    }
+
+    /// Changes the settings of the running cache.
+    ///
+    /// Changes to [`Settings::max_messages`] take effect immediately, see
+    /// [`Self::set_max_messages`]. Whether guilds, channels, users and auto moderation rules are
+    /// cached at all, as well as [`Settings::time_to_live`], is decided when the cache is created
+    /// and changing these settings has no effect afterwards.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::cache::Cache;
+    ///
+    /// let cache = Cache::new();
+    /// cache.update_settings(|settings| settings.max_messages += 100);
+    /// assert_eq!(cache.settings().max_messages, 100);
+    /// ```
+    pub fn update_settings(&self, f: impl FnOnce(&mut Settings)) {
+        let max = {
+            let mut settings = self.settings.write();
+            f(&mut settings);
+            settings.max_messages
+        };
+
+        self.trim_messages(max);
+    }
+
+    /// Removes the oldest cached messages of each channel holding more than `max` messages.
+    fn trim_messages(&self, max: usize) {
+        if max == 0 {
+            self.messages.clear();
+            self.message_queue.clear();
+            return;
+        }
+
+        // Locks the message map before the queue, same as when caching a new message.
+        for mut messages in self.messages.iter_mut() {
+            if messages.len() > max {
+                if let Some(mut queue) = self.message_queue.get_mut(messages.key()) {
+                    while queue.len() > max {
+                        if let Some(id) = queue.pop_front() {
+                            messages.remove(&id);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Sets the total number of shards, as returned by [`Self::shard_count`].
+    ///
+    /// This is updated from [`ReadyEvent`]s, so it only needs to be set manually if shards are
+    /// started with a different total before they have all become ready.
+    pub fn set_shard_count(&self, total: u32) {
+        self.shard_data.write().total = total;
     }
 
     /// Retrieves a [`User`] from the cache's [`Self::users`] map, if it exists.
@@ -668,7 +877,7 @@ impl Cache {
     /// ```
     #[inline]
     pub fn user<U: Into<UserId>>(&self, user_id: U) -> Option<UserRef<'_>> {
-        self.user_(user_id.into())
+        self.statistics.users.record(self.user_(user_id.into()))
     }
 
     #[cfg(feature = "temp_cache")]
@@ -706,8 +915,7 @@ impl Cache {
     /// Returns a channel category matching the given ID
     #[deprecated = "Use Cache::guild, Guild::channels, and GuildChannel::kind"]
     pub fn category(&self, channel_id: ChannelId) -> Option<GuildChannelRef<'_>> {
-        #[allow(deprecated)]
-        let channel = self.channel(channel_id)?;
+        let channel = self.channel_(channel_id)?;
         if channel.kind == ChannelType::Category {
             Some(channel)
         } else {
@@ -718,8 +926,7 @@ impl Cache {
     /// Returns the parent category of the given channel ID.
     #[deprecated = "Use Cache::guild, Guild::channels, and GuildChannel::parent_id"]
     pub fn channel_category_id(&self, channel_id: ChannelId) -> Option<ChannelId> {
-        #[allow(deprecated)]
-        self.channel(channel_id)?.parent_id
+        self.channel_(channel_id)?.parent_id
     }
 
     /// Clones all channel categories in the given guild and returns them.
@@ -773,7 +980,8 @@ impl Default for Cache {
 #[cfg(test)]
 mod test {
 
-    use crate::cache::{Cache, CacheUpdate, Settings};
+    use crate::cache::{Cache, CacheUpdate, LookupStatistics, Settings};
+    use crate::json::{from_value, json};
     use crate::model::prelude::*;
 
     #[test]
@@ -857,4 +1065,270 @@ mod test {
         // Assert that the channel's message cache no longer exists.
         assert!(!cache.messages.contains_key(&ChannelId::new(2)));
     }
+
+    #[test]
+    fn test_cache_voice_states() {
+        let cache = Cache::new();
+        let (guild_id, user_id) = (GuildId::new(1), UserId::new(2));
+
+        let mut state: VoiceState = from_value(json!({
+            "channel_id": "3",
+            "guild_id": "1",
+            "user_id": "2",
+            "deaf": false,
+            "mute": false,
+            "self_deaf": false,
+            "self_mute": false,
+            "self_video": false,
+            "session_id": "session",
+            "suppress": false,
+        }))
+        .unwrap();
+
+        let mut guild_create = GuildCreateEvent {
+            guild: Guild {
+                id: guild_id,
+                voice_states: HashMap::from([(user_id, state.clone())]),
+                ..Default::default()
+            },
+        };
+        cache.update(&mut guild_create);
+
+        assert_eq!(cache.voice_state(guild_id, user_id).unwrap().channel_id, state.channel_id);
+        assert_eq!(cache.voice_channel_of(guild_id, user_id), Some(ChannelId::new(3)));
+        assert!(cache.voice_state(guild_id, UserId::new(4)).is_none());
+        assert!(cache.voice_channel_of(GuildId::new(5), user_id).is_none());
+
+        // Leaving the voice channel removes the voice state.
+        state.channel_id = None;
+        cache.update(&mut VoiceStateUpdateEvent {
+            voice_state: state,
+        });
+        assert!(cache.voice_state(guild_id, user_id).is_none());
+        assert_eq!(cache.voice_channel_of(guild_id, user_id), None);
+    }
+
+    #[test]
+    fn test_cache_unavailable_guild() {
+        let cache = Cache::new();
+        let guild_id = GuildId::new(1);
+
+        let mut guild_create = GuildCreateEvent {
+            guild: Guild {
+                id: guild_id,
+                name: "old".into(),
+                ..Default::default()
+            },
+        };
+        cache.update(&mut guild_create);
+
+        let mut guild_delete = GuildDeleteEvent {
+            guild: UnavailableGuild {
+                id: guild_id,
+                unavailable: true,
+            },
+        };
+        assert!(cache.update(&mut guild_delete).is_none());
+        assert!(cache.guild(guild_id).is_none());
+        assert!(cache.unavailable_guilds().get(&guild_id).is_some());
+        assert_eq!(cache.unavailable_guild(guild_id).unwrap().name, "old");
+
+        // The guild becoming available again replaces the last known state.
+        guild_create.guild.name = "new".into();
+        cache.update(&mut guild_create);
+        assert!(cache.unavailable_guild(guild_id).is_none());
+        assert_eq!(cache.guild(guild_id).unwrap().name, "new");
+
+        // Removal of an unavailable guild evicts its last known state.
+        cache.update(&mut guild_delete);
+        guild_delete.guild.unavailable = false;
+        assert_eq!(cache.update(&mut guild_delete).unwrap().name, "new");
+        assert!(cache.unavailable_guild(guild_id).is_none());
+        assert!(cache.unavailable_guilds().get(&guild_id).is_none());
+    }
+
+    #[test]
+    fn test_cache_joined_threads() {
+        let cache = Cache::new();
+        let (guild_id, thread_id, user_id) = (GuildId::new(1), ChannelId::new(2), UserId::new(3));
+        cache.user.write().id = user_id;
+
+        let member = json!({
+            "id": "2",
+            "user_id": "3",
+            "guild_id": "1",
+            "join_timestamp": "2023-01-01T00:00:00Z",
+            "flags": 0,
+        });
+        let thread = GuildChannel {
+            id: thread_id,
+            guild_id,
+            kind: ChannelType::PublicThread,
+            thread_metadata: Some(
+                from_value(json!({
+                    "archived": false,
+                    "auto_archive_duration": 60,
+                    "archive_timestamp": null,
+                    "create_timestamp": null,
+                }))
+                .unwrap(),
+            ),
+            ..Default::default()
+        };
+        assert!(!thread.is_joined(&cache));
+
+        let mut member_update = ThreadMemberUpdateEvent {
+            member: from_value(member.clone()).unwrap(),
+        };
+        cache.update(&mut member_update);
+        assert!(thread.is_joined(&cache));
+
+        let mut members_update: ThreadMembersUpdateEvent = from_value(json!({
+            "id": "2",
+            "guild_id": "1",
+            "member_count": 0,
+            "removed_member_ids": ["3"],
+        }))
+        .unwrap();
+        cache.update(&mut members_update);
+        assert!(!thread.is_joined(&cache));
+
+        // A full sync marks exactly the threads listed in the members as joined.
+        let mut list_sync = ThreadListSyncEvent {
+            guild_id,
+            channel_ids: None,
+            threads: vec![thread.clone()],
+            members: vec![from_value(member).unwrap()],
+        };
+        cache.update(&mut list_sync);
+        assert!(thread.is_joined(&cache));
+
+        list_sync.members.clear();
+        cache.update(&mut list_sync);
+        assert!(!thread.is_joined(&cache));
+        assert!(cache.joined_threads(guild_id).is_none());
+    }
+
+    #[test]
+    fn test_cache_max_messages_runtime() {
+        let cache = Cache::new();
+        let channel_id = ChannelId::new(2);
+        let create = |id: u64| {
+            cache.update(&mut MessageCreateEvent {
+                message: Message {
+                    id: MessageId::new(id),
+                    channel_id,
+                    ..Default::default()
+                },
+            })
+        };
+        let cached_ids = |cache: &Cache| -> Vec<u64> {
+            let mut ids: Vec<_> = cache
+                .channel_messages(channel_id)
+                .map(|m| m.keys().map(|id| id.get()).collect())
+                .unwrap_or_default();
+            ids.sort_unstable();
+            ids
+        };
+
+        // Nothing is cached by default, growing the limit applies to new messages.
+        create(1);
+        assert!(cached_ids(&cache).is_empty());
+        cache.set_max_messages(4);
+        for id in 2..=5 {
+            create(id);
+        }
+        assert_eq!(cached_ids(&cache), [2, 3, 4, 5]);
+
+        // Shrinking evicts the oldest messages right away.
+        cache.update_settings(|settings| settings.max_messages = 2);
+        assert_eq!(cached_ids(&cache), [4, 5]);
+        assert_eq!(create(6).map(|m| m.id), Some(MessageId::new(4)));
+        assert_eq!(cached_ids(&cache), [5, 6]);
+
+        cache.set_max_messages(0);
+        assert!(cached_ids(&cache).is_empty());
+        assert!(cache.message_queue.is_empty());
+    }
+
+    #[test]
+    fn test_cache_member_flags() {
+        let cache = Cache::new();
+        let (guild_id, user_id) = (GuildId::new(1), UserId::new(2));
+
+        let mut guild_create = GuildCreateEvent {
+            guild: Guild {
+                id: guild_id,
+                ..Default::default()
+            },
+        };
+        cache.update(&mut guild_create);
+
+        let update = |flags: Option<u32>| {
+            let mut event: GuildMemberUpdateEvent = from_value(json!({
+                "guild_id": "1",
+                "joined_at": "2024-01-01T00:00:00Z",
+                "roles": [],
+                "user": {"id": "2", "username": "user", "discriminator": "0", "avatar": null},
+                "premium_since": null,
+                "avatar": null,
+                "banner": null,
+                "communication_disabled_until": null,
+                "unusual_dm_activity_until": null,
+                "flags": flags,
+            }))
+            .unwrap();
+            cache.update(&mut event);
+            cache.member(guild_id, user_id).unwrap().flags
+        };
+
+        // Joining inserts the member with its flags, later updates replace them.
+        assert_eq!(update(Some(1 << 3)), GuildMemberFlags::STARTED_ONBOARDING);
+        let flags = update(Some((1 << 0) | (1 << 1) | (1 << 8)));
+        assert!(flags.contains(GuildMemberFlags::DID_REJOIN));
+        assert!(flags.contains(GuildMemberFlags::COMPLETED_ONBOARDING));
+        assert!(!flags.contains(GuildMemberFlags::STARTED_ONBOARDING));
+
+        // Updates without flags keep the cached ones.
+        assert_eq!(update(None), flags);
+
+        // Unknown bits survive a round trip.
+        assert_eq!(flags.bits(), 0b1_0000_0011);
+        assert_eq!(from_value::<GuildMemberFlags>(json!(flags)).unwrap(), flags);
+    }
+
+    #[test]
+    fn test_cache_statistics() {
+        let cache = Cache::new();
+        let guild_id = GuildId::new(1);
+        let user_id = UserId::new(4);
+
+        let mut member = Member::default();
+        member.user.id = user_id;
+        let mut guild_create = GuildCreateEvent {
+            guild: Guild {
+                id: guild_id,
+                members: [(user_id, member)].into_iter().collect(),
+                ..Default::default()
+            },
+        };
+        // Lookups made while updating the cache aren't counted.
+        cache.update(&mut guild_create);
+        assert_eq!(cache.statistics().users.total(), 0);
+
+        assert!(cache.guild(guild_id).is_some());
+        assert!(cache.guild(guild_id).is_some());
+        assert!(cache.guild(GuildId::new(2)).is_none());
+        assert!(cache.user(UserId::new(3)).is_none());
+
+        let statistics = cache.statistics();
+        assert_eq!(statistics.guilds, LookupStatistics {
+            hits: 2,
+            misses: 1,
+        });
+        assert!(statistics.guilds.hit_rate().is_some_and(|rate| (rate - 2.0 / 3.0).abs() < 1e-9));
+        assert_eq!(statistics.users.misses, 1);
+        assert_eq!(statistics.messages.total(), 0);
+        assert_eq!(statistics.messages.hit_rate(), None);
+    }
 }
@@ -36,6 +36,35 @@ pub struct Settings {
     ///
     /// Defaults to true.
     pub cache_users: bool,
+    /// Whether to keep the last known state of guilds that became unavailable, e.g. due to an
+    /// outage, until they become available again. See [`Cache::unavailable_guild`].
+    ///
+    /// Has no effect if [`Self::cache_guilds`] is disabled.
+    ///
+    /// Defaults to true.
+    ///
+    /// [`Cache::unavailable_guild`]: super::Cache::unavailable_guild
+    pub cache_unavailable_guilds: bool,
+    /// Whether to cache auto moderation rules received from gateway.
+    ///
+    /// Discord only sends rules when they are created, updated or deleted, which requires the
+    /// [`AUTO_MODERATION_CONFIGURATION`] intent. Rules that existed before are not cached.
+    ///
+    /// Defaults to false.
+    ///
+    /// [`AUTO_MODERATION_CONFIGURATION`]: crate::model::gateway::GatewayIntents::AUTO_MODERATION_CONFIGURATION
+    pub cache_automod_rules: bool,
+    /// Whether to check the current user's permissions against the cache before making requests
+    /// that require them, e.g. sending a message, returning a [`ModelError::InvalidPermissions`]
+    /// locally instead of making a request that is bound to fail.
+    ///
+    /// Permissions are only checked if the channel, guild and current member are cached. Disable
+    /// this if the cache may be out of date, e.g. when [`Self::cache_guilds`] is disabled.
+    ///
+    /// Defaults to true.
+    ///
+    /// [`ModelError::InvalidPermissions`]: crate::model::ModelError::InvalidPermissions
+    pub precheck_permissions: bool,
 }
 
 impl Default for Settings {
@@ -46,6 +75,9 @@ impl Default for Settings {
             cache_guilds: true,
             cache_channels: true,
             cache_users: true,
+            cache_unavailable_guilds: true,
+            cache_automod_rules: false,
+            precheck_permissions: true,
         }
     }
 }
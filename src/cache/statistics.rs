@@ -0,0 +1,104 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Counts of cache lookups, retrieved via [`Cache::statistics`].
+///
+/// The counts start at zero when the cache is created and only include lookups through the
+/// methods of [`Cache`] named after each kind, such as [`Cache::guild`]. Lookups the cache makes
+/// while handling gateway events are not counted.
+///
+/// [`Cache`]: super::Cache
+/// [`Cache::statistics`]: super::Cache::statistics
+/// [`Cache::guild`]: super::Cache::guild
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct CacheStatistics {
+    /// Lookups of guilds.
+    pub guilds: LookupStatistics,
+    /// Lookups of guild channels.
+    pub channels: LookupStatistics,
+    /// Lookups of guild members.
+    pub members: LookupStatistics,
+    /// Lookups of messages.
+    pub messages: LookupStatistics,
+    /// Lookups of guild roles.
+    pub roles: LookupStatistics,
+    /// Lookups of users.
+    pub users: LookupStatistics,
+}
+
+/// The number of lookups of one kind of data that found or missed their item.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct LookupStatistics {
+    /// The number of lookups that found their item.
+    pub hits: u64,
+    /// The number of lookups that did not find their item.
+    pub misses: u64,
+}
+
+impl LookupStatistics {
+    /// The total number of lookups.
+    #[must_use]
+    pub const fn total(&self) -> u64 {
+        self.hits + self.misses
+    }
+
+    /// The fraction of lookups that found their item, between 0 and 1, or [`None`] if there were
+    /// no lookups.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn hit_rate(&self) -> Option<f64> {
+        let total = self.total();
+        (total > 0).then(|| self.hits as f64 / total as f64)
+    }
+}
+
+/// Hit and miss counters of one kind of lookup. Relaxed atomics are enough, as the counters are
+/// independent of each other and of the cached data.
+#[derive(Debug, Default)]
+pub(crate) struct LookupCounters {
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl LookupCounters {
+    /// Counts the result of a lookup and passes it through.
+    pub(crate) fn record<T>(&self, found: Option<T>) -> Option<T> {
+        let counter = if found.is_some() { &self.hits } else { &self.misses };
+        counter.fetch_add(1, Ordering::Relaxed);
+        found
+    }
+
+    fn get(&self) -> LookupStatistics {
+        LookupStatistics {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct StatisticsCounters {
+    pub(crate) guilds: LookupCounters,
+    pub(crate) channels: LookupCounters,
+    pub(crate) members: LookupCounters,
+    pub(crate) messages: LookupCounters,
+    pub(crate) roles: LookupCounters,
+    pub(crate) users: LookupCounters,
+}
+
+#[cfg(feature = "typesize")]
+impl typesize::TypeSize for StatisticsCounters {}
+
+impl StatisticsCounters {
+    pub(crate) fn get(&self) -> CacheStatistics {
+        CacheStatistics {
+            guilds: self.guilds.get(),
+            channels: self.channels.get(),
+            members: self.members.get(),
+            messages: self.messages.get(),
+            roles: self.roles.get(),
+            users: self.users.get(),
+        }
+    }
+}
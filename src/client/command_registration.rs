@@ -0,0 +1,217 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use tracing::warn;
+
+use super::{Context, EventHandler, FullEvent};
+use crate::builder::CreateCommand;
+use crate::model::application::Command;
+use crate::model::gateway::Ready;
+use crate::model::id::GuildId;
+
+/// Where the commands set via [`ClientBuilder::register_commands`] are registered.
+///
+/// [`ClientBuilder::register_commands`]: super::ClientBuilder::register_commands
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum CommandRegisterMode {
+    /// Registers the commands globally, in every guild and DM.
+    Global,
+    /// Registers the commands in each of the given guilds. Guild commands update instantly, which
+    /// makes this useful during development.
+    Guilds(Vec<GuildId>),
+}
+
+/// The outcome of registering the commands set via [`ClientBuilder::register_commands`], passed
+/// to [`EventHandler::commands_registered`].
+///
+/// Targets are either a guild, or [`None`] for global commands.
+///
+/// [`ClientBuilder::register_commands`]: super::ClientBuilder::register_commands
+#[derive(Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct CommandRegistrationSummary {
+    /// The commands as registered by Discord, per target.
+    pub registered: Vec<(Option<GuildId>, Vec<Command>)>,
+    /// The targets registering failed for, with the error that occurred.
+    pub failed: Vec<(Option<GuildId>, String)>,
+}
+
+/// An internal event handler that registers commands on the first [`Ready`] received by any
+/// shard.
+pub(crate) struct CommandRegistrar {
+    commands: Vec<CreateCommand>,
+    mode: CommandRegisterMode,
+    registered: AtomicBool,
+    event_handlers: Vec<Arc<dyn EventHandler>>,
+}
+
+impl CommandRegistrar {
+    pub(crate) fn new(
+        commands: Vec<CreateCommand>,
+        mode: CommandRegisterMode,
+        event_handlers: Vec<Arc<dyn EventHandler>>,
+    ) -> Self {
+        Self {
+            commands,
+            mode,
+            registered: AtomicBool::new(false),
+            event_handlers,
+        }
+    }
+
+    /// Returns `true` only the first time it is called, so that commands are not registered
+    /// again when shards reconnect or are restarted.
+    fn claim(&self) -> bool {
+        !self.registered.swap(true, Ordering::AcqRel)
+    }
+
+    async fn register(&self, ctx: &Context) -> CommandRegistrationSummary {
+        let mut summary = CommandRegistrationSummary::default();
+
+        let targets = match &self.mode {
+            CommandRegisterMode::Global => vec![None],
+            CommandRegisterMode::Guilds(guild_ids) => guild_ids.iter().copied().map(Some).collect(),
+        };
+        for target in targets {
+            let result = match target {
+                Some(guild_id) => guild_id.set_commands(&ctx.http, self.commands.clone()).await,
+                None => Command::set_global_commands(&ctx.http, self.commands.clone()).await,
+            };
+
+            match result {
+                Ok(commands) => summary.registered.push((target, commands)),
+                Err(why) => {
+                    warn!("Failed to register commands for {target:?}: {why}");
+                    summary.failed.push((target, why.to_string()));
+                },
+            }
+        }
+
+        summary
+    }
+}
+
+#[async_trait]
+impl EventHandler for CommandRegistrar {
+    async fn ready(&self, ctx: Context, _: Ready) {
+        if !self.claim() {
+            return;
+        }
+
+        let summary = self.register(&ctx).await;
+        for handler in &self.event_handlers {
+            let event = FullEvent::CommandsRegistered {
+                summary: summary.clone(),
+            };
+            event.dispatch(ctx.clone(), &**handler).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registers_once() {
+        let registrar = CommandRegistrar::new(vec![], CommandRegisterMode::Global, vec![]);
+
+        assert!(registrar.claim());
+        assert!(!registrar.claim());
+        assert!(!registrar.claim());
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "testing")]
+    async fn registers_once_across_shard_restarts() {
+        use std::sync::Mutex;
+
+        use tokio::sync::RwLock;
+        use typemap_rev::TypeMap;
+
+        use crate::gateway::ShardMessenger;
+        use crate::http::testing::MockTransport;
+        use crate::http::{Http, HttpBuilder, LightMethod, Route};
+        use crate::json::{from_value, json};
+        use crate::model::id::{ApplicationId, ShardId};
+
+        #[derive(Default)]
+        struct Handler {
+            summaries: Mutex<Vec<CommandRegistrationSummary>>,
+        }
+
+        #[async_trait]
+        impl EventHandler for Handler {
+            async fn commands_registered(&self, _: Context, summary: CommandRegistrationSummary) {
+                self.summaries.lock().unwrap().push(summary);
+            }
+        }
+
+        fn context(http: Arc<Http>, shard_id: u32) -> Context {
+            Context {
+                data: Arc::new(RwLock::new(TypeMap::new())),
+                shard: ShardMessenger {
+                    tx: futures::channel::mpsc::unbounded().0,
+                    #[cfg(feature = "collector")]
+                    collectors: Arc::new(Mutex::new(Vec::new())),
+                },
+                shard_id: ShardId(shard_id),
+                http,
+                #[cfg(feature = "cache")]
+                cache: Arc::new(crate::cache::Cache::new()),
+            }
+        }
+
+        fn ready(shard_id: u32) -> Ready {
+            from_value(json!({
+                "v": 10,
+                "user": {"id": "2", "username": "bot", "discriminator": "0000", "bot": true},
+                "guilds": [],
+                "session_id": format!("session-{shard_id}"),
+                "resume_gateway_url": "wss://gateway.discord.gg",
+                "shard": [shard_id, 2],
+                "application": {"id": "1", "flags": 0},
+            }))
+            .unwrap()
+        }
+
+        let mock = Arc::new(MockTransport::new());
+        let http = Arc::new(
+            HttpBuilder::new("token")
+                .application_id(ApplicationId::new(1))
+                .transport(mock.clone())
+                .build(),
+        );
+        mock.push_json(json!([]));
+
+        let handler = Arc::new(Handler::default());
+        let registrar = CommandRegistrar::new(
+            vec![CreateCommand::new("ping").description("Pong!")],
+            CommandRegisterMode::Global,
+            vec![handler.clone()],
+        );
+
+        // Both shards become ready, then shard 0 is restarted and identifies again.
+        for shard_id in [0, 1, 0] {
+            registrar.ready(context(Arc::clone(&http), shard_id), ready(shard_id)).await;
+        }
+
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, LightMethod::Put);
+        assert_eq!(
+            requests[0].path,
+            Route::Commands {
+                application_id: ApplicationId::new(1)
+            }
+            .path()
+        );
+
+        let summaries = handler.summaries.lock().unwrap();
+        assert_eq!(summaries.len(), 1);
+        assert_eq!(summaries[0].registered.len(), 1);
+        assert!(summaries[0].failed.is_empty());
+    }
+}
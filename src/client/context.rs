@@ -260,8 +260,37 @@ impl Context {
         self.shard.set_activity(activity);
     }
 
+    /// Sets multiple activities at once, such as a custom status alongside a game.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::prelude::*;
+    /// # use serenity::model::gateway::Ready;
+    /// # struct Handler;
+    ///
+    /// use serenity::gateway::ActivityData;
+    ///
+    /// #[serenity::async_trait]
+    /// impl EventHandler for Handler {
+    ///     async fn ready(&self, ctx: Context, _: Ready) {
+    ///         ctx.set_activities([
+    ///             ActivityData::custom("doing things 🦀"),
+    ///             ActivityData::playing("Heroes of the Storm"),
+    ///         ]);
+    ///     }
+    /// }
+    /// ```
+    #[cfg(feature = "gateway")]
+    #[inline]
+    pub fn set_activities(&self, activities: impl IntoIterator<Item = ActivityData>) {
+        self.shard.set_activities(activities);
+    }
+
     /// Sets the current user's presence, providing all fields to be passed.
     ///
+    /// Accepts either an [`Option`] for a single activity, or a collection of activities.
+    ///
     /// # Examples
     ///
     /// Setting the current user as having no activity and being [`Idle`]:
@@ -306,8 +335,12 @@ impl Context {
     /// [`Idle`]: OnlineStatus::Idle
     #[cfg(feature = "gateway")]
     #[inline]
-    pub fn set_presence(&self, activity: Option<ActivityData>, status: OnlineStatus) {
-        self.shard.set_presence(activity, status);
+    pub fn set_presence(
+        &self,
+        activities: impl IntoIterator<Item = ActivityData>,
+        status: OnlineStatus,
+    ) {
+        self.shard.set_presence(activities, status);
     }
 
     /// Gets all emojis for the current application.
@@ -1,3 +1,7 @@
+// Without the cache, the event data isn't updated and the dispatch state is only partly used.
+#![cfg_attr(not(feature = "cache"), allow(unused_mut, unused_variables))]
+
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use tracing::debug;
@@ -11,9 +15,8 @@ use crate::cache::{Cache, CacheUpdate};
 use crate::framework::Framework;
 use crate::internal::tokio::spawn_named;
 use crate::model::channel::ChannelType;
-use crate::model::event::Event;
+use crate::model::event::*;
 use crate::model::guild::Member;
-#[cfg(feature = "cache")]
 use crate::model::id::GuildId;
 
 #[cfg(feature = "cache")]
@@ -32,14 +35,56 @@ macro_rules! if_cache {
 
 #[cfg(feature = "cache")]
 macro_rules! update_cache {
-    ($cache:ident, $event:ident) => {
+    ($cache:expr, $event:expr) => {
         $event.update($cache)
     };
 }
 
 #[cfg(not(feature = "cache"))]
 macro_rules! update_cache {
-    ($cache:ident, $event:ident) => {};
+    ($cache:expr, $event:expr) => {};
+}
+
+/// The guilds of a shard which are sent in a `GUILD_CREATE` without having been newly joined.
+///
+/// These are the guilds received in the `READY` event, as well as guilds which became unavailable
+/// during the session. This is tracked per shard, independently of the cache.
+#[derive(Debug, Default)]
+pub(crate) struct PendingGuilds(HashSet<GuildId>);
+
+impl PendingGuilds {
+    fn ready(&mut self, guilds: impl IntoIterator<Item = GuildId>) {
+        self.0 = guilds.into_iter().collect();
+    }
+
+    fn delete(&mut self, guild_id: GuildId, unavailable: bool) {
+        if unavailable {
+            self.0.insert(guild_id);
+        } else {
+            self.0.remove(&guild_id);
+        }
+    }
+
+    /// Returns whether the guild of a `GUILD_CREATE` was newly joined.
+    fn create(&mut self, guild_id: GuildId) -> bool {
+        !self.0.remove(&guild_id)
+    }
+}
+
+/// The state shared by the [`IntoFullEvent`] implementations while dispatching an event.
+pub(crate) struct DispatchState<'a> {
+    #[cfg(feature = "cache")]
+    cache: &'a Cache,
+    pending_guilds: &'a mut PendingGuilds,
+    /// Secondary events to dispatch after the event itself.
+    extra_events: Vec<FullEvent>,
+}
+
+/// Updates the cache with the data of an [`Event`] and builds the [`FullEvent`] out of it.
+///
+/// Implemented for the data of every [`Event`] variant, which [`Event::dispatch`] calls into.
+pub(crate) trait IntoFullEvent {
+    fn into_full_event(self, state: &mut DispatchState<'_>) -> FullEvent;
 }
 
 pub(crate) fn dispatch_model(
@@ -48,6 +93,7 @@ pub(crate) fn dispatch_model(
     #[cfg(feature = "framework")] framework: Option<Arc<dyn Framework>>,
     event_handlers: Vec<Arc<dyn EventHandler>>,
     raw_event_handlers: Vec<Arc<dyn RawEventHandler>>,
+    pending_guilds: &mut PendingGuilds,
 ) {
     for raw_handler in raw_event_handlers {
         let (context, event) = (context.clone(), event.clone());
@@ -58,6 +104,7 @@ pub(crate) fn dispatch_model(
         #[cfg(feature = "cache")]
         &context.cache,
         event,
+        pending_guilds,
     );
 
     if let Some(events) = full_events {
@@ -87,411 +134,925 @@ pub(crate) fn dispatch_model(
 
 /// Updates the cache with the incoming event data and builds the full event data out of it.
 ///
-/// Can return a secondary [`FullEvent`] for "virtual" events like [`FullEvent::CacheReady`] or
-/// [`FullEvent::ShardsReady`]. Secondary events are traditionally dispatched first.
+/// Can return secondary [`FullEvent`]s for "virtual" events like [`FullEvent::CacheReady`],
+/// [`FullEvent::ShardsReady`] or [`FullEvent::GuildJoin`].
 ///
 /// Can return `None` if an event is unknown.
-#[cfg_attr(not(feature = "cache"), allow(unused_mut))]
 fn update_cache_with_event(
     #[cfg(feature = "cache")] cache: &Cache,
     event: Event,
-) -> Option<(FullEvent, Option<FullEvent>)> {
-    let mut extra_event = None;
-    let event = match event {
-        Event::CommandPermissionsUpdate(event) => FullEvent::CommandPermissionsUpdate {
-            permission: event.permission,
-        },
-        Event::AutoModRuleCreate(event) => FullEvent::AutoModRuleCreate {
-            rule: event.rule,
-        },
-        Event::AutoModRuleUpdate(event) => FullEvent::AutoModRuleUpdate {
-            rule: event.rule,
-        },
-        Event::AutoModRuleDelete(event) => FullEvent::AutoModRuleDelete {
-            rule: event.rule,
-        },
-        Event::AutoModActionExecution(event) => FullEvent::AutoModActionExecution {
-            execution: event.execution,
+    pending_guilds: &mut PendingGuilds,
+) -> Option<(FullEvent, Vec<FullEvent>)> {
+    let mut state = DispatchState {
+        #[cfg(feature = "cache")]
+        cache,
+        pending_guilds,
+        extra_events: Vec::new(),
+    };
+
+    match event.dispatch(&mut state) {
+        Ok(event) => Some((event, state.extra_events)),
+        Err(event) => {
+            debug!("An unknown event was received: {event:?}");
+            None
         },
-        Event::ChannelCreate(mut event) => {
-            update_cache!(cache, event);
-
-            let channel = event.channel;
-            if channel.kind == ChannelType::Category {
-                FullEvent::CategoryCreate {
-                    category: channel,
-                }
-            } else {
-                FullEvent::ChannelCreate {
-                    channel,
-                }
+    }
+}
+
+impl IntoFullEvent for CommandPermissionsUpdateEvent {
+    fn into_full_event(self, _state: &mut DispatchState<'_>) -> FullEvent {
+        FullEvent::CommandPermissionsUpdate {
+            permission: self.permission,
+        }
+    }
+}
+
+impl IntoFullEvent for AutoModRuleCreateEvent {
+    fn into_full_event(mut self, state: &mut DispatchState<'_>) -> FullEvent {
+        update_cache!(state.cache, self);
+
+        FullEvent::AutoModRuleCreate {
+            rule: self.rule,
+        }
+    }
+}
+
+impl IntoFullEvent for AutoModRuleUpdateEvent {
+    fn into_full_event(mut self, state: &mut DispatchState<'_>) -> FullEvent {
+        update_cache!(state.cache, self);
+
+        FullEvent::AutoModRuleUpdate {
+            rule: self.rule,
+        }
+    }
+}
+
+impl IntoFullEvent for AutoModRuleDeleteEvent {
+    fn into_full_event(mut self, state: &mut DispatchState<'_>) -> FullEvent {
+        update_cache!(state.cache, self);
+
+        FullEvent::AutoModRuleDelete {
+            rule: self.rule,
+        }
+    }
+}
+
+impl IntoFullEvent for AutoModActionExecutionEvent {
+    fn into_full_event(self, _state: &mut DispatchState<'_>) -> FullEvent {
+        FullEvent::AutoModActionExecution {
+            execution: self.execution,
+        }
+    }
+}
+
+impl IntoFullEvent for ChannelCreateEvent {
+    fn into_full_event(mut self, state: &mut DispatchState<'_>) -> FullEvent {
+        update_cache!(state.cache, self);
+
+        let channel = self.channel;
+        if channel.kind == ChannelType::Category {
+            FullEvent::CategoryCreate {
+                category: channel,
             }
-        },
-        Event::ChannelDelete(mut event) => {
-            let cached_messages = if_cache!(event.update(cache));
-
-            let channel = event.channel;
-            if channel.kind == ChannelType::Category {
-                FullEvent::CategoryDelete {
-                    category: channel,
-                }
-            } else {
-                FullEvent::ChannelDelete {
-                    channel,
-                    messages: cached_messages,
-                }
+        } else {
+            FullEvent::ChannelCreate {
+                channel,
             }
-        },
-        Event::ChannelPinsUpdate(event) => FullEvent::ChannelPinsUpdate {
-            pin: event,
-        },
-        Event::ChannelUpdate(mut event) => {
-            let old_channel = if_cache!(event.update(cache));
+        }
+    }
+}
+
+impl IntoFullEvent for ChannelDeleteEvent {
+    fn into_full_event(mut self, state: &mut DispatchState<'_>) -> FullEvent {
+        let cached_messages = if_cache!(self.update(state.cache));
 
-            FullEvent::ChannelUpdate {
-                old: old_channel,
-                new: event.channel,
+        let channel = self.channel;
+        if channel.kind == ChannelType::Category {
+            FullEvent::CategoryDelete {
+                category: channel,
             }
-        },
-        Event::GuildAuditLogEntryCreate(event) => FullEvent::GuildAuditLogEntryCreate {
-            entry: event.entry,
-            guild_id: event.guild_id,
-        },
-        Event::GuildBanAdd(event) => FullEvent::GuildBanAddition {
-            guild_id: event.guild_id,
-            banned_user: event.user,
-        },
-        Event::GuildBanRemove(event) => FullEvent::GuildBanRemoval {
-            guild_id: event.guild_id,
-            unbanned_user: event.user,
-        },
-        Event::GuildCreate(mut event) => {
-            let is_new = if_cache!(Some(!cache.unavailable_guilds.contains(&event.guild.id)));
+        } else {
+            FullEvent::ChannelDelete {
+                channel,
+                messages: cached_messages,
+            }
+        }
+    }
+}
 
-            update_cache!(cache, event);
+impl IntoFullEvent for ChannelPinsUpdateEvent {
+    fn into_full_event(self, _state: &mut DispatchState<'_>) -> FullEvent {
+        FullEvent::ChannelPinsUpdate {
+            pin: self,
+        }
+    }
+}
 
-            #[cfg(feature = "cache")]
-            {
-                if cache.unavailable_guilds.len() == 0 {
-                    cache.unavailable_guilds.shrink_to_fit();
+impl IntoFullEvent for ChannelUpdateEvent {
+    fn into_full_event(mut self, state: &mut DispatchState<'_>) -> FullEvent {
+        let old_channel = if_cache!(self.update(state.cache));
 
-                    let guild_amount =
-                        cache.guilds.iter().map(|i| *i.key()).collect::<Vec<GuildId>>();
+        FullEvent::ChannelUpdate {
+            old: old_channel,
+            new: self.channel,
+        }
+    }
+}
 
-                    extra_event = Some(FullEvent::CacheReady {
-                        guilds: guild_amount,
-                    });
-                }
-            }
+impl IntoFullEvent for GuildAuditLogEntryCreateEvent {
+    fn into_full_event(self, _state: &mut DispatchState<'_>) -> FullEvent {
+        FullEvent::GuildAuditLogEntryCreate {
+            entry: self.entry,
+            guild_id: self.guild_id,
+        }
+    }
+}
 
-            FullEvent::GuildCreate {
-                guild: event.guild,
-                is_new,
-            }
-        },
-        Event::GuildDelete(mut event) => {
-            let full = if_cache!(event.update(cache));
+impl IntoFullEvent for GuildBanAddEvent {
+    fn into_full_event(self, _state: &mut DispatchState<'_>) -> FullEvent {
+        FullEvent::GuildBanAddition {
+            guild_id: self.guild_id,
+            banned_user: self.user,
+        }
+    }
+}
 
-            FullEvent::GuildDelete {
-                incomplete: event.guild,
-                full,
-            }
-        },
-        Event::GuildEmojisUpdate(mut event) => {
-            update_cache!(cache, event);
+impl IntoFullEvent for GuildBanRemoveEvent {
+    fn into_full_event(self, _state: &mut DispatchState<'_>) -> FullEvent {
+        FullEvent::GuildBanRemoval {
+            guild_id: self.guild_id,
+            unbanned_user: self.user,
+        }
+    }
+}
 
-            FullEvent::GuildEmojisUpdate {
-                guild_id: event.guild_id,
-                current_state: event.emojis,
-            }
-        },
-        Event::GuildIntegrationsUpdate(event) => FullEvent::GuildIntegrationsUpdate {
-            guild_id: event.guild_id,
-        },
-        Event::GuildMemberAdd(mut event) => {
-            update_cache!(cache, event);
+impl IntoFullEvent for GuildCreateEvent {
+    fn into_full_event(mut self, state: &mut DispatchState<'_>) -> FullEvent {
+        let is_new = state.pending_guilds.create(self.guild.id);
 
-            FullEvent::GuildMemberAddition {
-                new_member: event.member,
-            }
-        },
-        Event::GuildMemberRemove(mut event) => {
-            let member = if_cache!(event.update(cache));
+        update_cache!(state.cache, self);
 
-            FullEvent::GuildMemberRemoval {
-                guild_id: event.guild_id,
-                user: event.user,
-                member_data_if_available: member,
+        // Shared so that the guild is cloned once, rather than again for every handler.
+        let guild = Arc::new(self.guild.clone());
+        state.extra_events.push(if is_new {
+            FullEvent::GuildJoin {
+                guild,
             }
-        },
-        Event::GuildMemberUpdate(mut event) => {
-            let before = if_cache!(event.update(cache));
-            let after: Option<Member> = if_cache!({
-                let guild = cache.guild(event.guild_id);
-                guild.and_then(|g| g.members.get(&event.user.id).cloned())
-            });
-
-            FullEvent::GuildMemberUpdate {
-                old_if_available: before,
-                new: after,
-                event,
+        } else {
+            FullEvent::GuildBackfill {
+                guild,
             }
-        },
-        Event::GuildMembersChunk(mut event) => {
-            update_cache!(cache, event);
+        });
 
-            FullEvent::GuildMembersChunk {
-                chunk: event,
-            }
-        },
-        Event::GuildRoleCreate(mut event) => {
-            update_cache!(cache, event);
+        #[cfg(feature = "cache")]
+        {
+            if state.cache.unavailable_guilds.len() == 0 {
+                state.cache.unavailable_guilds.shrink_to_fit();
 
-            FullEvent::GuildRoleCreate {
-                new: event.role,
-            }
-        },
-        Event::GuildRoleDelete(mut event) => {
-            let role = if_cache!(event.update(cache));
+                let guild_amount =
+                    state.cache.guilds.iter().map(|i| *i.key()).collect::<Vec<GuildId>>();
 
-            FullEvent::GuildRoleDelete {
-                guild_id: event.guild_id,
-                removed_role_id: event.role_id,
-                removed_role_data_if_available: role,
+                state.extra_events.push(FullEvent::CacheReady {
+                    guilds: guild_amount,
+                });
             }
-        },
-        Event::GuildRoleUpdate(mut event) => {
-            let before = if_cache!(event.update(cache));
+        }
 
-            FullEvent::GuildRoleUpdate {
-                old_data_if_available: before,
-                new: event.role,
-            }
-        },
-        Event::GuildStickersUpdate(mut event) => {
-            update_cache!(cache, event);
+        FullEvent::GuildCreate {
+            guild: self.guild,
+            is_new: Some(is_new),
+        }
+    }
+}
 
-            FullEvent::GuildStickersUpdate {
-                guild_id: event.guild_id,
-                current_state: event.stickers,
-            }
-        },
-        Event::GuildUpdate(event) => {
-            let before = if_cache!(cache.guild(event.guild.id).map(|g| g.clone()));
+impl IntoFullEvent for GuildDeleteEvent {
+    fn into_full_event(mut self, state: &mut DispatchState<'_>) -> FullEvent {
+        state.pending_guilds.delete(self.guild.id, self.guild.unavailable);
 
-            FullEvent::GuildUpdate {
-                old_data_if_available: before,
-                new_data: event.guild,
+        let full = if_cache!(self.update(state.cache));
+
+        FullEvent::GuildDelete {
+            incomplete: self.guild,
+            full,
+        }
+    }
+}
+
+impl IntoFullEvent for GuildEmojisUpdateEvent {
+    fn into_full_event(mut self, state: &mut DispatchState<'_>) -> FullEvent {
+        update_cache!(state.cache, self);
+
+        FullEvent::GuildEmojisUpdate {
+            guild_id: self.guild_id,
+            current_state: self.emojis,
+        }
+    }
+}
+
+impl IntoFullEvent for GuildIntegrationsUpdateEvent {
+    fn into_full_event(self, _state: &mut DispatchState<'_>) -> FullEvent {
+        FullEvent::GuildIntegrationsUpdate {
+            guild_id: self.guild_id,
+        }
+    }
+}
+
+impl IntoFullEvent for GuildMemberAddEvent {
+    fn into_full_event(mut self, state: &mut DispatchState<'_>) -> FullEvent {
+        update_cache!(state.cache, self);
+
+        FullEvent::GuildMemberAddition {
+            new_member: self.member,
+        }
+    }
+}
+
+impl IntoFullEvent for GuildMemberRemoveEvent {
+    fn into_full_event(mut self, state: &mut DispatchState<'_>) -> FullEvent {
+        let member = if_cache!(self.update(state.cache));
+
+        FullEvent::GuildMemberRemoval {
+            guild_id: self.guild_id,
+            user: self.user,
+            member_data_if_available: member,
+        }
+    }
+}
+
+impl IntoFullEvent for GuildMemberUpdateEvent {
+    fn into_full_event(mut self, state: &mut DispatchState<'_>) -> FullEvent {
+        let before = if_cache!(self.update(state.cache));
+        let after: Option<Member> = if_cache!({
+            let guild = state.cache.guild(self.guild_id);
+            guild.and_then(|g| g.members.get(&self.user.id).cloned())
+        });
+
+        FullEvent::GuildMemberUpdate {
+            old_if_available: before,
+            new: after,
+            event: self,
+        }
+    }
+}
+
+impl IntoFullEvent for GuildMembersChunkEvent {
+    fn into_full_event(mut self, state: &mut DispatchState<'_>) -> FullEvent {
+        update_cache!(state.cache, self);
+
+        FullEvent::GuildMembersChunk {
+            chunk: self,
+        }
+    }
+}
+
+impl IntoFullEvent for GuildRoleCreateEvent {
+    fn into_full_event(mut self, state: &mut DispatchState<'_>) -> FullEvent {
+        update_cache!(state.cache, self);
+
+        FullEvent::GuildRoleCreate {
+            new: self.role,
+        }
+    }
+}
+
+impl IntoFullEvent for GuildRoleDeleteEvent {
+    fn into_full_event(mut self, state: &mut DispatchState<'_>) -> FullEvent {
+        let role = if_cache!(self.update(state.cache));
+
+        FullEvent::GuildRoleDelete {
+            guild_id: self.guild_id,
+            removed_role_id: self.role_id,
+            removed_role_data_if_available: role,
+        }
+    }
+}
+
+impl IntoFullEvent for GuildRoleUpdateEvent {
+    fn into_full_event(mut self, state: &mut DispatchState<'_>) -> FullEvent {
+        let before = if_cache!(self.update(state.cache));
+
+        FullEvent::GuildRoleUpdate {
+            old_data_if_available: before,
+            new: self.role,
+        }
+    }
+}
+
+impl IntoFullEvent for GuildSoundboardSoundCreateEvent {
+    fn into_full_event(self, _state: &mut DispatchState<'_>) -> FullEvent {
+        FullEvent::GuildSoundboardSoundCreate {
+            sound: self.sound,
+        }
+    }
+}
+
+impl IntoFullEvent for GuildSoundboardSoundUpdateEvent {
+    fn into_full_event(self, _state: &mut DispatchState<'_>) -> FullEvent {
+        FullEvent::GuildSoundboardSoundUpdate {
+            sound: self.sound,
+        }
+    }
+}
+
+impl IntoFullEvent for GuildSoundboardSoundDeleteEvent {
+    fn into_full_event(self, _state: &mut DispatchState<'_>) -> FullEvent {
+        FullEvent::GuildSoundboardSoundDelete {
+            guild_id: self.guild_id,
+            sound_id: self.sound_id,
+        }
+    }
+}
+
+impl IntoFullEvent for GuildSoundboardSoundsUpdateEvent {
+    fn into_full_event(self, _state: &mut DispatchState<'_>) -> FullEvent {
+        FullEvent::GuildSoundboardSoundsUpdate {
+            guild_id: self.guild_id,
+            sounds: self.soundboard_sounds,
+        }
+    }
+}
+
+impl IntoFullEvent for GuildStickersUpdateEvent {
+    fn into_full_event(mut self, state: &mut DispatchState<'_>) -> FullEvent {
+        update_cache!(state.cache, self);
+
+        FullEvent::GuildStickersUpdate {
+            guild_id: self.guild_id,
+            current_state: self.stickers,
+        }
+    }
+}
+
+impl IntoFullEvent for GuildUpdateEvent {
+    fn into_full_event(self, state: &mut DispatchState<'_>) -> FullEvent {
+        let before = if_cache!(state.cache.guild(self.guild.id).map(|g| g.clone()));
+
+        FullEvent::GuildUpdate {
+            old_data_if_available: before,
+            new_data: self.guild,
+        }
+    }
+}
+
+impl IntoFullEvent for InviteCreateEvent {
+    fn into_full_event(self, _state: &mut DispatchState<'_>) -> FullEvent {
+        FullEvent::InviteCreate {
+            data: self,
+        }
+    }
+}
+
+impl IntoFullEvent for InviteDeleteEvent {
+    fn into_full_event(self, _state: &mut DispatchState<'_>) -> FullEvent {
+        FullEvent::InviteDelete {
+            data: self,
+        }
+    }
+}
+
+impl IntoFullEvent for MessageCreateEvent {
+    fn into_full_event(mut self, state: &mut DispatchState<'_>) -> FullEvent {
+        update_cache!(state.cache, self);
+
+        FullEvent::Message {
+            new_message: self.message,
+        }
+    }
+}
+
+impl IntoFullEvent for MessageDeleteBulkEvent {
+    fn into_full_event(self, _state: &mut DispatchState<'_>) -> FullEvent {
+        FullEvent::MessageDeleteBulk {
+            channel_id: self.channel_id,
+            multiple_deleted_messages_ids: self.ids,
+            guild_id: self.guild_id,
+        }
+    }
+}
+
+impl IntoFullEvent for MessageDeleteEvent {
+    fn into_full_event(self, _state: &mut DispatchState<'_>) -> FullEvent {
+        FullEvent::MessageDelete {
+            channel_id: self.channel_id,
+            deleted_message_id: self.message_id,
+            guild_id: self.guild_id,
+        }
+    }
+}
+
+impl IntoFullEvent for MessageUpdateEvent {
+    fn into_full_event(mut self, state: &mut DispatchState<'_>) -> FullEvent {
+        let before = if_cache!(self.update(state.cache));
+        let after = if_cache!(state.cache.message(self.channel_id, self.id).map(|m| m.clone()));
+
+        FullEvent::MessageUpdate {
+            old_if_available: before,
+            new: after,
+            event: self,
+        }
+    }
+}
+
+#[allow(deprecated)]
+impl IntoFullEvent for PresencesReplaceEvent {
+    fn into_full_event(self, _state: &mut DispatchState<'_>) -> FullEvent {
+        FullEvent::PresenceReplace {
+            presences: self.presences,
+        }
+    }
+}
+
+impl IntoFullEvent for PresenceUpdateEvent {
+    fn into_full_event(mut self, state: &mut DispatchState<'_>) -> FullEvent {
+        update_cache!(state.cache, self);
+
+        FullEvent::PresenceUpdate {
+            new_data: self.presence,
+        }
+    }
+}
+
+impl IntoFullEvent for ReactionAddEvent {
+    fn into_full_event(self, _state: &mut DispatchState<'_>) -> FullEvent {
+        FullEvent::ReactionAdd {
+            add_reaction: self.reaction,
+        }
+    }
+}
+
+impl IntoFullEvent for ReactionRemoveEvent {
+    fn into_full_event(self, _state: &mut DispatchState<'_>) -> FullEvent {
+        FullEvent::ReactionRemove {
+            removed_reaction: self.reaction,
+        }
+    }
+}
+
+impl IntoFullEvent for ReactionRemoveAllEvent {
+    fn into_full_event(self, _state: &mut DispatchState<'_>) -> FullEvent {
+        FullEvent::ReactionRemoveAll {
+            channel_id: self.channel_id,
+            removed_from_message_id: self.message_id,
+        }
+    }
+}
+
+impl IntoFullEvent for ReactionRemoveEmojiEvent {
+    fn into_full_event(self, _state: &mut DispatchState<'_>) -> FullEvent {
+        FullEvent::ReactionRemoveEmoji {
+            removed_reactions: self.reaction,
+        }
+    }
+}
+
+impl IntoFullEvent for ReadyEvent {
+    fn into_full_event(mut self, state: &mut DispatchState<'_>) -> FullEvent {
+        state.pending_guilds.ready(self.ready.guilds.iter().map(|guild| guild.id));
+
+        update_cache!(state.cache, self);
+
+        #[cfg(feature = "cache")]
+        {
+            let mut shards = state.cache.shard_data.write();
+            if shards.connected.len() as u32 == shards.total && !shards.has_sent_shards_ready {
+                shards.has_sent_shards_ready = true;
+                let total = shards.total;
+                drop(shards);
+
+                state.extra_events.push(FullEvent::ShardsReady {
+                    total_shards: total,
+                });
             }
-        },
-        Event::InviteCreate(event) => FullEvent::InviteCreate {
-            data: event,
-        },
-        Event::InviteDelete(event) => FullEvent::InviteDelete {
-            data: event,
-        },
-        Event::MessageCreate(mut event) => {
-            update_cache!(cache, event);
+        }
+
+        FullEvent::Ready {
+            data_about_bot: self.ready,
+        }
+    }
+}
+
+impl IntoFullEvent for ResumedEvent {
+    fn into_full_event(self, _state: &mut DispatchState<'_>) -> FullEvent {
+        FullEvent::Resume {
+            event: self,
+        }
+    }
+}
+
+impl IntoFullEvent for TypingStartEvent {
+    fn into_full_event(self, _state: &mut DispatchState<'_>) -> FullEvent {
+        FullEvent::TypingStart {
+            event: self,
+        }
+    }
+}
+
+impl IntoFullEvent for UserUpdateEvent {
+    fn into_full_event(mut self, state: &mut DispatchState<'_>) -> FullEvent {
+        let before = if_cache!(self.update(state.cache));
+
+        FullEvent::UserUpdate {
+            old_data: before,
+            new: self.current_user,
+        }
+    }
+}
+
+impl IntoFullEvent for VoiceServerUpdateEvent {
+    fn into_full_event(self, _state: &mut DispatchState<'_>) -> FullEvent {
+        FullEvent::VoiceServerUpdate {
+            event: self,
+        }
+    }
+}
+
+impl IntoFullEvent for VoiceStateUpdateEvent {
+    fn into_full_event(mut self, state: &mut DispatchState<'_>) -> FullEvent {
+        let before = if_cache!(self.update(state.cache));
+
+        FullEvent::VoiceStateUpdate {
+            old: before,
+            new: self.voice_state,
+        }
+    }
+}
+
+impl IntoFullEvent for VoiceChannelStatusUpdateEvent {
+    fn into_full_event(mut self, state: &mut DispatchState<'_>) -> FullEvent {
+        let old = if_cache!(self.update(state.cache));
+
+        FullEvent::VoiceChannelStatusUpdate {
+            old,
+            status: self.status,
+            id: self.id,
+            guild_id: self.guild_id,
+        }
+    }
+}
+
+impl IntoFullEvent for WebhookUpdateEvent {
+    fn into_full_event(self, _state: &mut DispatchState<'_>) -> FullEvent {
+        FullEvent::WebhookUpdate {
+            guild_id: self.guild_id,
+            belongs_to_channel_id: self.channel_id,
+        }
+    }
+}
+
+impl IntoFullEvent for InteractionCreateEvent {
+    fn into_full_event(self, _state: &mut DispatchState<'_>) -> FullEvent {
+        FullEvent::InteractionCreate {
+            interaction: self.interaction,
+        }
+    }
+}
+
+impl IntoFullEvent for IntegrationCreateEvent {
+    fn into_full_event(self, _state: &mut DispatchState<'_>) -> FullEvent {
+        FullEvent::IntegrationCreate {
+            integration: self.integration,
+        }
+    }
+}
+
+impl IntoFullEvent for IntegrationUpdateEvent {
+    fn into_full_event(self, _state: &mut DispatchState<'_>) -> FullEvent {
+        FullEvent::IntegrationUpdate {
+            integration: self.integration,
+        }
+    }
+}
+
+impl IntoFullEvent for IntegrationDeleteEvent {
+    fn into_full_event(self, _state: &mut DispatchState<'_>) -> FullEvent {
+        FullEvent::IntegrationDelete {
+            integration_id: self.id,
+            guild_id: self.guild_id,
+            application_id: self.application_id,
+        }
+    }
+}
+
+impl IntoFullEvent for StageInstanceCreateEvent {
+    fn into_full_event(self, _state: &mut DispatchState<'_>) -> FullEvent {
+        FullEvent::StageInstanceCreate {
+            stage_instance: self.stage_instance,
+        }
+    }
+}
+
+impl IntoFullEvent for StageInstanceUpdateEvent {
+    fn into_full_event(self, _state: &mut DispatchState<'_>) -> FullEvent {
+        FullEvent::StageInstanceUpdate {
+            stage_instance: self.stage_instance,
+        }
+    }
+}
+
+impl IntoFullEvent for StageInstanceDeleteEvent {
+    fn into_full_event(self, _state: &mut DispatchState<'_>) -> FullEvent {
+        FullEvent::StageInstanceDelete {
+            stage_instance: self.stage_instance,
+        }
+    }
+}
+
+impl IntoFullEvent for ThreadCreateEvent {
+    fn into_full_event(mut self, state: &mut DispatchState<'_>) -> FullEvent {
+        update_cache!(state.cache, self);
+
+        FullEvent::ThreadCreate {
+            thread: self.thread,
+        }
+    }
+}
+
+impl IntoFullEvent for ThreadUpdateEvent {
+    fn into_full_event(mut self, state: &mut DispatchState<'_>) -> FullEvent {
+        let old = if_cache!(self.update(state.cache));
+
+        FullEvent::ThreadUpdate {
+            old,
+            new: self.thread,
+        }
+    }
+}
+
+impl IntoFullEvent for ThreadDeleteEvent {
+    fn into_full_event(mut self, state: &mut DispatchState<'_>) -> FullEvent {
+        let full_thread_data = if_cache!(self.update(state.cache));
+
+        FullEvent::ThreadDelete {
+            thread: self.thread,
+            full_thread_data,
+        }
+    }
+}
+
+impl IntoFullEvent for ThreadListSyncEvent {
+    fn into_full_event(mut self, state: &mut DispatchState<'_>) -> FullEvent {
+        update_cache!(state.cache, self);
+
+        FullEvent::ThreadListSync {
+            thread_list_sync: self,
+        }
+    }
+}
+
+impl IntoFullEvent for ThreadMemberUpdateEvent {
+    fn into_full_event(mut self, state: &mut DispatchState<'_>) -> FullEvent {
+        update_cache!(state.cache, self);
+
+        FullEvent::ThreadMemberUpdate {
+            thread_member: self.member,
+        }
+    }
+}
+
+impl IntoFullEvent for ThreadMembersUpdateEvent {
+    fn into_full_event(mut self, state: &mut DispatchState<'_>) -> FullEvent {
+        update_cache!(state.cache, self);
+
+        FullEvent::ThreadMembersUpdate {
+            thread_members_update: self,
+        }
+    }
+}
+
+impl IntoFullEvent for GuildScheduledEventCreateEvent {
+    fn into_full_event(self, _state: &mut DispatchState<'_>) -> FullEvent {
+        FullEvent::GuildScheduledEventCreate {
+            event: self.event,
+        }
+    }
+}
+
+impl IntoFullEvent for GuildScheduledEventUpdateEvent {
+    fn into_full_event(self, _state: &mut DispatchState<'_>) -> FullEvent {
+        FullEvent::GuildScheduledEventUpdate {
+            event: self.event,
+        }
+    }
+}
+
+impl IntoFullEvent for GuildScheduledEventDeleteEvent {
+    fn into_full_event(self, _state: &mut DispatchState<'_>) -> FullEvent {
+        FullEvent::GuildScheduledEventDelete {
+            event: self.event,
+        }
+    }
+}
+
+impl IntoFullEvent for GuildScheduledEventUserAddEvent {
+    fn into_full_event(self, _state: &mut DispatchState<'_>) -> FullEvent {
+        FullEvent::GuildScheduledEventUserAdd {
+            subscribed: self,
+        }
+    }
+}
+
+impl IntoFullEvent for GuildScheduledEventUserRemoveEvent {
+    fn into_full_event(self, _state: &mut DispatchState<'_>) -> FullEvent {
+        FullEvent::GuildScheduledEventUserRemove {
+            unsubscribed: self,
+        }
+    }
+}
+
+impl IntoFullEvent for EntitlementCreateEvent {
+    fn into_full_event(self, _state: &mut DispatchState<'_>) -> FullEvent {
+        FullEvent::EntitlementCreate {
+            entitlement: self.entitlement,
+        }
+    }
+}
+
+impl IntoFullEvent for EntitlementUpdateEvent {
+    fn into_full_event(self, _state: &mut DispatchState<'_>) -> FullEvent {
+        FullEvent::EntitlementUpdate {
+            entitlement: self.entitlement,
+        }
+    }
+}
+
+impl IntoFullEvent for EntitlementDeleteEvent {
+    fn into_full_event(self, _state: &mut DispatchState<'_>) -> FullEvent {
+        FullEvent::EntitlementDelete {
+            entitlement: self.entitlement,
+        }
+    }
+}
+
+impl IntoFullEvent for MessagePollVoteAddEvent {
+    fn into_full_event(self, _state: &mut DispatchState<'_>) -> FullEvent {
+        FullEvent::MessagePollVoteAdd {
+            event: self,
+        }
+    }
+}
+
+impl IntoFullEvent for MessagePollVoteRemoveEvent {
+    fn into_full_event(self, _state: &mut DispatchState<'_>) -> FullEvent {
+        FullEvent::MessagePollVoteRemove {
+            event: self,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json::{from_value, json};
+    use crate::model::event::{GuildCreateEvent, GuildDeleteEvent};
+    use crate::model::guild::{Guild, UnavailableGuild};
 
-            FullEvent::Message {
-                new_message: event.message,
+    struct Replay {
+        #[cfg(feature = "cache")]
+        cache: Cache,
+        pending_guilds: PendingGuilds,
+    }
+
+    impl Replay {
+        fn new() -> Self {
+            Self {
+                #[cfg(feature = "cache")]
+                cache: Cache::new(),
+                pending_guilds: PendingGuilds::default(),
             }
-        },
-        Event::MessageDeleteBulk(event) => FullEvent::MessageDeleteBulk {
-            channel_id: event.channel_id,
-            multiple_deleted_messages_ids: event.ids,
-            guild_id: event.guild_id,
-        },
-        Event::MessageDelete(event) => FullEvent::MessageDelete {
-            channel_id: event.channel_id,
-            deleted_message_id: event.message_id,
-            guild_id: event.guild_id,
-        },
-        Event::MessageUpdate(mut event) => {
-            let before = if_cache!(event.update(cache));
-            let after = if_cache!(cache.message(event.channel_id, event.id).map(|m| m.clone()));
+        }
 
-            FullEvent::MessageUpdate {
-                old_if_available: before,
-                new: after,
+        fn dispatch(&mut self, event: Event) -> Vec<FullEvent> {
+            let (event, extra_events) = update_cache_with_event(
+                #[cfg(feature = "cache")]
+                &self.cache,
                 event,
-            }
-        },
-        #[allow(deprecated)]
-        Event::PresencesReplace(event) => FullEvent::PresenceReplace {
-            presences: event.presences,
-        },
-        Event::PresenceUpdate(mut event) => {
-            update_cache!(cache, event);
+                &mut self.pending_guilds,
+            )
+            .unwrap();
 
-            FullEvent::PresenceUpdate {
-                new_data: event.presence,
-            }
-        },
-        Event::ReactionAdd(event) => FullEvent::ReactionAdd {
-            add_reaction: event.reaction,
-        },
-        Event::ReactionRemove(event) => FullEvent::ReactionRemove {
-            removed_reaction: event.reaction,
-        },
-        Event::ReactionRemoveAll(event) => FullEvent::ReactionRemoveAll {
-            channel_id: event.channel_id,
-            removed_from_message_id: event.message_id,
-        },
-        Event::ReactionRemoveEmoji(event) => FullEvent::ReactionRemoveEmoji {
-            removed_reactions: event.reaction,
-        },
-        Event::Ready(mut event) => {
-            update_cache!(cache, event);
-
-            #[cfg(feature = "cache")]
-            {
-                let mut shards = cache.shard_data.write();
-                if shards.connected.len() as u32 == shards.total && !shards.has_sent_shards_ready {
-                    shards.has_sent_shards_ready = true;
-                    let total = shards.total;
-                    drop(shards);
-
-                    extra_event = Some(FullEvent::ShardsReady {
-                        total_shards: total,
-                    });
-                }
-            }
+            std::iter::once(event).chain(extra_events).collect()
+        }
 
-            FullEvent::Ready {
-                data_about_bot: event.ready,
-            }
-        },
-        Event::Resumed(event) => FullEvent::Resume {
-            event,
-        },
-        Event::TypingStart(event) => FullEvent::TypingStart {
-            event,
-        },
-        Event::Unknown(event) => {
-            debug!("An unknown event was received: {event:?}");
-            return None;
-        },
-        Event::UserUpdate(mut event) => {
-            let before = if_cache!(event.update(cache));
+        fn guild_create(&mut self, guild_id: u64) -> (Option<bool>, Vec<&'static str>) {
+            let events = self.dispatch(Event::GuildCreate(GuildCreateEvent {
+                guild: Guild {
+                    id: GuildId::new(guild_id),
+                    ..Default::default()
+                },
+            }));
 
-            FullEvent::UserUpdate {
-                old_data: before,
-                new: event.current_user,
-            }
-        },
-        Event::VoiceServerUpdate(event) => FullEvent::VoiceServerUpdate {
-            event,
-        },
-        Event::VoiceStateUpdate(mut event) => {
-            let before = if_cache!(event.update(cache));
+            let is_new = match &events[0] {
+                FullEvent::GuildCreate {
+                    is_new, ..
+                } => *is_new,
+                _ => panic!("expected a guild create event"),
+            };
+            (is_new, events.iter().map(FullEvent::snake_case_name).collect())
+        }
+    }
 
-            FullEvent::VoiceStateUpdate {
-                old: before,
-                new: event.voice_state,
-            }
-        },
-        Event::VoiceChannelStatusUpdate(mut event) => {
-            let old = if_cache!(event.update(cache));
-
-            FullEvent::VoiceChannelStatusUpdate {
-                old,
-                status: event.status,
-                id: event.id,
-                guild_id: event.guild_id,
-            }
-        },
+    fn ready(guild_ids: &[&str]) -> Event {
+        let guilds: Vec<_> =
+            guild_ids.iter().map(|id| json!({"id": id, "unavailable": true})).collect();
+        from_value(json!({
+            "t": "READY",
+            "d": {
+                "v": 10,
+                "user": {"id": "100", "username": "bot", "avatar": null},
+                "guilds": guilds,
+                "session_id": "session",
+                "resume_gateway_url": "wss://gateway.discord.gg",
+                "application": {"id": "100", "flags": 0},
+            },
+        }))
+        .unwrap()
+    }
 
-        Event::WebhookUpdate(event) => FullEvent::WebhookUpdate {
-            guild_id: event.guild_id,
-            belongs_to_channel_id: event.channel_id,
-        },
-        Event::InteractionCreate(event) => FullEvent::InteractionCreate {
-            interaction: event.interaction,
-        },
-        Event::IntegrationCreate(event) => FullEvent::IntegrationCreate {
-            integration: event.integration,
-        },
-        Event::IntegrationUpdate(event) => FullEvent::IntegrationUpdate {
-            integration: event.integration,
-        },
-        Event::IntegrationDelete(event) => FullEvent::IntegrationDelete {
-            integration_id: event.id,
-            guild_id: event.guild_id,
-            application_id: event.application_id,
-        },
-        Event::StageInstanceCreate(event) => FullEvent::StageInstanceCreate {
-            stage_instance: event.stage_instance,
-        },
-        Event::StageInstanceUpdate(event) => FullEvent::StageInstanceUpdate {
-            stage_instance: event.stage_instance,
-        },
-        Event::StageInstanceDelete(event) => FullEvent::StageInstanceDelete {
-            stage_instance: event.stage_instance,
-        },
-        Event::ThreadCreate(mut event) => {
-            update_cache!(cache, event);
+    #[test]
+    fn guild_backfill() {
+        let mut replay = Replay::new();
+        replay.dispatch(ready(&["1", "2"]));
 
-            FullEvent::ThreadCreate {
-                thread: event.thread,
-            }
-        },
-        Event::ThreadUpdate(mut event) => {
-            let old = if_cache!(event.update(cache));
+        let (is_new, names) = replay.guild_create(1);
+        assert_eq!(is_new, Some(false));
+        assert!(names.contains(&"guild_backfill"));
+        assert!(!names.contains(&"guild_join"));
 
-            FullEvent::ThreadUpdate {
-                old,
-                new: event.thread,
-            }
-        },
-        Event::ThreadDelete(mut event) => {
-            let full_thread_data = if_cache!(event.update(cache));
+        // A guild becoming available again after an outage is not a join either.
+        replay.dispatch(Event::GuildDelete(GuildDeleteEvent {
+            guild: UnavailableGuild {
+                id: GuildId::new(1),
+                unavailable: true,
+            },
+        }));
+        let (is_new, names) = replay.guild_create(1);
+        assert_eq!(is_new, Some(false));
+        assert!(names.contains(&"guild_backfill"));
+    }
 
-            FullEvent::ThreadDelete {
-                thread: event.thread,
-                full_thread_data,
-            }
-        },
-        Event::ThreadListSync(event) => FullEvent::ThreadListSync {
-            thread_list_sync: event,
-        },
-        Event::ThreadMemberUpdate(event) => FullEvent::ThreadMemberUpdate {
-            thread_member: event.member,
-        },
-        Event::ThreadMembersUpdate(event) => FullEvent::ThreadMembersUpdate {
-            thread_members_update: event,
-        },
-        Event::GuildScheduledEventCreate(event) => FullEvent::GuildScheduledEventCreate {
-            event: event.event,
-        },
-        Event::GuildScheduledEventUpdate(event) => FullEvent::GuildScheduledEventUpdate {
-            event: event.event,
-        },
-        Event::GuildScheduledEventDelete(event) => FullEvent::GuildScheduledEventDelete {
-            event: event.event,
-        },
-        Event::GuildScheduledEventUserAdd(event) => FullEvent::GuildScheduledEventUserAdd {
-            subscribed: event,
-        },
-        Event::GuildScheduledEventUserRemove(event) => FullEvent::GuildScheduledEventUserRemove {
-            unsubscribed: event,
-        },
-        Event::EntitlementCreate(event) => FullEvent::EntitlementCreate {
-            entitlement: event.entitlement,
-        },
-        Event::EntitlementUpdate(event) => FullEvent::EntitlementUpdate {
-            entitlement: event.entitlement,
-        },
-        Event::EntitlementDelete(event) => FullEvent::EntitlementDelete {
-            entitlement: event.entitlement,
-        },
-        Event::MessagePollVoteAdd(event) => FullEvent::MessagePollVoteAdd {
-            event,
-        },
-        Event::MessagePollVoteRemove(event) => FullEvent::MessagePollVoteRemove {
-            event,
-        },
-    };
+    #[test]
+    fn guild_join() {
+        let mut replay = Replay::new();
+        replay.dispatch(ready(&["1"]));
 
-    Some((event, extra_event))
+        let (is_new, names) = replay.guild_create(3);
+        assert_eq!(is_new, Some(true));
+        assert!(names.contains(&"guild_join"));
+        assert!(!names.contains(&"guild_backfill"));
+
+        // Rejoining a guild which was left is a join again.
+        replay.guild_create(1);
+        replay.dispatch(Event::GuildDelete(GuildDeleteEvent {
+            guild: UnavailableGuild {
+                id: GuildId::new(1),
+                unavailable: false,
+            },
+        }));
+        let (is_new, names) = replay.guild_create(1);
+        assert_eq!(is_new, Some(true));
+        assert!(names.contains(&"guild_join"));
+    }
+
+    #[test]
+    fn descriptors_match_dispatch() {
+        let mut replay = Replay::new();
+        let events = [
+            ready(&["1"]),
+            Event::GuildCreate(GuildCreateEvent {
+                guild: Guild {
+                    id: GuildId::new(1),
+                    ..Default::default()
+                },
+            }),
+            Event::GuildCreate(GuildCreateEvent {
+                guild: Guild {
+                    id: GuildId::new(2),
+                    ..Default::default()
+                },
+            }),
+            from_value(json!({"t": "RESUMED", "d": {}})).unwrap(),
+            from_value(json!({
+                "t": "TYPING_START",
+                "d": {"channel_id": "1", "user_id": "2", "timestamp": 1_700_000_000},
+            }))
+            .unwrap(),
+            from_value(json!({
+                "t": "MESSAGE_REACTION_REMOVE_ALL",
+                "d": {"channel_id": "1", "message_id": "2"},
+            }))
+            .unwrap(),
+        ];
+
+        for event in events {
+            let descriptor = event.descriptor().unwrap();
+            for dispatched in replay.dispatch(event) {
+                let name = dispatched.snake_case_name();
+                assert!(
+                    descriptor.handlers.contains(&name),
+                    "{name} is not listed for {}",
+                    descriptor.name
+                );
+            }
+        }
+    }
 }
@@ -1,7 +1,11 @@
+use std::sync::Arc;
+
 use async_trait::async_trait;
 
+#[cfg(feature = "model")]
+use super::command_registration::CommandRegistrationSummary;
 use super::context::Context;
-use crate::gateway::ShardStageUpdateEvent;
+use crate::gateway::{ShardDisconnectEvent, ShardStageUpdateEvent};
 use crate::http::RatelimitInfo;
 use crate::model::prelude::*;
 
@@ -166,9 +170,24 @@ event_handler! {
 
     /// Dispatched when a guild is created; or an existing guild's data is sent to us.
     ///
-    /// Provides the guild's data and whether the guild is new (only when cache feature is enabled).
+    /// Provides the guild's data and whether the guild is new. `is_new` is always [`Some`] and
+    /// only kept as an [`Option`] for compatibility.
+    ///
+    /// Prefer `guild_join` and `guild_backfill` to tell both cases apart.
     GuildCreate { guild: Guild, is_new: Option<bool> } => async fn guild_create(&self, ctx: Context);
 
+    /// Dispatched in addition to `guild_create` when the current user joins a guild.
+    ///
+    /// Provides the guild's data.
+    GuildJoin { guild: Arc<Guild> } => async fn guild_join(&self, ctx: Context);
+
+    /// Dispatched in addition to `guild_create` when the data of a guild the current user is
+    /// already in is sent, i.e. for the guilds of the `ready` event or when an unavailable guild
+    /// becomes available again.
+    ///
+    /// Provides the guild's data.
+    GuildBackfill { guild: Arc<Guild> } => async fn guild_backfill(&self, ctx: Context);
+
     /// Dispatched when a guild is deleted.
     ///
     /// Provides the partial data of the guild sent by discord, and the full data from the cache,
@@ -241,6 +260,26 @@ event_handler! {
     /// available) and new data.
     GuildRoleUpdate { old_data_if_available: Option<Role>, new: Role } => async fn guild_role_update(&self, ctx: Context);
 
+    /// Dispatched when a soundboard sound is created.
+    ///
+    /// Provides the created sound.
+    GuildSoundboardSoundCreate { sound: SoundboardSound } => async fn guild_soundboard_sound_create(&self, ctx: Context);
+
+    /// Dispatched when a soundboard sound is updated.
+    ///
+    /// Provides the new data of the sound.
+    GuildSoundboardSoundUpdate { sound: SoundboardSound } => async fn guild_soundboard_sound_update(&self, ctx: Context);
+
+    /// Dispatched when a soundboard sound is deleted.
+    ///
+    /// Provides the guild's id and the deleted sound's id.
+    GuildSoundboardSoundDelete { guild_id: GuildId, sound_id: SoundId } => async fn guild_soundboard_sound_delete(&self, ctx: Context);
+
+    /// Dispatched when several soundboard sounds of a guild are updated at once.
+    ///
+    /// Provides the guild's id and the new data of the updated sounds.
+    GuildSoundboardSoundsUpdate { guild_id: GuildId, sounds: Vec<SoundboardSound> } => async fn guild_soundboard_sounds_update(&self, ctx: Context);
+
     /// Dispatched when the stickers are updated.
     ///
     /// Provides the guild's id and the new state of the stickers in the guild.
@@ -303,7 +342,7 @@ event_handler! {
     /// Provides the channel's id and the message's id.
     ReactionRemoveEmoji { removed_reactions: Reaction } => async fn reaction_remove_emoji(&self, ctx: Context);
 
-    #[deprecated = "This event does not exist"]
+    #[deprecated = "This event does not exist, and will be removed in 0.13"]
     PresenceReplace { presences: Vec<Presence> } => async fn presence_replace(&self, ctx: Context);
 
     /// Dispatched when a user's presence is updated (e.g off -> on).
@@ -325,8 +364,20 @@ event_handler! {
     /// Dispatched when a shard's connection stage is updated
     ///
     /// Provides the context of the shard and the event information about the update.
+    ///
+    /// This event is created by the shard runner rather than received from the gateway, so it does
+    /// not update the cache.
     ShardStageUpdate { event: ShardStageUpdateEvent } => async fn shard_stage_update(&self, ctx: Context);
 
+    /// Dispatched when a shard is disconnected, along with its [`Self::shard_stage_update`].
+    ///
+    /// Provides the context of the shard and the stage it was disconnected from, as well as the
+    /// close code of the connection if it is known.
+    ///
+    /// This event is created by the shard runner rather than received from the gateway, so it does
+    /// not update the cache.
+    ShardDisconnect { event: ShardDisconnectEvent } => async fn shard_disconnect(&self, ctx: Context);
+
     /// Dispatched when a user starts typing.
     TypingStart { event: TypingStartEvent } => async fn typing_start(&self, ctx: Context);
 
@@ -481,8 +532,16 @@ event_handler! {
     /// Dispatched when a user removes a previous vote on a poll.
     MessagePollVoteRemove { event: MessagePollVoteRemoveEvent } => async fn poll_vote_remove(&self, ctx: Context);
 
-    /// Dispatched when an HTTP rate limit is hit
+    /// Dispatched when an HTTP rate limit is hit, either when Discord responds with a 429 or when
+    /// a request is pre-emptively held back. See [`RatelimitInfo`] for the scope and duration.
     Ratelimit { data: RatelimitInfo } => async fn ratelimit(&self);
+
+    /// Dispatched once after the commands set via [`ClientBuilder::register_commands`] were
+    /// registered, on the first [`Ready`] received by any shard.
+    ///
+    /// [`ClientBuilder::register_commands`]: crate::client::ClientBuilder::register_commands
+    #[cfg(feature = "model")]
+    CommandsRegistered { summary: CommandRegistrationSummary } => async fn commands_registered(&self, ctx: Context);
 }
 
 /// This core trait for handling raw events
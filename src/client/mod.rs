@@ -16,6 +16,8 @@
 //!
 //! [Client examples]: Client#examples
 
+#[cfg(all(feature = "gateway", feature = "model"))]
+mod command_registration;
 mod context;
 #[cfg(feature = "gateway")]
 pub(crate) mod dispatch;
@@ -33,15 +35,21 @@ use futures::channel::mpsc::UnboundedReceiver as Receiver;
 use futures::future::BoxFuture;
 use futures::StreamExt as _;
 use tokio::sync::{Mutex, RwLock};
-use tracing::{debug, error, info, instrument};
+use tracing::{debug, error, info, instrument, warn};
 use typemap_rev::{TypeMap, TypeMapKey};
 
+#[cfg(all(feature = "gateway", feature = "model"))]
+use self::command_registration::CommandRegistrar;
+#[cfg(all(feature = "gateway", feature = "model"))]
+pub use self::command_registration::{CommandRegisterMode, CommandRegistrationSummary};
 pub use self::context::Context;
 pub use self::error::Error as ClientError;
 #[cfg(feature = "gateway")]
 pub use self::event_handler::{EventHandler, FullEvent, RawEventHandler};
 #[cfg(feature = "gateway")]
 use super::gateway::GatewayError;
+#[cfg(all(feature = "gateway", feature = "model"))]
+use crate::builder::CreateCommand;
 #[cfg(feature = "cache")]
 pub use crate::cache::Cache;
 #[cfg(feature = "cache")]
@@ -52,11 +60,19 @@ use crate::framework::Framework;
 use crate::gateway::VoiceGatewayManager;
 use crate::gateway::{ActivityData, PresenceData};
 #[cfg(feature = "gateway")]
-use crate::gateway::{ShardManager, ShardManagerOptions};
+use crate::gateway::{
+    GatewayProxy,
+    IdentifyProperties,
+    OutgoingPayloadHook,
+    ShardConnectionOptions,
+    ShardManager,
+    ShardManagerOptions,
+    TransportCompression,
+};
 use crate::http::Http;
 use crate::internal::prelude::*;
 #[cfg(feature = "gateway")]
-use crate::model::gateway::GatewayIntents;
+use crate::model::gateway::{GatewayIntents, SessionStartLimit};
 use crate::model::id::ApplicationId;
 use crate::model::user::OnlineStatus;
 
@@ -69,6 +85,8 @@ pub struct ClientBuilder {
     intents: GatewayIntents,
     #[cfg(feature = "cache")]
     cache_settings: CacheSettings,
+    #[cfg(feature = "cache")]
+    precheck_permissions: Option<bool>,
     #[cfg(feature = "framework")]
     framework: Option<Box<dyn Framework>>,
     #[cfg(feature = "voice")]
@@ -76,6 +94,16 @@ pub struct ClientBuilder {
     event_handlers: Vec<Arc<dyn EventHandler>>,
     raw_event_handlers: Vec<Arc<dyn RawEventHandler>>,
     presence: PresenceData,
+    ignore_session_start_limit: bool,
+    gateway_proxy: Option<String>,
+    drop_typing_events: bool,
+    strict_deserialization: bool,
+    deserialization_error_snippets: bool,
+    identify_properties: IdentifyProperties,
+    transport_compression: TransportCompression,
+    outgoing_payload_hook: Option<OutgoingPayloadHook>,
+    #[cfg(feature = "model")]
+    commands: Option<(Vec<CreateCommand>, CommandRegisterMode)>,
 }
 
 #[cfg(feature = "gateway")]
@@ -87,6 +115,8 @@ impl ClientBuilder {
             intents,
             #[cfg(feature = "cache")]
             cache_settings: CacheSettings::default(),
+            #[cfg(feature = "cache")]
+            precheck_permissions: None,
             #[cfg(feature = "framework")]
             framework: None,
             #[cfg(feature = "voice")]
@@ -94,6 +124,16 @@ impl ClientBuilder {
             event_handlers: vec![],
             raw_event_handlers: vec![],
             presence: PresenceData::default(),
+            ignore_session_start_limit: false,
+            gateway_proxy: None,
+            drop_typing_events: false,
+            strict_deserialization: true,
+            deserialization_error_snippets: false,
+            identify_properties: IdentifyProperties::default(),
+            transport_compression: TransportCompression::default(),
+            outgoing_payload_hook: None,
+            #[cfg(feature = "model")]
+            commands: None,
         }
     }
 
@@ -175,6 +215,19 @@ impl ClientBuilder {
         self
     }
 
+    /// Sets whether the current user's permissions are checked against the cache before making
+    /// requests that require them. See [`Settings::precheck_permissions`] for more info.
+    ///
+    /// This overrides the value in the settings passed to [`Self::cache_settings`], regardless of
+    /// the order in which both are called.
+    ///
+    /// [`Settings::precheck_permissions`]: crate::cache::Settings::precheck_permissions
+    #[cfg(feature = "cache")]
+    pub fn precheck_permissions(mut self, precheck: bool) -> Self {
+        self.precheck_permissions = Some(precheck);
+        self
+    }
+
     /// Gets the cache settings. See [`Self::cache_settings`] for more info.
     #[cfg(feature = "cache")]
     pub fn get_cache_settings(&self) -> &CacheSettings {
@@ -184,8 +237,8 @@ impl ClientBuilder {
     /// Sets the command framework to be used. It will receive messages sent over the gateway and
     /// then consider - based on its settings - whether to dispatch a command.
     ///
-    /// *Info*: If a reference to the framework is required for manual dispatch, you can implement
-    /// [`Framework`] on [`Arc<YourFrameworkType>`] instead of `YourFrameworkType`.
+    /// *Info*: If a reference to the framework is required for manual dispatch or runtime
+    /// modification, you can pass an [`Arc<YourFrameworkType>`] and keep a clone of it.
     #[cfg(feature = "framework")]
     pub fn framework<F>(mut self, framework: F) -> Self
     where
@@ -306,7 +359,14 @@ impl ClientBuilder {
 
     /// Sets the initial activity.
     pub fn activity(mut self, activity: ActivityData) -> Self {
-        self.presence.activity = Some(activity);
+        self.presence.activities = vec![activity];
+
+        self
+    }
+
+    /// Sets multiple initial activities.
+    pub fn activities(mut self, activities: impl IntoIterator<Item = ActivityData>) -> Self {
+        self.presence.activities = activities.into_iter().collect();
 
         self
     }
@@ -322,6 +382,136 @@ impl ClientBuilder {
     pub fn get_presence(&self) -> &PresenceData {
         &self.presence
     }
+
+    /// Sets whether to start shards even if there are not enough session starts remaining.
+    ///
+    /// By default, the client checks the [`SessionStartLimit`] before booting shards and fails
+    /// with [`GatewayError::InsufficientSessionStarts`] if starting all of them would exceed it,
+    /// as exceeding it resets the bot's token.
+    pub fn ignore_session_start_limit(mut self, ignore: bool) -> Self {
+        self.ignore_session_start_limit = ignore;
+        self
+    }
+
+    /// Sets a proxy to tunnel the gateway websocket connections of all shards through, including
+    /// on reconnects and resumes.
+    ///
+    /// Both HTTP `CONNECT` proxies (`http://`) and SOCKS5 proxies (`socks5://` or `socks5h://`)
+    /// are supported, see [`GatewayProxy`] for details. This does not affect HTTP requests to the
+    /// Discord API.
+    ///
+    /// An invalid proxy URL makes building the client fail with a [`GatewayError::Proxy`].
+    pub fn gateway_proxy(mut self, url: impl Into<String>) -> Self {
+        self.gateway_proxy = Some(url.into());
+        self
+    }
+
+    /// Sets whether to drop [`TypingStart`] events without deserializing them.
+    ///
+    /// Typing events are sent frequently and unused by most bots, so dropping them saves the cost
+    /// of deserializing them. Dropped events are not passed to any event handler, raw event
+    /// handler, collector or the cache.
+    ///
+    /// Defaults to `false`.
+    ///
+    /// [`TypingStart`]: crate::model::event::Event::TypingStart
+    pub fn drop_typing_events(mut self, drop: bool) -> Self {
+        self.drop_typing_events = drop;
+        self
+    }
+
+    /// Sets whether gateway events must deserialize exactly.
+    ///
+    /// When Discord adds a field with an unexpected type, the event containing it fails to
+    /// deserialize and is dropped. If set to `false`, such events are instead deserialized again
+    /// with the offending field removed, so it falls back to its default value, and a warning
+    /// naming the field is logged. Essential fields such as Ids can not be recovered from.
+    ///
+    /// Re-parsing is only attempted for events that failed to deserialize, so this has no cost
+    /// for all other events.
+    ///
+    /// Defaults to `true`.
+    pub fn strict_deserialization(mut self, strict: bool) -> Self {
+        self.strict_deserialization = strict;
+        self
+    }
+
+    /// Sets whether errors for gateway payloads that fail to deserialize include the start of the
+    /// payload, see [`GatewayError::Deserialization`].
+    ///
+    /// Payloads may contain message contents and other user data, which then ends up in logs and
+    /// bug reports, so this is opt-in.
+    ///
+    /// Defaults to `false`.
+    ///
+    /// [`GatewayError::Deserialization`]: crate::gateway::GatewayError::Deserialization
+    pub fn deserialization_error_snippets(mut self, include: bool) -> Self {
+        self.deserialization_error_snippets = include;
+        self
+    }
+
+    /// Sets the connection properties sent by every shard when identifying with the gateway.
+    ///
+    /// Defaults to [`IdentifyProperties::default`]. Empty properties make building the client
+    /// fail with [`GatewayError::InvalidIdentifyProperties`].
+    pub fn identify_properties(mut self, properties: IdentifyProperties) -> Self {
+        self.identify_properties = properties;
+        self
+    }
+
+    /// Sets the compression of payloads received from the gateway.
+    ///
+    /// [`TransportCompression::ZlibStream`] considerably reduces the bandwidth used by large bots.
+    ///
+    /// Defaults to [`TransportCompression::Payload`].
+    pub fn transport_compression(mut self, compression: TransportCompression) -> Self {
+        self.transport_compression = compression;
+        self
+    }
+
+    /// Sets a hook called with every payload shards send to the gateway, except heartbeats.
+    ///
+    /// This is meant for debugging, e.g. to see the exact presence or voice state updates sent.
+    /// See [`OutgoingPayloadHook`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::prelude::*;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::builder("token", GatewayIntents::empty())
+    ///     .outgoing_payload_hook(|shard_id, payload| {
+    ///         println!("[{shard_id}] sent {payload}");
+    ///     })
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn outgoing_payload_hook(mut self, hook: OutgoingPayloadHook) -> Self {
+        self.outgoing_payload_hook = Some(hook);
+        self
+    }
+
+    /// Registers the given application commands once the first shard is ready, replacing all
+    /// commands previously registered in the same place.
+    ///
+    /// Registration happens only once per client, even if shards reconnect or restart. The result
+    /// is reported to [`EventHandler::commands_registered`] of every event handler.
+    ///
+    /// Commands are registered via [`Command::set_global_commands`] or [`GuildId::set_commands`],
+    /// so unchanged commands keep their Ids and permissions.
+    ///
+    /// [`Command::set_global_commands`]: crate::model::application::Command::set_global_commands
+    /// [`GuildId::set_commands`]: crate::model::id::GuildId::set_commands
+    #[cfg(feature = "model")]
+    pub fn register_commands(
+        mut self,
+        commands: Vec<CreateCommand>,
+        mode: CommandRegisterMode,
+    ) -> Self {
+        self.commands = Some((commands, mode));
+        self
+    }
 }
 
 #[cfg(feature = "gateway")]
@@ -335,10 +525,19 @@ impl IntoFuture for ClientBuilder {
         let data = Arc::new(RwLock::new(self.data));
         #[cfg(feature = "framework")]
         let framework = self.framework;
-        let event_handlers = self.event_handlers;
+        #[cfg_attr(not(feature = "model"), allow(unused_mut))]
+        let mut event_handlers = self.event_handlers;
         let raw_event_handlers = self.raw_event_handlers;
         let intents = self.intents;
         let presence = self.presence;
+        let ignore_session_start_limit = self.ignore_session_start_limit;
+        let gateway_proxy = self.gateway_proxy;
+        let drop_typing_events = self.drop_typing_events;
+        let strict_deserialization = self.strict_deserialization;
+        let deserialization_error_snippets = self.deserialization_error_snippets;
+        let identify_properties = self.identify_properties;
+        let transport_compression = self.transport_compression;
+        let outgoing_payload_hook = self.outgoing_payload_hook;
 
         let mut http = self.http;
 
@@ -354,13 +553,38 @@ impl IntoFuture for ClientBuilder {
 
         let http = Arc::new(http);
 
+        #[cfg(feature = "model")]
+        if let Some((commands, mode)) = self.commands {
+            let registrar = CommandRegistrar::new(commands, mode, event_handlers.clone());
+            event_handlers.push(Arc::new(registrar));
+        }
+
         #[cfg(feature = "voice")]
         let voice_manager = self.voice_manager;
 
         #[cfg(feature = "cache")]
-        let cache = Arc::new(Cache::new_with_settings(self.cache_settings));
+        let cache = {
+            let mut settings = self.cache_settings;
+            if let Some(precheck) = self.precheck_permissions {
+                settings.precheck_permissions = precheck;
+            }
+            Arc::new(Cache::new_with_settings(settings))
+        };
 
         Box::pin(async move {
+            let gateway_proxy = gateway_proxy.as_deref().map(GatewayProxy::new).transpose()?;
+            identify_properties.validate()?;
+
+            let connection = ShardConnectionOptions {
+                gateway_proxy,
+                drop_typing_events,
+                strict_deserialization,
+                deserialization_error_snippets,
+                identify_properties,
+                transport_compression,
+                outgoing_payload_hook,
+            };
+
             let ws_url = Arc::new(Mutex::new(match http.get_gateway().await {
                 Ok(response) => response.url,
                 Err(err) => {
@@ -389,6 +613,7 @@ impl IntoFuture for ClientBuilder {
                 intents,
                 presence: Some(presence),
             });
+            shard_manager.set_connection_options(connection);
 
             let client = Client {
                 data,
@@ -400,6 +625,8 @@ impl IntoFuture for ClientBuilder {
                 #[cfg(feature = "cache")]
                 cache,
                 http,
+                session_start_limit: None,
+                ignore_session_start_limit,
             };
             #[cfg(feature = "framework")]
             if let Some(mut framework) = framework {
@@ -626,6 +853,8 @@ pub struct Client {
     pub cache: Arc<Cache>,
     /// An HTTP client.
     pub http: Arc<Http>,
+    session_start_limit: Option<SessionStartLimit>,
+    ignore_session_start_limit: bool,
 }
 
 impl Client {
@@ -633,6 +862,14 @@ impl Client {
         ClientBuilder::new(token, intents)
     }
 
+    /// Returns the [`SessionStartLimit`] as last fetched when starting shards.
+    ///
+    /// This is [`None`] before any shards were started, or if fetching the limit failed.
+    #[must_use]
+    pub fn session_start_limit(&self) -> Option<&SessionStartLimit> {
+        self.session_start_limit.as_ref()
+    }
+
     /// Establish the connection and start listening for events.
     ///
     /// This will start receiving events in a loop and start dispatching the events to your
@@ -668,7 +905,7 @@ impl Client {
     /// [gateway docs]: crate::gateway#sharding
     #[instrument(skip(self))]
     pub async fn start(&mut self) -> Result<()> {
-        self.start_connection(0, 0, 1).await
+        self.start_connection(0, 0, 1, None).await
     }
 
     /// Establish the connection(s) and start listening for events.
@@ -709,13 +946,9 @@ impl Client {
     /// [gateway docs]: crate::gateway#sharding
     #[instrument(skip(self))]
     pub async fn start_autosharded(&mut self) -> Result<()> {
-        let (end, total) = {
-            let res = self.http.get_bot_gateway().await?;
+        let res = self.http.get_bot_gateway().await?;
 
-            (res.shards - 1, res.shards)
-        };
-
-        self.start_connection(0, end, total).await
+        self.start_connection(0, res.shards - 1, res.shards, Some(res.session_start_limit)).await
     }
 
     /// Establish a sharded connection and start listening for events.
@@ -774,7 +1007,7 @@ impl Client {
     /// [gateway docs]: crate::gateway#sharding
     #[instrument(skip(self))]
     pub async fn start_shard(&mut self, shard: u32, shards: u32) -> Result<()> {
-        self.start_connection(shard, shard, shards).await
+        self.start_connection(shard, shard, shards, None).await
     }
 
     /// Establish sharded connections and start listening for events.
@@ -815,7 +1048,7 @@ impl Client {
     /// [Gateway docs]: crate::gateway#sharding
     #[instrument(skip(self))]
     pub async fn start_shards(&mut self, total_shards: u32) -> Result<()> {
-        self.start_connection(0, total_shards - 1, total_shards).await
+        self.start_connection(0, total_shards - 1, total_shards, None).await
     }
 
     /// Establish a range of sharded connections and start listening for events.
@@ -856,7 +1089,7 @@ impl Client {
     /// [Gateway docs]: crate::gateway#sharding
     #[instrument(skip(self))]
     pub async fn start_shard_range(&mut self, range: Range<u32>, total_shards: u32) -> Result<()> {
-        self.start_connection(range.start, range.end, total_shards).await
+        self.start_connection(range.start, range.end, total_shards, None).await
     }
 
     /// Shard data layout is:
@@ -866,15 +1099,22 @@ impl Client {
     ///
     /// Not all shards need to be initialized in this process.
     ///
+    /// The session start limit is fetched unless it is passed in. Besides being checked, it
+    /// determines how many shards are started concurrently.
+    ///
     /// # Errors
     ///
     /// Returns a [`ClientError::Shutdown`] when all shards have shutdown due to an error.
+    ///
+    /// Returns a [`GatewayError::InsufficientSessionStarts`] if there are not enough session
+    /// starts remaining to start all shards.
     #[instrument(skip(self))]
     async fn start_connection(
         &mut self,
         start_shard: u32,
         end_shard: u32,
         total_shards: u32,
+        session_start_limit: Option<SessionStartLimit>,
     ) -> Result<()> {
         #[cfg(feature = "voice")]
         if let Some(voice_manager) = &self.voice_manager {
@@ -885,6 +1125,27 @@ impl Client {
 
         let init = end_shard - start_shard + 1;
 
+        let session_start_limit = match session_start_limit {
+            Some(limit) => Some(limit),
+            None => match self.http.get_bot_gateway().await {
+                Ok(res) => Some(res.session_start_limit),
+                Err(why) => {
+                    warn!("Failed to fetch the session start limit: {:?}", why);
+                    None
+                },
+            },
+        };
+
+        if let Some(limit) = &session_start_limit {
+            if !self.ignore_session_start_limit {
+                check_session_start_limit(limit, init).map_err(Error::Gateway)?;
+            }
+
+            let max_concurrency = u16::try_from(limit.max_concurrency).unwrap_or(u16::MAX);
+            self.shard_manager.set_max_concurrency(max_concurrency);
+        }
+        self.session_start_limit = session_start_limit;
+
         self.shard_manager.set_shards(start_shard, init, total_shards).await;
 
         debug!("Initializing shard info: {} - {}/{}", start_shard, init, total_shards);
@@ -905,3 +1166,62 @@ impl Client {
         Ok(())
     }
 }
+
+/// Checks whether enough session starts remain to identify `required` shards.
+#[cfg(feature = "gateway")]
+fn check_session_start_limit(limit: &SessionStartLimit, required: u32) -> Result<(), GatewayError> {
+    if limit.remaining >= u64::from(required) {
+        return Ok(());
+    }
+
+    Err(GatewayError::InsufficientSessionStarts {
+        remaining: limit.remaining,
+        required: u64::from(required),
+        reset_after: std::time::Duration::from_millis(limit.reset_after),
+    })
+}
+
+#[cfg(all(test, feature = "gateway"))]
+mod tests {
+    use std::time::Duration;
+
+    use super::check_session_start_limit;
+    use crate::gateway::GatewayError;
+    use crate::json::{from_value, json};
+    use crate::model::gateway::SessionStartLimit;
+
+    fn limit(remaining: u64) -> SessionStartLimit {
+        from_value(json!({
+            "total": 1000,
+            "remaining": remaining,
+            "reset_after": 14_400_000,
+            "max_concurrency": 1,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn enough_session_starts() {
+        assert!(check_session_start_limit(&limit(1000), 100).is_ok());
+        assert!(check_session_start_limit(&limit(100), 100).is_ok());
+        assert!(check_session_start_limit(&limit(1), 1).is_ok());
+    }
+
+    #[test]
+    fn insufficient_session_starts() {
+        match check_session_start_limit(&limit(40), 100) {
+            Err(GatewayError::InsufficientSessionStarts {
+                remaining,
+                required,
+                reset_after,
+            }) => {
+                assert_eq!(remaining, 40);
+                assert_eq!(required, 100);
+                assert_eq!(reset_after, Duration::from_secs(4 * 60 * 60));
+            },
+            other => panic!("unexpected result: {other:?}"),
+        }
+
+        assert!(check_session_start_limit(&limit(0), 1).is_err());
+    }
+}
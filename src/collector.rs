@@ -30,6 +30,30 @@ use crate::model::prelude::*;
 ///     .await;
 /// # }
 /// ```
+///
+/// Example: waiting for a user to join a voice channel in a guild
+/// ```rust
+/// # use futures::StreamExt as _;
+/// # use serenity::model::prelude::*;
+/// # use serenity::gateway::ShardMessenger;
+/// # use serenity::collector::collect;
+/// # async fn example_(shard: &ShardMessenger, user_id: UserId, guild_id: GuildId) {
+/// let mut stream = Box::pin(collect(shard, move |event| match event {
+///     Event::VoiceStateUpdate(VoiceStateUpdateEvent {
+///         voice_state, ..
+///     }) if voice_state.user_id == user_id && voice_state.guild_id == Some(guild_id) => {
+///         voice_state.channel_id
+///     },
+///     _ => None,
+/// }));
+///
+/// if let Some(channel_id) = stream.next().await {
+///     println!("{user_id} joined {channel_id}");
+/// }
+/// # }
+/// ```
+///
+/// The same can be achieved with [`UserId::await_voice_join`].
 pub fn collect<T: Send + 'static>(
     shard: &ShardMessenger,
     extractor: impl Fn(&Event) -> Option<T> + Send + Sync + 'static,
@@ -47,6 +71,9 @@ pub fn collect<T: Send + 'static>(
     futures::stream::poll_fn(move |cx| receiver.poll_recv(cx))
 }
 
+/// A custom filter of a collector, see e.g. [`MessageCollector::filter`].
+type FilterFn<T> = Box<dyn Fn(&T) -> bool + Send + Sync>;
+
 macro_rules! make_specific_collector {
     (
         $( #[ $($meta:tt)* ] )*
@@ -60,7 +87,7 @@ macro_rules! make_specific_collector {
         pub struct $collector_type {
             shard: ShardMessenger,
             duration: Option<std::time::Duration>,
-            filter: Option<Box<dyn Fn(&$item_type) -> bool + Send + Sync>>,
+            filter: Option<FilterFn<$item_type>>,
             $( $filter_name: Option<$filter_type>, )*
         }
 
@@ -187,6 +214,7 @@ make_specific_collector!(
     channel_id: ChannelId => reaction.channel_id == *channel_id,
     guild_id: GuildId => reaction.guild_id.map_or(true, |g| g == *guild_id),
     message_id: MessageId => reaction.message_id == *message_id,
+    emoji: ReactionType => emoji.matches(&reaction.emoji),
 );
 make_specific_collector!(
     MessageCollector, Message,
@@ -195,8 +223,347 @@ make_specific_collector!(
     channel_id: ChannelId => message.channel_id == *channel_id,
     guild_id: GuildId => message.guild_id.map_or(true, |g| g == *guild_id),
 );
+make_specific_collector!(
+    VoiceStateCollector, VoiceState,
+    Event::VoiceStateUpdate(VoiceStateUpdateEvent { voice_state }) => voice_state,
+    user_id: UserId => voice_state.user_id == *user_id,
+    guild_id: GuildId => voice_state.guild_id.map_or(true, |g| g == *guild_id),
+    channel_id: ChannelId => voice_state.channel_id == Some(*channel_id),
+);
 make_specific_collector!(
     #[deprecated = "prefer the stand-alone collect() function to collect arbitrary events"]
     EventCollector, Event,
     event => event,
 );
+
+/// A reaction that was added or removed, as collected by a [`ReactionActionCollector`].
+///
+/// Dereferences to the [`Reaction`] itself.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum ReactionAction {
+    Added(Reaction),
+    Removed(Reaction),
+}
+
+impl ReactionAction {
+    /// Returns the reaction that was added or removed.
+    #[must_use]
+    pub fn reaction(&self) -> &Reaction {
+        match self {
+            Self::Added(reaction) | Self::Removed(reaction) => reaction,
+        }
+    }
+
+    /// Converts into the reaction that was added or removed.
+    #[must_use]
+    pub fn into_reaction(self) -> Reaction {
+        match self {
+            Self::Added(reaction) | Self::Removed(reaction) => reaction,
+        }
+    }
+
+    /// Returns `true` if the reaction was added.
+    #[must_use]
+    pub fn is_added(&self) -> bool {
+        matches!(self, Self::Added(_))
+    }
+
+    /// Returns `true` if the reaction was removed.
+    #[must_use]
+    pub fn is_removed(&self) -> bool {
+        matches!(self, Self::Removed(_))
+    }
+
+    fn from_event(event: &Event) -> Option<Self> {
+        match event {
+            Event::ReactionAdd(ReactionAddEvent {
+                reaction,
+            }) => Some(Self::Added(reaction.clone())),
+            Event::ReactionRemove(ReactionRemoveEvent {
+                reaction,
+            }) => Some(Self::Removed(reaction.clone())),
+            _ => None,
+        }
+    }
+}
+
+impl std::ops::Deref for ReactionAction {
+    type Target = Reaction;
+
+    fn deref(&self) -> &Reaction {
+        self.reaction()
+    }
+}
+
+/// A [`ReactionActionCollector`] receives [`ReactionAction`]'s, both added and removed
+/// reactions, that match the given filters for a set duration.
+///
+/// Use [`ReactionCollector`] to only collect added reactions.
+#[must_use]
+pub struct ReactionActionCollector {
+    shard: ShardMessenger,
+    duration: Option<std::time::Duration>,
+    filter: Option<FilterFn<ReactionAction>>,
+    author_id: Option<UserId>,
+    channel_id: Option<ChannelId>,
+    guild_id: Option<GuildId>,
+    message_id: Option<MessageId>,
+    emoji: Option<ReactionType>,
+}
+
+impl ReactionActionCollector {
+    /// Creates a new collector without any filters configured.
+    pub fn new(shard: impl AsRef<ShardMessenger>) -> Self {
+        Self {
+            shard: shard.as_ref().clone(),
+            duration: None,
+            filter: None,
+            author_id: None,
+            channel_id: None,
+            guild_id: None,
+            message_id: None,
+            emoji: None,
+        }
+    }
+
+    /// Sets a duration for how long the collector shall receive reactions.
+    pub fn timeout(mut self, duration: std::time::Duration) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    /// Sets a generic filter function.
+    pub fn filter(
+        mut self,
+        filter: impl Fn(&ReactionAction) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.filter = Some(Box::new(filter));
+        self
+    }
+
+    /// Filters [`ReactionAction`]'s by the user that reacted.
+    pub fn author_id(mut self, author_id: UserId) -> Self {
+        self.author_id = Some(author_id);
+        self
+    }
+
+    /// Filters [`ReactionAction`]'s by a specific [`ChannelId`].
+    pub fn channel_id(mut self, channel_id: ChannelId) -> Self {
+        self.channel_id = Some(channel_id);
+        self
+    }
+
+    /// Filters [`ReactionAction`]'s by a specific [`GuildId`].
+    pub fn guild_id(mut self, guild_id: GuildId) -> Self {
+        self.guild_id = Some(guild_id);
+        self
+    }
+
+    /// Filters [`ReactionAction`]'s by a specific [`MessageId`].
+    pub fn message_id(mut self, message_id: MessageId) -> Self {
+        self.message_id = Some(message_id);
+        self
+    }
+
+    /// Filters [`ReactionAction`]'s by the reacted emoji. Custom emojis are compared by Id only,
+    /// see [`ReactionType::matches`].
+    pub fn emoji(mut self, emoji: impl Into<ReactionType>) -> Self {
+        self.emoji = Some(emoji.into());
+        self
+    }
+
+    fn filters_pass(&self, reaction: &ReactionAction) -> bool {
+        self.author_id.map_or(true, |id| reaction.user_id.map_or(true, |a| a == id))
+            && self.channel_id.map_or(true, |id| reaction.channel_id == id)
+            && self.guild_id.map_or(true, |id| reaction.guild_id.map_or(true, |g| g == id))
+            && self.message_id.map_or(true, |id| reaction.message_id == id)
+            && self.emoji.as_ref().map_or(true, |emoji| emoji.matches(&reaction.emoji))
+            && self.filter.as_ref().map_or(true, |filter| filter(reaction))
+    }
+
+    /// Returns a [`Stream`] over all collected [`ReactionAction`].
+    pub fn stream(self) -> impl Stream<Item = ReactionAction> {
+        // A future that completes once the timeout is triggered
+        let duration = self.duration;
+        let timeout = async move {
+            match duration {
+                Some(d) => tokio::time::sleep(d).await,
+                None => pending::<()>().await,
+            }
+        };
+
+        let shard = self.shard.clone();
+        let stream = collect(&shard, move |event| {
+            ReactionAction::from_event(event).filter(|r| self.filters_pass(r))
+        });
+        // Need to Box::pin this, or else users have to `pin_mut!()` the stream to the stack
+        stream.take_until(Box::pin(timeout))
+    }
+
+    /// Returns the next [`ReactionAction`] which passes the filters.
+    /// You can also call `.await` on the [`ReactionActionCollector`] directly.
+    pub async fn next(self) -> Option<ReactionAction> {
+        self.stream().next().await
+    }
+}
+
+impl std::future::IntoFuture for ReactionActionCollector {
+    type Output = Option<ReactionAction>;
+    type IntoFuture = futures::future::BoxFuture<'static, Self::Output>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(self.next())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use super::*;
+    use crate::gateway::{ChunkGuildFilter, ShardRunnerMessage};
+    use crate::json::{from_value, json};
+
+    fn shard_messenger() -> ShardMessenger {
+        ShardMessenger {
+            tx: futures::channel::mpsc::unbounded().0,
+            collectors: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    fn dispatch(shard: &ShardMessenger, event: &Event) {
+        shard.collectors.lock().unwrap().retain_mut(|callback| (callback.0)(event));
+    }
+
+    fn voice_state_event(user_id: &str, guild_id: &str, channel_id: Option<&str>) -> Event {
+        from_value(json!({
+            "t": "VOICE_STATE_UPDATE",
+            "d": {
+                "channel_id": channel_id,
+                "guild_id": guild_id,
+                "user_id": user_id,
+                "deaf": false,
+                "mute": false,
+                "self_deaf": false,
+                "self_mute": false,
+                "self_video": false,
+                "session_id": "session",
+                "suppress": false,
+            },
+        }))
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn voice_joins() {
+        let shard = shard_messenger();
+        let collector = UserId::new(1).await_voice_join(&shard, GuildId::new(2));
+        let mut stream = Box::pin(collector.stream());
+
+        for event in [
+            voice_state_event("3", "2", Some("10")),
+            voice_state_event("1", "4", Some("11")),
+            voice_state_event("1", "2", None),
+            voice_state_event("1", "2", Some("12")),
+        ] {
+            dispatch(&shard, &event);
+        }
+
+        let state = stream.next().await.unwrap();
+        assert_eq!(state.channel_id, Some(ChannelId::new(12)));
+
+        drop(stream);
+        dispatch(&shard, &voice_state_event("1", "2", Some("13")));
+        assert!(shard.collectors.lock().unwrap().is_empty());
+    }
+
+    fn members_chunk_event(nonce: &str, user_id: &str, chunk_index: u32) -> Event {
+        from_value(json!({
+            "t": "GUILD_MEMBERS_CHUNK",
+            "d": {
+                "guild_id": "1",
+                "members": [{
+                    "user": {"id": user_id, "username": "member", "discriminator": "0"},
+                    "roles": [],
+                    "joined_at": "2023-01-01T00:00:00.000000+00:00",
+                    "deaf": false,
+                    "mute": false,
+                }],
+                "chunk_index": chunk_index,
+                "chunk_count": 2,
+                "nonce": nonce,
+            },
+        }))
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn request_members() {
+        let (tx, mut rx) = futures::channel::mpsc::unbounded();
+        let shard = ShardMessenger {
+            tx,
+            collectors: Arc::new(Mutex::new(Vec::new())),
+        };
+
+        let guild_id = GuildId::new(1);
+        let filter = ChunkGuildFilter::None;
+        let timeout = std::time::Duration::from_secs(10);
+        let request = guild_id.request_members(shard.clone(), filter, None, timeout);
+        let members = tokio::spawn(request);
+
+        let Some(ShardRunnerMessage::ChunkGuild {
+            guild_id: requested,
+            nonce: Some(nonce),
+            ..
+        }) = rx.next().await
+        else {
+            panic!("expected a chunk guild request");
+        };
+        assert_eq!(requested, guild_id);
+
+        // Chunks may arrive out of order.
+        for event in [
+            members_chunk_event(&nonce, "4", 1),
+            members_chunk_event("other", "3", 0),
+            members_chunk_event(&nonce, "2", 0),
+        ] {
+            dispatch(&shard, &event);
+        }
+
+        let mut user_ids: Vec<_> = members.await.unwrap().iter().map(|m| m.user.id).collect();
+        user_ids.sort();
+        assert_eq!(user_ids, [UserId::new(2), UserId::new(4)]);
+
+        // The last chunk ends the collector.
+        dispatch(&shard, &members_chunk_event(&nonce, "5", 1));
+        assert!(shard.collectors.lock().unwrap().is_empty());
+    }
+
+    fn reaction_event(name: &str, emoji: &str) -> Event {
+        from_value(json!({
+            "t": name,
+            "d": {
+                "user_id": "1",
+                "channel_id": "2",
+                "message_id": "3",
+                "emoji": {"id": null, "name": emoji},
+                "burst": false,
+                "type": 0,
+            },
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn reaction_actions() {
+        let added = reaction_event("MESSAGE_REACTION_ADD", "👍");
+        let removed = reaction_event("MESSAGE_REACTION_REMOVE", "👍");
+
+        let action = ReactionAction::from_event(&added).unwrap();
+        assert!(action.is_added());
+        assert_eq!(action.message_id, MessageId::new(3));
+
+        assert!(ReactionAction::from_event(&removed).unwrap().is_removed());
+    }
+}
@@ -1,5 +1,7 @@
 //! A set of constants used by the library.
 
+use std::time::Duration;
+
 /// The maximum length of the textual size of an embed.
 pub const EMBED_MAX_LENGTH: usize = 6000;
 
@@ -21,6 +23,21 @@ pub const MESSAGE_CODE_LIMIT: usize = 2000;
 /// The maximum number of members the bot can fetch at once
 pub const MEMBER_FETCH_LIMIT: u64 = 1000;
 
+/// The maximum number of thread members the bot can fetch at once
+pub const THREAD_MEMBER_FETCH_LIMIT: u64 = 100;
+
+/// The maximum number of channels shown in a guild's welcome screen.
+pub const WELCOME_CHANNEL_MAX_COUNT: usize = 5;
+
+/// The minimum number of default channels required to enable a guild's onboarding in
+/// [`OnboardingMode::Default`].
+///
+/// [`OnboardingMode::Default`]: crate::model::guild::OnboardingMode::Default
+pub const ONBOARDING_DEFAULT_CHANNEL_MIN_COUNT: usize = 7;
+
+/// The maximum duration a member can be timed out for, 28 days.
+pub const MEMBER_TIMEOUT_MAX: Duration = Duration::from_secs(28 * 24 * 60 * 60);
+
 /// The [UserAgent] sent along with every request.
 ///
 /// [UserAgent]: ::reqwest::header::USER_AGENT
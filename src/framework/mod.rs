@@ -102,6 +102,51 @@ pub trait Framework: Send + Sync {
         let _: &Client = client;
     }
     /// Called on every incoming event.
+    ///
+    /// This is not limited to messages: frameworks may just as well treat e.g.
+    /// [`FullEvent::InteractionCreate`] or [`FullEvent::MessageUpdate`] as command triggers,
+    /// without registering a separate [`EventHandler`].
+    ///
+    /// Events are dispatched to the framework in their own task, concurrently to the event
+    /// handlers, so no ordering between the two is guaranteed.
+    ///
+    /// # Examples
+    ///
+    /// A framework reacting to both new and edited messages:
+    ///
+    /// ```rust,no_run
+    /// use serenity::async_trait;
+    /// use serenity::client::{Context, FullEvent};
+    /// use serenity::framework::Framework;
+    /// use serenity::model::channel::Message;
+    ///
+    /// struct EditAwareFramework;
+    ///
+    /// impl EditAwareFramework {
+    ///     async fn run_command(&self, ctx: &Context, msg: &Message) {
+    ///         if msg.content == "!ping" {
+    ///             let _ = msg.reply(ctx, "Pong!").await;
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// #[async_trait]
+    /// impl Framework for EditAwareFramework {
+    ///     async fn dispatch(&self, ctx: Context, event: FullEvent) {
+    ///         match event {
+    ///             FullEvent::Message {
+    ///                 new_message,
+    ///             } => self.run_command(&ctx, &new_message).await,
+    ///             FullEvent::MessageUpdate {
+    ///                 new: Some(new), ..
+    ///             } => self.run_command(&ctx, &new).await,
+    ///             _ => {},
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [`EventHandler`]: crate::client::EventHandler
     async fn dispatch(&self, ctx: Context, event: FullEvent);
 }
 
@@ -1,4 +1,5 @@
 use std::borrow::Cow;
+use std::collections::HashMap;
 use std::error::Error as StdError;
 use std::fmt;
 use std::marker::PhantomData;
@@ -317,6 +318,8 @@ enum State {
 #[derive(Clone, Debug)]
 pub struct Args {
     message: String,
+    /// The message passed to the command, if flags were removed from [`Self::message`].
+    original: Option<String>,
     args: Vec<Token>,
     offset: usize,
     state: State,
@@ -389,6 +392,7 @@ impl Args {
         Args {
             args,
             message: message.to_string(),
+            original: None,
             offset: 0,
             state: State::None,
         }
@@ -850,11 +854,119 @@ impl Args {
         Ok(parsed)
     }
 
+    /// Starting from the offset, remove all `key=value` flags from the arguments and return them.
+    ///
+    /// Values may be quoted to contain delimiters, in which case the quotes are removed. Arguments
+    /// that are quoted as a whole, such as `"a=b"`, are not flags. If a key is given more than
+    /// once, the last value is kept.
+    ///
+    /// The removed flags are no longer part of [`Self::rest`], but can still be accessed via
+    /// [`Self::message`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::framework::standard::{Args, Delimiter};
+    ///
+    /// let message = r#"@user reason="spamming links" days=7"#;
+    /// let mut args = Args::new(message, &[Delimiter::Single(' ')]);
+    ///
+    /// let flags = args.flags();
+    /// assert_eq!(flags["reason"], "spamming links");
+    /// assert_eq!(flags["days"], "7");
+    ///
+    /// assert_eq!(args.rest(), "@user");
+    /// assert_eq!(args.single::<String>().unwrap(), "@user");
+    /// assert!(args.is_empty());
+    /// ```
+    pub fn flags(&mut self) -> HashMap<String, String> {
+        let mut flags = HashMap::new();
+        // The byte ranges of the message to remove, in order.
+        let mut removed: Vec<(usize, usize)> = Vec::new();
+
+        let mut i = self.offset;
+        while i < self.args.len() {
+            let token = self.args[i];
+            let arg = &self.message[token.span.0..token.span.1];
+
+            let flag = match arg.split_once('=') {
+                Some((key, _))
+                    if token.kind == TokenKind::Argument
+                        && !key.is_empty()
+                        && !key.starts_with(['"', '\u{201C}']) =>
+                {
+                    key
+                },
+                _ => {
+                    i += 1;
+                    continue;
+                },
+            };
+
+            // A quoted value spans all arguments up to its closing quote.
+            let value_start = token.span.0 + flag.len() + 1;
+            let mut last = i;
+            if self.message[value_start..].starts_with(['"', '\u{201C}']) {
+                let closing = self.args[i..]
+                    .iter()
+                    .position(|t| is_quoted(&self.message[value_start..t.span.1]));
+                if let Some(offset) = closing {
+                    last = i + offset;
+                }
+            }
+
+            let value = &self.message[value_start..self.args[last].span.1];
+            flags.insert(flag.to_string(), remove_quotes(value).to_string());
+
+            // Remove the delimiter following the flag. If there are only flags left, remove
+            // everything after the preceding argument instead, including preceding flags.
+            let start = token.span.0;
+            if let Some(next) = self.args.get(last + 1) {
+                removed.push((start, next.span.0));
+            } else {
+                let previous_end = i.checked_sub(1).map_or(start, |p| self.args[p].span.1);
+                removed.retain(|(start, _)| *start < previous_end);
+                removed.push((previous_end, self.message.len()));
+            }
+            self.args.drain(i..=last);
+        }
+
+        if !removed.is_empty() {
+            self.remove_ranges(&removed);
+        }
+
+        flags
+    }
+
+    /// Removes the given ordered byte ranges from the message, shifting the spans of all
+    /// arguments accordingly.
+    fn remove_ranges(&mut self, ranges: &[(usize, usize)]) {
+        let mut message = String::with_capacity(self.message.len());
+        let mut kept_until = 0;
+        for &(start, end) in ranges {
+            message.push_str(&self.message[kept_until..start]);
+            kept_until = end;
+        }
+        message.push_str(&self.message[kept_until..]);
+
+        for token in &mut self.args {
+            let shift: usize = ranges
+                .iter()
+                .filter(|(_, end)| *end <= token.span.0)
+                .map(|(start, end)| end - start)
+                .sum();
+            token.span = (token.span.0 - shift, token.span.1 - shift);
+        }
+
+        let original = std::mem::replace(&mut self.message, message);
+        self.original.get_or_insert(original);
+    }
+
     /// Get the original, unmodified message passed to the command.
     #[inline]
     #[must_use]
     pub fn message(&self) -> &str {
-        &self.message
+        self.original.as_deref().unwrap_or(&self.message)
     }
 
     /// Starting from the offset, return the remainder of available arguments.
@@ -996,3 +1108,72 @@ impl<'a> Iterator for RawArguments<'a> {
         Some(s)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn space_args(message: &str) -> Args {
+        Args::new(message, &[Delimiter::Single(' ')])
+    }
+
+    #[test]
+    fn flags() {
+        let mut args = space_args(r#"@user reason="spamming = bad" days=7 extra"#);
+        let flags = args.flags();
+
+        assert_eq!(flags.len(), 2);
+        assert_eq!(flags["reason"], "spamming = bad");
+        assert_eq!(flags["days"], "7");
+        assert_eq!(args.rest(), "@user extra");
+        assert_eq!(args.len(), 2);
+        assert_eq!(args.single::<String>().unwrap(), "@user");
+        assert_eq!(args.single::<String>().unwrap(), "extra");
+        assert_eq!(args.message(), r#"@user reason="spamming = bad" days=7 extra"#);
+    }
+
+    #[test]
+    fn flags_trailing() {
+        let mut args = space_args("a b=1 c=2");
+        assert_eq!(args.flags().len(), 2);
+        assert_eq!(args.rest(), "a");
+
+        let mut args = space_args("b=1 c=2");
+        assert_eq!(args.flags().len(), 2);
+        assert_eq!(args.rest(), "");
+        assert!(args.is_empty());
+    }
+
+    #[test]
+    fn flags_duplicate_keys() {
+        let mut args = space_args("days=1 days=7");
+        let flags = args.flags();
+
+        assert_eq!(flags.len(), 1);
+        assert_eq!(flags["days"], "7");
+    }
+
+    #[test]
+    fn flags_quoted_arguments() {
+        let mut args = space_args(r#""a=b" =c d= "unterminated=e"#);
+        let flags = args.flags();
+
+        assert_eq!(flags.len(), 1);
+        assert_eq!(flags["d"], "");
+        assert_eq!(args.single_quoted::<String>().unwrap(), "a=b");
+        assert_eq!(args.single::<String>().unwrap(), "=c");
+    }
+
+    #[test]
+    fn flags_from_offset() {
+        let mut args = Args::new("x=1, y=2, z", &[Delimiter::Multiple(", ".into())]);
+        args.advance();
+        let flags = args.flags();
+
+        assert_eq!(flags.len(), 1);
+        assert_eq!(flags["y"], "2");
+        assert_eq!(args.rest(), "z");
+        args.rewind();
+        assert_eq!(args.current(), Some("x=1"));
+    }
+}
@@ -13,8 +13,8 @@
 //! use serenity::framework::standard::{
 //!     help_commands,
 //!     Args,
-//!     CommandGroup,
 //!     CommandResult,
+//!     GroupRef,
 //!     HelpOptions,
 //!     StandardFramework,
 //! };
@@ -30,7 +30,7 @@
 //!     msg: &Message,
 //!     args: Args,
 //!     help_options: &'static HelpOptions,
-//!     groups: &[&'static CommandGroup],
+//!     groups: &[GroupRef],
 //!     owners: HashSet<UserId>,
 //! ) -> CommandResult {
 //! #  #[cfg(all(feature = "cache", feature = "http"))]
@@ -59,16 +59,15 @@ use levenshtein::levenshtein;
 #[cfg(all(feature = "cache", feature = "http"))]
 use tracing::warn;
 
-#[cfg(all(feature = "cache", feature = "http"))]
-use super::structures::Command as InternalCommand;
 #[cfg(all(feature = "cache", feature = "http"))]
 use super::{
     has_correct_permissions,
     has_correct_roles,
     Args,
     Check,
-    CommandGroup,
     CommandOptions,
+    CommandRef,
+    GroupRef,
     HelpBehaviour,
     HelpOptions,
     OnlyIn,
@@ -299,21 +298,21 @@ async fn check_command_behaviour(
 fn nested_commands_search<'rec, 'a: 'rec>(
     ctx: &'rec Context,
     msg: &'rec Message,
-    group: &'rec CommandGroup,
-    commands: &'rec [&'static InternalCommand],
+    group: &'rec GroupRef,
+    commands: &'rec [CommandRef],
     name: &'rec mut String,
     help_options: &'a HelpOptions,
     similar_commands: &'rec mut Vec<SuggestedCommandName>,
     owners: &'rec HashSet<UserId, impl std::hash::BuildHasher + Send + Sync>,
-) -> BoxFuture<'rec, Option<&'a InternalCommand>> {
+) -> BoxFuture<'rec, Option<CommandRef>> {
     async move {
         for command in commands {
-            let mut command = *command;
+            let mut command = command.clone();
 
             let search_command_name_matched = {
                 let mut command_found = None;
 
-                for command_name in command.options.names {
+                for command_name in command.options().names {
                     if name == *command_name {
                         command_found = Some(*command_name);
 
@@ -327,7 +326,7 @@ fn nested_commands_search<'rec, 'a: 'rec>(
                     // Since the command could not be found in the group, we now will identify if
                     // the command is actually using a sub-command. We iterate all command names
                     // and check if one matches, if it does, we potentially have a sub-command.
-                    for command_name in command.options.names {
+                    for command_name in command.options().names {
                         if starts_with_whole_word(name, command_name) {
                             name.drain(..=command_name.len());
                             break;
@@ -341,8 +340,8 @@ fn nested_commands_search<'rec, 'a: 'rec>(
                                     == check_command_behaviour(
                                         ctx,
                                         msg,
-                                        command.options,
-                                        group.options.checks,
+                                        command.options(),
+                                        group.options().checks,
                                         owners,
                                         help_options,
                                     )
@@ -360,11 +359,9 @@ fn nested_commands_search<'rec, 'a: 'rec>(
                     // issued to the help-system.
                     let name_str = name.as_str();
                     let sub_command_found = command
-                        .options
-                        .sub_commands
-                        .iter()
-                        .find(|n| n.options.names.contains(&name_str))
-                        .copied();
+                        .sub_commands()
+                        .into_iter()
+                        .find(|n| n.options().names.contains(&name_str));
 
                     // If we found a sub-command, we replace the parent with it. This allows the
                     // help-system to extract information from the sub-command.
@@ -375,15 +372,16 @@ fn nested_commands_search<'rec, 'a: 'rec>(
                             == check_command_behaviour(
                                 ctx,
                                 msg,
-                                command.options,
-                                group.options.checks,
+                                command.options(),
+                                group.options().checks,
                                 owners,
                                 help_options,
                             )
                             .await
                         {
+                            let sub_command_name = sub_command.name();
                             command = sub_command;
-                            Some(sub_command.options.names[0])
+                            Some(sub_command_name)
                         } else {
                             break;
                         }
@@ -392,7 +390,7 @@ fn nested_commands_search<'rec, 'a: 'rec>(
                             ctx,
                             msg,
                             group,
-                            command.options.sub_commands,
+                            &command.sub_commands(),
                             name,
                             help_options,
                             similar_commands,
@@ -412,8 +410,8 @@ fn nested_commands_search<'rec, 'a: 'rec>(
                     == check_command_behaviour(
                         ctx,
                         msg,
-                        command.options,
-                        group.options.checks,
+                        command.options(),
+                        group.options().checks,
                         owners,
                         help_options,
                     )
@@ -436,7 +434,7 @@ fn nested_commands_search<'rec, 'a: 'rec>(
 fn nested_group_command_search<'rec, 'a: 'rec>(
     ctx: &'rec Context,
     msg: &'rec Message,
-    groups: &'rec [&'static CommandGroup],
+    groups: &'rec [GroupRef],
     name: &'rec mut String,
     help_options: &'a HelpOptions,
     similar_commands: &'rec mut Vec<SuggestedCommandName>,
@@ -444,9 +442,8 @@ fn nested_group_command_search<'rec, 'a: 'rec>(
 ) -> BoxFuture<'rec, Result<CustomisedHelpData<'a>, ()>> {
     async move {
         for group in groups {
-            let group = *group;
             let group_behaviour =
-                check_common_behaviour(ctx, msg, &group.options, owners, help_options);
+                check_common_behaviour(ctx, msg, &group.options(), owners, help_options);
 
             match &group_behaviour {
                 HelpBehaviour::Nothing => (),
@@ -455,8 +452,12 @@ fn nested_group_command_search<'rec, 'a: 'rec>(
                 },
             }
 
-            if !group.options.prefixes.is_empty()
-                && !group.options.prefixes.iter().any(|prefix| trim_prefixless_group(prefix, name))
+            if !group.options().prefixes.is_empty()
+                && !group
+                    .options()
+                    .prefixes
+                    .iter()
+                    .any(|prefix| trim_prefixless_group(prefix, name))
             {
                 continue;
             }
@@ -465,7 +466,7 @@ fn nested_group_command_search<'rec, 'a: 'rec>(
                 ctx,
                 msg,
                 group,
-                group.options.commands,
+                &group.commands(),
                 name,
                 help_options,
                 similar_commands,
@@ -474,7 +475,7 @@ fn nested_group_command_search<'rec, 'a: 'rec>(
             .await;
 
             if let Some(command) = found {
-                let options = &command.options;
+                let options = command.options();
 
                 if !options.help_available {
                     return Ok(CustomisedHelpData::NoCommandFound {
@@ -482,7 +483,7 @@ fn nested_group_command_search<'rec, 'a: 'rec>(
                     });
                 }
 
-                let is_only = |only| group.options.only_in == only || options.only_in == only;
+                let is_only = |only| group.options().only_in == only || options.only_in == only;
 
                 let available_text = if is_only(OnlyIn::Dm) {
                     &help_options.dm_only_text
@@ -495,28 +496,27 @@ fn nested_group_command_search<'rec, 'a: 'rec>(
                 similar_commands
                     .sort_unstable_by(|a, b| a.levenshtein_distance.cmp(&b.levenshtein_distance));
 
-                let check_names: Vec<String> = command
-                    .options
+                let check_names: Vec<String> = options
                     .checks
                     .iter()
-                    .chain(group.options.checks.iter())
+                    .chain(group.options().checks.iter())
                     .filter(|check| check.display_in_help)
                     .map(|check| check.name.to_string())
                     .collect();
 
-                let sub_command_names: Vec<String> = options
-                    .sub_commands
+                let sub_command_names: Vec<String> = command
+                    .sub_commands()
                     .iter()
-                    .filter(|cmd| cmd.options.help_available)
-                    .map(|cmd| cmd.options.names[0].to_string())
+                    .filter(|cmd| cmd.options().help_available)
+                    .map(|cmd| cmd.name().to_string())
                     .collect();
 
                 return Ok(CustomisedHelpData::SingleCommand {
                     command: Command {
                         name: options.names[0],
                         description: options.desc,
-                        group_name: group.name,
-                        group_prefixes: group.options.prefixes,
+                        group_name: group.name(),
+                        group_prefixes: group.options().prefixes,
                         checks: check_names,
                         aliases: options.names[1..].to_vec(),
                         availability: available_text,
@@ -530,7 +530,7 @@ fn nested_group_command_search<'rec, 'a: 'rec>(
             if let Ok(found) = nested_group_command_search(
                 ctx,
                 msg,
-                group.options.sub_groups,
+                &group.sub_groups(),
                 name,
                 help_options,
                 similar_commands,
@@ -553,7 +553,7 @@ fn nested_group_command_search<'rec, 'a: 'rec>(
 async fn fetch_single_command<'a>(
     ctx: &Context,
     msg: &Message,
-    groups: &[&'static CommandGroup],
+    groups: &[GroupRef],
     name: &'a str,
     help_options: &'a HelpOptions,
     owners: &HashSet<UserId, impl std::hash::BuildHasher + Send + Sync>,
@@ -579,15 +579,15 @@ async fn fetch_single_command<'a>(
 async fn fill_eligible_commands<'a>(
     ctx: &Context,
     msg: &Message,
-    commands: &[&'static InternalCommand],
+    commands: &[CommandRef],
     owners: &HashSet<UserId, impl std::hash::BuildHasher + Send + Sync>,
     help_options: &'a HelpOptions,
-    group: &'a CommandGroup,
+    group: &'a GroupRef,
     to_fill: &mut GroupCommandsPair,
     highest_formatter: &mut HelpBehaviour,
 ) {
-    to_fill.name = group.name;
-    to_fill.prefixes = group.options.prefixes.to_vec();
+    to_fill.name = group.name();
+    to_fill.prefixes = group.options().prefixes.to_vec();
 
     let group_behaviour = {
         if let HelpBehaviour::Hide = highest_formatter {
@@ -595,7 +595,7 @@ async fn fill_eligible_commands<'a>(
         } else {
             std::cmp::max(
                 *highest_formatter,
-                check_common_behaviour(ctx, msg, &group.options, owners, help_options),
+                check_common_behaviour(ctx, msg, &group.options(), owners, help_options),
             )
         }
     };
@@ -603,9 +603,7 @@ async fn fill_eligible_commands<'a>(
     *highest_formatter = group_behaviour;
 
     for command in commands {
-        let command = *command;
-        let options = &command.options;
-        let name = &options.names[0];
+        let name = command.name();
 
         if group_behaviour != HelpBehaviour::Nothing {
             let name = format_command_name!(&group_behaviour, &name);
@@ -617,8 +615,8 @@ async fn fill_eligible_commands<'a>(
         let command_behaviour = check_command_behaviour(
             ctx,
             msg,
-            command.options,
-            group.options.checks,
+            command.options(),
+            group.options().checks,
             owners,
             help_options,
         )
@@ -635,10 +633,10 @@ async fn fill_eligible_commands<'a>(
 fn fetch_all_eligible_commands_in_group<'rec, 'a: 'rec>(
     ctx: &'rec Context,
     msg: &'rec Message,
-    commands: &'rec [&'static InternalCommand],
+    commands: &'rec [CommandRef],
     owners: &'rec HashSet<UserId, impl std::hash::BuildHasher + Send + Sync>,
     help_options: &'a HelpOptions,
-    group: &'a CommandGroup,
+    group: &'a GroupRef,
     highest_formatter: HelpBehaviour,
 ) -> BoxFuture<'rec, GroupCommandsPair> {
     async move {
@@ -657,22 +655,22 @@ fn fetch_all_eligible_commands_in_group<'rec, 'a: 'rec>(
         )
         .await;
 
-        for sub_group in group.options.sub_groups {
+        for sub_group in group.sub_groups() {
+            let sub_group_commands = sub_group.commands();
+
             if HelpBehaviour::Hide == highest_formatter {
                 break;
-            } else if sub_group.options.commands.is_empty()
-                && sub_group.options.sub_groups.is_empty()
-            {
+            } else if sub_group_commands.is_empty() && sub_group.sub_groups().is_empty() {
                 continue;
             }
 
             let grouped_cmd = fetch_all_eligible_commands_in_group(
                 ctx,
                 msg,
-                sub_group.options.commands,
+                &sub_group_commands,
                 owners,
                 help_options,
-                sub_group,
+                &sub_group,
                 highest_formatter,
             )
             .await;
@@ -690,15 +688,13 @@ fn fetch_all_eligible_commands_in_group<'rec, 'a: 'rec>(
 async fn create_command_group_commands_pair_from_groups<'a>(
     ctx: &Context,
     msg: &Message,
-    groups: &[&'static CommandGroup],
+    groups: &[GroupRef],
     owners: &HashSet<UserId, impl std::hash::BuildHasher + Send + Sync>,
     help_options: &'a HelpOptions,
 ) -> Vec<GroupCommandsPair> {
     let mut listed_groups: Vec<GroupCommandsPair> = Vec::default();
 
     for group in groups {
-        let group = *group;
-
         let group_with_cmds = create_single_group(ctx, msg, group, owners, help_options).await;
 
         if !group_with_cmds.command_names.is_empty() || !group_with_cmds.sub_groups.is_empty() {
@@ -714,14 +710,14 @@ async fn create_command_group_commands_pair_from_groups<'a>(
 async fn create_single_group(
     ctx: &Context,
     msg: &Message,
-    group: &CommandGroup,
+    group: &GroupRef,
     owners: &HashSet<UserId, impl std::hash::BuildHasher + Send + Sync>,
     help_options: &HelpOptions,
 ) -> GroupCommandsPair {
     let mut group_with_cmds = fetch_all_eligible_commands_in_group(
         ctx,
         msg,
-        group.options.commands,
+        &group.commands(),
         owners,
         help_options,
         group,
@@ -729,8 +725,8 @@ async fn create_single_group(
     )
     .await;
 
-    group_with_cmds.name = group.name;
-    group_with_cmds.summary = group.options.summary;
+    group_with_cmds.name = group.name();
+    group_with_cmds.summary = group.options().summary;
 
     group_with_cmds
 }
@@ -757,18 +753,18 @@ fn trim_prefixless_group(group_name: &str, searched_group: &mut String) -> bool
 pub fn searched_lowercase<'rec, 'a: 'rec>(
     ctx: &'rec Context,
     msg: &'rec Message,
-    group: &'rec CommandGroup,
+    group: &'rec GroupRef,
     owners: &'rec HashSet<UserId, impl std::hash::BuildHasher + Send + Sync>,
     help_options: &'a HelpOptions,
     searched_named_lowercase: &'rec mut String,
 ) -> BoxFuture<'rec, Option<CustomisedHelpData<'a>>> {
     async move {
         let is_prefixless_group = {
-            group.options.prefixes.is_empty()
-                && trim_prefixless_group(&group.name.to_lowercase(), searched_named_lowercase)
+            group.options().prefixes.is_empty()
+                && trim_prefixless_group(&group.name().to_lowercase(), searched_named_lowercase)
         };
         let mut progressed = is_prefixless_group;
-        let is_word_prefix = group.options.prefixes.iter().any(|prefix| {
+        let is_word_prefix = group.options().prefixes.iter().any(|prefix| {
             if starts_with_whole_word(searched_named_lowercase, prefix) {
                 searched_named_lowercase.drain(..=prefix.len());
                 progressed = true;
@@ -783,19 +779,19 @@ pub fn searched_lowercase<'rec, 'a: 'rec>(
             if !single_group.command_names.is_empty() {
                 return Some(CustomisedHelpData::GroupedCommands {
                     help_description: group
-                        .options
+                        .options()
                         .description
                         .map(ToString::to_string)
                         .unwrap_or_default(),
                     groups: vec![single_group],
                 });
             }
-        } else if progressed || group.options.prefixes.is_empty() {
-            for sub_group in group.options.sub_groups {
+        } else if progressed || group.options().prefixes.is_empty() {
+            for sub_group in group.sub_groups() {
                 if let Some(found_set) = searched_lowercase(
                     ctx,
                     msg,
-                    sub_group,
+                    &sub_group,
                     owners,
                     help_options,
                     searched_named_lowercase,
@@ -820,7 +816,7 @@ pub async fn create_customised_help_data<'a>(
     ctx: &Context,
     msg: &Message,
     args: &'a Args,
-    groups: &[&'static CommandGroup],
+    groups: &[GroupRef],
     owners: &HashSet<UserId, impl std::hash::BuildHasher + Send + Sync>,
     help_options: &'a HelpOptions,
 ) -> CustomisedHelpData<'a> {
@@ -1125,8 +1121,8 @@ async fn send_error_embed(
 /// use serenity::framework::standard::macros::help;
 /// use serenity::framework::standard::{
 ///     Args,
-///     CommandGroup,
 ///     CommandResult,
+///     GroupRef,
 ///     HelpOptions,
 ///     StandardFramework,
 /// };
@@ -1138,7 +1134,7 @@ async fn send_error_embed(
 ///     msg: &Message,
 ///     args: Args,
 ///     help_options: &'static HelpOptions,
-///     groups: &[&'static CommandGroup],
+///     groups: &[GroupRef],
 ///     owners: HashSet<UserId>,
 /// ) -> CommandResult {
 ///     let _ = with_embeds(context, msg, args, &help_options, groups, owners).await?;
@@ -1159,7 +1155,7 @@ pub async fn with_embeds(
     msg: &Message,
     args: Args,
     help_options: &HelpOptions,
-    groups: &[&'static CommandGroup],
+    groups: &[GroupRef],
     owners: HashSet<UserId, impl std::hash::BuildHasher + Send + Sync>,
 ) -> Result<Message, Error> {
     let formatted_help =
@@ -1328,8 +1324,8 @@ fn single_command_to_plain_string(help_options: &HelpOptions, command: &Command<
 /// use serenity::framework::standard::macros::help;
 /// use serenity::framework::standard::{
 ///     Args,
-///     CommandGroup,
 ///     CommandResult,
+///     GroupRef,
 ///     HelpOptions,
 ///     StandardFramework,
 /// };
@@ -1341,7 +1337,7 @@ fn single_command_to_plain_string(help_options: &HelpOptions, command: &Command<
 ///     msg: &Message,
 ///     args: Args,
 ///     help_options: &'static HelpOptions,
-///     groups: &[&'static CommandGroup],
+///     groups: &[GroupRef],
 ///     owners: HashSet<UserId>,
 /// ) -> CommandResult {
 ///     let _ = plain(context, msg, args, &help_options, groups, owners).await?;
@@ -1359,7 +1355,7 @@ pub async fn plain(
     msg: &Message,
     args: Args,
     help_options: &HelpOptions,
-    groups: &[&'static CommandGroup],
+    groups: &[GroupRef],
     owners: HashSet<UserId, impl std::hash::BuildHasher + Send + Sync>,
 ) -> Result<Message, Error> {
     let formatted_help =
@@ -16,6 +16,7 @@ mod structures;
 
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 pub use args::{Args, Delimiter, Error as ArgError, Iter, RawArguments};
 use async_trait::async_trait;
@@ -24,7 +25,7 @@ use futures::future::BoxFuture;
 use parse::map::{CommandMap, GroupMap, Map};
 use parse::{Invoke, ParseError};
 pub use structures::buckets::BucketBuilder;
-use structures::buckets::{Bucket, RateLimitAction};
+use structures::buckets::{Bucket, BucketKey, RateLimitAction};
 pub use structures::*;
 use tokio::sync::Mutex;
 use tokio::time::sleep;
@@ -39,6 +40,7 @@ use crate::client::{Context, FullEvent};
 use crate::model::channel::Message;
 #[cfg(feature = "cache")]
 use crate::model::guild::Member;
+use crate::model::id::{ChannelId, GuildId, ShardId, UserId};
 use crate::model::permissions::Permissions;
 #[cfg(all(feature = "cache", feature = "http", feature = "model"))]
 use crate::model::{guild::Role, id::RoleId};
@@ -77,6 +79,29 @@ pub enum DispatchError {
     TooManyArguments { max: u16, given: usize },
 }
 
+/// A record of a single command invocation, passed to the [`StandardFramework::on_invocation`]
+/// hook.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct InvocationRecord {
+    /// The primary name of the invoked command.
+    pub command: String,
+    /// The guild the command was invoked in, if any.
+    pub guild_id: Option<GuildId>,
+    /// The channel the command was invoked in.
+    pub channel_id: ChannelId,
+    /// The user who invoked the command.
+    pub user_id: UserId,
+    /// The shard which received the invoking message.
+    pub shard_id: ShardId,
+    /// How long the command function took to execute.
+    pub duration: Duration,
+    /// How long the invocation was delayed by the command's ratelimit bucket.
+    pub bucket_delay: Duration,
+    /// Whether the command function returned [`Ok`].
+    pub success: bool,
+}
+
 type DispatchHook =
     for<'fut> fn(&'fut Context, &'fut Message, DispatchError, &'fut str) -> BoxFuture<'fut, ()>;
 type BeforeHook = for<'fut> fn(&'fut Context, &'fut Message, &'fut str) -> BoxFuture<'fut, bool>;
@@ -90,6 +115,7 @@ type UnrecognisedHook =
     for<'fut> fn(&'fut Context, &'fut Message, &'fut str) -> BoxFuture<'fut, ()>;
 type NormalMessageHook = for<'fut> fn(&'fut Context, &'fut Message) -> BoxFuture<'fut, ()>;
 type PrefixOnlyHook = for<'fut> fn(&'fut Context, &'fut Message) -> BoxFuture<'fut, ()>;
+type InvocationHook = for<'fut> fn(&'fut Context, &'fut InvocationRecord) -> BoxFuture<'fut, ()>;
 
 /// A utility for easily managing dispatches to commands.
 ///
@@ -98,7 +124,7 @@ type PrefixOnlyHook = for<'fut> fn(&'fut Context, &'fut Message) -> BoxFuture<'f
 /// [module-level documentation]: self
 #[derive(Default)]
 pub struct StandardFramework {
-    groups: Vec<(&'static CommandGroup, Map)>,
+    groups: parking_lot::RwLock<Vec<(GroupRef, Arc<Map>)>>,
     buckets: Mutex<HashMap<String, Bucket>>,
     before: Option<BeforeHook>,
     after: Option<AfterHook>,
@@ -106,6 +132,7 @@ pub struct StandardFramework {
     unrecognised_command: Option<UnrecognisedHook>,
     normal_message: Option<NormalMessageHook>,
     prefix_only: Option<PrefixOnlyHook>,
+    on_invocation: Option<InvocationHook>,
     config: parking_lot::RwLock<Configuration>,
     help: Option<&'static HelpCommand>,
     /// Whether the framework has been "initialized".
@@ -207,15 +234,20 @@ impl StandardFramework {
             || (config.ignore_webhooks && msg.webhook_id.is_some())
     }
 
+    #[allow(clippy::too_many_arguments)]
     async fn should_fail<'a>(
         &'a self,
         ctx: &'a Context,
         msg: &'a Message,
         args: &'a mut Args,
-        command: &'static CommandOptions,
-        group: &'static GroupOptions,
+        command: &'a CommandRef,
+        group: &'a GroupRef,
+        bucket_delay: &'a mut Duration,
+        bucket_key: &'a mut Option<BucketKey>,
     ) -> Option<DispatchError> {
-        if let Some(min) = command.min_args {
+        let options = command.options();
+
+        if let Some(min) = options.min_args {
             if args.len() < min as usize {
                 return Some(DispatchError::NotEnoughArguments {
                     min,
@@ -224,7 +256,7 @@ impl StandardFramework {
             }
         }
 
-        if let Some(max) = command.max_args {
+        if let Some(max) = options.max_args {
             if args.len() > max as usize {
                 return Some(DispatchError::TooManyArguments {
                     max,
@@ -235,7 +267,7 @@ impl StandardFramework {
 
         {
             let config = self.config.read();
-            if (group.owner_privilege && command.owner_privilege)
+            if (group.options().owner_privilege && options.owner_privilege)
                 && config.owners.contains(&msg.author.id)
             {
                 return None;
@@ -278,8 +310,12 @@ impl StandardFramework {
             {
                 let mut buckets = self.buckets.lock().await;
 
-                if let Some(bucket) = command.bucket.and_then(|b| buckets.get_mut(b)) {
-                    if let Some(rate_limit_info) = bucket.take(ctx, msg).await {
+                if let Some(bucket) = options.bucket.and_then(|b| buckets.get_mut(b)) {
+                    let key = match *bucket_key {
+                        Some(key) => key,
+                        None => *bucket_key.insert(bucket.key(ctx, msg, args).await),
+                    };
+                    if let Some(rate_limit_info) = bucket.take(ctx, msg, key).await {
                         duration = match rate_limit_info.action {
                             RateLimitAction::Cancelled | RateLimitAction::FailedDelay => {
                                 return Some(DispatchError::Ratelimited(rate_limit_info))
@@ -291,13 +327,16 @@ impl StandardFramework {
             }
 
             match duration {
-                Some(duration) => sleep(duration).await,
+                Some(duration) => {
+                    sleep(duration).await;
+                    *bucket_delay += duration;
+                },
                 None => break,
             }
         }
 
-        for check in group.checks.iter().chain(command.checks.iter()) {
-            let res = (check.function)(ctx, msg, args, command).await;
+        for check in group.options().checks.iter().chain(options.checks) {
+            let res = (check.function)(ctx, msg, args, options).await;
 
             if let Result::Err(reason) = res {
                 return Some(DispatchError::CheckFailed(check.name, reason));
@@ -362,6 +401,18 @@ impl StandardFramework {
         self
     }
 
+    /// Adds a group defined at runtime with a [`GroupBuilder`]. The framework owns the group and
+    /// its command handlers, which are freed when the framework is dropped.
+    #[must_use]
+    pub fn built_group(mut self, group: BuiltGroup) -> Self {
+        let group = GroupRef::from(group);
+        let map = self.group_map(&group);
+        self.groups.get_mut().push((group, map));
+        self.initialized = true;
+
+        self
+    }
+
     /// Adds a group to be used by the framework. Primary use-case is runtime modification of
     /// groups in the framework; will _not_ mark the framework as initialized. Refer to
     /// [`Self::group`] for adding groups in initial configuration.
@@ -369,17 +420,9 @@ impl StandardFramework {
     /// Note: does _not_ return [`Self`] like many other commands. This is because it's not
     /// intended to be chained as the other commands are.
     pub fn group_add(&mut self, group: &'static CommandGroup) {
-        let config = self.config.read();
-        let map = if group.options.prefixes.is_empty() {
-            Map::Prefixless(
-                GroupMap::new(group.options.sub_groups, &config),
-                CommandMap::new(group.options.commands, &config),
-            )
-        } else {
-            Map::WithPrefixes(GroupMap::new(&[group], &config))
-        };
-
-        self.groups.push((group, map));
+        let group = GroupRef::Static(group);
+        let map = self.group_map(&group);
+        self.groups.get_mut().push((group, map));
     }
 
     /// Removes a group from being used in the framework. Primary use-case is runtime modification
@@ -389,7 +432,118 @@ impl StandardFramework {
     /// intended to be chained as the other commands are.
     pub fn group_remove(&mut self, group: &'static CommandGroup) {
         // Iterates through the vector and if a given group _doesn't_ match, we retain it
-        self.groups.retain(|&(g, _)| g != group);
+        self.groups.get_mut().retain(|(g, _)| !matches!(g, GroupRef::Static(g) if *g == group));
+    }
+
+    /// Adds a group to the framework while it is in use, e.g. after it has been passed to
+    /// [`ClientBuilder::framework`]. If the group was already added, it is replaced.
+    ///
+    /// The group will be available for dispatch and will be listed by the help commands starting
+    /// with the next received message.
+    ///
+    /// To keep a handle to the framework after passing it to the client, wrap it in an [`Arc`]
+    /// and pass a clone of it to [`ClientBuilder::framework`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use std::sync::Arc;
+    /// # use serenity::prelude::*;
+    /// # struct Handler;
+    /// # impl EventHandler for Handler {}
+    /// use serenity::framework::standard::macros::{command, group};
+    /// use serenity::framework::standard::{CommandResult, StandardFramework};
+    /// use serenity::model::channel::Message;
+    ///
+    /// #[command]
+    /// async fn ping(ctx: &Context, msg: &Message) -> CommandResult {
+    ///     msg.channel_id.say(&ctx.http, "pong!").await?;
+    ///     Ok(())
+    /// }
+    ///
+    /// #[group]
+    /// #[commands(ping)]
+    /// struct General;
+    ///
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let framework = Arc::new(StandardFramework::new());
+    ///
+    /// let mut client = Client::builder("token", GatewayIntents::default())
+    ///     .event_handler(Handler)
+    ///     .framework(Arc::clone(&framework))
+    ///     .await?;
+    ///
+    /// // Later, e.g. when a plugin is loaded:
+    /// framework.add_group_dynamic(&GENERAL_GROUP);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`ClientBuilder::framework`]: crate::client::ClientBuilder::framework
+    pub fn add_group_dynamic(&self, group: &'static CommandGroup) {
+        self.add_dynamic(
+            GroupRef::Static(group),
+            |g| matches!(g, GroupRef::Static(g) if *g == group),
+        );
+    }
+
+    /// Adds a group defined with a [`GroupBuilder`] to the framework while it is in use. If a
+    /// built group with the same name was already added, it is replaced, so that groups loaded
+    /// from a configuration file may be reloaded.
+    ///
+    /// Refer to [`Self::add_group_dynamic`] for more information.
+    pub fn add_built_group_dynamic(&self, group: BuiltGroup) {
+        let name = group.name();
+        self.add_dynamic(
+            GroupRef::from(group),
+            |g| matches!(g, GroupRef::Built(g) if g.name() == name),
+        );
+    }
+
+    fn add_dynamic(&self, group: GroupRef, is_replaced: impl Fn(&GroupRef) -> bool) {
+        let map = self.group_map(&group);
+
+        let mut groups = self.groups.write();
+        if let Some(entry) = groups.iter_mut().find(|(g, _)| is_replaced(g)) {
+            *entry = (group, map);
+        } else {
+            groups.push((group, map));
+        }
+    }
+
+    /// Removes the group with the given name from the framework while it is in use, returning it
+    /// if it was found. This applies to groups defined with the [`group`] macro and with a
+    /// [`GroupBuilder`] alike. Refer to [`Self::add_group_dynamic`] for adding groups at runtime.
+    ///
+    /// Commands of the group that are already executing are unaffected and run to completion, but
+    /// no new invocations of them will be dispatched.
+    ///
+    /// [`group`]: macros::group
+    pub fn remove_group(&self, name: &str) -> Option<GroupRef> {
+        let mut groups = self.groups.write();
+        let index = groups.iter().position(|(g, _)| g.name() == name)?;
+
+        Some(groups.remove(index).0)
+    }
+
+    /// Returns the groups which are currently registered, in the order they are matched in.
+    #[must_use]
+    pub fn groups(&self) -> Vec<GroupRef> {
+        self.groups.read().iter().map(|(g, _)| g.clone()).collect()
+    }
+
+    fn group_map(&self, group: &GroupRef) -> Arc<Map> {
+        let config = self.config.read();
+        let map = if group.options().prefixes.is_empty() {
+            Map::Prefixless(
+                GroupMap::new(&group.sub_groups(), &config),
+                CommandMap::new(&group.commands(), &config),
+            )
+        } else {
+            Map::WithPrefixes(GroupMap::new(std::slice::from_ref(group), &config))
+        };
+
+        Arc::new(map)
     }
 
     /// Specify the function that's called in case a command wasn't executed for one reason or
@@ -536,6 +690,66 @@ impl StandardFramework {
         self
     }
 
+    /// Specify the function to be called with a record of every executed command, after the
+    /// [`Self::after`] hook has run.
+    ///
+    /// The hook is only called for commands which have actually been executed, so commands which
+    /// failed to dispatch - for example due to a failed check - are not recorded. Those are
+    /// passed to [`Self::on_dispatch_error`] instead.
+    ///
+    /// # Examples
+    ///
+    /// Using [`Self::on_invocation`] to log command usage:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::prelude::*;
+    /// use serenity::framework::standard::macros::hook;
+    /// use serenity::framework::standard::InvocationRecord;
+    /// use serenity::framework::StandardFramework;
+    ///
+    /// #[hook]
+    /// async fn invocation_hook(_: &Context, record: &InvocationRecord) {
+    ///     println!(
+    ///         "{} ran {} in {:?} (success: {})",
+    ///         record.user_id, record.command, record.duration, record.success
+    ///     );
+    /// }
+    ///
+    /// let framework = StandardFramework::new().on_invocation(invocation_hook);
+    /// ```
+    #[must_use]
+    pub fn on_invocation(mut self, f: InvocationHook) -> Self {
+        self.on_invocation = Some(f);
+
+        self
+    }
+
+    /// Constructs a record of an invocation and passes it to the [`Self::on_invocation`] hook.
+    async fn record_invocation(
+        &self,
+        ctx: &Context,
+        msg: &Message,
+        command: &str,
+        duration: Duration,
+        bucket_delay: Duration,
+        success: bool,
+    ) {
+        if let Some(on_invocation) = &self.on_invocation {
+            let record = InvocationRecord {
+                command: command.to_string(),
+                guild_id: msg.guild_id,
+                channel_id: msg.channel_id,
+                user_id: msg.author.id,
+                shard_id: ctx.shard_id,
+                duration,
+                bucket_delay,
+                success,
+            };
+
+            on_invocation(ctx, &record).await;
+        }
+    }
+
     /// Specify the function to be called if no command could be dispatched.
     ///
     /// # Examples
@@ -645,11 +859,15 @@ impl Framework for StandardFramework {
             return;
         }
 
+        // Take a snapshot of the groups, so that groups may be added or removed while commands are
+        // being parsed and executed.
+        let groups = self.groups.read().clone();
+
         let invocation = parse::command(
             &ctx,
             &msg,
             &mut stream,
-            &self.groups,
+            &groups,
             &config,
             self.help.map(|h| h.options.names),
         )
@@ -690,7 +908,7 @@ impl Framework for StandardFramework {
 
                 let args = Args::new(stream.rest(), &config.delimiters);
 
-                let groups = self.groups.iter().map(|(g, _)| *g).collect::<Vec<_>>();
+                let groups = groups.into_iter().map(|(g, _)| g).collect::<Vec<_>>();
 
                 // `parse_command` promises to never return a help invocation if
                 // `StandardFramework::help` is `None`.
@@ -703,12 +921,17 @@ impl Framework for StandardFramework {
                     }
                 }
 
+                let start = Instant::now();
                 let res =
                     (help.fun)(&mut ctx, &msg, args, help.options, &groups, config.owners).await;
+                let elapsed = start.elapsed();
+                let success = res.is_ok();
 
                 if let Some(after) = &self.after {
                     after(&mut ctx, &msg, name, res).await;
                 }
+
+                self.record_invocation(&ctx, &msg, name, elapsed, Duration::ZERO, success).await;
             },
             Invoke::Command {
                 command,
@@ -720,11 +943,11 @@ impl Framework for StandardFramework {
                     let mut delims = Cow::Borrowed(&config.delimiters);
 
                     // If user has configured the command's own delimiters, use those instead.
-                    if !command.options.delimiters.is_empty() {
+                    if !command.options().delimiters.is_empty() {
                         // FIXME: Get rid of this allocation.
-                        let mut v = Vec::with_capacity(command.options.delimiters.len());
+                        let mut v = Vec::with_capacity(command.options().delimiters.len());
 
-                        for delim in command.options.delimiters {
+                        for delim in command.options().delimiters {
                             if delim.len() == 1 {
                                 // Should always be Some() in this case
                                 #[allow(clippy::unwrap_used)]
@@ -741,18 +964,29 @@ impl Framework for StandardFramework {
                     Args::new(stream.rest(), &delims)
                 };
 
-                if let Some(error) =
-                    self.should_fail(&ctx, &msg, &mut args, command.options, group.options).await
+                let mut bucket_delay = Duration::ZERO;
+                let mut bucket_key = None;
+                if let Some(error) = self
+                    .should_fail(
+                        &ctx,
+                        &msg,
+                        &mut args,
+                        &command,
+                        &group,
+                        &mut bucket_delay,
+                        &mut bucket_key,
+                    )
+                    .await
                 {
                     if let Some(dispatch) = &self.dispatch {
-                        let command_name = command.options.names[0];
+                        let command_name = command.name();
                         dispatch(&mut ctx, &msg, error, command_name).await;
                     }
 
                     return;
                 }
 
-                let name = command.options.names[0];
+                let name = command.name();
 
                 if let Some(before) = &self.before {
                     if !before(&mut ctx, &msg, name).await {
@@ -760,25 +994,43 @@ impl Framework for StandardFramework {
                     }
                 }
 
-                let res = (command.fun)(&mut ctx, &msg, args).await;
+                let start = Instant::now();
+                let res = command.call(&ctx, &msg, args).await;
+                let elapsed = start.elapsed();
+                let success = res.is_ok();
 
                 // Check if the command wants to revert the bucket by giving back a ticket.
                 if matches!(&res, Err(e) if e.is::<RevertBucket>()) {
                     let mut buckets = self.buckets.lock().await;
 
-                    if let Some(bucket) = command.options.bucket.and_then(|b| buckets.get_mut(b)) {
-                        bucket.give(&ctx, &msg).await;
+                    let bucket = command.options().bucket.and_then(|b| buckets.get_mut(b));
+                    if let (Some(bucket), Some(key)) = (bucket, bucket_key) {
+                        bucket.give(&ctx, &msg, key).await;
                     }
                 }
 
                 if let Some(after) = &self.after {
                     after(&mut ctx, &msg, name, res).await;
                 }
+
+                self.record_invocation(&ctx, &msg, name, elapsed, bucket_delay, success).await;
             },
         }
     }
 }
 
+/// Allows keeping a handle to the framework after passing it to the client, e.g. to add groups
+/// with [`StandardFramework::add_group_dynamic`] at runtime.
+///
+/// All of the framework's state is behind locks and the standard framework needs no
+/// initialization, so the [`Arc`] may be cloned before building the client.
+#[async_trait]
+impl Framework for Arc<StandardFramework> {
+    async fn dispatch(&self, ctx: Context, event: FullEvent) {
+        (**self).dispatch(ctx, event).await;
+    }
+}
+
 pub trait CommonOptions {
     fn required_permissions(&self) -> &Permissions;
     fn allowed_roles(&self) -> &'static [&'static str];
@@ -883,3 +1135,619 @@ pub(crate) fn has_correct_roles(
             .any(|g| member.roles.contains(&g.id))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use tokio::sync::RwLock;
+    use typemap_rev::{TypeMap, TypeMapKey};
+
+    use super::*;
+    use crate::gateway::ShardMessenger;
+    use crate::http::Http;
+    use crate::model::Colour;
+
+    struct Records;
+
+    impl TypeMapKey for Records {
+        type Value = Vec<(&'static str, Option<InvocationRecord>)>;
+    }
+
+    const fn command_options(names: &'static [&'static str]) -> CommandOptions {
+        CommandOptions {
+            checks: &[],
+            bucket: None,
+            names,
+            desc: None,
+            delimiters: &[],
+            usage: None,
+            examples: &[],
+            min_args: None,
+            max_args: None,
+            allowed_roles: &[],
+            required_permissions: Permissions::empty(),
+            help_available: true,
+            only_in: OnlyIn::None,
+            owners_only: false,
+            owner_privilege: true,
+            sub_commands: &[],
+        }
+    }
+
+    fn ok<'fut>(_: &'fut Context, _: &'fut Message, _: Args) -> BoxFuture<'fut, CommandResult> {
+        Box::pin(async { Ok(()) })
+    }
+
+    fn fail<'fut>(_: &'fut Context, _: &'fut Message, _: Args) -> BoxFuture<'fut, CommandResult> {
+        Box::pin(async { Err("failed".into()) })
+    }
+
+    static OK_OPTIONS: CommandOptions = command_options(&["ok"]);
+    static OK_COMMAND: Command = Command {
+        fun: ok,
+        options: &OK_OPTIONS,
+    };
+    static FAIL_OPTIONS: CommandOptions = command_options(&["fail"]);
+    static FAIL_COMMAND: Command = Command {
+        fun: fail,
+        options: &FAIL_OPTIONS,
+    };
+    static GROUP_OPTIONS: GroupOptions = GroupOptions {
+        prefixes: &[],
+        only_in: OnlyIn::None,
+        owners_only: false,
+        owner_privilege: true,
+        help_available: true,
+        allowed_roles: &[],
+        required_permissions: Permissions::empty(),
+        checks: &[],
+        default_command: None,
+        description: None,
+        summary: None,
+        commands: &[&OK_COMMAND, &FAIL_COMMAND],
+        sub_groups: &[],
+    };
+    static GROUP: CommandGroup = CommandGroup {
+        name: "test",
+        options: &GROUP_OPTIONS,
+    };
+
+    fn after_hook<'fut>(
+        ctx: &'fut Context,
+        _: &'fut Message,
+        _: &'fut str,
+        _: Result<(), CommandError>,
+    ) -> BoxFuture<'fut, ()> {
+        Box::pin(async move {
+            ctx.data.write().await.get_mut::<Records>().unwrap().push(("after", None));
+        })
+    }
+
+    fn invocation_hook<'fut>(
+        ctx: &'fut Context,
+        record: &'fut InvocationRecord,
+    ) -> BoxFuture<'fut, ()> {
+        Box::pin(async move {
+            let mut data = ctx.data.write().await;
+            data.get_mut::<Records>().unwrap().push(("invocation", Some(record.clone())));
+        })
+    }
+
+    fn context() -> Context {
+        let mut data = TypeMap::new();
+        data.insert::<Records>(Vec::new());
+
+        Context {
+            data: Arc::new(RwLock::new(data)),
+            shard: ShardMessenger {
+                tx: futures::channel::mpsc::unbounded().0,
+                #[cfg(feature = "collector")]
+                collectors: Arc::new(std::sync::Mutex::new(Vec::new())),
+            },
+            shard_id: ShardId(3),
+            http: Arc::new(Http::new("")),
+            #[cfg(feature = "cache")]
+            cache: Arc::new(Cache::new()),
+        }
+    }
+
+    async fn invoke(content: &str) -> Vec<(&'static str, Option<InvocationRecord>)> {
+        let framework =
+            StandardFramework::new().group(&GROUP).after(after_hook).on_invocation(invocation_hook);
+        let ctx = context();
+
+        let mut msg = Message::default();
+        msg.content = content.to_string();
+        msg.guild_id = Some(GuildId::new(1));
+        msg.channel_id = ChannelId::new(2);
+        msg.author.id = UserId::new(4);
+
+        framework
+            .dispatch(ctx.clone(), FullEvent::Message {
+                new_message: msg,
+            })
+            .await;
+
+        let mut data = ctx.data.write().await;
+        std::mem::take(data.get_mut::<Records>().unwrap())
+    }
+
+    fn assert_record(record: &InvocationRecord, command: &str, success: bool) {
+        assert_eq!(record.command, command);
+        assert_eq!(record.guild_id, Some(GuildId::new(1)));
+        assert_eq!(record.channel_id, ChannelId::new(2));
+        assert_eq!(record.user_id, UserId::new(4));
+        assert_eq!(record.shard_id, ShardId(3));
+        assert_eq!(record.bucket_delay, Duration::ZERO);
+        assert_eq!(record.success, success);
+    }
+
+    #[tokio::test]
+    async fn invocation_record_success() {
+        let records = invoke("~ok").await;
+
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].0, "after");
+        assert_eq!(records[1].0, "invocation");
+        assert_record(records[1].1.as_ref().unwrap(), "ok", true);
+    }
+
+    #[tokio::test]
+    async fn invocation_record_error() {
+        let records = invoke("~fail").await;
+
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].0, "after");
+        assert_eq!(records[1].0, "invocation");
+        assert_record(records[1].1.as_ref().unwrap(), "fail", false);
+    }
+
+    #[tokio::test]
+    async fn invocation_record_not_a_command() {
+        assert!(invoke("hello").await.is_empty());
+        assert!(invoke("~unknown").await.is_empty());
+    }
+
+    fn revert<'fut>(_: &'fut Context, _: &'fut Message, _: Args) -> BoxFuture<'fut, CommandResult> {
+        Box::pin(async { Err(RevertBucket.into()) })
+    }
+
+    fn ratelimited_hook<'fut>(
+        ctx: &'fut Context,
+        _: &'fut Message,
+        error: DispatchError,
+        _: &'fut str,
+    ) -> BoxFuture<'fut, ()> {
+        Box::pin(async move {
+            if matches!(error, DispatchError::Ratelimited(_)) {
+                ctx.data.write().await.get_mut::<Records>().unwrap().push(("ratelimited", None));
+            }
+        })
+    }
+
+    static DAILY_OPTIONS: CommandOptions = CommandOptions {
+        bucket: Some("daily"),
+        ..command_options(&["daily"])
+    };
+    static DAILY_COMMAND: Command = Command {
+        fun: ok,
+        options: &DAILY_OPTIONS,
+    };
+    static REVERT_OPTIONS: CommandOptions = CommandOptions {
+        bucket: Some("daily"),
+        ..command_options(&["revert"])
+    };
+    static REVERT_COMMAND: Command = Command {
+        fun: revert,
+        options: &REVERT_OPTIONS,
+    };
+    static BUCKET_GROUP_OPTIONS: GroupOptions = GroupOptions {
+        commands: &[&DAILY_COMMAND, &REVERT_COMMAND],
+        ..GROUP_OPTIONS
+    };
+    static BUCKET_GROUP: CommandGroup = CommandGroup {
+        name: "bucket",
+        options: &BUCKET_GROUP_OPTIONS,
+    };
+
+    async fn invoke_bucketed(
+        framework: &StandardFramework,
+        ctx: &Context,
+        content: &str,
+    ) -> Vec<&'static str> {
+        let mut msg = Message::default();
+        msg.content = content.to_string();
+        msg.author.id = UserId::new(4);
+
+        framework
+            .dispatch(ctx.clone(), FullEvent::Message {
+                new_message: msg,
+            })
+            .await;
+
+        let mut data = ctx.data.write().await;
+        std::mem::take(data.get_mut::<Records>().unwrap()).into_iter().map(|(r, _)| r).collect()
+    }
+
+    #[tokio::test]
+    async fn bucket_key_fn() {
+        let bucket = BucketBuilder::default().time_span(60).limit(1).key_fn(|_, msg, args| {
+            BucketKey::new((msg.author.id, args.current().map(str::to_owned)))
+        });
+        let framework = StandardFramework::new()
+            .group(&BUCKET_GROUP)
+            .after(after_hook)
+            .on_dispatch_error(ratelimited_hook)
+            .bucket("daily", bucket)
+            .await;
+        let ctx = context();
+
+        assert_eq!(invoke_bucketed(&framework, &ctx, "~daily claim").await, ["after"]);
+        assert_eq!(invoke_bucketed(&framework, &ctx, "~daily claim").await, ["ratelimited"]);
+        assert_eq!(invoke_bucketed(&framework, &ctx, "~daily gift").await, ["after"]);
+    }
+
+    #[tokio::test]
+    async fn bucket_key_fn_revert() {
+        let bucket = BucketBuilder::default()
+            .time_span(60)
+            .limit(1)
+            .key_fn(|_, _, args| BucketKey::new(args.current().map(str::to_owned)));
+        let framework = StandardFramework::new()
+            .group(&BUCKET_GROUP)
+            .after(after_hook)
+            .on_dispatch_error(ratelimited_hook)
+            .bucket("daily", bucket)
+            .await;
+        let ctx = context();
+
+        assert_eq!(invoke_bucketed(&framework, &ctx, "~revert claim").await, ["after"]);
+        assert_eq!(invoke_bucketed(&framework, &ctx, "~daily claim").await, ["after"]);
+        assert_eq!(invoke_bucketed(&framework, &ctx, "~revert claim").await, ["ratelimited"]);
+    }
+
+    #[cfg(feature = "cache")]
+    #[tokio::test]
+    async fn bucket_category_thread() {
+        use crate::model::channel::{ChannelType, GuildChannel};
+        use crate::model::event::GuildCreateEvent;
+        use crate::model::guild::Guild;
+
+        let framework = StandardFramework::new()
+            .group(&BUCKET_GROUP)
+            .after(after_hook)
+            .on_dispatch_error(ratelimited_hook)
+            .bucket("daily", BucketBuilder::new_category().time_span(60).limit(1))
+            .await;
+        let ctx = context();
+
+        let channel = |id: u64, parent_id: u64, kind: ChannelType| GuildChannel {
+            id: ChannelId::new(id),
+            guild_id: GuildId::new(1),
+            parent_id: Some(ChannelId::new(parent_id)),
+            kind,
+            ..Default::default()
+        };
+        let guild = Guild {
+            id: GuildId::new(1),
+            channels: [channel(10, 100, ChannelType::Text), channel(20, 200, ChannelType::Text)]
+                .into_iter()
+                .map(|channel| (channel.id, channel))
+                .collect(),
+            threads: vec![channel(11, 10, ChannelType::PublicThread)],
+            ..Default::default()
+        };
+        ctx.cache.update(&mut GuildCreateEvent {
+            guild,
+        });
+
+        let invoke = |channel_id: u64| {
+            let mut msg = Message::default();
+            msg.content = "~daily".to_string();
+            msg.guild_id = Some(GuildId::new(1));
+            msg.channel_id = ChannelId::new(channel_id);
+            let (framework, ctx) = (&framework, &ctx);
+            async move {
+                framework
+                    .dispatch(ctx.clone(), FullEvent::Message {
+                        new_message: msg,
+                    })
+                    .await;
+                let mut data = ctx.data.write().await;
+                let records = std::mem::take(data.get_mut::<Records>().unwrap());
+                records.into_iter().map(|(r, _)| r).collect::<Vec<_>>()
+            }
+        };
+
+        assert_eq!(invoke(10).await, ["after"]);
+        // The thread shares the category of its parent channel.
+        assert_eq!(invoke(11).await, ["ratelimited"]);
+        assert_eq!(invoke(20).await, ["after"]);
+    }
+
+    struct CheckFailures;
+
+    impl TypeMapKey for CheckFailures {
+        type Value = Vec<(&'static str, String)>;
+    }
+
+    fn pass_check<'fut>(
+        _: &'fut Context,
+        _: &'fut Message,
+        _: &'fut mut Args,
+        _: &'fut CommandOptions,
+    ) -> BoxFuture<'fut, Result<(), Reason>> {
+        Box::pin(async { Ok(()) })
+    }
+
+    fn owner_check<'fut>(
+        _: &'fut Context,
+        _: &'fut Message,
+        _: &'fut mut Args,
+        _: &'fut CommandOptions,
+    ) -> BoxFuture<'fut, Result<(), Reason>> {
+        Box::pin(async { Err(Reason::User("not the owner".to_string())) })
+    }
+
+    fn admin_check<'fut>(
+        _: &'fut Context,
+        _: &'fut Message,
+        _: &'fut mut Args,
+        _: &'fut CommandOptions,
+    ) -> BoxFuture<'fut, Result<(), Reason>> {
+        Box::pin(async { Err(Reason::Log("not an admin".to_string())) })
+    }
+
+    const fn check(name: &'static str, function: CheckFunction) -> Check {
+        Check {
+            name,
+            function,
+            check_in_help: true,
+            display_in_help: true,
+        }
+    }
+
+    // What `#[checks(Owner | Admin)]` and `#[checks(Owner | Pass)]` expand to.
+    static PASS_CHECK: Check = check("Pass", pass_check);
+    static OWNER_CHECK: Check = check("Owner", owner_check);
+    static ADMIN_CHECK: Check = check("Admin", admin_check);
+    static OWNER_OR_ADMIN_CHECKS: [&Check; 2] = [&OWNER_CHECK, &ADMIN_CHECK];
+    static OWNER_OR_ADMIN_CHECK: Check = Check {
+        display_in_help: OWNER_CHECK.display_in_help || ADMIN_CHECK.display_in_help,
+        check_in_help: OWNER_CHECK.check_in_help && ADMIN_CHECK.check_in_help,
+        ..check("Owner | Admin", |ctx, msg, args, options| {
+            any_of_checks(&OWNER_OR_ADMIN_CHECKS, ctx, msg, args, options)
+        })
+    };
+    static OWNER_OR_PASS_CHECKS: [&Check; 2] = [&OWNER_CHECK, &PASS_CHECK];
+    static OWNER_OR_PASS_CHECK: Check = check("Owner | Pass", |ctx, msg, args, options| {
+        any_of_checks(&OWNER_OR_PASS_CHECKS, ctx, msg, args, options)
+    });
+
+    static GUARDED_OPTIONS: CommandOptions = CommandOptions {
+        checks: &[&PASS_CHECK, &OWNER_OR_ADMIN_CHECK],
+        ..command_options(&["guarded"])
+    };
+    static GUARDED_COMMAND: Command = Command {
+        fun: ok,
+        options: &GUARDED_OPTIONS,
+    };
+    static ALLOWED_OPTIONS: CommandOptions = CommandOptions {
+        checks: &[&OWNER_OR_PASS_CHECK],
+        ..command_options(&["allowed"])
+    };
+    static ALLOWED_COMMAND: Command = Command {
+        fun: ok,
+        options: &ALLOWED_OPTIONS,
+    };
+    static CHECKS_GROUP_OPTIONS: GroupOptions = GroupOptions {
+        commands: &[&GUARDED_COMMAND, &ALLOWED_COMMAND],
+        ..GROUP_OPTIONS
+    };
+    static CHECKS_GROUP: CommandGroup = CommandGroup {
+        name: "checks",
+        options: &CHECKS_GROUP_OPTIONS,
+    };
+
+    fn check_failed_hook<'fut>(
+        ctx: &'fut Context,
+        _: &'fut Message,
+        error: DispatchError,
+        _: &'fut str,
+    ) -> BoxFuture<'fut, ()> {
+        Box::pin(async move {
+            if let DispatchError::CheckFailed(name, reason) = error {
+                let mut data = ctx.data.write().await;
+                data.get_mut::<CheckFailures>().unwrap().push((name, reason.to_string()));
+            }
+        })
+    }
+
+    #[tokio::test]
+    async fn any_of_checks_dispatch() {
+        let framework = StandardFramework::new()
+            .group(&CHECKS_GROUP)
+            .after(after_hook)
+            .on_dispatch_error(check_failed_hook);
+        let ctx = context();
+        ctx.data.write().await.insert::<CheckFailures>(Vec::new());
+
+        assert_eq!(invoke_bucketed(&framework, &ctx, "~allowed").await, ["after"]);
+        assert!(ctx.data.read().await.get::<CheckFailures>().unwrap().is_empty());
+
+        assert!(invoke_bucketed(&framework, &ctx, "~guarded").await.is_empty());
+        let data = ctx.data.read().await;
+        assert_eq!(data.get::<CheckFailures>().unwrap(), &[(
+            "Owner | Admin",
+            "AnyOf [Owner: User not the owner, Admin: Log not an admin]".to_string()
+        )]);
+    }
+
+    #[tokio::test]
+    async fn built_commands_dispatch() {
+        let reply = Arc::new("configured");
+        let state = Arc::clone(&reply);
+        let group = GroupBuilder::new("built")
+            .prefix("cfg")
+            .command(
+                CommandBuilder::new("say")
+                    .alias("echo")
+                    .handler(move |ctx, _, _| {
+                        let reply = *state;
+                        Box::pin(async move {
+                            let mut data = ctx.data.write().await;
+                            data.get_mut::<Records>().unwrap().push((reply, None));
+                            Ok(())
+                        })
+                    })
+                    .sub_command(
+                        CommandBuilder::new("loud")
+                            .handler(|_, _, _| Box::pin(async { Err("too loud".into()) })),
+                    ),
+            )
+            .build();
+        assert_eq!(group.name(), "built");
+        let framework = StandardFramework::new().built_group(group).after(after_hook);
+        let ctx = context();
+
+        assert_eq!(invoke_bucketed(&framework, &ctx, "~cfg say").await, ["configured", "after"]);
+        assert_eq!(invoke_bucketed(&framework, &ctx, "~cfg echo").await, ["configured", "after"]);
+        assert_eq!(invoke_bucketed(&framework, &ctx, "~cfg say loud").await, ["after"]);
+        assert!(invoke_bucketed(&framework, &ctx, "~say").await.is_empty());
+
+        // The framework owns the handlers and the state they capture.
+        drop(framework);
+        assert_eq!(Arc::strong_count(&reply), 1);
+    }
+
+    fn options_check<'fut>(
+        _: &'fut Context,
+        _: &'fut Message,
+        _: &'fut mut Args,
+        options: &'fut CommandOptions,
+    ) -> BoxFuture<'fut, Result<(), Reason>> {
+        let options = format!("{:?} {:?} {:?}", options.names, options.bucket, options.delimiters);
+        Box::pin(async move { Err(Reason::Log(options)) })
+    }
+
+    static OPTIONS_CHECK: Check = check("Options", options_check);
+
+    #[tokio::test]
+    async fn built_command_checks_receive_options() {
+        let group = GroupBuilder::new("built")
+            .command(
+                CommandBuilder::new("roll")
+                    .alias("dice")
+                    .bucket("rolls")
+                    .delimiter(";")
+                    .check(&OPTIONS_CHECK),
+            )
+            .build();
+        let framework =
+            StandardFramework::new().built_group(group).on_dispatch_error(check_failed_hook);
+        let ctx = context();
+        ctx.data.write().await.insert::<CheckFailures>(Vec::new());
+
+        assert!(invoke_bucketed(&framework, &ctx, "~dice").await.is_empty());
+        let data = ctx.data.read().await;
+        assert_eq!(data.get::<CheckFailures>().unwrap(), &[(
+            "Options",
+            r#"Log ["roll", "dice"] Some("rolls") [";"]"#.to_string()
+        )]);
+    }
+
+    fn reply_command(name: &str, reply: &'static str) -> CommandBuilder {
+        CommandBuilder::new(name).description(reply).handler(move |ctx, _, _| {
+            Box::pin(async move {
+                ctx.data.write().await.get_mut::<Records>().unwrap().push((reply, None));
+                Ok(())
+            })
+        })
+    }
+
+    fn group_names_help<'fut>(
+        ctx: &'fut Context,
+        _: &'fut Message,
+        _: Args,
+        _: &'fut HelpOptions,
+        groups: &'fut [GroupRef],
+        _: HashSet<UserId>,
+    ) -> BoxFuture<'fut, CommandResult> {
+        Box::pin(async move {
+            let mut data = ctx.data.write().await;
+            let records = data.get_mut::<Records>().unwrap();
+            for group in groups {
+                records.push((group.name(), None));
+                records.extend(
+                    group.commands().iter().filter_map(|c| c.options().desc).map(|d| (d, None)),
+                );
+            }
+            Ok(())
+        })
+    }
+
+    static HELP_OPTIONS: HelpOptions = HelpOptions {
+        names: &["help"],
+        suggestion_text: "",
+        no_help_available_text: "",
+        usage_label: "",
+        usage_sample_label: "",
+        ungrouped_label: "",
+        description_label: "",
+        grouped_label: "",
+        aliases_label: "",
+        guild_only_text: "",
+        checks_label: "",
+        sub_commands_label: "",
+        dm_only_text: "",
+        dm_and_guild_text: "",
+        available_text: "",
+        command_not_found_text: "",
+        individual_command_tip: "",
+        strikethrough_commands_tip_in_dm: None,
+        strikethrough_commands_tip_in_guild: None,
+        group_prefix: "",
+        lacking_role: HelpBehaviour::Nothing,
+        lacking_permissions: HelpBehaviour::Nothing,
+        lacking_ownership: HelpBehaviour::Nothing,
+        lacking_conditions: HelpBehaviour::Nothing,
+        wrong_channel: HelpBehaviour::Nothing,
+        embed_error_colour: Colour::new(0),
+        embed_success_colour: Colour::new(0),
+        max_levenshtein_distance: 0,
+        indention_prefix: "",
+    };
+    static HELP: HelpCommand = HelpCommand {
+        fun: group_names_help,
+        options: &HELP_OPTIONS,
+    };
+
+    #[tokio::test]
+    async fn built_groups_at_runtime() {
+        let framework = Arc::new(StandardFramework::new().group(&GROUP).help(&HELP));
+        let ctx = context();
+        let names = |framework: &StandardFramework| {
+            framework.groups().iter().map(GroupRef::name).collect::<Vec<_>>()
+        };
+
+        let built =
+            |reply| GroupBuilder::new("built").prefix("cfg").command(reply_command("say", reply));
+        framework.add_built_group_dynamic(built("first").build());
+        assert_eq!(invoke_bucketed(&framework, &ctx, "~cfg say").await, ["first"]);
+
+        // Adding a built group with the same name replaces it.
+        framework.add_built_group_dynamic(built("second").build());
+        assert_eq!(names(&framework), ["test", "built"]);
+        assert_eq!(invoke_bucketed(&framework, &ctx, "~cfg say").await, ["second"]);
+
+        // Help commands are passed built groups as well.
+        assert_eq!(invoke_bucketed(&framework, &ctx, "~help").await, ["test", "built", "second"]);
+
+        let removed = framework.remove_group("built");
+        assert!(matches!(removed, Some(GroupRef::Built(group)) if group.name() == "built"));
+        assert_eq!(names(&framework), ["test"]);
+        assert!(invoke_bucketed(&framework, &ctx, "~cfg say").await.is_empty());
+        assert_eq!(invoke_bucketed(&framework, &ctx, "~help").await, ["test"]);
+    }
+}
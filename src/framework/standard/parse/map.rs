@@ -6,7 +6,7 @@ pub enum Map {
     Prefixless(GroupMap, CommandMap),
 }
 
-pub trait ParseMap {
+pub(crate) trait ParseMap {
     type Storage;
 
     fn get(&self, n: &str) -> Option<Self::Storage>;
@@ -17,19 +17,19 @@ pub trait ParseMap {
 
 #[derive(Debug, Default)]
 pub struct CommandMap {
-    cmds: HashMap<String, (&'static Command, Arc<CommandMap>)>,
+    cmds: HashMap<String, (CommandRef, Arc<CommandMap>)>,
     min_length: usize,
     max_length: usize,
 }
 
 impl CommandMap {
-    pub fn new(cmds: &[&'static Command], conf: &Configuration) -> Self {
+    pub fn new(cmds: &[CommandRef], conf: &Configuration) -> Self {
         let mut map = Self::default();
 
         for cmd in cmds {
-            let sub_map = Arc::new(Self::new(cmd.options.sub_commands, conf));
+            let sub_map = Arc::new(Self::new(&cmd.sub_commands(), conf));
 
-            for name in cmd.options.names {
+            for name in cmd.options().names {
                 let len = name.chars().count();
                 map.min_length = std::cmp::min(len, map.min_length);
                 map.max_length = std::cmp::max(len, map.max_length);
@@ -37,7 +37,7 @@ impl CommandMap {
                 let name =
                     if conf.case_insensitive { name.to_lowercase() } else { (*name).to_string() };
 
-                map.cmds.insert(name, (*cmd, Arc::clone(&sub_map)));
+                map.cmds.insert(name, (cmd.clone(), Arc::clone(&sub_map)));
             }
         }
 
@@ -46,7 +46,7 @@ impl CommandMap {
 }
 
 impl ParseMap for CommandMap {
-    type Storage = (&'static Command, Arc<CommandMap>);
+    type Storage = (CommandRef, Arc<CommandMap>);
 
     #[inline]
     fn min_length(&self) -> usize {
@@ -71,27 +71,27 @@ impl ParseMap for CommandMap {
 
 #[derive(Debug, Default)]
 pub struct GroupMap {
-    groups: HashMap<&'static str, (&'static CommandGroup, Arc<GroupMap>, Arc<CommandMap>)>,
+    groups: HashMap<&'static str, (GroupRef, Arc<GroupMap>, Arc<CommandMap>)>,
     min_length: usize,
     max_length: usize,
 }
 
 impl GroupMap {
-    pub fn new(groups: &[&'static CommandGroup], conf: &Configuration) -> Self {
+    pub fn new(groups: &[GroupRef], conf: &Configuration) -> Self {
         let mut map = Self::default();
 
         for group in groups {
-            let subgroups_map = Arc::new(Self::new(group.options.sub_groups, conf));
-            let commands_map = Arc::new(CommandMap::new(group.options.commands, conf));
+            let subgroups_map = Arc::new(Self::new(&group.sub_groups(), conf));
+            let commands_map = Arc::new(CommandMap::new(&group.commands(), conf));
 
-            for prefix in group.options.prefixes {
+            for prefix in group.options().prefixes {
                 let len = prefix.chars().count();
                 map.min_length = std::cmp::min(len, map.min_length);
                 map.max_length = std::cmp::max(len, map.max_length);
 
                 map.groups.insert(
                     *prefix,
-                    (*group, Arc::clone(&subgroups_map), Arc::clone(&commands_map)),
+                    (group.clone(), Arc::clone(&subgroups_map), Arc::clone(&commands_map)),
                 );
             }
         }
@@ -101,7 +101,7 @@ impl GroupMap {
 }
 
 impl ParseMap for GroupMap {
-    type Storage = (&'static CommandGroup, Arc<GroupMap>, Arc<CommandMap>);
+    type Storage = (GroupRef, Arc<GroupMap>, Arc<CommandMap>);
 
     #[inline]
     fn min_length(&self) -> usize {
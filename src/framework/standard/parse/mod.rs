@@ -1,4 +1,4 @@
-use super::{Command, *};
+use super::*;
 use crate::model::prelude::*;
 
 pub mod map;
@@ -275,7 +275,7 @@ fn parse_cmd<'a>(
     msg: &'a Message,
     config: &'a Configuration,
     map: &'a CommandMap,
-) -> BoxFuture<'a, Result<&'static Command, ParseError>> {
+) -> BoxFuture<'a, Result<CommandRef, ParseError>> {
     async move {
         let (n, r) =
             try_parse(stream, map, config.by_space, |s| to_lowercase(config, s).into_owned());
@@ -294,7 +294,7 @@ fn parse_cmd<'a>(
                 stream.take_while_char(char::is_whitespace);
             }
 
-            check_discrepancy(ctx, msg, config, &cmd.options).await.map_err(|e| {
+            check_discrepancy(ctx, msg, config, &cmd.options()).await.map_err(|e| {
                 ParseError::Dispatch {
                     error: e,
                     command_name: n,
@@ -322,7 +322,7 @@ fn parse_group<'a>(
     msg: &'a Message,
     config: &'a Configuration,
     map: &'a GroupMap,
-) -> BoxFuture<'a, Result<(&'static CommandGroup, Arc<CommandMap>), ParseError>> {
+) -> BoxFuture<'a, Result<(GroupRef, Arc<CommandMap>), ParseError>> {
     async move {
         let (n, o) = try_parse(stream, map, config.by_space, ToString::to_string);
 
@@ -333,7 +333,7 @@ fn parse_group<'a>(
                 stream.take_while_char(char::is_whitespace);
             }
 
-            check_discrepancy(ctx, msg, config, &group.options).await.map_err(|e| {
+            check_discrepancy(ctx, msg, config, &group.options()).await.map_err(|e| {
                 ParseError::Dispatch {
                     error: e,
                     command_name: n,
@@ -362,19 +362,19 @@ async fn handle_command<'a>(
     msg: &'a Message,
     config: &'a Configuration,
     map: &'a CommandMap,
-    group: &'static CommandGroup,
+    group: GroupRef,
 ) -> Result<Invoke, ParseError> {
     match parse_cmd(stream, ctx, msg, config, map).await {
         Ok(command) => Ok(Invoke::Command {
             group,
             command,
         }),
-        Err(err) => match group.options.default_command {
+        Err(err) => match group.default_command() {
             Some(command) => {
-                check_discrepancy(ctx, msg, config, &command.options).await.map_err(|e| {
+                check_discrepancy(ctx, msg, config, &command.options()).await.map_err(|e| {
                     ParseError::Dispatch {
                         error: e,
-                        command_name: command.options.names[0].to_string(),
+                        command_name: command.name().to_string(),
                     }
                 })?;
 
@@ -426,7 +426,7 @@ pub async fn command(
     ctx: &Context,
     msg: &Message,
     stream: &mut Stream<'_>,
-    groups: &[(&'static CommandGroup, Map)],
+    groups: &[(GroupRef, Arc<Map>)],
     config: &Configuration,
     help_was_set: Option<&[&'static str]>,
 ) -> Result<Invoke, ParseError> {
@@ -449,7 +449,7 @@ pub async fn command(
     let mut is_prefixless = false;
 
     for (group, map) in groups {
-        match map {
+        match &**map {
             // Includes [group] itself.
             Map::WithPrefixes(map) => {
                 let res = handle_group(stream, ctx, msg, config, map).await;
@@ -467,7 +467,7 @@ pub async fn command(
                     match res {
                         Ok(Invoke::Command {
                             command, ..
-                        }) => Some(command.options.names[0]),
+                        }) => Some(command.name()),
                         Ok(Invoke::Help(name)) => Some(name), // unreachable; fallback just in case
                         Err(ParseError::UnrecognisedCommand(_)) => None,
                         Err(ParseError::Dispatch {
@@ -481,7 +481,7 @@ pub async fn command(
                 let res = handle_group(stream, ctx, msg, config, subgroups).await;
 
                 if let Some(command_name) = command_name_if_recognised(&res) {
-                    check_discrepancy(ctx, msg, config, &group.options).await.map_err(|e| {
+                    check_discrepancy(ctx, msg, config, &group.options()).await.map_err(|e| {
                         ParseError::Dispatch {
                             error: e,
                             command_name: command_name.to_owned(),
@@ -490,10 +490,10 @@ pub async fn command(
                     return res;
                 }
 
-                let res = handle_command(stream, ctx, msg, config, commands, group).await;
+                let res = handle_command(stream, ctx, msg, config, commands, group.clone()).await;
 
                 if let Some(command_name) = command_name_if_recognised(&res) {
-                    check_discrepancy(ctx, msg, config, &group.options).await.map_err(|e| {
+                    check_discrepancy(ctx, msg, config, &group.options()).await.map_err(|e| {
                         ParseError::Dispatch {
                             error: e,
                             command_name: command_name.to_owned(),
@@ -512,6 +512,6 @@ pub async fn command(
 
 #[derive(Debug)]
 pub enum Invoke {
-    Command { group: &'static CommandGroup, command: &'static Command },
+    Command { group: GroupRef, command: CommandRef },
     Help(&'static str),
 }
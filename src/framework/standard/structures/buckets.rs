@@ -1,9 +1,12 @@
+use std::collections::hash_map::DefaultHasher;
 use std::collections::HashMap;
 use std::fmt;
+use std::hash::{Hash, Hasher};
 use std::time::{Duration, Instant};
 
 use futures::future::BoxFuture;
 
+use super::Args;
 use crate::client::Context;
 use crate::internal::tokio::spawn_named;
 use crate::model::channel::Message;
@@ -12,6 +15,43 @@ type Check = for<'fut> fn(&'fut Context, &'fut Message) -> BoxFuture<'fut, bool>
 
 type DelayHook = for<'fut> fn(&'fut Context, &'fut Message) -> BoxFuture<'fut, ()>;
 
+type KeyFn = fn(&Context, &Message, &Args) -> BucketKey;
+
+/// How a bucket computes its [`BucketKey`].
+#[derive(Clone, Copy)]
+pub(crate) enum KeySource {
+    Fn(KeyFn),
+    /// The category of the message's channel, which may require an HTTP request if the channel is
+    /// not cached.
+    #[cfg(feature = "cache")]
+    Category,
+}
+
+/// Identifies who or what a bucket collects tickets for, as returned by a
+/// [`BucketBuilder::key_fn`].
+///
+/// Invocations with equal keys share their tickets.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct BucketKey(Option<u64>);
+
+impl BucketKey {
+    /// Creates a key from any hashable value, such as a user Id or a tuple of a user Id and an
+    /// argument.
+    #[must_use]
+    pub fn new(value: impl Hash) -> Self {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        Self(Some(hasher.finish()))
+    }
+
+    /// A key exempting the invocation from the bucket, such as when a per-guild bucket is used in
+    /// direct messages.
+    #[must_use]
+    pub const fn exempt() -> Self {
+        Self(None)
+    }
+}
+
 pub(crate) struct Ratelimit {
     pub delay: Duration,
     pub limit: Option<(Duration, u32)>,
@@ -37,67 +77,37 @@ impl UnitRatelimit {
 }
 
 /// A bucket offers fine-grained control over the execution of commands.
-pub(crate) enum Bucket {
-    /// The bucket will collect tickets for every invocation of a command.
-    Global(TicketCounter),
-    /// The bucket will collect tickets per user.
-    User(TicketCounter),
-    /// The bucket will collect tickets per guild.
-    Guild(TicketCounter),
-    /// The bucket will collect tickets per channel.
-    Channel(TicketCounter),
-    /// The bucket will collect tickets per category.
-    ///
-    /// This requires the cache, as messages do not contain their channel's category and retrieving
-    /// channel data via HTTP is costly.
-    #[cfg(feature = "cache")]
-    Category(TicketCounter),
+pub(crate) struct Bucket {
+    counter: TicketCounter,
+    key: KeySource,
 }
 
 impl Bucket {
-    #[inline]
-    pub async fn take(&mut self, ctx: &Context, msg: &Message) -> Option<RateLimitInfo> {
-        match self {
-            Self::Global(counter) => counter.take(ctx, msg, 0).await,
-            Self::User(counter) => counter.take(ctx, msg, msg.author.id.get()).await,
-            Self::Guild(counter) => {
-                if let Some(guild_id) = msg.guild_id {
-                    counter.take(ctx, msg, guild_id.get()).await
-                } else {
-                    None
-                }
-            },
-            Self::Channel(counter) => counter.take(ctx, msg, msg.channel_id.get()).await,
-            // This requires the cache, as messages do not contain their channel's category.
+    /// Returns the key the bucket collects tickets for in this invocation.
+    pub async fn key(&self, ctx: &Context, msg: &Message, args: &Args) -> BucketKey {
+        match self.key {
+            KeySource::Fn(key_fn) => key_fn(ctx, msg, args),
             #[cfg(feature = "cache")]
-            Self::Category(counter) => {
-                if let Some(category_id) = msg.category_id(ctx).await {
-                    counter.take(ctx, msg, category_id.get()).await
-                } else {
-                    None
-                }
+            KeySource::Category => {
+                msg.category_id(ctx).await.map_or(BucketKey::exempt(), BucketKey::new)
             },
         }
     }
 
     #[inline]
-    pub async fn give(&mut self, ctx: &Context, msg: &Message) {
-        match self {
-            Self::Global(counter) => counter.give(ctx, msg, 0).await,
-            Self::User(counter) => counter.give(ctx, msg, msg.author.id.get()).await,
-            Self::Guild(counter) => {
-                if let Some(guild_id) = msg.guild_id {
-                    counter.give(ctx, msg, guild_id.get()).await;
-                }
-            },
-            Self::Channel(counter) => counter.give(ctx, msg, msg.channel_id.get()).await,
-            // This requires the cache, as messages do not contain their channel's category.
-            #[cfg(feature = "cache")]
-            Self::Category(counter) => {
-                if let Some(category_id) = msg.category_id(ctx).await {
-                    counter.give(ctx, msg, category_id.get()).await;
-                }
-            },
+    pub async fn take(
+        &mut self,
+        ctx: &Context,
+        msg: &Message,
+        key: BucketKey,
+    ) -> Option<RateLimitInfo> {
+        self.counter.take(ctx, msg, key.0?).await
+    }
+
+    #[inline]
+    pub async fn give(&mut self, ctx: &Context, msg: &Message, key: BucketKey) {
+        if let Some(id) = key.0 {
+            self.counter.give(ctx, msg, id).await;
         }
     }
 }
@@ -343,13 +353,26 @@ impl Default for LimitedFor {
     }
 }
 
+impl LimitedFor {
+    fn key_source(self) -> KeySource {
+        KeySource::Fn(match self {
+            Self::Global => |_, _, _| BucketKey::new(()),
+            Self::User => |_, msg, _| BucketKey::new(msg.author.id),
+            Self::Guild => |_, msg, _| msg.guild_id.map_or(BucketKey::exempt(), BucketKey::new),
+            Self::Channel => |_, msg, _| BucketKey::new(msg.channel_id),
+            #[cfg(feature = "cache")]
+            Self::Category => return KeySource::Category,
+        })
+    }
+}
+
 pub struct BucketBuilder {
     pub(crate) delay: Duration,
     pub(crate) time_span: Duration,
     pub(crate) limit: u32,
     pub(crate) check: Option<Check>,
     pub(crate) delay_action: Option<DelayHook>,
-    pub(crate) limited_for: LimitedFor,
+    pub(crate) key: KeySource,
     pub(crate) await_ratelimits: u32,
 }
 
@@ -361,7 +384,7 @@ impl Default for BucketBuilder {
             limit: 1,
             check: None,
             delay_action: None,
-            limited_for: LimitedFor::default(),
+            key: LimitedFor::default().key_source(),
             await_ratelimits: 0,
         }
     }
@@ -371,37 +394,25 @@ impl BucketBuilder {
     /// A bucket collecting tickets per command invocation.
     #[must_use]
     pub fn new_global() -> Self {
-        Self {
-            limited_for: LimitedFor::Global,
-            ..Default::default()
-        }
+        Self::default().limit_for(LimitedFor::Global)
     }
 
     /// A bucket collecting tickets per user.
     #[must_use]
     pub fn new_user() -> Self {
-        Self {
-            limited_for: LimitedFor::User,
-            ..Default::default()
-        }
+        Self::default().limit_for(LimitedFor::User)
     }
 
     /// A bucket collecting tickets per guild.
     #[must_use]
     pub fn new_guild() -> Self {
-        Self {
-            limited_for: LimitedFor::Guild,
-            ..Default::default()
-        }
+        Self::default().limit_for(LimitedFor::Guild)
     }
 
     /// A bucket collecting tickets per channel.
     #[must_use]
     pub fn new_channel() -> Self {
-        Self {
-            limited_for: LimitedFor::Channel,
-            ..Default::default()
-        }
+        Self::default().limit_for(LimitedFor::Channel)
     }
 
     /// A bucket collecting tickets per channel category.
@@ -410,10 +421,7 @@ impl BucketBuilder {
     #[cfg(feature = "cache")]
     #[must_use]
     pub fn new_category() -> Self {
-        Self {
-            limited_for: LimitedFor::Category,
-            ..Default::default()
-        }
+        Self::default().limit_for(LimitedFor::Category)
     }
 
     /// The "break" time between invocations of a command.
@@ -527,7 +535,30 @@ impl BucketBuilder {
     #[inline]
     #[must_use]
     pub fn limit_for(mut self, target: LimitedFor) -> Self {
-        self.limited_for = target;
+        self.key = target.key_source();
+        self
+    }
+
+    /// Limit the bucket by the key returned by `key_fn`, replacing [`Self::limit_for`].
+    /// Invocations with equal keys share their tickets.
+    ///
+    /// # Examples
+    ///
+    /// Limiting each subcommand of `~daily` per user separately, by keying on the user and the
+    /// first argument:
+    ///
+    /// ```rust
+    /// use serenity::framework::standard::buckets::BucketKey;
+    /// use serenity::framework::standard::BucketBuilder;
+    ///
+    /// let bucket = BucketBuilder::default().time_span(86400).limit(1).key_fn(|_, msg, args| {
+    ///     BucketKey::new((msg.author.id, args.current().unwrap_or_default().to_lowercase()))
+    /// });
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn key_fn(mut self, key_fn: KeyFn) -> Self {
+        self.key = KeySource::Fn(key_fn);
         self
     }
 
@@ -556,14 +587,9 @@ impl BucketBuilder {
             await_ratelimits: self.await_ratelimits,
         };
 
-        match self.limited_for {
-            LimitedFor::User => Bucket::User(counter),
-            LimitedFor::Guild => Bucket::Guild(counter),
-            LimitedFor::Channel => Bucket::Channel(counter),
-            // This requires the cache, as messages do not contain their channel's category.
-            #[cfg(feature = "cache")]
-            LimitedFor::Category => Bucket::Category(counter),
-            LimitedFor::Global => Bucket::Global(counter),
+        Bucket {
+            counter,
+            key: self.key,
         }
     }
 }
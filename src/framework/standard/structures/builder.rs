@@ -0,0 +1,601 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::hash::Hash;
+use std::sync::{Arc, OnceLock};
+
+use futures::future::BoxFuture;
+use parking_lot::Mutex;
+
+use super::{Check, Command, CommandGroup, CommandOptions, CommandResult, GroupOptions, OnlyIn};
+use crate::client::Context;
+use crate::framework::standard::Args;
+use crate::model::channel::Message;
+use crate::model::permissions::Permissions;
+
+/// The function of a command built with a [`CommandBuilder`].
+///
+/// Unlike [`CommandFn`], it may capture state, such as a reply loaded from a configuration file.
+///
+/// [`CommandFn`]: super::CommandFn
+pub type CommandHandler = Arc<
+    dyn for<'fut> Fn(&'fut Context, &'fut Message, Args) -> BoxFuture<'fut, CommandResult>
+        + Send
+        + Sync,
+>;
+
+/// A set of `'static` values, each of which is allocated the first time it is interned.
+struct Interner<T: ?Sized + 'static>(OnceLock<Mutex<HashSet<&'static T>>>);
+
+impl<T: ?Sized + Eq + Hash> Interner<T> {
+    const fn new() -> Self {
+        Self(OnceLock::new())
+    }
+
+    fn intern(&self, value: &T, allocate: impl FnOnce(&T) -> &'static T) -> &'static T {
+        let mut set = self.0.get_or_init(Mutex::default).lock();
+
+        if let Some(&interned) = set.get(value) {
+            return interned;
+        }
+
+        let interned = allocate(value);
+        set.insert(interned);
+        interned
+    }
+}
+
+// The options of commands and groups refer to `'static` text, as the macros define them as
+// statics. Built commands intern their text, so that checks and help commands receive their real
+// options, while groups which are rebuilt at runtime only ever allocate text they haven't seen.
+fn intern_str(value: &str) -> &'static str {
+    static STRS: Interner<str> = Interner::new();
+
+    STRS.intern(value, |value| Box::leak(value.into()))
+}
+
+fn intern_strs(values: &[String]) -> &'static [&'static str] {
+    static SLICES: Interner<[&'static str]> = Interner::new();
+
+    let values = values.iter().map(|value| intern_str(value)).collect::<Vec<_>>();
+    SLICES.intern(&values, |values| Box::leak(values.into()))
+}
+
+fn intern_checks(checks: Vec<&'static Check>) -> &'static [&'static Check] {
+    // Checks are compared by address, as they are statics defined by the `check` macro.
+    static CHECKS: OnceLock<Mutex<HashMap<Vec<usize>, &'static [&'static Check]>>> =
+        OnceLock::new();
+
+    let key = checks.iter().map(|&check| check as *const Check as usize).collect();
+    let mut checks_by_address = CHECKS.get_or_init(Mutex::default).lock();
+    checks_by_address.entry(key).or_insert_with(|| Box::leak(checks.into_boxed_slice()))
+}
+
+/// Defines a command at runtime, for example from a configuration file, as an alternative to the
+/// [`command`] macro. Commands are added to a [`GroupBuilder`].
+///
+/// The text of the command, such as its names and description, is interned: each distinct string
+/// is allocated once and kept for the rest of the program, so that built commands have the same
+/// [`CommandOptions`] as those defined with the macro.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use serenity::framework::standard::{CommandBuilder, GroupBuilder, StandardFramework};
+///
+/// let reply = "pong!".to_string();
+/// let ping =
+///     CommandBuilder::new("ping").description("Replies with pong").handler(move |ctx, msg, _| {
+///         let reply = reply.clone();
+///         Box::pin(async move {
+///             msg.reply(ctx, reply).await?;
+///             Ok(())
+///         })
+///     });
+///
+/// let group = GroupBuilder::new("general").command(ping).build();
+/// let framework = StandardFramework::new().built_group(group);
+/// ```
+///
+/// [`command`]: crate::framework::standard::macros::command
+#[must_use]
+pub struct CommandBuilder {
+    names: Vec<String>,
+    handler: Option<CommandHandler>,
+    checks: Vec<&'static Check>,
+    bucket: Option<String>,
+    desc: Option<String>,
+    delimiters: Vec<String>,
+    usage: Option<String>,
+    examples: Vec<String>,
+    min_args: Option<u16>,
+    max_args: Option<u16>,
+    allowed_roles: Vec<String>,
+    required_permissions: Permissions,
+    help_available: bool,
+    only_in: OnlyIn,
+    owners_only: bool,
+    owner_privilege: bool,
+    sub_commands: Vec<CommandBuilder>,
+}
+
+impl fmt::Debug for CommandBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CommandBuilder")
+            .field("names", &self.names)
+            .field("desc", &self.desc)
+            .field("sub_commands", &self.sub_commands)
+            .finish_non_exhaustive()
+    }
+}
+
+impl CommandBuilder {
+    /// Creates a command invoked by `name`, which does nothing until a [`Self::handler`] is set.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            names: vec![name.into()],
+            handler: None,
+            checks: Vec::new(),
+            bucket: None,
+            desc: None,
+            delimiters: Vec::new(),
+            usage: None,
+            examples: Vec::new(),
+            min_args: None,
+            max_args: None,
+            allowed_roles: Vec::new(),
+            required_permissions: Permissions::empty(),
+            help_available: true,
+            only_in: OnlyIn::None,
+            owners_only: false,
+            owner_privilege: true,
+            sub_commands: Vec::new(),
+        }
+    }
+
+    /// Sets the function called when the command is invoked.
+    pub fn handler<F>(mut self, handler: F) -> Self
+    where
+        F: for<'fut> Fn(&'fut Context, &'fut Message, Args) -> BoxFuture<'fut, CommandResult>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.handler = Some(Arc::new(handler));
+        self
+    }
+
+    /// Adds an alternative name the command can be invoked by.
+    pub fn alias(mut self, alias: impl Into<String>) -> Self {
+        self.names.push(alias.into());
+        self
+    }
+
+    /// Adds a check which must pass before the command is executed. Refer to
+    /// [`CommandOptions::checks`].
+    pub fn check(mut self, check: &'static Check) -> Self {
+        self.checks.push(check);
+        self
+    }
+
+    /// Sets the name of the ratelimit bucket, as registered with
+    /// [`StandardFramework::bucket`].
+    ///
+    /// [`StandardFramework::bucket`]: crate::framework::standard::StandardFramework::bucket
+    pub fn bucket(mut self, bucket: impl Into<String>) -> Self {
+        self.bucket = Some(bucket.into());
+        self
+    }
+
+    /// Sets the description shown by help commands.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.desc = Some(description.into());
+        self
+    }
+
+    /// Adds a delimiter to split the arguments of the command by, instead of the global ones.
+    pub fn delimiter(mut self, delimiter: impl Into<String>) -> Self {
+        self.delimiters.push(delimiter.into());
+        self
+    }
+
+    /// Sets the usage schema shown by help commands.
+    pub fn usage(mut self, usage: impl Into<String>) -> Self {
+        self.usage = Some(usage.into());
+        self
+    }
+
+    /// Adds example arguments shown by help commands.
+    pub fn example(mut self, example: impl Into<String>) -> Self {
+        self.examples.push(example.into());
+        self
+    }
+
+    /// Sets the minimum amount of arguments.
+    pub fn min_args(mut self, min_args: u16) -> Self {
+        self.min_args = Some(min_args);
+        self
+    }
+
+    /// Sets the maximum amount of arguments.
+    pub fn max_args(mut self, max_args: u16) -> Self {
+        self.max_args = Some(max_args);
+        self
+    }
+
+    /// Adds the name of a role allowed to use the command.
+    pub fn allowed_role(mut self, role: impl Into<String>) -> Self {
+        self.allowed_roles.push(role.into());
+        self
+    }
+
+    /// Sets the permissions required to use the command.
+    pub fn required_permissions(mut self, permissions: Permissions) -> Self {
+        self.required_permissions = permissions;
+        self
+    }
+
+    /// Sets whether the command is displayed by help commands. Defaults to `true`.
+    pub fn help_available(mut self, help_available: bool) -> Self {
+        self.help_available = help_available;
+        self
+    }
+
+    /// Sets whether the command can only be used in direct messages or guilds.
+    pub fn only_in(mut self, only_in: OnlyIn) -> Self {
+        self.only_in = only_in;
+        self
+    }
+
+    /// Sets whether the command can only be used by owners.
+    pub fn owners_only(mut self, owners_only: bool) -> Self {
+        self.owners_only = owners_only;
+        self
+    }
+
+    /// Sets whether owners bypass the restrictions of the command. Defaults to `true`.
+    pub fn owner_privilege(mut self, owner_privilege: bool) -> Self {
+        self.owner_privilege = owner_privilege;
+        self
+    }
+
+    /// Adds a sub-command.
+    pub fn sub_command(mut self, sub_command: CommandBuilder) -> Self {
+        self.sub_commands.push(sub_command);
+        self
+    }
+
+    fn build(self) -> Arc<BuiltCommand> {
+        Arc::new(BuiltCommand {
+            handler: self.handler,
+            options: CommandOptions {
+                checks: intern_checks(self.checks),
+                bucket: self.bucket.as_deref().map(intern_str),
+                names: intern_strs(&self.names),
+                desc: self.desc.as_deref().map(intern_str),
+                delimiters: intern_strs(&self.delimiters),
+                usage: self.usage.as_deref().map(intern_str),
+                examples: intern_strs(&self.examples),
+                min_args: self.min_args,
+                max_args: self.max_args,
+                allowed_roles: intern_strs(&self.allowed_roles),
+                required_permissions: self.required_permissions,
+                help_available: self.help_available,
+                only_in: self.only_in,
+                owners_only: self.owners_only,
+                owner_privilege: self.owner_privilege,
+                sub_commands: &[],
+            },
+            sub_commands: self.sub_commands.into_iter().map(CommandBuilder::build).collect(),
+        })
+    }
+}
+
+/// A command built with a [`CommandBuilder`], owned by the group it belongs to.
+pub struct BuiltCommand {
+    handler: Option<CommandHandler>,
+    /// The options of the command. Its sub-commands are held separately, so
+    /// [`CommandOptions::sub_commands`] is empty.
+    options: CommandOptions,
+    sub_commands: Vec<Arc<BuiltCommand>>,
+}
+
+impl fmt::Debug for BuiltCommand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BuiltCommand")
+            .field("options", &self.options)
+            .field("sub_commands", &self.sub_commands)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Defines a group of commands at runtime, as an alternative to the [`group`] macro.
+///
+/// The built group is added with [`StandardFramework::built_group`], which takes ownership of it.
+/// Its text is interned like that of the [`CommandBuilder`].
+///
+/// [`group`]: crate::framework::standard::macros::group
+/// [`StandardFramework::built_group`]: crate::framework::standard::StandardFramework::built_group
+#[derive(Debug)]
+#[must_use]
+pub struct GroupBuilder {
+    name: String,
+    prefixes: Vec<String>,
+    only_in: OnlyIn,
+    owners_only: bool,
+    owner_privilege: bool,
+    help_available: bool,
+    allowed_roles: Vec<String>,
+    required_permissions: Permissions,
+    checks: Vec<&'static Check>,
+    default_command: Option<CommandBuilder>,
+    description: Option<String>,
+    summary: Option<String>,
+    commands: Vec<CommandBuilder>,
+    sub_groups: Vec<GroupBuilder>,
+}
+
+impl GroupBuilder {
+    /// Creates an empty group with the given name.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            prefixes: Vec::new(),
+            only_in: OnlyIn::None,
+            owners_only: false,
+            owner_privilege: true,
+            help_available: true,
+            allowed_roles: Vec::new(),
+            required_permissions: Permissions::empty(),
+            checks: Vec::new(),
+            default_command: None,
+            description: None,
+            summary: None,
+            commands: Vec::new(),
+            sub_groups: Vec::new(),
+        }
+    }
+
+    /// Adds a prefix the commands of the group must be invoked with.
+    pub fn prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefixes.push(prefix.into());
+        self
+    }
+
+    /// Sets whether the commands can only be used in direct messages or guilds.
+    pub fn only_in(mut self, only_in: OnlyIn) -> Self {
+        self.only_in = only_in;
+        self
+    }
+
+    /// Sets whether the commands can only be used by owners.
+    pub fn owners_only(mut self, owners_only: bool) -> Self {
+        self.owners_only = owners_only;
+        self
+    }
+
+    /// Sets whether owners bypass the restrictions of the group. Defaults to `true`.
+    pub fn owner_privilege(mut self, owner_privilege: bool) -> Self {
+        self.owner_privilege = owner_privilege;
+        self
+    }
+
+    /// Sets whether the group is displayed by help commands. Defaults to `true`.
+    pub fn help_available(mut self, help_available: bool) -> Self {
+        self.help_available = help_available;
+        self
+    }
+
+    /// Adds the name of a role allowed to use the commands.
+    pub fn allowed_role(mut self, role: impl Into<String>) -> Self {
+        self.allowed_roles.push(role.into());
+        self
+    }
+
+    /// Sets the permissions required to use the commands.
+    pub fn required_permissions(mut self, permissions: Permissions) -> Self {
+        self.required_permissions = permissions;
+        self
+    }
+
+    /// Adds a check which must pass before any command of the group is executed.
+    pub fn check(mut self, check: &'static Check) -> Self {
+        self.checks.push(check);
+        self
+    }
+
+    /// Sets the command executed when the group is invoked by its prefix alone.
+    pub fn default_command(mut self, command: CommandBuilder) -> Self {
+        self.default_command = Some(command);
+        self
+    }
+
+    /// Sets the description shown by help commands when the group is searched for.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Sets the summary shown by help commands when listing all groups.
+    pub fn summary(mut self, summary: impl Into<String>) -> Self {
+        self.summary = Some(summary.into());
+        self
+    }
+
+    /// Adds a command.
+    pub fn command(mut self, command: CommandBuilder) -> Self {
+        self.commands.push(command);
+        self
+    }
+
+    /// Adds a sub-group.
+    pub fn sub_group(mut self, sub_group: GroupBuilder) -> Self {
+        self.sub_groups.push(sub_group);
+        self
+    }
+
+    /// Builds the group, to be added with [`StandardFramework::built_group`].
+    ///
+    /// [`StandardFramework::built_group`]: crate::framework::standard::StandardFramework::built_group
+    pub fn build(self) -> BuiltGroup {
+        BuiltGroup {
+            name: intern_str(&self.name),
+            options: GroupOptions {
+                prefixes: intern_strs(&self.prefixes),
+                only_in: self.only_in,
+                owners_only: self.owners_only,
+                owner_privilege: self.owner_privilege,
+                help_available: self.help_available,
+                allowed_roles: intern_strs(&self.allowed_roles),
+                required_permissions: self.required_permissions,
+                checks: intern_checks(self.checks),
+                default_command: None,
+                description: self.description.as_deref().map(intern_str),
+                summary: self.summary.as_deref().map(intern_str),
+                commands: &[],
+                sub_groups: &[],
+            },
+            default_command: self.default_command.map(CommandBuilder::build),
+            commands: self.commands.into_iter().map(CommandBuilder::build).collect(),
+            sub_groups: self.sub_groups.into_iter().map(|g| Arc::new(g.build())).collect(),
+        }
+    }
+}
+
+/// A group built with a [`GroupBuilder`].
+#[derive(Debug)]
+pub struct BuiltGroup {
+    name: &'static str,
+    /// The options of the group. Its commands and sub-groups are held separately, so the
+    /// respective fields of [`GroupOptions`] are empty.
+    options: GroupOptions,
+    default_command: Option<Arc<BuiltCommand>>,
+    commands: Vec<Arc<BuiltCommand>>,
+    sub_groups: Vec<Arc<BuiltGroup>>,
+}
+
+impl BuiltGroup {
+    /// The name of the group.
+    #[must_use]
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+/// A command of either a group defined with the [`group`] macro, or a [`BuiltGroup`].
+///
+/// [`group`]: crate::framework::standard::macros::group
+#[derive(Clone, Debug)]
+pub enum CommandRef {
+    Static(&'static Command),
+    Built(Arc<BuiltCommand>),
+}
+
+impl CommandRef {
+    /// The primary name of the command.
+    #[must_use]
+    pub fn name(&self) -> &'static str {
+        self.options().names[0]
+    }
+
+    #[must_use]
+    pub fn options(&self) -> &CommandOptions {
+        match self {
+            Self::Static(command) => command.options,
+            Self::Built(command) => &command.options,
+        }
+    }
+
+    #[must_use]
+    pub fn sub_commands(&self) -> Vec<CommandRef> {
+        match self {
+            Self::Static(command) => {
+                command.options.sub_commands.iter().map(|&c| Self::Static(c)).collect()
+            },
+            Self::Built(command) => command.sub_commands.iter().cloned().map(Self::Built).collect(),
+        }
+    }
+
+    /// Calls the function of the command.
+    pub(crate) async fn call(&self, ctx: &Context, msg: &Message, args: Args) -> CommandResult {
+        match self {
+            Self::Static(command) => (command.fun)(ctx, msg, args).await,
+            Self::Built(command) => match &command.handler {
+                Some(handler) => handler(ctx, msg, args).await,
+                None => Ok(()),
+            },
+        }
+    }
+}
+
+impl From<&'static Command> for CommandRef {
+    fn from(command: &'static Command) -> Self {
+        Self::Static(command)
+    }
+}
+
+/// A group defined with the [`group`] macro, or a [`BuiltGroup`].
+///
+/// [`group`]: crate::framework::standard::macros::group
+#[derive(Clone, Debug)]
+pub enum GroupRef {
+    Static(&'static CommandGroup),
+    Built(Arc<BuiltGroup>),
+}
+
+impl GroupRef {
+    #[must_use]
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Static(group) => group.name,
+            Self::Built(group) => group.name,
+        }
+    }
+
+    #[must_use]
+    pub fn options(&self) -> &GroupOptions {
+        match self {
+            Self::Static(group) => group.options,
+            Self::Built(group) => &group.options,
+        }
+    }
+
+    #[must_use]
+    pub fn default_command(&self) -> Option<CommandRef> {
+        match self {
+            Self::Static(group) => group.options.default_command.map(CommandRef::Static),
+            Self::Built(group) => group.default_command.clone().map(CommandRef::Built),
+        }
+    }
+
+    #[must_use]
+    pub fn commands(&self) -> Vec<CommandRef> {
+        match self {
+            Self::Static(group) => {
+                group.options.commands.iter().map(|&c| CommandRef::Static(c)).collect()
+            },
+            Self::Built(group) => group.commands.iter().cloned().map(CommandRef::Built).collect(),
+        }
+    }
+
+    #[must_use]
+    pub fn sub_groups(&self) -> Vec<GroupRef> {
+        match self {
+            Self::Static(group) => {
+                group.options.sub_groups.iter().map(|&g| Self::Static(g)).collect()
+            },
+            Self::Built(group) => group.sub_groups.iter().cloned().map(Self::Built).collect(),
+        }
+    }
+}
+
+impl From<&'static CommandGroup> for GroupRef {
+    fn from(group: &'static CommandGroup) -> Self {
+        Self::Static(group)
+    }
+}
+
+impl From<BuiltGroup> for GroupRef {
+    fn from(group: BuiltGroup) -> Self {
+        Self::Built(Arc::new(group))
+    }
+}
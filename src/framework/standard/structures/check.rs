@@ -23,6 +23,9 @@ pub enum Reason {
     Log(String),
     /// Information for the user but also for logging purposes.
     UserAndLog { user: String, log: String },
+    /// Every check of an any-of group, such as `#[checks(A | B)]`, failed. Contains the name and
+    /// reason of each check, in the order they were evaluated.
+    AnyOf(Vec<(&'static str, Reason)>),
 }
 
 impl Error for Reason {}
@@ -74,10 +77,44 @@ impl fmt::Display for Reason {
             } => {
                 write!(f, "UserAndLog {{user: {user}, log: {log}}}")
             },
+            Self::AnyOf(reasons) => {
+                f.write_str("AnyOf [")?;
+                for (i, (name, reason)) in reasons.iter().enumerate() {
+                    if i != 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{name}: {reason}")?;
+                }
+                f.write_str("]")
+            },
         }
     }
 }
 
+/// Evaluates the checks in order until one passes, failing only if every check fails.
+///
+/// This is how any-of groups such as `#[checks(A | B)]` are evaluated. If all checks fail, their
+/// reasons are collected into [`Reason::AnyOf`].
+pub fn any_of_checks<'fut>(
+    checks: &'static [&'static Check],
+    ctx: &'fut Context,
+    msg: &'fut Message,
+    args: &'fut mut Args,
+    options: &'fut CommandOptions,
+) -> BoxFuture<'fut, Result<(), Reason>> {
+    Box::pin(async move {
+        let mut reasons = Vec::with_capacity(checks.len());
+        for check in checks {
+            match (check.function)(ctx, msg, args, options).await {
+                Ok(()) => return Ok(()),
+                Err(reason) => reasons.push((check.name, reason)),
+            }
+        }
+
+        Err(Reason::AnyOf(reasons))
+    })
+}
+
 impl PartialEq for Check {
     fn eq(&self, other: &Self) -> bool {
         self.name == other.name
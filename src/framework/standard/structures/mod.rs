@@ -12,8 +12,18 @@ use crate::model::permissions::Permissions;
 use crate::model::Colour;
 
 pub mod buckets;
+mod builder;
 mod check;
 
+pub use self::builder::{
+    BuiltCommand,
+    BuiltGroup,
+    CommandBuilder,
+    CommandHandler,
+    CommandRef,
+    GroupBuilder,
+    GroupRef,
+};
 pub use self::check::*;
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -98,7 +108,7 @@ pub type HelpCommandFn = for<'fut> fn(
     &'fut Message,
     Args,
     &'fut HelpOptions,
-    &'fut [&'static CommandGroup],
+    &'fut [GroupRef],
     HashSet<UserId>,
 ) -> BoxFuture<'fut, CommandResult>;
 
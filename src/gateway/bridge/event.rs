@@ -1,14 +1,27 @@
 //! A collection of events created by the client, not a part of the Discord API itself.
 
+use std::fmt;
+
 use crate::gateway::ConnectionStage;
 use crate::model::id::ShardId;
 
 /// An event denoting that a shard's connection stage was changed.
 ///
+/// This event is created by the shard runner itself, so it is not passed through the cache or
+/// collectors like events received from the gateway.
+///
+/// Each shard runner starts out as [`ConnectionStage::Disconnected`] and ends as such once the
+/// shard is shut down or restarted. Stages which a shard passes through within a single step, such
+/// as [`ConnectionStage::Connecting`] while resuming, may be skipped.
+///
 /// # Examples
 ///
 /// This might happen when a shard changes from [`ConnectionStage::Identifying`] to
 /// [`ConnectionStage::Connected`].
+///
+/// The [`Display`] implementation formats the event as e.g. `shard 3: identifying -> connected`.
+///
+/// [`Display`]: fmt::Display
 #[derive(Clone, Debug)]
 pub struct ShardStageUpdateEvent {
     /// The new connection stage.
@@ -18,3 +31,66 @@ pub struct ShardStageUpdateEvent {
     /// The ID of the shard that had its connection stage change.
     pub shard_id: ShardId,
 }
+
+impl fmt::Display for ShardStageUpdateEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "shard {}: {} -> {}", self.shard_id, self.old, self.new)
+    }
+}
+
+/// An event denoting that a shard was disconnected.
+///
+/// This is dispatched along with the [`ShardStageUpdateEvent`] for the change to
+/// [`ConnectionStage::Disconnected`], and is likewise not passed through the cache or collectors.
+///
+/// The [`Display`] implementation formats the event as e.g.
+/// `shard 3: connected -> disconnected (close code 4008)`.
+///
+/// [`Display`]: fmt::Display
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct ShardDisconnectEvent {
+    /// The connection stage the shard was in before it was disconnected.
+    pub old: ConnectionStage,
+    /// The ID of the shard that was disconnected.
+    pub shard_id: ShardId,
+    /// The close code of the connection, if it is known.
+    pub close_code: Option<u16>,
+}
+
+impl fmt::Display for ShardDisconnectEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "shard {}: {} -> {}", self.shard_id, self.old, ConnectionStage::Disconnected)?;
+
+        if let Some(close_code) = self.close_code {
+            write!(f, " (close code {close_code})")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display() {
+        let event = ShardStageUpdateEvent {
+            new: ConnectionStage::Connected,
+            old: ConnectionStage::Identifying,
+            shard_id: ShardId(3),
+        };
+        assert_eq!(event.to_string(), "shard 3: identifying -> connected");
+
+        let mut event = ShardDisconnectEvent {
+            old: ConnectionStage::Connected,
+            shard_id: ShardId(3),
+            close_code: Some(4008),
+        };
+        assert_eq!(event.to_string(), "shard 3: connected -> disconnected (close code 4008)");
+
+        event.close_code = None;
+        assert_eq!(event.to_string(), "shard 3: connected -> disconnected");
+    }
+}
@@ -0,0 +1,150 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::model::event::{Event, EVENTS};
+
+/// The name under which events unknown to serenity are counted.
+const UNKNOWN: &str = "UNKNOWN";
+
+/// Returns the gateway name of every counter, with the counter of unknown events last.
+fn counter_names() -> impl Iterator<Item = &'static str> {
+    EVENTS.iter().map(|descriptor| descriptor.name).chain(std::iter::once(UNKNOWN))
+}
+
+/// Returns the index of the counter for the given event.
+fn event_index(event: &Event) -> usize {
+    event.index().unwrap_or(EVENTS.len())
+}
+
+fn name_index(name: &str) -> Option<usize> {
+    EVENTS.iter().position(|descriptor| descriptor.name == name)
+}
+
+/// Lock-free per event type counters, shared by all shard runners of a [`ShardManager`].
+///
+/// [`ShardManager`]: super::ShardManager
+#[derive(Debug)]
+pub(crate) struct EventCounters {
+    counts: Box<[AtomicU64]>,
+}
+
+impl EventCounters {
+    pub(crate) fn new() -> Self {
+        Self {
+            counts: counter_names().map(|_| AtomicU64::new(0)).collect(),
+        }
+    }
+
+    /// Records the receipt of a dispatched event.
+    pub(crate) fn record(&self, event: &Event) {
+        self.counts[event_index(event)].fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Records the receipt of an event by its gateway name, for events that are dropped before
+    /// being deserialized.
+    pub(crate) fn record_name(&self, name: &str) {
+        let index = name_index(name).unwrap_or(EVENTS.len());
+        self.counts[index].fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Returns the current counts, resetting them to zero if `reset` is `true`.
+    pub(crate) fn snapshot(&self, reset: bool) -> EventCounts {
+        let counts = counter_names()
+            .zip(self.counts.iter())
+            .filter_map(|(name, count)| {
+                let count = if reset {
+                    count.swap(0, Ordering::Relaxed)
+                } else {
+                    count.load(Ordering::Relaxed)
+                };
+                (count != 0).then_some((name, count))
+            })
+            .collect();
+
+        EventCounts {
+            counts,
+        }
+    }
+}
+
+/// The number of gateway events received per event type, aggregated across all shards of a
+/// [`ShardManager`].
+///
+/// Events are keyed by their gateway name, e.g. `MESSAGE_CREATE`. Events not known to serenity
+/// are counted under `UNKNOWN`.
+///
+/// Retrieved via [`ShardManager::event_counts`] or [`ShardManager::take_event_counts`].
+///
+/// [`ShardManager`]: super::ShardManager
+/// [`ShardManager::event_counts`]: super::ShardManager::event_counts
+/// [`ShardManager::take_event_counts`]: super::ShardManager::take_event_counts
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct EventCounts {
+    counts: HashMap<&'static str, u64>,
+}
+
+impl EventCounts {
+    /// Returns how many events with the given gateway name were received.
+    #[must_use]
+    pub fn get(&self, name: &str) -> u64 {
+        self.counts.get(name).copied().unwrap_or(0)
+    }
+
+    /// Returns the total number of events received.
+    #[must_use]
+    pub fn total(&self) -> u64 {
+        self.counts.values().sum()
+    }
+
+    /// Iterates over the gateway names and counts of every event type received at least once.
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, u64)> + '_ {
+        self.counts.iter().map(|(name, count)| (*name, *count))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json::{from_value, json};
+    use crate::model::event::{ResumedEvent, TypingStartEvent};
+
+    #[test]
+    fn names_are_unique() {
+        assert_eq!(counter_names().count(), EVENTS.len() + 1);
+        for (i, name) in counter_names().enumerate() {
+            if name == UNKNOWN {
+                assert_eq!(name_index(name), None);
+            } else {
+                assert_eq!(name_index(name), Some(i), "{name} is listed twice");
+            }
+        }
+    }
+
+    #[test]
+    fn count_events() {
+        let counters = EventCounters::new();
+        let typing: TypingStartEvent = from_value(json!({
+            "channel_id": "1",
+            "user_id": "2",
+            "timestamp": 1_700_000_000,
+        }))
+        .unwrap();
+
+        counters.record(&Event::Resumed(ResumedEvent {}));
+        counters.record(&Event::TypingStart(typing.clone()));
+        counters.record(&Event::TypingStart(typing));
+        counters.record_name("TYPING_START");
+        counters.record_name("SOMETHING_NEW");
+
+        let counts = counters.snapshot(false);
+        assert_eq!(counts.get("RESUMED"), 1);
+        assert_eq!(counts.get("TYPING_START"), 3);
+        assert_eq!(counts.get("UNKNOWN"), 1);
+        assert_eq!(counts.get("MESSAGE_CREATE"), 0);
+        assert_eq!(counts.total(), 5);
+        assert_eq!(counts.iter().count(), 3);
+
+        assert_eq!(counters.snapshot(true), counts);
+        assert_eq!(counters.snapshot(false).total(), 0);
+    }
+}
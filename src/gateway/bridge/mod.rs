@@ -41,6 +41,7 @@
 //! [`Shard`]: crate::gateway::Shard
 
 mod event;
+mod event_counts;
 mod shard_manager;
 mod shard_messenger;
 mod shard_queuer;
@@ -52,8 +53,15 @@ mod voice;
 use std::fmt;
 use std::time::Duration as StdDuration;
 
-pub use self::event::ShardStageUpdateEvent;
-pub use self::shard_manager::{ShardManager, ShardManagerOptions};
+pub use self::event::{ShardDisconnectEvent, ShardStageUpdateEvent};
+pub use self::event_counts::EventCounts;
+pub use self::shard_manager::{
+    ShardConnectionOptions,
+    ShardHealth,
+    ShardManager,
+    ShardManagerOptions,
+    ShutdownOutcome,
+};
 pub use self::shard_messenger::ShardMessenger;
 pub use self::shard_queuer::ShardQueuer;
 pub use self::shard_runner::{ShardRunner, ShardRunnerOptions};
@@ -1,26 +1,43 @@
 use std::collections::{HashMap, VecDeque};
-use std::sync::atomic::{AtomicU32, Ordering};
-use std::sync::Arc;
+use std::sync::atomic::{AtomicU16, AtomicU32, Ordering};
 #[cfg(feature = "framework")]
 use std::sync::OnceLock;
-use std::time::Duration;
+use std::sync::{Arc, Mutex as StdMutex};
+use std::time::{Duration, Instant};
 
 use futures::channel::mpsc::{self, UnboundedReceiver as Receiver, UnboundedSender as Sender};
 use futures::{SinkExt, StreamExt};
-use tokio::sync::{Mutex, RwLock};
-use tokio::time::timeout;
+use tokio::sync::{broadcast, Mutex, RwLock};
+use tokio::task::AbortHandle;
+use tokio::time::{timeout, timeout_at};
 use tracing::{info, instrument, warn};
 use typemap_rev::TypeMap;
 
+use super::event_counts::EventCounters;
 #[cfg(feature = "voice")]
 use super::VoiceGatewayManager;
-use super::{ShardId, ShardQueuer, ShardQueuerMessage, ShardRunnerInfo};
+use super::{
+    EventCounts,
+    ShardId,
+    ShardQueuer,
+    ShardQueuerMessage,
+    ShardRunnerInfo,
+    ShardRunnerMessage,
+};
 #[cfg(feature = "cache")]
 use crate::cache::Cache;
 use crate::client::{EventHandler, RawEventHandler};
 #[cfg(feature = "framework")]
 use crate::framework::Framework;
-use crate::gateway::{ConnectionStage, GatewayError, PresenceData};
+use crate::gateway::{
+    ConnectionStage,
+    GatewayError,
+    GatewayProxy,
+    IdentifyProperties,
+    OutgoingPayloadHook,
+    PresenceData,
+    TransportCompression,
+};
 use crate::http::Http;
 use crate::internal::prelude::*;
 use crate::internal::tokio::spawn_named;
@@ -110,12 +127,111 @@ pub struct ShardManager {
     shard_init: AtomicU32,
     /// The total shards in use, 1-indexed.
     shard_total: AtomicU32,
+    /// The number of shards which may be started concurrently.
+    max_concurrency: AtomicU16,
+    /// How shards started from now on connect to the gateway.
+    connection: StdMutex<ShardConnectionOptions>,
     shard_queuer: Sender<ShardQueuerMessage>,
     // We can safely use a Mutex for this field, as it is only ever used in one single place
     // and only is ever used to receive a single message
     shard_shutdown: Mutex<Receiver<ShardId>>,
     shard_shutdown_send: Sender<ShardId>,
+    /// Handles to the tasks driving each shard runner, used to abort runners that do not shut
+    /// down in time.
+    runner_tasks: StdMutex<HashMap<ShardId, AbortHandle>>,
     gateway_intents: GatewayIntents,
+    stage_tx: broadcast::Sender<(ShardId, ConnectionStage)>,
+    health: StdMutex<HealthTracker>,
+    pub(crate) event_counters: EventCounters,
+}
+
+/// The result of shutting down a single shard with [`ShardManager::shutdown_all_with_timeout`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ShutdownOutcome {
+    /// The shard closed its connection before the deadline.
+    Clean,
+    /// The shard did not close its connection before the deadline, so its runner was aborted.
+    Forced,
+    /// The shutdown message could not be delivered, as the shard runner had already stopped.
+    Errored,
+}
+
+/// An aggregate of the connection stages of all shards managed by a [`ShardManager`].
+///
+/// Retrieved via [`ShardManager::health`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct ShardHealth {
+    /// The number of shards that are [`ConnectionStage::Connected`].
+    pub connected: u32,
+    /// The number of shards the manager is responsible for.
+    pub total: u32,
+    /// Since when at least one shard has not been connected, or [`None`] if all shards are
+    /// connected.
+    pub degraded_since: Option<Instant>,
+}
+
+impl ShardHealth {
+    /// Whether all shards are connected.
+    #[must_use]
+    pub fn is_healthy(&self) -> bool {
+        self.degraded_since.is_none()
+    }
+}
+
+/// Keeps track of the latest stage of every shard, so that [`ShardHealth`] can be computed
+/// without locking the runners.
+#[derive(Debug)]
+struct HealthTracker {
+    stages: HashMap<ShardId, ConnectionStage>,
+    total: u32,
+    degraded_since: Option<Instant>,
+}
+
+impl HealthTracker {
+    fn new(total: u32, now: Instant) -> Self {
+        Self {
+            stages: HashMap::new(),
+            total,
+            degraded_since: (total > 0).then_some(now),
+        }
+    }
+
+    /// Records the stage of a shard, returning whether it changed.
+    fn record(&mut self, shard_id: ShardId, stage: ConnectionStage, now: Instant) -> bool {
+        let changed = self.stages.insert(shard_id, stage) != Some(stage);
+        self.refresh(now);
+        changed
+    }
+
+    fn reset(&mut self, total: u32, now: Instant) {
+        self.stages.clear();
+        self.total = total;
+        self.refresh(now);
+    }
+
+    fn connected(&self) -> u32 {
+        let connected =
+            self.stages.values().filter(|stage| **stage == ConnectionStage::Connected).count();
+        connected as u32
+    }
+
+    fn refresh(&mut self, now: Instant) {
+        if self.connected() >= self.total {
+            self.degraded_since = None;
+        } else if self.degraded_since.is_none() {
+            self.degraded_since = Some(now);
+        }
+    }
+
+    fn health(&self) -> ShardHealth {
+        ShardHealth {
+            connected: self.connected(),
+            total: self.total,
+            degraded_since: self.degraded_since,
+        }
+    }
 }
 
 impl ShardManager {
@@ -128,6 +244,7 @@ impl ShardManager {
 
         let runners = Arc::new(Mutex::new(HashMap::new()));
         let (shutdown_send, shutdown_recv) = mpsc::unbounded();
+        let (stage_tx, _) = broadcast::channel(64);
 
         let manager = Arc::new(Self {
             return_value_tx: Mutex::new(return_value_tx),
@@ -135,10 +252,16 @@ impl ShardManager {
             shard_init: AtomicU32::new(opt.shard_init),
             shard_queuer: shard_queue_tx,
             shard_total: AtomicU32::new(opt.shard_total),
+            max_concurrency: AtomicU16::new(1),
+            connection: StdMutex::new(ShardConnectionOptions::default()),
             shard_shutdown: Mutex::new(shutdown_recv),
             shard_shutdown_send: shutdown_send,
+            runner_tasks: StdMutex::new(HashMap::new()),
             runners: Arc::clone(&runners),
             gateway_intents: opt.intents,
+            stage_tx,
+            health: StdMutex::new(HealthTracker::new(opt.shard_init, Instant::now())),
+            event_counters: EventCounters::new(),
         });
 
         let mut shard_queuer = ShardQueuer {
@@ -208,6 +331,38 @@ impl ShardManager {
         self.shard_index.store(index, Ordering::Relaxed);
         self.shard_init.store(init, Ordering::Relaxed);
         self.shard_total.store(total, Ordering::Relaxed);
+
+        self.health.lock().expect("poison").reset(init, Instant::now());
+    }
+
+    /// Sets the number of shards which may be started concurrently, as given by
+    /// [`SessionStartLimit::max_concurrency`].
+    ///
+    /// Shards are only started concurrently if they are in different ratelimit buckets, see
+    /// [`ShardQueuer`].
+    ///
+    /// [`SessionStartLimit::max_concurrency`]: crate::model::gateway::SessionStartLimit::max_concurrency
+    pub fn set_max_concurrency(&self, max_concurrency: u16) {
+        self.max_concurrency.store(max_concurrency.max(1), Ordering::Relaxed);
+    }
+
+    /// Returns the number of shards which may be started concurrently, as set by
+    /// [`Self::set_max_concurrency`].
+    pub fn max_concurrency(&self) -> u16 {
+        self.max_concurrency.load(Ordering::Relaxed)
+    }
+
+    /// Sets how shards connect to the gateway and handle its payloads.
+    ///
+    /// This applies to shards started afterwards, so it should be called before starting any
+    /// shards. Running shards keep their options until they are restarted.
+    pub fn set_connection_options(&self, options: ShardConnectionOptions) {
+        *self.connection.lock().expect("poison") = options;
+    }
+
+    /// Returns how shards connect to the gateway, as set by [`Self::set_connection_options`].
+    pub fn connection_options(&self) -> ShardConnectionOptions {
+        self.connection.lock().expect("poison").clone()
     }
 
     /// Restarts a shard runner.
@@ -292,6 +447,8 @@ impl ShardManager {
         }
 
         self.runners.lock().await.remove(&shard_id);
+        self.runner_tasks.lock().expect("runner tasks poisoned").remove(&shard_id);
+        self.record_stage(shard_id, ConnectionStage::Disconnected);
     }
 
     /// Sends a shutdown message for all shards that the manager is responsible for that are still
@@ -299,22 +456,71 @@ impl ShardManager {
     ///
     /// If you only need to shutdown a select number of shards, prefer looping over the
     /// [`Self::shutdown`] method.
+    ///
+    /// Shards that do not close within 5 seconds are aborted, see
+    /// [`Self::shutdown_all_with_timeout`].
     #[instrument(skip(self))]
     pub async fn shutdown_all(&self) {
-        let keys = {
+        self.shutdown_all_with_timeout(Duration::from_secs(5)).await;
+    }
+
+    /// Shuts down all shards that the manager is responsible for that are still known to be
+    /// running, waiting at most `timeout` in total for them to close their connections.
+    ///
+    /// The runner of every shard that has not closed its connection once the deadline passes is
+    /// aborted. Either way, all shards are removed from the manager and marked as
+    /// [`ConnectionStage::Disconnected`].
+    ///
+    /// Returns how the shutdown of each shard went, ordered by [`ShardId`].
+    #[instrument(skip(self))]
+    pub async fn shutdown_all_with_timeout(
+        &self,
+        timeout: Duration,
+    ) -> Vec<(ShardId, ShutdownOutcome)> {
+        let deadline = tokio::time::Instant::now() + timeout;
+
+        let mut shard_shutdown = self.shard_shutdown.lock().await;
+
+        let (pending, mut outcomes) = {
             let runners = self.runners.lock().await;
 
             if runners.is_empty() {
-                return;
+                return Vec::new();
+            }
+
+            info!("Shutting down all shards");
+
+            let mut pending = Vec::new();
+            let mut outcomes = Vec::new();
+            for (&shard_id, runner) in runners.iter() {
+                let msg = ShardRunnerMessage::Shutdown(shard_id, 1000);
+                if let Err(why) = runner.runner_tx.tx.unbounded_send(msg) {
+                    warn!("Failed to send shutdown to shard {}: {:?}", shard_id, why);
+                    // The runner has already stopped, so there is nothing left to abort.
+                    self.runner_tasks.lock().expect("runner tasks poisoned").remove(&shard_id);
+                    outcomes.push((shard_id, ShutdownOutcome::Errored));
+                } else {
+                    pending.push(shard_id);
+                }
             }
 
-            runners.keys().copied().collect::<Vec<_>>()
+            (pending, outcomes)
         };
 
-        info!("Shutting down all shards");
+        outcomes.extend(
+            await_shutdowns(pending, &mut shard_shutdown, &self.runner_tasks, deadline).await,
+        );
+        outcomes.sort_unstable_by_key(|(shard_id, _)| *shard_id);
+        drop(shard_shutdown);
 
-        for shard_id in keys {
-            self.shutdown(shard_id, 1000).await;
+        {
+            let mut runners = self.runners.lock().await;
+            for (shard_id, _) in &outcomes {
+                runners.remove(shard_id);
+            }
+        }
+        for (shard_id, _) in &outcomes {
+            self.record_stage(*shard_id, ConnectionStage::Disconnected);
         }
 
         drop(self.shard_queuer.unbounded_send(ShardQueuerMessage::Shutdown));
@@ -322,6 +528,13 @@ impl ShardManager {
         // this message is received by Client::start_connection, which lets the main thread know
         // and finally return from Client::start
         drop(self.return_value_tx.lock().await.unbounded_send(Ok(())));
+
+        outcomes
+    }
+
+    /// Stores the handle of the task driving the runner of a shard, replacing any previous one.
+    pub(crate) fn set_runner_task(&self, shard_id: ShardId, task: AbortHandle) {
+        self.runner_tasks.lock().expect("runner tasks poisoned").insert(shard_id, task);
     }
 
     #[instrument(skip(self))]
@@ -333,6 +546,49 @@ impl ShardManager {
         drop(self.shard_queuer.unbounded_send(msg));
     }
 
+    /// Subscribes to connection stage transitions of all shards managed by this manager.
+    ///
+    /// A `(shard_id, new_stage)` pair is sent every time the stage of a shard changes. Slow
+    /// receivers may miss transitions, see [`broadcast::Receiver::recv`]; use [`Self::health`]
+    /// to retrieve the current state.
+    #[must_use]
+    pub fn subscribe_stages(&self) -> broadcast::Receiver<(ShardId, ConnectionStage)> {
+        self.stage_tx.subscribe()
+    }
+
+    /// Returns an aggregate of the latest connection stages of all shards.
+    ///
+    /// This is kept up to date as stages change and does not lock [`Self::runners`], making it
+    /// cheap to call from e.g. a health check endpoint.
+    #[must_use]
+    pub fn health(&self) -> ShardHealth {
+        self.health.lock().expect("poison").health()
+    }
+
+    /// Returns the number of gateway events received per event type, aggregated across all
+    /// shards since the manager was created or the counts were last taken.
+    ///
+    /// Counting is done with a single atomic increment per event, so this is suitable for
+    /// exporting metrics without registering a [`RawEventHandler`].
+    #[must_use]
+    pub fn event_counts(&self) -> EventCounts {
+        self.event_counters.snapshot(false)
+    }
+
+    /// Like [`Self::event_counts`], but resets all counts to zero.
+    pub fn take_event_counts(&self) -> EventCounts {
+        self.event_counters.snapshot(true)
+    }
+
+    fn record_stage(&self, shard_id: ShardId, stage: ConnectionStage) {
+        let changed = self.health.lock().expect("poison").record(shard_id, stage, Instant::now());
+
+        if changed {
+            // An error only means that there are currently no subscribers.
+            drop(self.stage_tx.send((shard_id, stage)));
+        }
+    }
+
     /// Returns the gateway intents used for this gateway connection.
     #[must_use]
     pub fn intents(&self) -> GatewayIntents {
@@ -368,6 +624,8 @@ impl ShardManager {
             runner.latency = latency;
             runner.stage = stage;
         }
+
+        self.record_stage(id, stage);
     }
 }
 
@@ -401,3 +659,201 @@ pub struct ShardManagerOptions {
     pub intents: GatewayIntents,
     pub presence: Option<PresenceData>,
 }
+
+/// Options for how shards connect to the gateway and handle its payloads.
+///
+/// Create one with [`ShardConnectionOptions::default`], set the fields to change and pass it to
+/// [`ShardManager::set_connection_options`].
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct ShardConnectionOptions {
+    /// The proxy to tunnel the gateway connections of shards through, if any.
+    pub gateway_proxy: Option<GatewayProxy>,
+    /// Whether shards drop typing events without deserializing them.
+    pub drop_typing_events: bool,
+    /// Whether shards fail to deserialize events with unexpected fields, instead of dropping
+    /// those fields. Defaults to `true`.
+    pub strict_deserialization: bool,
+    /// Whether shards include the start of payloads that fail to deserialize in errors.
+    pub deserialization_error_snippets: bool,
+    /// The connection properties shards send when identifying. These are not validated here,
+    /// see [`IdentifyProperties::validate`].
+    pub identify_properties: IdentifyProperties,
+    /// The compression of payloads received by shards.
+    pub transport_compression: TransportCompression,
+    /// A hook called with every payload shards send, except heartbeats, for debugging. See
+    /// [`OutgoingPayloadHook`].
+    pub outgoing_payload_hook: Option<OutgoingPayloadHook>,
+}
+
+impl Default for ShardConnectionOptions {
+    fn default() -> Self {
+        Self {
+            gateway_proxy: None,
+            drop_typing_events: false,
+            strict_deserialization: true,
+            deserialization_error_snippets: false,
+            identify_properties: IdentifyProperties::default(),
+            transport_compression: TransportCompression::default(),
+            outgoing_payload_hook: None,
+        }
+    }
+}
+
+/// Waits until every shard in `pending` has reported a finished shutdown or the deadline has
+/// passed, aborting the runner task of each shard that has not.
+async fn await_shutdowns(
+    mut pending: Vec<ShardId>,
+    finished: &mut Receiver<ShardId>,
+    tasks: &StdMutex<HashMap<ShardId, AbortHandle>>,
+    deadline: tokio::time::Instant,
+) -> Vec<(ShardId, ShutdownOutcome)> {
+    let mut outcomes = Vec::with_capacity(pending.len());
+
+    while !pending.is_empty() {
+        match timeout_at(deadline, finished.next()).await {
+            Ok(Some(shard_id)) => {
+                if let Some(index) = pending.iter().position(|id| *id == shard_id) {
+                    pending.swap_remove(index);
+                    outcomes.push((shard_id, ShutdownOutcome::Clean));
+                }
+            },
+            Ok(None) | Err(_) => break,
+        }
+    }
+
+    let mut tasks = tasks.lock().expect("runner tasks poisoned");
+    for (shard_id, _) in &outcomes {
+        tasks.remove(shard_id);
+    }
+    for shard_id in pending {
+        warn!("Failed to cleanly shutdown shard {}, reached timeout", shard_id);
+        if let Some(task) = tasks.remove(&shard_id) {
+            task.abort();
+        }
+        outcomes.push((shard_id, ShutdownOutcome::Forced));
+    }
+
+    outcomes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gateway::ShardMessenger;
+
+    #[test]
+    fn health_aggregate() {
+        let start = Instant::now();
+        let mut tracker = HealthTracker::new(2, start);
+
+        let health = tracker.health();
+        assert_eq!((health.connected, health.total), (0, 2));
+        assert_eq!(health.degraded_since, Some(start));
+
+        assert!(tracker.record(ShardId(0), ConnectionStage::Handshake, start));
+        assert!(tracker.record(ShardId(0), ConnectionStage::Connected, start));
+        assert!(!tracker.record(ShardId(0), ConnectionStage::Connected, start));
+        assert_eq!(tracker.health().connected, 1);
+        assert_eq!(tracker.health().degraded_since, Some(start));
+
+        tracker.record(ShardId(1), ConnectionStage::Connected, start);
+        let health = tracker.health();
+        assert_eq!(health.connected, 2);
+        assert!(health.is_healthy());
+
+        let later = start + Duration::from_secs(5);
+        tracker.record(ShardId(1), ConnectionStage::Resuming, later);
+        tracker.record(ShardId(0), ConnectionStage::Disconnected, later + Duration::from_secs(1));
+        let health = tracker.health();
+        assert_eq!(health.connected, 0);
+        assert_eq!(health.degraded_since, Some(later));
+
+        tracker.record(ShardId(0), ConnectionStage::Connected, later);
+        tracker.record(ShardId(1), ConnectionStage::Connected, later);
+        assert!(tracker.health().is_healthy());
+    }
+
+    #[test]
+    fn health_reset() {
+        let start = Instant::now();
+        let mut tracker = HealthTracker::new(1, start);
+        tracker.record(ShardId(0), ConnectionStage::Connected, start);
+        assert!(tracker.health().is_healthy());
+
+        let later = start + Duration::from_secs(1);
+        tracker.reset(3, later);
+        let health = tracker.health();
+        assert_eq!((health.connected, health.total), (0, 3));
+        assert_eq!(health.degraded_since, Some(later));
+
+        assert!(HealthTracker::new(0, start).health().is_healthy());
+    }
+
+    #[tokio::test]
+    async fn shutdown_aborts_runners_past_deadline() {
+        let (finished_tx, mut finished_rx) = mpsc::unbounded();
+
+        // Shard 0 closes cleanly, shard 1 refuses to close.
+        let clean = tokio::spawn(async move {
+            finished_tx.unbounded_send(ShardId(0)).unwrap();
+        });
+        let stuck = tokio::spawn(futures::future::pending::<()>());
+
+        let tasks = StdMutex::new(HashMap::from([
+            (ShardId(0), clean.abort_handle()),
+            (ShardId(1), stuck.abort_handle()),
+        ]));
+        let deadline = tokio::time::Instant::now() + Duration::from_millis(50);
+        let mut outcomes =
+            await_shutdowns(vec![ShardId(0), ShardId(1)], &mut finished_rx, &tasks, deadline).await;
+        outcomes.sort_unstable_by_key(|(id, _)| *id);
+
+        assert_eq!(outcomes, [
+            (ShardId(0), ShutdownOutcome::Clean),
+            (ShardId(1), ShutdownOutcome::Forced),
+        ]);
+        assert!(stuck.await.unwrap_err().is_cancelled());
+        assert!(clean.await.is_ok());
+        assert!(tasks.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn shutdown_forgets_stopped_runners() {
+        let (manager, _) = ShardManager::new(ShardManagerOptions {
+            data: Arc::new(RwLock::new(TypeMap::new())),
+            event_handlers: vec![],
+            raw_event_handlers: vec![],
+            #[cfg(feature = "framework")]
+            framework: Arc::new(OnceLock::new()),
+            shard_index: 0,
+            shard_init: 1,
+            shard_total: 1,
+            #[cfg(feature = "voice")]
+            voice_manager: None,
+            ws_url: Arc::new(Mutex::new(String::new())),
+            #[cfg(feature = "cache")]
+            cache: Arc::new(Cache::new()),
+            http: Arc::new(Http::new("")),
+            intents: GatewayIntents::empty(),
+            presence: None,
+        });
+
+        // The runner of the shard has already stopped, dropping its receiver.
+        let runner_tx = ShardMessenger {
+            tx: mpsc::unbounded().0,
+            #[cfg(feature = "collector")]
+            collectors: Arc::new(StdMutex::new(Vec::new())),
+        };
+        manager.runners.lock().await.insert(ShardId(0), ShardRunnerInfo {
+            latency: None,
+            runner_tx,
+            stage: ConnectionStage::Connected,
+        });
+        manager.set_runner_task(ShardId(0), tokio::spawn(async {}).abort_handle());
+
+        let outcomes = manager.shutdown_all_with_timeout(Duration::from_millis(50)).await;
+        assert_eq!(outcomes, [(ShardId(0), ShutdownOutcome::Errored)]);
+        assert!(manager.runner_tasks.lock().unwrap().is_empty());
+    }
+}
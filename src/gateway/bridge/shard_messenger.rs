@@ -163,8 +163,18 @@ impl ShardMessenger {
         self.send_to_shard(ShardRunnerMessage::SetActivity(activity));
     }
 
+    /// Sets multiple activities at once, replacing the current activities.
+    ///
+    /// Setting no activities clears the current activities.
+    pub fn set_activities(&self, activities: impl IntoIterator<Item = ActivityData>) {
+        let activities = activities.into_iter().collect();
+        self.send_to_shard(ShardRunnerMessage::SetActivities(activities));
+    }
+
     /// Sets the user's full presence information.
     ///
+    /// Accepts either an [`Option`] for a single activity, or a collection of activities.
+    ///
     /// Consider using the individual setters if you only need to modify one of these.
     ///
     /// # Examples
@@ -194,12 +204,17 @@ impl ShardMessenger {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn set_presence(&self, activity: Option<ActivityData>, mut status: OnlineStatus) {
+    pub fn set_presence(
+        &self,
+        activities: impl IntoIterator<Item = ActivityData>,
+        mut status: OnlineStatus,
+    ) {
         if status == OnlineStatus::Offline {
             status = OnlineStatus::Invisible;
         }
 
-        self.send_to_shard(ShardRunnerMessage::SetPresence(activity, status));
+        let activities = activities.into_iter().collect();
+        self.send_to_shard(ShardRunnerMessage::SetPresence(activities, status));
     }
 
     /// Sets the user's current online status.
@@ -247,6 +262,33 @@ impl ShardMessenger {
         self.send_to_shard(ShardRunnerMessage::SetStatus(online_status));
     }
 
+    /// Updates the current user's voice state in a guild.
+    ///
+    /// Passing a `channel_id` joins or moves to that voice channel, while [`None`] disconnects
+    /// from voice in the guild. Unlike most voice integrations, this also allows setting
+    /// `self_video` for camera and streaming integrations.
+    ///
+    /// **Note**: If a [`VoiceGatewayManager`] such as songbird is in use, prefer its methods, as
+    /// it needs to keep track of the voice state itself.
+    ///
+    /// [`VoiceGatewayManager`]: crate::gateway::VoiceGatewayManager
+    pub fn update_voice_state(
+        &self,
+        guild_id: GuildId,
+        channel_id: Option<ChannelId>,
+        self_mute: bool,
+        self_deaf: bool,
+        self_video: bool,
+    ) {
+        self.send_to_shard(ShardRunnerMessage::UpdateVoiceState {
+            guild_id,
+            channel_id,
+            self_mute,
+            self_deaf,
+            self_video,
+        });
+    }
+
     /// Shuts down the websocket by attempting to cleanly close the connection.
     pub fn shutdown_clean(&self) {
         self.send_to_shard(ShardRunnerMessage::Close(1000, None));
@@ -262,6 +304,24 @@ impl ShardMessenger {
         self.send_to_shard(ShardRunnerMessage::Message(message));
     }
 
+    /// Sends a raw JSON payload over the WebSocket, e.g. a gateway opcode not yet supported by
+    /// serenity.
+    ///
+    /// The payload must be a complete gateway payload, including the `op` and `d` fields, and is
+    /// sent as-is. Prefer the typed methods, such as [`Self::update_voice_state`], where possible.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Json`] if the payload could not be serialized.
+    ///
+    /// [`Error::Json`]: crate::Error::Json
+    #[cfg(feature = "unstable_gateway")]
+    pub fn send_raw(&self, json: &crate::json::Value) -> Result<()> {
+        let text = crate::json::to_string(json)?;
+        self.websocket_message(Message::Text(text));
+        Ok(())
+    }
+
     /// Sends a message to the shard.
     #[inline]
     pub fn send_to_shard(&self, msg: ShardRunnerMessage) {
@@ -1,12 +1,13 @@
-use std::collections::{HashMap, VecDeque};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::sync::Arc;
 #[cfg(feature = "framework")]
 use std::sync::OnceLock;
 
 use futures::channel::mpsc::UnboundedReceiver as Receiver;
+use futures::future::join_all;
 use futures::StreamExt;
 use tokio::sync::{Mutex, RwLock};
-use tokio::time::{sleep, timeout, Duration, Instant};
+use tokio::time::{timeout, Duration, Instant};
 use tracing::{debug, info, instrument, warn};
 use typemap_rev::TypeMap;
 
@@ -38,6 +39,11 @@ const WAIT_BETWEEN_BOOTS_IN_SECONDS: u64 = 5;
 ///
 /// A shard queuer instance _should_ be run in its own thread, due to the blocking nature of the
 /// loop itself as well as a 5 second thread sleep between shard starts.
+///
+/// Bots with a [`max_concurrency`] above 1 may start that many shards per 5 seconds, as long as
+/// each of them is in a different ratelimit bucket, which is `shard_id % max_concurrency`.
+///
+/// [`max_concurrency`]: crate::model::gateway::SessionStartLimit::max_concurrency
 pub struct ShardQueuer {
     /// A copy of [`Client::data`] to be given to runners for contextual dispatching.
     ///
@@ -54,7 +60,7 @@ pub struct ShardQueuer {
     /// A copy of the framework
     #[cfg(feature = "framework")]
     pub framework: Arc<OnceLock<Arc<dyn Framework>>>,
-    /// The instant that a shard was last started.
+    /// The instant that a batch of shards was last started.
     ///
     /// This is used to determine how long to wait between shard IDENTIFYs.
     pub last_start: Option<Instant>,
@@ -86,12 +92,14 @@ impl ShardQueuer {
     /// This will loop over the internal [`Self::rx`] for [`ShardQueuerMessage`]s, blocking for
     /// messages on what to do.
     ///
-    /// If a [`ShardQueuerMessage::Start`] is received, this will:
+    /// If a [`ShardQueuerMessage::Start`] is received, the shard is queued. Queued shards are
+    /// started in batches:
     ///
-    /// 1. Check how much time has passed since the last shard was started
-    /// 2. If the amount of time is less than the ratelimit, it will sleep until that time has
-    ///    passed
-    /// 3. Start the shard by ID
+    /// 1. Check how much time has passed since the last batch was started
+    /// 2. If the amount of time is less than the ratelimit, it will wait until that time has
+    ///    passed, while still handling incoming messages
+    /// 3. Concurrently start up to [`ShardManager::max_concurrency`] queued shards, one per
+    ///    ratelimit bucket
     ///
     /// If a [`ShardQueuerMessage::Shutdown`] is received, this will return and the loop will be
     /// over.
@@ -105,78 +113,118 @@ impl ShardQueuer {
         const TIMEOUT: Duration = Duration::from_secs(WAIT_BETWEEN_BOOTS_IN_SECONDS);
 
         loop {
-            match timeout(TIMEOUT, self.rx.next()).await {
-                Ok(Some(ShardQueuerMessage::Shutdown)) => {
-                    debug!("[Shard Queuer] Received to shutdown.");
-                    self.shutdown_runners().await;
+            let wait = if self.queue.is_empty() { TIMEOUT } else { self.time_until_next_batch() };
 
-                    break;
-                },
-                Ok(Some(ShardQueuerMessage::ShutdownShard(shard, code))) => {
-                    debug!("[Shard Queuer] Received to shutdown shard {} with {}.", shard.0, code);
-                    self.shutdown(shard, code).await;
-                },
-                Ok(Some(ShardQueuerMessage::Start(id, total))) => {
-                    debug!("[Shard Queuer] Received to start shard {} of {}.", id.0, total.0);
-                    self.checked_start(id, total.0).await;
-                },
-                Ok(None) => break,
-                Err(_) => {
-                    if let Some(shard) = self.queue.pop_front() {
-                        self.checked_start(shard.id, shard.total).await;
+            match timeout(wait, self.rx.next()).await {
+                Ok(Some(msg)) => {
+                    if !self.handle_message(msg).await {
+                        break;
                     }
                 },
+                Ok(None) => break,
+                Err(_) => {},
+            }
+
+            // Pick up all messages that are already waiting, so that shards requested together
+            // can be started in the same batch.
+            loop {
+                match self.rx.try_recv() {
+                    Ok(msg) => {
+                        if !self.handle_message(msg).await {
+                            return;
+                        }
+                    },
+                    Err(why) if why.is_closed() => return,
+                    Err(_) => break,
+                }
+            }
+
+            if !self.queue.is_empty() && self.time_until_next_batch().is_zero() {
+                self.start_batch().await;
             }
         }
     }
 
-    #[instrument(skip(self))]
-    async fn check_last_start(&mut self) {
-        let Some(instant) = self.last_start else { return };
+    /// Handles a message, returning whether the queuer should keep running.
+    async fn handle_message(&mut self, msg: ShardQueuerMessage) -> bool {
+        match msg {
+            ShardQueuerMessage::Shutdown => {
+                debug!("[Shard Queuer] Received to shutdown.");
+                self.shutdown_runners().await;
+
+                return false;
+            },
+            ShardQueuerMessage::ShutdownShard(shard, code) => {
+                debug!("[Shard Queuer] Received to shutdown shard {} with {}.", shard.0, code);
+                self.shutdown(shard, code).await;
+            },
+            ShardQueuerMessage::Start(id, total) => {
+                debug!("[Shard Queuer] Received to start shard {} of {}.", id.0, total.0);
+                self.queue.push_back(ShardInfo::new(id, total.0));
+            },
+        }
+
+        true
+    }
+
+    fn time_until_next_batch(&self) -> Duration {
+        let Some(instant) = self.last_start else { return Duration::ZERO };
 
         // We must wait 5 seconds between IDENTIFYs to avoid session invalidations.
         let duration = Duration::from_secs(WAIT_BETWEEN_BOOTS_IN_SECONDS);
-        let elapsed = instant.elapsed();
+        duration.saturating_sub(instant.elapsed())
+    }
 
-        if elapsed >= duration {
+    #[instrument(skip(self))]
+    async fn start_batch(&mut self) {
+        let max_concurrency = self.manager.max_concurrency();
+        let batch = next_batch(&mut self.queue, max_concurrency);
+        if batch.is_empty() {
             return;
         }
 
-        let to_sleep = duration - elapsed;
-
-        sleep(to_sleep).await;
-    }
+        info!(
+            "[Shard Queuer] Starting shards {:?} (max concurrency {})",
+            batch.iter().map(|shard| shard.id.0).collect::<Vec<_>>(),
+            max_concurrency,
+        );
 
-    #[instrument(skip(self))]
-    async fn checked_start(&mut self, id: ShardId, total: u32) {
-        debug!("[Shard Queuer] Checked start for shard {} out of {}", id, total);
-        self.check_last_start().await;
+        // Shards of a batch are in different ratelimit buckets, so they may identify at the same
+        // time.
+        let results = join_all(batch.iter().map(|shard| self.start(shard.id, shard.total))).await;
 
-        if let Err(why) = self.start(id, total).await {
-            warn!("[Shard Queuer] Err starting shard {}: {:?}", id, why);
-            info!("[Shard Queuer] Re-queueing start of shard {}", id);
+        for (shard, result) in batch.into_iter().zip(results) {
+            if let Err(why) = result {
+                warn!("[Shard Queuer] Err starting shard {}: {:?}", shard.id, why);
+                info!("[Shard Queuer] Re-queueing start of shard {}", shard.id);
 
-            self.queue.push_back(ShardInfo::new(id, total));
+                self.queue.push_back(shard);
+            }
         }
 
         self.last_start = Some(Instant::now());
     }
 
     #[instrument(skip(self))]
-    async fn start(&mut self, id: ShardId, total: u32) -> Result<()> {
+    async fn start(&self, id: ShardId, total: u32) -> Result<()> {
         let shard_info = ShardInfo::new(id, total);
+        let connection = self.manager.connection_options();
 
-        let mut shard = Shard::new(
+        let mut shard = Shard::new_with_compression(
             Arc::clone(&self.ws_url),
             self.http.token(),
             shard_info,
             self.intents,
             self.presence.clone(),
+            connection.gateway_proxy.clone(),
+            connection.transport_compression,
         )
         .await?;
 
         let cloned_http = Arc::clone(&self.http);
         shard.set_application_id_callback(move |id| cloned_http.set_application_id(id));
+        shard.set_identify_properties(connection.identify_properties.clone());
+        shard.set_outgoing_payload_hook(connection.outgoing_payload_hook);
 
         let mut runner = ShardRunner::new(ShardRunnerOptions {
             data: Arc::clone(&self.data),
@@ -191,6 +239,7 @@ impl ShardQueuer {
             #[cfg(feature = "cache")]
             cache: Arc::clone(&self.cache),
             http: Arc::clone(&self.http),
+            connection,
         });
 
         let runner_info = ShardRunnerInfo {
@@ -199,10 +248,11 @@ impl ShardQueuer {
             stage: ConnectionStage::Disconnected,
         };
 
-        spawn_named("shard_queuer::stop", async move {
+        let task = spawn_named("shard_queuer::stop", async move {
             drop(runner.run().await);
             debug!("[ShardRunner {:?}] Stopping", runner.shard.shard_info());
         });
+        self.manager.set_runner_task(id, task.abort_handle());
 
         self.runners.lock().await.insert(id, runner_info);
 
@@ -250,3 +300,67 @@ impl ShardQueuer {
         }
     }
 }
+
+/// Takes up to `max_concurrency` shards from the queue, at most one per ratelimit bucket,
+/// preserving the order of the remaining shards.
+fn next_batch(queue: &mut VecDeque<ShardInfo>, max_concurrency: u16) -> Vec<ShardInfo> {
+    let max_concurrency = u32::from(max_concurrency.max(1));
+
+    let mut buckets = HashSet::new();
+    let mut batch = Vec::new();
+    let mut i = 0;
+    while i < queue.len() && batch.len() < max_concurrency as usize {
+        if buckets.insert(queue[i].id.0 % max_concurrency) {
+            batch.extend(queue.remove(i));
+        } else {
+            i += 1;
+        }
+    }
+
+    batch
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+
+    use super::next_batch;
+    use crate::model::gateway::ShardInfo;
+    use crate::model::id::ShardId;
+
+    fn queue(ids: &[u32]) -> VecDeque<ShardInfo> {
+        ids.iter().map(|id| ShardInfo::new(ShardId(*id), 16)).collect()
+    }
+
+    fn ids(shards: &[ShardInfo]) -> Vec<u32> {
+        shards.iter().map(|shard| shard.id.0).collect()
+    }
+
+    #[test]
+    fn serial_batches() {
+        let mut queue = queue(&[0, 1, 2]);
+        assert_eq!(ids(&next_batch(&mut queue, 1)), [0]);
+        assert_eq!(ids(&next_batch(&mut queue, 1)), [1]);
+        assert_eq!(ids(&next_batch(&mut queue, 0)), [2]);
+        assert!(next_batch(&mut queue, 1).is_empty());
+    }
+
+    #[test]
+    fn bucketed_batches() {
+        let mut queue = queue(&(0..10).collect::<Vec<_>>());
+        assert_eq!(ids(&next_batch(&mut queue, 4)), [0, 1, 2, 3]);
+        assert_eq!(ids(&next_batch(&mut queue, 4)), [4, 5, 6, 7]);
+        assert_eq!(ids(&next_batch(&mut queue, 4)), [8, 9]);
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn one_shard_per_bucket() {
+        // Shards 0, 4 and 8 share bucket 0, shard 2 and 6 share bucket 2.
+        let mut queue = queue(&[0, 4, 2, 8, 6, 1]);
+        assert_eq!(ids(&next_batch(&mut queue, 4)), [0, 2, 1]);
+        assert_eq!(ids(queue.make_contiguous()), [4, 8, 6]);
+        assert_eq!(ids(&next_batch(&mut queue, 4)), [4, 6]);
+        assert_eq!(ids(&next_batch(&mut queue, 4)), [8]);
+    }
+}
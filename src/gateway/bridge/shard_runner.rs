@@ -9,19 +9,20 @@ use tokio_tungstenite::tungstenite::protocol::frame::CloseFrame;
 use tracing::{debug, error, info, instrument, trace, warn};
 use typemap_rev::TypeMap;
 
-use super::event::ShardStageUpdateEvent;
+use super::event::{ShardDisconnectEvent, ShardStageUpdateEvent};
 #[cfg(feature = "collector")]
 use super::CollectorCallback;
 #[cfg(feature = "voice")]
 use super::VoiceGatewayManager;
-use super::{ShardId, ShardManager, ShardRunnerMessage};
+use super::{ShardConnectionOptions, ShardId, ShardManager, ShardRunnerMessage};
 #[cfg(feature = "cache")]
 use crate::cache::Cache;
-use crate::client::dispatch::dispatch_model;
+use crate::client::dispatch::{dispatch_model, PendingGuilds};
 use crate::client::{Context, EventHandler, RawEventHandler};
 #[cfg(feature = "framework")]
 use crate::framework::Framework;
-use crate::gateway::{GatewayError, ReconnectType, Shard, ShardAction};
+use crate::gateway::ws::ReceivedPayload;
+use crate::gateway::{ConnectionStage, GatewayError, ReconnectType, Shard, ShardAction};
 use crate::http::Http;
 use crate::internal::prelude::*;
 use crate::internal::tokio::spawn_named;
@@ -47,6 +48,14 @@ pub struct ShardRunner {
     pub http: Arc<Http>,
     #[cfg(feature = "collector")]
     pub(crate) collectors: Arc<std::sync::Mutex<Vec<CollectorCallback>>>,
+    drop_typing_events: bool,
+    strict_deserialization: bool,
+    deserialization_error_snippets: bool,
+    // the last stage dispatched to the event handlers
+    stage: ConnectionStage,
+    // the close code of the connection, until the disconnect is dispatched
+    close_code: Option<u16>,
+    pending_guilds: PendingGuilds,
 }
 
 impl ShardRunner {
@@ -71,6 +80,12 @@ impl ShardRunner {
             http: opt.http,
             #[cfg(feature = "collector")]
             collectors: Arc::new(std::sync::Mutex::new(vec![])),
+            drop_typing_events: opt.connection.drop_typing_events,
+            strict_deserialization: opt.connection.strict_deserialization,
+            deserialization_error_snippets: opt.connection.deserialization_error_snippets,
+            stage: ConnectionStage::Disconnected,
+            close_code: None,
+            pending_guilds: PendingGuilds::default(),
         }
     }
 
@@ -111,25 +126,9 @@ impl ShardRunner {
                 return self.request_restart().await;
             }
 
-            let pre = self.shard.stage();
+            self.update_stage(self.shard.stage()).await;
             let (event, action, successful) = self.recv_event().await?;
-            let post = self.shard.stage();
-
-            if post != pre {
-                self.update_manager().await;
-
-                for event_handler in self.event_handlers.clone() {
-                    let context = self.make_context();
-                    let event = ShardStageUpdateEvent {
-                        new: post,
-                        old: pre,
-                        shard_id: self.shard.shard_info().id,
-                    };
-                    spawn_named("dispatch::event_handler::shard_stage_update", async move {
-                        event_handler.shard_stage_update(context, event).await;
-                    });
-                }
-            }
+            self.update_stage(self.shard.stage()).await;
 
             match action {
                 Some(ShardAction::Reconnect(ReconnectType::Reidentify)) => {
@@ -162,6 +161,8 @@ impl ShardRunner {
                 None => {},
             }
 
+            self.update_stage(self.shard.stage()).await;
+
             if let Some(event) = event {
                 #[cfg(feature = "collector")]
                 self.collectors.lock().expect("poison").retain_mut(|callback| (callback.0)(&event));
@@ -173,6 +174,7 @@ impl ShardRunner {
                     self.framework.clone(),
                     self.event_handlers.clone(),
                     self.raw_event_handlers.clone(),
+                    &mut self.pending_guilds,
                 );
             }
 
@@ -247,6 +249,9 @@ impl ShardRunner {
             }
         }
 
+        self.close_code = Some(close_code);
+        self.update_stage(ConnectionStage::Disconnected).await;
+
         // Inform the manager that shutdown for this shard has finished.
         self.manager.shutdown_finished(id);
         false
@@ -285,6 +290,17 @@ impl ShardRunner {
                 .chunk_guild(guild_id, limit, presences, filter, nonce.as_deref())
                 .await
                 .is_ok(),
+            ShardRunnerMessage::UpdateVoiceState {
+                guild_id,
+                channel_id,
+                self_mute,
+                self_deaf,
+                self_video,
+            } => self
+                .shard
+                .update_voice_state(guild_id, channel_id, self_mute, self_deaf, self_video)
+                .await
+                .is_ok(),
             ShardRunnerMessage::Close(code, reason) => {
                 let reason = reason.unwrap_or_default();
                 let close = CloseFrame {
@@ -298,8 +314,12 @@ impl ShardRunner {
                 self.shard.set_activity(activity);
                 self.shard.update_presence().await.is_ok()
             },
-            ShardRunnerMessage::SetPresence(activity, status) => {
-                self.shard.set_presence(activity, status);
+            ShardRunnerMessage::SetActivities(activities) => {
+                self.shard.set_activities(activities);
+                self.shard.update_presence().await.is_ok()
+            },
+            ShardRunnerMessage::SetPresence(activities, status) => {
+                self.shard.set_presence(activities, status);
                 self.shard.update_presence().await.is_ok()
             },
             ShardRunnerMessage::SetStatus(status) => {
@@ -344,13 +364,13 @@ impl ShardRunner {
     #[instrument(skip(self))]
     async fn recv(&mut self) -> Result<bool> {
         loop {
-            match self.runner_rx.try_next() {
-                Ok(Some(value)) => {
+            match self.runner_rx.try_recv() {
+                Ok(value) => {
                     if !self.handle_rx_value(value).await {
                         return Ok(false);
                     }
                 },
-                Ok(None) => {
+                Err(why) if why.is_closed() => {
                     warn!(
                         "[ShardRunner {:?}] Sending half DC; restarting",
                         self.shard.shard_info(),
@@ -372,8 +392,24 @@ impl ShardRunner {
     /// successful.
     #[instrument(skip(self))]
     async fn recv_event(&mut self) -> Result<(Option<Event>, Option<ShardAction>, bool)> {
-        let gw_event = match self.shard.client.recv_json().await {
-            Ok(inner) => Ok(inner),
+        let gw_event = match self
+            .shard
+            .client
+            .recv_json(
+                self.drop_typing_events,
+                self.strict_deserialization,
+                self.deserialization_error_snippets,
+            )
+            .await
+        {
+            Ok(Some(ReceivedPayload::Dropped(seq))) => {
+                self.shard.skip_dispatch(seq);
+                self.manager.event_counters.record_name("TYPING_START");
+
+                return Ok((None, None, true));
+            },
+            Ok(Some(ReceivedPayload::Event(event))) => Ok(Some(event)),
+            Ok(None) => Ok(None),
             Err(Error::Tungstenite(TungsteniteError::Io(_))) => {
                 debug!("Attempting to auto-reconnect");
 
@@ -402,6 +438,10 @@ impl ShardRunner {
             Err(why) => Err(why),
         };
 
+        if let Err(Error::Gateway(GatewayError::Closed(Some(frame)))) = &event {
+            self.close_code = Some(frame.code.into());
+        }
+
         let action = match self.shard.handle_event(&event) {
             Ok(Some(action)) => Some(action),
             Ok(None) => None,
@@ -435,7 +475,10 @@ impl ShardRunner {
         }
 
         let event = match event {
-            Ok(GatewayEvent::Dispatch(_, event)) => Some(event),
+            Ok(GatewayEvent::Dispatch(_, event)) => {
+                self.manager.event_counters.record(&event);
+                Some(event)
+            },
             _ => None,
         };
 
@@ -446,6 +489,7 @@ impl ShardRunner {
     async fn request_restart(&mut self) -> Result<()> {
         debug!("[ShardRunner {:?}] Requesting restart", self.shard.shard_info());
 
+        self.update_stage(ConnectionStage::Disconnected).await;
         self.update_manager().await;
 
         let shard_id = self.shard.shard_info().id;
@@ -459,6 +503,40 @@ impl ShardRunner {
         Ok(())
     }
 
+    /// Dispatches a [`ShardStageUpdateEvent`] to the event handlers if the stage differs from the
+    /// last dispatched one, followed by a [`ShardDisconnectEvent`] if the shard was disconnected.
+    async fn update_stage(&mut self, new: ConnectionStage) {
+        if new == self.stage {
+            return;
+        }
+
+        self.update_manager().await;
+
+        let event = ShardStageUpdateEvent {
+            new,
+            old: std::mem::replace(&mut self.stage, new),
+            shard_id: self.shard.shard_info().id,
+        };
+        let disconnect = (new == ConnectionStage::Disconnected).then(|| ShardDisconnectEvent {
+            old: event.old,
+            shard_id: event.shard_id,
+            close_code: self.close_code.take(),
+        });
+
+        for event_handler in self.event_handlers.clone() {
+            let context = self.make_context();
+            let event = event.clone();
+            let disconnect = disconnect.clone();
+            spawn_named("dispatch::event_handler::shard_stage_update", async move {
+                event_handler.shard_stage_update(context.clone(), event).await;
+
+                if let Some(disconnect) = disconnect {
+                    event_handler.shard_disconnect(context, disconnect).await;
+                }
+            });
+        }
+    }
+
     #[instrument(skip(self))]
     async fn update_manager(&self) {
         self.manager
@@ -485,4 +563,123 @@ pub struct ShardRunnerOptions {
     #[cfg(feature = "cache")]
     pub cache: Arc<Cache>,
     pub http: Arc<Http>,
+    /// How the shard handles gateway payloads. Only the payload handling options are used here,
+    /// the shard is expected to be connected already.
+    pub connection: ShardConnectionOptions,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use async_trait::async_trait;
+    use futures::{SinkExt, StreamExt};
+    use tokio::net::TcpListener;
+    use tokio::sync::Mutex;
+    use tokio::time::timeout;
+    use tokio_tungstenite::tungstenite::protocol::frame::coding::CloseCode;
+
+    use super::*;
+    use crate::gateway::ShardManagerOptions;
+    use crate::model::gateway::{GatewayIntents, ShardInfo};
+
+    struct Handler(Sender<String>);
+
+    #[async_trait]
+    impl EventHandler for Handler {
+        async fn shard_stage_update(&self, _: Context, event: ShardStageUpdateEvent) {
+            self.0.unbounded_send(event.to_string()).unwrap();
+        }
+
+        async fn shard_disconnect(&self, _: Context, event: ShardDisconnectEvent) {
+            self.0.unbounded_send(event.to_string()).unwrap();
+        }
+    }
+
+    /// Accepts a single websocket connection and closes it as ratelimited, standing in for the
+    /// gateway.
+    async fn closing_gateway() -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(stream).await.unwrap();
+            let frame = CloseFrame {
+                code: CloseCode::from(4008),
+                reason: Cow::from("ratelimited"),
+            };
+            ws.send(tungstenite::Message::Close(Some(frame))).await.unwrap();
+            while let Some(Ok(_)) = ws.next().await {}
+        });
+
+        url
+    }
+
+    async fn next_update(rx: &mut Receiver<String>) -> String {
+        timeout(Duration::from_secs(5), rx.next()).await.unwrap().unwrap()
+    }
+
+    #[tokio::test]
+    async fn disconnect_close_code() {
+        let (tx, mut rx) = mpsc::unbounded();
+        let event_handlers: Vec<Arc<dyn EventHandler>> = vec![Arc::new(Handler(tx))];
+        let ws_url = Arc::new(Mutex::new(closing_gateway().await));
+        let data = Arc::new(RwLock::new(TypeMap::new()));
+        #[cfg(feature = "cache")]
+        let cache = Arc::new(Cache::new());
+        let http = Arc::new(Http::new("secret"));
+
+        let (manager, _) = ShardManager::new(ShardManagerOptions {
+            data: Arc::clone(&data),
+            event_handlers: event_handlers.clone(),
+            raw_event_handlers: vec![],
+            #[cfg(feature = "framework")]
+            framework: Arc::new(std::sync::OnceLock::new()),
+            shard_index: 0,
+            shard_init: 1,
+            shard_total: 1,
+            #[cfg(feature = "voice")]
+            voice_manager: None,
+            ws_url: Arc::clone(&ws_url),
+            #[cfg(feature = "cache")]
+            cache: Arc::clone(&cache),
+            http: Arc::clone(&http),
+            intents: GatewayIntents::empty(),
+            presence: None,
+        });
+        let shard_info = ShardInfo::new(ShardId(0), 1);
+        let shard =
+            Shard::new(ws_url, "secret", shard_info, GatewayIntents::empty(), None).await.unwrap();
+        let mut runner = ShardRunner::new(ShardRunnerOptions {
+            data,
+            event_handlers,
+            raw_event_handlers: vec![],
+            #[cfg(feature = "framework")]
+            framework: None,
+            manager,
+            shard,
+            #[cfg(feature = "voice")]
+            voice_manager: None,
+            #[cfg(feature = "cache")]
+            cache,
+            http,
+            connection: ShardConnectionOptions::default(),
+        });
+
+        runner.update_stage(runner.shard.stage()).await;
+        assert_eq!(next_update(&mut rx).await, "shard 0: disconnected -> handshaking");
+
+        let (event, action, successful) = runner.recv_event().await.unwrap();
+        assert!(event.is_none());
+        assert!(matches!(action, Some(ShardAction::Reconnect(ReconnectType::Reidentify))));
+        assert!(successful);
+
+        runner.update_stage(runner.shard.stage()).await;
+        assert_eq!(next_update(&mut rx).await, "shard 0: handshaking -> disconnected");
+        assert_eq!(
+            next_update(&mut rx).await,
+            "shard 0: handshaking -> disconnected (close code 4008)"
+        );
+    }
 }
@@ -2,11 +2,12 @@ use tokio_tungstenite::tungstenite::Message;
 
 use super::ShardId;
 use crate::gateway::{ActivityData, ChunkGuildFilter};
-use crate::model::id::GuildId;
+use crate::model::id::{ChannelId, GuildId};
 use crate::model::user::OnlineStatus;
 
 /// A message to send from a shard over a WebSocket.
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum ShardRunnerMessage {
     /// Indicator that a shard should be restarted.
     Restart(ShardId),
@@ -34,6 +35,21 @@ pub enum ShardRunnerMessage {
         /// [`GuildMembersChunkEvent`]: crate::model::event::GuildMembersChunkEvent
         nonce: Option<String>,
     },
+    /// Indicates that the client is to update the current user's voice state in a guild.
+    UpdateVoiceState {
+        /// The Id of the [`Guild`] to update the voice state in.
+        ///
+        /// [`Guild`]: crate::model::guild::Guild
+        guild_id: GuildId,
+        /// The Id of the voice channel to join, or [`None`] to disconnect.
+        channel_id: Option<ChannelId>,
+        /// Whether the current user is muted.
+        self_mute: bool,
+        /// Whether the current user is deafened.
+        self_deaf: bool,
+        /// Whether the current user's camera is enabled.
+        self_video: bool,
+    },
     /// Indicates that the client is to close with the given status code and reason.
     ///
     /// You should rarely - if _ever_ - need this, but the option is available. Prefer to use the
@@ -46,8 +62,10 @@ pub enum ShardRunnerMessage {
     Message(Message),
     /// Indicates that the client is to update the shard's presence's activity.
     SetActivity(Option<ActivityData>),
+    /// Indicates that the client is to update the shard's presence's activities.
+    SetActivities(Vec<ActivityData>),
     /// Indicates that the client is to update the shard's presence in its entirety.
-    SetPresence(Option<ActivityData>, OnlineStatus),
+    SetPresence(Vec<ActivityData>, OnlineStatus),
     /// Indicates that the client is to update the shard's presence's status.
     SetStatus(OnlineStatus),
 }
@@ -1,8 +1,13 @@
 use std::error::Error as StdError;
 use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
 
 use tokio_tungstenite::tungstenite::protocol::CloseFrame;
 
+use super::ProxyError;
+use crate::json::JsonError;
+
 /// An error that occurred while attempting to deal with the gateway.
 ///
 /// Note that - from a user standpoint - there should be no situation in which you manually handle
@@ -45,11 +50,51 @@ pub enum Error {
     ReconnectFailure,
     /// When undocumented gateway intents are provided.
     InvalidGatewayIntents,
+    /// When one of the [`IdentifyProperties`] is empty.
+    ///
+    /// [`IdentifyProperties`]: super::IdentifyProperties
+    InvalidIdentifyProperties,
     /// When disallowed gateway intents are provided.
     ///
     /// If an connection has been established but privileged gateway intents were provided without
     /// enabling them prior.
     DisallowedGatewayIntents,
+    /// When there are not enough session starts left in the current ratelimit period to start
+    /// all shards.
+    ///
+    /// Identifying beyond the limit resets the bot's token, so starting is refused instead. This
+    /// check can be disabled via [`ClientBuilder::ignore_session_start_limit`].
+    ///
+    /// [`ClientBuilder::ignore_session_start_limit`]: crate::client::ClientBuilder::ignore_session_start_limit
+    InsufficientSessionStarts {
+        /// The number of session starts remaining.
+        remaining: u64,
+        /// The number of session starts required to start all shards.
+        required: u64,
+        /// The time until the limit resets.
+        reset_after: Duration,
+    },
+    /// A payload received from the gateway failed to deserialize.
+    ///
+    /// [`ClientBuilder::strict_deserialization`] can be disabled to recover from some of these.
+    ///
+    /// [`ClientBuilder::strict_deserialization`]: crate::client::ClientBuilder::strict_deserialization
+    Deserialization {
+        /// The name of the event, e.g. `MESSAGE_CREATE`, or the opcode for payloads that are not
+        /// dispatches.
+        event: String,
+        /// The error that occurred while deserializing.
+        source: Arc<JsonError>,
+        /// The start of the payload with special characters escaped, if enabled via
+        /// [`ClientBuilder::deserialization_error_snippets`].
+        ///
+        /// [`ClientBuilder::deserialization_error_snippets`]: crate::client::ClientBuilder::deserialization_error_snippets
+        snippet: Option<String>,
+    },
+    /// Establishing a tunnel through the configured [`GatewayProxy`] failed.
+    ///
+    /// [`GatewayProxy`]: super::GatewayProxy
+    Proxy(ProxyError),
 }
 
 impl fmt::Display for Error {
@@ -67,11 +112,45 @@ impl fmt::Display for Error {
             Self::OverloadedShard => f.write_str("Shard has too many guilds"),
             Self::ReconnectFailure => f.write_str("Failed to Reconnect"),
             Self::InvalidGatewayIntents => f.write_str("Invalid gateway intents were provided"),
+            Self::InvalidIdentifyProperties => {
+                f.write_str("Empty identify properties were provided")
+            },
             Self::DisallowedGatewayIntents => {
                 f.write_str("Disallowed gateway intents were provided")
             },
+            Self::InsufficientSessionStarts {
+                remaining,
+                required,
+                reset_after,
+            } => write!(
+                f,
+                "{required} session starts required, but only {remaining} remaining for the next \
+                 {}s",
+                reset_after.as_secs()
+            ),
+            Self::Deserialization {
+                event,
+                source,
+                snippet,
+            } => {
+                write!(f, "Failed to deserialize {event} payload: {source}")?;
+                if let Some(snippet) = snippet {
+                    write!(f, "; payload: {snippet}")?;
+                }
+                Ok(())
+            },
+            Self::Proxy(why) => fmt::Display::fmt(why, f),
         }
     }
 }
 
-impl StdError for Error {}
+impl StdError for Error {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self {
+            Self::Deserialization {
+                source, ..
+            } => Some(&**source),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,201 @@
+//! Recovery from gateway events that fail to deserialize, used when strict deserialization is
+//! disabled via [`ClientBuilder::strict_deserialization`].
+//!
+//! [`ClientBuilder::strict_deserialization`]: crate::client::ClientBuilder::strict_deserialization
+
+use std::collections::BTreeSet;
+use std::fmt;
+
+use serde::Deserialize;
+
+use crate::json::Value;
+#[cfg(feature = "simd_json")]
+use crate::json::{ValueAsContainer, ValueAsMutContainer};
+use crate::model::event::GatewayEvent;
+
+/// The maximum nesting depth below the event data at which fields are considered for removal.
+const MAX_DEPTH: usize = 6;
+/// The maximum number of fields tried to be removed before giving up.
+const MAX_ATTEMPTS: usize = 256;
+
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+enum Segment {
+    Key(String),
+    Each,
+}
+
+/// The path to a field of a gateway payload, where `[]` stands for every element of an array.
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub(crate) struct FieldPath(Vec<Segment>);
+
+impl fmt::Display for FieldPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, segment) in self.0.iter().enumerate() {
+            match segment {
+                Segment::Key(key) if i == 0 => f.write_str(key)?,
+                Segment::Key(key) => write!(f, ".{key}")?,
+                Segment::Each => f.write_str("[]")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Attempts to deserialize a gateway payload that failed to deserialize, by removing one field of
+/// the event data at a time until it succeeds.
+///
+/// Removed fields fall back to their default value, so this only recovers from fields that are
+/// optional or marked `#[serde(default)]`. Deeper fields are tried first to drop as little data as
+/// possible, and a field is removed from every element of an array at once.
+///
+/// Returns the event and the path of the removed field, or `None` if no single field could be
+/// removed to deserialize the payload.
+pub(crate) fn recover(mut value: Value) -> Option<(GatewayEvent, FieldPath)> {
+    let data = value.as_object()?.get("d")?;
+
+    let mut paths = BTreeSet::new();
+    collect_paths(data, &mut vec![Segment::Key("d".into())], &mut paths);
+    let mut paths: Vec<_> = paths.into_iter().collect();
+    paths.sort_by_key(|path| std::cmp::Reverse(path.0.len()));
+
+    // Fields are removed in place and put back if the payload still fails to deserialize, which
+    // avoids cloning the payload for every attempt.
+    for path in paths.into_iter().take(MAX_ATTEMPTS) {
+        let mut removed = Vec::new();
+        remove(&mut value, &path.0, &mut removed);
+        if let Ok(event) = GatewayEvent::deserialize(&value) {
+            return Some((event, path));
+        }
+        restore(&mut value, &path.0, &mut removed.into_iter());
+    }
+
+    None
+}
+
+fn collect_paths(value: &Value, path: &mut Vec<Segment>, paths: &mut BTreeSet<FieldPath>) {
+    if path.len() > MAX_DEPTH {
+        return;
+    }
+
+    if let Some(map) = value.as_object() {
+        for (key, value) in map {
+            path.push(Segment::Key(key.clone()));
+            paths.insert(FieldPath(path.clone()));
+            collect_paths(value, path, paths);
+            path.pop();
+        }
+    } else if let Some(values) = value.as_array() {
+        path.push(Segment::Each);
+        for value in values {
+            collect_paths(value, path, paths);
+        }
+        path.pop();
+    }
+}
+
+/// Removes the field at `path`, pushing one entry to `removed` for every object visited at the
+/// end of the path, so that [`restore`] can put the fields back.
+fn remove(value: &mut Value, path: &[Segment], removed: &mut Vec<Option<Value>>) {
+    match path {
+        [Segment::Key(key)] => {
+            if let Some(map) = value.as_object_mut() {
+                removed.push(map.remove(key.as_str()));
+            }
+        },
+        [Segment::Key(key), rest @ ..] => {
+            if let Some(value) = value.as_object_mut().and_then(|map| map.get_mut(key.as_str())) {
+                remove(value, rest, removed);
+            }
+        },
+        [Segment::Each, rest @ ..] => {
+            if let Some(values) = value.as_array_mut() {
+                for value in values {
+                    remove(value, rest, removed);
+                }
+            }
+        },
+        [] => {},
+    }
+}
+
+/// Puts back the fields removed by [`remove`], visiting the same objects in the same order.
+fn restore(value: &mut Value, path: &[Segment], removed: &mut impl Iterator<Item = Option<Value>>) {
+    match path {
+        [Segment::Key(key)] => {
+            if let Some(map) = value.as_object_mut() {
+                if let Some(Some(field)) = removed.next() {
+                    map.insert(key.clone(), field);
+                }
+            }
+        },
+        [Segment::Key(key), rest @ ..] => {
+            if let Some(value) = value.as_object_mut().and_then(|map| map.get_mut(key.as_str())) {
+                restore(value, rest, removed);
+            }
+        },
+        [Segment::Each, rest @ ..] => {
+            if let Some(values) = value.as_array_mut() {
+                for value in values {
+                    restore(value, rest, removed);
+                }
+            }
+        },
+        [] => {},
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json::{from_value, json};
+    use crate::model::event::Event;
+
+    fn message_create(channel_id: Value, mentions: Value) -> Value {
+        json!({
+            "op": 0,
+            "s": 3,
+            "t": "MESSAGE_CREATE",
+            "d": {
+                "id": "100",
+                "channel_id": channel_id,
+                "author": {"id": "1", "username": "alice", "discriminator": "0", "avatar": null},
+                "content": "hi",
+                "timestamp": "2024-01-01T00:00:00.000000+00:00",
+                "edited_timestamp": null,
+                "tts": false,
+                "mention_everyone": false,
+                "mentions": mentions,
+                "mention_roles": [],
+                "attachments": [],
+                "embeds": [],
+                "pinned": false,
+                "type": 0,
+            },
+        })
+    }
+
+    #[test]
+    fn recover_non_essential_field() {
+        let value = message_create(
+            json!("10"),
+            json!([
+                {"id": "2", "username": "bob", "discriminator": "0", "avatar": null, "bot": "yes"},
+            ]),
+        );
+        assert!(from_value::<GatewayEvent>(value.clone()).is_err());
+
+        let (event, path) = recover(value).unwrap();
+        assert_eq!(path.to_string(), "d.mentions[].bot");
+        let GatewayEvent::Dispatch(3, Event::MessageCreate(event)) = event else {
+            panic!("expected a MESSAGE_CREATE dispatch");
+        };
+        assert_eq!(event.message.mentions.len(), 1);
+        assert!(!event.message.mentions[0].bot);
+    }
+
+    #[test]
+    fn essential_field_is_not_recovered() {
+        let value = message_create(json!(["not", "an", "id"]), json!([]));
+        assert!(recover(value).is_none());
+    }
+}
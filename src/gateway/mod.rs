@@ -41,9 +41,13 @@
 
 mod bridge;
 mod error;
+#[cfg(feature = "client")]
+mod lenient;
+mod proxy;
 mod shard;
 mod ws;
 
+use std::env::consts;
 use std::fmt;
 
 #[cfg(feature = "http")]
@@ -52,19 +56,67 @@ use reqwest::Url;
 
 pub use self::bridge::*;
 pub use self::error::Error as GatewayError;
+pub use self::proxy::{GatewayProxy, ProxyError};
 pub use self::shard::Shard;
-pub use self::ws::WsClient;
+pub use self::ws::{OutgoingPayloadHook, TransportCompression, WsClient};
 #[cfg(feature = "http")]
 use crate::internal::prelude::*;
 use crate::model::gateway::{Activity, ActivityType};
 use crate::model::id::UserId;
 use crate::model::user::OnlineStatus;
 
+/// The connection properties sent when identifying with the gateway.
+///
+/// Discord uses these for analytics, and some tooling shows them to users. By default, the
+/// properties serenity always sent are used: the operating system the bot runs on, and
+/// `serenity` as both the browser and device.
+///
+/// Set via [`ClientBuilder::identify_properties`] or
+/// [`ShardConnectionOptions::identify_properties`].
+///
+/// [Discord docs](https://discord.com/developers/docs/topics/gateway-events#identify-identify-connection-properties).
+///
+/// [`ClientBuilder::identify_properties`]: crate::client::ClientBuilder::identify_properties
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct IdentifyProperties {
+    /// The operating system of the connection.
+    pub os: String,
+    /// The library name, or the browser for user clients.
+    pub browser: String,
+    /// The library name, or the device for user clients.
+    pub device: String,
+}
+
+impl IdentifyProperties {
+    /// Checks that none of the properties are empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GatewayError::InvalidIdentifyProperties`] if any property is empty.
+    pub fn validate(&self) -> Result<()> {
+        if [&self.os, &self.browser, &self.device].iter().any(|property| property.is_empty()) {
+            return Err(Error::Gateway(GatewayError::InvalidIdentifyProperties));
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for IdentifyProperties {
+    fn default() -> Self {
+        Self {
+            os: consts::OS.into(),
+            browser: "serenity".into(),
+            device: "serenity".into(),
+        }
+    }
+}
+
 /// Presence data of the current user.
 #[derive(Clone, Debug, Default)]
 pub struct PresenceData {
-    /// The current activity, if present
-    pub activity: Option<ActivityData>,
+    /// The current activities, which may be empty.
+    pub activities: Vec<ActivityData>,
     /// The current online status
     pub status: OnlineStatus,
 }
@@ -143,13 +195,14 @@ impl ActivityData {
         }
     }
 
-    /// Creates an activity that appears as `<state>`.
+    /// Creates a custom status activity that appears as `<state>`.
+    ///
+    /// Discord displays the [`Self::state`] of custom activities rather than their name.
     #[must_use]
     pub fn custom(state: impl Into<String>) -> Self {
         Self {
-            // discord seems to require a name for custom activities
-            // even though it's not displayed
-            name: "~".to_string(),
+            // Discord requires a name for custom activities, even though it is not displayed
+            name: "Custom Status".to_string(),
             kind: ActivityType::Custom,
             state: Some(state.into()),
             url: None,
@@ -268,3 +321,48 @@ pub enum ChunkGuildFilter {
     /// Will return a maximum of 100 members.
     UserIds(Vec<UserId>),
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json::{json, to_value};
+
+    #[test]
+    fn identify_properties() {
+        let properties = IdentifyProperties::default();
+        assert!(properties.validate().is_ok());
+        assert_eq!(
+            to_value(&properties).unwrap(),
+            json!({
+                "os": consts::OS,
+                "browser": "serenity",
+                "device": "serenity",
+            })
+        );
+
+        let properties = IdentifyProperties {
+            os: "linux".into(),
+            browser: "my-bot".into(),
+            device: String::new(),
+        };
+        assert!(matches!(
+            properties.validate(),
+            Err(Error::Gateway(GatewayError::InvalidIdentifyProperties))
+        ));
+    }
+
+    #[test]
+    fn custom_activity() {
+        let activity = ActivityData::custom("doing things 🦀");
+
+        assert_eq!(
+            to_value(activity).unwrap(),
+            json!({
+                "name": "Custom Status",
+                "type": 4,
+                "state": "doing things 🦀",
+                "url": null,
+            })
+        );
+    }
+}
@@ -12,16 +12,20 @@ use super::{
     ChunkGuildFilter,
     ConnectionStage,
     GatewayError,
+    GatewayProxy,
+    IdentifyProperties,
+    OutgoingPayloadHook,
     PresenceData,
     ReconnectType,
     ShardAction,
+    TransportCompression,
     WsClient,
 };
-use crate::constants::{self, close_codes};
+use crate::constants::close_codes;
 use crate::internal::prelude::*;
 use crate::model::event::{Event, GatewayEvent};
 use crate::model::gateway::{GatewayIntents, ShardInfo};
-use crate::model::id::{ApplicationId, GuildId};
+use crate::model::id::{ApplicationId, ChannelId, GuildId};
 use crate::model::user::OnlineStatus;
 
 /// A Shard is a higher-level handler for a websocket connection to Discord's gateway.
@@ -75,7 +79,11 @@ pub struct Shard {
     pub started: Instant,
     pub token: String,
     ws_url: Arc<Mutex<String>>,
+    proxy: Option<GatewayProxy>,
+    compression: TransportCompression,
+    outgoing_payload_hook: Option<OutgoingPayloadHook>,
     pub intents: GatewayIntents,
+    identify_properties: IdentifyProperties,
 }
 
 impl Shard {
@@ -125,9 +133,59 @@ impl Shard {
         shard_info: ShardInfo,
         intents: GatewayIntents,
         presence: Option<PresenceData>,
+    ) -> Result<Shard> {
+        Self::new_with_proxy(ws_url, token, shard_info, intents, presence, None).await
+    }
+
+    /// Instantiates a new instance of a Shard, connecting through the given proxy.
+    ///
+    /// The proxy is also used when reconnecting or resuming. See [`Self::new`] for more
+    /// information.
+    ///
+    /// # Errors
+    ///
+    /// On Error, will return either [`Error::Gateway`], [`Error::Tungstenite`] or a Rustls/native
+    /// TLS error. Failing to establish the tunnel through the proxy returns a
+    /// [`GatewayError::Proxy`].
+    pub async fn new_with_proxy(
+        ws_url: Arc<Mutex<String>>,
+        token: &str,
+        shard_info: ShardInfo,
+        intents: GatewayIntents,
+        presence: Option<PresenceData>,
+        proxy: Option<GatewayProxy>,
+    ) -> Result<Shard> {
+        Self::new_with_compression(
+            ws_url,
+            token,
+            shard_info,
+            intents,
+            presence,
+            proxy,
+            TransportCompression::default(),
+        )
+        .await
+    }
+
+    /// Instantiates a new instance of a Shard, receiving payloads with the given compression.
+    ///
+    /// The compression is also used when reconnecting or resuming. See [`Self::new_with_proxy`]
+    /// for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Self::new_with_proxy`].
+    pub async fn new_with_compression(
+        ws_url: Arc<Mutex<String>>,
+        token: &str,
+        shard_info: ShardInfo,
+        intents: GatewayIntents,
+        presence: Option<PresenceData>,
+        proxy: Option<GatewayProxy>,
+        compression: TransportCompression,
     ) -> Result<Shard> {
         let url = ws_url.lock().await.clone();
-        let client = connect(&url).await?;
+        let client = connect(&url, proxy.as_ref(), compression).await?;
 
         let presence = presence.unwrap_or_default();
         let last_heartbeat_sent = None;
@@ -153,7 +211,11 @@ impl Shard {
             session_id,
             shard_info,
             ws_url,
+            proxy,
+            compression,
             intents,
+            identify_properties: IdentifyProperties::default(),
+            outgoing_payload_hook: None,
         })
     }
 
@@ -167,6 +229,20 @@ impl Shard {
         self.application_id_callback = Some(Box::new(callback));
     }
 
+    /// Sets the connection properties sent when identifying, used from the next identify on.
+    pub fn set_identify_properties(&mut self, properties: IdentifyProperties) {
+        self.identify_properties = properties;
+    }
+
+    /// Sets a hook called with every payload the shard sends, except heartbeats, see
+    /// [`OutgoingPayloadHook`].
+    ///
+    /// The hook is kept when reconnecting or resuming.
+    pub fn set_outgoing_payload_hook(&mut self, hook: Option<OutgoingPayloadHook>) {
+        self.outgoing_payload_hook = hook;
+        self.client.set_outgoing_payload_hook(self.shard_info.id, hook);
+    }
+
     /// Retrieves the current presence of the shard.
     #[inline]
     pub fn presence(&self) -> &PresenceData {
@@ -240,16 +316,29 @@ impl Shard {
         self.session_id.as_ref()
     }
 
+    /// Sets a single activity, replacing any current activities.
     #[inline]
     #[instrument(skip(self))]
     pub fn set_activity(&mut self, activity: Option<ActivityData>) {
-        self.presence.activity = activity;
+        self.set_activities(activity);
     }
 
+    /// Sets the activities, replacing any current activities.
     #[inline]
-    #[instrument(skip(self))]
-    pub fn set_presence(&mut self, activity: Option<ActivityData>, status: OnlineStatus) {
-        self.set_activity(activity);
+    pub fn set_activities(&mut self, activities: impl IntoIterator<Item = ActivityData>) {
+        self.presence.activities = activities.into_iter().collect();
+    }
+
+    /// Sets the activities and the online status.
+    ///
+    /// Accepts either an [`Option`] for a single activity, or a collection of activities.
+    #[inline]
+    pub fn set_presence(
+        &mut self,
+        activities: impl IntoIterator<Item = ActivityData>,
+        status: OnlineStatus,
+    ) {
+        self.set_activities(activities);
         self.set_status(status);
     }
 
@@ -309,6 +398,15 @@ impl Shard {
         None
     }
 
+    /// Advances the sequence number for a dispatch that was dropped without being deserialized.
+    pub(crate) fn skip_dispatch(&mut self, seq: u64) {
+        if seq > self.seq + 1 {
+            warn!("[{:?}] Sequence off; them: {}, us: {}", self.shard_info, seq, self.seq);
+        }
+
+        self.seq = seq;
+    }
+
     #[instrument(skip(self))]
     fn handle_heartbeat_event(&mut self, s: u64) -> ShardAction {
         info!("[{:?}] Received shard heartbeat", self.shard_info);
@@ -341,6 +439,8 @@ impl Shard {
         let num = data.map(|d| d.code.into());
         let clean = num == Some(1000);
 
+        self.stage = ConnectionStage::Disconnected;
+
         match num {
             Some(close_codes::UNKNOWN_OPCODE) => {
                 warn!("[{:?}] Sent invalid opcode.", self.shard_info);
@@ -672,13 +772,51 @@ impl Shard {
             .await
     }
 
+    /// Updates the current user's voice state in a guild, joining, moving between, or leaving
+    /// (if `channel_id` is [`None`]) voice channels.
+    ///
+    /// **Note**: If a [`VoiceGatewayManager`] such as songbird is in use, prefer its methods, as
+    /// it needs to keep track of the voice state itself.
+    ///
+    /// # Errors
+    ///
+    /// Errors if there is a problem with the WS connection.
+    ///
+    /// [`VoiceGatewayManager`]: crate::gateway::VoiceGatewayManager
+    #[instrument(skip(self))]
+    pub async fn update_voice_state(
+        &mut self,
+        guild_id: GuildId,
+        channel_id: Option<ChannelId>,
+        self_mute: bool,
+        self_deaf: bool,
+        self_video: bool,
+    ) -> Result<()> {
+        self.client
+            .send_voice_state_update(
+                &self.shard_info,
+                guild_id,
+                channel_id,
+                self_mute,
+                self_deaf,
+                self_video,
+            )
+            .await
+    }
+
     /// Sets the shard as going into identifying stage, which sets:
     /// - the time that the last heartbeat sent as being now
     /// - the `stage` to [`ConnectionStage::Identifying`]
     #[instrument(skip(self))]
     pub async fn identify(&mut self) -> Result<()> {
         self.client
-            .send_identify(&self.shard_info, &self.token, self.intents, &self.presence)
+            .send_identify_with_properties(
+                &self.shard_info,
+                &self.token,
+                self.intents,
+                &self.identify_properties,
+                &self.presence,
+            )
             .await?;
 
         self.last_heartbeat_sent = Some(Instant::now());
@@ -704,7 +842,8 @@ impl Shard {
         self.stage = ConnectionStage::Connecting;
         self.started = Instant::now();
         let url = &self.ws_url.lock().await.clone();
-        let client = connect(url).await?;
+        let mut client = connect(url, self.proxy.as_ref(), self.compression).await?;
+        client.set_outgoing_payload_hook(self.shard_info.id, self.outgoing_payload_hook);
         self.stage = ConnectionStage::Handshake;
 
         Ok(client)
@@ -752,13 +891,76 @@ impl Shard {
     }
 }
 
-async fn connect(base_url: &str) -> Result<WsClient> {
-    let url =
-        Url::parse(&format!("{base_url}?v={}", constants::GATEWAY_VERSION)).map_err(|why| {
-            warn!("Error building gateway URL with base `{}`: {:?}", base_url, why);
+async fn connect(
+    base_url: &str,
+    proxy: Option<&GatewayProxy>,
+    compression: TransportCompression,
+) -> Result<WsClient> {
+    let url = Url::parse(&format!("{base_url}?{}", compression.query())).map_err(|why| {
+        warn!("Error building gateway URL with base `{}`: {:?}", base_url, why);
+
+        Error::Gateway(GatewayError::BuildingUrl)
+    })?;
+
+    WsClient::connect(url, proxy, compression).await
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex as StdMutex;
 
-            Error::Gateway(GatewayError::BuildingUrl)
-        })?;
+    use futures::StreamExt;
+    use tokio::net::TcpListener;
 
-    WsClient::connect(url).await
+    use super::*;
+    use crate::json::Value;
+    use crate::model::id::ShardId;
+
+    static SENT: StdMutex<Vec<(ShardId, Value)>> = StdMutex::new(Vec::new());
+
+    fn record(shard_id: &ShardId, payload: &Value) {
+        SENT.lock().unwrap().push((*shard_id, payload.clone()));
+    }
+
+    /// Accepts a single websocket connection and reads from it until it is closed, standing in
+    /// for the gateway.
+    async fn mock_gateway() -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(stream).await.unwrap();
+            while let Some(Ok(_)) = ws.next().await {}
+        });
+
+        url
+    }
+
+    #[tokio::test]
+    async fn outgoing_payload_hook() {
+        let url = Arc::new(Mutex::new(mock_gateway().await));
+        let shard_info = ShardInfo::new(ShardId(1), 2);
+        let mut shard =
+            Shard::new(url, "secret", shard_info, GatewayIntents::empty(), None).await.unwrap();
+        shard.set_outgoing_payload_hook(Some(record));
+
+        shard.heartbeat().await.unwrap();
+        shard.identify().await.unwrap();
+        shard.set_activity(Some(ActivityData::playing("with crabs")));
+        shard.update_presence().await.unwrap();
+
+        let sent = SENT.lock().unwrap();
+        // Heartbeats are not passed to the hook.
+        assert_eq!(sent.len(), 2);
+        assert!(sent.iter().all(|(shard_id, _)| *shard_id == ShardId(1)));
+
+        let (_, identify) = &sent[0];
+        assert_eq!(identify["op"], 2);
+        assert_eq!(identify["d"]["token"], "[redacted]");
+
+        let (_, presence) = &sent[1];
+        assert_eq!(presence["op"], 3);
+        assert_eq!(presence["d"]["activities"][0]["name"], "with crabs");
+    }
 }
@@ -1,10 +1,14 @@
-use std::env::consts;
 #[cfg(feature = "client")]
 use std::io::Read;
+#[cfg(feature = "client")]
+use std::sync::Arc;
 use std::time::SystemTime;
 
 #[cfg(feature = "client")]
 use flate2::read::ZlibDecoder;
+use flate2::Decompress;
+#[cfg(feature = "client")]
+use flate2::{FlushDecompress, Status};
 use futures::SinkExt;
 #[cfg(feature = "client")]
 use futures::StreamExt;
@@ -17,34 +21,36 @@ use tokio_tungstenite::tungstenite::protocol::WebSocketConfig;
 #[cfg(feature = "client")]
 use tokio_tungstenite::tungstenite::Error as WsError;
 use tokio_tungstenite::tungstenite::Message;
-use tokio_tungstenite::{connect_async_with_config, MaybeTlsStream, WebSocketStream};
+use tokio_tungstenite::{
+    client_async_tls_with_config,
+    connect_async_with_config,
+    MaybeTlsStream,
+    WebSocketStream,
+};
 #[cfg(feature = "client")]
 use tracing::warn;
 use tracing::{debug, instrument, trace};
 use url::Url;
 
-use super::{ActivityData, ChunkGuildFilter, PresenceData};
+#[cfg(feature = "client")]
+use super::lenient;
+use super::{ActivityData, ChunkGuildFilter, GatewayProxy, IdentifyProperties, PresenceData};
 use crate::constants::{self, Opcode};
 #[cfg(feature = "client")]
 use crate::gateway::GatewayError;
 #[cfg(feature = "client")]
 use crate::json::from_str;
-use crate::json::to_string;
+#[cfg(feature = "simd_json")]
+use crate::json::ValueAsMutContainer;
+use crate::json::{to_string, to_value, Value};
 #[cfg(feature = "client")]
-use crate::model::event::GatewayEvent;
+use crate::model::event::{Event, GatewayEvent, UnknownEvent};
 use crate::model::gateway::{GatewayIntents, ShardInfo};
-use crate::model::id::{GuildId, UserId};
+use crate::model::id::{ChannelId, GuildId, ShardId, UserId};
 #[cfg(feature = "client")]
 use crate::Error;
 use crate::Result;
 
-#[derive(Serialize)]
-struct IdentifyProperties {
-    browser: &'static str,
-    device: &'static str,
-    os: &'static str,
-}
-
 #[derive(Serialize)]
 struct ChunkGuildMessage<'a> {
     guild_id: GuildId,
@@ -62,7 +68,16 @@ struct PresenceUpdateMessage<'a> {
     afk: bool,
     status: &'a str,
     since: SystemTime,
-    activities: &'a [&'a ActivityData],
+    activities: &'a [ActivityData],
+}
+
+#[derive(Serialize)]
+struct VoiceStateUpdateMessage {
+    guild_id: GuildId,
+    channel_id: Option<ChannelId>,
+    self_mute: bool,
+    self_deaf: bool,
+    self_video: bool,
 }
 
 #[derive(Serialize)]
@@ -76,10 +91,11 @@ enum WebSocketMessageData<'a> {
         large_threshold: u8,
         shard: &'a ShardInfo,
         intents: GatewayIntents,
-        properties: IdentifyProperties,
+        properties: &'a IdentifyProperties,
         presence: PresenceUpdateMessage<'a>,
     },
     PresenceUpdate(PresenceUpdateMessage<'a>),
+    VoiceStateUpdate(VoiceStateUpdateMessage),
     Resume {
         session_id: &'a str,
         token: &'a str,
@@ -93,7 +109,269 @@ struct WebSocketMessage<'a> {
     d: WebSocketMessageData<'a>,
 }
 
-pub struct WsClient(WebSocketStream<MaybeTlsStream<TcpStream>>);
+/// The compression used for payloads received from the gateway.
+///
+/// [Discord docs](https://discord.com/developers/docs/topics/gateway#compression).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TransportCompression {
+    /// Payloads are not compressed.
+    None,
+    /// Large payloads are compressed individually, as requested when identifying.
+    #[default]
+    Payload,
+    /// The whole connection is compressed as a single zlib stream, requested through the
+    /// `compress=zlib-stream` query parameter of the gateway URL.
+    ///
+    /// This uses considerably less bandwidth than [`Self::Payload`], especially for the burst of
+    /// `GUILD_CREATE` events received when starting up.
+    ZlibStream,
+}
+
+/// A function called with every payload a shard sends to the gateway, except heartbeats, for
+/// debugging.
+///
+/// Tokens in identify and resume payloads are replaced with `[redacted]` before the hook sees
+/// them. Messages sent through [`ShardMessenger::websocket_message`] are not passed to the hook.
+///
+/// [`ShardMessenger::websocket_message`]: super::ShardMessenger::websocket_message
+pub type OutgoingPayloadHook = fn(&ShardId, &Value);
+
+impl TransportCompression {
+    /// Returns the query string of the gateway URL for this compression.
+    pub(crate) fn query(self) -> String {
+        match self {
+            Self::ZlibStream => format!("v={}&compress=zlib-stream", constants::GATEWAY_VERSION),
+            Self::None | Self::Payload => format!("v={}", constants::GATEWAY_VERSION),
+        }
+    }
+}
+
+/// The suffix of every message of a zlib stream, ending a `Z_SYNC_FLUSH` block.
+#[cfg(feature = "client")]
+const ZLIB_SUFFIX: [u8; 4] = [0x00, 0x00, 0xFF, 0xFF];
+
+/// Inflates the messages of a `zlib-stream` connection, which all share a single zlib context.
+#[cfg_attr(not(feature = "client"), allow(dead_code))]
+struct Inflater {
+    decompress: Decompress,
+    compressed: Vec<u8>,
+}
+
+impl Inflater {
+    fn new() -> Self {
+        Self {
+            decompress: Decompress::new(true),
+            compressed: Vec::new(),
+        }
+    }
+
+    /// Buffers a binary frame, returning the decompressed message once its last frame has been
+    /// received.
+    #[cfg(feature = "client")]
+    fn push(&mut self, frame: &[u8]) -> Result<Option<String>> {
+        self.compressed.extend_from_slice(frame);
+        if !self.compressed.ends_with(&ZLIB_SUFFIX) {
+            return Ok(None);
+        }
+
+        let start = self.decompress.total_in();
+        let mut decompressed = Vec::with_capacity(self.compressed.len() * DECOMPRESSION_MULTIPLIER);
+        loop {
+            // Make sure there is always room left, so that a full buffer means more output.
+            if decompressed.len() == decompressed.capacity() {
+                decompressed.reserve(self.compressed.len());
+            }
+
+            let consumed = (self.decompress.total_in() - start) as usize;
+            let status = self
+                .decompress
+                .decompress_vec(
+                    &self.compressed[consumed..],
+                    &mut decompressed,
+                    FlushDecompress::Sync,
+                )
+                .map_err(|why| {
+                    warn!("Err decompressing zlib stream: {why:?}");
+                    std::io::Error::new(std::io::ErrorKind::InvalidData, why)
+                })?;
+
+            let consumed = (self.decompress.total_in() - start) as usize;
+            let drained = decompressed.len() < decompressed.capacity();
+            if status != Status::Ok || (consumed == self.compressed.len() && drained) {
+                break;
+            }
+        }
+
+        debug!(
+            "Decompressed zlib stream message from {} to {} bytes",
+            self.compressed.len(),
+            decompressed.len()
+        );
+        self.compressed.clear();
+
+        let decompressed = String::from_utf8(decompressed).map_err(|why| {
+            std::io::Error::new(std::io::ErrorKind::InvalidData, why.utf8_error())
+        })?;
+        Ok(Some(decompressed))
+    }
+}
+
+/// Decompresses a payload compressed on its own.
+#[cfg(feature = "client")]
+fn inflate_payload(bytes: &[u8]) -> Result<String> {
+    let mut decompressed = String::with_capacity(bytes.len() * DECOMPRESSION_MULTIPLIER);
+
+    ZlibDecoder::new(bytes).read_to_string(&mut decompressed).map_err(|why| {
+        warn!("Err decompressing bytes: {why:?}");
+        debug!("Failing bytes: {bytes:?}");
+
+        why
+    })?;
+    debug!("Decompressed payload from {} to {} bytes", bytes.len(), decompressed.len());
+
+    Ok(decompressed)
+}
+
+pub struct WsClient {
+    stream: WebSocketStream<MaybeTlsStream<TcpStream>>,
+    compression: TransportCompression,
+    inflater: Option<Inflater>,
+    outgoing_payload_hook: Option<(ShardId, OutgoingPayloadHook)>,
+}
+
+/// A payload received by [`WsClient::recv_json`].
+#[cfg(feature = "client")]
+#[derive(Debug)]
+pub(crate) enum ReceivedPayload {
+    /// A fully deserialized gateway event.
+    Event(GatewayEvent),
+    /// A dispatch that was dropped without deserializing it, with its sequence number.
+    Dropped(u64),
+}
+
+/// The opcode, event name and sequence number of a gateway payload, without its data.
+#[cfg(feature = "client")]
+#[derive(Deserialize)]
+struct PayloadHeader {
+    op: Option<u8>,
+    t: Option<String>,
+    s: Option<u64>,
+}
+
+/// A dispatch payload with its data left as a value.
+#[cfg(feature = "client")]
+#[derive(Deserialize)]
+struct RawDispatch {
+    op: u8,
+    t: String,
+    s: u64,
+    d: Option<Value>,
+}
+
+/// The maximum number of characters of a payload included in a [`GatewayError::Deserialization`].
+#[cfg(feature = "client")]
+const SNIPPET_LENGTH: usize = 256;
+
+#[cfg(feature = "client")]
+fn parse_payload(
+    payload: &str,
+    drop_typing_events: bool,
+    strict: bool,
+    error_snippets: bool,
+) -> Result<ReceivedPayload> {
+    let why = match parse_payload_inner(payload, drop_typing_events, strict) {
+        Ok(payload) => return Ok(payload),
+        Err(why) => deserialization_error(payload, why, error_snippets),
+    };
+
+    // Dispatches are still passed on as unknown events, so that the sequence number advances and
+    // raw event handlers receive them.
+    match from_str::<RawDispatch>(payload) {
+        Ok(RawDispatch {
+            op: 0,
+            t,
+            s,
+            d,
+        }) => {
+            warn!("Passing on an event which failed to deserialize as unknown: {why}");
+
+            Ok(ReceivedPayload::Event(GatewayEvent::Dispatch(
+                s,
+                Event::Unknown(UnknownEvent {
+                    kind: t,
+                    value: d.unwrap_or(crate::json::NULL),
+                }),
+            )))
+        },
+        _ => Err(why),
+    }
+}
+
+#[cfg(feature = "client")]
+fn parse_payload_inner(
+    payload: &str,
+    drop_typing_events: bool,
+    strict: bool,
+) -> Result<ReceivedPayload> {
+    // Only the event name and sequence number are deserialized, which skips allocating the event
+    // data. The substring check avoids even that for all other events.
+    if drop_typing_events && payload.contains("\"TYPING_START\"") {
+        let header: PayloadHeader = from_str(payload)?;
+        if let (Some("TYPING_START"), Some(seq)) = (header.t.as_deref(), header.s) {
+            return Ok(ReceivedPayload::Dropped(seq));
+        }
+    }
+
+    match from_str(payload) {
+        Ok(event) => Ok(ReceivedPayload::Event(event)),
+        Err(why) if strict => Err(why),
+        Err(why) => match lenient::recover(from_str(payload)?) {
+            Some((event, path)) => {
+                warn!("Removed field `{path}` to deserialize event: {why:?}");
+                Ok(ReceivedPayload::Event(event))
+            },
+            None => Err(why),
+        },
+    }
+}
+
+/// Wraps a JSON error into a [`GatewayError::Deserialization`] naming the event of the payload,
+/// and including the start of the payload if `include_snippet` is set.
+#[cfg(feature = "client")]
+fn deserialization_error(payload: &str, why: Error, include_snippet: bool) -> Error {
+    let Error::Json(source) = why else {
+        return why;
+    };
+
+    let event = match from_str::<PayloadHeader>(payload) {
+        Ok(PayloadHeader {
+            t: Some(name), ..
+        }) => name,
+        Ok(PayloadHeader {
+            op: Some(op), ..
+        }) => format!("opcode {op}"),
+        _ => String::from("unknown"),
+    };
+    let snippet = include_snippet.then(|| {
+        let (prefix, truncated) = match payload.char_indices().nth(SNIPPET_LENGTH) {
+            Some((end, _)) => (&payload[..end], true),
+            None => (payload, false),
+        };
+
+        let mut snippet = prefix.escape_debug().to_string();
+        if truncated {
+            snippet.push_str("...");
+        }
+        snippet
+    });
+
+    Error::Gateway(GatewayError::Deserialization {
+        event,
+        source: Arc::new(source),
+        snippet,
+    })
+}
 
 #[cfg(feature = "client")]
 const TIMEOUT: Duration = Duration::from_millis(500);
@@ -101,20 +379,60 @@ const TIMEOUT: Duration = Duration::from_millis(500);
 const DECOMPRESSION_MULTIPLIER: usize = 3;
 
 impl WsClient {
-    pub(crate) async fn connect(url: Url) -> Result<Self> {
+    pub(crate) async fn connect(
+        url: Url,
+        proxy: Option<&GatewayProxy>,
+        compression: TransportCompression,
+    ) -> Result<Self> {
         let config = WebSocketConfig {
             max_message_size: None,
             max_frame_size: None,
             ..Default::default()
         };
-        let (stream, _) = connect_async_with_config(url, Some(config), false).await?;
 
-        Ok(Self(stream))
+        let (stream, _) = match proxy {
+            Some(proxy) => {
+                let host = url.host_str().unwrap_or_default().to_string();
+                let port = url.port_or_known_default().unwrap_or(443);
+                let tunnel = proxy.connect(&host, port).await?;
+
+                client_async_tls_with_config(url, tunnel, Some(config), None).await?
+            },
+            None => connect_async_with_config(url, Some(config), false).await?,
+        };
+
+        Ok(Self {
+            stream,
+            compression,
+            inflater: (compression == TransportCompression::ZlibStream).then(Inflater::new),
+            outgoing_payload_hook: None,
+        })
+    }
+
+    /// Sets the hook called with the payloads sent by the given shard, see
+    /// [`OutgoingPayloadHook`].
+    pub(crate) fn set_outgoing_payload_hook(
+        &mut self,
+        shard_id: ShardId,
+        hook: Option<OutgoingPayloadHook>,
+    ) {
+        self.outgoing_payload_hook = hook.map(|hook| (shard_id, hook));
     }
 
+    /// Receives the next payload from the gateway.
+    ///
+    /// If `drop_typing_events` is set, `TYPING_START` dispatches are not deserialized, and are
+    /// returned as [`ReceivedPayload::Dropped`] instead. If `strict` is not set, events that fail
+    /// to deserialize are deserialized again without the offending field. If `error_snippets` is
+    /// set, errors for payloads that fail to deserialize include the start of the payload.
     #[cfg(feature = "client")]
-    pub(crate) async fn recv_json(&mut self) -> Result<Option<GatewayEvent>> {
-        let message = match timeout(TIMEOUT, self.0.next()).await {
+    pub(crate) async fn recv_json(
+        &mut self,
+        drop_typing_events: bool,
+        strict: bool,
+        error_snippets: bool,
+    ) -> Result<Option<ReceivedPayload>> {
+        let message = match timeout(TIMEOUT, self.stream.next()).await {
             Ok(Some(Ok(msg))) => msg,
             Ok(Some(Err(e))) => return Err(e.into()),
             Ok(None) | Err(_) => return Ok(None),
@@ -122,28 +440,33 @@ impl WsClient {
 
         let value = match message {
             Message::Binary(bytes) => {
-                let mut decompressed =
-                    String::with_capacity(bytes.len() * DECOMPRESSION_MULTIPLIER);
-
-                ZlibDecoder::new(&bytes[..]).read_to_string(&mut decompressed).map_err(|why| {
-                    warn!("Err decompressing bytes: {why:?}");
-                    debug!("Failing bytes: {bytes:?}");
-
-                    why
-                })?;
-
-                from_str(&decompressed).map_err(|why| {
-                    warn!("Err deserializing bytes: {why:?}");
-                    debug!("Failing bytes: {bytes:?}");
-
-                    why
-                })?
+                let decompressed = match &mut self.inflater {
+                    Some(inflater) => match inflater.push(&bytes)? {
+                        Some(decompressed) => decompressed,
+                        None => return Ok(None),
+                    },
+                    None => inflate_payload(&bytes)?,
+                };
+
+                parse_payload(&decompressed, drop_typing_events, strict, error_snippets).map_err(
+                    |why| {
+                        warn!("Err deserializing bytes: {why:?}");
+                        debug!("Failing bytes: {bytes:?}");
+
+                        why
+                    },
+                )?
+            },
+            Message::Text(payload) => {
+                parse_payload(&payload, drop_typing_events, strict, error_snippets).map_err(
+                    |why| {
+                        warn!("Err deserializing text: {why:?}");
+                        debug!("Failing text: {payload}");
+
+                        why
+                    },
+                )?
             },
-            Message::Text(payload) => from_str(&payload).map_err(|why| {
-                warn!("Err deserializing text: {why:?}; text: {payload}");
-
-                why
-            })?,
             Message::Close(Some(frame)) => {
                 return Err(Error::Gateway(GatewayError::Closed(Some(frame))));
             },
@@ -153,30 +476,36 @@ impl WsClient {
         Ok(Some(value))
     }
 
-    pub(crate) async fn send_json(&mut self, value: &impl serde::Serialize) -> Result<()> {
+    async fn send_json(&mut self, value: &WebSocketMessage<'_>) -> Result<()> {
+        if let Some((shard_id, hook)) = self.outgoing_payload_hook {
+            if value.op != Opcode::Heartbeat {
+                hook(&shard_id, &redacted_payload(value)?);
+            }
+        }
+
         let message = to_string(value).map(Message::Text)?;
 
-        self.0.send(message).await?;
+        self.stream.send(message).await?;
         Ok(())
     }
 
     /// Delegate to `StreamExt::next`
     #[cfg(feature = "client")]
     pub(crate) async fn next(&mut self) -> Option<std::result::Result<Message, WsError>> {
-        self.0.next().await
+        self.stream.next().await
     }
 
     /// Delegate to `SinkExt::send`
     #[cfg(feature = "client")]
     pub(crate) async fn send(&mut self, message: Message) -> Result<()> {
-        self.0.send(message).await?;
+        self.stream.send(message).await?;
         Ok(())
     }
 
     /// Delegate to `WebSocketStream::close`
     #[cfg(feature = "client")]
     pub(crate) async fn close(&mut self, msg: Option<CloseFrame<'_>>) -> Result<()> {
-        self.0.close(msg).await?;
+        self.stream.close(msg).await?;
         Ok(())
     }
 
@@ -231,7 +560,21 @@ impl WsClient {
         intents: GatewayIntents,
         presence: &PresenceData,
     ) -> Result<()> {
-        let activities: Vec<_> = presence.activity.iter().collect();
+        let properties = IdentifyProperties::default();
+        self.send_identify_with_properties(shard, token, intents, &properties, presence).await
+    }
+
+    /// Like [`Self::send_identify`], but sends the given connection properties instead of the
+    /// default ones.
+    #[instrument(skip(self, token))]
+    pub async fn send_identify_with_properties(
+        &mut self,
+        shard: &ShardInfo,
+        token: &str,
+        intents: GatewayIntents,
+        properties: &IdentifyProperties,
+        presence: &PresenceData,
+    ) -> Result<()> {
         let now = SystemTime::now();
 
         debug!("[{:?}] Identifying", shard);
@@ -242,18 +585,14 @@ impl WsClient {
                 token,
                 shard,
                 intents,
-                compress: true,
+                compress: self.compression == TransportCompression::Payload,
                 large_threshold: constants::LARGE_THRESHOLD,
-                properties: IdentifyProperties {
-                    browser: "serenity",
-                    device: "serenity",
-                    os: consts::OS,
-                },
+                properties,
                 presence: PresenceUpdateMessage {
                     afk: false,
                     since: now,
                     status: presence.status.name(),
-                    activities: &activities,
+                    activities: &presence.activities,
                 },
             },
         };
@@ -267,7 +606,6 @@ impl WsClient {
         shard_info: &ShardInfo,
         presence: &PresenceData,
     ) -> Result<()> {
-        let activities: Vec<_> = presence.activity.iter().collect();
         let now = SystemTime::now();
 
         debug!("[{:?}] Sending presence update", shard_info);
@@ -278,12 +616,29 @@ impl WsClient {
                 afk: false,
                 since: now,
                 status: presence.status.name(),
-                activities: &activities,
+                activities: &presence.activities,
             }),
         })
         .await
     }
 
+    #[instrument(skip(self))]
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn send_voice_state_update(
+        &mut self,
+        shard_info: &ShardInfo,
+        guild_id: GuildId,
+        channel_id: Option<ChannelId>,
+        self_mute: bool,
+        self_deaf: bool,
+        self_video: bool,
+    ) -> Result<()> {
+        debug!("[{:?}] Sending voice state update", shard_info);
+
+        self.send_json(&voice_state_update(guild_id, channel_id, self_mute, self_deaf, self_video))
+            .await
+    }
+
     #[instrument(skip(self, token))]
     pub async fn send_resume(
         &mut self,
@@ -305,3 +660,175 @@ impl WsClient {
         .await
     }
 }
+
+/// Serializes a payload for an [`OutgoingPayloadHook`], replacing any token.
+fn redacted_payload(message: &WebSocketMessage<'_>) -> Result<Value> {
+    let mut value = to_value(message)?;
+    let data = value.as_object_mut().and_then(|value| value.get_mut("d"));
+    if let Some(token) = data.and_then(|data| data.as_object_mut()?.get_mut("token")) {
+        *token = Value::from("[redacted]");
+    }
+
+    Ok(value)
+}
+
+fn voice_state_update(
+    guild_id: GuildId,
+    channel_id: Option<ChannelId>,
+    self_mute: bool,
+    self_deaf: bool,
+    self_video: bool,
+) -> WebSocketMessage<'static> {
+    WebSocketMessage {
+        op: Opcode::VoiceStateUpdate,
+        d: WebSocketMessageData::VoiceStateUpdate(VoiceStateUpdateMessage {
+            guild_id,
+            channel_id,
+            self_mute,
+            self_deaf,
+            self_video,
+        }),
+    }
+}
+
+#[cfg(all(test, feature = "client"))]
+mod tests {
+    use super::*;
+
+    const TYPING_START: &str = concat!(
+        r#"{"t":"TYPING_START","s":42,"op":0,"#,
+        r#""d":{"channel_id":"1","user_id":"2","timestamp":1700000000}}"#
+    );
+
+    #[test]
+    fn drop_typing_events() {
+        let payload = parse_payload(TYPING_START, true, true, false).unwrap();
+        assert!(matches!(payload, ReceivedPayload::Dropped(42)));
+
+        let payload = parse_payload(TYPING_START, false, true, false).unwrap();
+        assert!(matches!(payload, ReceivedPayload::Event(GatewayEvent::Dispatch(42, _))));
+
+        // Other events mentioning the event name are still deserialized.
+        let heartbeat = r#"{"t":null,"s":7,"op":1,"d":"TYPING_START"}"#;
+        let payload = parse_payload(heartbeat, true, true, false).unwrap();
+        assert!(matches!(payload, ReceivedPayload::Event(GatewayEvent::Heartbeat(7))));
+    }
+
+    #[test]
+    fn deserialization_error_context() {
+        // Dispatches without a sequence number fail to deserialize.
+        let malformed = r#"{"t":"MESSAGE_CREATE","op":0,"d":{"content":"hi"}}"#;
+
+        let Err(Error::Gateway(GatewayError::Deserialization {
+            event,
+            snippet,
+            ..
+        })) = parse_payload(malformed, false, true, false)
+        else {
+            panic!("expected a deserialization error");
+        };
+        assert_eq!(event, "MESSAGE_CREATE");
+        assert_eq!(snippet, None);
+
+        let Err(Error::Gateway(GatewayError::Deserialization {
+            snippet, ..
+        })) = parse_payload(malformed, false, true, true)
+        else {
+            panic!("expected a deserialization error");
+        };
+        assert_eq!(snippet.unwrap(), malformed.escape_debug().to_string());
+
+        let long = format!(r#"{{"op":10,"d":"{}"}}"#, "a".repeat(SNIPPET_LENGTH));
+        let Err(Error::Gateway(GatewayError::Deserialization {
+            event,
+            snippet,
+            ..
+        })) = parse_payload(&long, false, true, true)
+        else {
+            panic!("expected a deserialization error");
+        };
+        assert_eq!(event, "opcode 10");
+        assert_eq!(snippet.unwrap(), format!("{}...", long[..SNIPPET_LENGTH].escape_debug()));
+    }
+
+    #[test]
+    fn deserialization_error_event_data() {
+        // Known events with invalid data are passed on as unknown, keeping their sequence number.
+        let malformed = r#"{"t":"CHANNEL_DELETE","s":5,"op":0,"d":{"id":"not a snowflake"}}"#;
+
+        for strict in [true, false] {
+            let payload = parse_payload(malformed, false, strict, true).unwrap();
+            let ReceivedPayload::Event(GatewayEvent::Dispatch(5, Event::Unknown(event))) = payload
+            else {
+                panic!("expected an unknown dispatch");
+            };
+            assert_eq!(event.kind, "CHANNEL_DELETE");
+            assert_eq!(event.value["id"], "not a snowflake");
+        }
+
+        let unknown = r#"{"t":"SOMETHING_NEW","s":5,"op":0,"d":{"id":"not a snowflake"}}"#;
+        let payload = parse_payload(unknown, false, true, true).unwrap();
+        assert!(matches!(
+            payload,
+            ReceivedPayload::Event(GatewayEvent::Dispatch(5, Event::Unknown(_)))
+        ));
+    }
+
+    #[test]
+    fn voice_state_update_payload() {
+        use crate::json::{json, to_value};
+
+        let msg = voice_state_update(GuildId::new(1), Some(ChannelId::new(2)), false, true, true);
+        assert_eq!(
+            to_value(msg).unwrap(),
+            json!({
+                "op": 4,
+                "d": {
+                    "guild_id": "1",
+                    "channel_id": "2",
+                    "self_mute": false,
+                    "self_deaf": true,
+                    "self_video": true,
+                },
+            })
+        );
+
+        let msg = voice_state_update(GuildId::new(1), None, false, false, false);
+        assert_eq!(to_value(msg).unwrap()["d"]["channel_id"], json!(null));
+    }
+
+    #[test]
+    fn transport_compression_query() {
+        let version = constants::GATEWAY_VERSION;
+        assert_eq!(TransportCompression::default(), TransportCompression::Payload);
+        assert_eq!(TransportCompression::Payload.query(), format!("v={version}"));
+        assert_eq!(TransportCompression::None.query(), format!("v={version}"));
+        assert_eq!(
+            TransportCompression::ZlibStream.query(),
+            format!("v={version}&compress=zlib-stream")
+        );
+    }
+
+    #[test]
+    fn zlib_stream() {
+        use flate2::{Compress, Compression, FlushCompress};
+
+        let messages = [r#"{"op":11,"d":null}"#, r#"{"op":1,"d":42}"#, TYPING_START];
+
+        // Every message is flushed with a sync flush, sharing the compression context.
+        let mut compress = Compress::new(Compression::default(), true);
+        let mut inflater = Inflater::new();
+        for message in messages {
+            let mut compressed = Vec::with_capacity(message.len() + 64);
+            compress
+                .compress_vec(message.as_bytes(), &mut compressed, FlushCompress::Sync)
+                .unwrap();
+            assert!(compressed.ends_with(&ZLIB_SUFFIX));
+
+            // Messages may be split across several frames.
+            let (first, second) = compressed.split_at(compressed.len() / 2);
+            assert_eq!(inflater.push(first).unwrap(), None);
+            assert_eq!(inflater.push(second).unwrap().as_deref(), Some(message));
+        }
+    }
+}
@@ -1,9 +1,12 @@
 #![allow(clippy::missing_errors_doc)]
 
 use std::borrow::Cow;
+use std::collections::HashSet;
+use std::mem::Discriminant;
 use std::num::NonZeroU64;
 use std::sync::atomic::{AtomicU64, Ordering};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
 use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
 use reqwest::header::{HeaderMap as Headers, HeaderValue};
@@ -12,19 +15,25 @@ use reqwest::Url;
 use reqwest::{Client, ClientBuilder, Response as ReqwestResponse, StatusCode};
 use secrecy::{ExposeSecret, SecretString};
 use serde::de::DeserializeOwned;
+use tokio::io::{AsyncWrite, AsyncWriteExt};
 use tracing::{debug, instrument, warn};
 
 use super::multipart::{Multipart, MultipartUpload};
 use super::ratelimiting::Ratelimiter;
 use super::request::Request;
 use super::routing::Route;
+use super::transport::Transport;
 use super::typing::Typing;
 use super::{
+    DownloadedFile,
     ErrorResponse,
     GuildPagination,
     HttpError,
     LightMethod,
     MessagePagination,
+    ResponseHook,
+    ResponseInfo,
+    RetryPolicy,
     UserPagination,
 };
 use crate::builder::{CreateAllowedMentions, CreateAttachment};
@@ -52,12 +61,19 @@ use crate::model::prelude::*;
 #[must_use]
 pub struct HttpBuilder {
     client: Option<Client>,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    gzip: bool,
+    compress_requests: Option<usize>,
     ratelimiter: Option<Ratelimiter>,
     ratelimiter_disabled: bool,
     token: SecretString,
     proxy: Option<String>,
     application_id: Option<ApplicationId>,
     default_allowed_mentions: Option<CreateAllowedMentions>,
+    retry_policy: Option<RetryPolicy>,
+    transport: Option<Arc<dyn Transport>>,
+    on_response: Option<ResponseHook>,
 }
 
 impl HttpBuilder {
@@ -66,12 +82,19 @@ impl HttpBuilder {
     pub fn new(token: impl AsRef<str>) -> Self {
         Self {
             client: None,
+            timeout: None,
+            connect_timeout: None,
+            gzip: true,
+            compress_requests: None,
             ratelimiter: None,
             ratelimiter_disabled: false,
             token: SecretString::new(parse_token(token)),
             proxy: None,
             application_id: None,
             default_allowed_mentions: None,
+            retry_policy: None,
+            transport: None,
+            on_response: None,
         }
     }
 
@@ -89,11 +112,61 @@ impl HttpBuilder {
     }
 
     /// Sets the [`reqwest::Client`]. If one isn't provided, a default one will be used.
+    ///
+    /// The client is used for every request, including [`Http::download`], and is wrapped by the
+    /// default ratelimiter. This allows configuring e.g. TCP keepalive or TLS settings, or sharing
+    /// a connection pool with other HTTP traffic.
+    ///
+    /// **Note**: [`Self::timeout`] and [`Self::connect_timeout`] only apply to the default client,
+    /// so they must be configured on the provided client instead.
     pub fn client(mut self, client: Client) -> Self {
         self.client = Some(client);
         self
     }
 
+    /// Sets the total timeout of each request made by the default [`reqwest::Client`], from
+    /// connecting until the response body has been read.
+    ///
+    /// By default, requests do not time out.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the timeout for connecting to Discord (or the [proxy]) used by the default
+    /// [`reqwest::Client`].
+    ///
+    /// By default, connecting does not time out.
+    ///
+    /// [proxy]: Self::proxy
+    pub fn connect_timeout(mut self, connect_timeout: Duration) -> Self {
+        self.connect_timeout = Some(connect_timeout);
+        self
+    }
+
+    /// Sets whether the default [`reqwest::Client`] asks for gzip-compressed responses and
+    /// transparently decompresses them.
+    ///
+    /// By default, this is enabled.
+    pub fn gzip(mut self, gzip: bool) -> Self {
+        self.gzip = gzip;
+        self
+    }
+
+    /// Compresses JSON request bodies of at least `threshold` bytes with gzip before sending
+    /// them, such as when bulk overwriting application commands.
+    ///
+    /// Not every endpoint accepts compressed bodies. If Discord (or the [proxy]) responds with
+    /// `415 Unsupported Media Type`, the request is sent again uncompressed.
+    ///
+    /// By default, request bodies are not compressed.
+    ///
+    /// [proxy]: Self::proxy
+    pub fn compress_requests(mut self, threshold: usize) -> Self {
+        self.compress_requests = Some(threshold);
+        self
+    }
+
     /// Sets the ratelimiter to be used. If one isn't provided, a default one will be used.
     pub fn ratelimiter(mut self, ratelimiter: Ratelimiter) -> Self {
         self.ratelimiter = Some(ratelimiter);
@@ -139,19 +212,68 @@ impl HttpBuilder {
         self
     }
 
+    /// Sets the [`RetryPolicy`] for requests which fail due to server or connection errors.
+    ///
+    /// By default, failed requests are not retried.
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
+    /// Sets the [`Transport`] used to send requests instead of the [`reqwest::Client`].
+    ///
+    /// **Note**: The ratelimiter and [`Self::proxy`] are bypassed when a transport is set.
+    pub fn transport(mut self, transport: Arc<dyn Transport>) -> Self {
+        self.transport = Some(transport);
+        self
+    }
+
+    /// Sets a hook which is called with the status and ratelimit headers of every response
+    /// received from Discord, including unsuccessful ones.
+    ///
+    /// This allows adapting to the state of ratelimit buckets, e.g. to lower the concurrency of a
+    /// bulk export. The hook is called before the body is read, and should return quickly.
+    ///
+    /// ```rust
+    /// # use serenity::http::HttpBuilder;
+    /// let http = HttpBuilder::new("token")
+    ///     .on_response(|info| {
+    ///         if info.ratelimit.remaining == Some(0) {
+    ///             let path = info.route.path();
+    ///             println!("{path} is exhausted for {:?}", info.ratelimit.reset_after);
+    ///         }
+    ///     })
+    ///     .build();
+    /// ```
+    pub fn on_response(mut self, hook: impl Fn(&ResponseInfo<'_>) + Send + Sync + 'static) -> Self {
+        self.on_response = Some(ResponseHook(Arc::new(hook)));
+        self
+    }
+
     /// Use the given configuration to build the `Http` client.
     #[must_use]
     pub fn build(self) -> Http {
         let application_id = AtomicU64::new(self.application_id.map_or(0, ApplicationId::get));
 
         let client = self.client.unwrap_or_else(|| {
-            let builder = configure_client_backend(Client::builder());
+            let mut builder = configure_client_backend(Client::builder()).gzip(self.gzip);
+            if let Some(timeout) = self.timeout {
+                builder = builder.timeout(timeout);
+            }
+            if let Some(connect_timeout) = self.connect_timeout {
+                builder = builder.connect_timeout(connect_timeout);
+            }
             builder.build().expect("Cannot build reqwest::Client")
         });
 
         let ratelimiter = (!self.ratelimiter_disabled).then(|| {
-            self.ratelimiter
-                .unwrap_or_else(|| Ratelimiter::new(client.clone(), self.token.expose_secret()))
+            let mut ratelimiter = self
+                .ratelimiter
+                .unwrap_or_else(|| Ratelimiter::new(client.clone(), self.token.expose_secret()));
+            if let Some(hook) = &self.on_response {
+                ratelimiter.set_response_hook(hook.clone());
+            }
+            ratelimiter
         });
 
         Http {
@@ -160,21 +282,70 @@ impl HttpBuilder {
             proxy: self.proxy,
             token: self.token,
             application_id,
+            compress_requests: self.compress_requests,
             default_allowed_mentions: self.default_allowed_mentions,
+            retry_policy: self.retry_policy,
+            transport: self.transport,
+            on_response: self.on_response,
+            gzip_rejected: Mutex::default(),
         }
     }
 }
 
+/// Returns the content type and file name of a download.
+fn download_metadata(response: &ReqwestResponse) -> (Option<String>, Option<String>) {
+    let content_type = response
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .map(ToString::to_string);
+    let filename = response
+        .url()
+        .path_segments()
+        .and_then(Iterator::last)
+        .filter(|segment| !segment.is_empty())
+        .map(ToString::to_string);
+    (content_type, filename)
+}
+
+/// Whether the media type of a `Content-Type` header matches `expected`, which may use `*` as its
+/// subtype.
+fn content_type_matches(expected: &str, received: &str) -> bool {
+    let received = received.split(';').next().unwrap_or_default().trim();
+    match expected.strip_suffix("/*") {
+        Some(kind) => received.split_once('/').is_some_and(|(k, _)| k.eq_ignore_ascii_case(kind)),
+        None => received.eq_ignore_ascii_case(expected),
+    }
+}
+
 fn parse_token(token: impl AsRef<str>) -> String {
     let token = token.as_ref().trim();
 
-    if token.starts_with("Bot ") || token.starts_with("Bearer ") {
+    if token.is_empty() {
+        // Token-less clients may only use the endpoints which don't require authentication.
+        String::new()
+    } else if token.starts_with("Bot ") || token.starts_with("Bearer ") {
         token.to_string()
     } else {
         format!("Bot {token}")
     }
 }
 
+fn invite_params(
+    member_counts: bool,
+    expiration: bool,
+    event_id: Option<ScheduledEventId>,
+) -> Vec<(&'static str, String)> {
+    let mut params = vec![
+        ("with_counts", member_counts.to_string()),
+        ("with_expiration", expiration.to_string()),
+    ];
+    if let Some(event_id) = event_id {
+        params.push(("guild_scheduled_event_id", event_id.to_string()));
+    }
+    params
+}
+
 fn reason_into_header(reason: &str) -> Headers {
     let mut headers = Headers::new();
 
@@ -199,7 +370,13 @@ pub struct Http {
     pub proxy: Option<String>,
     token: SecretString,
     application_id: AtomicU64,
+    compress_requests: Option<usize>,
     pub default_allowed_mentions: Option<CreateAllowedMentions>,
+    pub retry_policy: Option<RetryPolicy>,
+    transport: Option<Arc<dyn Transport>>,
+    on_response: Option<ResponseHook>,
+    /// The routes which rejected a compressed body, which are sent uncompressed from then on.
+    gzip_rejected: Mutex<HashSet<Discriminant<Route<'static>>>>,
 }
 
 impl Http {
@@ -247,6 +424,7 @@ impl Http {
                     user_id,
                 },
                 params: None,
+                retry_non_idempotent: false,
             })
             .await?;
 
@@ -280,6 +458,7 @@ impl Http {
                 user_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -312,6 +491,7 @@ impl Http {
                 user_id,
             },
             params: Some(vec![("delete_message_seconds", delete_message_seconds.to_string())]),
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -334,6 +514,7 @@ impl Http {
                 guild_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -355,6 +536,7 @@ impl Http {
                 channel_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -384,6 +566,7 @@ impl Http {
                 guild_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -401,6 +584,7 @@ impl Http {
             method: LightMethod::Post,
             route: Route::StageInstances,
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -425,6 +609,7 @@ impl Http {
                 message_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -447,6 +632,7 @@ impl Http {
                 channel_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -482,6 +668,7 @@ impl Http {
                 channel_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -508,6 +695,7 @@ impl Http {
                 guild_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -518,15 +706,24 @@ impl Http {
     ///
     /// [`Context::create_application_emoji`]: crate::client::Context::create_application_emoji
     pub async fn create_application_emoji(&self, map: &impl serde::Serialize) -> Result<Emoji> {
+        self.create_emoji_of_application(self.try_application_id()?, map).await
+    }
+
+    pub(crate) async fn create_emoji_of_application(
+        &self,
+        application_id: ApplicationId,
+        map: &impl serde::Serialize,
+    ) -> Result<Emoji> {
         self.fire(Request {
             body: Some(to_vec(map)?),
             multipart: None,
             headers: None,
             method: LightMethod::Post,
             route: Route::Emojis {
-                application_id: self.try_application_id()?,
+                application_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -550,6 +747,7 @@ impl Http {
                 token: interaction_token,
             },
             params: None,
+            retry_non_idempotent: false,
         };
 
         if files.is_empty() {
@@ -585,6 +783,7 @@ impl Http {
                 application_id: self.try_application_id()?,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -603,6 +802,7 @@ impl Http {
                 application_id: self.try_application_id()?,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -623,6 +823,7 @@ impl Http {
                 guild_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -668,6 +869,52 @@ impl Http {
             method: LightMethod::Post,
             route: Route::Guilds,
             params: None,
+            retry_non_idempotent: false,
+        })
+        .await
+    }
+
+    /// Creates a new guild from a [`GuildTemplate`], with the channels, roles and settings of the
+    /// template.
+    ///
+    /// **Note**: This endpoint can only be used by bots in less than 10 guilds.
+    pub async fn create_guild_from_template(
+        &self,
+        code: &str,
+        map: &impl serde::Serialize,
+    ) -> Result<PartialGuild> {
+        self.fire(Request {
+            body: Some(to_vec(map)?),
+            multipart: None,
+            headers: None,
+            method: LightMethod::Post,
+            route: Route::GuildsTemplate {
+                code,
+            },
+            params: None,
+            retry_non_idempotent: false,
+        })
+        .await
+    }
+
+    /// Creates a [`GuildTemplate`] from the current state of a guild.
+    ///
+    /// See [`GuildId::create_template`] for permissions requirements.
+    pub async fn create_guild_template(
+        &self,
+        guild_id: GuildId,
+        map: &impl serde::Serialize,
+    ) -> Result<GuildTemplate> {
+        self.fire(Request {
+            body: Some(to_vec(map)?),
+            multipart: None,
+            headers: None,
+            method: LightMethod::Post,
+            route: Route::GuildTemplates {
+                guild_id,
+            },
+            params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -694,6 +941,7 @@ impl Http {
                 guild_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -723,6 +971,7 @@ impl Http {
                 integration_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -750,6 +999,7 @@ impl Http {
                 token: interaction_token,
             },
             params: None,
+            retry_non_idempotent: false,
         };
 
         if files.is_empty() {
@@ -792,6 +1042,7 @@ impl Http {
                 channel_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -816,6 +1067,7 @@ impl Http {
                 target_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -831,6 +1083,7 @@ impl Http {
             method: LightMethod::Post,
             route: Route::UserMeDmChannels,
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -853,6 +1106,7 @@ impl Http {
                 reaction: &reaction_type.as_data(),
             },
             params: Some(vec![("burst", burst.to_string())]),
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -894,6 +1148,7 @@ impl Http {
                     guild_id,
                 },
                 params: None,
+                retry_non_idempotent: false,
             })
             .await?;
 
@@ -927,6 +1182,7 @@ impl Http {
                 guild_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -956,6 +1212,30 @@ impl Http {
                 guild_id,
             },
             params: None,
+            retry_non_idempotent: false,
+        })
+        .await
+    }
+
+    /// Creates a soundboard sound in a guild.
+    ///
+    /// See [`GuildId::create_soundboard_sound`] for permissions requirements.
+    pub async fn create_soundboard_sound(
+        &self,
+        guild_id: GuildId,
+        map: &impl serde::Serialize,
+        audit_log_reason: Option<&str>,
+    ) -> Result<SoundboardSound> {
+        self.fire(Request {
+            body: Some(to_vec(map)?),
+            multipart: None,
+            headers: audit_log_reason.map(reason_into_header),
+            method: LightMethod::Post,
+            route: Route::GuildSoundboardSounds {
+                guild_id,
+            },
+            params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -995,6 +1275,25 @@ impl Http {
                 application_id: self.try_application_id()?,
             },
             params: None,
+            retry_non_idempotent: false,
+        })
+        .await
+    }
+
+    /// Marks a one-time purchase entitlement as consumed, setting its [`Entitlement::consumed`]
+    /// field to `true`. Only applies to entitlements of consumable SKUs.
+    pub async fn consume_entitlement(&self, entitlement_id: EntitlementId) -> Result<()> {
+        self.wind(204, Request {
+            body: None,
+            multipart: None,
+            headers: None,
+            method: LightMethod::Post,
+            route: Route::EntitlementConsume {
+                application_id: self.try_application_id()?,
+                entitlement_id,
+            },
+            params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -1042,6 +1341,7 @@ impl Http {
                 channel_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -1061,6 +1361,7 @@ impl Http {
                 channel_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -1080,6 +1381,7 @@ impl Http {
                 channel_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -1103,22 +1405,32 @@ impl Http {
                 emoji_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
 
     /// Deletes an application emoji.
     pub async fn delete_application_emoji(&self, emoji_id: EmojiId) -> Result<()> {
+        self.delete_emoji_of_application(self.try_application_id()?, emoji_id).await
+    }
+
+    pub(crate) async fn delete_emoji_of_application(
+        &self,
+        application_id: ApplicationId,
+        emoji_id: EmojiId,
+    ) -> Result<()> {
         self.wind(204, Request {
             body: None,
             multipart: None,
             headers: None,
             method: LightMethod::Delete,
             route: Route::Emoji {
-                application_id: self.try_application_id()?,
+                application_id,
                 emoji_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -1140,6 +1452,7 @@ impl Http {
                 message_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -1156,6 +1469,7 @@ impl Http {
                 command_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -1171,6 +1485,7 @@ impl Http {
                 guild_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -1192,6 +1507,7 @@ impl Http {
                 command_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -1213,6 +1529,30 @@ impl Http {
                 integration_id,
             },
             params: None,
+            retry_non_idempotent: false,
+        })
+        .await
+    }
+
+    /// Deletes a guild template, returning the deleted template.
+    ///
+    /// See [`GuildId::delete_template`] for permissions requirements.
+    pub async fn delete_guild_template(
+        &self,
+        guild_id: GuildId,
+        code: &str,
+    ) -> Result<GuildTemplate> {
+        self.fire(Request {
+            body: None,
+            multipart: None,
+            headers: None,
+            method: LightMethod::Delete,
+            route: Route::GuildTemplate {
+                guild_id,
+                code,
+            },
+            params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -1232,6 +1572,7 @@ impl Http {
                 code,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -1253,6 +1594,7 @@ impl Http {
                 message_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -1273,6 +1615,7 @@ impl Http {
                 channel_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -1310,6 +1653,7 @@ impl Http {
                 message_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -1332,6 +1676,7 @@ impl Http {
                 reaction: &reaction_type.as_data(),
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -1351,6 +1696,7 @@ impl Http {
                 token: interaction_token,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -1372,6 +1718,7 @@ impl Http {
                 target_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -1396,6 +1743,7 @@ impl Http {
                 reaction: &reaction_type.as_data(),
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -1418,6 +1766,7 @@ impl Http {
                 reaction: &reaction_type.as_data(),
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -1439,6 +1788,7 @@ impl Http {
                 role_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -1464,6 +1814,7 @@ impl Http {
                 event_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -1487,6 +1838,31 @@ impl Http {
                 sticker_id,
             },
             params: None,
+            retry_non_idempotent: false,
+        })
+        .await
+    }
+
+    /// Deletes a soundboard sound from a guild.
+    ///
+    /// See [`GuildId::delete_soundboard_sound`] for permissions requirements.
+    pub async fn delete_soundboard_sound(
+        &self,
+        guild_id: GuildId,
+        sound_id: SoundId,
+        audit_log_reason: Option<&str>,
+    ) -> Result<()> {
+        self.wind(204, Request {
+            body: None,
+            multipart: None,
+            headers: audit_log_reason.map(reason_into_header),
+            method: LightMethod::Delete,
+            route: Route::GuildSoundboardSound {
+                guild_id,
+                sound_id,
+            },
+            params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -1504,6 +1880,7 @@ impl Http {
                 entitlement_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -1541,6 +1918,7 @@ impl Http {
                 webhook_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -1582,6 +1960,7 @@ impl Http {
                 token,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -1604,6 +1983,7 @@ impl Http {
                 channel_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -1624,6 +2004,7 @@ impl Http {
                 channel_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -1650,6 +2031,7 @@ impl Http {
                 emoji_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -1663,6 +2045,15 @@ impl Http {
         &self,
         emoji_id: EmojiId,
         map: &impl serde::Serialize,
+    ) -> Result<Emoji> {
+        self.edit_emoji_of_application(self.try_application_id()?, emoji_id, map).await
+    }
+
+    pub(crate) async fn edit_emoji_of_application(
+        &self,
+        application_id: ApplicationId,
+        emoji_id: EmojiId,
+        map: &impl serde::Serialize,
     ) -> Result<Emoji> {
         self.fire(Request {
             body: Some(to_vec(map)?),
@@ -1670,10 +2061,11 @@ impl Http {
             headers: None,
             method: LightMethod::Patch,
             route: Route::Emoji {
-                application_id: self.try_application_id()?,
+                application_id,
                 emoji_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -1701,6 +2093,7 @@ impl Http {
                 message_id,
             },
             params: None,
+            retry_non_idempotent: false,
         };
 
         if new_attachments.is_empty() {
@@ -1737,6 +2130,7 @@ impl Http {
                 message_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -1763,6 +2157,7 @@ impl Http {
                 command_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -1785,6 +2180,7 @@ impl Http {
                 guild_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -1813,6 +2209,7 @@ impl Http {
                 command_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -1841,6 +2238,7 @@ impl Http {
                 command_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -1862,6 +2260,7 @@ impl Http {
                 guild_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -1889,6 +2288,7 @@ impl Http {
                 guild_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
         .map(|mfa: GuildMfaLevel| mfa.level)
@@ -1912,6 +2312,31 @@ impl Http {
                 guild_id,
             },
             params: None,
+            retry_non_idempotent: false,
+        })
+        .await
+    }
+
+    /// Edits the name and description of a guild template.
+    ///
+    /// See [`GuildId::edit_template`] for permissions requirements.
+    pub async fn edit_guild_template(
+        &self,
+        guild_id: GuildId,
+        code: &str,
+        map: &impl serde::Serialize,
+    ) -> Result<GuildTemplate> {
+        self.fire(Request {
+            body: Some(to_vec(map)?),
+            multipart: None,
+            headers: None,
+            method: LightMethod::Patch,
+            route: Route::GuildTemplate {
+                guild_id,
+                code,
+            },
+            params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -1934,6 +2359,30 @@ impl Http {
                 guild_id,
             },
             params: None,
+            retry_non_idempotent: false,
+        })
+        .await
+    }
+
+    /// Edits the onboarding flow of a guild.
+    pub async fn edit_guild_onboarding(
+        &self,
+        guild_id: GuildId,
+        map: &impl serde::Serialize,
+        audit_log_reason: Option<&str>,
+    ) -> Result<Onboarding> {
+        let body = to_vec(map)?;
+
+        self.fire(Request {
+            body: Some(body),
+            multipart: None,
+            headers: audit_log_reason.map(reason_into_header),
+            method: LightMethod::Put,
+            route: Route::GuildOnboarding {
+                guild_id,
+            },
+            params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -1959,6 +2408,7 @@ impl Http {
                     user_id,
                 },
                 params: None,
+                retry_non_idempotent: false,
             })
             .await?;
 
@@ -1989,6 +2439,7 @@ impl Http {
                 message_id,
             },
             params: None,
+            retry_non_idempotent: false,
         };
 
         if new_attachments.is_empty() {
@@ -2022,6 +2473,7 @@ impl Http {
                 message_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -2030,7 +2482,7 @@ impl Http {
     pub async fn edit_member_me(
         &self,
         guild_id: GuildId,
-        map: &JsonMap,
+        map: &impl serde::Serialize,
         audit_log_reason: Option<&str>,
     ) -> Result<Member> {
         let body = to_vec(map)?;
@@ -2044,6 +2496,7 @@ impl Http {
                 guild_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -2069,6 +2522,7 @@ impl Http {
                 guild_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -2091,6 +2545,7 @@ impl Http {
                 channel_id: news_channel_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -2110,6 +2565,7 @@ impl Http {
                 token: interaction_token,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -2135,6 +2591,7 @@ impl Http {
                 token: interaction_token,
             },
             params: None,
+            retry_non_idempotent: false,
         };
 
         if new_attachments.is_empty() {
@@ -2161,6 +2618,7 @@ impl Http {
             method: LightMethod::Patch,
             route: Route::UserMe,
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -2184,6 +2642,7 @@ impl Http {
                     role_id,
                 },
                 params: None,
+                retry_non_idempotent: false,
             })
             .await?;
 
@@ -2218,6 +2677,7 @@ impl Http {
                     guild_id,
                 },
                 params: None,
+                retry_non_idempotent: false,
             })
             .await?;
 
@@ -2255,6 +2715,7 @@ impl Http {
                 event_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -2282,6 +2743,7 @@ impl Http {
                     sticker_id,
                 },
                 params: None,
+                retry_non_idempotent: false,
             })
             .await?;
 
@@ -2292,6 +2754,31 @@ impl Http {
         from_value(value).map_err(From::from)
     }
 
+    /// Changes a soundboard sound in a guild.
+    ///
+    /// See [`GuildId::edit_soundboard_sound`] for permissions requirements.
+    pub async fn edit_soundboard_sound(
+        &self,
+        guild_id: GuildId,
+        sound_id: SoundId,
+        map: &impl serde::Serialize,
+        audit_log_reason: Option<&str>,
+    ) -> Result<SoundboardSound> {
+        self.fire(Request {
+            body: Some(to_vec(map)?),
+            multipart: None,
+            headers: audit_log_reason.map(reason_into_header),
+            method: LightMethod::Patch,
+            route: Route::GuildSoundboardSound {
+                guild_id,
+                sound_id,
+            },
+            params: None,
+            retry_non_idempotent: false,
+        })
+        .await
+    }
+
     /// Edits a thread channel in the [`GuildChannel`] given its Id.
     pub async fn edit_thread(
         &self,
@@ -2308,6 +2795,7 @@ impl Http {
                 channel_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -2358,6 +2846,7 @@ impl Http {
                 user_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -2409,6 +2898,7 @@ impl Http {
                 guild_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -2431,6 +2921,7 @@ impl Http {
                 channel_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -2460,7 +2951,7 @@ impl Http {
     /// let id = WebhookId::new(245037420704169985);
     /// let image = CreateAttachment::path("./webhook_img.png").await?;
     /// let map = json!({
-    ///     "avatar": image.to_base64(),
+    ///     "avatar": image.to_base64()?,
     /// });
     ///
     /// let edited = http.edit_webhook(id, &map, None).await?;
@@ -2482,6 +2973,7 @@ impl Http {
                 webhook_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -2530,6 +3022,7 @@ impl Http {
                 token,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -2608,6 +3101,7 @@ impl Http {
                 token,
             },
             params: Some(params),
+            retry_non_idempotent: false,
         };
 
         if files.is_empty() {
@@ -2648,6 +3142,7 @@ impl Http {
                 message_id,
             },
             params: thread_id.map(|thread_id| vec![("thread_id", thread_id.to_string())]),
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -2673,6 +3168,7 @@ impl Http {
                 message_id,
             },
             params: thread_id.map(|thread_id| vec![("thread_id", thread_id.to_string())]),
+            retry_non_idempotent: false,
         };
 
         if new_attachments.is_empty() {
@@ -2707,6 +3203,7 @@ impl Http {
                 message_id,
             },
             params: thread_id.map(|thread_id| vec![("thread_id", thread_id.to_string())]),
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -2729,6 +3226,7 @@ impl Http {
                 method: LightMethod::Get,
                 route: Route::StatusMaintenancesActive,
                 params: None,
+                retry_non_idempotent: false,
             })
             .await?;
 
@@ -2773,6 +3271,7 @@ impl Http {
                 guild_id,
             },
             params: Some(params),
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -2809,6 +3308,7 @@ impl Http {
                 guild_id,
             },
             params: Some(params),
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -2826,6 +3326,7 @@ impl Http {
                 guild_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -2844,6 +3345,7 @@ impl Http {
                 rule_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -2868,6 +3370,7 @@ impl Http {
                 guild_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -2894,6 +3397,7 @@ impl Http {
                 rule_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -2917,6 +3421,7 @@ impl Http {
                 rule_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -2930,6 +3435,7 @@ impl Http {
             method: LightMethod::Get,
             route: Route::GatewayBot,
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -2945,6 +3451,7 @@ impl Http {
                 channel_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -2963,10 +3470,63 @@ impl Http {
                 channel_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
 
+    /// Gets a page of the thread members of a thread, each including its guild [`Member`].
+    ///
+    /// Optionally pass a `limit` (1-100, defaults to 100) and the Id of the user to offset the
+    /// result by. The `guild_id` of the thread is set on each [`ThreadMember::member`], as Discord
+    /// does not include it.
+    pub async fn get_channel_thread_members_paginated(
+        &self,
+        guild_id: GuildId,
+        channel_id: ChannelId,
+        limit: Option<u64>,
+        after: Option<UserId>,
+    ) -> Result<Vec<ThreadMember>> {
+        if let Some(l) = limit {
+            if !(1..=constants::THREAD_MEMBER_FETCH_LIMIT).contains(&l) {
+                return Err(Error::NotInRange("limit", l, 1, constants::THREAD_MEMBER_FETCH_LIMIT));
+            }
+        }
+
+        let mut params = vec![
+            ("with_member", true.to_string()),
+            ("limit", limit.unwrap_or(constants::THREAD_MEMBER_FETCH_LIMIT).to_string()),
+        ];
+        if let Some(after) = after {
+            params.push(("after", after.to_string()));
+        }
+
+        let mut value: Value = self
+            .fire(Request {
+                body: None,
+                multipart: None,
+                headers: None,
+                method: LightMethod::Get,
+                route: Route::ChannelThreadMembers {
+                    channel_id,
+                },
+                params: Some(params),
+                retry_non_idempotent: false,
+            })
+            .await?;
+
+        if let Some(values) = value.as_array_mut() {
+            for value in values {
+                let member = value.as_object_mut().and_then(|element| element.get_mut("member"));
+                if let Some(member) = member.and_then(Value::as_object_mut) {
+                    member.insert("guild_id".to_string(), guild_id.get().into());
+                }
+            }
+        }
+
+        from_value(value).map_err(From::from)
+    }
+
     /// Gets all active threads from a guild.
     pub async fn get_guild_active_threads(&self, guild_id: GuildId) -> Result<ThreadsData> {
         self.fire(Request {
@@ -2978,6 +3538,7 @@ impl Http {
                 guild_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -3006,6 +3567,7 @@ impl Http {
                 channel_id,
             },
             params: Some(params),
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -3034,6 +3596,7 @@ impl Http {
                 channel_id,
             },
             params: Some(params),
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -3062,6 +3625,7 @@ impl Http {
                 channel_id,
             },
             params: Some(params),
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -3077,6 +3641,7 @@ impl Http {
                 channel_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -3092,6 +3657,7 @@ impl Http {
                 channel_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -3112,6 +3678,7 @@ impl Http {
                 user_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -3132,6 +3699,7 @@ impl Http {
                 user_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -3152,6 +3720,7 @@ impl Http {
                 user_id,
             },
             params: Some(vec![("with_member", with_member.to_string())]),
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -3186,6 +3755,7 @@ impl Http {
                 channel_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -3201,6 +3771,7 @@ impl Http {
                 channel_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -3216,6 +3787,7 @@ impl Http {
                 guild_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -3231,6 +3803,7 @@ impl Http {
                 channel_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -3270,6 +3843,7 @@ impl Http {
                     answer_id,
                 },
                 params: Some(params),
+                retry_non_idempotent: false,
             })
             .await?;
 
@@ -3291,13 +3865,115 @@ impl Http {
                 message_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
 
-    /// Gets information about the current application.
-    ///
-    /// **Note**: Only applications may use this endpoint.
+    /// Gets the role connection metadata records of the current application.
+    pub async fn get_application_role_connection_metadata(
+        &self,
+    ) -> Result<Vec<ApplicationRoleConnectionMetadata>> {
+        self.fire(Request {
+            body: None,
+            multipart: None,
+            headers: None,
+            method: LightMethod::Get,
+            route: Route::ApplicationRoleConnectionMetadata {
+                application_id: self.try_application_id()?,
+            },
+            params: None,
+            retry_non_idempotent: false,
+        })
+        .await
+    }
+
+    /// Overwrites the role connection metadata records of the current application, which are
+    /// used to configure linked roles. Takes a list of [`ApplicationRoleConnectionMetadata`].
+    ///
+    /// Refer to Discord's [docs] for field information.
+    ///
+    /// [docs]: https://discord.com/developers/docs/resources/application-role-connection-metadata#update-application-role-connection-metadata-records
+    pub async fn edit_application_role_connection_metadata(
+        &self,
+        map: &impl serde::Serialize,
+    ) -> Result<Vec<ApplicationRoleConnectionMetadata>> {
+        self.fire(Request {
+            body: Some(to_vec(map)?),
+            multipart: None,
+            headers: None,
+            method: LightMethod::Put,
+            route: Route::ApplicationRoleConnectionMetadata {
+                application_id: self.try_application_id()?,
+            },
+            params: None,
+            retry_non_idempotent: false,
+        })
+        .await
+    }
+
+    /// Gets the role connection that the given application has attached to the current user.
+    ///
+    /// **Note**: This must be called with the user's OAuth2 access token, which needs the
+    /// [`Scope::RoleConnectionsWrite`] scope, instead of a bot token. Construct a separate [`Http`]
+    /// for this by passing `"Bearer <access token>"` as the token.
+    pub async fn get_current_user_application_role_connection(
+        &self,
+        application_id: ApplicationId,
+    ) -> Result<ApplicationRoleConnection> {
+        self.fire(Request {
+            body: None,
+            multipart: None,
+            headers: None,
+            method: LightMethod::Get,
+            route: Route::UserMeApplicationRoleConnection {
+                application_id,
+            },
+            params: None,
+            retry_non_idempotent: false,
+        })
+        .await
+    }
+
+    /// Updates the role connection that the given application has attached to the current user.
+    /// Takes an [`ApplicationRoleConnection`].
+    ///
+    /// **Note**: This must be called with the user's OAuth2 access token, which needs the
+    /// [`Scope::RoleConnectionsWrite`] scope, instead of a bot token. Construct a separate [`Http`]
+    /// for this by passing `"Bearer <access token>"` as the token:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// # use serenity::model::prelude::*;
+    /// # async fn run(access_token: &str, application_id: ApplicationId) -> serenity::Result<()> {
+    /// let http = Http::new(&format!("Bearer {access_token}"));
+    /// let connection = ApplicationRoleConnection::new().metadata("level", &12);
+    /// http.edit_current_user_application_role_connection(application_id, &connection).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn edit_current_user_application_role_connection(
+        &self,
+        application_id: ApplicationId,
+        map: &impl serde::Serialize,
+    ) -> Result<ApplicationRoleConnection> {
+        self.fire(Request {
+            body: Some(to_vec(map)?),
+            multipart: None,
+            headers: None,
+            method: LightMethod::Put,
+            route: Route::UserMeApplicationRoleConnection {
+                application_id,
+            },
+            params: None,
+            retry_non_idempotent: false,
+        })
+        .await
+    }
+
+    /// Gets information about the current application.
+    ///
+    /// **Note**: Only applications may use this endpoint.
     pub async fn get_current_application_info(&self) -> Result<CurrentApplicationInfo> {
         self.fire(Request {
             body: None,
@@ -3306,6 +3982,7 @@ impl Http {
             method: LightMethod::Get,
             route: Route::Oauth2ApplicationCurrent,
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -3319,6 +3996,7 @@ impl Http {
             method: LightMethod::Get,
             route: Route::UserMe,
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -3334,6 +4012,7 @@ impl Http {
                 guild_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -3350,12 +4029,20 @@ impl Http {
                 emoji_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
 
     /// Gets all emojis for the current application.
     pub async fn get_application_emojis(&self) -> Result<Vec<Emoji>> {
+        self.get_emojis_of_application(self.try_application_id()?).await
+    }
+
+    pub(crate) async fn get_emojis_of_application(
+        &self,
+        application_id: ApplicationId,
+    ) -> Result<Vec<Emoji>> {
         // Why, discord...
         #[derive(Deserialize)]
         struct ApplicationEmojis {
@@ -3369,9 +4056,10 @@ impl Http {
                 headers: None,
                 method: LightMethod::Get,
                 route: Route::Emojis {
-                    application_id: self.try_application_id()?,
+                    application_id,
                 },
                 params: None,
+                retry_non_idempotent: false,
             })
             .await?;
 
@@ -3380,16 +4068,25 @@ impl Http {
 
     /// Gets information about an application emoji.
     pub async fn get_application_emoji(&self, emoji_id: EmojiId) -> Result<Emoji> {
+        self.get_emoji_of_application(self.try_application_id()?, emoji_id).await
+    }
+
+    pub(crate) async fn get_emoji_of_application(
+        &self,
+        application_id: ApplicationId,
+        emoji_id: EmojiId,
+    ) -> Result<Emoji> {
         self.fire(Request {
             body: None,
             multipart: None,
             headers: None,
             method: LightMethod::Get,
             route: Route::Emoji {
-                application_id: self.try_application_id()?,
+                application_id,
                 emoji_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -3441,6 +4138,7 @@ impl Http {
                 application_id: self.try_application_id()?,
             },
             params: Some(params),
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -3454,6 +4152,7 @@ impl Http {
             method: LightMethod::Get,
             route: Route::Gateway,
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -3469,6 +4168,7 @@ impl Http {
                 application_id: self.try_application_id()?,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -3484,6 +4184,7 @@ impl Http {
                 application_id: self.try_application_id()?,
             },
             params: Some(vec![("with_localizations", true.to_string())]),
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -3500,6 +4201,7 @@ impl Http {
                 command_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -3515,6 +4217,7 @@ impl Http {
                 guild_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -3530,6 +4233,7 @@ impl Http {
                 guild_id,
             },
             params: Some(vec![("with_counts", true.to_string())]),
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -3546,6 +4250,7 @@ impl Http {
                 guild_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -3566,6 +4271,7 @@ impl Http {
                 guild_id,
             },
             params: Some(vec![("with_localizations", true.to_string())]),
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -3587,6 +4293,7 @@ impl Http {
                 command_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -3606,6 +4313,7 @@ impl Http {
                 guild_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -3627,6 +4335,7 @@ impl Http {
                 command_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -3644,6 +4353,26 @@ impl Http {
                 guild_id,
             },
             params: None,
+            retry_non_idempotent: false,
+        })
+        .await
+    }
+
+    /// Gets the public `widget.json` of a guild.
+    ///
+    /// This does not require authentication, so it also works on an [`Http`] created with an empty
+    /// token.
+    pub async fn get_guild_widget_json(&self, guild_id: GuildId) -> Result<PublicGuildWidget> {
+        self.fire(Request {
+            body: None,
+            multipart: None,
+            headers: None,
+            method: LightMethod::Get,
+            route: Route::GuildWidgetJson {
+                guild_id,
+            },
+            params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -3659,6 +4388,7 @@ impl Http {
                 guild_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -3674,6 +4404,23 @@ impl Http {
                 guild_id,
             },
             params: None,
+            retry_non_idempotent: false,
+        })
+        .await
+    }
+
+    /// Gets the onboarding flow of a guild.
+    pub async fn get_guild_onboarding(&self, guild_id: GuildId) -> Result<Onboarding> {
+        self.fire(Request {
+            body: None,
+            multipart: None,
+            headers: None,
+            method: LightMethod::Get,
+            route: Route::GuildOnboarding {
+                guild_id,
+            },
+            params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -3689,6 +4436,7 @@ impl Http {
                 guild_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -3704,6 +4452,41 @@ impl Http {
                 guild_id,
             },
             params: None,
+            retry_non_idempotent: false,
+        })
+        .await
+    }
+
+    /// Gets a [`GuildTemplate`] by its code.
+    pub async fn get_guild_template(&self, code: &str) -> Result<GuildTemplate> {
+        self.fire(Request {
+            body: None,
+            multipart: None,
+            headers: None,
+            method: LightMethod::Get,
+            route: Route::GuildsTemplate {
+                code,
+            },
+            params: None,
+            retry_non_idempotent: false,
+        })
+        .await
+    }
+
+    /// Gets the templates of a guild.
+    ///
+    /// See [`GuildId::templates`] for permissions requirements.
+    pub async fn get_guild_templates(&self, guild_id: GuildId) -> Result<Vec<GuildTemplate>> {
+        self.fire(Request {
+            body: None,
+            multipart: None,
+            headers: None,
+            method: LightMethod::Get,
+            route: Route::GuildTemplates {
+                guild_id,
+            },
+            params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -3724,6 +4507,45 @@ impl Http {
                 guild_id,
             },
             params: None,
+            retry_non_idempotent: false,
+        })
+        .await
+        .map(|x| x.code)
+    }
+
+    /// Sets the vanity URL code of a guild, returning the new code.
+    ///
+    /// **Note**: Only guilds with the `VANITY_URL` feature can set a vanity URL.
+    pub async fn edit_guild_vanity_url(
+        &self,
+        guild_id: GuildId,
+        code: &str,
+        audit_log_reason: Option<&str>,
+    ) -> Result<String> {
+        #[derive(serde::Serialize)]
+        struct EditGuildVanityUrl<'a> {
+            code: &'a str,
+        }
+
+        #[derive(Deserialize)]
+        struct GuildVanityUrl {
+            code: String,
+        }
+
+        let body = to_vec(&EditGuildVanityUrl {
+            code,
+        })?;
+
+        self.fire::<GuildVanityUrl>(Request {
+            body: Some(body),
+            multipart: None,
+            headers: audit_log_reason.map(reason_into_header),
+            method: LightMethod::Patch,
+            route: Route::GuildVanityUrl {
+                guild_id,
+            },
+            params: None,
+            retry_non_idempotent: false,
         })
         .await
         .map(|x| x.code)
@@ -3759,6 +4581,7 @@ impl Http {
                     guild_id,
                 },
                 params: Some(params),
+                retry_non_idempotent: false,
             })
             .await?;
 
@@ -3784,6 +4607,7 @@ impl Http {
                 guild_id,
             },
             params: Some(vec![("days", days.to_string())]),
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -3800,6 +4624,7 @@ impl Http {
                 guild_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -3817,6 +4642,7 @@ impl Http {
                     role_id,
                 },
                 params: None,
+                retry_non_idempotent: false,
             })
             .await?;
 
@@ -3839,6 +4665,7 @@ impl Http {
                     guild_id,
                 },
                 params: None,
+                retry_non_idempotent: false,
             })
             .await?;
 
@@ -3874,6 +4701,7 @@ impl Http {
                 event_id,
             },
             params: Some(vec![("with_user_count", with_user_count.to_string())]),
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -3897,6 +4725,7 @@ impl Http {
                 guild_id,
             },
             params: Some(vec![("with_user_count", with_user_count.to_string())]),
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -3949,6 +4778,7 @@ impl Http {
                 event_id,
             },
             params: Some(params),
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -3965,6 +4795,7 @@ impl Http {
                     guild_id,
                 },
                 params: None,
+                retry_non_idempotent: false,
             })
             .await?;
 
@@ -3996,6 +4827,7 @@ impl Http {
                     sticker_id,
                 },
                 params: None,
+                retry_non_idempotent: false,
             })
             .await?;
 
@@ -4036,6 +4868,7 @@ impl Http {
                 guild_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -4089,6 +4922,7 @@ impl Http {
             method: LightMethod::Get,
             route: Route::UserMeGuilds,
             params: Some(params),
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -4133,6 +4967,7 @@ impl Http {
                     guild_id,
                 },
                 params: None,
+                retry_non_idempotent: false,
             })
             .await?;
 
@@ -4164,14 +4999,7 @@ impl Http {
         #[cfg(feature = "utils")]
         let code = crate::utils::parse_invite(code);
 
-        let mut params = vec![
-            ("member_counts", member_counts.to_string()),
-            ("expiration", expiration.to_string()),
-        ];
-        if let Some(event_id) = event_id {
-            params.push(("event_id", event_id.to_string()));
-        }
-
+        let params = invite_params(member_counts, expiration, event_id);
         self.fire(Request {
             body: None,
             multipart: None,
@@ -4181,6 +5009,7 @@ impl Http {
                 code,
             },
             params: Some(params),
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -4198,6 +5027,7 @@ impl Http {
                     user_id,
                 },
                 params: None,
+                retry_non_idempotent: false,
             })
             .await?;
 
@@ -4224,6 +5054,7 @@ impl Http {
                 message_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -4256,6 +5087,7 @@ impl Http {
                 channel_id,
             },
             params: Some(params),
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -4271,6 +5103,7 @@ impl Http {
                 sticker_pack_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -4289,11 +5122,72 @@ impl Http {
             method: LightMethod::Get,
             route: Route::StickerPacks,
             params: None,
+            retry_non_idempotent: false,
         })
         .await
         .map(|s| s.sticker_packs)
     }
 
+    /// Retrieves a list of the soundboard sounds in a [`Guild`].
+    pub async fn get_guild_soundboard_sounds(
+        &self,
+        guild_id: GuildId,
+    ) -> Result<Vec<SoundboardSound>> {
+        #[derive(Deserialize)]
+        struct SoundboardSounds {
+            items: Vec<SoundboardSound>,
+        }
+
+        self.fire::<SoundboardSounds>(Request {
+            body: None,
+            multipart: None,
+            headers: None,
+            method: LightMethod::Get,
+            route: Route::GuildSoundboardSounds {
+                guild_id,
+            },
+            params: None,
+            retry_non_idempotent: false,
+        })
+        .await
+        .map(|s| s.items)
+    }
+
+    /// Retrieves a single soundboard sound in a [`Guild`].
+    pub async fn get_guild_soundboard_sound(
+        &self,
+        guild_id: GuildId,
+        sound_id: SoundId,
+    ) -> Result<SoundboardSound> {
+        self.fire(Request {
+            body: None,
+            multipart: None,
+            headers: None,
+            method: LightMethod::Get,
+            route: Route::GuildSoundboardSound {
+                guild_id,
+                sound_id,
+            },
+            params: None,
+            retry_non_idempotent: false,
+        })
+        .await
+    }
+
+    /// Retrieves the default soundboard sounds which can be used by all users.
+    pub async fn get_default_soundboard_sounds(&self) -> Result<Vec<SoundboardSound>> {
+        self.fire(Request {
+            body: None,
+            multipart: None,
+            headers: None,
+            method: LightMethod::Get,
+            route: Route::SoundboardDefaultSounds,
+            params: None,
+            retry_non_idempotent: false,
+        })
+        .await
+    }
+
     /// Gets all pins of a channel.
     pub async fn get_pins(&self, channel_id: ChannelId) -> Result<Vec<Message>> {
         self.fire(Request {
@@ -4305,6 +5199,7 @@ impl Http {
                 channel_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -4333,6 +5228,40 @@ impl Http {
                 reaction: &reaction_type.as_data(),
             },
             params: Some(params),
+            retry_non_idempotent: false,
+        })
+        .await
+    }
+
+    /// Gets the users who reacted to a message, like [`Self::get_reaction_users`].
+    ///
+    /// The `kind` selects whether users who sent a normal reaction or a super reaction are
+    /// returned.
+    pub async fn get_reaction_users_with_type(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+        reaction_type: &ReactionType,
+        kind: ReactionTypes,
+        limit: u8,
+        after: Option<u64>,
+    ) -> Result<Vec<User>> {
+        let mut params = vec![("limit", limit.to_string()), ("type", u8::from(kind).to_string())];
+        if let Some(after) = after {
+            params.push(("after", after.to_string()));
+        }
+        self.fire(Request {
+            body: None,
+            multipart: None,
+            headers: None,
+            method: LightMethod::Get,
+            route: Route::ChannelMessageReactionEmoji {
+                channel_id,
+                message_id,
+                reaction: &reaction_type.as_data(),
+            },
+            params: Some(params),
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -4348,6 +5277,7 @@ impl Http {
                 application_id: self.try_application_id()?,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -4363,6 +5293,7 @@ impl Http {
                 sticker_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -4385,6 +5316,7 @@ impl Http {
                 method: LightMethod::Get,
                 route: Route::StatusIncidentsUnresolved,
                 params: None,
+                retry_non_idempotent: false,
             })
             .await?;
 
@@ -4409,6 +5341,7 @@ impl Http {
                 method: LightMethod::Get,
                 route: Route::StatusMaintenancesUpcoming,
                 params: None,
+                retry_non_idempotent: false,
             })
             .await?;
 
@@ -4426,6 +5359,7 @@ impl Http {
                 user_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -4443,6 +5377,7 @@ impl Http {
             method: LightMethod::Get,
             route: Route::UserMeConnections,
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -4456,6 +5391,7 @@ impl Http {
             method: LightMethod::Get,
             route: Route::UserMeDmChannels,
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -4469,6 +5405,7 @@ impl Http {
             method: LightMethod::Get,
             route: Route::VoiceRegions,
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -4503,6 +5440,7 @@ impl Http {
                 webhook_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -4543,6 +5481,7 @@ impl Http {
                 token,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -4580,6 +5519,7 @@ impl Http {
                 token,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -4601,6 +5541,7 @@ impl Http {
                 user_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -4616,6 +5557,29 @@ impl Http {
                 guild_id,
             },
             params: None,
+            retry_non_idempotent: false,
+        })
+        .await
+    }
+
+    /// Plays a soundboard sound in a voice channel the current user is connected to.
+    ///
+    /// See [`ChannelId::send_soundboard_sound`] for permissions requirements.
+    pub async fn send_soundboard_sound(
+        &self,
+        channel_id: ChannelId,
+        map: &impl serde::Serialize,
+    ) -> Result<()> {
+        self.wind(204, Request {
+            body: Some(to_vec(map)?),
+            multipart: None,
+            headers: None,
+            method: LightMethod::Post,
+            route: Route::ChannelSendSoundboardSound {
+                channel_id,
+            },
+            params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -4640,6 +5604,7 @@ impl Http {
                 channel_id,
             },
             params: None,
+            retry_non_idempotent: false,
         };
 
         if files.is_empty() {
@@ -4672,6 +5637,7 @@ impl Http {
                 message_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -4693,6 +5659,7 @@ impl Http {
                 user_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -4720,6 +5687,7 @@ impl Http {
                 role_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -4745,6 +5713,7 @@ impl Http {
                     ("query", query.to_string()),
                     ("limit", limit.unwrap_or(constants::MEMBER_FETCH_LIMIT).to_string()),
                 ]),
+                retry_non_idempotent: false,
             })
             .await?;
 
@@ -4775,6 +5744,30 @@ impl Http {
                 guild_id,
             },
             params: Some(vec![("days", days.to_string())]),
+            retry_non_idempotent: false,
+        })
+        .await
+    }
+
+    /// Syncs a guild template to the current state of its guild.
+    ///
+    /// See [`GuildId::sync_template`] for permissions requirements.
+    pub async fn sync_guild_template(
+        &self,
+        guild_id: GuildId,
+        code: &str,
+    ) -> Result<GuildTemplate> {
+        self.fire(Request {
+            body: None,
+            multipart: None,
+            headers: None,
+            method: LightMethod::Put,
+            route: Route::GuildTemplate {
+                guild_id,
+                code,
+            },
+            params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -4795,6 +5788,7 @@ impl Http {
                 integration_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
@@ -4856,10 +5850,128 @@ impl Http {
                 message_id,
             },
             params: None,
+            retry_non_idempotent: false,
         })
         .await
     }
 
+    /// Downloads a file, such as an attachment or an asset from Discord's CDN.
+    ///
+    /// The request is made using the same [`reqwest::Client`] as all other requests, so any
+    /// configuration set via [`HttpBuilder::client`], e.g. a proxy, applies to downloads too. The
+    /// API proxy set via [`HttpBuilder::proxy`] is not used, as it only handles API requests.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Url`] if the URL is invalid, or [`Error::Http`] if the request fails or the
+    /// response status is unsuccessful.
+    pub async fn download(&self, url: &str) -> Result<DownloadedFile> {
+        let response = self.start_download(url).await?;
+        let (content_type, filename) = download_metadata(&response);
+        let bytes = response.bytes().await?.to_vec();
+
+        Ok(DownloadedFile {
+            bytes,
+            content_type,
+            filename,
+        })
+    }
+
+    /// Downloads a file like [`Self::download`], aborting as soon as it turns out to be larger
+    /// than `max_bytes` or not of the expected `content_type`.
+    ///
+    /// The size is checked against the `Content-Length` header before reading the body, and
+    /// against the number of bytes actually received while reading it, so at most `max_bytes` are
+    /// buffered.
+    ///
+    /// If `content_type` is set, the media type of the `Content-Type` header must match it, not
+    /// considering parameters such as the charset. A subtype of `*` matches all subtypes, e.g.
+    /// `image/*` matches `image/png`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HttpError::DownloadTooLarge`] if the file is larger than `max_bytes`, and
+    /// [`HttpError::UnexpectedContentType`] if it is not of the expected content type. Otherwise,
+    /// see [`Self::download`].
+    pub async fn download_limited(
+        &self,
+        url: &str,
+        max_bytes: usize,
+        content_type: Option<&str>,
+    ) -> Result<DownloadedFile> {
+        let too_large = || {
+            Error::Http(HttpError::DownloadTooLarge {
+                max_bytes,
+            })
+        };
+
+        let mut response = self.start_download(url).await?;
+        if response.content_length().is_some_and(|len| len > max_bytes as u64) {
+            return Err(too_large());
+        }
+
+        let (received, filename) = download_metadata(&response);
+        if let Some(expected) = content_type {
+            if !received.as_deref().is_some_and(|received| content_type_matches(expected, received))
+            {
+                return Err(Error::Http(HttpError::UnexpectedContentType {
+                    expected: expected.to_string(),
+                    received,
+                }));
+            }
+        }
+        let mut bytes = Vec::new();
+        while let Some(chunk) = response.chunk().await? {
+            if bytes.len() + chunk.len() > max_bytes {
+                return Err(too_large());
+            }
+            bytes.extend_from_slice(&chunk);
+        }
+
+        Ok(DownloadedFile {
+            bytes,
+            content_type: received,
+            filename,
+        })
+    }
+
+    /// Downloads a file like [`Self::download`], writing its contents to `writer` as they are
+    /// received instead of buffering the whole file.
+    ///
+    /// Returns the number of bytes written.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Io`] if writing fails. Otherwise, see [`Self::download`].
+    pub async fn download_to<W>(&self, url: &str, writer: &mut W) -> Result<u64>
+    where
+        W: AsyncWrite + Unpin + ?Sized,
+    {
+        let mut response = self.start_download(url).await?;
+
+        let mut written = 0;
+        while let Some(chunk) = response.chunk().await? {
+            writer.write_all(&chunk).await?;
+            written += chunk.len() as u64;
+        }
+        writer.flush().await?;
+
+        Ok(written)
+    }
+
+    pub(crate) async fn start_download(&self, url: &str) -> Result<ReqwestResponse> {
+        let url = Url::parse(url).map_err(|_| Error::Url(url.to_string()))?;
+
+        let response = self.client.get(url).send().await?;
+        if !response.status().is_success() {
+            return Err(Error::Http(HttpError::UnsuccessfulRequest(
+                ErrorResponse::from_response(response, reqwest::Method::GET).await,
+            )));
+        }
+
+        Ok(response)
+    }
+
     /// Fires off a request, deserializing the response reader via the given type bound.
     ///
     /// If you don't need to deserialize the response and want the response instance itself, use
@@ -4896,10 +6008,21 @@ impl Http {
     ///
     /// # Errors
     ///
-    /// If there is an error, it will be either [`Error::Http`] or [`Error::Json`].
+    /// If there is an error, it will be [`Error::Http`]. A response that fails to deserialize is
+    /// returned as [`HttpError::Deserialization`].
     pub async fn fire<T: DeserializeOwned>(&self, req: Request<'_>) -> Result<T> {
+        let method = req.method.reqwest_method();
+        let route = req.route.path().into_owned();
+
         let response = self.request(req).await?;
-        decode_resp(response).await
+        decode_resp(response).await.map_err(|why| match why {
+            Error::Json(source) => Error::Http(HttpError::Deserialization {
+                method,
+                route,
+                source,
+            }),
+            why => why,
+        })
     }
 
     /// Performs a request, ratelimiting it if necessary.
@@ -4907,6 +6030,8 @@ impl Http {
     /// Returns the raw reqwest Response. Use [`Self::fire`] to deserialize the response into some
     /// type.
     ///
+    /// If a [`RetryPolicy`] is set, the request is retried on transient failures according to it.
+    ///
     /// # Examples
     ///
     /// Send a body of bytes over the create message endpoint:
@@ -4935,12 +6060,51 @@ impl Http {
     /// ```
     #[instrument]
     pub async fn request(&self, req: Request<'_>) -> Result<ReqwestResponse> {
+        let Some(policy) = self.retry_policy.filter(|_| req.is_retryable()) else {
+            return self.request_once(Cow::Owned(req)).await;
+        };
+
+        let mut attempts = 1;
+        loop {
+            // Borrowed, so the request is only cloned if it has to be consumed by an attempt.
+            match self.request_once(Cow::Borrowed(&req)).await {
+                Err(Error::Http(why)) if policy.retry_on.matches(&why) => {
+                    if attempts > policy.max_retries {
+                        return Err(Error::Http(HttpError::RetriesExhausted {
+                            attempts,
+                            source: Box::new(why),
+                        }));
+                    }
+
+                    let delay = policy.backoff.delay(attempts);
+                    debug!("Retrying request to {} in {delay:?}: {why}", req.route.path());
+                    tokio::time::sleep(delay).await;
+                    attempts += 1;
+                },
+                result => return result,
+            }
+        }
+    }
+
+    async fn request_once(&self, req: Cow<'_, Request<'_>>) -> Result<ReqwestResponse> {
+        let route = req.route;
         let method = req.method.reqwest_method();
-        let response = if let Some(ratelimiter) = &self.ratelimiter {
-            ratelimiter.perform(req).await?
-        } else {
-            let request = req.build(&self.client, self.token(), self.proxy.as_deref())?.build()?;
-            self.client.execute(request).await?
+        let gzipped = self
+            .compress_requests
+            .filter(|_| !self.gzip_rejected.lock().expect("poisoned").contains(&route.kind()))
+            .and_then(|threshold| req.gzipped(threshold));
+        let response = match gzipped {
+            Some(gzipped) => {
+                let response = self.send(Cow::Owned(gzipped)).await?;
+                if response.status() == StatusCode::UNSUPPORTED_MEDIA_TYPE {
+                    debug!("Compressed body rejected by {}, resending uncompressed", route.path());
+                    self.gzip_rejected.lock().expect("poisoned").insert(route.kind());
+                    self.send(req).await?
+                } else {
+                    response
+                }
+            },
+            None => self.send(req).await?,
         };
 
         if response.status().is_success() {
@@ -4952,6 +6116,25 @@ impl Http {
         }
     }
 
+    async fn send(&self, req: Cow<'_, Request<'_>>) -> Result<ReqwestResponse> {
+        let (method, route) = (req.method, req.route);
+        let response = if let Some(transport) = &self.transport {
+            transport.request(req.into_owned()).await?
+        } else if let Some(ratelimiter) = &self.ratelimiter {
+            // The ratelimiter calls the response hook itself, as it retries ratelimited requests.
+            return ratelimiter.perform_ref(&req).await;
+        } else {
+            let req = req.into_owned();
+            let request = req.build(&self.client, self.token(), self.proxy.as_deref())?.build()?;
+            self.client.execute(request).await?
+        };
+
+        if let Some(hook) = &self.on_response {
+            hook.call(method, route, &response);
+        }
+        Ok(response)
+    }
+
     /// Performs a request and then verifies that the response status code is equal to the expected
     /// value.
     ///
@@ -4994,3 +6177,394 @@ impl AsRef<Http> for Http {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    use super::*;
+    use crate::http::{Backoff, RetryOn};
+
+    /// Serves a single request with the given raw response, returning the base URL.
+    async fn serve_once(response: &'static [u8]) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut request = Vec::new();
+            let mut buf = [0; 1024];
+            while !request.ends_with(b"\r\n\r\n") {
+                let n = stream.read(&mut buf).await.unwrap();
+                request.extend_from_slice(&buf[..n]);
+            }
+            stream.write_all(response).await.unwrap();
+        });
+
+        format!("http://{addr}")
+    }
+
+    /// Serves one connection per response, returning the base URL and a receiver of the head
+    /// (lowercased) and body of each request.
+    async fn serve_each(
+        responses: Vec<Vec<u8>>,
+    ) -> (String, tokio::sync::mpsc::UnboundedReceiver<(String, Vec<u8>)>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+
+        tokio::spawn(async move {
+            for response in responses {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                let mut request = Vec::new();
+                let mut buf = [0; 1024];
+                let head_len = loop {
+                    if let Some(i) = request.windows(4).position(|w| w == b"\r\n\r\n") {
+                        break i + 4;
+                    }
+                    let n = stream.read(&mut buf).await.unwrap();
+                    request.extend_from_slice(&buf[..n]);
+                };
+                let head = String::from_utf8_lossy(&request[..head_len]).to_lowercase();
+                let content_length = head
+                    .lines()
+                    .find_map(|line| line.strip_prefix("content-length: "))
+                    .map_or(0, |len| len.parse().unwrap());
+                while request.len() < head_len + content_length {
+                    let n = stream.read(&mut buf).await.unwrap();
+                    request.extend_from_slice(&buf[..n]);
+                }
+                tx.send((head, request.split_off(head_len))).unwrap();
+                stream.write_all(&response).await.unwrap();
+            }
+        });
+
+        (format!("http://{addr}"), rx)
+    }
+
+    fn gzip(bytes: &[u8]) -> Vec<u8> {
+        use std::io::Write as _;
+
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::fast());
+        encoder.write_all(bytes).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    fn gunzip(bytes: &[u8]) -> Vec<u8> {
+        use std::io::Read as _;
+
+        let mut decoded = Vec::new();
+        flate2::read::GzDecoder::new(bytes).read_to_end(&mut decoded).unwrap();
+        decoded
+    }
+
+    #[tokio::test]
+    async fn download() {
+        let base = serve_once(
+            b"HTTP/1.1 200 OK\r\nContent-Type: image/png\r\nContent-Length: 4\r\n\r\n\x89PNG",
+        )
+        .await;
+
+        let http = Http::new("");
+        let file = http.download(&format!("{base}/attachments/1/2/cat.png?ex=1")).await.unwrap();
+        assert_eq!(file.bytes, b"\x89PNG");
+        assert_eq!(file.content_type.as_deref(), Some("image/png"));
+        assert_eq!(file.filename.as_deref(), Some("cat.png"));
+    }
+
+    #[tokio::test]
+    async fn download_unsuccessful() {
+        let base = serve_once(b"HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\n\r\n").await;
+
+        let http = Http::new("");
+        let result = http.download(&format!("{base}/missing.png")).await;
+        assert!(matches!(
+            result,
+            Err(Error::Http(HttpError::UnsuccessfulRequest(ErrorResponse { status_code, .. })))
+                if status_code == StatusCode::NOT_FOUND
+        ));
+
+        assert!(matches!(http.download("not a url").await, Err(Error::Url(_))));
+    }
+
+    #[tokio::test]
+    async fn download_limited() {
+        let http = Http::new("");
+        let too_large = |result: Result<DownloadedFile>| {
+            matches!(result, Err(Error::Http(HttpError::DownloadTooLarge { .. })))
+        };
+
+        let response = b"HTTP/1.1 200 OK\r\nContent-Length: 4\r\n\r\n\x89PNG";
+        let base = serve_once(response).await;
+        let file = http.download_limited(&format!("{base}/cat.png"), 4, None).await.unwrap();
+        assert_eq!(file.bytes, b"\x89PNG");
+
+        let base = serve_once(response).await;
+        assert!(too_large(http.download_limited(&format!("{base}/cat.png"), 3, None).await));
+
+        // Without a Content-Length, the body is checked while reading.
+        let response = b"HTTP/1.1 200 OK\r\nConnection: close\r\n\r\n0123456789";
+        let base = serve_once(response).await;
+        assert!(too_large(http.download_limited(&format!("{base}/big.txt"), 4, None).await));
+
+        let base = serve_once(response).await;
+        let mut written = Vec::new();
+        let len = http.download_to(&format!("{base}/big.txt"), &mut written).await.unwrap();
+        assert_eq!(len, 10);
+        assert_eq!(written, b"0123456789");
+    }
+
+    #[tokio::test]
+    async fn download_limited_content_type() {
+        let http = Http::new("");
+        let response =
+            b"HTTP/1.1 200 OK\r\nContent-Type: image/png\r\nContent-Length: 4\r\n\r\n\x89PNG";
+
+        for expected in ["image/png", "image/*", "IMAGE/PNG"] {
+            let base = serve_once(response).await;
+            let file = http.download_limited(&format!("{base}/cat.png"), 4, Some(expected)).await;
+            assert_eq!(file.unwrap().content_type.as_deref(), Some("image/png"));
+        }
+
+        let base = serve_once(response).await;
+        let result = http.download_limited(&format!("{base}/cat.png"), 4, Some("text/*")).await;
+        assert!(matches!(
+            result,
+            Err(Error::Http(HttpError::UnexpectedContentType { expected, received }))
+                if expected == "text/*" && received.as_deref() == Some("image/png")
+        ));
+
+        assert!(content_type_matches("text/plain", "text/plain; charset=utf-8"));
+        assert!(!content_type_matches("image/png", "image/pngx"));
+    }
+
+    #[tokio::test]
+    async fn attachment_url_with_filename() {
+        let base = serve_once(b"HTTP/1.1 200 OK\r\nContent-Length: 4\r\n\r\n\x89PNG").await;
+
+        let http = Http::new("");
+        let url = format!("{base}/attachments/1/2/image?ex=1&is=2");
+        let attachment = CreateAttachment::url_with_filename(&http, &url, "cat.png").await.unwrap();
+        assert_eq!(attachment.filename, "cat.png");
+        assert_eq!(attachment.data, b"\x89PNG");
+    }
+
+    #[tokio::test]
+    async fn attachment_url_streamed() {
+        let source = serve_once(b"HTTP/1.1 200 OK\r\nContent-Length: 8\r\n\r\n\x89PNGdata").await;
+        let (base, mut requests) =
+            serve_each(vec![b"HTTP/1.1 204 No Content\r\nConnection: close\r\n\r\n".to_vec()])
+                .await;
+        let http = HttpBuilder::new("").proxy(base).ratelimiter_disabled(true).build();
+
+        let url = format!("{source}/attachments/1/2/image?ex=1");
+        let attachment = CreateAttachment::url_streamed(&http, &url, "cat.png").await.unwrap();
+        assert!(attachment.data.is_empty());
+
+        let request = Request::new(Route::Gateway, LightMethod::Post).multipart(Some(Multipart {
+            upload: MultipartUpload::Attachments(vec![attachment]),
+            fields: Vec::new(),
+            payload_json: None,
+        }));
+        http.request(request.clone()).await.unwrap();
+
+        let (_, body) = requests.recv().await.unwrap();
+        let body = String::from_utf8_lossy(&body);
+        assert!(body.contains(r#"name="files[0]"; filename="cat.png""#));
+        assert!(body.contains("\r\n\r\n\u{fffd}PNGdata\r\n"));
+
+        // The data was consumed by the first request.
+        assert!(matches!(
+            http.request(request).await,
+            Err(Error::Http(HttpError::AttachmentStreamConsumed))
+        ));
+    }
+
+    #[test]
+    fn invite_query() {
+        assert_eq!(invite_params(true, false, None), [
+            ("with_counts", "true".to_string()),
+            ("with_expiration", "false".to_string()),
+        ]);
+        assert_eq!(invite_params(false, true, Some(ScheduledEventId::new(5)))[1..], [
+            ("with_expiration", "true".to_string()),
+            ("guild_scheduled_event_id", "5".to_string()),
+        ]);
+    }
+
+    #[tokio::test]
+    async fn builder_timeout() {
+        // Accept the connection, but never respond.
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (_stream, _) = listener.accept().await.unwrap();
+            tokio::time::sleep(Duration::from_secs(10)).await;
+        });
+
+        let http = HttpBuilder::new("").timeout(Duration::from_millis(100)).build();
+        let result = http.download(&format!("http://{addr}/slow.png")).await;
+        assert!(matches!(result, Err(Error::Http(HttpError::Request(e))) if e.is_timeout()));
+    }
+
+    #[tokio::test]
+    async fn on_response() {
+        let base = serve_once(
+            b"HTTP/1.1 204 No Content\r\nx-ratelimit-bucket: abcd1234\r\nx-ratelimit-limit: 5\r\n\
+            x-ratelimit-remaining: 4\r\nx-ratelimit-reset-after: 1.5\r\n\r\n",
+        )
+        .await;
+
+        let seen = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = Arc::clone(&seen);
+        let http = HttpBuilder::new("")
+            .proxy(base)
+            .ratelimiter_disabled(true)
+            .on_response(move |info| {
+                let path = info.route.path().into_owned();
+                sink.lock().unwrap().push((info.method, path, info.status, info.ratelimit.clone()));
+            })
+            .build();
+
+        http.delete_message(ChannelId::new(1), MessageId::new(2), None).await.unwrap();
+
+        let seen = seen.lock().unwrap();
+        let (method, path, status, ratelimit) = &seen[0];
+        assert_eq!(*method, LightMethod::Delete);
+        assert!(path.ends_with("/channels/1/messages/2"));
+        assert_eq!(*status, StatusCode::NO_CONTENT);
+        assert_eq!(ratelimit.bucket.as_deref(), Some("abcd1234"));
+        assert_eq!(ratelimit.limit, Some(5));
+        assert_eq!(ratelimit.remaining, Some(4));
+        assert_eq!(ratelimit.reset_after, Some(Duration::from_millis(1500)));
+        assert_eq!(ratelimit.scope, None);
+    }
+
+    #[tokio::test]
+    async fn retry_policy() {
+        // Answer every request with a 502, closing the connection each time.
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let attempts = Arc::new(AtomicU64::new(0));
+        let counter = Arc::clone(&attempts);
+        tokio::spawn(async move {
+            loop {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                let mut request = Vec::new();
+                let mut buf = [0; 1024];
+                while !request.ends_with(b"\r\n\r\n") {
+                    let n = stream.read(&mut buf).await.unwrap();
+                    request.extend_from_slice(&buf[..n]);
+                }
+                counter.fetch_add(1, Ordering::Relaxed);
+                let response = b"HTTP/1.1 502 Bad Gateway\r\nContent-Length: 0\r\n\
+                    Connection: close\r\n\r\n";
+                stream.write_all(response).await.unwrap();
+            }
+        });
+
+        let policy = RetryPolicy {
+            max_retries: 2,
+            backoff: Backoff {
+                initial: Duration::from_millis(1),
+                max: Duration::from_millis(1),
+                jitter: false,
+            },
+            retry_on: RetryOn::SERVER_ERRORS,
+        };
+        let http = HttpBuilder::new("")
+            .proxy(format!("http://{addr}"))
+            .ratelimiter_disabled(true)
+            .retry_policy(policy)
+            .build();
+
+        let result = http.get_gateway().await;
+        assert!(matches!(
+            result,
+            Err(Error::Http(HttpError::RetriesExhausted { attempts: 3, ref source }))
+                if source.status_code() == Some(StatusCode::BAD_GATEWAY)
+        ));
+        assert_eq!(attempts.load(Ordering::Relaxed), 3);
+
+        // Non-idempotent requests are only attempted once.
+        let result = http.request(Request::new(Route::Gateway, LightMethod::Post)).await;
+        assert!(matches!(result, Err(Error::Http(HttpError::UnsuccessfulRequest(_)))));
+        assert_eq!(attempts.load(Ordering::Relaxed), 4);
+    }
+
+    #[tokio::test]
+    async fn compress_requests() {
+        let large = to_vec(&json!({"name": "x".repeat(64)})).unwrap();
+        let small = b"{}".to_vec();
+
+        let encoded = gzip(br#"{"url":"wss://gateway.discord.gg"}"#);
+        let mut compressed_response = format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Encoding: gzip\r\n\
+            Content-Length: {}\r\nConnection: close\r\n\r\n",
+            encoded.len()
+        )
+        .into_bytes();
+        compressed_response.extend_from_slice(&encoded);
+        let no_content = b"HTTP/1.1 204 No Content\r\nConnection: close\r\n\r\n".to_vec();
+
+        let (base, mut requests) = serve_each(vec![compressed_response, no_content]).await;
+        let http = HttpBuilder::new("")
+            .proxy(base)
+            .ratelimiter_disabled(true)
+            .compress_requests(32)
+            .build();
+
+        let request = Request::new(Route::Gateway, LightMethod::Post);
+        let response = http.request(request.clone().body(Some(large.clone()))).await.unwrap();
+        assert_eq!(response.text().await.unwrap(), r#"{"url":"wss://gateway.discord.gg"}"#);
+
+        let (head, body) = requests.recv().await.unwrap();
+        assert!(head.contains("\r\ncontent-encoding: gzip\r\n"));
+        assert!(head.contains("\r\naccept-encoding: gzip\r\n"));
+        assert_eq!(gunzip(&body), large);
+
+        // Bodies below the threshold are sent as is.
+        http.request(request.body(Some(small.clone()))).await.unwrap();
+        let (head, body) = requests.recv().await.unwrap();
+        assert!(!head.contains("content-encoding"));
+        assert_eq!(body, small);
+    }
+
+    #[tokio::test]
+    async fn compress_requests_unsupported() {
+        let large = to_vec(&json!({"name": "x".repeat(64)})).unwrap();
+        let (base, mut requests) = serve_each(vec![
+            b"HTTP/1.1 415 Unsupported Media Type\r\nContent-Length: 0\r\n\
+            Connection: close\r\n\r\n"
+                .to_vec(),
+            b"HTTP/1.1 204 No Content\r\nConnection: close\r\n\r\n".to_vec(),
+            b"HTTP/1.1 204 No Content\r\nConnection: close\r\n\r\n".to_vec(),
+        ])
+        .await;
+        let http = HttpBuilder::new("")
+            .proxy(base)
+            .ratelimiter_disabled(true)
+            .compress_requests(32)
+            .build();
+
+        let request = Request::new(Route::Gateway, LightMethod::Post).body(Some(large.clone()));
+        let response = http.request(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+        let (head, body) = requests.recv().await.unwrap();
+        assert!(head.contains("content-encoding: gzip"));
+        assert_eq!(gunzip(&body), large);
+
+        let (head, body) = requests.recv().await.unwrap();
+        assert!(!head.contains("content-encoding"));
+        assert_eq!(body, large);
+
+        // The route is remembered, so later requests to it aren't compressed at all.
+        let request = Request::new(Route::Gateway, LightMethod::Post).body(Some(large.clone()));
+        http.request(request).await.unwrap();
+        let (head, body) = requests.recv().await.unwrap();
+        assert!(!head.contains("content-encoding"));
+        assert_eq!(body, large);
+    }
+}
@@ -21,6 +21,196 @@ pub struct DiscordJsonError {
     pub errors: Vec<DiscordJsonSingleError>,
 }
 
+impl DiscordJsonError {
+    /// Returns the error code as a [`JsonErrorCode`].
+    ///
+    /// Codes which don't fit into a `u32`, such as the `-1` used when the error response itself
+    /// could not be decoded, are returned as `JsonErrorCode::Unknown(u32::MAX)`.
+    #[must_use]
+    pub fn code(&self) -> JsonErrorCode {
+        u32::try_from(self.code).map_or(JsonErrorCode::Unknown(u32::MAX), JsonErrorCode::from)
+    }
+}
+
+enum_number! {
+    /// A JSON error code returned by Discord alongside an unsuccessful response.
+    ///
+    /// [Discord docs](https://discord.com/developers/docs/topics/opcodes-and-status-codes#json-json-error-codes).
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+    #[non_exhaustive]
+    pub enum JsonErrorCode {
+        /// General error, such as a malformed request body.
+        GeneralError = 0,
+        /// Unknown account.
+        UnknownAccount = 10001,
+        /// Unknown application.
+        UnknownApplication = 10002,
+        /// Unknown channel.
+        UnknownChannel = 10003,
+        /// Unknown guild.
+        UnknownGuild = 10004,
+        /// Unknown integration.
+        UnknownIntegration = 10005,
+        /// Unknown invite.
+        UnknownInvite = 10006,
+        /// Unknown member.
+        UnknownMember = 10007,
+        /// Unknown message.
+        UnknownMessage = 10008,
+        /// Unknown permission overwrite.
+        UnknownPermissionOverwrite = 10009,
+        /// Unknown role.
+        UnknownRole = 10011,
+        /// Unknown token.
+        UnknownToken = 10012,
+        /// Unknown user.
+        UnknownUser = 10013,
+        /// Unknown emoji.
+        UnknownEmoji = 10014,
+        /// Unknown webhook.
+        UnknownWebhook = 10015,
+        /// Unknown webhook service.
+        UnknownWebhookService = 10016,
+        /// Unknown session.
+        UnknownSession = 10020,
+        /// Unknown ban.
+        UnknownBan = 10026,
+        /// Unknown sticker.
+        UnknownSticker = 10060,
+        /// Unknown interaction.
+        UnknownInteraction = 10062,
+        /// Unknown application command.
+        UnknownApplicationCommand = 10063,
+        /// Unknown stage instance.
+        UnknownStageInstance = 10067,
+        /// Unknown guild scheduled event.
+        UnknownGuildScheduledEvent = 10070,
+        /// Bots cannot use this endpoint.
+        BotsCannotUseEndpoint = 20001,
+        /// Only bots can use this endpoint.
+        OnlyBotsCanUseEndpoint = 20002,
+        /// This message cannot be edited due to announcement rate limits.
+        AnnouncementEditRateLimit = 20022,
+        /// The channel you are writing has hit the write rate limit.
+        ChannelWriteRateLimit = 20028,
+        /// Maximum number of guilds reached (100).
+        MaximumGuilds = 30001,
+        /// Maximum number of pins reached for the channel (50).
+        MaximumPins = 30003,
+        /// Maximum number of guild roles reached (250).
+        MaximumRoles = 30005,
+        /// Maximum number of webhooks reached (15).
+        MaximumWebhooks = 30007,
+        /// Maximum number of reactions reached (20).
+        MaximumReactions = 30010,
+        /// Maximum number of guild channels reached (500).
+        MaximumChannels = 30013,
+        /// Maximum number of thread members reached (1000).
+        MaximumThreadMembers = 30033,
+        /// Unauthorized. Provide a valid token and try again.
+        Unauthorized = 40001,
+        /// You need to verify your account in order to perform this action.
+        AccountVerificationRequired = 40002,
+        /// Request entity too large.
+        RequestEntityTooLarge = 40005,
+        /// This feature has been temporarily disabled server-side.
+        FeatureTemporarilyDisabled = 40006,
+        /// The user is banned from this guild.
+        UserBannedFromGuild = 40007,
+        /// This message has already been crossposted.
+        MessageAlreadyCrossposted = 40033,
+        /// An application command with that name already exists.
+        ApplicationCommandNameExists = 40041,
+        /// Interaction has already been acknowledged.
+        InteractionAlreadyAcknowledged = 40060,
+        /// Missing access.
+        MissingAccess = 50001,
+        /// Invalid account type.
+        InvalidAccountType = 50002,
+        /// Cannot execute action on a DM channel.
+        CannotExecuteOnDmChannel = 50003,
+        /// Guild widget disabled.
+        GuildWidgetDisabled = 50004,
+        /// Cannot edit a message authored by another user.
+        CannotEditOtherUsersMessage = 50005,
+        /// Cannot send an empty message.
+        CannotSendEmptyMessage = 50006,
+        /// Cannot send messages to this user.
+        CannotMessageUser = 50007,
+        /// Cannot send messages in a non-text channel.
+        CannotSendInNonTextChannel = 50008,
+        /// Channel verification level is too high for you to gain access.
+        ChannelVerificationLevelTooHigh = 50009,
+        /// OAuth2 application does not have a bot.
+        ApplicationHasNoBot = 50010,
+        /// OAuth2 application limit reached.
+        ApplicationLimitReached = 50011,
+        /// Invalid OAuth2 state.
+        InvalidOAuth2State = 50012,
+        /// You lack permissions to perform that action.
+        MissingPermissions = 50013,
+        /// Invalid authentication token provided.
+        InvalidAuthenticationToken = 50014,
+        /// Note was too long.
+        NoteTooLong = 50015,
+        /// Provided too few or too many messages to delete.
+        InvalidBulkDeleteCount = 50016,
+        /// A message can only be pinned to the channel it was sent in.
+        PinInWrongChannel = 50019,
+        /// Invite code was either invalid or taken.
+        InvalidInviteCode = 50020,
+        /// Cannot execute action on a system message.
+        CannotExecuteOnSystemMessage = 50021,
+        /// Invalid OAuth2 access token provided.
+        InvalidOAuth2AccessToken = 50025,
+        /// A message provided was too old to bulk delete.
+        MessageTooOldToBulkDelete = 50034,
+        /// Invalid form body or invalid `Content-Type` provided.
+        InvalidFormBody = 50035,
+        /// An invite was accepted to a guild the application's bot is not in.
+        InviteAcceptedToGuildWithoutBot = 50036,
+        /// Invalid API version provided.
+        InvalidApiVersion = 50041,
+        /// File uploaded exceeds the maximum size.
+        FileTooLarge = 50045,
+        /// Invalid file uploaded.
+        InvalidFileUploaded = 50046,
+        /// An operation cannot be performed on an archived thread.
+        ThreadArchived = 50083,
+        /// The guild needs more boosts to perform this action, as it lacks the required feature.
+        GuildNeedsMoreBoosts = 50101,
+        /// Two factor is required for this operation.
+        TwoFactorRequired = 60003,
+        /// Reaction was blocked.
+        ReactionBlocked = 90001,
+        /// API resource is currently overloaded. Try again a little later.
+        ResourceOverloaded = 130000,
+        /// The thread is locked.
+        ThreadLocked = 160005,
+        /// Message was blocked by automatic moderation.
+        BlockedByAutoMod = 200000,
+        _ => Unknown(u32),
+    }
+}
+
+impl JsonErrorCode {
+    /// Returns true if the code signals that an entity, such as a channel or message, does not
+    /// exist (anymore).
+    ///
+    /// This covers every code in the `10xxx` range, including ones not known to this enum.
+    #[must_use]
+    pub fn is_unknown_entity(self) -> bool {
+        (10001..11000).contains(&u32::from(self))
+    }
+
+    /// Returns true if the code signals that the current user lacks the permissions or access
+    /// required for the request, namely [`Self::MissingAccess`] and [`Self::MissingPermissions`].
+    #[must_use]
+    pub fn is_missing_permissions(self) -> bool {
+        matches!(self, Self::MissingAccess | Self::MissingPermissions)
+    }
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
 pub struct DiscordJsonSingleError {
     /// The error code.
@@ -80,6 +270,39 @@ pub enum HttpError {
     InvalidPort,
     /// When an application id was expected but missing.
     ApplicationIdMissing,
+    /// When the response to a request failed to deserialize.
+    Deserialization {
+        /// The method of the request.
+        method: Method,
+        /// The path of the request, e.g. `channels/1/messages`.
+        route: String,
+        /// The error that occurred while deserializing.
+        source: JsonError,
+    },
+    /// When a request still failed after being retried according to the [`RetryPolicy`].
+    ///
+    /// [`RetryPolicy`]: super::RetryPolicy
+    RetriesExhausted {
+        /// The number of attempts made, including the first one.
+        attempts: u32,
+        /// The error of the last attempt.
+        source: Box<HttpError>,
+    },
+    /// When a download was aborted because the file is larger than the allowed size.
+    DownloadTooLarge {
+        /// The maximum allowed size in bytes.
+        max_bytes: usize,
+    },
+    /// When a download was aborted because the file is not of the expected content type.
+    UnexpectedContentType {
+        /// The expected content type.
+        expected: String,
+        /// The `Content-Type` header of the response, if present.
+        received: Option<String>,
+    },
+    /// When a request with a streamed attachment had to be sent again, e.g. after hitting a
+    /// ratelimit, but the attachment data was already consumed by the first attempt.
+    AttachmentStreamConsumed,
 }
 
 impl HttpError {
@@ -106,9 +329,42 @@ impl HttpError {
     pub fn status_code(&self) -> Option<StatusCode> {
         match self {
             Self::UnsuccessfulRequest(res) => Some(res.status_code),
+            Self::RetriesExhausted {
+                source, ..
+            } => source.status_code(),
+            _ => None,
+        }
+    }
+
+    /// Returns the JSON error code if the error is an unsuccessful request
+    #[must_use]
+    pub fn json_error_code(&self) -> Option<JsonErrorCode> {
+        match self {
+            Self::UnsuccessfulRequest(res) => Some(res.error.code()),
+            Self::RetriesExhausted {
+                source, ..
+            } => source.json_error_code(),
             _ => None,
         }
     }
+
+    /// Returns true when the request failed because the current user is missing access to a
+    /// resource or lacks the permissions for the action.
+    ///
+    /// See [`JsonErrorCode::is_missing_permissions`].
+    #[must_use]
+    pub fn is_missing_permissions(&self) -> bool {
+        self.json_error_code().is_some_and(JsonErrorCode::is_missing_permissions)
+    }
+
+    /// Returns true when the request failed because the targeted entity, such as a channel or
+    /// message, does not exist.
+    ///
+    /// See [`JsonErrorCode::is_unknown_entity`].
+    #[must_use]
+    pub fn is_unknown_entity(&self) -> bool {
+        self.json_error_code().is_some_and(JsonErrorCode::is_unknown_entity)
+    }
 }
 
 impl From<ErrorResponse> for HttpError {
@@ -168,6 +424,29 @@ impl fmt::Display for HttpError {
             Self::InvalidScheme => f.write_str("Invalid Url scheme."),
             Self::InvalidPort => f.write_str("Invalid port."),
             Self::ApplicationIdMissing => f.write_str("Application id was expected but missing."),
+            Self::Deserialization {
+                method,
+                route,
+                source,
+            } => write!(f, "Failed to deserialize the response to {method} {route}: {source}"),
+            Self::RetriesExhausted {
+                attempts,
+                source,
+            } => write!(f, "Request failed after {attempts} attempts: {source}"),
+            Self::DownloadTooLarge {
+                max_bytes,
+            } => write!(f, "Download is larger than the limit of {max_bytes} bytes"),
+            Self::UnexpectedContentType {
+                expected,
+                received: Some(received),
+            } => write!(f, "Expected a download of type {expected}, got {received}"),
+            Self::UnexpectedContentType {
+                expected,
+                received: None,
+            } => write!(f, "Expected a download of type {expected}, got no content type"),
+            Self::AttachmentStreamConsumed => {
+                f.write_str("Streamed attachment data can't be sent more than once.")
+            },
         }
     }
 }
@@ -177,6 +456,12 @@ impl StdError for HttpError {
         match self {
             Self::Url(inner) => Some(inner),
             Self::Request(inner) => Some(inner),
+            Self::Deserialization {
+                source, ..
+            } => Some(source),
+            Self::RetriesExhausted {
+                source, ..
+            } => Some(source),
             _ => None,
         }
     }
@@ -278,4 +563,38 @@ mod test {
 
         assert_eq!(error_response, known);
     }
+
+    #[test]
+    fn json_error_codes() {
+        fn http_error(code: isize) -> HttpError {
+            HttpError::UnsuccessfulRequest(ErrorResponse {
+                status_code: StatusCode::FORBIDDEN,
+                url: String::from("https://discord.com/api/v10/channels/1/messages"),
+                method: Method::POST,
+                error: DiscordJsonError {
+                    code,
+                    message: String::new(),
+                    errors: vec![],
+                },
+            })
+        }
+
+        let missing_access = http_error(50001);
+        assert_eq!(missing_access.json_error_code(), Some(JsonErrorCode::MissingAccess));
+        assert!(missing_access.is_missing_permissions());
+        assert!(!missing_access.is_unknown_entity());
+
+        let unknown_channel = http_error(10003);
+        assert_eq!(unknown_channel.json_error_code(), Some(JsonErrorCode::UnknownChannel));
+        assert!(unknown_channel.is_unknown_entity());
+        assert!(!unknown_channel.is_missing_permissions());
+
+        let unknown_entity = http_error(10999);
+        assert_eq!(unknown_entity.json_error_code(), Some(JsonErrorCode::Unknown(10999)));
+        assert!(unknown_entity.is_unknown_entity());
+
+        assert_eq!(http_error(-1).json_error_code(), Some(JsonErrorCode::Unknown(u32::MAX)));
+        assert_eq!(HttpError::InvalidWebhook.json_error_code(), None);
+        assert!(!HttpError::InvalidWebhook.is_missing_permissions());
+    }
 }
@@ -11,7 +11,9 @@
 //! The former require a [`Client`] to have logged in, while the latter may be made regardless of
 //! any other usage of the library.
 //!
-//! If a request spuriously fails, it will be retried once.
+//! Requests which fail due to server or connection errors may be retried, see [`RetryPolicy`].
+//! The way requests are sent can be replaced with a [`Transport`], e.g. to test code which makes
+//! requests without a connection to Discord.
 //!
 //! Note that you may want to perform requests through a [model]s' instance methods where possible,
 //! as they each offer different levels of a high-level interface to the HTTP module.
@@ -24,7 +26,11 @@ mod error;
 mod multipart;
 mod ratelimiting;
 mod request;
+mod retry;
 mod routing;
+#[cfg(feature = "testing")]
+pub mod testing;
+mod transport;
 mod typing;
 
 use std::sync::Arc;
@@ -37,7 +43,9 @@ pub use self::error::*;
 pub use self::multipart::*;
 pub use self::ratelimiting::*;
 pub use self::request::*;
+pub use self::retry::*;
 pub use self::routing::*;
+pub use self::transport::*;
 pub use self::typing::*;
 #[cfg(feature = "cache")]
 use crate::cache::Cache;
@@ -57,7 +65,40 @@ use crate::model::prelude::*;
 ///
 /// If you are calling a function that expects `impl CacheHttp` as argument and you wish to utilise
 /// the `cache`-feature but you got no access to a [`Context`], you can pass a tuple of
-/// `(&Arc<Cache>, &Http)`.
+/// `(&Arc<Cache>, &Http)`, or a [`CacheAndHttp`] if you need to store both. Any reference to or
+/// [`Arc`] of an implementor also implements this trait.
+///
+/// The [`Http`] is used to perform requests, while the [`Cache`], if present, is used both to
+/// avoid requests by looking up the data in the cache, and to check for errors (such as missing
+/// permissions) before a request is made.
+///
+/// # Examples
+///
+/// All of these compositions can be passed to model methods:
+///
+/// ```rust,no_run
+/// # #[cfg(feature = "cache")]
+/// # async fn run() -> Result<(), serenity::Error> {
+/// use std::sync::Arc;
+///
+/// use serenity::cache::Cache;
+/// use serenity::http::{CacheAndHttp, Http};
+/// use serenity::model::id::ChannelId;
+///
+/// let cache = Arc::new(Cache::new());
+/// let http = Arc::new(Http::new("token"));
+/// let channel_id = ChannelId::new(1);
+///
+/// channel_id.to_channel(&*http).await?;
+/// channel_id.to_channel(&http).await?;
+/// channel_id.to_channel((&cache, &*http)).await?;
+///
+/// let cache_and_http = CacheAndHttp::new(cache, http);
+/// channel_id.to_channel(&cache_and_http).await?;
+/// channel_id.to_channel(Arc::new(cache_and_http)).await?;
+/// # Ok(())
+/// # }
+/// ```
 pub trait CacheHttp: Send + Sync {
     fn http(&self) -> &Http;
 
@@ -135,6 +176,55 @@ impl AsRef<Http> for (&Arc<Cache>, &Http) {
     }
 }
 
+/// An owned pair of a [`Cache`] and an [`Http`] client, implementing [`CacheHttp`].
+///
+/// This is useful for libraries and applications which need to store "something that is
+/// [`CacheHttp`]" without holding a [`Context`].
+#[cfg(feature = "cache")]
+#[derive(Clone, Debug)]
+pub struct CacheAndHttp {
+    /// The cache, used to avoid requests and to check for errors before making requests.
+    pub cache: Arc<Cache>,
+    /// The HTTP client, used to perform requests.
+    pub http: Arc<Http>,
+}
+
+#[cfg(feature = "cache")]
+impl CacheAndHttp {
+    /// Creates a new pair of the given cache and HTTP client.
+    #[must_use]
+    pub fn new(cache: Arc<Cache>, http: Arc<Http>) -> Self {
+        Self {
+            cache,
+            http,
+        }
+    }
+}
+
+#[cfg(feature = "cache")]
+impl CacheHttp for CacheAndHttp {
+    fn http(&self) -> &Http {
+        &self.http
+    }
+    fn cache(&self) -> Option<&Arc<Cache>> {
+        Some(&self.cache)
+    }
+}
+
+#[cfg(feature = "cache")]
+impl AsRef<Cache> for CacheAndHttp {
+    fn as_ref(&self) -> &Cache {
+        &self.cache
+    }
+}
+
+#[cfg(feature = "cache")]
+impl AsRef<Http> for CacheAndHttp {
+    fn as_ref(&self) -> &Http {
+        &self.http
+    }
+}
+
 /// An method used for ratelimiting special routes.
 ///
 /// This is needed because [`reqwest`]'s [`Method`] enum does not derive Copy.
@@ -184,6 +274,18 @@ pub enum UserPagination {
     Before(UserId),
 }
 
+/// A file downloaded via [`Http::download`].
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct DownloadedFile {
+    /// The contents of the file.
+    pub bytes: Vec<u8>,
+    /// The value of the `Content-Type` header of the response, if present.
+    pub content_type: Option<String>,
+    /// The file name, taken from the last segment of the URL path, if not empty.
+    pub filename: Option<String>,
+}
+
 #[derive(Clone, Copy, Debug)]
 #[non_exhaustive]
 pub enum MessagePagination {
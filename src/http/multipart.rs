@@ -1,19 +1,66 @@
 use std::borrow::Cow;
+use std::fmt;
+use std::sync::{Arc, Mutex};
 
 use reqwest::multipart::{Form, Part};
+use reqwest::Body;
 
+use super::HttpError;
 use crate::builder::CreateAttachment;
 use crate::internal::prelude::*;
 
 impl CreateAttachment {
     fn into_part(self) -> Result<Part> {
-        let mut part = Part::bytes(self.data);
+        let mut part = match self.stream {
+            Some(stream) => stream.into_part()?,
+            None => Part::bytes(self.data),
+        };
         part = guess_mime_str(part, &self.filename)?;
         part = part.file_name(self.filename);
         Ok(part)
     }
 }
 
+/// Attachment data which is streamed into the upload instead of being buffered in memory, see
+/// [`CreateAttachment::url_streamed`].
+///
+/// The data can only be sent once. Clones share the same underlying stream.
+#[derive(Clone)]
+pub(crate) struct AttachmentStream {
+    body: Arc<Mutex<Option<Body>>>,
+    len: Option<u64>,
+}
+
+impl AttachmentStream {
+    pub(crate) fn new(body: Body, len: Option<u64>) -> Self {
+        Self {
+            body: Arc::new(Mutex::new(Some(body))),
+            len,
+        }
+    }
+
+    fn into_part(self) -> Result<Part> {
+        let body = self.body.lock().expect("poisoned").take();
+        let body = body.ok_or(Error::Http(HttpError::AttachmentStreamConsumed))?;
+        Ok(match self.len {
+            Some(len) => Part::stream_with_length(body, len),
+            None => Part::stream(body),
+        })
+    }
+}
+
+impl fmt::Debug for AttachmentStream {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AttachmentStream").field("len", &self.len).finish_non_exhaustive()
+    }
+}
+
+impl PartialEq for AttachmentStream {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.body, &other.body)
+    }
+}
+
 #[derive(Clone, Debug)]
 pub enum MultipartUpload {
     /// A file sent with the form data as an individual upload. For example, a sticker.
@@ -49,7 +49,7 @@ use tokio::time::{sleep, Duration};
 use tracing::{debug, instrument};
 
 pub use super::routing::RatelimitingBucket;
-use super::{HttpError, LightMethod, Request};
+use super::{HttpError, LightMethod, Request, Route};
 use crate::internal::prelude::*;
 
 /// Passed to the [`Ratelimiter::set_ratelimit_callback`] callback. If using Client, that callback
@@ -57,11 +57,122 @@ use crate::internal::prelude::*;
 #[derive(Clone, Debug)]
 #[non_exhaustive]
 pub struct RatelimitInfo {
+    /// How long the request will wait before being retried.
     pub timeout: std::time::Duration,
     pub limit: i64,
     pub method: LightMethod,
     pub path: String,
     pub global: bool,
+    /// The scope of the ratelimit, as reported by Discord.
+    ///
+    /// This is [`None`] for pre-emptive ratelimits, as no response was received.
+    pub scope: Option<RatelimitScope>,
+    /// Whether the request was held back before being sent because the bucket was known to be
+    /// exhausted, rather than in response to a 429.
+    pub preemptive: bool,
+}
+
+/// The scope of a ratelimit, as sent by Discord in the `X-RateLimit-Scope` header of a 429.
+///
+/// [Discord docs](https://discord.com/developers/docs/topics/rate-limits#header-format).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum RatelimitScope {
+    /// The ratelimit is specific to the bot.
+    User,
+    /// The ratelimit applies to all requests made by the bot.
+    Global,
+    /// The ratelimit is shared with everyone accessing the resource, and does not count towards
+    /// the bot's own limits.
+    Shared,
+}
+
+/// The ratelimit headers of a response, passed to the [`HttpBuilder::on_response`] hook as part
+/// of a [`ResponseInfo`].
+///
+/// Each field is [`None`] if the header was missing or could not be parsed.
+///
+/// [`HttpBuilder::on_response`]: super::HttpBuilder::on_response
+#[derive(Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct RatelimitHeaders {
+    /// The unique string identifying the ratelimit bucket, from `X-RateLimit-Bucket`.
+    pub bucket: Option<String>,
+    /// The number of requests that can be made in the bucket's period, from `X-RateLimit-Limit`.
+    pub limit: Option<i64>,
+    /// The number of requests remaining in the current period, from `X-RateLimit-Remaining`.
+    pub remaining: Option<i64>,
+    /// The time until the current period resets, from `X-RateLimit-Reset-After`.
+    pub reset_after: Option<Duration>,
+    /// The scope of the ratelimit, from `X-RateLimit-Scope`. Only sent with a 429.
+    pub scope: Option<RatelimitScope>,
+}
+
+impl RatelimitHeaders {
+    /// Parses the ratelimit headers of a response.
+    #[must_use]
+    pub fn from_headers(headers: &HeaderMap) -> Self {
+        let bucket = headers.get("x-ratelimit-bucket").and_then(|v| v.to_str().ok());
+        Self {
+            bucket: bucket.map(ToString::to_string),
+            limit: parse_header(headers, "x-ratelimit-limit").ok().flatten(),
+            remaining: parse_header(headers, "x-ratelimit-remaining").ok().flatten(),
+            reset_after: parse_header(headers, "x-ratelimit-reset-after")
+                .ok()
+                .flatten()
+                .and_then(|secs| Duration::try_from_secs_f64(secs).ok()),
+            scope: RatelimitScope::from_headers(headers),
+        }
+    }
+}
+
+/// Information about a completed request, passed to the [`HttpBuilder::on_response`] hook.
+///
+/// [`HttpBuilder::on_response`]: super::HttpBuilder::on_response
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct ResponseInfo<'a> {
+    pub method: LightMethod,
+    pub route: Route<'a>,
+    pub status: StatusCode,
+    pub ratelimit: RatelimitHeaders,
+}
+
+/// The hook set with [`HttpBuilder::on_response`], shared between [`Http`] and its
+/// [`Ratelimiter`].
+///
+/// [`HttpBuilder::on_response`]: super::HttpBuilder::on_response
+/// [`Http`]: super::Http
+#[derive(Clone)]
+pub(super) struct ResponseHook(pub(super) Arc<dyn Fn(&ResponseInfo<'_>) + Send + Sync>);
+
+impl ResponseHook {
+    /// Calls the hook with the status and ratelimit headers of a response.
+    pub(super) fn call(&self, method: LightMethod, route: Route<'_>, response: &Response) {
+        (self.0)(&ResponseInfo {
+            method,
+            route,
+            status: response.status(),
+            ratelimit: RatelimitHeaders::from_headers(response.headers()),
+        });
+    }
+}
+
+impl fmt::Debug for ResponseHook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Fn(&ResponseInfo)")
+    }
+}
+
+impl RatelimitScope {
+    fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        match headers.get("x-ratelimit-scope")?.as_bytes() {
+            b"user" => Some(Self::User),
+            b"global" => Some(Self::Global),
+            b"shared" => Some(Self::Shared),
+            _ => None,
+        }
+    }
 }
 
 /// Ratelimiter for requests to the Discord API.
@@ -90,6 +201,7 @@ pub struct Ratelimiter {
     token: SecretString,
     absolute_ratelimits: bool,
     ratelimit_callback: Box<dyn Fn(RatelimitInfo) + Send + Sync>,
+    response_hook: Option<ResponseHook>,
 }
 
 impl fmt::Debug for Ratelimiter {
@@ -101,6 +213,7 @@ impl fmt::Debug for Ratelimiter {
             .field("token", &self.token)
             .field("absolute_ratelimits", &self.absolute_ratelimits)
             .field("ratelimit_callback", &"Fn(RatelimitInfo)")
+            .field("response_hook", &self.response_hook)
             .finish()
     }
 }
@@ -122,6 +235,7 @@ impl Ratelimiter {
             token: SecretString::new(token),
             ratelimit_callback: Box::new(|_| {}),
             absolute_ratelimits: false,
+            response_hook: None,
         }
     }
 
@@ -138,6 +252,11 @@ impl Ratelimiter {
         self.absolute_ratelimits = absolute_ratelimits;
     }
 
+    /// Sets the hook called with every response, including ratelimited ones which are retried.
+    pub(super) fn set_response_hook(&mut self, hook: ResponseHook) {
+        self.response_hook = Some(hook);
+    }
+
     /// The routes mutex is a HashMap of each [`RatelimitingBucket`] and their respective ratelimit
     /// information.
     ///
@@ -180,6 +299,11 @@ impl Ratelimiter {
     /// Only error kind that may be returned is [`Error::Http`].
     #[instrument]
     pub async fn perform(&self, req: Request<'_>) -> Result<Response> {
+        self.perform_ref(&req).await
+    }
+
+    /// Performs a request like [`Self::perform`], leaving it to the caller to retry it later.
+    pub(super) async fn perform_ref(&self, req: &Request<'_>) -> Result<Response> {
         loop {
             // This will block if another thread hit the global ratelimit.
             drop(self.global.lock().await);
@@ -194,10 +318,13 @@ impl Ratelimiter {
             let bucket =
                 Arc::clone(self.routes.write().await.entry(ratelimiting_bucket).or_default());
 
-            bucket.lock().await.pre_hook(&req, &self.ratelimit_callback).await;
+            bucket.lock().await.pre_hook(req, &self.ratelimit_callback).await;
 
             let request = req.clone().build(&self.client, self.token.expose_secret(), None)?;
             let response = self.client.execute(request.build()?).await?;
+            if let Some(hook) = &self.response_hook {
+                hook.call(req.method, req.route, &response);
+            }
 
             // Check if the request got ratelimited by checking for status 429, and if so, sleep
             // for the value of the header 'retry-after' - which is in milliseconds - and then
@@ -232,6 +359,8 @@ impl Ratelimiter {
                             method: req.method,
                             path: req.route.path().to_string(),
                             global: true,
+                            scope: Some(RatelimitScope::Global),
+                            preemptive: false,
                         });
                         sleep(Duration::from_secs_f64(retry_after)).await;
 
@@ -244,7 +373,7 @@ impl Ratelimiter {
                 bucket
                     .lock()
                     .await
-                    .post_hook(&response, &req, &self.ratelimit_callback, self.absolute_ratelimits)
+                    .post_hook(&response, req, &self.ratelimit_callback, self.absolute_ratelimits)
                     .await
             };
 
@@ -313,6 +442,8 @@ impl Ratelimit {
                 method: req.method,
                 path: req.route.path().to_string(),
                 global: false,
+                scope: None,
+                preemptive: true,
             });
 
             sleep(delay).await;
@@ -369,6 +500,8 @@ impl Ratelimit {
                 method: req.method,
                 path: req.route.path().to_string(),
                 global: false,
+                scope: RatelimitScope::from_headers(response.headers()),
+                preemptive: false,
             });
 
             sleep(Duration::from_secs_f64(retry_after)).await;
@@ -434,12 +567,17 @@ fn parse_header<T: FromStr>(headers: &HeaderMap, header: &str) -> Result<Option<
 mod tests {
     use std::error::Error as StdError;
     use std::result::Result as StdResult;
+    use std::sync::Mutex as StdMutex;
+    use std::time::{Duration, SystemTime};
 
     use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
 
-    use super::parse_header;
+    use super::{parse_header, Ratelimit, RatelimitInfo, RatelimitScope};
     use crate::error::Error;
-    use crate::http::HttpError;
+    use crate::http::{HttpError, LightMethod, Request, Route};
+    use crate::model::id::ChannelId;
 
     type Result<T> = StdResult<T, Box<dyn StdError>>;
 
@@ -492,4 +630,48 @@ mod tests {
             Error::Http(HttpError::RateLimitUtf8)
         ));
     }
+
+    #[tokio::test]
+    async fn test_ratelimit_callback() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut request = Vec::new();
+            let mut buf = [0; 1024];
+            while !request.ends_with(b"\r\n\r\n") {
+                let n = stream.read(&mut buf).await.unwrap();
+                request.extend_from_slice(&buf[..n]);
+            }
+            let response = b"HTTP/1.1 429 Too Many Requests\r\nx-ratelimit-limit: 5\r\n\
+                x-ratelimit-remaining: 0\r\nx-ratelimit-reset-after: 0.01\r\n\
+                x-ratelimit-scope: shared\r\nretry-after: 0.01\r\nContent-Length: 2\r\n\
+                Connection: close\r\n\r\n{}";
+            stream.write_all(response).await.unwrap();
+        });
+
+        let infos = StdMutex::new(Vec::new());
+        let callback = |info: RatelimitInfo| infos.lock().unwrap().push(info);
+        let req = Request::new(
+            Route::Channel {
+                channel_id: ChannelId::new(1),
+            },
+            LightMethod::Get,
+        );
+        let mut ratelimit = Ratelimit::default();
+
+        let response = reqwest::get(format!("http://{addr}")).await.unwrap();
+        assert!(ratelimit.post_hook(&response, &req, &callback, false).await.unwrap());
+        ratelimit.reset = Some(SystemTime::now() + Duration::from_millis(10));
+        ratelimit.pre_hook(&req, &callback).await;
+
+        let infos = infos.into_inner().unwrap();
+        assert_eq!(infos.len(), 2);
+        assert_eq!(infos[0].scope, Some(RatelimitScope::Shared));
+        assert_eq!(infos[0].limit, 5);
+        assert!(!infos[0].preemptive);
+        assert_eq!(infos[1].scope, None);
+        assert!(infos[1].preemptive);
+        assert!(infos[1].path.ends_with("/channels/1"));
+    }
 }
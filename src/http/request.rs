@@ -1,9 +1,13 @@
 use std::fmt::Write;
+use std::io::Write as _;
 
+use flate2::write::GzEncoder;
+use flate2::Compression;
 use reqwest::header::{
     HeaderMap as Headers,
     HeaderValue,
     AUTHORIZATION,
+    CONTENT_ENCODING,
     CONTENT_LENGTH,
     CONTENT_TYPE,
     USER_AGENT,
@@ -29,6 +33,7 @@ pub struct Request<'a> {
     pub(super) method: LightMethod,
     pub(super) route: Route<'a>,
     pub(super) params: Option<Vec<(&'static str, String)>>,
+    pub(super) retry_non_idempotent: bool,
 }
 
 impl<'a> Request<'a> {
@@ -40,6 +45,7 @@ impl<'a> Request<'a> {
             method,
             route,
             params: None,
+            retry_non_idempotent: false,
         }
     }
 
@@ -63,6 +69,49 @@ impl<'a> Request<'a> {
         self
     }
 
+    /// Allows this request to be retried according to the [`RetryPolicy`] even though its method
+    /// is not idempotent, such as `POST` and `PATCH`.
+    ///
+    /// Only enable this if sending the request twice has the same effect as sending it once.
+    ///
+    /// [`RetryPolicy`]: super::RetryPolicy
+    pub fn retry_non_idempotent(mut self, retry_non_idempotent: bool) -> Self {
+        self.retry_non_idempotent = retry_non_idempotent;
+        self
+    }
+
+    /// Whether this request may be retried after a transient failure.
+    pub(super) fn is_retryable(&self) -> bool {
+        self.retry_non_idempotent
+            || matches!(self.method, LightMethod::Get | LightMethod::Put | LightMethod::Delete)
+    }
+
+    /// Returns a copy of this request with its JSON body gzip-compressed, if the body is at least
+    /// `threshold` bytes long.
+    pub(super) fn gzipped(&self, threshold: usize) -> Option<Self> {
+        if self.multipart.is_some() {
+            return None;
+        }
+        let body = self.body.as_deref().filter(|body| body.len() >= threshold)?;
+
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(body).ok()?;
+        let body = encoder.finish().ok()?;
+
+        let mut headers = self.headers.clone().unwrap_or_default();
+        headers.insert(CONTENT_ENCODING, HeaderValue::from_static("gzip"));
+
+        Some(Self {
+            body: Some(body),
+            multipart: None,
+            headers: Some(headers),
+            method: self.method,
+            route: self.route,
+            params: self.params.clone(),
+            retry_non_idempotent: self.retry_non_idempotent,
+        })
+    }
+
     #[instrument(skip(token))]
     pub fn build(
         self,
@@ -89,8 +138,12 @@ impl<'a> Request<'a> {
 
         let mut headers = self.headers.unwrap_or_default();
         headers.insert(USER_AGENT, HeaderValue::from_static(constants::USER_AGENT));
-        headers
-            .insert(AUTHORIZATION, HeaderValue::from_str(token).map_err(HttpError::InvalidHeader)?);
+        if !token.is_empty() {
+            headers.insert(
+                AUTHORIZATION,
+                HeaderValue::from_str(token).map_err(HttpError::InvalidHeader)?,
+            );
+        }
 
         if let Some(multipart) = self.multipart {
             // Setting multipart adds the content-length header.
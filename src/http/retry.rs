@@ -0,0 +1,191 @@
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+use reqwest::StatusCode;
+
+use super::HttpError;
+
+bitflags! {
+    /// The failures after which a [`RetryPolicy`] retries a request.
+    #[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+    pub struct RetryOn: u8 {
+        /// `500 Internal Server Error`, `502 Bad Gateway`, `503 Service Unavailable` and
+        /// `504 Gateway Timeout` responses.
+        const SERVER_ERRORS = 1 << 0;
+        /// Failures to connect to Discord or to send the request, including timeouts.
+        const CONNECTION_ERRORS = 1 << 1;
+    }
+}
+
+impl RetryOn {
+    /// Whether a request which failed with the given error is retried.
+    #[must_use]
+    pub fn matches(self, error: &HttpError) -> bool {
+        match error {
+            HttpError::UnsuccessfulRequest(response) => {
+                self.contains(Self::SERVER_ERRORS)
+                    && matches!(
+                        response.status_code,
+                        StatusCode::INTERNAL_SERVER_ERROR
+                            | StatusCode::BAD_GATEWAY
+                            | StatusCode::SERVICE_UNAVAILABLE
+                            | StatusCode::GATEWAY_TIMEOUT
+                    )
+            },
+            HttpError::Request(why) => {
+                self.contains(Self::CONNECTION_ERRORS)
+                    && (why.is_connect() || why.is_timeout() || why.is_request())
+            },
+            _ => false,
+        }
+    }
+}
+
+/// Exponential backoff between the attempts of a request.
+///
+/// The delay before the first retry is [`Self::initial`], and doubles for every further retry,
+/// up to [`Self::max`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Backoff {
+    /// The delay before the first retry.
+    pub initial: Duration,
+    /// The maximum delay between two attempts.
+    pub max: Duration,
+    /// Whether to randomly shorten each delay by up to half, so that requests failing at the
+    /// same time are not all retried at the same time.
+    pub jitter: bool,
+}
+
+impl Backoff {
+    /// Returns the delay before the given retry, starting at 1.
+    #[must_use]
+    pub fn delay(&self, retry: u32) -> Duration {
+        let factor = 1_u32 << retry.saturating_sub(1).min(16);
+        let delay = self.initial.saturating_mul(factor).min(self.max);
+
+        if self.jitter {
+            let half = delay / 2;
+            let random = RandomState::new().build_hasher().finish();
+            half + Duration::from_nanos(random % (half.as_nanos() as u64 + 1))
+        } else {
+            delay
+        }
+    }
+}
+
+impl Default for Backoff {
+    /// Starts at half a second and is capped at 8 seconds, with jitter.
+    fn default() -> Self {
+        Self {
+            initial: Duration::from_millis(500),
+            max: Duration::from_secs(8),
+            jitter: true,
+        }
+    }
+}
+
+/// Configures how [`Http`] retries requests that failed due to transient errors, see
+/// [`HttpBuilder::retry_policy`].
+///
+/// Only requests with an idempotent method, `GET`, `PUT` and `DELETE`, are retried. Other
+/// requests are only retried if allowed via [`Request::retry_non_idempotent`]. Every attempt
+/// goes through the ratelimiter again.
+///
+/// If a request still fails after [`Self::max_retries`] retries, [`HttpError::RetriesExhausted`]
+/// is returned with the last error.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use serenity::http::{Backoff, HttpBuilder, RetryOn, RetryPolicy};
+///
+/// let http = HttpBuilder::new("token")
+///     .retry_policy(RetryPolicy {
+///         max_retries: 3,
+///         backoff: Backoff {
+///             initial: Duration::from_secs(1),
+///             ..Default::default()
+///         },
+///         retry_on: RetryOn::all(),
+///     })
+///     .build();
+/// ```
+///
+/// [`Http`]: super::Http
+/// [`HttpBuilder::retry_policy`]: super::HttpBuilder::retry_policy
+/// [`Request::retry_non_idempotent`]: super::Request::retry_non_idempotent
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct RetryPolicy {
+    /// The maximum number of times a request is retried after the first attempt.
+    pub max_retries: u32,
+    /// The delays between attempts.
+    pub backoff: Backoff,
+    /// The failures after which requests are retried.
+    pub retry_on: RetryOn,
+}
+
+impl Default for RetryPolicy {
+    /// Retries twice on server and connection errors, with the default [`Backoff`].
+    fn default() -> Self {
+        Self {
+            max_retries: 2,
+            backoff: Backoff::default(),
+            retry_on: RetryOn::all(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::Method;
+
+    use super::*;
+    use crate::http::{DiscordJsonError, ErrorResponse};
+
+    fn unsuccessful(status_code: StatusCode) -> HttpError {
+        HttpError::UnsuccessfulRequest(ErrorResponse {
+            status_code,
+            url: String::from("https://discord.com/api/v10/gateway"),
+            method: Method::GET,
+            error: DiscordJsonError {
+                code: 0,
+                message: String::new(),
+                errors: vec![],
+            },
+        })
+    }
+
+    #[test]
+    fn retry_on() {
+        for status in [StatusCode::BAD_GATEWAY, StatusCode::GATEWAY_TIMEOUT] {
+            assert!(RetryOn::SERVER_ERRORS.matches(&unsuccessful(status)));
+            assert!(!RetryOn::CONNECTION_ERRORS.matches(&unsuccessful(status)));
+        }
+        assert!(!RetryOn::all().matches(&unsuccessful(StatusCode::NOT_FOUND)));
+        assert!(!RetryOn::all().matches(&unsuccessful(StatusCode::TOO_MANY_REQUESTS)));
+        assert!(!RetryOn::all().matches(&HttpError::InvalidWebhook));
+    }
+
+    #[test]
+    fn backoff_delays() {
+        let mut backoff = Backoff {
+            initial: Duration::from_millis(100),
+            max: Duration::from_millis(350),
+            jitter: false,
+        };
+        assert_eq!(backoff.delay(1), Duration::from_millis(100));
+        assert_eq!(backoff.delay(2), Duration::from_millis(200));
+        assert_eq!(backoff.delay(3), Duration::from_millis(350));
+        assert_eq!(backoff.delay(u32::MAX), Duration::from_millis(350));
+
+        backoff.jitter = true;
+        for (retry, max) in [(1, 100), (2, 200), (3, 350)] {
+            let max = Duration::from_millis(max);
+            let delay = backoff.delay(retry);
+            assert!(delay >= max / 2 && delay <= max);
+        }
+    }
+}
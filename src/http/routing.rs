@@ -60,14 +60,7 @@ macro_rules! routes {
                     )+
                 };
 
-                // This avoids adding a lifetime on RatelimitingBucket and causing lifetime infection
-                // SAFETY: std::mem::discriminant erases lifetimes.
-                let discriminant = unsafe {
-                    std::mem::transmute::<Discriminant<Route<'a>>, Discriminant<Route<'static>>>(
-                        std::mem::discriminant(self),
-                    )
-                };
-
+                let discriminant = self.kind();
                 RatelimitingBucket(ratelimiting_kind.map(|r| {
                     let id = match r {
                         RatelimitingKind::PathAndId(id) => Some(id),
@@ -77,6 +70,17 @@ macro_rules! routes {
                 }))
             }
 
+            /// Identifies the endpoint of this route, regardless of its parameters.
+            pub(crate) fn kind(&self) -> Discriminant<Route<'static>> {
+                // This avoids adding a lifetime on RatelimitingBucket and causing lifetime infection
+                // SAFETY: std::mem::discriminant erases lifetimes.
+                unsafe {
+                    std::mem::transmute::<Discriminant<Route<'a>>, Discriminant<Route<'static>>>(
+                        std::mem::discriminant(self),
+                    )
+                }
+            }
+
         }
     };
 }
@@ -198,6 +202,10 @@ routes! ('a, {
     api!("/channels/{}/voice-status", channel_id),
     Some(RatelimitingKind::PathAndId(channel_id.into()));
 
+    ChannelSendSoundboardSound { channel_id: ChannelId },
+    api!("/channels/{}/send-soundboard-sound", channel_id),
+    Some(RatelimitingKind::PathAndId(channel_id.into()));
+
     Gateway,
     api!("/gateway"),
     Some(RatelimitingKind::Path);
@@ -242,6 +250,10 @@ routes! ('a, {
     api!("/guilds/{}/widget", guild_id),
     Some(RatelimitingKind::PathAndId(guild_id.into()));
 
+    GuildWidgetJson { guild_id: GuildId },
+    api!("/guilds/{}/widget.json", guild_id),
+    Some(RatelimitingKind::PathAndId(guild_id.into()));
+
     GuildPreview { guild_id: GuildId },
     api!("/guilds/{}/preview", guild_id),
     Some(RatelimitingKind::PathAndId(guild_id.into()));
@@ -322,6 +334,14 @@ routes! ('a, {
     api!("/guilds/{}/scheduled-events/{}/users", guild_id, event_id),
     Some(RatelimitingKind::PathAndId(guild_id.into()));
 
+    GuildSoundboardSound { guild_id: GuildId, sound_id: SoundId },
+    api!("/guilds/{}/soundboard-sounds/{}", guild_id, sound_id),
+    Some(RatelimitingKind::PathAndId(guild_id.into()));
+
+    GuildSoundboardSounds { guild_id: GuildId },
+    api!("/guilds/{}/soundboard-sounds", guild_id),
+    Some(RatelimitingKind::PathAndId(guild_id.into()));
+
     GuildSticker { guild_id: GuildId, sticker_id: StickerId },
     api!("/guilds/{}/stickers/{}", guild_id, sticker_id),
     Some(RatelimitingKind::PathAndId(guild_id.into()));
@@ -330,6 +350,14 @@ routes! ('a, {
     api!("/guilds/{}/stickers", guild_id),
     Some(RatelimitingKind::PathAndId(guild_id.into()));
 
+    GuildTemplate { guild_id: GuildId, code: &'a str },
+    api!("/guilds/{}/templates/{}", guild_id, code),
+    Some(RatelimitingKind::PathAndId(guild_id.into()));
+
+    GuildTemplates { guild_id: GuildId },
+    api!("/guilds/{}/templates", guild_id),
+    Some(RatelimitingKind::PathAndId(guild_id.into()));
+
     GuildVanityUrl { guild_id: GuildId },
     api!("/guilds/{}/vanity-url", guild_id),
     Some(RatelimitingKind::PathAndId(guild_id.into()));
@@ -346,6 +374,10 @@ routes! ('a, {
     api!("/guilds/{}/webhooks", guild_id),
     Some(RatelimitingKind::PathAndId(guild_id.into()));
 
+    GuildOnboarding { guild_id: GuildId },
+    api!("/guilds/{}/onboarding", guild_id),
+    Some(RatelimitingKind::PathAndId(guild_id.into()));
+
     GuildWelcomeScreen { guild_id: GuildId },
     api!("/guilds/{}/welcome-screen", guild_id),
     Some(RatelimitingKind::PathAndId(guild_id.into()));
@@ -358,6 +390,10 @@ routes! ('a, {
     api!("/guilds"),
     Some(RatelimitingKind::Path);
 
+    GuildsTemplate { code: &'a str },
+    api!("/guilds/templates/{}", code),
+    Some(RatelimitingKind::Path);
+
     Invite { code: &'a str },
     api!("/invites/{}", code),
     Some(RatelimitingKind::Path);
@@ -378,6 +414,10 @@ routes! ('a, {
     status!("/scheduled-maintenances/upcoming.json"),
     None;
 
+    SoundboardDefaultSounds,
+    api!("/soundboard-default-sounds"),
+    Some(RatelimitingKind::Path);
+
     Sticker { sticker_id: StickerId },
     api!("/stickers/{}", sticker_id),
     Some(RatelimitingKind::Path);
@@ -402,6 +442,10 @@ routes! ('a, {
     api!("/users/@me/connections"),
     Some(RatelimitingKind::Path);
 
+    UserMeApplicationRoleConnection { application_id: ApplicationId },
+    api!("/users/@me/applications/{}/role-connection", application_id),
+    Some(RatelimitingKind::PathAndId(application_id.into()));
+
     UserMeDmChannels,
     api!("/users/@me/channels"),
     Some(RatelimitingKind::Path);
@@ -458,6 +502,10 @@ routes! ('a, {
     api!("/applications/{}/commands", application_id),
     Some(RatelimitingKind::PathAndId(application_id.into()));
 
+    ApplicationRoleConnectionMetadata { application_id: ApplicationId },
+    api!("/applications/{}/role-connections/metadata", application_id),
+    Some(RatelimitingKind::PathAndId(application_id.into()));
+
     GuildCommand { application_id: ApplicationId, guild_id: GuildId, command_id: CommandId },
     api!("/applications/{}/guilds/{}/commands/{}", application_id, guild_id, command_id),
     Some(RatelimitingKind::PathAndId(application_id.into()));
@@ -490,6 +538,10 @@ routes! ('a, {
     api!("/applications/{}/entitlements/{}", application_id, entitlement_id),
     Some(RatelimitingKind::PathAndId(application_id.into()));
 
+    EntitlementConsume { application_id: ApplicationId, entitlement_id: EntitlementId },
+    api!("/applications/{}/entitlements/{}/consume", application_id, entitlement_id),
+    Some(RatelimitingKind::PathAndId(application_id.into()));
+
     Entitlements { application_id: ApplicationId },
     api!("/applications/{}/entitlements", application_id),
     Some(RatelimitingKind::PathAndId(application_id.into()));
@@ -0,0 +1,198 @@
+//! Utilities for testing code which makes HTTP requests, without a connection to Discord.
+//!
+//! # Examples
+//!
+//! Check that a message is deleted from the right channel:
+//!
+//! ```rust
+//! # async fn run() -> serenity::Result<()> {
+//! use std::sync::Arc;
+//!
+//! use serenity::http::testing::MockTransport;
+//! use serenity::http::{HttpBuilder, LightMethod, Route};
+//! use serenity::model::id::{ChannelId, MessageId};
+//!
+//! let mock = Arc::new(MockTransport::new());
+//! let http = HttpBuilder::new("token").transport(mock.clone()).build();
+//! mock.push_no_content();
+//!
+//! let (channel_id, message_id) = (ChannelId::new(1), MessageId::new(2));
+//! channel_id.delete_message(&http, message_id).await?;
+//!
+//! let requests = mock.requests();
+//! assert_eq!(requests[0].method, LightMethod::Delete);
+//! assert_eq!(requests[0].path, Route::ChannelMessage { channel_id, message_id }.path());
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use reqwest::header::{HeaderMap as Headers, CONTENT_TYPE};
+use reqwest::{Response, StatusCode};
+use serde::Serialize;
+
+use super::{LightMethod, Request, Transport};
+use crate::internal::prelude::*;
+
+/// A request which was sent through a [`MockTransport`].
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct RecordedRequest {
+    /// The method of the request.
+    pub method: LightMethod,
+    /// The full URL of the route, without query parameters.
+    pub path: String,
+    /// The query parameters of the request.
+    pub params: Vec<(&'static str, String)>,
+    /// The headers of the request, such as the audit log reason.
+    pub headers: Option<Headers>,
+    /// The JSON body of the request, if any. Multipart bodies are not recorded.
+    pub body: Option<Vec<u8>>,
+}
+
+impl RecordedRequest {
+    /// Parses the body of the request as JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Json`] if the body is not valid JSON.
+    pub fn body_json(&self) -> Result<Option<Value>> {
+        self.body.as_deref().map(crate::json::from_slice).transpose()
+    }
+}
+
+#[derive(Debug)]
+struct MockResponse {
+    status: StatusCode,
+    body: Vec<u8>,
+}
+
+/// A [`Transport`] which records each request and answers it with a queued response, in the order
+/// they were pushed.
+///
+/// Install it with [`HttpBuilder::transport`], keeping a clone of the [`Arc`] to push responses
+/// and inspect the requests that were made.
+///
+/// **Note**: Receiving a request while no response is queued causes a panic, to point out
+/// unexpected requests in tests.
+///
+/// [`HttpBuilder::transport`]: super::HttpBuilder::transport
+/// [`Arc`]: std::sync::Arc
+#[derive(Debug, Default)]
+pub struct MockTransport {
+    responses: Mutex<VecDeque<MockResponse>>,
+    requests: Mutex<Vec<RecordedRequest>>,
+}
+
+impl MockTransport {
+    /// Creates a transport without any queued responses.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queues a response with the given status code and raw body.
+    pub fn push_response(&self, status: StatusCode, body: impl Into<Vec<u8>>) {
+        let response = MockResponse {
+            status,
+            body: body.into(),
+        };
+        self.responses.lock().expect("poisoned").push_back(response);
+    }
+
+    /// Queues a `200 OK` response with the given value, serialized as JSON, as its body.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value can't be serialized.
+    pub fn push_json(&self, value: impl Serialize) {
+        let body = crate::json::to_vec(&value).expect("response body should be serializable");
+        self.push_response(StatusCode::OK, body);
+    }
+
+    /// Queues an empty `204 No Content` response, as returned by e.g. deleting a message.
+    pub fn push_no_content(&self) {
+        self.push_response(StatusCode::NO_CONTENT, Vec::new());
+    }
+
+    /// Returns the requests received so far, oldest first.
+    #[must_use]
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.requests.lock().expect("poisoned").clone()
+    }
+
+    /// Returns the number of queued responses which have not been used yet.
+    #[must_use]
+    pub fn remaining_responses(&self) -> usize {
+        self.responses.lock().expect("poisoned").len()
+    }
+}
+
+#[async_trait]
+impl Transport for MockTransport {
+    async fn request(&self, req: Request<'_>) -> Result<Response> {
+        let recorded = RecordedRequest {
+            method: *req.method_ref(),
+            path: req.route_ref().path().into_owned(),
+            params: req.params_ref().map(<[_]>::to_vec).unwrap_or_default(),
+            headers: req.headers_ref().clone(),
+            body: req.body_ref().map(<[u8]>::to_vec),
+        };
+
+        let Some(response) = self.responses.lock().expect("poisoned").pop_front() else {
+            panic!("No response queued for {:?} {}", recorded.method, recorded.path);
+        };
+        self.requests.lock().expect("poisoned").push(recorded);
+
+        let response = http_crate::Response::builder()
+            .status(response.status)
+            .header(CONTENT_TYPE, "application/json")
+            .body(response.body)
+            .expect("valid response");
+        Ok(Response::from(response))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::http::{HttpBuilder, HttpError, Route};
+    use crate::model::id::{ChannelId, MessageId};
+
+    #[tokio::test]
+    async fn records_requests() {
+        let mock = Arc::new(MockTransport::new());
+        let http = HttpBuilder::new("token").transport(mock.clone()).build();
+
+        mock.push_no_content();
+        mock.push_response(StatusCode::NOT_FOUND, &br#"{"code": 10008, "message": "Unknown"}"#[..]);
+
+        let channel_id = ChannelId::new(1);
+        let message_id = MessageId::new(2);
+        http.delete_message(channel_id, message_id, Some("cleanup")).await.unwrap();
+        let error = http.delete_message(channel_id, message_id, None).await.unwrap_err();
+        assert!(matches!(
+            error,
+            Error::Http(HttpError::UnsuccessfulRequest(response)) if response.error.code == 10008
+        ));
+
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].method, LightMethod::Delete);
+        assert_eq!(
+            requests[0].path,
+            Route::ChannelMessage {
+                channel_id,
+                message_id,
+            }
+            .path()
+        );
+        assert!(requests[0].headers.as_ref().unwrap().contains_key("X-Audit-Log-Reason"));
+        assert_eq!(mock.remaining_responses(), 0);
+    }
+}
@@ -0,0 +1,36 @@
+use std::fmt;
+
+use async_trait::async_trait;
+use reqwest::Response;
+
+use super::Request;
+use crate::internal::prelude::*;
+
+/// Sends the requests made by [`Http`] in place of the built-in [`reqwest::Client`].
+///
+/// A transport can be installed with [`HttpBuilder::transport`]. It receives every request
+/// before the ratelimiter and [proxy] would be applied, so it is responsible for both if they are
+/// needed. Unsuccessful responses are turned into errors by [`Http`] as usual.
+///
+/// This is mainly useful for testing code which uses [`Http`] without a connection to Discord.
+///
+/// [`Http`]: super::Http
+/// [`HttpBuilder::transport`]: super::HttpBuilder::transport
+/// [proxy]: super::HttpBuilder::proxy
+#[cfg_attr(
+    feature = "testing",
+    doc = "See [`MockTransport`](super::testing::MockTransport) for a ready-made implementation."
+)]
+#[cfg_attr(
+    not(feature = "testing"),
+    doc = "The `testing` feature provides a ready-made implementation, `MockTransport`."
+)]
+#[async_trait]
+pub trait Transport: fmt::Debug + Send + Sync {
+    /// Sends the request and returns the response.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request could not be sent.
+    async fn request(&self, req: Request<'_>) -> Result<Response>;
+}
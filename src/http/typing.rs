@@ -1,7 +1,8 @@
 use std::sync::Arc;
 
-use tokio::sync::oneshot::error::TryRecvError;
+use reqwest::StatusCode;
 use tokio::sync::oneshot::{self, Sender};
+use tokio::task::JoinHandle;
 use tokio::time::{sleep, Duration};
 
 use crate::http::Http;
@@ -46,7 +47,10 @@ use crate::model::id::ChannelId;
 ///
 /// [`Channel`]: crate::model::channel::Channel
 #[derive(Debug)]
-pub struct Typing(Sender<()>);
+pub struct Typing {
+    stop: Sender<()>,
+    task: JoinHandle<Result<()>>,
+}
 
 impl Typing {
     /// Starts typing in the specified [`Channel`] for an indefinite period of time.
@@ -59,28 +63,42 @@ impl Typing {
     ///
     /// Returns an  [`Error::Http`] if there is an error.
     ///
+    /// If the channel is not found when typing is first triggered, e.g. because it is a thread
+    /// that was only just created, triggering is retried once after a second.
+    ///
     /// [`Channel`]: crate::model::channel::Channel
     pub fn start(http: Arc<Http>, channel_id: ChannelId) -> Self {
         let (sx, mut rx) = oneshot::channel();
 
-        spawn_named::<_, Result<_>>("typing::start", async move {
+        let task = spawn_named::<_, Result<_>>("typing::start", async move {
+            let mut first_trigger = true;
             loop {
-                match rx.try_recv() {
-                    Ok(()) | Err(TryRecvError::Closed) => break,
-                    _ => (),
-                }
-
-                http.broadcast_typing(channel_id).await?;
+                let delay = match http.broadcast_typing(channel_id).await {
+                    Err(Error::Http(why))
+                        if first_trigger && why.status_code() == Some(StatusCode::NOT_FOUND) =>
+                    {
+                        Duration::from_secs(1)
+                    },
+                    // It is unclear for how long typing persists after this method is called.
+                    // It is generally assumed to be 7 or 10 seconds, so we use 7 to be safe.
+                    result => result.map(|()| Duration::from_secs(7))?,
+                };
+                first_trigger = false;
 
-                // It is unclear for how long typing persists after this method is called.
-                // It is generally assumed to be 7 or 10 seconds, so we use 7 to be safe.
-                sleep(Duration::from_secs(7)).await;
+                // Stops when either stopped explicitly or the `Typing` is dropped.
+                tokio::select! {
+                    _ = &mut rx => break,
+                    () = sleep(delay) => {},
+                }
             }
 
             Ok(())
         });
 
-        Self(sx)
+        Self {
+            stop: sx,
+            task,
+        }
     }
 
     /// Stops typing in [`Channel`].
@@ -92,6 +110,74 @@ impl Typing {
     /// [`Channel`]: crate::model::channel::Channel
     #[allow(clippy::must_use_candidate)]
     pub fn stop(self) -> bool {
-        self.0.send(()).is_ok()
+        self.stop.send(()).is_ok()
+    }
+
+    /// Stops typing in [`Channel`] like [`Typing::stop`], and waits until typing is no longer
+    /// being triggered.
+    ///
+    /// # Errors
+    ///
+    /// Returns the [`Error::Http`] which stopped typing early, e.g. due to missing permissions.
+    ///
+    /// # Panics
+    ///
+    /// Resumes the panic of the typing task, if it panicked.
+    ///
+    /// [`Channel`]: crate::model::channel::Channel
+    pub async fn stop_and_wait(self) -> Result<()> {
+        // An error only means that the task has already ended.
+        self.stop.send(()).ok();
+        match self.task.await {
+            Ok(result) => result,
+            Err(why) if why.is_panic() => std::panic::resume_unwind(why.into_panic()),
+            // The task was cancelled, e.g. because the runtime is shutting down.
+            Err(_) => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    use super::*;
+    use crate::http::HttpBuilder;
+
+    #[tokio::test]
+    async fn retries_not_found_once() {
+        // Responds 404 to the first request and 204 to all others.
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let requests = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&requests);
+        tokio::spawn(async move {
+            loop {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                let mut request = Vec::new();
+                let mut buf = [0; 1024];
+                while !request.ends_with(b"\r\n\r\n") {
+                    let n = stream.read(&mut buf).await.unwrap();
+                    request.extend_from_slice(&buf[..n]);
+                }
+                let response: &[u8] = if counter.fetch_add(1, Ordering::SeqCst) == 0 {
+                    b"HTTP/1.1 404 Not Found\r\nContent-Length: 2\r\nConnection: close\r\n\r\n{}"
+                } else {
+                    b"HTTP/1.1 204 No Content\r\nConnection: close\r\n\r\n"
+                };
+                stream.write_all(response).await.unwrap();
+            }
+        });
+
+        let http =
+            HttpBuilder::new("").proxy(format!("http://{addr}")).ratelimiter_disabled(true).build();
+        let typing = Typing::start(Arc::new(http), ChannelId::new(1));
+
+        sleep(Duration::from_millis(1500)).await;
+        typing.stop_and_wait().await.unwrap();
+        assert_eq!(requests.load(Ordering::SeqCst), 2);
     }
 }
@@ -7,6 +7,8 @@
 //!
 //! See [`Verifier`] for example usage.
 
+use std::sync::{Arc, PoisonError, RwLock};
+
 /// Parses a hex string into an array of `[u8]`
 fn parse_hex<const N: usize>(s: &str) -> Option<[u8; N]> {
     if s.len() != N * 2 {
@@ -53,9 +55,14 @@ impl std::error::Error for InvalidKey {
 ///     // Send HTTP 401 Unauthorized response
 /// }
 /// ```
+///
+/// A verifier may hold multiple public keys, e.g. when one endpoint serves multiple applications
+/// or while a key is being rotated. Requests are accepted if they were signed with any of the
+/// keys. Clones of a verifier share their keys, so keys added or removed through
+/// [`Self::add_key`] and [`Self::remove_key`] apply to all clones.
 #[derive(Clone)]
 pub struct Verifier {
-    public_key: ed25519_dalek::VerifyingKey,
+    public_keys: Arc<RwLock<Vec<ed25519_dalek::VerifyingKey>>>,
 }
 
 impl Verifier {
@@ -75,13 +82,69 @@ impl Verifier {
     ///
     /// [`InvalidKey`] if the key isn't cryptographically valid.
     pub fn try_new(public_key: [u8; 32]) -> Result<Self, InvalidKey> {
-        Ok(Self {
-            public_key: ed25519_dalek::VerifyingKey::from_bytes(&public_key).map_err(InvalidKey)?,
-        })
+        Ok(Self::from_keys(vec![parse_key(public_key)?]))
+    }
+
+    /// Creates a new [`Verifier`] accepting requests signed with any of the given public key hex
+    /// strings.
+    ///
+    /// Panics if any of the given keys is invalid. For a low-level, non-panicking variant, see
+    /// [`Self::try_new_multi()`].
+    #[must_use]
+    pub fn new_multi(public_keys: &[&str]) -> Self {
+        let public_keys = public_keys.iter().map(|public_key| {
+            parse_hex(public_key).expect("public key must be a 64 digit hex string")
+        });
+        Self::try_new_multi(public_keys).expect("invalid public key")
+    }
+
+    /// Creates a new [`Verifier`] accepting requests signed with any of the given public keys.
+    ///
+    /// # Errors
+    ///
+    /// [`InvalidKey`] if any of the keys isn't cryptographically valid.
+    pub fn try_new_multi(
+        public_keys: impl IntoIterator<Item = [u8; 32]>,
+    ) -> Result<Self, InvalidKey> {
+        let public_keys = public_keys.into_iter().map(parse_key).collect::<Result<_, _>>()?;
+        Ok(Self::from_keys(public_keys))
+    }
+
+    fn from_keys(public_keys: Vec<ed25519_dalek::VerifyingKey>) -> Self {
+        Self {
+            public_keys: Arc::new(RwLock::new(public_keys)),
+        }
+    }
+
+    /// Adds a public key to accept requests for. Adding a key that is already present does
+    /// nothing.
+    ///
+    /// # Errors
+    ///
+    /// [`InvalidKey`] if the key isn't cryptographically valid.
+    pub fn add_key(&self, public_key: [u8; 32]) -> Result<(), InvalidKey> {
+        let public_key = parse_key(public_key)?;
+        let mut public_keys = self.public_keys.write().unwrap_or_else(PoisonError::into_inner);
+        if !public_keys.contains(&public_key) {
+            public_keys.push(public_key);
+        }
+        Ok(())
+    }
+
+    /// Removes a public key, so requests signed with it are no longer accepted. Returns whether
+    /// the key was present.
+    pub fn remove_key(&self, public_key: [u8; 32]) -> bool {
+        let mut public_keys = self.public_keys.write().unwrap_or_else(PoisonError::into_inner);
+        let len = public_keys.len();
+        public_keys.retain(|key| key.as_bytes() != &public_key);
+        public_keys.len() != len
     }
 
     /// Verifies a Discord request for authenticity, given the `X-Signature-Ed25519` HTTP header,
     /// `X-Signature-Timestamp` HTTP headers and request body.
+    ///
+    /// The signature is checked against every key, even after a match, so the time taken does
+    /// not reveal which key signed the request.
     // We just need to differentiate "pass" and "failure". There's deliberately no data besides ().
     #[allow(clippy::result_unit_err, clippy::missing_errors_doc)]
     pub fn verify(&self, signature: &str, timestamp: &str, body: &[u8]) -> Result<(), ()> {
@@ -93,10 +156,22 @@ impl Verifier {
 
         // Verify
         let message_to_verify = [timestamp.as_bytes(), body].concat();
-        self.public_key.verify(&message_to_verify, &signature).map_err(|_| ())
+        let public_keys = self.public_keys.read().unwrap_or_else(PoisonError::into_inner);
+        let verified = public_keys.iter().fold(false, |verified, public_key| {
+            public_key.verify(&message_to_verify, &signature).is_ok() | verified
+        });
+        if verified {
+            Ok(())
+        } else {
+            Err(())
+        }
     }
 }
 
+fn parse_key(public_key: [u8; 32]) -> Result<ed25519_dalek::VerifyingKey, InvalidKey> {
+    ed25519_dalek::VerifyingKey::from_bytes(&public_key).map_err(InvalidKey)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -117,4 +192,40 @@ mod tests {
             ])
         );
     }
+
+    fn sign(secret_key: [u8; 32], timestamp: &str, body: &[u8]) -> String {
+        use std::fmt::Write as _;
+
+        use ed25519_dalek::Signer as _;
+
+        let signing_key = ed25519_dalek::SigningKey::from_bytes(&secret_key);
+        let signature = signing_key.sign(&[timestamp.as_bytes(), body].concat());
+        signature.to_bytes().iter().fold(String::new(), |mut hex, byte| {
+            write!(hex, "{byte:02x}").unwrap();
+            hex
+        })
+    }
+
+    fn public_key(secret_key: [u8; 32]) -> [u8; 32] {
+        ed25519_dalek::SigningKey::from_bytes(&secret_key).verifying_key().to_bytes()
+    }
+
+    #[test]
+    fn test_multiple_keys() {
+        let (first, second, third) = ([1; 32], [2; 32], [3; 32]);
+        let verifier = Verifier::try_new_multi([public_key(first), public_key(second)]).unwrap();
+
+        let body = br#"{"type":1}"#;
+        assert!(verifier.verify(&sign(first, "1700000000", body), "1700000000", body).is_ok());
+        assert!(verifier.verify(&sign(second, "1700000000", body), "1700000000", body).is_ok());
+        assert!(verifier.verify(&sign(third, "1700000000", body), "1700000000", body).is_err());
+        assert!(verifier.verify(&sign(first, "1700000000", body), "1700000001", body).is_err());
+
+        let rotated = verifier.clone();
+        rotated.add_key(public_key(third)).unwrap();
+        assert!(rotated.remove_key(public_key(first)));
+        assert!(!rotated.remove_key(public_key(first)));
+        assert!(verifier.verify(&sign(first, "1700000000", body), "1700000000", body).is_err());
+        assert!(verifier.verify(&sign(third, "1700000000", body), "1700000000", body).is_ok());
+    }
 }
@@ -19,6 +19,7 @@ use crate::model::id::{
     RoleId,
     UserId,
 };
+use crate::model::mention::CommandMention;
 use crate::model::Permissions;
 
 /// The base command model that belongs to an application.
@@ -99,6 +100,38 @@ pub struct Command {
     pub version: CommandVersionId,
 }
 
+impl Command {
+    /// Creates a [`CommandMention`] of this command, which is displayed as `</name:id>`.
+    ///
+    /// **Note**: Commands with subcommands can't be mentioned themselves, use
+    /// [`Self::mention_subcommand`] instead.
+    #[must_use]
+    pub fn mention(&self) -> CommandMention {
+        self.id.mention_named(self.name.clone())
+    }
+
+    /// Creates a [`CommandMention`] of a subcommand of this command, given the names of the
+    /// subcommand group, if any, and the subcommand.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::model::application::Command;
+    /// # let command: Command = unimplemented!();
+    /// // Displayed as `</config channel set:id>`
+    /// let mention = command.mention_subcommand(&["channel", "set"]);
+    /// ```
+    #[must_use]
+    pub fn mention_subcommand(&self, path: &[&str]) -> CommandMention {
+        let mut name = self.name.clone();
+        for part in path {
+            name.push(' ');
+            name.push_str(part);
+        }
+        self.id.mention_named(name)
+    }
+}
+
 #[cfg(feature = "model")]
 impl Command {
     /// Create a global [`Command`], overriding an existing one with the same name if it exists.
@@ -172,6 +205,10 @@ impl Command {
         http: impl AsRef<Http>,
         commands: Vec<CreateCommand>,
     ) -> Result<Vec<Command>> {
+        for command in &commands {
+            command.validate()?;
+        }
+
         http.as_ref().create_global_commands(&commands).await
     }
 
@@ -412,6 +449,20 @@ impl CommandPermissionId {
     }
 }
 
+impl CommandId {
+    /// Creates a [`CommandMention`] of the command with the given name.
+    ///
+    /// To mention a subcommand, the name must be the command name followed by the names of the
+    /// subcommand group and subcommand, if any, separated by spaces.
+    #[must_use]
+    pub fn mention_named(self, name: impl Into<String>) -> CommandMention {
+        CommandMention {
+            id: self,
+            name: name.into(),
+        }
+    }
+}
+
 impl From<RoleId> for CommandPermissionId {
     fn from(id: RoleId) -> Self {
         Self::new(id.get())
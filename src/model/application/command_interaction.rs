@@ -4,6 +4,7 @@ use serde::de::{Deserializer, Error as DeError};
 use serde::ser::{Error as _, Serializer};
 use serde::{Deserialize, Serialize};
 
+use super::ResponseState;
 #[cfg(feature = "unstable_discord_api")]
 use super::{AuthorizingIntegrationOwners, InteractionContext};
 #[cfg(feature = "model")]
@@ -91,6 +92,9 @@ pub struct CommandInteraction {
     /// The context where the interaction was triggered from.
     #[cfg(feature = "unstable_discord_api")]
     pub context: Option<InteractionContext>,
+    /// Whether the interaction was responded to by this process.
+    #[serde(skip)]
+    pub(crate) responded: ResponseState,
 }
 
 #[cfg(feature = "model")]
@@ -110,19 +114,29 @@ impl CommandInteraction {
     ///
     /// # Errors
     ///
-    /// Returns an [`Error::Model`] if the message content is too long. May also return an
-    /// [`Error::Http`] if the API returns an error, or an [`Error::Json`] if there is an error in
-    /// deserializing the API response.
+    /// Returns an [`Error::Model`] if the message content is too long, or if the interaction was
+    /// already responded to. May also return an [`Error::Http`] if the API returns an error, or an
+    /// [`Error::Json`] if there is an error in deserializing the API response.
     pub async fn create_response(
         &self,
         cache_http: impl CacheHttp,
         builder: CreateInteractionResponse,
     ) -> Result<()> {
-        builder.execute(cache_http, (self.id, &self.token)).await
+        self.responded.respond(builder.execute(cache_http, (self.id, &self.token))).await
+    }
+
+    /// Whether the interaction was already responded to or deferred, using this interaction or
+    /// one of its clones.
+    #[must_use]
+    pub fn has_responded(&self) -> bool {
+        self.responded.get()
     }
 
     /// Edits the initial interaction response.
     ///
+    /// If the interaction was deferred with [`Self::defer_ephemeral`], the edited response stays
+    /// ephemeral.
+    ///
     /// **Note**: Message contents must be under 2000 unicode code points.
     ///
     /// # Errors
@@ -248,7 +262,49 @@ impl CommandInteraction {
         ctx: &Context,
         builder: CreateQuickModal,
     ) -> Result<Option<QuickModalResponse>> {
-        builder.execute(ctx, self.id, &self.token).await
+        self.responded.respond(builder.execute(ctx, self.id, &self.token)).await
+    }
+}
+
+impl CommandInteraction {
+    /// The user targeted by a [`User`] context menu command, looked up in the resolved data.
+    ///
+    /// Returns `None` for other command types.
+    ///
+    /// [`User`]: CommandType::User
+    #[must_use]
+    pub fn target_user(&self) -> Option<&User> {
+        match self.data.target()? {
+            ResolvedTarget::User(user, _) => Some(user),
+            ResolvedTarget::Message(_) => None,
+        }
+    }
+
+    /// The member targeted by a [`User`] context menu command, looked up in the resolved data.
+    ///
+    /// Returns `None` for other command types, and if the command was not used in a guild.
+    ///
+    /// [`User`]: CommandType::User
+    #[must_use]
+    pub fn target_member(&self) -> Option<&PartialMember> {
+        match self.data.target()? {
+            ResolvedTarget::User(_, member) => member,
+            ResolvedTarget::Message(_) => None,
+        }
+    }
+
+    /// The message targeted by a [`Message`] context menu command, looked up in the resolved
+    /// data.
+    ///
+    /// Returns `None` for other command types.
+    ///
+    /// [`Message`]: CommandType::Message
+    #[must_use]
+    pub fn target_message(&self) -> Option<&Message> {
+        match self.data.target()? {
+            ResolvedTarget::Message(message) => Some(message),
+            ResolvedTarget::User(..) => None,
+        }
     }
 }
 
@@ -816,7 +872,102 @@ impl From<TargetId> for UserId {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::json::{assert_json, json};
+    use crate::json::{assert_json, from_value, json, Value};
+
+    fn interaction(data: Value) -> CommandInteraction {
+        from_value(json!({
+            "id": "1",
+            "application_id": "2",
+            "type": 2,
+            "data": data,
+            "guild_id": "3",
+            "channel_id": "4",
+            "member": {
+                "user": {"id": "5", "username": "invoker", "discriminator": "0"},
+                "roles": [],
+                "joined_at": "2023-01-01T00:00:00.000000+00:00",
+                "deaf": false,
+                "mute": false,
+            },
+            "token": "token",
+            "version": 1,
+            "app_permissions": "0",
+            "locale": "en-US",
+            "guild_locale": "en-US",
+            "entitlements": [],
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn user_command_target() {
+        let interaction = interaction(json!({
+            "id": "10",
+            "name": "Inspect",
+            "type": 2,
+            "target_id": "6",
+            "resolved": {
+                "users": {
+                    "6": {"id": "6", "username": "target", "discriminator": "0", "avatar": null},
+                },
+                "members": {
+                    "6": {
+                        "roles": ["7"],
+                        "nick": "nick",
+                        "joined_at": "2023-01-01T00:00:00.000000+00:00",
+                        "permissions": "8",
+                    },
+                },
+            },
+        }));
+
+        assert_eq!(interaction.target_user().map(|u| u.id), Some(UserId::new(6)));
+        assert_eq!(interaction.target_member().and_then(|m| m.nick.as_deref()), Some("nick"));
+        assert!(interaction.target_message().is_none());
+    }
+
+    #[test]
+    fn message_command_target() {
+        let interaction = interaction(json!({
+            "id": "10",
+            "name": "Bookmark",
+            "type": 3,
+            "target_id": "8",
+            "resolved": {
+                "messages": {
+                    "8": {
+                        "id": "8",
+                        "channel_id": "4",
+                        "author": {"id": "6", "username": "target", "discriminator": "0"},
+                        "content": "hello",
+                        "timestamp": "2023-01-01T00:00:00.000000+00:00",
+                        "edited_timestamp": null,
+                        "tts": false,
+                        "mention_everyone": false,
+                        "mentions": [],
+                        "mention_roles": [],
+                        "attachments": [],
+                        "embeds": [],
+                        "pinned": false,
+                        "type": 0,
+                    },
+                },
+            },
+        }));
+
+        assert_eq!(interaction.target_message().map(|m| m.content.as_str()), Some("hello"));
+        assert!(interaction.target_user().is_none());
+        assert!(interaction.target_member().is_none());
+    }
+
+    #[test]
+    fn slash_command_target() {
+        let interaction = interaction(json!({"id": "10", "name": "ping", "type": 1}));
+
+        assert!(interaction.target_user().is_none());
+        assert!(interaction.target_member().is_none());
+        assert!(interaction.target_message().is_none());
+    }
 
     #[test]
     fn nested_options() {
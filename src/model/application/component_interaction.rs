@@ -69,6 +69,9 @@ pub struct ComponentInteraction {
     /// The context where the interaction was triggered from.
     #[cfg(feature = "unstable_discord_api")]
     pub context: Option<InteractionContext>,
+    /// Whether the interaction was responded to by this process.
+    #[serde(skip)]
+    pub(crate) responded: ResponseState,
 }
 
 #[cfg(feature = "model")]
@@ -88,19 +91,29 @@ impl ComponentInteraction {
     ///
     /// # Errors
     ///
-    /// Returns an [`Error::Model`] if the message content is too long. May also return an
-    /// [`Error::Http`] if the API returns an error, or an [`Error::Json`] if there is an error in
-    /// deserializing the API response.
+    /// Returns an [`Error::Model`] if the message content is too long, or if the interaction was
+    /// already responded to. May also return an [`Error::Http`] if the API returns an error, or an
+    /// [`Error::Json`] if there is an error in deserializing the API response.
     pub async fn create_response(
         &self,
         cache_http: impl CacheHttp,
         builder: CreateInteractionResponse,
     ) -> Result<()> {
-        builder.execute(cache_http, (self.id, &self.token)).await
+        self.responded.respond(builder.execute(cache_http, (self.id, &self.token))).await
+    }
+
+    /// Whether the interaction was already responded to or deferred, using this interaction or
+    /// one of its clones.
+    #[must_use]
+    pub fn has_responded(&self) -> bool {
+        self.responded.get()
     }
 
     /// Edits the initial interaction response.
     ///
+    /// If the interaction was deferred with [`Self::defer_ephemeral`], the edited response stays
+    /// ephemeral.
+    ///
     /// **Note**: Message contents must be under 2000 unicode code points.
     ///
     /// # Errors
@@ -225,7 +238,7 @@ impl ComponentInteraction {
         ctx: &Context,
         builder: CreateQuickModal,
     ) -> Result<Option<QuickModalResponse>> {
-        builder.execute(ctx, self.id, &self.token).await
+        self.responded.respond(builder.execute(ctx, self.id, &self.token)).await
     }
 }
 
@@ -1,3 +1,8 @@
+use std::sync::atomic::AtomicBool;
+#[cfg(feature = "model")]
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
 use serde::de::{Deserialize, Deserializer, Error as DeError};
 use serde::ser::{Serialize, Serializer};
 
@@ -15,6 +20,8 @@ use crate::model::user::User;
 use crate::model::utils::deserialize_val;
 #[cfg(feature = "unstable_discord_api")]
 use crate::model::utils::StrOrInt;
+#[cfg(feature = "model")]
+use crate::model::ModelError;
 use crate::model::Permissions;
 
 /// [Discord docs](https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-object)
@@ -444,3 +451,159 @@ pub struct MessageInteractionMetadata {
     /// interactions
     pub triggering_interaction_metadata: Option<Box<MessageInteractionMetadata>>,
 }
+
+/// Tracks whether an interaction has been responded to. Clones of an interaction share the state.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(not(feature = "model"), allow(dead_code))]
+pub(crate) struct ResponseState(Arc<AtomicBool>);
+
+#[cfg(feature = "typesize")]
+impl typesize::TypeSize for ResponseState {}
+
+impl ResponseState {
+    #[cfg(feature = "model")]
+    pub(crate) fn get(&self) -> bool {
+        self.0.load(Ordering::Acquire)
+    }
+
+    /// Runs `respond`, unless a response was already sent or is being sent. A failed response can
+    /// be retried.
+    #[cfg(feature = "model")]
+    pub(crate) async fn respond<T>(
+        &self,
+        respond: impl std::future::Future<Output = Result<T>>,
+    ) -> Result<T> {
+        if self.0.swap(true, Ordering::AcqRel) {
+            return Err(Error::Model(ModelError::AlreadyResponded));
+        }
+
+        let result = respond.await;
+        if result.is_err() {
+            self.0.store(false, Ordering::Release);
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+#[cfg(all(feature = "model", feature = "testing"))]
+mod tests {
+    use std::sync::Arc;
+
+    use reqwest::StatusCode;
+
+    use super::*;
+    use crate::builder::{CreateInteractionResponse, CreateModal};
+    use crate::http::testing::MockTransport;
+    use crate::http::HttpBuilder;
+    use crate::json::json;
+
+    fn interaction(kind: u8, data: Value) -> Interaction {
+        from_value(json!({
+            "id": "1",
+            "application_id": "2",
+            "type": kind,
+            "data": data,
+            "channel_id": "4",
+            "user": {"id": "5", "username": "invoker", "discriminator": "0"},
+            "token": "token",
+            "version": 1,
+            "locale": "en-US",
+            "entitlements": [],
+            "message": {
+                "id": "8",
+                "channel_id": "4",
+                "author": {"id": "6", "username": "bot", "discriminator": "0"},
+                "content": "hello",
+                "timestamp": "2023-01-01T00:00:00.000000+00:00",
+                "edited_timestamp": null,
+                "tts": false,
+                "mention_everyone": false,
+                "mentions": [],
+                "mention_roles": [],
+                "attachments": [],
+                "embeds": [],
+                "pinned": false,
+                "type": 0,
+            },
+        }))
+        .unwrap()
+    }
+
+    fn is_already_responded(result: Result<()>) -> bool {
+        matches!(result, Err(Error::Model(ModelError::AlreadyResponded)))
+    }
+
+    #[tokio::test]
+    async fn double_response() {
+        let mock = Arc::new(MockTransport::new());
+        let http = HttpBuilder::new("token").transport(mock.clone()).build();
+        mock.push_no_content();
+        mock.push_no_content();
+        mock.push_no_content();
+
+        let Interaction::Command(command) =
+            interaction(2, json!({"id": "10", "name": "ping", "type": 1}))
+        else {
+            unreachable!()
+        };
+        let clone = command.clone();
+        command.defer(&http).await.unwrap();
+        assert!(clone.has_responded());
+        assert!(is_already_responded(clone.defer(&http).await));
+
+        let Interaction::Component(component) =
+            interaction(3, json!({"custom_id": "button", "component_type": 2}))
+        else {
+            unreachable!()
+        };
+        component.defer(&http).await.unwrap();
+        assert!(is_already_responded(component.defer_ephemeral(&http).await));
+
+        let Interaction::Modal(modal) =
+            interaction(5, json!({"custom_id": "modal", "components": []}))
+        else {
+            unreachable!()
+        };
+        modal.defer(&http).await.unwrap();
+        assert!(is_already_responded(modal.defer(&http).await));
+
+        assert_eq!(mock.requests().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn failed_response_can_be_retried() {
+        let mock = Arc::new(MockTransport::new());
+        let http = HttpBuilder::new("token").transport(mock.clone()).build();
+        mock.push_response(StatusCode::INTERNAL_SERVER_ERROR, "{}");
+        mock.push_no_content();
+
+        let Interaction::Component(component) =
+            interaction(3, json!({"custom_id": "button", "component_type": 2}))
+        else {
+            unreachable!()
+        };
+        assert!(matches!(component.defer(&http).await, Err(Error::Http(_))));
+        assert!(!component.has_responded());
+        component.defer(&http).await.unwrap();
+        assert!(component.has_responded());
+    }
+
+    #[tokio::test]
+    async fn modal_response_to_modal() {
+        let mock = Arc::new(MockTransport::new());
+        let http = HttpBuilder::new("token").transport(mock.clone()).build();
+
+        let Interaction::Modal(modal) =
+            interaction(5, json!({"custom_id": "modal", "components": []}))
+        else {
+            unreachable!()
+        };
+        let response = CreateInteractionResponse::Modal(CreateModal::new("modal", "Title"));
+        let result = modal.create_response(&http, response).await;
+
+        assert!(matches!(result, Err(Error::Model(ModelError::NestedModal))));
+        assert!(!modal.has_responded());
+        assert!(mock.requests().is_empty());
+    }
+}
@@ -16,12 +16,26 @@ mod oauth;
 pub use oauth::*;
 mod ping_interaction;
 pub use ping_interaction::*;
+mod role_connection;
+pub use role_connection::*;
 
+#[cfg(feature = "model")]
+use super::guild::Emoji;
 use super::guild::PartialGuild;
+#[cfg(feature = "model")]
+use super::id::EmojiId;
 use super::id::{ApplicationId, GenericId, GuildId, SkuId, UserId};
 use super::misc::ImageHash;
 use super::user::User;
 use super::Permissions;
+#[cfg(feature = "model")]
+use crate::builder::{Builder, CreateApplicationEmoji};
+#[cfg(feature = "model")]
+use crate::http::{CacheHttp, Http};
+#[cfg(feature = "model")]
+use crate::internal::prelude::*;
+#[cfg(feature = "model")]
+use crate::json::json;
 
 /// Partial information about the given application.
 ///
@@ -97,6 +111,102 @@ impl CurrentApplicationInfo {
     pub fn store_url(&self) -> String {
         format!("https://discord.com/application-directory/{}/store", self.id)
     }
+
+    /// Gets all emojis owned by the application.
+    ///
+    /// Refer to [`ApplicationId::emojis`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user is not a bot of the application.
+    #[cfg(feature = "model")]
+    pub async fn emojis(&self, http: impl AsRef<Http>) -> Result<Vec<Emoji>> {
+        self.id.emojis(http).await
+    }
+
+    /// Creates an emoji owned by the application.
+    ///
+    /// Refer to [`ApplicationId::create_emoji`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// See [`CreateApplicationEmoji::execute`] for a list of possible errors.
+    ///
+    /// [`CreateApplicationEmoji::execute`]: ../../builder/struct.CreateApplicationEmoji.html#method.execute
+    #[cfg(feature = "model")]
+    pub async fn create_emoji(
+        &self,
+        cache_http: impl CacheHttp,
+        builder: CreateApplicationEmoji,
+    ) -> Result<Emoji> {
+        self.id.create_emoji(cache_http, builder).await
+    }
+}
+
+#[cfg(feature = "model")]
+impl ApplicationId {
+    /// Gets all emojis owned by the application via HTTP.
+    ///
+    /// Application emojis can be used by the application's bot in any guild, without the bot being
+    /// a member of the guild they belong to. An application can own up to 2000 emojis.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user is not a bot of the application.
+    pub async fn emojis(self, http: impl AsRef<Http>) -> Result<Vec<Emoji>> {
+        http.as_ref().get_emojis_of_application(self).await
+    }
+
+    /// Gets an emoji owned by the application by its Id via HTTP.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if an emoji with that Id does not exist.
+    pub async fn emoji(self, http: impl AsRef<Http>, emoji_id: EmojiId) -> Result<Emoji> {
+        http.as_ref().get_emoji_of_application(self, emoji_id).await
+    }
+
+    /// Creates an emoji owned by the application.
+    ///
+    /// # Errors
+    ///
+    /// See [`CreateApplicationEmoji::execute`] for a list of possible errors.
+    ///
+    /// [`CreateApplicationEmoji::execute`]: ../../builder/struct.CreateApplicationEmoji.html#method.execute
+    pub async fn create_emoji(
+        self,
+        cache_http: impl CacheHttp,
+        builder: CreateApplicationEmoji,
+    ) -> Result<Emoji> {
+        builder.execute(cache_http, self).await
+    }
+
+    /// Renames an emoji owned by the application.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if an emoji with that Id does not exist, or if the name is invalid.
+    pub async fn edit_emoji(
+        self,
+        http: impl AsRef<Http>,
+        emoji_id: EmojiId,
+        name: &str,
+    ) -> Result<Emoji> {
+        let map = json!({
+            "name": name,
+        });
+
+        http.as_ref().edit_emoji_of_application(self, emoji_id, &map).await
+    }
+
+    /// Deletes an emoji owned by the application.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if an emoji with that Id does not exist.
+    pub async fn delete_emoji(self, http: impl AsRef<Http>, emoji_id: EmojiId) -> Result<()> {
+        http.as_ref().delete_emoji_of_application(self, emoji_id).await
+    }
 }
 
 #[cfg(feature = "unstable_discord_api")]
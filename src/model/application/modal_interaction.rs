@@ -60,6 +60,9 @@ pub struct ModalInteraction {
     pub guild_locale: Option<String>,
     /// For monetized applications, any entitlements of the invoking user.
     pub entitlements: Vec<Entitlement>,
+    /// Whether the interaction was responded to by this process.
+    #[serde(skip)]
+    pub(crate) responded: ResponseState,
 }
 
 #[cfg(feature = "model")]
@@ -79,19 +82,33 @@ impl ModalInteraction {
     ///
     /// # Errors
     ///
-    /// Returns an [`Error::Model`] if the message content is too long. May also return an
-    /// [`Error::Http`] if the API returns an error, or an [`Error::Json`] if there is an error in
-    /// deserializing the API response.
+    /// Returns an [`Error::Model`] if the message content is too long, if the interaction was
+    /// already responded to, or if the response is a modal. May also return an [`Error::Http`] if
+    /// the API returns an error, or an [`Error::Json`] if there is an error in deserializing the
+    /// API response.
     pub async fn create_response(
         &self,
         cache_http: impl CacheHttp,
         builder: CreateInteractionResponse,
     ) -> Result<()> {
-        builder.execute(cache_http, (self.id, &self.token)).await
+        if matches!(builder, CreateInteractionResponse::Modal(_)) {
+            return Err(Error::Model(ModelError::NestedModal));
+        }
+        self.responded.respond(builder.execute(cache_http, (self.id, &self.token))).await
+    }
+
+    /// Whether the interaction was already responded to or deferred, using this interaction or
+    /// one of its clones.
+    #[must_use]
+    pub fn has_responded(&self) -> bool {
+        self.responded.get()
     }
 
     /// Edits the initial interaction response.
     ///
+    /// If the interaction was deferred with [`Self::defer_ephemeral`], the edited response stays
+    /// ephemeral.
+    ///
     /// **Note**: Message contents must be under 2000 unicode code points.
     ///
     /// # Errors
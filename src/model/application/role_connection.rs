@@ -0,0 +1,200 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+/// A requirement that users must meet to receive a linked role, registered by an application.
+///
+/// Applications register up to 5 of these with
+/// [`Http::edit_application_role_connection_metadata`]. Guild admins can then pick a value to
+/// compare the [`ApplicationRoleConnection::metadata`] of a user against.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/application-role-connection-metadata#application-role-connection-metadata-object).
+///
+/// [`Http::edit_application_role_connection_metadata`]: crate::http::Http::edit_application_role_connection_metadata
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct ApplicationRoleConnectionMetadata {
+    /// How a user's value is compared against the guild's configured value.
+    #[serde(rename = "type")]
+    pub kind: ApplicationRoleConnectionMetadataType,
+    /// The key of the value in [`ApplicationRoleConnection::metadata`]. Must be `a-z`, `0-9`, or
+    /// `_` characters; 1-50 characters.
+    pub key: String,
+    /// The name of the metadata field; 1-100 characters.
+    pub name: String,
+    /// Localizations of the name, with the locale as the key.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name_localizations: Option<HashMap<String, String>>,
+    /// The description of the metadata field; 1-200 characters.
+    pub description: String,
+    /// Localizations of the description, with the locale as the key.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description_localizations: Option<HashMap<String, String>>,
+}
+
+impl ApplicationRoleConnectionMetadata {
+    /// Creates a new metadata field to register with
+    /// [`Http::edit_application_role_connection_metadata`].
+    ///
+    /// [`Http::edit_application_role_connection_metadata`]: crate::http::Http::edit_application_role_connection_metadata
+    pub fn new(
+        kind: ApplicationRoleConnectionMetadataType,
+        key: impl Into<String>,
+        name: impl Into<String>,
+        description: impl Into<String>,
+    ) -> Self {
+        Self {
+            kind,
+            key: key.into(),
+            name: name.into(),
+            name_localizations: None,
+            description: description.into(),
+            description_localizations: None,
+        }
+    }
+
+    /// Adds a localized name for the given locale, e.g. `"de"`.
+    #[must_use]
+    pub fn name_localized(mut self, locale: impl Into<String>, name: impl Into<String>) -> Self {
+        self.name_localizations.get_or_insert_with(HashMap::new).insert(locale.into(), name.into());
+        self
+    }
+
+    /// Adds a localized description for the given locale, e.g. `"de"`.
+    #[must_use]
+    pub fn description_localized(
+        mut self,
+        locale: impl Into<String>,
+        description: impl Into<String>,
+    ) -> Self {
+        let localizations = self.description_localizations.get_or_insert_with(HashMap::new);
+        localizations.insert(locale.into(), description.into());
+        self
+    }
+}
+
+enum_number! {
+    /// How a user's [`ApplicationRoleConnection::metadata`] value is compared against the value
+    /// configured by the guild.
+    ///
+    /// [Discord docs](https://discord.com/developers/docs/resources/application-role-connection-metadata#application-role-connection-metadata-object-application-role-connection-metadata-type).
+    #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
+    #[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+    #[serde(from = "u8", into = "u8")]
+    #[non_exhaustive]
+    pub enum ApplicationRoleConnectionMetadataType {
+        /// The user's integer value is less than or equal to the guild's.
+        IntegerLessThanOrEqual = 1,
+        /// The user's integer value is greater than or equal to the guild's.
+        IntegerGreaterThanOrEqual = 2,
+        /// The user's integer value is equal to the guild's.
+        IntegerEqual = 3,
+        /// The user's integer value is not equal to the guild's.
+        IntegerNotEqual = 4,
+        /// The user's ISO8601 date is less than or equal to the guild's number of days ago.
+        DatetimeLessThanOrEqual = 5,
+        /// The user's ISO8601 date is greater than or equal to the guild's number of days ago.
+        DatetimeGreaterThanOrEqual = 6,
+        /// The user's boolean value is equal to the guild's.
+        BooleanEqual = 7,
+        /// The user's boolean value is not equal to the guild's.
+        BooleanNotEqual = 8,
+        _ => Unknown(u8),
+    }
+}
+
+/// The role connection that an application has attached to a user.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/user#application-role-connection-object).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct ApplicationRoleConnection {
+    /// The vanity name of the platform the application is for; max 50 characters.
+    pub platform_name: Option<String>,
+    /// The username of the user on the platform; max 100 characters.
+    pub platform_username: Option<String>,
+    /// Values for the [`ApplicationRoleConnectionMetadata`] keys of the application, stringified.
+    /// Values are max 100 characters.
+    #[serde(default)]
+    pub metadata: HashMap<String, String>,
+}
+
+impl ApplicationRoleConnection {
+    /// Creates an empty role connection to update with
+    /// [`Http::edit_current_user_application_role_connection`].
+    ///
+    /// [`Http::edit_current_user_application_role_connection`]: crate::http::Http::edit_current_user_application_role_connection
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the vanity name of the platform.
+    #[must_use]
+    pub fn platform_name(mut self, platform_name: impl Into<String>) -> Self {
+        self.platform_name = Some(platform_name.into());
+        self
+    }
+
+    /// Sets the username of the user on the platform.
+    #[must_use]
+    pub fn platform_username(mut self, platform_username: impl Into<String>) -> Self {
+        self.platform_username = Some(platform_username.into());
+        self
+    }
+
+    /// Sets the value for a metadata key. Integers and booleans (`1` or `0`) are stringified, and
+    /// dates must be ISO8601 strings.
+    #[must_use]
+    pub fn metadata(mut self, key: impl Into<String>, value: &(impl ToString + ?Sized)) -> Self {
+        self.metadata.insert(key.into(), value.to_string());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json::{assert_json, json};
+
+    #[test]
+    fn metadata_serde() {
+        let metadata = ApplicationRoleConnectionMetadata::new(
+            ApplicationRoleConnectionMetadataType::IntegerGreaterThanOrEqual,
+            "level",
+            "Level",
+            "Minimum level",
+        )
+        .name_localized("de", "Stufe");
+
+        assert_json(
+            &metadata,
+            json!({
+                "type": 2,
+                "key": "level",
+                "name": "Level",
+                "name_localizations": {"de": "Stufe"},
+                "description": "Minimum level",
+            }),
+        );
+    }
+
+    #[test]
+    fn role_connection_serde() {
+        let connection = ApplicationRoleConnection::new()
+            .platform_name("Example")
+            .metadata("level", &12)
+            .metadata("verified", &1);
+
+        assert_json(
+            &connection,
+            json!({
+                "platform_name": "Example",
+                "platform_username": null,
+                "metadata": {"level": "12", "verified": "1"},
+            }),
+        );
+    }
+}
@@ -1,7 +1,11 @@
 #[cfg(feature = "model")]
 use reqwest::Client as ReqwestClient;
 use serde_cow::CowStr;
+#[cfg(feature = "model")]
+use tokio::io::AsyncWrite;
 
+#[cfg(feature = "model")]
+use crate::http::{Http, HttpError};
 #[cfg(feature = "model")]
 use crate::internal::prelude::*;
 use crate::model::prelude::*;
@@ -87,6 +91,9 @@ impl Attachment {
 
     /// Downloads the attachment, returning back a vector of bytes.
     ///
+    /// This uses a new HTTP client for every call, see [`Self::download_with`] to reuse the
+    /// client of an [`Http`] instance instead.
+    ///
     /// # Examples
     ///
     /// Download all of the attachments associated with a [`Message`]:
@@ -155,4 +162,62 @@ impl Attachment {
         let bytes = reqwest.get(&self.url).send().await?.bytes().await?;
         Ok(bytes.to_vec())
     }
+
+    /// Downloads the attachment via [`Http::download`], returning back a vector of bytes.
+    ///
+    /// Unlike [`Self::download`], this reuses the HTTP client and its configuration, e.g. its
+    /// proxy and timeouts.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] when there is a problem retrieving the attachment.
+    pub async fn download_with(&self, http: impl AsRef<Http>) -> Result<Vec<u8>> {
+        Ok(http.as_ref().download(&self.url).await?.bytes)
+    }
+
+    /// Downloads the attachment like [`Self::download_with`], unless it is larger than `max_bytes`
+    /// or not of the expected `content_type`.
+    ///
+    /// The [`size`] reported by Discord is checked before downloading, and the download is aborted
+    /// if more than `max_bytes` are received, so at most `max_bytes` are buffered. This makes it
+    /// safe to use on attachments uploaded by arbitrary users. The content type is checked against
+    /// the one served by Discord's CDN, see [`Http::download_limited`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HttpError::DownloadTooLarge`] if the attachment is larger than `max_bytes`, and
+    /// [`HttpError::UnexpectedContentType`] if it is not of the expected content type. Otherwise,
+    /// see [`Self::download_with`].
+    ///
+    /// [`size`]: Self::size
+    pub async fn download_limited(
+        &self,
+        http: impl AsRef<Http>,
+        max_bytes: usize,
+        content_type: Option<&str>,
+    ) -> Result<Vec<u8>> {
+        if self.size as usize > max_bytes {
+            return Err(Error::Http(HttpError::DownloadTooLarge {
+                max_bytes,
+            }));
+        }
+
+        Ok(http.as_ref().download_limited(&self.url, max_bytes, content_type).await?.bytes)
+    }
+
+    /// Downloads the attachment, writing its contents to `writer` as they are received, e.g. to
+    /// stream it to a file without buffering it in memory.
+    ///
+    /// Returns the number of bytes written.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Io`] if writing fails, or an [`Error::Http`] when there is a problem
+    /// retrieving the attachment.
+    pub async fn download_to<W>(&self, http: impl AsRef<Http>, writer: &mut W) -> Result<u64>
+    where
+        W: AsyncWrite + Unpin + ?Sized,
+    {
+        http.as_ref().download_to(&self.url, writer).await
+    }
 }
@@ -1,8 +1,12 @@
 #[cfg(feature = "model")]
+use std::collections::BTreeMap;
+#[cfg(feature = "model")]
 use std::sync::Arc;
 
 #[cfg(feature = "model")]
 use futures::stream::Stream;
+#[cfg(feature = "model")]
+use tracing::debug;
 
 #[cfg(feature = "model")]
 use crate::builder::{
@@ -27,7 +31,7 @@ use crate::collector::{MessageCollector, ReactionCollector};
 #[cfg(feature = "collector")]
 use crate::gateway::ShardMessenger;
 #[cfg(feature = "model")]
-use crate::http::{CacheHttp, Http, Typing};
+use crate::http::{CacheHttp, Http, JsonErrorCode, MessagePagination, Typing};
 #[cfg(feature = "model")]
 use crate::json::json;
 use crate::model::prelude::*;
@@ -331,6 +335,29 @@ impl ChannelId {
         builder.execute(cache_http, self).await
     }
 
+    /// Sets the status of a voice channel, shown below its name, e.g. to display the currently
+    /// playing track. Passing [`None`] clears the status.
+    ///
+    /// **Note**: Requires the [Set Voice Channel Status] permission. If the current user is not
+    /// connected to the channel, the [Manage Channels] permission is required instead.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission, or if the channel is not a
+    /// voice channel.
+    ///
+    /// [Set Voice Channel Status]: Permissions::SET_VOICE_CHANNEL_STATUS
+    /// [Manage Channels]: Permissions::MANAGE_CHANNELS
+    pub async fn set_voice_status(
+        self,
+        http: impl AsRef<Http>,
+        status: Option<&str>,
+    ) -> Result<()> {
+        let map = json!({ "status": status.unwrap_or_default() });
+
+        http.as_ref().edit_voice_status(self, &map, None).await
+    }
+
     /// Edits a [`Message`] in the channel given its Id.
     ///
     /// Message editing preserves all unchanged message data, with some exceptions for embeds and
@@ -355,6 +382,41 @@ impl ChannelId {
         builder.execute(cache_http, (self, message_id.into(), None)).await
     }
 
+    /// Suppresses or unsuppresses the embeds of a [`Message`] in the channel given its Id, without
+    /// changing anything else about the message.
+    ///
+    /// Discord expects message edits to contain all of the message's flags, not just the changed
+    /// ones, so the message's other flags are preserved. Pass them as `current_flags` if they are
+    /// known, e.g. from [`Message::flags`]. Otherwise the message is fetched first to read them.
+    ///
+    /// **Note**: Requires the [Manage Messages] permission if the message was not sent by the
+    /// current user.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission, or if the message does not
+    /// exist.
+    ///
+    /// [Manage Messages]: Permissions::MANAGE_MESSAGES
+    pub async fn suppress_embeds(
+        self,
+        http: impl AsRef<Http>,
+        message_id: impl Into<MessageId>,
+        suppress: bool,
+        current_flags: Option<MessageFlags>,
+    ) -> Result<Message> {
+        let http = http.as_ref();
+        let message_id = message_id.into();
+
+        let current_flags = match current_flags {
+            Some(flags) => flags,
+            None => http.get_message(self, message_id).await?.flags.unwrap_or_default(),
+        };
+
+        let builder = suppress_embeds_builder(current_flags, suppress);
+        http.edit_message(self, message_id, &builder, vec![]).await
+    }
+
     /// Follows the News Channel
     ///
     /// Requires [Manage Webhook] permissions on the target channel.
@@ -491,6 +553,86 @@ impl ChannelId {
         builder.execute(cache_http, self).await
     }
 
+    /// Gets up to `count` messages surrounding the given message, sorted from oldest to newest.
+    ///
+    /// Discord returns at most 100 messages per request, so larger counts are fetched with
+    /// additional requests for the messages before and after the ones already fetched, splitting
+    /// the remainder evenly between both directions. The given message is included only once, if
+    /// it still exists.
+    ///
+    /// **Note**: Requires the [Read Message History] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the first request fails. If a follow-up request fails with a
+    /// `403` or `404` status, for example because the message was deleted or access was lost in
+    /// the meantime, the messages fetched so far are returned instead.
+    ///
+    /// [Read Message History]: Permissions::READ_MESSAGE_HISTORY
+    pub async fn messages_around(
+        self,
+        http: impl AsRef<Http>,
+        message_id: impl Into<MessageId>,
+        count: usize,
+    ) -> Result<Vec<Message>> {
+        let http = http.as_ref();
+        if count == 0 {
+            return Ok(Vec::new());
+        }
+
+        let limit = count.min(100) as u8;
+        let target = Some(MessagePagination::Around(message_id.into()));
+        let page = http.get_messages(self, target, Some(limit)).await?;
+
+        let mut messages: BTreeMap<_, _> = page.into_iter().map(|m| (m.id, m)).collect();
+
+        // The first page doesn't tell on which side messages ran out, so each direction is only
+        // known to be exhausted once a request in it returns fewer messages than requested.
+        let mut more_before = !messages.is_empty();
+        let mut more_after = !messages.is_empty();
+
+        while messages.len() < count && (more_before || more_after) {
+            let (before, after) =
+                around_page_limits(count - messages.len(), more_before, more_after);
+
+            if let Some(&oldest) = messages.keys().next().filter(|_| before > 0) {
+                let target = MessagePagination::Before(oldest);
+                more_before = self.fetch_around_page(http, target, before, &mut messages).await?;
+            }
+            if let Some(&newest) = messages.keys().next_back().filter(|_| after > 0) {
+                let target = MessagePagination::After(newest);
+                more_after = self.fetch_around_page(http, target, after, &mut messages).await?;
+            }
+        }
+
+        Ok(messages.into_values().collect())
+    }
+
+    /// Fetches a follow-up page for [`Self::messages_around`], returning whether there may be more
+    /// messages in the same direction.
+    async fn fetch_around_page(
+        self,
+        http: &Http,
+        target: MessagePagination,
+        limit: u8,
+        messages: &mut BTreeMap<MessageId, Message>,
+    ) -> Result<bool> {
+        match http.get_messages(self, Some(target), Some(limit)).await {
+            Ok(page) => {
+                let more = page.len() == usize::from(limit);
+                messages.extend(page.into_iter().map(|m| (m.id, m)));
+                Ok(more)
+            },
+            Err(Error::Http(why))
+                if why.status_code().is_some_and(|status| status == 403 || status == 404) =>
+            {
+                tracing::warn!("Stopped fetching messages around in {self}: {why}");
+                Ok(false)
+            },
+            Err(why) => Err(why),
+        }
+    }
+
     /// Streams over all the messages in a channel.
     ///
     /// This is accomplished and equivalent to repeated calls to [`Self::messages`]. A buffer of at
@@ -537,6 +679,7 @@ impl ChannelId {
         Ok(match channel {
             Channel::Guild(channel) => channel.name,
             Channel::Private(channel) => channel.name(),
+            Channel::Group(channel) => channel.name(),
         })
     }
 
@@ -633,6 +776,39 @@ impl ChannelId {
             .await
     }
 
+    /// Gets the list of [`User`]s who have reacted to a [`Message`] with a certain [`Emoji`] and
+    /// type of reaction, normal or super. Each user is only listed under the type of reaction they
+    /// sent.
+    ///
+    /// Otherwise the same as [`Self::reaction_users`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission to read messages in the
+    /// channel.
+    pub async fn reaction_users_with_type(
+        self,
+        http: impl AsRef<Http>,
+        message_id: impl Into<MessageId>,
+        reaction_type: impl Into<ReactionType>,
+        kind: ReactionTypes,
+        limit: Option<u8>,
+        after: impl Into<Option<UserId>>,
+    ) -> Result<Vec<User>> {
+        let limit = limit.map_or(50, |x| if x > 100 { 100 } else { x });
+
+        http.as_ref()
+            .get_reaction_users_with_type(
+                self,
+                message_id.into(),
+                &reaction_type.into(),
+                kind,
+                limit,
+                after.into().map(UserId::get),
+            )
+            .await
+    }
+
     /// Sends a message with just the given message content in the channel.
     ///
     /// **Note**: Message content must be under 2000 unicode code points.
@@ -651,6 +827,48 @@ impl ChannelId {
         self.send_message(cache_http, builder).await
     }
 
+    /// Shows the current user as typing while the message is being prepared, and sends it once
+    /// `builder` resolves. Typing stops right before the message is sent.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use std::sync::Arc;
+    /// # use serenity::builder::CreateMessage;
+    /// # use serenity::http::Http;
+    /// # use serenity::model::id::ChannelId;
+    /// # async fn run() -> serenity::Result<()> {
+    /// # let http: Arc<Http> = unimplemented!();
+    /// # async fn long_process() -> String { unimplemented!() }
+    /// let channel_id = ChannelId::new(7);
+    /// let message = channel_id
+    ///     .say_with_typing(&http, async {
+    ///         let report = long_process().await;
+    ///         CreateMessage::new().content(report)
+    ///     })
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Self::send_message`].
+    pub async fn say_with_typing(
+        self,
+        http: &Arc<Http>,
+        builder: impl std::future::Future<Output = CreateMessage>,
+    ) -> Result<Message> {
+        let typing = self.start_typing(http);
+        let builder = builder.await;
+
+        // Typing is stopped first, so that it can't be triggered again after the message is sent.
+        if let Err(why) = typing.stop_and_wait().await {
+            debug!("Failed to show typing in {self}: {why:?}");
+        }
+        self.send_message(http, builder).await
+    }
+
     /// Sends file(s) along with optional message contents. The filename _must_ be specified.
     ///
     /// Message contents may be passed using the `builder` argument.
@@ -743,6 +961,42 @@ impl ChannelId {
         builder.execute(cache_http, (self, None)).await
     }
 
+    /// Plays a soundboard sound in this voice channel.
+    ///
+    /// The current user must be connected to the voice channel, and neither deafened, muted nor
+    /// suppressed. To play a sound from another guild, pass that guild's Id as `source_guild_id`.
+    ///
+    /// **Note**: Requires the [Speak] and [Use Soundboard] permissions, and the [Use External
+    /// Sounds] permission for sounds from other guilds.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission or is not connected to the
+    /// channel, or if a sound with that Id does not exist.
+    ///
+    /// [Speak]: Permissions::SPEAK
+    /// [Use Soundboard]: Permissions::USE_SOUNDBOARD
+    /// [Use External Sounds]: Permissions::USE_EXTERNAL_SOUNDS
+    pub async fn send_soundboard_sound(
+        self,
+        http: impl AsRef<Http>,
+        sound_id: SoundId,
+        source_guild_id: Option<GuildId>,
+    ) -> Result<()> {
+        #[derive(serde::Serialize)]
+        struct SendSoundboardSound {
+            sound_id: SoundId,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            source_guild_id: Option<GuildId>,
+        }
+
+        let map = SendSoundboardSound {
+            sound_id,
+            source_guild_id,
+        };
+        http.as_ref().send_soundboard_sound(self, &map).await
+    }
+
     /// Starts typing in the channel for an indefinite period of time.
     ///
     /// Returns [`Typing`] that is used to trigger the typing. [`Typing::stop`] must be called on
@@ -830,6 +1084,68 @@ impl ChannelId {
         builder.execute(cache_http, self).await
     }
 
+    /// Returns the channel's webhook with the given name that is owned by the current
+    /// application, creating it if there is none.
+    ///
+    /// A webhook is considered owned if it is an [`Incoming`] webhook with a token, created by the
+    /// application set through [`Http::set_application_id`]. If no application Id is set, any
+    /// such webhook with a matching name is returned.
+    ///
+    /// If the `temp_cache` feature is enabled, the webhook is cached so that repeated calls don't
+    /// need to list the channel's webhooks again. A cached webhook may have been deleted since.
+    ///
+    /// **Note**: Requires the [Manage Webhooks] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission, or see
+    /// [`CreateWebhook::execute`] for the errors when creating the webhook.
+    ///
+    /// [`Incoming`]: WebhookType::Incoming
+    /// [Manage Webhooks]: Permissions::MANAGE_WEBHOOKS
+    pub async fn webhook_or_create(
+        self,
+        cache_http: impl CacheHttp,
+        name: impl Into<String>,
+        avatar: Option<&CreateAttachment>,
+    ) -> Result<Webhook> {
+        let name = name.into();
+
+        #[cfg(feature = "temp_cache")]
+        if let Some(cache) = cache_http.cache() {
+            if let Some(webhook) = cache.temp_webhooks.get(&(self, name.clone())) {
+                return Ok(Webhook::clone(&*webhook));
+            }
+        }
+
+        let application_id = cache_http.http().application_id();
+        let existing = self.webhooks(cache_http.http()).await?.into_iter().find(|webhook| {
+            webhook.kind == WebhookType::Incoming
+                && webhook.token.is_some()
+                && webhook.name.as_deref() == Some(name.as_str())
+                && (application_id.is_none() || webhook.application_id == application_id)
+        });
+
+        let webhook = if let Some(webhook) = existing {
+            webhook
+        } else {
+            let mut builder = CreateWebhook::new(name.clone());
+            if let Some(avatar) = avatar {
+                builder = builder.avatar(avatar);
+            }
+            self.create_webhook(&cache_http, builder).await?
+        };
+
+        #[cfg(feature = "temp_cache")]
+        if let Some(cache) = cache_http.cache() {
+            use crate::cache::MaybeOwnedArc;
+
+            cache.temp_webhooks.insert((self, name), MaybeOwnedArc::new(webhook.clone()));
+        }
+
+        Ok(webhook)
+    }
+
     /// Returns a builder which can be awaited to obtain a message or stream of messages in this
     /// channel.
     #[cfg(feature = "collector")]
@@ -972,13 +1288,77 @@ impl ChannelId {
         http.as_ref().get_channel_thread_members(self).await
     }
 
+    /// Gets a page of thread members including their [`ThreadMember::member`], if this channel is
+    /// a thread in the guild with the given Id.
+    ///
+    /// Use `limit` (at most 100, defaults to 100) and `after` to paginate the results.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NotInRange`] if the `limit` is not between 1 and 100.
+    ///
+    /// It may return an [`Error::Http`] if the channel is not a thread channel
+    pub async fn thread_members(
+        self,
+        http: impl AsRef<Http>,
+        guild_id: impl Into<GuildId>,
+        limit: Option<u64>,
+        after: impl Into<Option<UserId>>,
+    ) -> Result<Vec<ThreadMember>> {
+        http.as_ref()
+            .get_channel_thread_members_paginated(guild_id.into(), self, limit, after.into())
+            .await
+    }
+
+    /// Streams over all the thread members, if this channel is a thread.
+    ///
+    /// This is accomplished and equivalent to repeated calls to [`Self::thread_members`]. When
+    /// `with_member` is `true`, a buffer of at most 100 thread members is used, and each
+    /// [`ThreadMember::member`] is filled in. The thread is fetched once beforehand to get its
+    /// guild Id.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::model::id::ChannelId;
+    /// # use serenity::http::Http;
+    /// #
+    /// # async fn run() {
+    /// # let channel_id = ChannelId::new(1);
+    /// # let ctx: Http = unimplemented!();
+    /// use serenity::futures::StreamExt;
+    ///
+    /// let mut thread_members = channel_id.thread_members_iter(&ctx, true).boxed();
+    /// while let Some(thread_member_result) = thread_members.next().await {
+    ///     match thread_member_result {
+    ///         Ok(thread_member) => println!("{} is in the thread", thread_member.user_id),
+    ///         Err(error) => eprintln!("Uh oh! Error: {}", error),
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn thread_members_iter<H: AsRef<Http>>(
+        self,
+        http: H,
+        with_member: bool,
+    ) -> impl Stream<Item = Result<ThreadMember>> {
+        ThreadMembersIter::<H>::stream(http, self, with_member)
+    }
+
     /// Joins the thread, if this channel is a thread.
     ///
+    /// Joining a thread the current user is already a member of succeeds without changes.
+    ///
     /// # Errors
     ///
     /// It may return an [`Error::Http`] if the channel is not a thread channel
+    ///
+    /// Returns a [`ModelError::ThreadArchived`] or [`ModelError::ThreadLocked`] if the thread is
+    /// archived or locked.
+    ///
+    /// Returns a [`ModelError::ThreadMembersFull`] if the thread has the maximum number of members.
     pub async fn join_thread(self, http: impl AsRef<Http>) -> Result<()> {
-        http.as_ref().join_thread_channel(self).await
+        http.as_ref().join_thread_channel(self).await.map_err(thread_member_error)
     }
 
     /// Leaves the thread, if this channel is a thread.
@@ -986,17 +1366,27 @@ impl ChannelId {
     /// # Errors
     ///
     /// It may return an [`Error::Http`] if the channel is not a thread channel
+    ///
+    /// Returns a [`ModelError::ThreadArchived`] or [`ModelError::ThreadLocked`] if the thread is
+    /// archived or locked.
     pub async fn leave_thread(self, http: impl AsRef<Http>) -> Result<()> {
-        http.as_ref().leave_thread_channel(self).await
+        http.as_ref().leave_thread_channel(self).await.map_err(thread_member_error)
     }
 
     /// Adds a thread member, if this channel is a thread.
     ///
+    /// Adding a user who is already a member of the thread succeeds without changes.
+    ///
     /// # Errors
     ///
     /// It may return an [`Error::Http`] if the channel is not a thread channel
+    ///
+    /// Returns a [`ModelError::ThreadArchived`] or [`ModelError::ThreadLocked`] if the thread is
+    /// archived or locked.
+    ///
+    /// Returns a [`ModelError::ThreadMembersFull`] if the thread has the maximum number of members.
     pub async fn add_thread_member(self, http: impl AsRef<Http>, user_id: UserId) -> Result<()> {
-        http.as_ref().add_thread_channel_member(self, user_id).await
+        http.as_ref().add_thread_channel_member(self, user_id).await.map_err(thread_member_error)
     }
 
     /// Removes a thread member, if this channel is a thread.
@@ -1004,8 +1394,11 @@ impl ChannelId {
     /// # Errors
     ///
     /// It may return an [`Error::Http`] if the channel is not a thread channel
+    ///
+    /// Returns a [`ModelError::ThreadArchived`] or [`ModelError::ThreadLocked`] if the thread is
+    /// archived or locked.
     pub async fn remove_thread_member(self, http: impl AsRef<Http>, user_id: UserId) -> Result<()> {
-        http.as_ref().remove_thread_channel_member(self, user_id).await
+        http.as_ref().remove_thread_channel_member(self, user_id).await.map_err(thread_member_error)
     }
 
     /// Gets a thread member, if this channel is a thread.
@@ -1092,6 +1485,23 @@ impl ChannelId {
     }
 }
 
+/// Turns the errors Discord returns for thread membership requests on archived, locked or full
+/// threads into the matching [`ModelError`].
+#[cfg(feature = "model")]
+fn thread_member_error(why: Error) -> Error {
+    let code = match &why {
+        Error::Http(why) => why.json_error_code(),
+        _ => None,
+    };
+
+    match code {
+        Some(JsonErrorCode::ThreadArchived) => Error::Model(ModelError::ThreadArchived),
+        Some(JsonErrorCode::ThreadLocked) => Error::Model(ModelError::ThreadLocked),
+        Some(JsonErrorCode::MaximumThreadMembers) => Error::Model(ModelError::ThreadMembersFull),
+        _ => why,
+    }
+}
+
 #[cfg(feature = "model")]
 impl From<Channel> for ChannelId {
     /// Gets the Id of a [`Channel`].
@@ -1108,6 +1518,20 @@ impl From<&Channel> for ChannelId {
     }
 }
 
+impl From<GroupChannel> for ChannelId {
+    /// Gets the Id of a group channel.
+    fn from(group_channel: GroupChannel) -> ChannelId {
+        group_channel.id
+    }
+}
+
+impl From<&GroupChannel> for ChannelId {
+    /// Gets the Id of a group channel.
+    fn from(group_channel: &GroupChannel) -> ChannelId {
+        group_channel.id
+    }
+}
+
 impl From<PrivateChannel> for ChannelId {
     /// Gets the Id of a private channel.
     fn from(private_channel: PrivateChannel) -> ChannelId {
@@ -1256,3 +1680,368 @@ impl<H: AsRef<Http>> MessagesIter<H> {
         })
     }
 }
+
+/// A helper class returned by [`ChannelId::thread_members_iter`]
+#[derive(Clone, Debug)]
+#[cfg(feature = "model")]
+pub struct ThreadMembersIter<H: AsRef<Http>> {
+    http: H,
+    channel_id: ChannelId,
+    with_member: bool,
+    guild_id: Option<GuildId>,
+    buffer: Vec<ThreadMember>,
+    after: Option<UserId>,
+    tried_fetch: bool,
+}
+
+#[cfg(feature = "model")]
+impl<H: AsRef<Http>> ThreadMembersIter<H> {
+    fn new(http: H, channel_id: ChannelId, with_member: bool) -> ThreadMembersIter<H> {
+        ThreadMembersIter {
+            http,
+            channel_id,
+            with_member,
+            guild_id: None,
+            buffer: Vec::new(),
+            after: None,
+            tried_fetch: false,
+        }
+    }
+
+    /// Fills the `self.buffer` cache of [`ThreadMember`]s.
+    ///
+    /// This drops any thread members that were currently in the buffer, so it should only be
+    /// called when `self.buffer` is empty. Additionally, this updates `self.after` so that the
+    /// next call does not return duplicate items. If there are no more thread members to be
+    /// fetched, then this marks `self.after` as None, indicating that no more calls ought to be
+    /// made.
+    ///
+    /// Discord only paginates thread members when `with_member` is set, so without it every
+    /// thread member is fetched in a single call.
+    async fn refresh(&mut self) -> Result<()> {
+        if self.with_member {
+            let guild_id = if let Some(guild_id) = self.guild_id {
+                guild_id
+            } else {
+                let channel = self.http.as_ref().get_channel(self.channel_id).await?;
+                let thread = channel.guild().ok_or(ModelError::InvalidChannelType)?;
+                *self.guild_id.insert(thread.guild_id)
+            };
+            let grab_size = crate::constants::THREAD_MEMBER_FETCH_LIMIT;
+
+            self.buffer = self
+                .channel_id
+                .thread_members(&self.http, guild_id, Some(grab_size), self.after)
+                .await?;
+
+            // Get the last member. If shorter than 100, there are no more results anyway
+            self.after = self.buffer.get(grab_size as usize - 1).map(|member| member.user_id);
+        } else {
+            self.buffer = self.channel_id.get_thread_members(&self.http).await?;
+            self.after = None;
+        }
+
+        // Reverse to optimize pop()
+        self.buffer.reverse();
+
+        self.tried_fetch = true;
+
+        Ok(())
+    }
+
+    /// Streams over all the thread members in a thread.
+    ///
+    /// Refer to [`ChannelId::thread_members_iter`] for more information.
+    pub fn stream(
+        http: impl AsRef<Http>,
+        channel_id: ChannelId,
+        with_member: bool,
+    ) -> impl Stream<Item = Result<ThreadMember>> {
+        let init_state = ThreadMembersIter::new(http, channel_id, with_member);
+
+        futures::stream::unfold(init_state, |mut state| async {
+            if state.buffer.is_empty() && state.after.is_some() || !state.tried_fetch {
+                if let Err(error) = state.refresh().await {
+                    return Some((Err(error), state));
+                }
+            }
+
+            state.buffer.pop().map(|entry| (Ok(entry), state))
+        })
+    }
+}
+
+/// Splits the number of messages still missing for [`ChannelId::messages_around`] into the limits
+/// of the next requests before and after the fetched messages, each capped at 100.
+#[cfg(feature = "model")]
+fn around_page_limits(remaining: usize, more_before: bool, more_after: bool) -> (u8, u8) {
+    let (before, after) = match (more_before, more_after) {
+        (true, true) => (remaining - remaining / 2, remaining / 2),
+        (true, false) => (remaining, 0),
+        (false, true) => (0, remaining),
+        (false, false) => (0, 0),
+    };
+    (before.min(100) as u8, after.min(100) as u8)
+}
+
+/// Returns a builder that only changes whether a message's embeds are suppressed, keeping its
+/// other flags.
+#[cfg(feature = "model")]
+fn suppress_embeds_builder(mut flags: MessageFlags, suppress: bool) -> EditMessage {
+    flags.set(MessageFlags::SUPPRESS_EMBEDS, suppress);
+    EditMessage::new().flags(flags)
+}
+
+#[cfg(all(test, feature = "model"))]
+mod tests {
+    use super::*;
+    use crate::json::to_value;
+
+    #[test]
+    fn suppress_embeds_payload() {
+        let flags = MessageFlags::CROSSPOSTED | MessageFlags::SUPPRESS_NOTIFICATIONS;
+
+        let builder = suppress_embeds_builder(flags, true);
+        assert_eq!(to_value(builder).unwrap(), json!({"flags": 1 | 4 | 4096}));
+
+        let builder = suppress_embeds_builder(flags | MessageFlags::SUPPRESS_EMBEDS, false);
+        assert_eq!(to_value(builder).unwrap(), json!({"flags": 1 | 4096}));
+    }
+
+    #[test]
+    fn around_page_limits_split() {
+        assert_eq!(around_page_limits(101, true, true), (51, 50));
+        assert_eq!(around_page_limits(1, true, true), (1, 0));
+        assert_eq!(around_page_limits(300, true, true), (100, 100));
+        assert_eq!(around_page_limits(150, false, true), (0, 100));
+        assert_eq!(around_page_limits(20, true, false), (20, 0));
+        assert_eq!(around_page_limits(20, false, false), (0, 0));
+    }
+
+    #[test]
+    fn thread_member_errors() {
+        use reqwest::{Method, StatusCode};
+
+        use crate::http::{DiscordJsonError, ErrorResponse, HttpError};
+
+        fn http_error(code: isize) -> Error {
+            Error::Http(HttpError::UnsuccessfulRequest(ErrorResponse {
+                status_code: StatusCode::BAD_REQUEST,
+                url: String::from("https://discord.com/api/v10/channels/1/thread-members/@me"),
+                method: Method::PUT,
+                error: DiscordJsonError {
+                    code,
+                    message: String::new(),
+                    errors: vec![],
+                },
+            }))
+        }
+
+        assert!(matches!(
+            thread_member_error(http_error(50083)),
+            Error::Model(ModelError::ThreadArchived)
+        ));
+        assert!(matches!(
+            thread_member_error(http_error(160005)),
+            Error::Model(ModelError::ThreadLocked)
+        ));
+        assert!(matches!(
+            thread_member_error(http_error(30033)),
+            Error::Model(ModelError::ThreadMembersFull)
+        ));
+        assert!(matches!(thread_member_error(http_error(50001)), Error::Http(_)));
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "testing")]
+    async fn webhook_or_create() {
+        use std::sync::Arc;
+
+        use crate::http::testing::MockTransport;
+        use crate::http::{HttpBuilder, LightMethod};
+
+        let mock = Arc::new(MockTransport::new());
+        let http = HttpBuilder::new("token")
+            .application_id(ApplicationId::new(5))
+            .transport(mock.clone())
+            .build();
+        let webhook = |id: u64, name: &str, application_id: u64| {
+            json!({
+                "id": id.to_string(),
+                "type": 1,
+                "channel_id": "1",
+                "name": name,
+                "avatar": null,
+                "token": "secret",
+                "application_id": application_id.to_string(),
+            })
+        };
+        let channel_id = ChannelId::new(1);
+
+        // Neither another application's webhook nor one with another name is reused.
+        mock.push_json(json!([webhook(2, "logs", 6), webhook(3, "other", 5)]));
+        mock.push_json(webhook(4, "logs", 5));
+        let created = channel_id.webhook_or_create(&http, "logs", None).await.unwrap();
+        assert_eq!(created.id, WebhookId::new(4));
+
+        mock.push_json(json!([webhook(2, "logs", 6), webhook(4, "logs", 5)]));
+        let found = channel_id.webhook_or_create(&http, "logs", None).await.unwrap();
+        assert_eq!(found.id, created.id);
+
+        let methods: Vec<_> = mock.requests().iter().map(|request| request.method).collect();
+        assert_eq!(methods, [LightMethod::Get, LightMethod::Post, LightMethod::Get]);
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "testing")]
+    async fn reaction_users_with_type() {
+        use std::sync::Arc;
+
+        use crate::http::testing::MockTransport;
+        use crate::http::HttpBuilder;
+
+        let mock = Arc::new(MockTransport::new());
+        let http = HttpBuilder::new("token").transport(mock.clone()).build();
+        let channel_id = ChannelId::new(1);
+
+        mock.push_json(json!([]));
+        mock.push_json(json!([]));
+        channel_id.reaction_users(&http, MessageId::new(2), '👍', None, None).await.unwrap();
+        channel_id
+            .reaction_users_with_type(
+                &http,
+                MessageId::new(2),
+                '👍',
+                ReactionTypes::Burst,
+                Some(10),
+                UserId::new(3),
+            )
+            .await
+            .unwrap();
+
+        let requests = mock.requests();
+        assert_eq!(requests[0].params, [("limit", String::from("50"))]);
+        assert_eq!(requests[1].params, [
+            ("limit", String::from("10")),
+            ("type", String::from("1")),
+            ("after", String::from("3")),
+        ]);
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "testing")]
+    async fn messages_around_exhaustion() {
+        use std::sync::Arc;
+
+        use crate::http::testing::MockTransport;
+        use crate::http::HttpBuilder;
+
+        let mock = Arc::new(MockTransport::new());
+        let http = HttpBuilder::new("token").transport(mock.clone()).build();
+        let messages = |ids: std::ops::RangeInclusive<u64>| {
+            let messages: Vec<_> = ids
+                .rev()
+                .map(|id| {
+                    json!({
+                        "id": id.to_string(),
+                        "channel_id": "1",
+                        "author": {"id": "2", "username": "bob", "discriminator": "0", "avatar": null},
+                        "content": "",
+                        "timestamp": "2024-01-01T00:00:00.000000+00:00",
+                        "edited_timestamp": null,
+                        "tts": false,
+                        "mention_everyone": false,
+                        "mentions": [],
+                        "mention_roles": [],
+                        "attachments": [],
+                        "embeds": [],
+                        "pinned": false,
+                        "type": 0,
+                    })
+                })
+                .collect();
+            json!(messages)
+        };
+
+        // The target message is the channel's newest one, so the first page falls short of the
+        // limit although there are more messages before it. Only the request after the target
+        // finds that side exhausted, after which the rest is fetched from before.
+        mock.push_json(messages(150..=200));
+        mock.push_json(messages(100..=149));
+        mock.push_json(json!([]));
+        mock.push_json(messages(60..=99));
+        let channel_id = ChannelId::new(1);
+        let around = channel_id.messages_around(&http, MessageId::new(200), 150).await.unwrap();
+
+        let ids: Vec<_> = around.iter().map(|m| m.id.get()).collect();
+        assert_eq!(ids, (60..=200).collect::<Vec<_>>());
+
+        let params = |limit: &str, target: &'static str, id: &str| {
+            vec![("limit", String::from(limit)), (target, String::from(id))]
+        };
+        let requests = mock.requests();
+        assert_eq!(requests[0].params, params("100", "around", "200"));
+        assert_eq!(requests[1].params, params("50", "before", "150"));
+        assert_eq!(requests[2].params, params("49", "after", "200"));
+        assert_eq!(requests[3].params, params("49", "before", "100"));
+        assert_eq!(mock.remaining_responses(), 0);
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "testing")]
+    async fn thread_members_iter() {
+        use std::sync::Arc;
+
+        use futures::TryStreamExt;
+
+        use crate::http::testing::MockTransport;
+        use crate::http::HttpBuilder;
+        use crate::json::to_value;
+
+        let mock = Arc::new(MockTransport::new());
+        let http = HttpBuilder::new("token").transport(mock.clone()).build();
+        let thread_member = |user_id: u64| {
+            json!({
+                "id": "1",
+                "user_id": user_id.to_string(),
+                "join_timestamp": "2023-01-01T00:00:00.000000+00:00",
+                "flags": 0,
+                "member": {
+                    "user": {"id": user_id.to_string(), "username": "member", "discriminator": "0"},
+                    "roles": [],
+                    "joined_at": "2023-01-01T00:00:00.000000+00:00",
+                    "deaf": false,
+                    "mute": false,
+                },
+            })
+        };
+        let thread = GuildChannel {
+            id: ChannelId::new(1),
+            guild_id: GuildId::new(2),
+            kind: ChannelType::PublicThread,
+            ..Default::default()
+        };
+
+        mock.push_json(to_value(thread).unwrap());
+        mock.push_json(Value::Array((10..110).map(thread_member).collect()));
+        mock.push_json(json!([thread_member(110)]));
+
+        let members: Vec<_> =
+            ChannelId::new(1).thread_members_iter(&http, true).try_collect().await.unwrap();
+        assert_eq!(members.len(), 101);
+        assert_eq!(members[100].user_id, UserId::new(110));
+        assert!(members.iter().all(|m| m.member.as_ref().unwrap().guild_id == GuildId::new(2)));
+
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 3);
+        assert_eq!(requests[1].params, [
+            ("with_member", String::from("true")),
+            ("limit", String::from("100")),
+        ]);
+        assert_eq!(requests[2].params, [
+            ("with_member", String::from("true")),
+            ("limit", String::from("100")),
+            ("after", String::from("109")),
+        ]);
+    }
+}
@@ -0,0 +1,224 @@
+use std::fmt;
+
+#[cfg(feature = "model")]
+use crate::builder::{CreateMessage, GetMessages};
+#[cfg(feature = "model")]
+use crate::http::{CacheHttp, Http};
+use crate::model::prelude::*;
+
+/// A group Direct Message channel between multiple users.
+///
+/// Bots can only be part of a group DM if they were added to it using the `gdm.join` OAuth2
+/// scope.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/channel#channel-object).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct GroupChannel {
+    /// The unique Id of the group channel.
+    ///
+    /// Can be used to calculate the first message's creation date.
+    pub id: ChannelId,
+    /// The Id of the last message sent.
+    pub last_message_id: Option<MessageId>,
+    /// Timestamp of the last time a [`Message`] was pinned.
+    pub last_pin_timestamp: Option<Timestamp>,
+    /// Indicator of the type of channel this is.
+    ///
+    /// This should always be [`ChannelType::GroupDm`].
+    #[serde(rename = "type")]
+    pub kind: ChannelType,
+    /// The name of the group, if one has been set.
+    pub name: Option<String>,
+    /// The hash of the group's icon, if one has been set.
+    pub icon: Option<ImageHash>,
+    /// The Id of the user that created the group.
+    pub owner_id: UserId,
+    /// The recipients of the group, excluding the current user.
+    #[serde(default)]
+    pub recipients: Vec<User>,
+    /// The Id of the application that created the group, if it was created by a bot.
+    pub application_id: Option<ApplicationId>,
+    /// Whether the group is managed by an application via the `gdm.join` OAuth2 scope.
+    #[serde(default)]
+    pub managed: bool,
+}
+
+#[cfg(feature = "model")]
+impl GroupChannel {
+    /// Broadcasts that the current user is typing to the group.
+    ///
+    /// See [ChannelId::broadcast_typing] for more details.
+    #[allow(clippy::missing_errors_doc)]
+    #[inline]
+    pub async fn broadcast_typing(&self, http: impl AsRef<Http>) -> Result<()> {
+        self.id.broadcast_typing(http).await
+    }
+
+    /// Leaves the group. If the current user is the owner, ownership is transferred to another
+    /// recipient.
+    #[allow(clippy::missing_errors_doc)]
+    #[inline]
+    pub async fn delete(&self, http: impl AsRef<Http>) -> Result<GroupChannel> {
+        self.id.delete(http).await?.group().ok_or(Error::Model(ModelError::InvalidChannelType))
+    }
+
+    /// Returns the formatted URL of the group's icon, if one exists.
+    #[must_use]
+    pub fn icon_url(&self) -> Option<String> {
+        self.icon.as_ref().map(|icon| cdn!("/channel-icons/{}/{}.webp", self.id, icon))
+    }
+
+    /// Gets messages from the channel.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user is not a recipient of the group.
+    #[inline]
+    pub async fn messages(
+        &self,
+        cache_http: impl CacheHttp,
+        builder: GetMessages,
+    ) -> Result<Vec<Message>> {
+        self.id.messages(cache_http, builder).await
+    }
+
+    /// Returns the name of the group, or a comma separated list of the recipients' usernames if
+    /// no name has been set.
+    #[must_use]
+    pub fn name(&self) -> String {
+        if let Some(name) = &self.name {
+            name.clone()
+        } else {
+            let names: Vec<&str> = self.recipients.iter().map(|u| u.name.as_str()).collect();
+            names.join(", ")
+        }
+    }
+
+    /// Sends a message with just the given message content in the channel.
+    ///
+    /// **Note**: Message content must be under 2000 unicode code points.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::MessageTooLong`] if the content length is over the above limit. See
+    /// [`CreateMessage::execute`] for more details.
+    ///
+    /// [`CreateMessage::execute`]: ../../builder/struct.CreateMessage.html#method.execute
+    #[inline]
+    pub async fn say(
+        &self,
+        cache_http: impl CacheHttp,
+        content: impl Into<String>,
+    ) -> Result<Message> {
+        self.id.say(cache_http, content).await
+    }
+
+    /// Sends a message to the channel.
+    ///
+    /// Refer to the documentation for [`CreateMessage`] for information regarding content
+    /// restrictions and requirements.
+    ///
+    /// # Errors
+    ///
+    /// See [`CreateMessage::execute`] for a list of possible errors, and their corresponding
+    /// reasons.
+    ///
+    /// [`CreateMessage::execute`]: ../../builder/struct.CreateMessage.html#method.execute
+    #[inline]
+    pub async fn send_message(
+        &self,
+        cache_http: impl CacheHttp,
+        builder: CreateMessage,
+    ) -> Result<Message> {
+        self.id.send_message(cache_http, builder).await
+    }
+}
+
+impl fmt::Display for GroupChannel {
+    /// Formats the group channel, displaying its name or its recipients' usernames.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(name) = &self.name {
+            return f.write_str(name);
+        }
+
+        for (i, user) in self.recipients.iter().enumerate() {
+            if i != 0 {
+                f.write_str(", ")?;
+            }
+            f.write_str(&user.name)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "model"))]
+mod tests {
+    use super::*;
+    use crate::json::{from_value, json};
+
+    fn group_dm_json() -> crate::json::Value {
+        json!({
+            "id": "10",
+            "type": 3,
+            "last_message_id": "20",
+            "name": null,
+            "icon": "a2e6ea2a5ae8d9e31b1e6d2a0c0b3c3e",
+            "owner_id": "1",
+            "application_id": "5",
+            "managed": true,
+            "recipients": [
+                {"id": "1", "username": "alice", "discriminator": "0", "avatar": null},
+                {"id": "2", "username": "bob", "discriminator": "0", "avatar": null},
+            ],
+        })
+    }
+
+    #[test]
+    fn group_dm_channel() {
+        let channel: Channel = from_value(group_dm_json()).unwrap();
+        assert_eq!(channel.id(), ChannelId::new(10));
+        assert_eq!(channel.position(), None);
+
+        let group = channel.group().unwrap();
+        assert_eq!(group.kind, ChannelType::GroupDm);
+        assert_eq!(group.owner_id, UserId::new(1));
+        assert_eq!(group.application_id, Some(ApplicationId::new(5)));
+        assert!(group.managed);
+        assert_eq!(group.recipients.len(), 2);
+        assert_eq!(group.to_string(), "alice, bob");
+        assert_eq!(
+            group.icon_url().as_deref(),
+            Some(concat!(
+                "https://cdn.discordapp.com/channel-icons/10/",
+                "a2e6ea2a5ae8d9e31b1e6d2a0c0b3c3e.webp"
+            )),
+        );
+    }
+
+    #[test]
+    fn group_dm_message() {
+        let value = json!({
+            "id": "30",
+            "channel_id": "10",
+            "author": {"id": "2", "username": "bob", "discriminator": "0", "avatar": null},
+            "content": "hello group",
+            "timestamp": "2024-01-01T00:00:00.000000+00:00",
+            "edited_timestamp": null,
+            "tts": false,
+            "mention_everyone": false,
+            "mentions": [],
+            "mention_roles": [],
+            "attachments": [],
+            "embeds": [],
+            "pinned": false,
+            "type": 0,
+        });
+
+        let message: Message = from_value(value).unwrap();
+        assert_eq!(message.channel_id, ChannelId::new(10));
+        assert_eq!(message.guild_id, None);
+        assert!(message.member.is_none());
+    }
+}
@@ -19,7 +19,7 @@ use crate::builder::{
     EditVoiceState,
     GetMessages,
 };
-#[cfg(feature = "cache")]
+#[cfg(all(feature = "cache", feature = "model"))]
 use crate::cache::{self, Cache};
 #[cfg(feature = "collector")]
 use crate::collector::{MessageCollector, ReactionCollector};
@@ -198,12 +198,22 @@ enum_number! {
 #[cfg(feature = "model")]
 impl GuildChannel {
     /// Whether or not this channel is text-based, meaning that it is possible to send messages.
+    /// See [`ChannelType::is_text_based`].
     #[must_use]
     pub fn is_text_based(&self) -> bool {
-        matches!(
-            self.kind,
-            ChannelType::Text | ChannelType::News | ChannelType::Voice | ChannelType::Stage
-        )
+        self.kind.is_text_based()
+    }
+
+    /// Whether users can connect to this channel. See [`ChannelType::is_voice_based`].
+    #[must_use]
+    pub fn is_voice_based(&self) -> bool {
+        self.kind.is_voice_based()
+    }
+
+    /// Whether this channel is a thread. See [`ChannelType::is_thread`].
+    #[must_use]
+    pub fn is_thread(&self) -> bool {
+        self.kind.is_thread()
     }
 
     /// Broadcasts to the channel that the current user is typing.
@@ -767,6 +777,114 @@ impl GuildChannel {
         Ok(guild.user_permissions_in(self, member))
     }
 
+    /// Whether the current user has joined this thread, according to the cache.
+    ///
+    /// Always returns `false` if this channel is not a thread.
+    #[cfg(feature = "cache")]
+    #[must_use]
+    pub fn is_joined(&self, cache: impl AsRef<Cache>) -> bool {
+        self.is_thread()
+            && cache
+                .as_ref()
+                .joined_threads(self.guild_id)
+                .is_some_and(|threads| threads.contains(&self.id))
+    }
+
+    /// Whether the user can send messages in this channel, i.e. has the [View Channel] and [Send
+    /// Messages] permissions, or [Send Messages in Threads] if this is a thread.
+    ///
+    /// This is computed from the role permissions and overwrites in the cache, so it can be used
+    /// to avoid requests that are bound to fail.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::GuildNotFound`] or [`ModelError::MemberNotFound`] if the channel's
+    /// guild or the member could not be found in the [`Cache`]. Returns a
+    /// [`ModelError::ChannelNotFound`] if this is a thread whose parent channel is not cached.
+    ///
+    /// [View Channel]: Permissions::VIEW_CHANNEL
+    /// [Send Messages]: Permissions::SEND_MESSAGES
+    /// [Send Messages in Threads]: Permissions::SEND_MESSAGES_IN_THREADS
+    #[cfg(feature = "cache")]
+    pub fn can_send_message(
+        &self,
+        cache: impl AsRef<Cache>,
+        user_id: impl Into<UserId>,
+    ) -> Result<bool> {
+        self.can_send_with(cache, user_id.into(), Permissions::empty())
+    }
+
+    /// Whether the user can send messages with embedded links in this channel, i.e. can
+    /// [send messages](Self::can_send_message) and has the [Embed Links] permission.
+    ///
+    /// # Errors
+    ///
+    /// See [`Self::can_send_message`].
+    ///
+    /// [Embed Links]: Permissions::EMBED_LINKS
+    #[cfg(feature = "cache")]
+    pub fn can_embed(&self, cache: impl AsRef<Cache>, user_id: impl Into<UserId>) -> Result<bool> {
+        self.can_send_with(cache, user_id.into(), Permissions::EMBED_LINKS)
+    }
+
+    /// Whether the user can send messages with attachments in this channel, i.e. can
+    /// [send messages](Self::can_send_message) and has the [Attach Files] permission.
+    ///
+    /// # Errors
+    ///
+    /// See [`Self::can_send_message`].
+    ///
+    /// [Attach Files]: Permissions::ATTACH_FILES
+    #[cfg(feature = "cache")]
+    pub fn can_attach(&self, cache: impl AsRef<Cache>, user_id: impl Into<UserId>) -> Result<bool> {
+        self.can_send_with(cache, user_id.into(), Permissions::ATTACH_FILES)
+    }
+
+    #[cfg(feature = "cache")]
+    fn can_send_with(
+        &self,
+        cache: impl AsRef<Cache>,
+        user_id: UserId,
+        extra: Permissions,
+    ) -> Result<bool> {
+        let guild = self.guild(&cache).ok_or(Error::Model(ModelError::GuildNotFound))?;
+        let member = guild.members.get(&user_id).ok_or(Error::Model(ModelError::MemberNotFound))?;
+
+        // Threads have no overwrites of their own and use those of their parent channel.
+        let (channel, send) = if self.is_thread() {
+            let parent = self.parent_id.and_then(|parent_id| guild.channels.get(&parent_id));
+            let parent = parent.ok_or(Error::Model(ModelError::ChannelNotFound))?;
+            (parent, Permissions::SEND_MESSAGES_IN_THREADS)
+        } else {
+            (self, Permissions::SEND_MESSAGES)
+        };
+
+        let required = Permissions::VIEW_CHANNEL | send | extra;
+        Ok(guild.user_permissions_in(channel, member).contains(required))
+    }
+
+    /// Explains how a [`Member`]'s permissions in this channel are derived.
+    ///
+    /// Unlike [`Guild::user_permissions_in`], which only returns the final permissions, this
+    /// returns the base permissions from the member's roles and every channel overwrite applied
+    /// to them, in order. Timed out members are taken into account as well.
+    ///
+    /// This is calculated from the given data only, and does not make any requests.
+    #[must_use]
+    pub fn permission_overwrite_trace(&self, guild: &Guild, member: &Member) -> PermissionTrace {
+        let timed_out =
+            member.communication_disabled_until.is_some_and(|until| until > Timestamp::now());
+        Guild::permission_trace_(
+            Some(self),
+            member.user.id,
+            &member.roles,
+            timed_out,
+            guild.id,
+            &guild.roles,
+            guild.owner_id,
+        )
+    }
+
     /// Calculates the permissions of a role.
     ///
     /// The Id of the argument must be a [`Role`] of the [`Guild`] that the channel is in.
@@ -858,6 +976,24 @@ impl GuildChannel {
         self.id.reaction_users(http, message_id, reaction_type, limit, after).await
     }
 
+    /// Gets the list of [`User`]s who have reacted to a [`Message`] with a certain [`Emoji`] and
+    /// type of reaction. See [`ChannelId::reaction_users_with_type`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    pub async fn reaction_users_with_type(
+        &self,
+        http: impl AsRef<Http>,
+        message_id: impl Into<MessageId>,
+        reaction_type: impl Into<ReactionType>,
+        kind: ReactionTypes,
+        limit: Option<u8>,
+        after: impl Into<Option<UserId>>,
+    ) -> Result<Vec<User>> {
+        self.id.reaction_users_with_type(http, message_id, reaction_type, kind, limit, after).await
+    }
+
     /// Sends a message with just the given message content in the channel.
     ///
     /// **Note**: Message content must be under 2000 unicode code points.
@@ -1072,8 +1208,8 @@ impl GuildChannel {
         builder: CreateWebhook<'_>,
     ) -> Result<Webhook> {
         // forum channels are not text-based, but webhooks can be created in them
-        // and used to send messages in their posts
-        if !self.is_text_based() && self.kind != ChannelType::Forum {
+        // and used to send messages in their posts. Threads use the webhooks of their parent.
+        if self.is_thread() || !(self.is_text_based() || self.kind == ChannelType::Forum) {
             return Err(Error::Model(ModelError::InvalidChannelType));
         }
 
@@ -1215,3 +1351,110 @@ pub struct PartialGuildChannel {
     #[serde(rename = "type")]
     pub kind: ChannelType,
 }
+
+#[cfg(all(test, feature = "cache"))]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    use super::*;
+
+    fn cache(settings: cache::Settings) -> Cache {
+        let (guild_id, user_id) = (GuildId::new(2), UserId::new(3));
+        let everyone = Role {
+            id: RoleId::new(2),
+            guild_id,
+            permissions: Permissions::VIEW_CHANNEL
+                | Permissions::SEND_MESSAGES
+                | Permissions::EMBED_LINKS
+                | Permissions::ATTACH_FILES,
+            ..Default::default()
+        };
+        let member = Member {
+            user: User {
+                id: user_id,
+                ..Default::default()
+            },
+            guild_id,
+            ..Default::default()
+        };
+        let channel = GuildChannel {
+            id: ChannelId::new(4),
+            guild_id,
+            permission_overwrites: vec![
+                PermissionOverwrite::member(user_id).deny(Permissions::ATTACH_FILES)
+            ],
+            ..Default::default()
+        };
+
+        let cache = Cache::new_with_settings(settings);
+        cache.update(&mut GuildCreateEvent {
+            guild: Guild {
+                id: guild_id,
+                roles: HashMap::from([(everyone.id, everyone)]),
+                members: HashMap::from([(user_id, member)]),
+                channels: HashMap::from([(channel.id, channel)]),
+                ..Default::default()
+            },
+        });
+        cache
+    }
+
+    #[test]
+    fn send_permissions() {
+        let cache = cache(cache::Settings::default());
+        let channel = cache.guild(GuildId::new(2)).unwrap().channels[&ChannelId::new(4)].clone();
+        let user_id = UserId::new(3);
+
+        assert!(channel.can_send_message(&cache, user_id).unwrap());
+        assert!(channel.can_embed(&cache, user_id).unwrap());
+        assert!(!channel.can_attach(&cache, user_id).unwrap());
+        assert!(channel.can_send_message(&cache, UserId::new(5)).is_err());
+
+        // Threads require a separate permission, which the @everyone role lacks.
+        let thread = GuildChannel {
+            id: ChannelId::new(6),
+            kind: ChannelType::PublicThread,
+            parent_id: Some(channel.id),
+            thread_metadata: Some(
+                crate::json::from_value(crate::json::json!({
+                    "archived": false,
+                    "auto_archive_duration": 60,
+                    "archive_timestamp": null,
+                }))
+                .unwrap(),
+            ),
+            ..channel
+        };
+        assert!(!thread.can_send_message(&cache, user_id).unwrap());
+    }
+
+    #[test]
+    fn precheck_permissions() {
+        let required = Permissions::SEND_MESSAGES | Permissions::ATTACH_FILES;
+        for precheck in [true, false] {
+            let cache = cache(cache::Settings {
+                precheck_permissions: precheck,
+                ..Default::default()
+            });
+            cache.user.write().id = UserId::new(3);
+
+            let result = crate::utils::user_has_perms_cache(&cache, ChannelId::new(4), required);
+            if precheck {
+                assert!(matches!(
+                    result,
+                    Err(Error::Model(ModelError::InvalidPermissions { required: r, .. }))
+                        if r == required
+                ));
+            } else {
+                assert!(result.is_ok());
+            }
+
+            let (cache, http) = (Arc::new(cache), Http::new("token"));
+            let manage_roles = Permissions::MANAGE_ROLES;
+            let result =
+                crate::utils::user_has_guild_perms((&cache, &http), GuildId::new(2), manage_roles);
+            assert_eq!(result.is_ok(), !precheck);
+        }
+    }
+}
@@ -49,12 +49,16 @@ pub struct Message {
     /// Indicator of whether the command is to be played back via text-to-speech.
     ///
     /// In the client, this is done via the `/tts` slash command.
+    #[serde(default)]
     pub tts: bool,
     /// Indicator of whether the message mentions everyone.
+    #[serde(default)]
     pub mention_everyone: bool,
     /// Array of users mentioned in the message.
+    #[serde(default)]
     pub mentions: Vec<User>,
     /// Array of [`Role`]s' Ids mentioned in the message.
+    #[serde(default)]
     pub mention_roles: Vec<RoleId>,
     /// Channels specifically mentioned in this message.
     ///
@@ -76,8 +80,10 @@ pub struct Message {
     #[serde(default = "Vec::new")]
     pub mention_channels: Vec<ChannelMention>,
     /// An vector of the files attached to a message.
+    #[serde(default)]
     pub attachments: Vec<Attachment>,
     /// Array of embeds sent with the message.
+    #[serde(default)]
     pub embeds: Vec<Embed>,
     /// Array of reactions performed on the message.
     #[serde(default)]
@@ -86,6 +92,7 @@ pub struct Message {
     #[serde(default)]
     pub nonce: Option<Nonce>,
     /// Indicator of whether the message is pinned.
+    #[serde(default)]
     pub pinned: bool,
     /// The Id of the webhook that sent this message, if one did.
     pub webhook_id: Option<WebhookId>,
@@ -100,12 +107,19 @@ pub struct Message {
     /// If the message is an Interaction or application-owned webhook, this is the id of the
     /// application.
     pub application_id: Option<ApplicationId>,
-    /// Reference data sent with crossposted messages.
+    /// Reference data sent with crossposted, replied to and forwarded messages.
     pub message_reference: Option<MessageReference>,
     /// Bit flags describing extra features of the message.
     pub flags: Option<MessageFlags>,
     /// The message that was replied to using this message.
     pub referenced_message: Option<Box<Message>>, // Boxed to avoid recursion
+    /// The contents of the forwarded message, if this message is a [forward].
+    ///
+    /// Forwarded messages have no content of their own, so this is where to read it from.
+    ///
+    /// [forward]: MessageReferenceKind::Forward
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub message_snapshots: Vec<MessageSnapshot>,
     #[cfg_attr(
         all(not(ignore_serenity_deprecated), feature = "unstable_discord_api"),
         deprecated = "Use interaction_metadata"
@@ -370,6 +384,62 @@ impl Message {
         Ok(())
     }
 
+    /// Suppresses the embeds of the message, keeping all other flags of the message intact.
+    ///
+    /// Discord may attach link embeds after the message was sent, racing with this edit. If the
+    /// edited message still contains embeds, the edit is retried once after a short delay.
+    ///
+    /// **Note**: Requires the [Manage Messages] permission if the current user is not the author.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ModelError::CannotEditVoiceMessage`] if the message is a voice message, or
+    /// [`Error::Http`] if the current user lacks permission.
+    ///
+    /// [Manage Messages]: Permissions::MANAGE_MESSAGES
+    pub async fn suppress_embeds(&mut self, cache_http: impl CacheHttp) -> Result<()> {
+        self.set_suppress_embeds(&cache_http, true).await?;
+
+        if !self.embeds.is_empty() {
+            tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+            self.set_suppress_embeds(&cache_http, true).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Removes the suppression of embeds from the message, keeping all other flags of the message
+    /// intact.
+    ///
+    /// **Note**: Requires the [Manage Messages] permission if the current user is not the author.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ModelError::CannotEditVoiceMessage`] if the message is a voice message, or
+    /// [`Error::Http`] if the current user lacks permission.
+    ///
+    /// [Manage Messages]: Permissions::MANAGE_MESSAGES
+    pub async fn unsuppress_embeds(&mut self, cache_http: impl CacheHttp) -> Result<()> {
+        self.set_suppress_embeds(cache_http, false).await
+    }
+
+    async fn set_suppress_embeds(
+        &mut self,
+        cache_http: impl CacheHttp,
+        suppress: bool,
+    ) -> Result<()> {
+        let builder = self.suppress_embeds_builder(suppress);
+        self.edit(cache_http, builder).await
+    }
+
+    /// Builds the edit setting [`MessageFlags::SUPPRESS_EMBEDS`], keeping the other flags.
+    fn suppress_embeds_builder(&self, suppress: bool) -> EditMessage {
+        let mut flags = self.flags.unwrap_or_default();
+        flags.set(MessageFlags::SUPPRESS_EMBEDS, suppress);
+
+        EditMessage::new().flags(flags)
+    }
+
     /// Returns message content, but with user and role mentions replaced with
     /// names and everyone/here mentions cancelled.
     #[cfg(feature = "cache")]
@@ -448,11 +518,35 @@ impl Message {
         self.channel_id.reaction_users(http, self.id, reaction_type, limit, after).await
     }
 
+    /// Gets the list of [`User`]s who have reacted to this message with a certain [`Emoji`] and
+    /// type of reaction. See [`ChannelId::reaction_users_with_type`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    #[inline]
+    pub async fn reaction_users_with_type(
+        &self,
+        http: impl AsRef<Http>,
+        reaction_type: impl Into<ReactionType>,
+        kind: ReactionTypes,
+        limit: Option<u8>,
+        after: impl Into<Option<UserId>>,
+    ) -> Result<Vec<User>> {
+        self.channel_id
+            .reaction_users_with_type(http, self.id, reaction_type, kind, limit, after)
+            .await
+    }
+
     /// Returns the associated [`Guild`] for the message if one is in the cache.
     ///
     /// Returns [`None`] if the guild's Id could not be found via [`Self::guild_id`] or if the
     /// Guild itself is not cached.
     ///
+    /// The guild is not cloned: the returned [`GuildRef`] borrows it from the cache and
+    /// dereferences to it. The guild stays locked while the reference is alive, so drop it before
+    /// any `.await` point, and clone only the fields needed beyond that.
+    ///
     /// Requires the `cache` feature be enabled.
     #[cfg(feature = "cache")]
     pub fn guild<'a>(&self, cache: &'a Cache) -> Option<GuildRef<'a>> {
@@ -884,8 +978,12 @@ impl Message {
         #[cfg(feature = "cache")]
         if let Some(cache) = cache_http.cache() {
             if let Some(guild) = cache.guild(self.guild_id?) {
-                let channel = guild.channels.get(&self.channel_id)?;
-                return if channel.thread_metadata.is_some() {
+                // Threads are not stored with the other channels.
+                let channel = guild
+                    .channels
+                    .get(&self.channel_id)
+                    .or_else(|| guild.threads.iter().find(|thread| thread.id == self.channel_id))?;
+                return if channel.is_thread() {
                     let thread_parent = guild.channels.get(&channel.parent_id?)?;
                     thread_parent.parent_id
                 } else {
@@ -895,7 +993,7 @@ impl Message {
         }
 
         let channel = self.channel_id.to_channel(&cache_http).await.ok()?.guild()?;
-        if channel.thread_metadata.is_some() {
+        if channel.is_thread() {
             let thread_parent = channel.parent_id?.to_channel(cache_http).await.ok()?.guild()?;
             thread_parent.parent_id
         } else {
@@ -1086,13 +1184,16 @@ pub struct MessageActivity {
     pub party_id: Option<String>,
 }
 
-/// Reference data sent with crossposted messages.
+/// Reference data sent with crossposted, replied to and forwarded messages.
 ///
 /// [Discord docs](https://discord.com/developers/docs/resources/channel#message-reference-object-message-reference-structure).
 #[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct MessageReference {
+    /// The type of reference.
+    #[serde(rename = "type", default)]
+    pub kind: MessageReferenceKind,
     /// ID of the originating message.
     pub message_id: Option<MessageId>,
     /// ID of the originating message's channel.
@@ -1107,6 +1208,7 @@ pub struct MessageReference {
 impl From<&Message> for MessageReference {
     fn from(m: &Message) -> Self {
         Self {
+            kind: MessageReferenceKind::default(),
             message_id: Some(m.id),
             channel_id: m.channel_id,
             guild_id: m.guild_id,
@@ -1118,6 +1220,7 @@ impl From<&Message> for MessageReference {
 impl From<(ChannelId, MessageId)> for MessageReference {
     fn from(pair: (ChannelId, MessageId)) -> Self {
         Self {
+            kind: MessageReferenceKind::default(),
             message_id: Some(pair.1),
             channel_id: pair.0,
             guild_id: None,
@@ -1126,6 +1229,138 @@ impl From<(ChannelId, MessageId)> for MessageReference {
     }
 }
 
+impl MessageReference {
+    /// Creates a reference forwarding the given message.
+    #[must_use]
+    pub fn forward(message: &Message) -> Self {
+        Self {
+            kind: MessageReferenceKind::Forward,
+            ..message.into()
+        }
+    }
+}
+
+enum_number! {
+    /// The type of a [`MessageReference`].
+    ///
+    /// [Discord docs](https://discord.com/developers/docs/resources/channel#message-reference-types).
+    #[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
+    #[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+    #[serde(from = "u8", into = "u8")]
+    #[non_exhaustive]
+    pub enum MessageReferenceKind {
+        /// A reply to, or a crosspost of the referenced message.
+        #[default]
+        Default = 0,
+        /// A forward of the referenced message, whose contents are included in
+        /// [`Message::message_snapshots`].
+        Forward = 1,
+        _ => Unknown(u8),
+    }
+}
+
+/// A snapshot of a forwarded message, taken when it was forwarded.
+///
+/// Only contains a subset of the fields of a [`Message`].
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/channel#message-snapshot-object).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(from = "MessageSnapshotData", into = "MessageSnapshotData")]
+#[non_exhaustive]
+pub struct MessageSnapshot {
+    /// The type of the forwarded message.
+    pub kind: MessageType,
+    /// The content of the forwarded message.
+    pub content: String,
+    /// The embeds of the forwarded message.
+    pub embeds: Vec<Embed>,
+    /// The files attached to the forwarded message.
+    pub attachments: Vec<Attachment>,
+    /// When the forwarded message was sent.
+    pub timestamp: Timestamp,
+    /// When the forwarded message was last edited, if it was.
+    pub edited_timestamp: Option<Timestamp>,
+    /// The flags of the forwarded message.
+    pub flags: Option<MessageFlags>,
+    /// The users mentioned in the forwarded message.
+    pub mentions: Vec<User>,
+    /// The Ids of the roles mentioned in the forwarded message.
+    pub mention_roles: Vec<RoleId>,
+    /// The stickers sent with the forwarded message.
+    pub sticker_items: Vec<StickerItem>,
+    /// The components of the forwarded message.
+    pub components: Vec<ActionRow>,
+}
+
+/// Discord wraps the fields of a [`MessageSnapshot`] in a `message` object.
+#[derive(Deserialize, Serialize)]
+struct MessageSnapshotData {
+    message: MessageSnapshotMessage,
+}
+
+#[derive(Deserialize, Serialize)]
+struct MessageSnapshotMessage {
+    #[serde(rename = "type")]
+    kind: MessageType,
+    #[serde(default)]
+    content: String,
+    #[serde(default)]
+    embeds: Vec<Embed>,
+    #[serde(default)]
+    attachments: Vec<Attachment>,
+    timestamp: Timestamp,
+    edited_timestamp: Option<Timestamp>,
+    flags: Option<MessageFlags>,
+    #[serde(default)]
+    mentions: Vec<User>,
+    #[serde(default)]
+    mention_roles: Vec<RoleId>,
+    #[serde(default)]
+    sticker_items: Vec<StickerItem>,
+    #[serde(default)]
+    components: Vec<ActionRow>,
+}
+
+impl From<MessageSnapshotData> for MessageSnapshot {
+    fn from(data: MessageSnapshotData) -> Self {
+        let message = data.message;
+        Self {
+            kind: message.kind,
+            content: message.content,
+            embeds: message.embeds,
+            attachments: message.attachments,
+            timestamp: message.timestamp,
+            edited_timestamp: message.edited_timestamp,
+            flags: message.flags,
+            mentions: message.mentions,
+            mention_roles: message.mention_roles,
+            sticker_items: message.sticker_items,
+            components: message.components,
+        }
+    }
+}
+
+impl From<MessageSnapshot> for MessageSnapshotData {
+    fn from(snapshot: MessageSnapshot) -> Self {
+        Self {
+            message: MessageSnapshotMessage {
+                kind: snapshot.kind,
+                content: snapshot.content,
+                embeds: snapshot.embeds,
+                attachments: snapshot.attachments,
+                timestamp: snapshot.timestamp,
+                edited_timestamp: snapshot.edited_timestamp,
+                flags: snapshot.flags,
+                mentions: snapshot.mentions,
+                mention_roles: snapshot.mention_roles,
+                sticker_items: snapshot.sticker_items,
+                components: snapshot.components,
+            },
+        }
+    }
+}
+
 /// [Discord docs](https://discord.com/developers/docs/resources/channel#channel-mention-object).
 #[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -1374,3 +1609,89 @@ pub struct PollAnswerCount {
     pub count: u64,
     pub me_voted: bool,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json::{from_value, json, to_value};
+
+    #[test]
+    fn forwarded_message_deserialize() {
+        let value = json!({
+            "id": "3",
+            "channel_id": "2",
+            "author": {"id": "1", "username": "alice", "discriminator": "0", "avatar": null},
+            "content": "",
+            "timestamp": "2024-10-01T00:00:00.000000+00:00",
+            "edited_timestamp": null,
+            "type": 0,
+            "message_reference": {
+                "type": 1,
+                "message_id": "20",
+                "channel_id": "10",
+                "guild_id": "5",
+            },
+            "message_snapshots": [{
+                "message": {
+                    "type": 0,
+                    "content": "forward me",
+                    "embeds": [],
+                    "attachments": [],
+                    "timestamp": "2024-09-01T00:00:00.000000+00:00",
+                    "edited_timestamp": null,
+                    "flags": 0,
+                    "mentions": [],
+                    "mention_roles": [],
+                    "components": [],
+                },
+            }],
+        });
+
+        let message: Message = from_value(value).unwrap();
+        let reference = message.message_reference.unwrap();
+        assert_eq!(reference.kind, MessageReferenceKind::Forward);
+        assert_eq!(reference.message_id, Some(MessageId::new(20)));
+        assert_eq!(message.message_snapshots.len(), 1);
+        assert_eq!(message.message_snapshots[0].content, "forward me");
+    }
+
+    #[test]
+    fn forward_reference_serialize() {
+        let message = Message {
+            id: MessageId::new(20),
+            channel_id: ChannelId::new(10),
+            guild_id: Some(GuildId::new(5)),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            to_value(MessageReference::forward(&message)).unwrap(),
+            json!({
+                "type": 1,
+                "message_id": "20",
+                "channel_id": "10",
+                "guild_id": "5",
+                "fail_if_not_exists": null,
+            })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "model")]
+    fn suppress_embeds_keeps_flags() {
+        let mut message = Message::default();
+        message.flags = Some(MessageFlags::CROSSPOSTED | MessageFlags::SUPPRESS_NOTIFICATIONS);
+
+        let flags = message.flags.unwrap() | MessageFlags::SUPPRESS_EMBEDS;
+        assert_eq!(message.suppress_embeds_builder(true), EditMessage::new().flags(flags));
+
+        message.flags = Some(MessageFlags::SUPPRESS_EMBEDS | MessageFlags::EPHEMERAL);
+        let flags = MessageFlags::EPHEMERAL;
+        assert_eq!(message.suppress_embeds_builder(false), EditMessage::new().flags(flags));
+
+        // Messages without flags only get the embed suppression.
+        message.flags = None;
+        let flags = MessageFlags::SUPPRESS_EMBEDS;
+        assert_eq!(message.suppress_embeds_builder(true), EditMessage::new().flags(flags));
+    }
+}
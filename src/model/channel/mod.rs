@@ -3,9 +3,11 @@
 mod attachment;
 mod channel_id;
 mod embed;
+mod group_channel;
 mod guild_channel;
 mod message;
 mod partial_channel;
+mod permission_trace;
 mod private_channel;
 mod reaction;
 
@@ -17,9 +19,11 @@ use serde::ser::SerializeMap as _;
 pub use self::attachment::*;
 pub use self::channel_id::*;
 pub use self::embed::*;
+pub use self::group_channel::*;
 pub use self::guild_channel::*;
 pub use self::message::*;
 pub use self::partial_channel::*;
+pub use self::permission_trace::*;
 pub use self::private_channel::*;
 pub use self::reaction::*;
 #[cfg(feature = "model")]
@@ -44,6 +48,8 @@ pub enum Channel {
     /// A private channel to another [`User`] (Direct Message). No other users may access the
     /// channel.
     Private(PrivateChannel),
+    /// A group Direct Message channel between multiple [`User`]s.
+    Group(GroupChannel),
 }
 
 #[cfg(feature = "model")]
@@ -109,6 +115,18 @@ impl Channel {
         }
     }
 
+    /// Converts from [`Channel`] to `Option<GroupChannel>`.
+    ///
+    /// Converts `self` into an `Option<GroupChannel>`, consuming `self`, and discarding any other
+    /// kind of channel.
+    #[must_use]
+    pub fn group(self) -> Option<GroupChannel> {
+        match self {
+            Self::Group(lock) => Some(lock),
+            _ => None,
+        }
+    }
+
     /// If this is a category channel, returns it.
     #[must_use]
     pub fn category(self) -> Option<GuildChannel> {
@@ -134,6 +152,9 @@ impl Channel {
             Self::Private(private_channel) => {
                 private_channel.delete(cache_http.http()).await?;
             },
+            Self::Group(group_channel) => {
+                group_channel.delete(cache_http.http()).await?;
+            },
         }
 
         Ok(())
@@ -148,29 +169,62 @@ impl Channel {
         match self {
             #[allow(deprecated)]
             Self::Guild(channel) => channel.is_nsfw(),
-            Self::Private(_) => false,
+            Self::Private(_) | Self::Group(_) => false,
         }
     }
 
-    /// Retrieves the Id of the inner [`GuildChannel`], or [`PrivateChannel`].
+    /// Retrieves the Id of the inner [`GuildChannel`], [`PrivateChannel`], or [`GroupChannel`].
     #[inline]
     #[must_use]
     pub const fn id(&self) -> ChannelId {
         match self {
             Self::Guild(ch) => ch.id,
             Self::Private(ch) => ch.id,
+            Self::Group(ch) => ch.id,
         }
     }
 
+    /// Retrieves the type of the inner [`GuildChannel`], [`PrivateChannel`], or [`GroupChannel`].
+    #[inline]
+    #[must_use]
+    pub const fn kind(&self) -> ChannelType {
+        match self {
+            Self::Guild(ch) => ch.kind,
+            Self::Private(ch) => ch.kind,
+            Self::Group(ch) => ch.kind,
+        }
+    }
+
+    /// Whether messages can be sent in the channel. See [`ChannelType::is_text_based`].
+    #[inline]
+    #[must_use]
+    pub const fn is_text_based(&self) -> bool {
+        self.kind().is_text_based()
+    }
+
+    /// Whether users can connect to the channel. See [`ChannelType::is_voice_based`].
+    #[inline]
+    #[must_use]
+    pub const fn is_voice_based(&self) -> bool {
+        self.kind().is_voice_based()
+    }
+
+    /// Whether the channel is a thread. See [`ChannelType::is_thread`].
+    #[inline]
+    #[must_use]
+    pub const fn is_thread(&self) -> bool {
+        self.kind().is_thread()
+    }
+
     /// Retrieves the position of the inner [`GuildChannel`].
     ///
-    /// In DMs (private channel) it will return None.
+    /// In DMs (private and group channels) it will return None.
     #[inline]
     #[must_use]
     pub const fn position(&self) -> Option<u16> {
         match self {
             Self::Guild(channel) => Some(channel.position),
-            Self::Private(_) => None,
+            Self::Private(_) | Self::Group(_) => None,
         }
     }
 }
@@ -194,6 +248,7 @@ impl<'de> Deserialize<'de> for Channel {
         match kind {
             0 | 2 | 4 | 5 | 10 | 11 | 12 | 13 | 14 | 15 => from_value(value).map(Channel::Guild),
             1 => from_value(value).map(Channel::Private),
+            3 => from_value(value).map(Channel::Group),
             _ => return Err(DeError::custom("Unknown channel type")),
         }
         .map_err(DeError::custom)
@@ -205,12 +260,14 @@ impl fmt::Display for Channel {
     ///
     /// This will return a different format for each type of channel:
     /// - [`PrivateChannel`]s: the recipient's name;
+    /// - [`GroupChannel`]s: the group's name, or its recipients' names;
     /// - [`GuildChannel`]s: a string mentioning the channel that users who can see the channel can
     ///   click on.
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Guild(ch) => fmt::Display::fmt(&ch.id.mention(), f),
             Self::Private(ch) => fmt::Display::fmt(&ch.recipient.name, f),
+            Self::Group(ch) => fmt::Display::fmt(ch, f),
         }
     }
 }
@@ -231,7 +288,7 @@ enum_number! {
         Private = 1,
         /// An indicator that the channel is a voice [`GuildChannel`].
         Voice = 2,
-        /// An indicator that the channel is a group DM.
+        /// An indicator that the channel is a [`GroupChannel`].
         GroupDm = 3,
         /// An indicator that the channel is a channel category.
         Category = 4,
@@ -277,6 +334,43 @@ impl ChannelType {
             Self::Unknown(_) => "unknown",
         }
     }
+
+    /// Whether messages can be sent in channels of this type, including the text chat of voice
+    /// and stage channels.
+    ///
+    /// Returns `false` for categories, directories and forums, whose messages are sent in threads,
+    /// as well as for unknown types.
+    #[must_use]
+    pub const fn is_text_based(&self) -> bool {
+        matches!(
+            self,
+            Self::Text
+                | Self::Private
+                | Self::Voice
+                | Self::GroupDm
+                | Self::News
+                | Self::NewsThread
+                | Self::PublicThread
+                | Self::PrivateThread
+                | Self::Stage
+        )
+    }
+
+    /// Whether users can connect to channels of this type, i.e. voice and stage channels.
+    ///
+    /// Returns `false` for unknown types.
+    #[must_use]
+    pub const fn is_voice_based(&self) -> bool {
+        matches!(self, Self::Voice | Self::Stage)
+    }
+
+    /// Whether channels of this type are threads.
+    ///
+    /// Returns `false` for unknown types.
+    #[must_use]
+    pub const fn is_thread(&self) -> bool {
+        matches!(self, Self::NewsThread | Self::PublicThread | Self::PrivateThread)
+    }
 }
 
 /// [Discord docs](https://discord.com/developers/docs/resources/channel#overwrite-object).
@@ -343,6 +437,55 @@ pub struct PermissionOverwrite {
     pub kind: PermissionOverwriteType,
 }
 
+impl PermissionOverwrite {
+    /// Creates an overwrite for a role which neither allows nor denies any permissions.
+    ///
+    /// # Examples
+    ///
+    /// Deny the mute role sending messages:
+    ///
+    /// ```rust
+    /// use serenity::model::channel::PermissionOverwrite;
+    /// use serenity::model::id::RoleId;
+    /// use serenity::model::Permissions;
+    ///
+    /// let overwrite =
+    ///     PermissionOverwrite::role(RoleId::new(1)).deny(Permissions::SEND_MESSAGES);
+    /// ```
+    #[must_use]
+    pub fn role(role_id: impl Into<RoleId>) -> Self {
+        Self {
+            allow: Permissions::empty(),
+            deny: Permissions::empty(),
+            kind: PermissionOverwriteType::Role(role_id.into()),
+        }
+    }
+
+    /// Creates an overwrite for a member which neither allows nor denies any permissions.
+    #[must_use]
+    pub fn member(user_id: impl Into<UserId>) -> Self {
+        Self {
+            allow: Permissions::empty(),
+            deny: Permissions::empty(),
+            kind: PermissionOverwriteType::Member(user_id.into()),
+        }
+    }
+
+    /// Sets the permissions which are explicitly allowed.
+    #[must_use]
+    pub fn allow(mut self, permissions: Permissions) -> Self {
+        self.allow = permissions;
+        self
+    }
+
+    /// Sets the permissions which are explicitly denied.
+    #[must_use]
+    pub fn deny(mut self, permissions: Permissions) -> Self {
+        self.deny = permissions;
+        self
+    }
+}
+
 /// The type of edit being made to a Channel's permissions.
 ///
 /// This is for use with methods such as [`GuildChannel::create_permission`].
@@ -588,3 +731,49 @@ bitflags! {
         const REQUIRE_TAG = 1 << 4;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json::{assert_json, json};
+
+    #[test]
+    fn channel_type_classification() {
+        // (kind, text based, voice based, thread)
+        let cases = [
+            (ChannelType::Text, true, false, false),
+            (ChannelType::Private, true, false, false),
+            (ChannelType::Voice, true, true, false),
+            (ChannelType::GroupDm, true, false, false),
+            (ChannelType::Category, false, false, false),
+            (ChannelType::News, true, false, false),
+            (ChannelType::NewsThread, true, false, true),
+            (ChannelType::PublicThread, true, false, true),
+            (ChannelType::PrivateThread, true, false, true),
+            (ChannelType::Stage, true, true, false),
+            (ChannelType::Directory, false, false, false),
+            (ChannelType::Forum, false, false, false),
+            (ChannelType::Unknown(16), false, false, false),
+        ];
+
+        for (kind, text_based, voice_based, thread) in cases {
+            assert_eq!(kind.is_text_based(), text_based, "{kind:?}");
+            assert_eq!(kind.is_voice_based(), voice_based, "{kind:?}");
+            assert_eq!(kind.is_thread(), thread, "{kind:?}");
+        }
+    }
+
+    #[test]
+    fn permission_overwrite_constructors() {
+        let overwrite = PermissionOverwrite::role(RoleId::new(1))
+            .allow(Permissions::VIEW_CHANNEL)
+            .deny(Permissions::SEND_MESSAGES);
+        assert_json(
+            &overwrite,
+            json!({"allow": "1024", "deny": "2048", "id": "1", "type": 0}),
+        );
+
+        let overwrite = PermissionOverwrite::member(UserId::new(2)).deny(Permissions::SPEAK);
+        assert_json(&overwrite, json!({"allow": "0", "deny": "2097152", "id": "2", "type": 1}));
+    }
+}
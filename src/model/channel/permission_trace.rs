@@ -0,0 +1,200 @@
+use crate::model::prelude::*;
+
+/// The derivation of a [`Member`]'s permissions in a [`GuildChannel`], as returned by
+/// [`GuildChannel::permission_overwrite_trace`].
+///
+/// Follows the [Discord docs] algorithm: the permissions of the @everyone role and of the member's
+/// roles are combined into the base permissions, then the channel's overwrites are applied in
+/// order.
+///
+/// [Discord docs]: https://discord.com/developers/docs/topics/permissions#permission-overwrites
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct PermissionTrace {
+    /// The guild level permissions of the @everyone role.
+    pub everyone: Permissions,
+    /// The guild level permissions of each of the member's roles, in the order of
+    /// [`Member::roles`]. Roles missing from the guild are skipped.
+    pub roles: Vec<(RoleId, Permissions)>,
+    /// The guild level permissions of the member, combining [`Self::everyone`] and
+    /// [`Self::roles`].
+    pub base: Permissions,
+    /// The overwrites of the channel that apply to the member, in order of application.
+    ///
+    /// Empty if the permissions were decided by a [`PermissionOverride`] before the overwrites
+    /// were reached.
+    pub overwrites: Vec<TracedOverwrite>,
+    /// The rule that overrode the regular calculation, if any.
+    pub override_reason: Option<PermissionOverride>,
+    /// The final permissions of the member in the channel.
+    pub permissions: Permissions,
+}
+
+/// A channel overwrite applied during a [`PermissionTrace`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct TracedOverwrite {
+    /// What the overwrite targets.
+    pub source: OverwriteSource,
+    /// The permissions allowed by the overwrite.
+    pub allow: Permissions,
+    /// The permissions denied by the overwrite.
+    pub deny: Permissions,
+    /// The permissions of the member after applying the overwrite.
+    ///
+    /// Role overwrites are applied together, with all of their denies applied before all of their
+    /// allows, so every role overwrite holds the permissions after applying all of them.
+    pub permissions: Permissions,
+}
+
+/// What a [`TracedOverwrite`] targets.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum OverwriteSource {
+    /// The overwrite targets the @everyone role.
+    Everyone,
+    /// The overwrite targets one of the member's roles.
+    Role(RoleId),
+    /// The overwrite targets the member itself.
+    Member(UserId),
+}
+
+/// A rule overriding the regular calculation of a [`PermissionTrace`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum PermissionOverride {
+    /// The member owns the guild, and has all permissions.
+    Owner,
+    /// The member's base permissions include [Administrator], so it has all permissions.
+    ///
+    /// [Administrator]: Permissions::ADMINISTRATOR
+    Administrator,
+    /// The member is timed out, and is limited to the [View Channel] and [Read Message History]
+    /// permissions after applying the overwrites.
+    ///
+    /// [View Channel]: Permissions::VIEW_CHANNEL
+    /// [Read Message History]: Permissions::READ_MESSAGE_HISTORY
+    TimedOut,
+}
+
+#[cfg(all(test, feature = "model"))]
+mod tests {
+    use super::*;
+
+    fn role(id: u64, permissions: Permissions) -> (RoleId, Role) {
+        let mut role = Role::default();
+        role.id = RoleId::new(id);
+        role.permissions = permissions;
+        (role.id, role)
+    }
+
+    fn overwrite(
+        kind: PermissionOverwriteType,
+        allow: Permissions,
+        deny: Permissions,
+    ) -> PermissionOverwrite {
+        PermissionOverwrite {
+            allow,
+            deny,
+            kind,
+        }
+    }
+
+    fn fixture() -> (Guild, GuildChannel, Member) {
+        let mut guild = Guild::default();
+        guild.id = GuildId::new(1);
+        guild.owner_id = UserId::new(100);
+        guild.roles = [
+            role(1, Permissions::VIEW_CHANNEL | Permissions::SEND_MESSAGES),
+            role(2, Permissions::MANAGE_MESSAGES),
+            role(3, Permissions::ATTACH_FILES),
+        ]
+        .into_iter()
+        .collect();
+
+        let mut channel = GuildChannel::default();
+        channel.permission_overwrites = vec![
+            overwrite(
+                PermissionOverwriteType::Role(RoleId::new(1)),
+                Permissions::empty(),
+                Permissions::SEND_MESSAGES,
+            ),
+            overwrite(
+                PermissionOverwriteType::Role(RoleId::new(2)),
+                Permissions::SEND_MESSAGES,
+                Permissions::EMBED_LINKS,
+            ),
+            overwrite(
+                PermissionOverwriteType::Role(RoleId::new(3)),
+                Permissions::EMBED_LINKS,
+                Permissions::SEND_MESSAGES,
+            ),
+            overwrite(
+                PermissionOverwriteType::Member(UserId::new(10)),
+                Permissions::empty(),
+                Permissions::ATTACH_FILES,
+            ),
+            overwrite(
+                PermissionOverwriteType::Member(UserId::new(11)),
+                Permissions::ADMINISTRATOR,
+                Permissions::empty(),
+            ),
+        ];
+
+        let mut member = Member::default();
+        member.user.id = UserId::new(10);
+        member.roles = vec![RoleId::new(2), RoleId::new(3), RoleId::new(4)];
+
+        (guild, channel, member)
+    }
+
+    #[test]
+    fn trace_overwrites() {
+        let (guild, channel, member) = fixture();
+        let trace = channel.permission_overwrite_trace(&guild, &member);
+
+        assert_eq!(trace.everyone, Permissions::VIEW_CHANNEL | Permissions::SEND_MESSAGES);
+        assert_eq!(trace.roles, [
+            (RoleId::new(2), Permissions::MANAGE_MESSAGES),
+            (RoleId::new(3), Permissions::ATTACH_FILES),
+        ]);
+        assert_eq!(trace.override_reason, None);
+
+        let sources: Vec<_> = trace.overwrites.iter().map(|overwrite| overwrite.source).collect();
+        assert_eq!(sources, [
+            OverwriteSource::Everyone,
+            OverwriteSource::Role(RoleId::new(2)),
+            OverwriteSource::Role(RoleId::new(3)),
+            OverwriteSource::Member(UserId::new(10)),
+        ]);
+        assert!(!trace.overwrites[0].permissions.contains(Permissions::SEND_MESSAGES));
+        // Role allows win over role denies, regardless of their order.
+        assert!(trace.overwrites[2].permissions.contains(Permissions::SEND_MESSAGES));
+        assert!(trace.overwrites[2].permissions.contains(Permissions::EMBED_LINKS));
+        assert!(!trace.permissions.contains(Permissions::ATTACH_FILES));
+
+        assert_eq!(trace.permissions, guild.user_permissions_in(&channel, &member));
+    }
+
+    #[test]
+    fn trace_overrides() {
+        let (mut guild, channel, mut member) = fixture();
+
+        member.communication_disabled_until =
+            Some(Timestamp::from_unix_timestamp(4_000_000_000).unwrap());
+        let trace = channel.permission_overwrite_trace(&guild, &member);
+        assert_eq!(trace.override_reason, Some(PermissionOverride::TimedOut));
+        assert_eq!(trace.permissions, Permissions::VIEW_CHANNEL);
+
+        guild.roles.get_mut(&RoleId::new(3)).unwrap().permissions = Permissions::ADMINISTRATOR;
+        let trace = channel.permission_overwrite_trace(&guild, &member);
+        assert_eq!(trace.override_reason, Some(PermissionOverride::Administrator));
+        assert_eq!(trace.permissions, Permissions::all());
+        assert!(trace.overwrites.is_empty());
+
+        guild.owner_id = member.user.id;
+        let trace = channel.permission_overwrite_trace(&guild, &member);
+        assert_eq!(trace.override_reason, Some(PermissionOverride::Owner));
+        assert_eq!(trace.permissions, Permissions::all());
+    }
+}
@@ -227,6 +227,25 @@ impl PrivateChannel {
         self.id.reaction_users(http, message_id, reaction_type, limit, after).await
     }
 
+    /// Gets the list of [`User`]s who have reacted to a [`Message`] with a certain [`Emoji`] and
+    /// type of reaction. See [`ChannelId::reaction_users_with_type`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if a message with the given Id does not exist in the channel.
+    #[inline]
+    pub async fn reaction_users_with_type(
+        &self,
+        http: impl AsRef<Http>,
+        message_id: impl Into<MessageId>,
+        reaction_type: impl Into<ReactionType>,
+        kind: ReactionTypes,
+        limit: Option<u8>,
+        after: impl Into<Option<UserId>>,
+    ) -> Result<Vec<User>> {
+        self.id.reaction_users_with_type(http, message_id, reaction_type, kind, limit, after).await
+    }
+
     /// Pins a [`Message`] to the channel.
     ///
     /// # Errors
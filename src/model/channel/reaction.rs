@@ -230,6 +230,8 @@ impl Reaction {
     /// The optional `after` attribute is to retrieve the users after a certain user. This is
     /// useful for pagination.
     ///
+    /// Only users who sent the same type of reaction as this one, normal or super, are returned.
+    ///
     /// Requires the [Read Message History] permission.
     ///
     /// **Note**: This will send a request to the REST API.
@@ -271,10 +273,11 @@ impl Reaction {
         }
 
         http.as_ref()
-            .get_reaction_users(
+            .get_reaction_users_with_type(
                 self.channel_id,
                 self.message_id,
                 reaction_type,
+                self.reaction_type,
                 limit,
                 after.map(UserId::get),
             )
@@ -378,6 +381,38 @@ impl ReactionType {
         }
     }
 
+    /// Whether both reaction types refer to the same emoji.
+    ///
+    /// Unlike `==`, custom emojis are compared by their Id only, which is the canonical identity of
+    /// a custom emoji; their names may change and their animated flag is not always known, e.g.
+    /// when parsed from `<:name:id>`. Unicode emojis are compared exactly. A custom emoji never
+    /// matches a unicode emoji.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use serenity::model::channel::ReactionType;
+    /// let configured: ReactionType = "<:serenity:123>".parse().unwrap();
+    /// let received: ReactionType = "<a:renamed:123>".parse().unwrap();
+    /// assert!(configured.matches(&received));
+    /// assert_ne!(configured, received);
+    /// ```
+    #[must_use]
+    pub fn matches(&self, other: &ReactionType) -> bool {
+        match (self, other) {
+            (
+                ReactionType::Custom {
+                    id, ..
+                },
+                ReactionType::Custom {
+                    id: other_id, ..
+                },
+            ) => id == other_id,
+            (ReactionType::Unicode(unicode), ReactionType::Unicode(other)) => unicode == other,
+            _ => false,
+        }
+    }
+
     /// Helper function to allow testing equality of unicode emojis without having to perform any
     /// allocation. Will always return false if the reaction was not a unicode reaction.
     #[must_use]
@@ -595,3 +630,50 @@ impl fmt::Display for ReactionType {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn custom(animated: bool, id: u64, name: &str) -> ReactionType {
+        ReactionType::Custom {
+            animated,
+            id: EmojiId::new(id),
+            name: Some(name.to_string()),
+        }
+    }
+
+    #[test]
+    fn matches_renamed_custom_emoji() {
+        let configured = custom(false, 1, "old_name");
+
+        assert!(configured.matches(&custom(false, 1, "new_name")));
+        assert!(configured.matches(&ReactionType::Custom {
+            animated: false,
+            id: EmojiId::new(1),
+            name: None,
+        }));
+        assert!(!configured.matches(&custom(false, 2, "old_name")));
+        assert!(!configured.matches(&ReactionType::Unicode("old_name".into())));
+    }
+
+    #[test]
+    fn matches_animated_custom_emoji() {
+        let parsed = ReactionType::try_from("<a:party:1>").unwrap();
+        assert_eq!(parsed, custom(true, 1, "party"));
+
+        // The animated flag is not part of the emoji's identity.
+        assert!(parsed.matches(&custom(false, 1, "party")));
+        assert_ne!(parsed, custom(false, 1, "party"));
+    }
+
+    #[test]
+    fn matches_unicode_emoji() {
+        let apple = ReactionType::Unicode("🍎".into());
+
+        assert!(apple.matches(&ReactionType::from('🍎')));
+        assert!(!apple.matches(&ReactionType::Unicode("🍏".into())));
+        assert!(apple.unicode_eq("🍎"));
+        assert!(!custom(false, 1, "🍎").unicode_eq("🍎"));
+    }
+}
@@ -1,6 +1,7 @@
 //! Error enum definition wrapping potential model implementation errors.
 
 use std::error::Error as StdError;
+use std::ffi::OsString;
 use std::fmt;
 
 use super::Permissions;
@@ -50,6 +51,8 @@ use super::Permissions;
 pub enum Error {
     /// When attempting to delete below or above the minimum or maximum allowed number of messages.
     BulkDeleteAmount,
+    /// When attempting to ban below or above the minimum or maximum allowed number of users.
+    BulkBanAmount,
     /// When attempting to delete a number of days' worth of messages that is not allowed.
     DeleteMessageDaysAmount(u8),
     /// When attempting to send a message with over 10 embeds.
@@ -151,6 +154,64 @@ pub enum Error {
     StickerAmount,
     /// When attempting to edit a voice message.
     CannotEditVoiceMessage,
+    /// When attempting to create an attachment from a path whose file name is not valid UTF-8.
+    NonUtf8Filename(OsString),
+    /// When attempting to attach a poll to an edit of a message, as polls cannot be edited.
+    CannotEditPoll,
+    /// When attempting to use functionality that requires a guild feature the guild does not
+    /// have, such as `ROLE_ICONS` for role icons.
+    ///
+    /// The inner value is the name of the missing feature.
+    MissingGuildFeature(&'static str),
+    /// When attempting to add more than 5 channels to a guild's welcome screen.
+    WelcomeChannelAmount,
+    /// When attempting to enable a guild's onboarding with fewer than 7 default channels.
+    OnboardingDefaultChannelAmount,
+    /// When a command option is nested in a way Discord does not allow, e.g. a subcommand inside
+    /// another subcommand.
+    ///
+    /// The path is the space separated name of the offending option, e.g. `config logging set`.
+    InvalidCommandNesting {
+        /// The path of the offending option.
+        path: String,
+    },
+    /// When a command or command option name is not 1-32 lowercase characters without spaces.
+    ///
+    /// The path is the space separated name of the offending command or option.
+    InvalidCommandName {
+        /// The path of the offending command or option.
+        path: String,
+    },
+    /// When a command, subcommand group, or subcommand has over 25 options.
+    ///
+    /// The path is the space separated name of the offending command or option.
+    CommandOptionAmount {
+        /// The path of the offending command or option.
+        path: String,
+    },
+    /// When attempting to execute a webhook both in an existing thread and creating a new one.
+    WebhookThreadConflict,
+    /// When image data, such as an avatar or emoji, is in a format that is not supported in its
+    /// place or is too large.
+    InvalidImage(ImageError),
+    /// When attempting to time out a member for longer than [`MEMBER_TIMEOUT_MAX`].
+    ///
+    /// [`MEMBER_TIMEOUT_MAX`]: crate::constants::MEMBER_TIMEOUT_MAX
+    TimeoutTooLong,
+    /// When a thread membership cannot be changed because the thread is archived.
+    ThreadArchived,
+    /// When a thread membership cannot be changed because the thread is locked.
+    ThreadLocked,
+    /// When a user cannot be added to a thread because it has the maximum number of members.
+    ThreadMembersFull,
+    /// When sound data for the soundboard is not an MP3 or Ogg file, or is larger than 512 KiB.
+    InvalidSound,
+    /// When attempting to respond to an interaction that was already responded to, or deferred.
+    /// Use the methods editing the response or creating followups instead.
+    AlreadyResponded,
+    /// When attempting to respond to a modal submit interaction with another modal, which Discord
+    /// does not allow.
+    NestedModal,
 }
 
 impl Error {
@@ -172,6 +233,7 @@ impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::BulkDeleteAmount => f.write_str("Too few/many messages to bulk delete."),
+            Self::BulkBanAmount => f.write_str("Too few/many users to bulk ban."),
             Self::DeleteMessageDaysAmount(_) => f.write_str("Invalid delete message days."),
             Self::EmbedAmount => f.write_str("Too many embeds in a message."),
             Self::EmbedTooLarge(_) => f.write_str("Embed too large."),
@@ -199,8 +261,114 @@ impl fmt::Display for Error {
             Self::NoStickerFileSet => f.write_str("Sticker file is not set."),
             Self::StickerAmount => f.write_str("Too many stickers in a message."),
             Self::CannotEditVoiceMessage => f.write_str("Cannot edit voice message."),
+            Self::NonUtf8Filename(_) => f.write_str("File name is not valid UTF-8."),
+            Self::CannotEditPoll => f.write_str("Cannot edit a poll."),
+            Self::MissingGuildFeature(_) => f.write_str("The guild is missing a required feature."),
+            Self::WelcomeChannelAmount => f.write_str("Too many welcome screen channels."),
+            Self::OnboardingDefaultChannelAmount => {
+                f.write_str("Too few default channels to enable onboarding.")
+            },
+            Self::InvalidCommandNesting {
+                path,
+            } => write!(f, "Command option `{path}` is nested invalidly."),
+            Self::InvalidCommandName {
+                path,
+            } => write!(f, "Command name `{path}` is invalid."),
+            Self::CommandOptionAmount {
+                path,
+            } => write!(f, "Command `{path}` has too many options."),
+            Self::InvalidImage(why) => write!(f, "Invalid image: {why}"),
+            Self::TimeoutTooLong => f.write_str("Member timeout is over 28 days."),
+            Self::ThreadArchived => f.write_str("The thread is archived."),
+            Self::ThreadLocked => f.write_str("The thread is locked."),
+            Self::ThreadMembersFull => f.write_str("The thread has too many members."),
+            Self::InvalidSound => {
+                f.write_str("Sound is not an MP3 or Ogg file of at most 512 KiB.")
+            },
+            Self::AlreadyResponded => f.write_str("The interaction was already responded to."),
+            Self::NestedModal => f.write_str("Cannot respond to a modal with another modal."),
+            Self::WebhookThreadConflict => {
+                f.write_str("Cannot execute a webhook in a thread and create a thread at once.")
+            },
         }
     }
 }
 
 impl StdError for Error {}
+
+/// The format of image data, as detected from its leading bytes.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum ImageFormat {
+    /// A PNG or animated PNG image.
+    Png,
+    /// A JPEG image.
+    Jpeg,
+    /// A GIF image, which may be animated.
+    Gif,
+    /// A WebP image.
+    WebP,
+}
+
+impl ImageFormat {
+    /// Detects the format of the given image data from its magic bytes.
+    ///
+    /// Returns [`None`] if the data is not in any of the known formats.
+    #[must_use]
+    pub fn detect(data: &[u8]) -> Option<Self> {
+        match data {
+            [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1A, b'\n', ..] => Some(Self::Png),
+            [0xFF, 0xD8, 0xFF, ..] => Some(Self::Jpeg),
+            [b'G', b'I', b'F', b'8', b'7' | b'9', b'a', ..] => Some(Self::Gif),
+            [b'R', b'I', b'F', b'F', _, _, _, _, b'W', b'E', b'B', b'P', ..] => Some(Self::WebP),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ImageFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Png => "PNG",
+            Self::Jpeg => "JPEG",
+            Self::Gif => "GIF",
+            Self::WebP => "WebP",
+        })
+    }
+}
+
+/// The reason image data is invalid, see [`Error::InvalidImage`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum ImageError {
+    /// The data is not in any known image format.
+    UnknownFormat,
+    /// The format is not supported in this place, e.g. a WebP avatar.
+    UnsupportedFormat(ImageFormat),
+    /// The data is larger than the maximum size in bytes.
+    TooLarge {
+        /// The size of the data in bytes.
+        size: usize,
+        /// The maximum size in bytes.
+        max: usize,
+    },
+    /// The data is streamed while uploading, see [`CreateAttachment::url_streamed`], so it can't
+    /// be sent as base64.
+    ///
+    /// [`CreateAttachment::url_streamed`]: crate::builder::CreateAttachment::url_streamed
+    Streamed,
+}
+
+impl fmt::Display for ImageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownFormat => f.write_str("unknown image format"),
+            Self::UnsupportedFormat(format) => write!(f, "{format} images are not supported here"),
+            Self::TooLarge {
+                size,
+                max,
+            } => write!(f, "image is {size} bytes, over the maximum of {max} bytes"),
+            Self::Streamed => f.write_str("streamed attachments can't be sent as images"),
+        }
+    }
+}
@@ -259,8 +259,10 @@ pub struct GuildMemberUpdateEvent {
     #[serde(default)]
     pub mute: bool,
     pub avatar: Option<ImageHash>,
+    pub banner: Option<ImageHash>,
     pub communication_disabled_until: Option<Timestamp>,
     pub unusual_dm_activity_until: Option<Timestamp>,
+    pub flags: Option<GuildMemberFlags>,
 }
 
 /// Requires no gateway intents.
@@ -379,6 +381,54 @@ pub struct GuildStickersUpdateEvent {
     pub guild_id: GuildId,
 }
 
+/// Requires [`GatewayIntents::GUILD_EMOJIS_AND_STICKERS`].
+///
+/// [Discord docs](https://discord.com/developers/docs/topics/gateway-events#guild-soundboard-sound-create).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(transparent)]
+#[non_exhaustive]
+pub struct GuildSoundboardSoundCreateEvent {
+    pub sound: SoundboardSound,
+}
+
+/// Requires [`GatewayIntents::GUILD_EMOJIS_AND_STICKERS`].
+///
+/// [Discord docs](https://discord.com/developers/docs/topics/gateway-events#guild-soundboard-sound-update).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(transparent)]
+#[non_exhaustive]
+pub struct GuildSoundboardSoundUpdateEvent {
+    pub sound: SoundboardSound,
+}
+
+/// Requires [`GatewayIntents::GUILD_EMOJIS_AND_STICKERS`].
+///
+/// [Discord docs](https://discord.com/developers/docs/topics/gateway-events#guild-soundboard-sound-delete).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct GuildSoundboardSoundDeleteEvent {
+    /// The Id of the deleted sound.
+    pub sound_id: SoundId,
+    /// The Id of the guild the sound was in.
+    pub guild_id: GuildId,
+}
+
+/// Requires [`GatewayIntents::GUILD_EMOJIS_AND_STICKERS`].
+///
+/// [Discord docs](https://discord.com/developers/docs/topics/gateway-events#guild-soundboard-sounds-update).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct GuildSoundboardSoundsUpdateEvent {
+    /// The updated sounds.
+    pub soundboard_sounds: Vec<SoundboardSound>,
+    /// The Id of the guild the sounds are in.
+    pub guild_id: GuildId,
+}
+
 /// Requires [`GatewayIntents::GUILD_INVITES`] and [`Permissions::MANAGE_CHANNELS´] permission.
 ///
 /// [Discord docs](https://discord.com/developers/docs/topics/gateway-events#invite-create).
@@ -448,7 +498,7 @@ pub struct MessageCreateEvent {
     pub message: Message,
 }
 
-/// Requires [`GatewayIntents::GUILD_MESSAGES`] or [`GatewayIntents::DIRECT_MESSAGES`].
+/// Requires [`GatewayIntents::GUILD_MESSAGES`].
 ///
 /// [Discord docs](https://discord.com/developers/docs/topics/gateway-events#message-delete-bulk).
 #[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
@@ -483,7 +533,7 @@ where
     Deserialize::deserialize(deserializer).map(Some)
 }
 
-/// Requires [`GatewayIntents::GUILD_MESSAGES`].
+/// Requires [`GatewayIntents::GUILD_MESSAGES`] or [`GatewayIntents::DIRECT_MESSAGES`].
 ///
 /// Contains identical fields to [`Message`], except everything but `id` and `channel_id` are
 /// optional. Even fields that cannot change in a message update event are included, because Discord
@@ -525,6 +575,7 @@ pub struct MessageUpdateEvent {
     pub flags: Option<Option<MessageFlags>>,
     #[serde(default, deserialize_with = "deserialize_some")]
     pub referenced_message: Option<Option<Box<Message>>>,
+    pub message_snapshots: Option<Vec<MessageSnapshot>>,
     #[cfg_attr(
         all(not(ignore_serenity_deprecated), feature = "unstable_discord_api"),
         deprecated = "Use interaction_metadata"
@@ -575,6 +626,7 @@ impl MessageUpdateEvent {
             message_reference,
             flags,
             referenced_message,
+            message_snapshots,
             interaction,
             #[cfg(feature = "unstable_discord_api")]
             interaction_metadata,
@@ -614,6 +666,7 @@ impl MessageUpdateEvent {
         if let Some(x) = message_reference { message.message_reference.clone_from(x) }
         if let Some(x) = flags { message.flags.clone_from(x) }
         if let Some(x) = referenced_message { message.referenced_message.clone_from(x) }
+        if let Some(x) = message_snapshots { message.message_snapshots.clone_from(x) }
         if let Some(x) = interaction { message.interaction.clone_from(x) }
         #[cfg(feature = "unstable_discord_api")]
         if let Some(x) = interaction_metadata { message.interaction_metadata.clone_from(x) }
@@ -639,7 +692,10 @@ pub struct PresenceUpdateEvent {
 }
 
 /// Not officially documented.
-#[cfg_attr(not(ignore_serenity_deprecated), deprecated = "This event doesn't exist")]
+#[cfg_attr(
+    not(ignore_serenity_deprecated),
+    deprecated = "This event doesn't exist, and will be removed in 0.13"
+)]
 #[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(transparent)]
@@ -726,6 +782,7 @@ pub struct ResumedEvent {}
 /// [Discord docs](https://discord.com/developers/docs/topics/gateway-events#typing-start).
 #[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(remote = "Self")]
 #[non_exhaustive]
 pub struct TypingStartEvent {
     /// ID of the channel.
@@ -740,6 +797,23 @@ pub struct TypingStartEvent {
     pub member: Option<Member>,
 }
 
+// Manual impl needed to insert guild_id into Member
+impl<'de> Deserialize<'de> for TypingStartEvent {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        let mut event = Self::deserialize(deserializer)?; // calls #[serde(remote)]-generated inherent method
+        if let (Some(guild_id), Some(member)) = (event.guild_id, event.member.as_mut()) {
+            member.guild_id = guild_id;
+        }
+        Ok(event)
+    }
+}
+
+impl Serialize for TypingStartEvent {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> StdResult<S::Ok, S::Error> {
+        Self::serialize(self, serializer) // calls #[serde(remote)]-generated inherent method
+    }
+}
+
 #[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[non_exhaustive]
@@ -1145,229 +1219,732 @@ impl<'de> Deserialize<'de> for GatewayEvent {
     }
 }
 
-/// Event received over a websocket connection
+/// Describes an [`Event`] received from the gateway.
 ///
-/// [Discord docs](https://discord.com/developers/docs/topics/gateway-events#receive-events).
-#[allow(clippy::large_enum_variant)]
-#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
-#[derive(Clone, Debug, Deserialize, Serialize)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-#[serde(tag = "t", content = "d")]
+/// See [`EVENTS`] for the descriptors of all events.
+#[derive(Clone, Copy, Debug)]
 #[non_exhaustive]
-pub enum Event {
+pub struct EventDescriptor {
+    /// The name of the event sent by the gateway, e.g. `MESSAGE_CREATE`.
+    pub name: &'static str,
+    /// The name of the [`Event`] variant of the event.
+    pub variant: &'static str,
+    /// The names of the `EventHandler` methods the event is dispatched to, depending on its
+    /// contents and the state of the cache.
+    pub handlers: &'static [&'static str],
+    /// The gateway intents of which at least one is required to receive the event. Empty if the
+    /// event is sent regardless of intents.
+    pub intents: GatewayIntents,
+}
+
+impl EventDescriptor {
+    /// Returns the descriptor of the event with the given gateway name, e.g. `MESSAGE_CREATE`.
+    #[must_use]
+    pub fn find(name: &str) -> Option<&'static Self> {
+        EVENTS.iter().find(|descriptor| descriptor.name == name)
+    }
+
+    /// Whether the event is received with the given intents.
+    #[must_use]
+    pub const fn is_received_with(&self, intents: GatewayIntents) -> bool {
+        self.intents.is_empty() || self.intents.intersects(intents)
+    }
+}
+
+/// Generates the [`Event`] enum, its dispatch to the `IntoFullEvent` implementations and [`EVENTS`]
+/// from a single table, so that supporting a new event only requires adding a row.
+macro_rules! event_table {
+    ( $(
+        $( #[$attr:meta] )*
+        $variant:ident($event:ty) {
+            name: $name:literal,
+            handlers: [ $( $handler:ident ),* ],
+            intents: [ $( $intent:ident ),* ],
+        },
+    )* ) => {
+        /// Event received over a websocket connection
+        ///
+        /// [Discord docs](https://discord.com/developers/docs/topics/gateway-events#receive-events).
+        #[allow(clippy::large_enum_variant, deprecated)]
+        #[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+        #[derive(Clone, Debug, Serialize)]
+        #[serde(tag = "t", content = "d")]
+        #[non_exhaustive]
+        pub enum Event {
+            $(
+                $( #[$attr] )*
+                #[serde(rename = $name)]
+                $variant($event),
+            )*
+            /// An event type not covered by the above
+            #[serde(untagged)]
+            Unknown(UnknownEvent),
+        }
+
+        // Manual impl needed so that events with a known name but invalid data fail to deserialize,
+        // instead of falling back to `Event::Unknown`.
+        #[allow(deprecated)]
+        impl<'de> Deserialize<'de> for Event {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+                let mut map = JsonMap::deserialize(deserializer)?;
+                let kind: String = remove_from_map(&mut map, "t")?;
+                let value = map.remove("d").unwrap_or(crate::json::NULL);
+
+                Ok(match kind.as_str() {
+                    $( $name => Self::$variant(deserialize_val(value)?), )*
+                    _ => Self::Unknown(UnknownEvent {
+                        kind,
+                        value,
+                    }),
+                })
+            }
+        }
+
+        /// The descriptors of every event supported by serenity, in the order of the [`Event`]
+        /// variants.
+        ///
+        /// | Gateway event | Variant | Event handlers | Intents (any of) |
+        /// |---|---|---|---|
+        $(
+            #[doc = concat!(
+                "| `", $name, "` ",
+                "| [`", stringify!($variant), "`](Event::", stringify!($variant), ") ",
+                "| ",
+                $( "`", stringify!($handler), "` ", )*
+                "| ",
+                $( "`", stringify!($intent), "` ", )*
+                "|"
+            )]
+        )*
+        pub static EVENTS: &[EventDescriptor] = &[
+            $(
+                EventDescriptor {
+                    name: $name,
+                    variant: stringify!($variant),
+                    handlers: &[ $( stringify!($handler) ),* ],
+                    intents: GatewayIntents::empty() $( .union(GatewayIntents::$intent) )*,
+                },
+            )*
+        ];
+
+        /// The index of every event in [`EVENTS`].
+        #[allow(clippy::enum_variant_names)]
+        enum EventIndex {
+            $( $variant, )*
+        }
+
+        #[cfg(all(feature = "client", feature = "gateway"))]
+        impl Event {
+            /// Updates the cache with the event and builds the [`FullEvent`] for the event
+            /// handlers out of it, or returns the event back if it is
+            /// [`Unknown`](Event::Unknown).
+            ///
+            /// [`FullEvent`]: crate::client::FullEvent
+            #[allow(deprecated)]
+            pub(crate) fn dispatch(
+                self,
+                state: &mut crate::client::dispatch::DispatchState<'_>,
+            ) -> StdResult<crate::client::FullEvent, UnknownEvent> {
+                use crate::client::dispatch::IntoFullEvent as _;
+
+                Ok(match self {
+                    $( Self::$variant(event) => event.into_full_event(state), )*
+                    Self::Unknown(event) => return Err(event),
+                })
+            }
+        }
+
+        impl Event {
+            /// Returns the index of the descriptor of this event in [`EVENTS`], or [`None`] if the
+            /// event is [`Unknown`](Event::Unknown).
+            #[allow(deprecated)]
+            pub(crate) fn index(&self) -> Option<usize> {
+                let index = match self {
+                    $( Self::$variant(_) => EventIndex::$variant, )*
+                    Self::Unknown(_) => return None,
+                };
+                Some(index as usize)
+            }
+        }
+    };
+}
+
+event_table! {
     /// The permissions of an [`Command`] was changed.
     ///
     /// Fires the [`EventHandler::command_permissions_update`] event.
     ///
     /// [`Command`]: crate::model::application::Command
     /// [`EventHandler::command_permissions_update`]: crate::client::EventHandler::command_permissions_update
-    #[serde(rename = "APPLICATION_COMMAND_PERMISSIONS_UPDATE")]
-    CommandPermissionsUpdate(CommandPermissionsUpdateEvent),
+    CommandPermissionsUpdate(CommandPermissionsUpdateEvent) {
+        name: "APPLICATION_COMMAND_PERMISSIONS_UPDATE",
+        handlers: [command_permissions_update],
+        intents: [],
+    },
     /// A [`Rule`] was created.
     ///
     /// Fires the [`EventHandler::auto_moderation_rule_create`] event.
     ///
     /// [`EventHandler::auto_moderation_rule_create`]:
     /// crate::client::EventHandler::auto_moderation_rule_create
-    #[serde(rename = "AUTO_MODERATION_RULE_CREATE")]
-    AutoModRuleCreate(AutoModRuleCreateEvent),
+    AutoModRuleCreate(AutoModRuleCreateEvent) {
+        name: "AUTO_MODERATION_RULE_CREATE",
+        handlers: [auto_moderation_rule_create],
+        intents: [AUTO_MODERATION_CONFIGURATION],
+    },
     /// A [`Rule`] has been updated.
     ///
     /// Fires the [`EventHandler::auto_moderation_rule_update`] event.
     ///
     /// [`EventHandler::auto_moderation_rule_update`]:
     /// crate::client::EventHandler::auto_moderation_rule_update
-    #[serde(rename = "AUTO_MODERATION_RULE_UPDATE")]
-    AutoModRuleUpdate(AutoModRuleUpdateEvent),
+    AutoModRuleUpdate(AutoModRuleUpdateEvent) {
+        name: "AUTO_MODERATION_RULE_UPDATE",
+        handlers: [auto_moderation_rule_update],
+        intents: [AUTO_MODERATION_CONFIGURATION],
+    },
     /// A [`Rule`] was deleted.
     ///
     /// Fires the [`EventHandler::auto_moderation_rule_delete`] event.
     ///
     /// [`EventHandler::auto_moderation_rule_delete`]:
     /// crate::client::EventHandler::auto_moderation_rule_delete
-    #[serde(rename = "AUTO_MODERATION_RULE_DELETE")]
-    AutoModRuleDelete(AutoModRuleDeleteEvent),
+    AutoModRuleDelete(AutoModRuleDeleteEvent) {
+        name: "AUTO_MODERATION_RULE_DELETE",
+        handlers: [auto_moderation_rule_delete],
+        intents: [AUTO_MODERATION_CONFIGURATION],
+    },
     /// A [`Rule`] was triggered and an action was executed.
     ///
     /// Fires the [`EventHandler::auto_moderation_action_execution`] event.
     ///
     /// [`EventHandler::auto_moderation_action_execution`]:
     /// crate::client::EventHandler::auto_moderation_action_execution
-    #[serde(rename = "AUTO_MODERATION_ACTION_EXECUTION")]
-    AutoModActionExecution(AutoModActionExecutionEvent),
+    AutoModActionExecution(AutoModActionExecutionEvent) {
+        name: "AUTO_MODERATION_ACTION_EXECUTION",
+        handlers: [auto_moderation_action_execution],
+        intents: [AUTO_MODERATION_EXECUTION],
+    },
     /// A [`Channel`] was created.
     ///
     /// Fires the [`EventHandler::channel_create`] event.
     ///
     /// [`EventHandler::channel_create`]: crate::client::EventHandler::channel_create
-    ChannelCreate(ChannelCreateEvent),
+    ChannelCreate(ChannelCreateEvent) {
+        name: "CHANNEL_CREATE",
+        handlers: [channel_create, category_create],
+        intents: [GUILDS],
+    },
     /// A [`Channel`] has been deleted.
     ///
     /// Fires the [`EventHandler::channel_delete`] event.
     ///
     /// [`EventHandler::channel_delete`]: crate::client::EventHandler::channel_delete
-    ChannelDelete(ChannelDeleteEvent),
+    ChannelDelete(ChannelDeleteEvent) {
+        name: "CHANNEL_DELETE",
+        handlers: [channel_delete, category_delete],
+        intents: [GUILDS],
+    },
     /// The pins for a [`Channel`] have been updated.
     ///
     /// Fires the [`EventHandler::channel_pins_update`] event.
     ///
     /// [`EventHandler::channel_pins_update`]: crate::client::EventHandler::channel_pins_update
-    ChannelPinsUpdate(ChannelPinsUpdateEvent),
+    ChannelPinsUpdate(ChannelPinsUpdateEvent) {
+        name: "CHANNEL_PINS_UPDATE",
+        handlers: [channel_pins_update],
+        intents: [GUILDS, DIRECT_MESSAGES],
+    },
     /// A [`Channel`] has been updated.
     ///
     /// Fires the [`EventHandler::channel_update`] event.
     ///
     /// [`EventHandler::channel_update`]: crate::client::EventHandler::channel_update
-    ChannelUpdate(ChannelUpdateEvent),
-    GuildAuditLogEntryCreate(GuildAuditLogEntryCreateEvent),
-    GuildBanAdd(GuildBanAddEvent),
-    GuildBanRemove(GuildBanRemoveEvent),
-    GuildCreate(GuildCreateEvent),
-    GuildDelete(GuildDeleteEvent),
-    GuildEmojisUpdate(GuildEmojisUpdateEvent),
-    GuildIntegrationsUpdate(GuildIntegrationsUpdateEvent),
-    GuildMemberAdd(GuildMemberAddEvent),
-    GuildMemberRemove(GuildMemberRemoveEvent),
+    ChannelUpdate(ChannelUpdateEvent) {
+        name: "CHANNEL_UPDATE",
+        handlers: [channel_update],
+        intents: [GUILDS],
+    },
+    GuildAuditLogEntryCreate(GuildAuditLogEntryCreateEvent) {
+        name: "GUILD_AUDIT_LOG_ENTRY_CREATE",
+        handlers: [guild_audit_log_entry_create],
+        intents: [GUILD_MODERATION],
+    },
+    GuildBanAdd(GuildBanAddEvent) {
+        name: "GUILD_BAN_ADD",
+        handlers: [guild_ban_addition],
+        intents: [GUILD_MODERATION],
+    },
+    GuildBanRemove(GuildBanRemoveEvent) {
+        name: "GUILD_BAN_REMOVE",
+        handlers: [guild_ban_removal],
+        intents: [GUILD_MODERATION],
+    },
+    GuildCreate(GuildCreateEvent) {
+        name: "GUILD_CREATE",
+        handlers: [guild_create, guild_join, guild_backfill, cache_ready],
+        intents: [GUILDS],
+    },
+    GuildDelete(GuildDeleteEvent) {
+        name: "GUILD_DELETE",
+        handlers: [guild_delete],
+        intents: [GUILDS],
+    },
+    GuildEmojisUpdate(GuildEmojisUpdateEvent) {
+        name: "GUILD_EMOJIS_UPDATE",
+        handlers: [guild_emojis_update],
+        intents: [GUILD_EMOJIS_AND_STICKERS],
+    },
+    GuildIntegrationsUpdate(GuildIntegrationsUpdateEvent) {
+        name: "GUILD_INTEGRATIONS_UPDATE",
+        handlers: [guild_integrations_update],
+        intents: [GUILD_INTEGRATIONS],
+    },
+    GuildMemberAdd(GuildMemberAddEvent) {
+        name: "GUILD_MEMBER_ADD",
+        handlers: [guild_member_addition],
+        intents: [GUILD_MEMBERS],
+    },
+    GuildMemberRemove(GuildMemberRemoveEvent) {
+        name: "GUILD_MEMBER_REMOVE",
+        handlers: [guild_member_removal],
+        intents: [GUILD_MEMBERS],
+    },
     /// A member's roles have changed
-    GuildMemberUpdate(GuildMemberUpdateEvent),
-    GuildMembersChunk(GuildMembersChunkEvent),
-    GuildRoleCreate(GuildRoleCreateEvent),
-    GuildRoleDelete(GuildRoleDeleteEvent),
-    GuildRoleUpdate(GuildRoleUpdateEvent),
+    GuildMemberUpdate(GuildMemberUpdateEvent) {
+        name: "GUILD_MEMBER_UPDATE",
+        handlers: [guild_member_update],
+        intents: [GUILD_MEMBERS],
+    },
+    GuildMembersChunk(GuildMembersChunkEvent) {
+        name: "GUILD_MEMBERS_CHUNK",
+        handlers: [guild_members_chunk],
+        intents: [],
+    },
+    GuildRoleCreate(GuildRoleCreateEvent) {
+        name: "GUILD_ROLE_CREATE",
+        handlers: [guild_role_create],
+        intents: [GUILDS],
+    },
+    GuildRoleDelete(GuildRoleDeleteEvent) {
+        name: "GUILD_ROLE_DELETE",
+        handlers: [guild_role_delete],
+        intents: [GUILDS],
+    },
+    GuildRoleUpdate(GuildRoleUpdateEvent) {
+        name: "GUILD_ROLE_UPDATE",
+        handlers: [guild_role_update],
+        intents: [GUILDS],
+    },
+    /// A [`SoundboardSound`] was created.
+    GuildSoundboardSoundCreate(GuildSoundboardSoundCreateEvent) {
+        name: "GUILD_SOUNDBOARD_SOUND_CREATE",
+        handlers: [guild_soundboard_sound_create],
+        intents: [GUILD_EMOJIS_AND_STICKERS],
+    },
+    /// A [`SoundboardSound`] was updated.
+    GuildSoundboardSoundUpdate(GuildSoundboardSoundUpdateEvent) {
+        name: "GUILD_SOUNDBOARD_SOUND_UPDATE",
+        handlers: [guild_soundboard_sound_update],
+        intents: [GUILD_EMOJIS_AND_STICKERS],
+    },
+    /// A [`SoundboardSound`] was deleted.
+    GuildSoundboardSoundDelete(GuildSoundboardSoundDeleteEvent) {
+        name: "GUILD_SOUNDBOARD_SOUND_DELETE",
+        handlers: [guild_soundboard_sound_delete],
+        intents: [GUILD_EMOJIS_AND_STICKERS],
+    },
+    /// Several [`SoundboardSound`]s of a guild were updated at once.
+    GuildSoundboardSoundsUpdate(GuildSoundboardSoundsUpdateEvent) {
+        name: "GUILD_SOUNDBOARD_SOUNDS_UPDATE",
+        handlers: [guild_soundboard_sounds_update],
+        intents: [GUILD_EMOJIS_AND_STICKERS],
+    },
     /// A [`Sticker`] was created, updated, or deleted
-    GuildStickersUpdate(GuildStickersUpdateEvent),
-    GuildUpdate(GuildUpdateEvent),
+    GuildStickersUpdate(GuildStickersUpdateEvent) {
+        name: "GUILD_STICKERS_UPDATE",
+        handlers: [guild_stickers_update],
+        intents: [GUILD_EMOJIS_AND_STICKERS],
+    },
+    GuildUpdate(GuildUpdateEvent) {
+        name: "GUILD_UPDATE",
+        handlers: [guild_update],
+        intents: [GUILDS],
+    },
     /// An [`Invite`] was created.
     ///
     /// Fires the [`EventHandler::invite_create`] event handler.
     ///
     /// [`EventHandler::invite_create`]: crate::client::EventHandler::invite_create
-    InviteCreate(InviteCreateEvent),
+    InviteCreate(InviteCreateEvent) {
+        name: "INVITE_CREATE",
+        handlers: [invite_create],
+        intents: [GUILD_INVITES],
+    },
     /// An [`Invite`] was deleted.
     ///
     /// Fires the [`EventHandler::invite_delete`] event handler.
     ///
     /// [`EventHandler::invite_delete`]: crate::client::EventHandler::invite_delete
-    InviteDelete(InviteDeleteEvent),
-    MessageCreate(MessageCreateEvent),
-    MessageDelete(MessageDeleteEvent),
-    MessageDeleteBulk(MessageDeleteBulkEvent),
+    InviteDelete(InviteDeleteEvent) {
+        name: "INVITE_DELETE",
+        handlers: [invite_delete],
+        intents: [GUILD_INVITES],
+    },
+    MessageCreate(MessageCreateEvent) {
+        name: "MESSAGE_CREATE",
+        handlers: [message],
+        intents: [GUILD_MESSAGES, DIRECT_MESSAGES],
+    },
+    MessageDelete(MessageDeleteEvent) {
+        name: "MESSAGE_DELETE",
+        handlers: [message_delete],
+        intents: [GUILD_MESSAGES, DIRECT_MESSAGES],
+    },
+    MessageDeleteBulk(MessageDeleteBulkEvent) {
+        name: "MESSAGE_DELETE_BULK",
+        handlers: [message_delete_bulk],
+        intents: [GUILD_MESSAGES],
+    },
     /// A message has been edited, either by the user or the system
-    MessageUpdate(MessageUpdateEvent),
+    MessageUpdate(MessageUpdateEvent) {
+        name: "MESSAGE_UPDATE",
+        handlers: [message_update],
+        intents: [GUILD_MESSAGES, DIRECT_MESSAGES],
+    },
     /// A member's presence state (or username or avatar) has changed
-    PresenceUpdate(PresenceUpdateEvent),
+    PresenceUpdate(PresenceUpdateEvent) {
+        name: "PRESENCE_UPDATE",
+        handlers: [presence_update],
+        intents: [GUILD_PRESENCES],
+    },
     /// The presence list of the user's friends should be replaced entirely
-    #[cfg_attr(not(ignore_serenity_deprecated), deprecated = "This event doesn't exist")]
-    PresencesReplace(PresencesReplaceEvent),
+    #[cfg_attr(
+        not(ignore_serenity_deprecated),
+        deprecated = "This event doesn't exist, and will be removed in 0.13"
+    )]
+    PresencesReplace(PresencesReplaceEvent) {
+        name: "PRESENCES_REPLACE",
+        handlers: [presence_replace],
+        intents: [],
+    },
     /// A reaction was added to a message.
     ///
     /// Fires the [`EventHandler::reaction_add`] event handler.
     ///
     /// [`EventHandler::reaction_add`]: crate::client::EventHandler::reaction_add
-    #[serde(rename = "MESSAGE_REACTION_ADD")]
-    ReactionAdd(ReactionAddEvent),
+    ReactionAdd(ReactionAddEvent) {
+        name: "MESSAGE_REACTION_ADD",
+        handlers: [reaction_add],
+        intents: [GUILD_MESSAGE_REACTIONS, DIRECT_MESSAGE_REACTIONS],
+    },
     /// A reaction was removed to a message.
     ///
     /// Fires the [`EventHandler::reaction_remove`] event handler.
     ///
     /// [`EventHandler::reaction_remove`]: crate::client::EventHandler::reaction_remove
-    #[serde(rename = "MESSAGE_REACTION_REMOVE")]
-    ReactionRemove(ReactionRemoveEvent),
+    ReactionRemove(ReactionRemoveEvent) {
+        name: "MESSAGE_REACTION_REMOVE",
+        handlers: [reaction_remove],
+        intents: [GUILD_MESSAGE_REACTIONS, DIRECT_MESSAGE_REACTIONS],
+    },
     /// A request was issued to remove all [`Reaction`]s from a [`Message`].
     ///
     /// Fires the [`EventHandler::reaction_remove_all`] event handler.
     ///
     /// [`EventHandler::reaction_remove_all`]: crate::client::EventHandler::reaction_remove_all
-    #[serde(rename = "MESSAGE_REACTION_REMOVE_ALL")]
-    ReactionRemoveAll(ReactionRemoveAllEvent),
+    ReactionRemoveAll(ReactionRemoveAllEvent) {
+        name: "MESSAGE_REACTION_REMOVE_ALL",
+        handlers: [reaction_remove_all],
+        intents: [GUILD_MESSAGE_REACTIONS, DIRECT_MESSAGE_REACTIONS],
+    },
     /// Sent when a bot removes all instances of a given emoji from the reactions of a message.
     ///
     /// Fires the [`EventHandler::reaction_remove_emoji`] event handler.
     ///
     /// [`EventHandler::reaction_remove_emoji`]: crate::client::EventHandler::reaction_remove_emoji
-    #[serde(rename = "MESSAGE_REACTION_REMOVE_EMOJI")]
-    ReactionRemoveEmoji(ReactionRemoveEmojiEvent),
+    ReactionRemoveEmoji(ReactionRemoveEmojiEvent) {
+        name: "MESSAGE_REACTION_REMOVE_EMOJI",
+        handlers: [reaction_remove_emoji],
+        intents: [GUILD_MESSAGE_REACTIONS, DIRECT_MESSAGE_REACTIONS],
+    },
     /// The first event in a connection, containing the initial ready cache.
     ///
     /// May also be received at a later time in the event of a reconnect.
-    Ready(ReadyEvent),
+    Ready(ReadyEvent) {
+        name: "READY",
+        handlers: [ready, shards_ready],
+        intents: [],
+    },
     /// The connection has successfully resumed after a disconnect.
-    Resumed(ResumedEvent),
+    Resumed(ResumedEvent) {
+        name: "RESUMED",
+        handlers: [resume],
+        intents: [],
+    },
     /// A user is typing; considered to last 5 seconds
-    TypingStart(TypingStartEvent),
+    TypingStart(TypingStartEvent) {
+        name: "TYPING_START",
+        handlers: [typing_start],
+        intents: [GUILD_MESSAGE_TYPING, DIRECT_MESSAGE_TYPING],
+    },
     /// Update to the logged-in user's information
-    UserUpdate(UserUpdateEvent),
+    UserUpdate(UserUpdateEvent) {
+        name: "USER_UPDATE",
+        handlers: [user_update],
+        intents: [],
+    },
     /// A member's voice state has changed
-    VoiceStateUpdate(VoiceStateUpdateEvent),
+    VoiceStateUpdate(VoiceStateUpdateEvent) {
+        name: "VOICE_STATE_UPDATE",
+        handlers: [voice_state_update],
+        intents: [GUILD_VOICE_STATES],
+    },
     /// Voice server information is available
-    VoiceServerUpdate(VoiceServerUpdateEvent),
+    VoiceServerUpdate(VoiceServerUpdateEvent) {
+        name: "VOICE_SERVER_UPDATE",
+        handlers: [voice_server_update],
+        intents: [],
+    },
     /// Fired when the status of a Voice Channel changes.
-    VoiceChannelStatusUpdate(VoiceChannelStatusUpdateEvent),
+    VoiceChannelStatusUpdate(VoiceChannelStatusUpdateEvent) {
+        name: "VOICE_CHANNEL_STATUS_UPDATE",
+        handlers: [voice_channel_status_update],
+        intents: [GUILDS],
+    },
     /// A webhook for a [channel][`GuildChannel`] was updated in a [`Guild`].
-    #[serde(rename = "WEBHOOKS_UPDATE")]
-    WebhookUpdate(WebhookUpdateEvent),
+    WebhookUpdate(WebhookUpdateEvent) {
+        name: "WEBHOOKS_UPDATE",
+        handlers: [webhook_update],
+        intents: [GUILD_WEBHOOKS],
+    },
     /// An interaction was created.
-    InteractionCreate(InteractionCreateEvent),
+    InteractionCreate(InteractionCreateEvent) {
+        name: "INTERACTION_CREATE",
+        handlers: [interaction_create],
+        intents: [],
+    },
     /// A guild integration was created
-    IntegrationCreate(IntegrationCreateEvent),
+    IntegrationCreate(IntegrationCreateEvent) {
+        name: "INTEGRATION_CREATE",
+        handlers: [integration_create],
+        intents: [GUILD_INTEGRATIONS],
+    },
     /// A guild integration was updated
-    IntegrationUpdate(IntegrationUpdateEvent),
+    IntegrationUpdate(IntegrationUpdateEvent) {
+        name: "INTEGRATION_UPDATE",
+        handlers: [integration_update],
+        intents: [GUILD_INTEGRATIONS],
+    },
     /// A guild integration was deleted
-    IntegrationDelete(IntegrationDeleteEvent),
+    IntegrationDelete(IntegrationDeleteEvent) {
+        name: "INTEGRATION_DELETE",
+        handlers: [integration_delete],
+        intents: [GUILD_INTEGRATIONS],
+    },
     /// A stage instance was created.
-    StageInstanceCreate(StageInstanceCreateEvent),
+    StageInstanceCreate(StageInstanceCreateEvent) {
+        name: "STAGE_INSTANCE_CREATE",
+        handlers: [stage_instance_create],
+        intents: [GUILDS],
+    },
     /// A stage instance was updated.
-    StageInstanceUpdate(StageInstanceUpdateEvent),
+    StageInstanceUpdate(StageInstanceUpdateEvent) {
+        name: "STAGE_INSTANCE_UPDATE",
+        handlers: [stage_instance_update],
+        intents: [GUILDS],
+    },
     /// A stage instance was deleted.
-    StageInstanceDelete(StageInstanceDeleteEvent),
+    StageInstanceDelete(StageInstanceDeleteEvent) {
+        name: "STAGE_INSTANCE_DELETE",
+        handlers: [stage_instance_delete],
+        intents: [GUILDS],
+    },
     /// A thread was created or the current user was added
     /// to a private thread.
-    ThreadCreate(ThreadCreateEvent),
+    ThreadCreate(ThreadCreateEvent) {
+        name: "THREAD_CREATE",
+        handlers: [thread_create],
+        intents: [GUILDS],
+    },
     /// A thread was updated.
-    ThreadUpdate(ThreadUpdateEvent),
+    ThreadUpdate(ThreadUpdateEvent) {
+        name: "THREAD_UPDATE",
+        handlers: [thread_update],
+        intents: [GUILDS],
+    },
     /// A thread was deleted.
-    ThreadDelete(ThreadDeleteEvent),
+    ThreadDelete(ThreadDeleteEvent) {
+        name: "THREAD_DELETE",
+        handlers: [thread_delete],
+        intents: [GUILDS],
+    },
     /// The current user gains access to a channel.
-    ThreadListSync(ThreadListSyncEvent),
+    ThreadListSync(ThreadListSyncEvent) {
+        name: "THREAD_LIST_SYNC",
+        handlers: [thread_list_sync],
+        intents: [GUILDS],
+    },
     /// The [`ThreadMember`] object for the current user is updated.
-    ThreadMemberUpdate(ThreadMemberUpdateEvent),
+    ThreadMemberUpdate(ThreadMemberUpdateEvent) {
+        name: "THREAD_MEMBER_UPDATE",
+        handlers: [thread_member_update],
+        intents: [GUILDS],
+    },
     /// Anyone is added to or removed from a thread.
-    ThreadMembersUpdate(ThreadMembersUpdateEvent),
+    ThreadMembersUpdate(ThreadMembersUpdateEvent) {
+        name: "THREAD_MEMBERS_UPDATE",
+        handlers: [thread_members_update],
+        intents: [GUILDS],
+    },
     /// A scheduled event was created.
-    GuildScheduledEventCreate(GuildScheduledEventCreateEvent),
+    GuildScheduledEventCreate(GuildScheduledEventCreateEvent) {
+        name: "GUILD_SCHEDULED_EVENT_CREATE",
+        handlers: [guild_scheduled_event_create],
+        intents: [GUILD_SCHEDULED_EVENTS],
+    },
     /// A scheduled event was updated.
-    GuildScheduledEventUpdate(GuildScheduledEventUpdateEvent),
+    GuildScheduledEventUpdate(GuildScheduledEventUpdateEvent) {
+        name: "GUILD_SCHEDULED_EVENT_UPDATE",
+        handlers: [guild_scheduled_event_update],
+        intents: [GUILD_SCHEDULED_EVENTS],
+    },
     /// A scheduled event was deleted.
-    GuildScheduledEventDelete(GuildScheduledEventDeleteEvent),
+    GuildScheduledEventDelete(GuildScheduledEventDeleteEvent) {
+        name: "GUILD_SCHEDULED_EVENT_DELETE",
+        handlers: [guild_scheduled_event_delete],
+        intents: [GUILD_SCHEDULED_EVENTS],
+    },
     /// A guild member has subscribed to a scheduled event.
-    GuildScheduledEventUserAdd(GuildScheduledEventUserAddEvent),
+    GuildScheduledEventUserAdd(GuildScheduledEventUserAddEvent) {
+        name: "GUILD_SCHEDULED_EVENT_USER_ADD",
+        handlers: [guild_scheduled_event_user_add],
+        intents: [GUILD_SCHEDULED_EVENTS],
+    },
     /// A guild member has unsubscribed from a scheduled event.
-    GuildScheduledEventUserRemove(GuildScheduledEventUserRemoveEvent),
+    GuildScheduledEventUserRemove(GuildScheduledEventUserRemoveEvent) {
+        name: "GUILD_SCHEDULED_EVENT_USER_REMOVE",
+        handlers: [guild_scheduled_event_user_remove],
+        intents: [GUILD_SCHEDULED_EVENTS],
+    },
     /// A user subscribed to a SKU.
-    EntitlementCreate(EntitlementCreateEvent),
+    EntitlementCreate(EntitlementCreateEvent) {
+        name: "ENTITLEMENT_CREATE",
+        handlers: [entitlement_create],
+        intents: [],
+    },
     /// A user's entitlement was updated or renewed.
-    EntitlementUpdate(EntitlementUpdateEvent),
+    EntitlementUpdate(EntitlementUpdateEvent) {
+        name: "ENTITLEMENT_UPDATE",
+        handlers: [entitlement_update],
+        intents: [],
+    },
     /// A user's entitlement was deleted by Discord, or refunded.
-    EntitlementDelete(EntitlementDeleteEvent),
+    EntitlementDelete(EntitlementDeleteEvent) {
+        name: "ENTITLEMENT_DELETE",
+        handlers: [entitlement_delete],
+        intents: [],
+    },
     /// A user has voted on a Message Poll.
-    MessagePollVoteAdd(MessagePollVoteAddEvent),
+    MessagePollVoteAdd(MessagePollVoteAddEvent) {
+        name: "MESSAGE_POLL_VOTE_ADD",
+        handlers: [poll_vote_add],
+        intents: [GUILD_MESSAGE_POLLS, DIRECT_MESSAGE_POLLS],
+    },
     /// A user has removed a previous vote on a Message Poll.
-    MessagePollVoteRemove(MessagePollVoteRemoveEvent),
-    /// An event type not covered by the above
-    #[serde(untagged)]
-    Unknown(UnknownEvent),
+    MessagePollVoteRemove(MessagePollVoteRemoveEvent) {
+        name: "MESSAGE_POLL_VOTE_REMOVE",
+        handlers: [poll_vote_remove],
+        intents: [GUILD_MESSAGE_POLLS, DIRECT_MESSAGE_POLLS],
+    },
 }
 
 impl Event {
+    /// Returns the descriptor of this event. Returns [`None`] if the event is
+    /// [`Unknown`](Event::Unknown).
+    #[must_use]
+    pub fn descriptor(&self) -> Option<&'static EventDescriptor> {
+        self.index().map(|index| &EVENTS[index])
+    }
+
     /// Return the event name of this event. Returns [`None`] if the event is
     /// [`Unknown`](Event::Unknown).
     #[must_use]
     pub fn name(&self) -> Option<String> {
-        if let Self::Unknown(_) = self {
-            None
-        } else {
-            let map = serde_json::to_value(self).ok()?;
-            Some(map.get("t")?.as_str()?.to_string())
+        self.descriptor().map(|descriptor| descriptor.name.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json::{from_value, json};
+
+    #[test]
+    fn typing_start_member() {
+        let value = json!({
+            "channel_id": "1",
+            "guild_id": "2",
+            "user_id": "3",
+            "timestamp": 1_700_000_000,
+            "member": {
+                "user": {
+                    "id": "3",
+                    "username": "typist",
+                    "discriminator": "0",
+                    "avatar": null,
+                },
+                "nick": "Typist",
+                "roles": ["4"],
+                "joined_at": "2023-01-01T00:00:00.000000+00:00",
+                "deaf": false,
+                "mute": false,
+                "flags": 0,
+            },
+        });
+
+        let event: TypingStartEvent = from_value(value).unwrap();
+        let member = event.member.unwrap();
+        assert_eq!(member.guild_id, GuildId::new(2));
+        assert_eq!(member.user.id, UserId::new(3));
+        assert_eq!(member.nick.as_deref(), Some("Typist"));
+        assert_eq!(member.roles, [RoleId::new(4)]);
+    }
+
+    #[test]
+    fn descriptors() {
+        for (i, descriptor) in EVENTS.iter().enumerate() {
+            let found = EventDescriptor::find(descriptor.name).unwrap();
+            assert!(std::ptr::eq(found, descriptor), "{} is listed twice", descriptor.name);
+            assert!(!descriptor.handlers.is_empty(), "{} has no handlers", descriptor.name);
+            assert!(EVENTS[..i].iter().all(|other| other.variant != descriptor.variant));
         }
+        assert!(EventDescriptor::find("NOT_AN_EVENT").is_none());
+
+        // The table determines the names events are (de)serialized with.
+        let event: Event = from_value(json!({
+            "t": "MESSAGE_REACTION_REMOVE_ALL",
+            "d": {"channel_id": "1", "message_id": "2"},
+        }))
+        .unwrap();
+        let descriptor = event.descriptor().unwrap();
+        assert_eq!(descriptor.variant, "ReactionRemoveAll");
+        assert_eq!(event.name().as_deref(), Some(descriptor.name));
+        assert_eq!(crate::json::to_value(&event).unwrap()["t"], json!(descriptor.name));
+
+        let event: Event = from_value(json!({"t": "SOMETHING_NEW", "d": {}})).unwrap();
+        assert!(event.descriptor().is_none());
+        assert!(event.name().is_none());
+    }
+
+    #[test]
+    fn descriptor_intents() {
+        let typing = EventDescriptor::find("TYPING_START").unwrap();
+        assert!(typing.is_received_with(GatewayIntents::DIRECT_MESSAGE_TYPING));
+        assert!(!typing.is_received_with(GatewayIntents::GUILD_MESSAGES));
+
+        let ready = EventDescriptor::find("READY").unwrap();
+        assert!(ready.intents.is_empty());
+        assert!(ready.is_received_with(GatewayIntents::empty()));
     }
 }
@@ -216,3 +216,31 @@ impl From<&Emoji> for EmojiId {
         emoji.id
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json::{from_value, json};
+    use crate::model::id::UserId;
+
+    #[test]
+    fn application_emoji_deserialize() {
+        let value = json!({
+            "id": "1",
+            "name": "ferris",
+            "roles": [],
+            "user": {"id": "2", "username": "alice", "discriminator": "0", "avatar": null},
+            "require_colons": true,
+            "managed": false,
+            "animated": true,
+            "available": true,
+        });
+
+        let emoji: Emoji = from_value(value).unwrap();
+        assert_eq!(emoji.id, EmojiId::new(1));
+        assert!(emoji.animated && emoji.require_colons);
+        assert!(emoji.roles.is_empty());
+        assert_eq!(emoji.user.as_ref().map(|user| user.id), Some(UserId::new(2)));
+        assert_eq!(emoji.to_string(), "<a:ferris:1>");
+    }
+}
@@ -9,31 +9,36 @@ use crate::builder::{
     Builder,
     CreateChannel,
     CreateCommand,
+    CreateGuildTemplate,
     CreateScheduledEvent,
+    CreateSoundboardSound,
     CreateSticker,
     EditAutoModRule,
     EditCommandPermissions,
+    EditCurrentMember,
     EditGuild,
+    EditGuildTemplate,
     EditGuildWelcomeScreen,
     EditGuildWidget,
     EditMember,
+    EditOnboarding,
     EditRole,
     EditScheduledEvent,
+    EditSoundboardSound,
     EditSticker,
 };
 #[cfg(all(feature = "cache", feature = "model"))]
 use crate::cache::{Cache, GuildRef};
 #[cfg(feature = "collector")]
-use crate::collector::{MessageCollector, ReactionCollector};
+use crate::collector::{collect, MessageCollector, ReactionCollector};
 #[cfg(feature = "collector")]
-use crate::gateway::ShardMessenger;
+use crate::gateway::{ChunkGuildFilter, ShardMessenger};
 #[cfg(feature = "model")]
-use crate::http::{CacheHttp, Http, UserPagination};
+use crate::http::{CacheHttp, Http, JsonErrorCode, UserPagination};
 #[cfg(feature = "model")]
 use crate::internal::prelude::*;
 #[cfg(feature = "model")]
 use crate::json::json;
-use crate::model::guild::SerializeIter;
 use crate::model::prelude::*;
 
 #[cfg(feature = "model")]
@@ -255,7 +260,10 @@ impl GuildId {
     ///
     /// # Errors
     ///
-    /// Errors if none of the users are banned or you do not have the
+    /// Returns a [`ModelError::BulkBanAmount`] if an attempt was made to ban 0 or more than 200
+    /// users, and [`Error::ExceededLimit`] if the reason is longer than 512 characters.
+    ///
+    /// Otherwise errors if none of the users are banned or you do not have the
     /// required [`BAN_MEMBERS`] and [`MANAGE_GUILD`] permissions.
     ///
     /// [`BAN_MEMBERS`]: Permissions::BAN_MEMBERS
@@ -268,13 +276,24 @@ impl GuildId {
         reason: Option<&str>,
     ) -> Result<BulkBanResponse> {
         #[derive(serde::Serialize)]
-        struct BulkBan<I> {
-            user_ids: I,
+        struct BulkBan {
+            user_ids: Vec<UserId>,
             delete_message_seconds: u32,
         }
 
+        let user_ids: Vec<UserId> = users.into_iter().collect();
+        if user_ids.is_empty() || user_ids.len() > 200 {
+            return Err(Error::Model(ModelError::BulkBanAmount));
+        }
+
+        if let Some(reason) = reason {
+            if reason.chars().count() > 512 {
+                return Err(Error::ExceededLimit(reason.to_string(), 512));
+            }
+        }
+
         let map = BulkBan {
-            user_ids: SerializeIter::new(users.into_iter()),
+            user_ids,
             delete_message_seconds,
         };
 
@@ -493,6 +512,62 @@ impl GuildId {
         builder.execute(cache_http, self).await
     }
 
+    /// Creates a [`GuildTemplate`] from the current state of the guild.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission, or if invalid data is given.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    #[inline]
+    pub async fn create_template(
+        self,
+        cache_http: impl CacheHttp,
+        builder: CreateGuildTemplate,
+    ) -> Result<GuildTemplate> {
+        builder.execute(cache_http, self).await
+    }
+
+    /// Creates a new soundboard sound in the guild.
+    ///
+    /// **Note**: Requires the [Create Guild Expressions] permission.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// # use serenity::model::id::GuildId;
+    /// use serenity::builder::{CreateAttachment, CreateSoundboardSound};
+    ///
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let http: Http = unimplemented!();
+    /// let sound = CreateAttachment::path("./quack.mp3").await?;
+    /// let builder = CreateSoundboardSound::new("quack", &sound).emoji_name("🦆");
+    /// GuildId::new(7).create_soundboard_sound(&http, builder).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, returns a [`ModelError::InvalidPermissions`] if the current user
+    /// lacks permission. Otherwise returns [`Error::Http`], as well as if invalid data is given.
+    ///
+    /// Returns a [`ModelError::InvalidSound`] if the sound is not an MP3 or Ogg file of at most
+    /// 512 KiB.
+    ///
+    /// [Create Guild Expressions]: Permissions::CREATE_GUILD_EXPRESSIONS
+    #[inline]
+    pub async fn create_soundboard_sound(
+        self,
+        cache_http: impl CacheHttp,
+        builder: CreateSoundboardSound<'_>,
+    ) -> Result<SoundboardSound> {
+        builder.execute(cache_http, self).await
+    }
+
     /// Deletes the current guild if the current account is the owner of the
     /// guild.
     ///
@@ -612,6 +687,47 @@ impl GuildId {
         http.as_ref().delete_sticker(self, sticker_id.into(), None).await
     }
 
+    /// Deletes a [`GuildTemplate`] of the guild by its code, returning the deleted template.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission, or if the guild has no
+    /// template with that code.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    #[inline]
+    pub async fn delete_template(
+        self,
+        http: impl AsRef<Http>,
+        code: &str,
+    ) -> Result<GuildTemplate> {
+        http.as_ref().delete_guild_template(self, code).await
+    }
+
+    /// Deletes a [`SoundboardSound`] by id from the guild.
+    ///
+    /// **Note**: If the sound was created by the current user, requires either the [Create Guild
+    /// Expressions] or the [Manage Guild Expressions] permission. Otherwise, the [Manage Guild
+    /// Expressions] permission is required.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission, or if a sound with that id
+    /// does not exist.
+    ///
+    /// [Create Guild Expressions]: Permissions::CREATE_GUILD_EXPRESSIONS
+    /// [Manage Guild Expressions]: Permissions::MANAGE_GUILD_EXPRESSIONS
+    #[inline]
+    pub async fn delete_soundboard_sound(
+        self,
+        http: impl AsRef<Http>,
+        sound_id: impl Into<SoundId>,
+    ) -> Result<()> {
+        http.as_ref().delete_soundboard_sound(self, sound_id.into(), None).await
+    }
+
     /// Edits the current guild with new data where specified.
     ///
     /// **Note**: Requires the [Manage Guild] permission.
@@ -698,6 +814,49 @@ impl GuildId {
         builder.execute(cache_http, (self, user_id.into())).await
     }
 
+    /// Times out a member for the given duration, preventing them from sending messages,
+    /// reacting, or joining voice channels.
+    ///
+    /// **Note**: Requires the [Moderate Members] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::TimeoutTooLong`] if the duration is longer than
+    /// [`MEMBER_TIMEOUT_MAX`], 28 days.
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    ///
+    /// [Moderate Members]: Permissions::MODERATE_MEMBERS
+    /// [`MEMBER_TIMEOUT_MAX`]: crate::constants::MEMBER_TIMEOUT_MAX
+    pub async fn timeout_member(
+        self,
+        cache_http: impl CacheHttp,
+        user_id: impl Into<UserId>,
+        duration: std::time::Duration,
+    ) -> Result<Member> {
+        let end = timeout_end(Timestamp::now(), duration)?;
+        let builder = EditMember::new().disable_communication_until_datetime(end);
+        self.edit_member(cache_http, user_id, builder).await
+    }
+
+    /// Removes a member's timeout, if there is one.
+    ///
+    /// **Note**: Requires the [Moderate Members] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    ///
+    /// [Moderate Members]: Permissions::MODERATE_MEMBERS
+    pub async fn remove_member_timeout(
+        self,
+        cache_http: impl CacheHttp,
+        user_id: impl Into<UserId>,
+    ) -> Result<Member> {
+        let builder = EditMember::new().enable_communication();
+        self.edit_member(cache_http, user_id, builder).await
+    }
+
     /// Edits the guild's MFA level. Returns the new level on success.
     ///
     /// Requires guild ownership.
@@ -717,6 +876,25 @@ impl GuildId {
         http.as_ref().edit_guild_mfa_level(self, &value, audit_log_reason).await
     }
 
+    /// Edits the current user's member in the guild, such as its nickname or guild specific
+    /// avatar and banner.
+    ///
+    /// Refer to [`EditCurrentMember`] for the fields that can be edited.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidImage`] if an invalid avatar or banner is given.
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission, or if invalid data is given.
+    #[inline]
+    pub async fn edit_current_member(
+        self,
+        cache_http: impl CacheHttp,
+        builder: EditCurrentMember<'_>,
+    ) -> Result<Member> {
+        builder.execute(cache_http, self).await
+    }
+
     /// Edits the current user's nickname for the guild.
     ///
     /// Pass [`None`] to reset the nickname.
@@ -728,6 +906,7 @@ impl GuildId {
     /// Returns [`Error::Http`] if the current user lacks permission.
     ///
     /// [Change Nickname]: Permissions::CHANGE_NICKNAME
+    #[deprecated = "Use [`Self::edit_current_member`] instead"]
     #[inline]
     pub async fn edit_nickname(
         self,
@@ -839,6 +1018,47 @@ impl GuildId {
         builder.execute(cache_http, (self, sticker_id.into())).await
     }
 
+    /// Edits the name and description of a [`GuildTemplate`] of the guild.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission, or if invalid data is given.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    #[inline]
+    pub async fn edit_template(
+        self,
+        cache_http: impl CacheHttp,
+        code: &str,
+        builder: EditGuildTemplate,
+    ) -> Result<GuildTemplate> {
+        builder.execute(cache_http, (self, code)).await
+    }
+
+    /// Edits a soundboard sound.
+    ///
+    /// **Note**: If the sound was created by the current user, requires either the [Create Guild
+    /// Expressions] or the [Manage Guild Expressions] permission. Otherwise, the [Manage Guild
+    /// Expressions] permission is required.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission, or if invalid data is given.
+    ///
+    /// [Create Guild Expressions]: Permissions::CREATE_GUILD_EXPRESSIONS
+    /// [Manage Guild Expressions]: Permissions::MANAGE_GUILD_EXPRESSIONS
+    #[inline]
+    pub async fn edit_soundboard_sound(
+        self,
+        cache_http: impl CacheHttp,
+        sound_id: impl Into<SoundId>,
+        builder: EditSoundboardSound<'_>,
+    ) -> Result<SoundboardSound> {
+        builder.execute(cache_http, (self, sound_id.into())).await
+    }
+
     /// Edit the position of a [`Role`] relative to all others in the [`Guild`].
     ///
     /// **Note**: Requires the [Manage Roles] permission.
@@ -882,6 +1102,25 @@ impl GuildId {
         builder.execute(cache_http, self).await
     }
 
+    /// Edits the guild's onboarding flow.
+    ///
+    /// **Note**: Requires the [Manage Guild] and [Manage Roles] permissions.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::OnboardingDefaultChannelAmount`] if onboarding is enabled with too
+    /// few channels. Otherwise returns [`Error::Http`] if the current user lacks permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    /// [Manage Roles]: Permissions::MANAGE_ROLES
+    pub async fn edit_onboarding(
+        self,
+        cache_http: impl CacheHttp,
+        builder: EditOnboarding<'_>,
+    ) -> Result<Onboarding> {
+        builder.execute(cache_http, self).await
+    }
+
     /// Edits the guild's widget.
     ///
     /// **Note**: Requires the [Manage Guild] permission.
@@ -911,6 +1150,9 @@ impl GuildId {
 
     /// Gets all of the guild's roles over the REST API.
     ///
+    /// **Note**: The returned map doesn't keep the roles in any particular order. Use
+    /// [`Self::ordered_roles`] to get them sorted by their position.
+    ///
     /// # Errors
     ///
     /// Returns [`Error::Http`] if the current user is not in
@@ -921,6 +1163,19 @@ impl GuildId {
         Ok(roles.into_iter().map(|r| (r.id, r)).collect())
     }
 
+    /// Gets all of the guild's roles over the REST API, sorted from the lowest (`@everyone`) to
+    /// the highest position. Roles with the same position are sorted by their Id.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user is not in the guild.
+    pub async fn ordered_roles(self, http: impl AsRef<Http>) -> Result<Vec<Role>> {
+        let mut roles = http.as_ref().get_guild_roles(self).await?;
+        roles.sort();
+
+        Ok(roles)
+    }
+
     /// Gets the default permission role (@everyone) from the guild.
     #[inline]
     #[must_use]
@@ -935,6 +1190,91 @@ impl GuildId {
         cache.as_ref().guild(self)
     }
 
+    /// Creates the permission overwrite in every cached channel of the guild which matches the
+    /// `filter`, e.g. to deny a mute role sending messages everywhere.
+    ///
+    /// Channels which already have the exact overwrite are skipped. The remaining channels are
+    /// edited one after another in order of their position, waiting for `delay` in between to
+    /// spread out the requests. After each channel, `progress` is called with the number of
+    /// channels done and the total number of channels to edit.
+    ///
+    /// Returns the result of [`ChannelId::create_permission`] for every edited channel.
+    ///
+    /// **Note**: Requires the [Manage Roles] permission.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// # use serenity::cache::Cache;
+    /// # use serenity::model::id::{GuildId, RoleId};
+    /// # use std::sync::Arc;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let cache_http: (&Arc<Cache>, &Http) = unimplemented!();
+    /// use std::time::Duration;
+    ///
+    /// use serenity::model::channel::{ChannelType, PermissionOverwrite};
+    /// use serenity::model::Permissions;
+    ///
+    /// let overwrite = PermissionOverwrite::role(RoleId::new(2)).deny(Permissions::SEND_MESSAGES);
+    /// let results = GuildId::new(1)
+    ///     .apply_overwrite_to_all_channels(
+    ///         cache_http,
+    ///         overwrite,
+    ///         |channel| channel.kind == ChannelType::Text,
+    ///         Duration::from_millis(500),
+    ///         |done, total| println!("{done}/{total}"),
+    ///     )
+    ///     .await?;
+    ///
+    /// for (channel_id, result) in results {
+    ///     if let Err(why) = result {
+    ///         println!("Failed to edit {channel_id}: {why}");
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ModelError::GuildNotFound`] if the guild is not in the cache or the cache is
+    /// unavailable. Errors of the individual requests are part of the returned results.
+    ///
+    /// [Manage Roles]: Permissions::MANAGE_ROLES
+    #[cfg(feature = "cache")]
+    pub async fn apply_overwrite_to_all_channels(
+        self,
+        cache_http: impl CacheHttp,
+        overwrite: PermissionOverwrite,
+        filter: impl FnMut(&GuildChannel) -> bool,
+        delay: std::time::Duration,
+        mut progress: impl FnMut(usize, usize),
+    ) -> Result<Vec<(ChannelId, Result<()>)>> {
+        let channel_ids = {
+            let guild = cache_http
+                .cache()
+                .and_then(|cache| cache.guild(self))
+                .ok_or(Error::Model(ModelError::GuildNotFound))?;
+
+            overwrite_targets(&guild, &overwrite, filter)
+        };
+
+        let total = channel_ids.len();
+        let mut results = Vec::with_capacity(total);
+        for (index, channel_id) in channel_ids.into_iter().enumerate() {
+            if index > 0 {
+                tokio::time::sleep(delay).await;
+            }
+
+            let result = channel_id.create_permission(cache_http.http(), overwrite.clone()).await;
+            results.push((channel_id, result));
+            progress(index + 1, total);
+        }
+
+        Ok(results)
+    }
+
     /// Requests [`PartialGuild`] over REST API.
     ///
     /// **Note**: This will not be a [`Guild`], as the REST API does not send
@@ -993,6 +1333,30 @@ impl GuildId {
         http.as_ref().get_emoji(self, emoji_id).await
     }
 
+    /// Gets all [`SoundboardSound`]s of this guild via HTTP.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the guild is unavailable.
+    #[inline]
+    pub async fn soundboard_sounds(self, http: impl AsRef<Http>) -> Result<Vec<SoundboardSound>> {
+        http.as_ref().get_guild_soundboard_sounds(self).await
+    }
+
+    /// Gets a [`SoundboardSound`] of this guild by its ID via HTTP.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if a sound with that Id does not exist.
+    #[inline]
+    pub async fn soundboard_sound(
+        self,
+        http: impl AsRef<Http>,
+        sound_id: SoundId,
+    ) -> Result<SoundboardSound> {
+        http.as_ref().get_guild_soundboard_sound(self, sound_id).await
+    }
+
     /// Gets all [`Sticker`]s of this guild via HTTP.
     ///
     /// # Errors
@@ -1331,6 +1695,33 @@ impl GuildId {
         http.as_ref().get_scheduled_events(self, with_user_count).await
     }
 
+    /// Fetches the scheduled events in the guild like [`Self::scheduled_events`], keeping only
+    /// those matching the given filters. The filtering happens client-side.
+    ///
+    /// If `status` is set, only events with that status are kept.
+    ///
+    /// If `starts_within` is set, only events which are currently [`Active`], or which start
+    /// between now and `starts_within` from now, are kept. Events whose start time has passed but
+    /// which have not been started are not kept.
+    ///
+    /// **Note**: Requires the [View Channel] permission at the guild level.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    ///
+    /// [`Active`]: ScheduledEventStatus::Active
+    /// [View Channel]: Permissions::VIEW_CHANNEL
+    pub async fn scheduled_events_filtered(
+        self,
+        http: impl AsRef<Http>,
+        status: Option<ScheduledEventStatus>,
+        starts_within: Option<std::time::Duration>,
+    ) -> Result<Vec<ScheduledEvent>> {
+        let events = self.scheduled_events(http, false).await?;
+        Ok(filter_scheduled_events(events, status, starts_within, Timestamp::now()))
+    }
+
     /// Fetches a list of interested users for the specified event.
     ///
     /// If `limit` is left unset, by default at most 100 users are returned.
@@ -1376,6 +1767,51 @@ impl GuildId {
             .await
     }
 
+    /// Streams over all interested users for the specified event, in ascending order of their
+    /// Ids.
+    ///
+    /// This is accomplished and equivalent to repeated calls to
+    /// [`Self::scheduled_event_users_optioned`]. A buffer of at most 100 users is used, which is
+    /// the maximum Discord returns at once.
+    ///
+    /// If `with_member` is set to `true`, then the [`member`] field of each user is populated if
+    /// the user is a member of the guild.
+    ///
+    /// **Note**: Requires the [View Channel] permission for the channel associated with the event.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::model::id::{GuildId, ScheduledEventId};
+    /// # use serenity::http::Http;
+    /// #
+    /// # async fn run() {
+    /// # let guild_id = GuildId::new(1);
+    /// # let event_id = ScheduledEventId::new(2);
+    /// # let ctx: Http = unimplemented!();
+    /// use serenity::futures::StreamExt;
+    ///
+    /// let mut users = guild_id.scheduled_event_users_iter(&ctx, event_id, false).boxed();
+    /// while let Some(user_result) = users.next().await {
+    ///     match user_result {
+    ///         Ok(user) => println!("{} is interested", user.user.name),
+    ///         Err(error) => eprintln!("Uh oh!  Error: {}", error),
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// [`member`]: ScheduledEventUser::member
+    /// [View Channel]: Permissions::VIEW_CHANNEL
+    pub fn scheduled_event_users_iter<H: AsRef<Http>>(
+        self,
+        http: H,
+        event_id: impl Into<ScheduledEventId>,
+        with_member: bool,
+    ) -> impl Stream<Item = Result<ScheduledEventUser>> {
+        ScheduledEventUsersIter::<H>::stream(http, self, event_id.into(), with_member)
+    }
+
     /// Returns the Id of the shard associated with the guild.
     ///
     /// When the cache is enabled this will automatically retrieve the total number of shards.
@@ -1453,6 +1889,36 @@ impl GuildId {
         http.as_ref().start_guild_prune(self, days, None).await
     }
 
+    /// Syncs a [`GuildTemplate`] of the guild to the guild's current channels, roles and
+    /// settings.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission, or if the guild has no
+    /// template with that code.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    #[inline]
+    pub async fn sync_template(self, http: impl AsRef<Http>, code: &str) -> Result<GuildTemplate> {
+        http.as_ref().sync_guild_template(self, code).await
+    }
+
+    /// Retrieves the guild's [`GuildTemplate`]s.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    #[inline]
+    pub async fn templates(self, http: impl AsRef<Http>) -> Result<Vec<GuildTemplate>> {
+        http.as_ref().get_guild_templates(self).await
+    }
+
     /// Unbans a [`User`] from the guild.
     ///
     /// **Note**: Requires the [Ban Members] permission.
@@ -1482,6 +1948,42 @@ impl GuildId {
         http.as_ref().get_guild_vanity_url(self).await
     }
 
+    /// Sets the guild's vanity URL code, returning the new code.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission, and the guild must have the `VANITY_URL`
+    /// feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::MissingGuildFeature`] if the guild lacks the `VANITY_URL` feature.
+    /// If the `cache` is enabled and the guild is cached, this is checked before sending the
+    /// request.
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission, or the code is invalid or
+    /// already taken.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    pub async fn set_vanity_url(self, cache_http: impl CacheHttp, code: &str) -> Result<String> {
+        #[cfg(feature = "cache")]
+        {
+            if let Some(guild) = cache_http.cache().and_then(|c| c.guild(self)) {
+                if !guild.features.iter().any(|f| f == "VANITY_URL") {
+                    return Err(Error::Model(ModelError::MissingGuildFeature("VANITY_URL")));
+                }
+            }
+        }
+
+        let result = cache_http.http().edit_guild_vanity_url(self, code, None).await;
+        result.map_err(|why| match why {
+            Error::Http(why)
+                if why.json_error_code() == Some(JsonErrorCode::GuildNeedsMoreBoosts) =>
+            {
+                Error::Model(ModelError::MissingGuildFeature("VANITY_URL"))
+            },
+            why => why,
+        })
+    }
+
     /// Retrieves the guild's webhooks.
     ///
     /// **Note**: Requires the [Manage Webhooks] permission.
@@ -1525,6 +2027,78 @@ impl GuildId {
         self.await_reaction(shard_messenger)
     }
 
+    /// Requests this guild's members over the gateway and waits for all member chunks to arrive.
+    ///
+    /// The request is sent with a unique nonce, so concurrent requests don't mix up their chunks.
+    /// Members that arrive before `timeout` elapses are returned, even if some chunks are still
+    /// missing. As the chunks are dispatched like any other event, the cache is updated with the
+    /// received members as well.
+    ///
+    /// **Note**: Requesting all members with [`ChunkGuildFilter::None`] requires the
+    /// [`GUILD_MEMBERS`] intent.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::gateway::{ChunkGuildFilter, ShardMessenger};
+    /// # use serenity::model::id::GuildId;
+    /// # async fn example_(shard: &ShardMessenger, guild_id: GuildId) {
+    /// use std::time::Duration;
+    ///
+    /// let filter = ChunkGuildFilter::None;
+    /// let members = guild_id.request_members(shard, filter, None, Duration::from_secs(30)).await;
+    /// println!("{guild_id} has {} members", members.len());
+    /// # }
+    /// ```
+    ///
+    /// [`GUILD_MEMBERS`]: GatewayIntents::GUILD_MEMBERS
+    #[cfg(feature = "collector")]
+    pub async fn request_members(
+        self,
+        shard_messenger: impl AsRef<ShardMessenger>,
+        filter: ChunkGuildFilter,
+        limit: Option<u16>,
+        timeout: std::time::Duration,
+    ) -> Vec<Member> {
+        use std::sync::atomic::{AtomicU64, Ordering};
+
+        use futures::StreamExt as _;
+
+        static NONCE: AtomicU64 = AtomicU64::new(0);
+
+        let shard = shard_messenger.as_ref();
+        let nonce = format!("request_members-{}", NONCE.fetch_add(1, Ordering::Relaxed));
+
+        // Register the collector before sending the request, so no chunk is missed.
+        let expected_nonce = nonce.clone();
+        let mut chunks = Box::pin(collect(shard, move |event| match event {
+            Event::GuildMembersChunk(chunk)
+                if chunk.guild_id == self && chunk.nonce.as_ref() == Some(&expected_nonce) =>
+            {
+                Some((chunk.members.clone(), chunk.chunk_count))
+            },
+            _ => None,
+        }));
+        shard.chunk_guild(self, limit, false, filter, Some(nonce));
+
+        let mut members = Vec::new();
+        let collect_chunks = async {
+            // Chunks may arrive out of order, so the last index doesn't mean all have arrived.
+            let mut received = 0;
+            while let Some((chunk, count)) = chunks.next().await {
+                members.extend(chunk.into_values());
+                received += 1;
+                if received >= count {
+                    break;
+                }
+            }
+        };
+        if tokio::time::timeout(timeout, collect_chunks).await.is_err() {
+            tracing::debug!("Timed out waiting for member chunks of guild {self}");
+        }
+        members
+    }
+
     /// Create a guild specific application [`Command`].
     ///
     /// **Note**: Unlike global commands, guild commands will update instantly.
@@ -1550,6 +2124,10 @@ impl GuildId {
         http: impl AsRef<Http>,
         commands: Vec<CreateCommand>,
     ) -> Result<Vec<Command>> {
+        for command in &commands {
+            command.validate()?;
+        }
+
         http.as_ref().create_guild_commands(self, &commands).await
     }
 
@@ -1660,6 +2238,15 @@ impl GuildId {
         http.as_ref().get_guild_welcome_screen(self).await
     }
 
+    /// Get the guild's onboarding flow.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user is not in the guild.
+    pub async fn get_onboarding(self, http: impl AsRef<Http>) -> Result<Onboarding> {
+        http.as_ref().get_guild_onboarding(self).await
+    }
+
     /// Get the guild preview.
     ///
     /// **Note**: The bot need either to be part of the guild or the guild needs to have the
@@ -1681,6 +2268,18 @@ impl GuildId {
         http.as_ref().get_guild_widget(self).await
     }
 
+    /// Get the public `widget.json` of the guild, which includes the online members and an
+    /// instant invite.
+    ///
+    /// This does not require authentication, so `http` may have been created with an empty token.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the widget of the guild is disabled.
+    pub async fn widget_json(self, http: impl AsRef<Http>) -> Result<PublicGuildWidget> {
+        http.as_ref().get_guild_widget_json(self).await
+    }
+
     /// Get the widget image URL.
     #[must_use]
     pub fn widget_image_url(self, style: GuildWidgetStyle) -> String {
@@ -1768,6 +2367,56 @@ impl From<&WebhookGuild> for GuildId {
     }
 }
 
+/// Returns the channels of the guild which the overwrite should be applied to, ordered by their
+/// position.
+#[cfg(all(feature = "cache", feature = "model"))]
+fn overwrite_targets(
+    guild: &Guild,
+    overwrite: &PermissionOverwrite,
+    mut filter: impl FnMut(&GuildChannel) -> bool,
+) -> Vec<ChannelId> {
+    let mut channels: Vec<_> = guild
+        .channels
+        .values()
+        .filter(|channel| !channel.permission_overwrites.contains(overwrite))
+        .filter(|channel| filter(channel))
+        .collect();
+    channels.sort_by_key(|channel| (channel.position, channel.id));
+
+    channels.into_iter().map(|channel| channel.id).collect()
+}
+
+/// Returns the time a timeout of the given duration starting `now` ends at.
+#[cfg(feature = "model")]
+fn timeout_end(now: Timestamp, duration: std::time::Duration) -> Result<Timestamp> {
+    if duration > crate::constants::MEMBER_TIMEOUT_MAX {
+        return Err(Error::Model(ModelError::TimeoutTooLong));
+    }
+
+    let end = now.unix_timestamp() * 1000 + duration.as_millis() as i64;
+    Timestamp::from_millis(end).map_err(|_| Error::Model(ModelError::TimeoutTooLong))
+}
+
+/// Keeps the events matching the filters of [`GuildId::scheduled_events_filtered`].
+#[cfg(feature = "model")]
+fn filter_scheduled_events(
+    mut events: Vec<ScheduledEvent>,
+    status: Option<ScheduledEventStatus>,
+    starts_within: Option<std::time::Duration>,
+    now: Timestamp,
+) -> Vec<ScheduledEvent> {
+    let now = now.unix_timestamp();
+    events.retain(|event| {
+        let start = event.start_time.unix_timestamp();
+        status.map_or(true, |status| event.status == status)
+            && starts_within.map_or(true, |window| {
+                event.status == ScheduledEventStatus::Active
+                    || (now..=now.saturating_add(window.as_secs() as i64)).contains(&start)
+            })
+    });
+    events
+}
+
 /// A helper class returned by [`GuildId::members_iter`]
 #[derive(Clone, Debug)]
 #[cfg(feature = "model")]
@@ -1855,6 +2504,92 @@ impl<H: AsRef<Http>> MembersIter<H> {
     }
 }
 
+/// A helper class returned by [`GuildId::scheduled_event_users_iter`]
+#[derive(Clone, Debug)]
+#[cfg(feature = "model")]
+pub struct ScheduledEventUsersIter<H: AsRef<Http>> {
+    guild_id: GuildId,
+    event_id: ScheduledEventId,
+    with_member: bool,
+    http: H,
+    buffer: Vec<ScheduledEventUser>,
+    after: Option<UserId>,
+    tried_fetch: bool,
+}
+
+#[cfg(feature = "model")]
+impl<H: AsRef<Http>> ScheduledEventUsersIter<H> {
+    fn new(
+        guild_id: GuildId,
+        event_id: ScheduledEventId,
+        with_member: bool,
+        http: H,
+    ) -> ScheduledEventUsersIter<H> {
+        ScheduledEventUsersIter {
+            guild_id,
+            event_id,
+            with_member,
+            http,
+            buffer: Vec::new(),
+            after: None,
+            tried_fetch: false,
+        }
+    }
+
+    /// Fills the `self.buffer` cache of users.
+    ///
+    /// This drops any users that were currently in the buffer, so it should only be called when
+    /// `self.buffer` is empty. If there are no more users to be fetched, then this marks
+    /// `self.after` as None, indicating that no more calls ought to be made.
+    async fn refresh(&mut self) -> Result<()> {
+        // Number of users to fetch, which is also the maximum
+        let grab_size: u64 = 100;
+
+        self.buffer = self
+            .guild_id
+            .scheduled_event_users_optioned(
+                &self.http,
+                self.event_id,
+                Some(grab_size),
+                self.after.map(UserPagination::After),
+                Some(self.with_member),
+            )
+            .await?;
+
+        // Get the last user. If shorter than 100, there are no more results anyway
+        self.after = self.buffer.get(grab_size as usize - 1).map(|entry| entry.user.id);
+
+        // Reverse to optimize pop()
+        self.buffer.reverse();
+
+        self.tried_fetch = true;
+
+        Ok(())
+    }
+
+    /// Streams over all interested users for a scheduled event.
+    ///
+    /// Refer to [`GuildId::scheduled_event_users_iter`] for more information.
+    pub fn stream(
+        http: impl AsRef<Http>,
+        guild_id: GuildId,
+        event_id: ScheduledEventId,
+        with_member: bool,
+    ) -> impl Stream<Item = Result<ScheduledEventUser>> {
+        let init_state = ScheduledEventUsersIter::new(guild_id, event_id, with_member, http);
+
+        futures::stream::unfold(init_state, |mut state| async {
+            if state.buffer.is_empty() && state.after.is_some() || !state.tried_fetch {
+                if let Err(error) = state.refresh().await {
+                    return Some((Err(error), state));
+                }
+            }
+
+            state.buffer.pop().map(|entry| (Ok(entry), state))
+        })
+    }
+}
+
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Deserialize, Serialize)]
 #[non_exhaustive]
 pub enum GuildWidgetStyle {
@@ -1876,3 +2611,241 @@ impl fmt::Display for GuildWidgetStyle {
         }
     }
 }
+
+#[cfg(test)]
+#[cfg(all(feature = "cache", feature = "model"))]
+mod tests {
+    use super::*;
+
+    fn channel(id: u64, position: u16, kind: ChannelType) -> GuildChannel {
+        GuildChannel {
+            id: ChannelId::new(id),
+            position,
+            kind,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn overwrite_targets() {
+        let overwrite = PermissionOverwrite::role(RoleId::new(1)).deny(Permissions::SEND_MESSAGES);
+
+        let mut applied = channel(3, 0, ChannelType::Text);
+        applied.permission_overwrites.push(overwrite.clone());
+        let mut different = channel(4, 1, ChannelType::Text);
+        different.permission_overwrites.push(overwrite.clone().allow(Permissions::SEND_MESSAGES));
+
+        let guild = Guild {
+            channels: [
+                channel(1, 2, ChannelType::Text),
+                channel(2, 0, ChannelType::Voice),
+                channel(5, 0, ChannelType::Text),
+                applied,
+                different,
+            ]
+            .into_iter()
+            .map(|channel| (channel.id, channel))
+            .collect(),
+            ..Default::default()
+        };
+
+        let targets = super::overwrite_targets(&guild, &overwrite, |_| true);
+        let expected = [2, 5, 4, 1].map(ChannelId::new);
+        assert_eq!(targets, expected);
+
+        let targets = super::overwrite_targets(&guild, &overwrite, |c| c.kind == ChannelType::Text);
+        assert_eq!(targets, [5, 4, 1].map(ChannelId::new));
+    }
+
+    #[test]
+    fn timeout_end() {
+        let now = Timestamp::from_unix_timestamp(1_700_000_000).unwrap();
+
+        let end = super::timeout_end(now, std::time::Duration::from_secs(60)).unwrap();
+        assert_eq!(end.unix_timestamp(), 1_700_000_060);
+
+        let max = crate::constants::MEMBER_TIMEOUT_MAX;
+        assert!(super::timeout_end(now, max).is_ok());
+        assert!(matches!(
+            super::timeout_end(now, max + std::time::Duration::from_secs(1)),
+            Err(Error::Model(ModelError::TimeoutTooLong))
+        ));
+    }
+
+    #[test]
+    fn filter_scheduled_events() {
+        let event = |id: u64, start: i64, status: ScheduledEventStatus| {
+            let mut event: ScheduledEvent = crate::json::from_value(crate::json::json!({
+                "id": id.to_string(),
+                "guild_id": "1",
+                "channel_id": null,
+                "name": "event",
+                "scheduled_start_time": "2023-11-14T22:13:20Z",
+                "privacy_level": 2,
+                "status": 1,
+                "entity_type": 3,
+            }))
+            .unwrap();
+            event.start_time = Timestamp::from_unix_timestamp(start).unwrap();
+            event.status = status;
+            event
+        };
+
+        let now = 1_700_000_000;
+        let week = std::time::Duration::from_secs(7 * 24 * 60 * 60);
+        let events = vec![
+            event(1, now - 60, ScheduledEventStatus::Active),
+            event(2, now - 60, ScheduledEventStatus::Scheduled),
+            event(3, now, ScheduledEventStatus::Scheduled),
+            event(4, now + 7 * 24 * 60 * 60, ScheduledEventStatus::Scheduled),
+            event(5, now + 7 * 24 * 60 * 60 + 1, ScheduledEventStatus::Scheduled),
+            event(6, now + 60, ScheduledEventStatus::Canceled),
+        ];
+        let now = Timestamp::from_unix_timestamp(now).unwrap();
+        let ids = |events: Vec<ScheduledEvent>| -> Vec<u64> {
+            events.into_iter().map(|event| event.id.get()).collect()
+        };
+
+        let filtered = super::filter_scheduled_events(events.clone(), None, Some(week), now);
+        assert_eq!(ids(filtered), [1, 3, 4, 6]);
+
+        let status = Some(ScheduledEventStatus::Scheduled);
+        let filtered = super::filter_scheduled_events(events.clone(), status, Some(week), now);
+        assert_eq!(ids(filtered), [3, 4]);
+
+        let filtered = super::filter_scheduled_events(events, status, None, now);
+        assert_eq!(ids(filtered), [2, 3, 4, 5]);
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "testing")]
+    async fn scheduled_event_users_iter() {
+        use std::sync::Arc;
+
+        use futures::StreamExt;
+
+        use crate::http::testing::MockTransport;
+        use crate::http::HttpBuilder;
+        use crate::json::{json, Value};
+
+        let page = |ids: std::ops::RangeInclusive<u64>| -> Value {
+            let users = ids.map(|id| {
+                json!({
+                    "guild_scheduled_event_id": "2",
+                    "user": {"id": id.to_string(), "username": "user", "discriminator": "0"},
+                })
+            });
+            json!(users.collect::<Vec<_>>())
+        };
+
+        let mock = Arc::new(MockTransport::new());
+        let http = HttpBuilder::new("token").transport(mock.clone()).build();
+        mock.push_json(page(1..=100));
+        mock.push_json(page(101..=130));
+
+        let users: Vec<_> = GuildId::new(1)
+            .scheduled_event_users_iter(&http, ScheduledEventId::new(2), false)
+            .map(|user| user.unwrap().user.id.get())
+            .collect()
+            .await;
+        assert_eq!(users, (1..=130).collect::<Vec<_>>());
+
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[0].params.iter().all(|(key, _)| *key != "after"));
+        assert!(requests[1].params.contains(&("after", "100".to_string())));
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "testing")]
+    async fn fetch_roles() {
+        use std::sync::Arc;
+
+        use crate::http::testing::MockTransport;
+        use crate::http::HttpBuilder;
+        use crate::json::json;
+
+        let role = |id: &str, position: u16| {
+            json!({
+                "id": id,
+                "name": "role",
+                "color": 0,
+                "hoist": false,
+                "position": position,
+                "permissions": "0",
+                "managed": false,
+                "mentionable": false,
+            })
+        };
+
+        let mock = Arc::new(MockTransport::new());
+        let http = HttpBuilder::new("token").transport(mock.clone()).build();
+        let cache = Arc::new(Cache::new());
+        mock.push_json(role("2", 3));
+        mock.push_json(role("2", 3));
+        mock.push_json(json!([role("3", 1), role("1", 0), role("2", 3), role("4", 1)]));
+
+        let guild_id = GuildId::new(1);
+        let fetched = guild_id.role(&http, RoleId::new(2)).await.unwrap();
+        assert_eq!((fetched.id, fetched.guild_id), (RoleId::new(2), guild_id));
+
+        // The guild isn't cached, so this falls back to the same endpoint.
+        let fetched = RoleId::new(2).to_role((&cache, &http), guild_id).await.unwrap();
+        assert_eq!(fetched.position, 3);
+
+        let roles = guild_id.ordered_roles(&http).await.unwrap();
+        let ids: Vec<_> = roles.iter().map(|role| role.id.get()).collect();
+        assert_eq!(ids, [1, 3, 4, 2]);
+        assert!(roles.iter().all(|role| role.guild_id == guild_id));
+
+        let requests = mock.requests();
+        assert!(requests[0].path.ends_with("/guilds/1/roles/2"));
+        assert_eq!(requests[0].path, requests[1].path);
+        assert!(requests[2].path.ends_with("/guilds/1/roles"));
+    }
+
+    #[tokio::test]
+    #[cfg(all(feature = "testing", feature = "cache"))]
+    async fn set_vanity_url() {
+        use std::sync::Arc;
+
+        use crate::http::testing::MockTransport;
+        use crate::http::{HttpBuilder, LightMethod, StatusCode};
+        use crate::json::json;
+
+        let mock = Arc::new(MockTransport::new());
+        let http = HttpBuilder::new("token").transport(mock.clone()).build();
+        let cache = Arc::new(Cache::new());
+        mock.push_json(json!({"code": "serenity"}));
+
+        // The guild isn't cached, so the request is sent.
+        let guild_id = GuildId::new(1);
+        let code = guild_id.set_vanity_url((&cache, &http), "serenity").await.unwrap();
+        assert_eq!(code, "serenity");
+
+        let requests = mock.requests();
+        assert_eq!(requests[0].method, LightMethod::Patch);
+        assert!(requests[0].path.ends_with("/guilds/1/vanity-url"));
+        assert_eq!(requests[0].body_json().unwrap(), Some(json!({"code": "serenity"})));
+
+        let guild = Guild {
+            id: guild_id,
+            ..Default::default()
+        };
+        cache.guilds.insert(guild_id, guild);
+        assert!(matches!(
+            guild_id.set_vanity_url((&cache, &http), "serenity").await,
+            Err(Error::Model(ModelError::MissingGuildFeature("VANITY_URL")))
+        ));
+        assert_eq!(mock.requests().len(), 1);
+
+        // Without the guild cached, Discord's error is turned into the same one.
+        let body = json!({"code": 50101, "message": "This server needs more boosts"});
+        mock.push_response(StatusCode::BAD_REQUEST, crate::json::to_vec(&body).unwrap());
+        assert!(matches!(
+            GuildId::new(2).set_vanity_url((&cache, &http), "serenity").await,
+            Err(Error::Model(ModelError::MissingGuildFeature("VANITY_URL")))
+        ));
+        assert_eq!(mock.requests().len(), 2);
+    }
+}
@@ -0,0 +1,180 @@
+use crate::model::id::{ChannelId, GuildId};
+use crate::model::user::OnlineStatus;
+
+/// The public `widget.json` of a [`Guild`], available without authentication when the widget is
+/// enabled.
+///
+/// Not to be confused with [`GuildWidget`], which holds the widget settings.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/guild#guild-widget-object).
+///
+/// [`Guild`]: super::Guild
+/// [`GuildWidget`]: super::GuildWidget
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct PublicGuildWidget {
+    /// The guild Id.
+    pub id: GuildId,
+    /// The guild name.
+    pub name: String,
+    /// The invite URL of the widget channel, if one is set.
+    pub instant_invite: Option<String>,
+    /// The voice and stage channels visible to everyone, sorted by position.
+    pub channels: Vec<GuildWidgetChannel>,
+    /// Up to 100 online members.
+    ///
+    /// **Note**: Discord anonymizes these entries, see [`GuildWidgetMember::id`].
+    pub members: Vec<GuildWidgetMember>,
+    /// The number of online members in the guild.
+    pub presence_count: u64,
+}
+
+/// A channel listed in a [`PublicGuildWidget`].
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/guild#guild-widget-object-example-guild-widget).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct GuildWidgetChannel {
+    /// The channel Id.
+    pub id: ChannelId,
+    /// The channel name.
+    pub name: String,
+    /// The sorting position of the channel.
+    pub position: u16,
+}
+
+/// An online member listed in a [`PublicGuildWidget`].
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/guild#guild-widget-object-example-guild-widget).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct GuildWidgetMember {
+    /// An anonymized identifier of the member.
+    ///
+    /// This is **not** a [`UserId`], but a sequential index (`"0"`, `"1"`, ...) which is only
+    /// unique within a single response.
+    ///
+    /// [`UserId`]: crate::model::id::UserId
+    pub id: String,
+    /// The display name of the member.
+    pub username: String,
+    /// Always `"0000"`, as the real discriminator is hidden.
+    pub discriminator: String,
+    /// Always [`None`], use [`Self::avatar_url`] instead.
+    pub avatar: Option<String>,
+    /// The online status of the member.
+    pub status: OnlineStatus,
+    /// The URL of the member's avatar, proxied by Discord.
+    pub avatar_url: String,
+    /// The activity the member is currently engaged in.
+    #[serde(default)]
+    pub activity: Option<GuildWidgetActivity>,
+    /// The voice channel the member is connected to, if it's listed in the widget.
+    #[serde(default)]
+    pub channel_id: Option<ChannelId>,
+    /// Whether the member is deafened by the guild.
+    #[serde(default)]
+    pub deaf: bool,
+    /// Whether the member is muted by the guild.
+    #[serde(default)]
+    pub mute: bool,
+    /// Whether the member deafened themselves.
+    #[serde(default)]
+    pub self_deaf: bool,
+    /// Whether the member muted themselves.
+    #[serde(default)]
+    pub self_mute: bool,
+    /// Whether the member is suppressed in a stage channel.
+    #[serde(default)]
+    pub suppress: bool,
+}
+
+/// The activity of a [`GuildWidgetMember`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct GuildWidgetActivity {
+    /// The name of the activity.
+    pub name: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json::{from_value, json};
+
+    #[test]
+    fn public_widget_deserialization() {
+        let value = json!({
+            "id": "81384788765712384",
+            "name": "Discord API",
+            "instant_invite": "https://discord.com/invite/discord-api",
+            "channels": [
+                {"id": "85482585546833920", "name": "General", "position": 0},
+                {"id": "85482630350790656", "name": "Music", "position": 1}
+            ],
+            "members": [
+                {
+                    "id": "0",
+                    "username": "Alice",
+                    "discriminator": "0000",
+                    "avatar": null,
+                    "status": "online",
+                    "avatar_url": "https://cdn.discordapp.com/widget-avatars/abc/def",
+                    "activity": {"name": "Rust"},
+                    "channel_id": "85482630350790656",
+                    "deaf": false,
+                    "mute": false,
+                    "self_deaf": true,
+                    "self_mute": true,
+                    "suppress": false
+                },
+                {
+                    "id": "1",
+                    "username": "Bob",
+                    "discriminator": "0000",
+                    "avatar": null,
+                    "status": "dnd",
+                    "avatar_url": "https://cdn.discordapp.com/widget-avatars/ghi/jkl"
+                }
+            ],
+            "presence_count": 2
+        });
+
+        let widget: PublicGuildWidget = from_value(value).unwrap();
+        assert_eq!(widget.id, GuildId::new(81384788765712384));
+        assert_eq!(widget.instant_invite.as_deref(), Some("https://discord.com/invite/discord-api"));
+        assert_eq!(widget.channels.len(), 2);
+        assert_eq!(widget.channels[1].name, "Music");
+        assert_eq!(widget.presence_count, 2);
+
+        let alice = &widget.members[0];
+        assert_eq!(alice.id, "0");
+        assert_eq!(alice.status, OnlineStatus::Online);
+        assert_eq!(alice.activity.as_ref().map(|a| a.name.as_str()), Some("Rust"));
+        assert_eq!(alice.channel_id, Some(ChannelId::new(85482630350790656)));
+        assert!(alice.self_mute && alice.self_deaf);
+
+        let bob = &widget.members[1];
+        assert_eq!(bob.id, "1");
+        assert_eq!(bob.status, OnlineStatus::DoNotDisturb);
+        assert!(bob.activity.is_none());
+        assert!(bob.channel_id.is_none());
+        assert!(!bob.self_mute);
+    }
+
+    #[test]
+    fn public_widget_without_invite() {
+        let value = json!({
+            "id": "81384788765712384",
+            "name": "Discord API",
+            "instant_invite": null,
+            "channels": [],
+            "members": [],
+            "presence_count": 0
+        });
+
+        let widget: PublicGuildWidget = from_value(value).unwrap();
+        assert!(widget.instant_invite.is_none());
+        assert!(widget.members.is_empty());
+    }
+}
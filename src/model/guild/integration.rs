@@ -11,19 +11,32 @@ use crate::model::prelude::*;
 pub struct Integration {
     pub id: IntegrationId,
     pub name: String,
+    /// The integration type, one of `"twitch"`, `"youtube"`, `"discord"` or
+    /// `"guild_subscription"`.
     #[serde(rename = "type")]
     pub kind: String,
     pub enabled: bool,
+    /// Whether the integration is syncing. Only present for Twitch and YouTube integrations.
     pub syncing: Option<bool>,
+    /// The role used for subscribers. Only present for Twitch and YouTube integrations.
     pub role_id: Option<RoleId>,
+    /// Whether emoticons should be synced. Only present for Twitch integrations.
     pub enable_emoticons: Option<bool>,
+    /// What happens once a subscription expires. Only present for Twitch and YouTube
+    /// integrations.
     #[serde(rename = "expire_behavior")]
     pub expire_behaviour: Option<IntegrationExpireBehaviour>,
+    /// The grace period in days before expiring subscribers. Only present for Twitch and YouTube
+    /// integrations.
     pub expire_grace_period: Option<u64>,
     pub user: Option<User>,
     pub account: IntegrationAccount,
     pub synced_at: Option<Timestamp>,
+    /// How many subscribers the integration has. Only present for Twitch and YouTube
+    /// integrations.
     pub subscriber_count: Option<u64>,
+    /// Whether the integration has been revoked. Only present for Twitch and YouTube
+    /// integrations.
     pub revoked: Option<bool>,
     pub application: Option<IntegrationApplication>,
     pub scopes: Option<Vec<Scope>>,
@@ -77,3 +90,94 @@ pub struct IntegrationApplication {
     pub description: String,
     pub bot: Option<User>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json::{from_value, json};
+
+    #[test]
+    fn twitch_integration() {
+        let value = json!({
+            "id": "33590653072239123",
+            "name": "A Name",
+            "type": "twitch",
+            "enabled": true,
+            "syncing": false,
+            "role_id": "41771983423143936",
+            "enable_emoticons": true,
+            "expire_behavior": 1,
+            "expire_grace_period": 7,
+            "user": {
+                "id": "80351110224678912",
+                "username": "Nelly",
+                "discriminator": "1337",
+                "avatar": null
+            },
+            "account": {"id": "31278467", "name": "nelly"},
+            "synced_at": "2023-04-13T10:20:30.000000+00:00",
+            "subscriber_count": 42,
+            "revoked": false
+        });
+
+        let integration: Integration = from_value(value).unwrap();
+        assert_eq!(integration.kind, "twitch");
+        assert_eq!(integration.role_id, Some(RoleId::new(41771983423143936)));
+        assert_eq!(integration.enable_emoticons, Some(true));
+        assert_eq!(integration.expire_behaviour, Some(IntegrationExpireBehaviour::Kick));
+        assert_eq!(integration.expire_grace_period, Some(7));
+        assert_eq!(integration.subscriber_count, Some(42));
+        assert_eq!(integration.revoked, Some(false));
+        assert_eq!(integration.account.name, "nelly");
+    }
+
+    #[test]
+    fn youtube_integration() {
+        let value = json!({
+            "id": "33590653072239124",
+            "name": "A Channel",
+            "type": "youtube",
+            "enabled": true,
+            "syncing": true,
+            "role_id": "41771983423143937",
+            "expire_behavior": 0,
+            "expire_grace_period": 30,
+            "account": {"id": "UCabc", "name": "A Channel"},
+            "subscriber_count": 1000,
+            "revoked": true
+        });
+
+        let integration: Integration = from_value(value).unwrap();
+        assert_eq!(integration.kind, "youtube");
+        assert_eq!(integration.syncing, Some(true));
+        assert_eq!(integration.enable_emoticons, None);
+        assert_eq!(integration.expire_behaviour, Some(IntegrationExpireBehaviour::RemoveRole));
+        assert_eq!(integration.expire_grace_period, Some(30));
+        assert_eq!(integration.subscriber_count, Some(1000));
+        assert_eq!(integration.revoked, Some(true));
+        assert!(integration.user.is_none());
+    }
+
+    #[test]
+    fn bot_integration() {
+        let value = json!({
+            "id": "33590653072239125",
+            "name": "A Bot",
+            "type": "discord",
+            "enabled": true,
+            "account": {"id": "33590653072239125", "name": "A Bot"},
+            "application": {
+                "id": "33590653072239125",
+                "name": "A Bot",
+                "icon": null,
+                "description": ""
+            },
+            "scopes": ["bot", "applications.commands"]
+        });
+
+        let integration: Integration = from_value(value).unwrap();
+        assert!(integration.subscriber_count.is_none());
+        assert!(integration.revoked.is_none());
+        assert_eq!(integration.scopes.map(|s| s.len()), Some(2));
+    }
+}
@@ -1,10 +1,12 @@
-#[cfg(feature = "cache")]
+#[cfg(all(feature = "cache", feature = "model"))]
 use std::cmp::Reverse;
 use std::fmt;
 
+use serde::ser::{Serialize, Serializer};
+
 #[cfg(feature = "model")]
 use crate::builder::EditMember;
-#[cfg(feature = "cache")]
+#[cfg(all(feature = "cache", feature = "model"))]
 use crate::cache::Cache;
 #[cfg(feature = "model")]
 use crate::http::{CacheHttp, Http};
@@ -30,6 +32,8 @@ pub struct Member {
     pub nick: Option<String>,
     /// The guild avatar hash
     pub avatar: Option<ImageHash>,
+    /// The guild banner hash
+    pub banner: Option<ImageHash>,
     /// Vector of Ids of [`Role`]s given to the member.
     pub roles: Vec<RoleId>,
     /// Timestamp representing the date when the member joined.
@@ -37,10 +41,13 @@ pub struct Member {
     /// Timestamp representing the date since the member is boosting the guild.
     pub premium_since: Option<Timestamp>,
     /// Indicator of whether the member can hear in voice channels.
+    #[serde(default)]
     pub deaf: bool,
     /// Indicator of whether the member can speak in voice channels.
+    #[serde(default)]
     pub mute: bool,
     /// Guild member flags.
+    #[serde(default)]
     pub flags: GuildMemberFlags,
     /// Indicator that the member hasn't accepted the rules of the guild yet.
     #[serde(default)]
@@ -66,13 +73,20 @@ pub struct Member {
     pub unusual_dm_activity_until: Option<Timestamp>,
 }
 
-bitflags! {
-    /// Flags for a guild member.
-    ///
-    /// [Discord docs](https://discord.com/developers/docs/resources/guild#guild-member-object-guild-member-flags).
-    #[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
-    #[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialEq)]
-    pub struct GuildMemberFlags: u32 {
+/// Flags for a guild member.
+///
+/// Unknown bits are kept when deserializing, so that flags which are sent back to Discord through
+/// [`EditMember::flags`] don't unset flags this library doesn't know about yet.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/guild#guild-member-object-guild-member-flags).
+///
+/// [`EditMember::flags`]: crate::builder::EditMember::flags
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialEq)]
+pub struct GuildMemberFlags(u32);
+
+bitflags::bitflags! {
+    impl GuildMemberFlags: u32 {
         /// Member has left and rejoined the guild. Not editable
         const DID_REJOIN = 1 << 0;
         /// Member has completed onboarding. Not editable
@@ -92,6 +106,20 @@ bitflags! {
         const AUTOMOD_QUARANTINED_USERNAME = 1 << 7;
         /// Member has dismissed the DM settings upsell. Not editable
         const DM_SETTINGS_UPSELL_ACKNOWLEDGED = 1 << 9;
+        /// Member's guild tag is blocked by AutoMod. Not editable
+        const AUTOMOD_QUARANTINED_GUILD_TAG = 1 << 10;
+    }
+}
+
+impl<'de> Deserialize<'de> for GuildMemberFlags {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        Ok(Self::from_bits_retain(u32::deserialize(deserializer)?))
+    }
+}
+
+impl Serialize for GuildMemberFlags {
+    fn serialize<S: Serializer>(&self, serializer: S) -> StdResult<S::Ok, S::Error> {
+        self.bits().serialize(serializer)
     }
 }
 
@@ -228,6 +256,88 @@ impl Member {
         }
     }
 
+    /// Times the member out for the given duration, preventing them from sending messages,
+    /// reacting, or joining voice channels.
+    ///
+    /// **Note**: Requires the [Moderate Members] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::TimeoutTooLong`] if the duration is longer than 28 days.
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    ///
+    /// [Moderate Members]: Permissions::MODERATE_MEMBERS
+    pub async fn timeout(
+        &mut self,
+        cache_http: impl CacheHttp,
+        duration: std::time::Duration,
+    ) -> Result<()> {
+        *self = self.guild_id.timeout_member(cache_http, self.user.id, duration).await?;
+        Ok(())
+    }
+
+    /// Removes the member's timeout, if there is one. Equivalent to
+    /// [`Self::enable_communication`].
+    ///
+    /// **Note**: Requires the [Moderate Members] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    ///
+    /// [Moderate Members]: Permissions::MODERATE_MEMBERS
+    pub async fn remove_timeout(&mut self, cache_http: impl CacheHttp) -> Result<()> {
+        *self = self.guild_id.remove_member_timeout(cache_http, self.user.id).await?;
+        Ok(())
+    }
+
+    /// Whether the member is currently timed out, i.e. [`Self::communication_disabled_until`] is
+    /// in the future.
+    ///
+    /// Timed out members only have the [View Channel] and [Read Message History] permissions, if
+    /// they have them at all.
+    ///
+    /// [View Channel]: Permissions::VIEW_CHANNEL
+    /// [Read Message History]: Permissions::READ_MESSAGE_HISTORY
+    #[must_use]
+    pub fn is_timed_out(&self) -> bool {
+        self.communication_disabled_until.is_some_and(|until| until > Timestamp::now())
+    }
+
+    /// Whether the member has left and rejoined the guild.
+    #[must_use]
+    pub fn did_rejoin(&self) -> bool {
+        self.flags.contains(GuildMemberFlags::DID_REJOIN)
+    }
+
+    /// Whether the member has started, but not yet completed, the guild's onboarding.
+    #[must_use]
+    pub fn is_onboarding(&self) -> bool {
+        self.flags.contains(GuildMemberFlags::STARTED_ONBOARDING) && !self.completed_onboarding()
+    }
+
+    /// Whether the member has completed the guild's onboarding.
+    #[must_use]
+    pub fn completed_onboarding(&self) -> bool {
+        self.flags.contains(GuildMemberFlags::COMPLETED_ONBOARDING)
+    }
+
+    /// Whether the member is exempt from the guild's verification requirements.
+    #[must_use]
+    pub fn bypasses_verification(&self) -> bool {
+        self.flags.contains(GuildMemberFlags::BYPASSES_VERIFICATION)
+    }
+
+    /// Whether the member is quarantined by AutoMod, because of their name or guild tag.
+    #[must_use]
+    pub fn is_automod_quarantined(&self) -> bool {
+        self.flags.intersects(
+            GuildMemberFlags::AUTOMOD_QUARANTINED_USERNAME
+                | GuildMemberFlags::AUTOMOD_QUARANTINED_GUILD_TAG,
+        )
+    }
+
     /// Calculates the member's display name.
     ///
     /// The nickname takes priority over the member's username if it exists.
@@ -304,6 +414,15 @@ impl Member {
             .map(|r| (r.id, r.position))
     }
 
+    /// Returns the member's highest role in the given guild's hierarchy.
+    ///
+    /// If two roles share the highest position, the role with the lower ID is returned. Returns
+    /// [`None`] if the member has no roles present in the guild.
+    #[must_use]
+    pub fn top_role<'a>(&self, guild: &'a Guild) -> Option<&'a Role> {
+        guild.member_highest_role(self)
+    }
+
     /// Kick the member from the guild.
     ///
     /// **Note**: Requires the [Kick Members] permission.
@@ -529,6 +648,19 @@ impl Member {
     pub fn face(&self) -> String {
         self.avatar_url().unwrap_or_else(|| self.user.face())
     }
+
+    /// Returns the formatted URL of the member's per guild banner, if one exists.
+    ///
+    /// This will produce a WEBP image URL, or GIF if the member has a GIF banner.
+    #[inline]
+    #[must_use]
+    pub fn banner_url(&self) -> Option<String> {
+        self.banner.as_ref().map(|hash| {
+            let ext = if hash.is_animated() { "gif" } else { "webp" };
+            let (guild_id, user_id) = (self.guild_id, self.user.id);
+            cdn!("/guilds/{}/users/{}/banners/{}.{}?size=1024", guild_id, user_id, hash, ext)
+        })
+    }
 }
 
 impl fmt::Display for Member {
@@ -606,6 +738,7 @@ impl From<PartialMember> for Member {
             user: partial.user.unwrap_or_default(),
             nick: partial.nick,
             avatar: None,
+            banner: None,
             roles: partial.roles,
             joined_at: partial.joined_at,
             premium_since: partial.premium_since,
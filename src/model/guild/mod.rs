@@ -5,13 +5,16 @@ pub mod automod;
 mod emoji;
 mod guild_id;
 mod guild_preview;
+mod guild_widget;
 mod integration;
 mod member;
+mod onboarding;
 mod partial_guild;
 mod premium_tier;
 mod role;
 mod scheduled_event;
 mod system_channel;
+mod template;
 mod welcome_screen;
 
 #[cfg(feature = "model")]
@@ -23,24 +26,29 @@ use tracing::{error, warn};
 pub use self::emoji::*;
 pub use self::guild_id::*;
 pub use self::guild_preview::*;
+pub use self::guild_widget::*;
 pub use self::integration::*;
 pub use self::member::*;
+pub use self::onboarding::*;
 pub use self::partial_guild::*;
 pub use self::premium_tier::*;
 pub use self::role::*;
 pub use self::scheduled_event::*;
 pub use self::system_channel::*;
+pub use self::template::*;
 pub use self::welcome_screen::*;
 #[cfg(feature = "model")]
 use crate::builder::{
     AddMember,
     CreateChannel,
     CreateCommand,
+    CreateGuildTemplate,
     CreateScheduledEvent,
     CreateSticker,
     EditAutoModRule,
     EditCommandPermissions,
     EditGuild,
+    EditGuildTemplate,
     EditGuildWelcomeScreen,
     EditGuildWidget,
     EditMember,
@@ -55,7 +63,7 @@ use crate::collector::{MessageCollector, ReactionCollector};
 #[cfg(feature = "model")]
 use crate::constants::LARGE_THRESHOLD;
 #[cfg(feature = "collector")]
-use crate::gateway::ShardMessenger;
+use crate::gateway::{ChunkGuildFilter, ShardMessenger};
 #[cfg(feature = "model")]
 use crate::http::{CacheHttp, Http, UserPagination};
 #[cfg(feature = "model")]
@@ -80,11 +88,12 @@ pub struct Ban {
 /// [Discord docs](https://github.com/discord/discord-api-docs/pull/6720).
 #[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct BulkBanResponse {
     /// The users that were successfully banned.
-    banned_users: Vec<UserId>,
+    pub banned_users: Vec<UserId>,
     /// The users that were not successfully banned.
-    failed_users: Vec<UserId>,
+    pub failed_users: Vec<UserId>,
 }
 
 #[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
@@ -139,17 +148,20 @@ pub struct Guild {
     /// The channel id that the widget will generate an invite to, or null if set to no invite
     pub widget_channel_id: Option<ChannelId>,
     /// Indicator of the current verification level of the guild.
+    #[serde(default)]
     pub verification_level: VerificationLevel,
     /// Indicator of whether notifications for all messages are enabled by
     /// default in the guild.
+    #[serde(default)]
     pub default_message_notifications: DefaultMessageNotificationLevel,
     /// Default explicit content filter level.
+    #[serde(default)]
     pub explicit_content_filter: ExplicitContentFilter,
     /// A mapping of the guild's roles.
     #[serde(with = "roles")]
     pub roles: HashMap<RoleId, Role>,
     /// All of the guild's custom emojis.
-    #[serde(with = "emojis")]
+    #[serde(default, with = "emojis")]
     pub emojis: HashMap<EmojiId, Emoji>,
     /// The guild features. More information available at [`discord documentation`].
     ///
@@ -182,15 +194,18 @@ pub struct Guild {
     ///
     ///
     /// [`discord documentation`]: https://discord.com/developers/docs/resources/guild#guild-object-guild-features
+    #[serde(default)]
     pub features: Vec<String>,
     /// Indicator of whether the guild requires multi-factor authentication for [`Role`]s or
     /// [`User`]s with moderation permissions.
+    #[serde(default)]
     pub mfa_level: MfaLevel,
     /// Application ID of the guild creator if it is bot-created.
     pub application_id: Option<ApplicationId>,
     /// The ID of the channel to which system messages are sent.
     pub system_channel_id: Option<ChannelId>,
     /// System channel flags.
+    #[serde(default)]
     pub system_channel_flags: SystemChannelFlags,
     /// The id of the channel where rules and/or guidelines are displayed.
     ///
@@ -209,11 +224,13 @@ pub struct Guild {
     /// The guild's banner, if it has one.
     pub banner: Option<String>,
     /// The server's premium boosting level.
+    #[serde(default)]
     pub premium_tier: PremiumTier,
     /// The total number of users currently boosting this server.
     pub premium_subscription_count: Option<u64>,
     /// The preferred locale of this guild only set if guild has the "DISCOVERABLE" feature,
     /// defaults to en-US.
+    #[serde(default)]
     pub preferred_locale: String,
     /// The id of the channel where admins and moderators of Community guilds receive notices from
     /// Discord.
@@ -235,11 +252,13 @@ pub struct Guild {
     /// The guild NSFW state. See [`discord support article`].
     ///
     /// [`discord support article`]: https://support.discord.com/hc/en-us/articles/1500005389362-NSFW-Server-Designation
+    #[serde(default)]
     pub nsfw_level: NsfwLevel,
     /// All of the guild's custom stickers.
-    #[serde(with = "stickers")]
+    #[serde(default, with = "stickers")]
     pub stickers: HashMap<StickerId, Sticker>,
     /// Whether the guild has the boost progress bar enabled
+    #[serde(default)]
     pub premium_progress_bar_enabled: bool,
 
     // =======
@@ -248,15 +267,18 @@ pub struct Guild {
     /// The date that the current user joined the guild.
     pub joined_at: Timestamp,
     /// Indicator of whether the guild is considered "large" by Discord.
+    #[serde(default)]
     pub large: bool,
     /// Whether this guild is unavailable due to an outage.
     #[serde(default)]
     pub unavailable: bool,
     /// The number of members in the guild.
+    #[serde(default)]
     pub member_count: u64,
     /// A mapping of [`User`]s to their current voice state.
     #[serde(serialize_with = "serialize_map_values")]
     #[serde(deserialize_with = "deserialize_voice_states")]
+    #[serde(default)]
     pub voice_states: HashMap<UserId, VoiceState>,
     /// Users who are members of the guild.
     ///
@@ -272,16 +294,18 @@ pub struct Guild {
     #[serde(deserialize_with = "deserialize_guild_channels")]
     pub channels: HashMap<ChannelId, GuildChannel>,
     /// All active threads in this guild that current user has permission to view.
+    #[serde(default)]
     pub threads: Vec<GuildChannel>,
     /// A mapping of [`User`]s' Ids to their current presences.
     ///
     /// **Note**: This will be empty unless the "guild presences" privileged intent is enabled.
-    #[serde(with = "presences")]
+    #[serde(default, with = "presences")]
     pub presences: HashMap<UserId, Presence>,
     /// The stage instances in this guild.
+    #[serde(default)]
     pub stage_instances: Vec<StageInstance>,
     /// The stage instances in this guild.
-    #[serde(rename = "guild_scheduled_events")]
+    #[serde(default, rename = "guild_scheduled_events")]
     pub scheduled_events: Vec<ScheduledEvent>,
 }
 
@@ -955,6 +979,22 @@ impl Guild {
         self.id.create_sticker(cache_http.http(), builder).await
     }
 
+    /// Creates a [`GuildTemplate`] from the current state of the guild.
+    ///
+    /// Refer to [`GuildId::create_template`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission, or if invalid data is given.
+    #[inline]
+    pub async fn create_template(
+        &self,
+        cache_http: impl CacheHttp,
+        builder: CreateGuildTemplate,
+    ) -> Result<GuildTemplate> {
+        self.id.create_template(cache_http, builder).await
+    }
+
     /// Deletes the current guild if the current user is the owner of the
     /// guild.
     ///
@@ -1082,6 +1122,23 @@ impl Guild {
         self.id.delete_sticker(http, sticker_id).await
     }
 
+    /// Deletes a [`GuildTemplate`] of the guild by its code, returning the deleted template.
+    ///
+    /// Refer to [`GuildId::delete_template`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission, or if the guild has no
+    /// template with that code.
+    #[inline]
+    pub async fn delete_template(
+        &self,
+        http: impl AsRef<Http>,
+        code: &str,
+    ) -> Result<GuildTemplate> {
+        self.id.delete_template(http, code).await
+    }
+
     /// Edits the current guild with new data where specified.
     ///
     /// **Note**: Requires the [Manage Guild] permission.
@@ -1208,6 +1265,7 @@ impl Guild {
     /// Otherwise will return [`Error::Http`] if the current user lacks permission.
     ///
     /// [Change Nickname]: Permissions::CHANGE_NICKNAME
+    #[deprecated = "Use [`GuildId::edit_current_member`] instead"]
     pub async fn edit_nickname(
         &self,
         cache_http: impl CacheHttp,
@@ -1220,7 +1278,7 @@ impl Guild {
             }
         }
 
-        self.id.edit_nickname(cache_http.http(), new_nickname).await
+        cache_http.http().edit_nickname(self.id, new_nickname, None).await
     }
 
     /// Edits a role, optionally setting its fields.
@@ -1336,6 +1394,23 @@ impl Guild {
         self.id.edit_sticker(cache_http, sticker_id, builder).await
     }
 
+    /// Edits the name and description of a [`GuildTemplate`] of the guild.
+    ///
+    /// Refer to [`GuildId::edit_template`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission, or if invalid data is given.
+    #[inline]
+    pub async fn edit_template(
+        &self,
+        cache_http: impl CacheHttp,
+        code: &str,
+        builder: EditGuildTemplate,
+    ) -> Result<GuildTemplate> {
+        self.id.edit_template(cache_http, code, builder).await
+    }
+
     /// Edits the guild's welcome screen.
     ///
     /// **Note**: Requires the [Manage Guild] permission.
@@ -1413,6 +1488,37 @@ impl Guild {
         highest
     }
 
+    /// Returns the roles of this guild, ordered from the highest to the lowest in the hierarchy.
+    ///
+    /// Roles are ordered by descending position. If two roles share a position, the role with the
+    /// lower ID is considered higher, matching [`Self::member_highest_role`].
+    #[must_use]
+    pub fn roles_sorted(&self) -> Vec<&Role> {
+        let mut roles: Vec<&Role> = self.roles.values().collect();
+        roles.sort_unstable_by_key(|r| std::cmp::Reverse((r.position, std::cmp::Reverse(r.id))));
+        roles
+    }
+
+    /// Returns the role directly above the given role in the hierarchy.
+    ///
+    /// Returns [`None`] if the role is not in this guild or is already the highest role.
+    #[must_use]
+    pub fn role_above(&self, role_id: RoleId) -> Option<&Role> {
+        let roles = self.roles_sorted();
+        let index = roles.iter().position(|r| r.id == role_id)?;
+        index.checked_sub(1).map(|i| roles[i])
+    }
+
+    /// Returns the role directly below the given role in the hierarchy.
+    ///
+    /// Returns [`None`] if the role is not in this guild or is already the lowest role.
+    #[must_use]
+    pub fn role_below(&self, role_id: RoleId) -> Option<&Role> {
+        let roles = self.roles_sorted();
+        let index = roles.iter().position(|r| r.id == role_id)?;
+        roles.get(index + 1).copied()
+    }
+
     /// Returns which of two [`User`]s has a higher [`Member`] hierarchy.
     ///
     /// Hierarchy is essentially who has the [`Role`] with the highest [`position`].
@@ -1515,6 +1621,21 @@ impl Guild {
         self.id.emoji(http, emoji_id).await
     }
 
+    /// Whether the given [`Member`] can use the custom [`Emoji`] with the given Id of this guild.
+    ///
+    /// This is the case if the emoji exists in the cached [`Self::emojis`], is
+    /// [available](Emoji::available), and is either not restricted to any roles or the member has
+    /// one of the allowed roles.
+    #[must_use]
+    pub fn emoji_available(&self, emoji_id: EmojiId, member: &Member) -> bool {
+        let Some(emoji) = self.emojis.get(&emoji_id) else {
+            return false;
+        };
+
+        emoji.available
+            && (emoji.roles.is_empty() || emoji.roles.iter().any(|r| member.roles.contains(r)))
+    }
+
     /// Gets all integration of the guild.
     ///
     /// **Note**: Requires the [Manage Guild] permission.
@@ -1987,29 +2108,46 @@ impl Guild {
         guild_roles: &HashMap<RoleId, Role>,
         guild_owner_id: UserId,
     ) -> Permissions {
-        let mut everyone_allow_overwrites = Permissions::empty();
-        let mut everyone_deny_overwrites = Permissions::empty();
-        let mut roles_allow_overwrites = Vec::new();
-        let mut roles_deny_overwrites = Vec::new();
-        let mut member_allow_overwrites = Permissions::empty();
-        let mut member_deny_overwrites = Permissions::empty();
+        Self::permission_trace_(
+            channel,
+            member_user_id,
+            member_roles,
+            false,
+            guild_id,
+            guild_roles,
+            guild_owner_id,
+        )
+        .permissions
+    }
+
+    /// Helper function that also records how the permissions were derived, used by
+    /// [`GuildChannel::permission_overwrite_trace`].
+    pub(crate) fn permission_trace_(
+        channel: Option<&GuildChannel>,
+        member_user_id: UserId,
+        member_roles: &[RoleId],
+        member_timed_out: bool,
+        guild_id: GuildId,
+        guild_roles: &HashMap<RoleId, Role>,
+        guild_owner_id: UserId,
+    ) -> PermissionTrace {
+        let mut everyone_overwrite = None;
+        let mut role_overwrites = Vec::new();
+        let mut member_overwrite = None;
 
         if let Some(channel) = channel {
             for overwrite in &channel.permission_overwrites {
                 match overwrite.kind {
                     PermissionOverwriteType::Member(user_id) => {
                         if member_user_id == user_id {
-                            member_allow_overwrites = overwrite.allow;
-                            member_deny_overwrites = overwrite.deny;
+                            member_overwrite = Some(overwrite);
                         }
                     },
                     PermissionOverwriteType::Role(role_id) => {
                         if role_id.get() == guild_id.get() {
-                            everyone_allow_overwrites = overwrite.allow;
-                            everyone_deny_overwrites = overwrite.deny;
+                            everyone_overwrite = Some(overwrite);
                         } else if member_roles.contains(&role_id) {
-                            roles_allow_overwrites.push(overwrite.allow);
-                            roles_deny_overwrites.push(overwrite.deny);
+                            role_overwrites.push((role_id, overwrite));
                         }
                     },
                 }
@@ -2017,7 +2155,9 @@ impl Guild {
         }
 
         calculate_permissions(CalculatePermissions {
+            member_user_id,
             is_guild_owner: member_user_id == guild_owner_id,
+            is_timed_out: member_timed_out,
             everyone_permissions: if let Some(role) = guild_roles.get(&RoleId::new(guild_id.get()))
             {
                 role.permissions
@@ -2027,24 +2167,21 @@ impl Guild {
             },
             user_roles_permissions: member_roles
                 .iter()
-                .map(|role_id| {
+                .filter_map(|role_id| {
                     if let Some(role) = guild_roles.get(role_id) {
-                        role.permissions
+                        Some((*role_id, role.permissions))
                     } else {
                         warn!(
                             "{} on {} has non-existent role {:?}",
                             member_user_id, guild_id, role_id
                         );
-                        Permissions::empty()
+                        None
                     }
                 })
                 .collect(),
-            everyone_allow_overwrites,
-            everyone_deny_overwrites,
-            roles_allow_overwrites,
-            roles_deny_overwrites,
-            member_allow_overwrites,
-            member_deny_overwrites,
+            everyone_overwrite,
+            role_overwrites,
+            member_overwrite,
         })
     }
 
@@ -2406,6 +2543,32 @@ impl Guild {
         self.id.unban(cache_http.http(), user_id).await
     }
 
+    /// Syncs a [`GuildTemplate`] of the guild to the guild's current channels, roles and
+    /// settings.
+    ///
+    /// Refer to [`GuildId::sync_template`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission, or if the guild has no
+    /// template with that code.
+    #[inline]
+    pub async fn sync_template(&self, http: impl AsRef<Http>, code: &str) -> Result<GuildTemplate> {
+        self.id.sync_template(http, code).await
+    }
+
+    /// Retrieves the guild's [`GuildTemplate`]s.
+    ///
+    /// Refer to [`GuildId::templates`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    #[inline]
+    pub async fn templates(&self, http: impl AsRef<Http>) -> Result<Vec<GuildTemplate>> {
+        self.id.templates(http).await
+    }
+
     /// Retrieve's the guild's vanity URL.
     ///
     /// **Note**: Requires the [Manage Guild] permission.
@@ -2421,6 +2584,26 @@ impl Guild {
         self.id.vanity_url(http).await
     }
 
+    /// Sets the guild's vanity URL code, returning the new code.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission, and the guild must have the `VANITY_URL`
+    /// feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::MissingGuildFeature`] if the guild lacks the `VANITY_URL` feature.
+    /// Otherwise returns [`Error::Http`] if the current user lacks permission, or the code is
+    /// invalid or already taken.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    pub async fn set_vanity_url(&self, cache_http: impl CacheHttp, code: &str) -> Result<String> {
+        if !self.features.iter().any(|f| f == "VANITY_URL") {
+            return Err(Error::Model(ModelError::MissingGuildFeature("VANITY_URL")));
+        }
+
+        self.id.set_vanity_url(cache_http, code).await
+    }
+
     /// Retrieves the guild's webhooks.
     ///
     /// **Note**: Requires the [Manage Webhooks] permission.
@@ -2497,6 +2680,20 @@ impl Guild {
         self.await_reaction(shard_messenger)
     }
 
+    /// Requests this guild's members over the gateway and waits for all member chunks to arrive.
+    ///
+    /// Refer to [`GuildId::request_members`] for more information.
+    #[cfg(feature = "collector")]
+    pub async fn request_members(
+        &self,
+        shard_messenger: impl AsRef<ShardMessenger>,
+        filter: ChunkGuildFilter,
+        limit: Option<u16>,
+        timeout: std::time::Duration,
+    ) -> Vec<Member> {
+        self.id.request_members(shard_messenger, filter, limit, timeout).await
+    }
+
     /// Gets the guild active threads.
     ///
     /// # Errors
@@ -2509,89 +2706,115 @@ impl Guild {
 }
 
 #[cfg(feature = "model")]
-struct CalculatePermissions {
+struct CalculatePermissions<'a> {
+    /// The Id of the guild member
+    pub member_user_id: UserId,
     /// Whether the guild member is the guild owner
     pub is_guild_owner: bool,
+    /// Whether the guild member is timed out
+    pub is_timed_out: bool,
     /// Base permissions given to @everyone (guild level)
     pub everyone_permissions: Permissions,
     /// Permissions allowed to a user by their roles (guild level)
-    pub user_roles_permissions: Vec<Permissions>,
-    /// Overwrites that deny permissions for @everyone (channel level)
-    pub everyone_allow_overwrites: Permissions,
-    /// Overwrites that allow permissions for @everyone (channel level)
-    pub everyone_deny_overwrites: Permissions,
-    /// Overwrites that deny permissions for specific roles (channel level)
-    pub roles_allow_overwrites: Vec<Permissions>,
-    /// Overwrites that allow permissions for specific roles (channel level)
-    pub roles_deny_overwrites: Vec<Permissions>,
-    /// Member-specific overwrites that deny permissions (channel level)
-    pub member_allow_overwrites: Permissions,
-    /// Member-specific overwrites that allow permissions (channel level)
-    pub member_deny_overwrites: Permissions,
-}
-
-#[cfg(feature = "model")]
-impl Default for CalculatePermissions {
-    fn default() -> Self {
-        Self {
-            is_guild_owner: false,
-            everyone_permissions: Permissions::empty(),
-            user_roles_permissions: Vec::new(),
-            everyone_allow_overwrites: Permissions::empty(),
-            everyone_deny_overwrites: Permissions::empty(),
-            roles_allow_overwrites: Vec::new(),
-            roles_deny_overwrites: Vec::new(),
-            member_allow_overwrites: Permissions::empty(),
-            member_deny_overwrites: Permissions::empty(),
-        }
-    }
+    pub user_roles_permissions: Vec<(RoleId, Permissions)>,
+    /// Overwrite for @everyone (channel level)
+    pub everyone_overwrite: Option<&'a PermissionOverwrite>,
+    /// Overwrites for specific roles (channel level)
+    pub role_overwrites: Vec<(RoleId, &'a PermissionOverwrite)>,
+    /// Member-specific overwrite (channel level)
+    pub member_overwrite: Option<&'a PermissionOverwrite>,
 }
 
 /// Translated from the pseudo code at https://discord.com/developers/docs/topics/permissions#permission-overwrites
 ///
 /// The comments within this file refer to the above link
 #[cfg(feature = "model")]
-fn calculate_permissions(data: CalculatePermissions) -> Permissions {
-    if data.is_guild_owner {
-        return Permissions::all();
-    }
-
+fn calculate_permissions(data: CalculatePermissions<'_>) -> PermissionTrace {
     // 1. Base permissions given to @everyone are applied at a guild level
     let mut permissions = data.everyone_permissions;
     // 2. Permissions allowed to a user by their roles are applied at a guild level
-    for role_permission in data.user_roles_permissions {
-        permissions |= role_permission;
+    for (_, role_permission) in &data.user_roles_permissions {
+        permissions |= *role_permission;
+    }
+
+    let mut trace = PermissionTrace {
+        everyone: data.everyone_permissions,
+        roles: data.user_roles_permissions,
+        base: permissions,
+        overwrites: Vec::new(),
+        override_reason: None,
+        permissions: Permissions::all(),
+    };
+
+    if data.is_guild_owner {
+        trace.override_reason = Some(PermissionOverride::Owner);
+        return trace;
     }
 
     if permissions.contains(Permissions::ADMINISTRATOR) {
-        return Permissions::all();
+        trace.override_reason = Some(PermissionOverride::Administrator);
+        return trace;
     }
 
-    // 3. Overwrites that deny permissions for @everyone are applied at a channel level
-    permissions &= !data.everyone_deny_overwrites;
-    // 4. Overwrites that allow permissions for @everyone are applied at a channel level
-    permissions |= data.everyone_allow_overwrites;
+    if let Some(overwrite) = data.everyone_overwrite {
+        // 3. Overwrites that deny permissions for @everyone are applied at a channel level
+        permissions &= !overwrite.deny;
+        // 4. Overwrites that allow permissions for @everyone are applied at a channel level
+        permissions |= overwrite.allow;
+
+        trace.overwrites.push(TracedOverwrite {
+            source: OverwriteSource::Everyone,
+            allow: overwrite.allow,
+            deny: overwrite.deny,
+            permissions,
+        });
+    }
 
     // 5. Overwrites that deny permissions for specific roles are applied at a channel level
     let mut role_deny_permissions = Permissions::empty();
-    for p in data.roles_deny_overwrites {
-        role_deny_permissions |= p;
+    for (_, overwrite) in &data.role_overwrites {
+        role_deny_permissions |= overwrite.deny;
     }
     permissions &= !role_deny_permissions;
 
     // 6. Overwrites that allow permissions for specific roles are applied at a channel level
     let mut role_allow_permissions = Permissions::empty();
-    for p in data.roles_allow_overwrites {
-        role_allow_permissions |= p;
+    for (_, overwrite) in &data.role_overwrites {
+        role_allow_permissions |= overwrite.allow;
     }
     permissions |= role_allow_permissions;
 
-    // 7. Member-specific overwrites that deny permissions are applied at a channel level
-    permissions &= !data.member_deny_overwrites;
-    // 8. Member-specific overwrites that allow permissions are applied at a channel level
-    permissions |= data.member_allow_overwrites;
+    trace.overwrites.extend(data.role_overwrites.into_iter().map(|(role_id, overwrite)| {
+        TracedOverwrite {
+            source: OverwriteSource::Role(role_id),
+            allow: overwrite.allow,
+            deny: overwrite.deny,
+            permissions,
+        }
+    }));
+
+    if let Some(overwrite) = data.member_overwrite {
+        // 7. Member-specific overwrites that deny permissions are applied at a channel level
+        permissions &= !overwrite.deny;
+        // 8. Member-specific overwrites that allow permissions are applied at a channel level
+        permissions |= overwrite.allow;
+
+        trace.overwrites.push(TracedOverwrite {
+            source: OverwriteSource::Member(data.member_user_id),
+            allow: overwrite.allow,
+            deny: overwrite.deny,
+            permissions,
+        });
+    }
 
-    permissions
+    // Timed out members are limited to reading the channel, whatever the overwrites allow.
+    if data.is_timed_out {
+        trace.override_reason = Some(PermissionOverride::TimedOut);
+        permissions &= Permissions::VIEW_CHANNEL | Permissions::READ_MESSAGE_HISTORY;
+    }
+
+    trace.permissions = permissions;
+    trace
 }
 
 /// Checks if a `&str` contains another `&str`.
@@ -2857,6 +3080,78 @@ mod test {
             }
         }
 
+        fn gen_roles() -> Guild {
+            let role = |id: u64, position: u16| Role {
+                id: RoleId::new(id),
+                position,
+                ..Default::default()
+            };
+            let roles = [role(1, 0), role(2, 3), role(3, 1), role(4, 1), role(5, 2)];
+
+            Guild {
+                roles: roles.into_iter().map(|r| (r.id, r)).collect(),
+                ..Default::default()
+            }
+        }
+
+        #[test]
+        fn roles_sorted_tie_break() {
+            let guild = gen_roles();
+            let ids: Vec<u64> = guild.roles_sorted().iter().map(|r| r.id.get()).collect();
+
+            assert_eq!(ids, [2, 5, 3, 4, 1]);
+        }
+
+        #[test]
+        fn role_above_below() {
+            let guild = gen_roles();
+
+            assert_eq!(guild.role_above(RoleId::new(4)).map(|r| r.id), Some(RoleId::new(3)));
+            assert_eq!(guild.role_below(RoleId::new(3)).map(|r| r.id), Some(RoleId::new(4)));
+            assert_eq!(guild.role_above(RoleId::new(2)), None);
+            assert_eq!(guild.role_below(RoleId::new(1)), None);
+            assert_eq!(guild.role_above(RoleId::new(6)), None);
+        }
+
+        #[test]
+        fn member_top_role() {
+            let mut guild = gen_roles();
+            let member = Member {
+                roles: vec![RoleId::new(1), RoleId::new(4), RoleId::new(3)],
+                ..Default::default()
+            };
+
+            assert_eq!(member.top_role(&guild).map(|r| r.id), Some(RoleId::new(3)));
+
+            guild.roles.clear();
+            assert_eq!(member.top_role(&guild), None);
+        }
+
+        #[test]
+        fn emoji_available() {
+            let mut guild = gen();
+            let emojis = [(1, true, vec![]), (2, false, vec![]), (3, true, vec![5])];
+            for (id, available, roles) in emojis {
+                let emoji: Emoji = crate::json::from_value(crate::json::json!({
+                    "id": id.to_string(),
+                    "name": "emoji",
+                    "available": available,
+                    "roles": roles.iter().map(ToString::to_string).collect::<Vec<_>>(),
+                }))
+                .unwrap();
+                guild.emojis.insert(emoji.id, emoji);
+            }
+
+            let mut member = gen_member();
+            assert!(guild.emoji_available(EmojiId::new(1), &member));
+            assert!(!guild.emoji_available(EmojiId::new(2), &member));
+            assert!(!guild.emoji_available(EmojiId::new(3), &member));
+            assert!(!guild.emoji_available(EmojiId::new(4), &member));
+
+            member.roles.push(RoleId::new(5));
+            assert!(guild.emoji_available(EmojiId::new(3), &member));
+        }
+
         #[test]
         fn member_named_username() {
             let guild = gen();
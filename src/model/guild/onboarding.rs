@@ -0,0 +1,188 @@
+use crate::model::prelude::*;
+
+/// The onboarding flow of a guild, shown to new members when joining.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/guild#guild-onboarding-object).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct Onboarding {
+    /// The Id of the guild this onboarding is part of.
+    pub guild_id: GuildId,
+    /// The prompts shown during onboarding and in customize community.
+    pub prompts: Vec<OnboardingPrompt>,
+    /// The Ids of the channels that members get opted into automatically.
+    pub default_channel_ids: Vec<ChannelId>,
+    /// Whether onboarding is enabled in the guild.
+    pub enabled: bool,
+    /// The criteria used to determine whether onboarding is configured correctly.
+    pub mode: OnboardingMode,
+}
+
+/// A prompt shown during [`Onboarding`].
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/guild#guild-onboarding-object-onboarding-prompt-structure).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct OnboardingPrompt {
+    /// The Id of the prompt.
+    pub id: OnboardingPromptId,
+    /// The type of the prompt.
+    #[serde(rename = "type")]
+    pub kind: OnboardingPromptType,
+    /// The options available within the prompt.
+    pub options: Vec<PromptOption>,
+    /// The title of the prompt.
+    pub title: String,
+    /// Whether users are limited to selecting one option for the prompt.
+    pub single_select: bool,
+    /// Whether the prompt is required before a user completes the onboarding flow.
+    pub required: bool,
+    /// Whether the prompt is present in the onboarding flow. If `false`, the prompt will only
+    /// appear in the Channels & Roles tab.
+    pub in_onboarding: bool,
+}
+
+/// An option of an [`OnboardingPrompt`].
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/guild#guild-onboarding-object-prompt-option-structure).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct PromptOption {
+    /// The Id of the option.
+    pub id: PromptOptionId,
+    /// The Ids of the channels a member is added to when selecting this option.
+    pub channel_ids: Vec<ChannelId>,
+    /// The Ids of the roles assigned to a member when selecting this option.
+    pub role_ids: Vec<RoleId>,
+    /// The emoji of the option, if any.
+    #[serde(default, deserialize_with = "deserialize_emoji")]
+    pub emoji: Option<ReactionType>,
+    /// The title of the option.
+    pub title: String,
+    /// The description of the option.
+    pub description: Option<String>,
+}
+
+/// Discord sends an emoji with neither an Id nor a name for options without an emoji.
+fn deserialize_emoji<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> StdResult<Option<ReactionType>, D::Error> {
+    #[derive(Deserialize)]
+    struct PartialEmoji {
+        #[serde(default)]
+        animated: bool,
+        id: Option<EmojiId>,
+        name: Option<String>,
+    }
+
+    let Some(emoji) = Option::<PartialEmoji>::deserialize(deserializer)? else {
+        return Ok(None);
+    };
+
+    Ok(match (emoji.id, emoji.name) {
+        (Some(id), name) => Some(ReactionType::Custom {
+            animated: emoji.animated,
+            id,
+            name,
+        }),
+        (None, Some(name)) => Some(ReactionType::Unicode(name)),
+        (None, None) => None,
+    })
+}
+
+enum_number! {
+    /// Defines the criteria used to satisfy the [`Onboarding`] constraints that are required for
+    /// enabling it.
+    ///
+    /// [Discord docs](https://discord.com/developers/docs/resources/guild#guild-onboarding-object-onboarding-mode).
+    #[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
+    #[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+    #[serde(from = "u8", into = "u8")]
+    #[non_exhaustive]
+    pub enum OnboardingMode {
+        /// Only the default channels count towards the constraints.
+        #[default]
+        Default = 0,
+        /// The default channels and the channels of the prompts count towards the constraints.
+        Advanced = 1,
+        _ => Unknown(u8),
+    }
+}
+
+enum_number! {
+    /// The type of an [`OnboardingPrompt`].
+    ///
+    /// [Discord docs](https://discord.com/developers/docs/resources/guild#guild-onboarding-object-prompt-types).
+    #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
+    #[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+    #[serde(from = "u8", into = "u8")]
+    #[non_exhaustive]
+    pub enum OnboardingPromptType {
+        MultipleChoice = 0,
+        Dropdown = 1,
+        _ => Unknown(u8),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json::{from_value, json};
+
+    #[test]
+    fn onboarding_deserialize() {
+        let value = json!({
+            "guild_id": "1",
+            "prompts": [{
+                "id": "2",
+                "type": 0,
+                "title": "What do you like?",
+                "single_select": false,
+                "required": true,
+                "in_onboarding": true,
+                "options": [
+                    {
+                        "id": "3",
+                        "channel_ids": ["10"],
+                        "role_ids": ["20"],
+                        "emoji": {"id": "30", "name": "crab", "animated": true},
+                        "title": "Rust",
+                        "description": null,
+                    },
+                    {
+                        "id": "4",
+                        "channel_ids": [],
+                        "role_ids": [],
+                        "emoji": {"id": null, "name": null},
+                        "title": "Nothing",
+                        "description": "Just lurking",
+                    },
+                ],
+            }],
+            "default_channel_ids": ["10", "11"],
+            "enabled": true,
+            "mode": 1,
+        });
+
+        let onboarding: Onboarding = from_value(value).unwrap();
+        assert_eq!(onboarding.mode, OnboardingMode::Advanced);
+        assert_eq!(onboarding.default_channel_ids, [ChannelId::new(10), ChannelId::new(11)]);
+
+        let prompt = &onboarding.prompts[0];
+        assert_eq!(prompt.kind, OnboardingPromptType::MultipleChoice);
+        assert_eq!(prompt.options[0].role_ids, [RoleId::new(20)]);
+        assert_eq!(
+            prompt.options[0].emoji,
+            Some(ReactionType::Custom {
+                animated: true,
+                id: EmojiId::new(30),
+                name: Some("crab".into()),
+            })
+        );
+        assert_eq!(prompt.options[1].emoji, None);
+        assert_eq!(prompt.options[1].description.as_deref(), Some("Just lurking"));
+    }
+}
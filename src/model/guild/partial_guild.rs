@@ -857,13 +857,14 @@ impl PartialGuild {
     /// Returns [`Error::Http`] if the current user lacks permission to change their nickname.
     ///
     /// [Change Nickname]: Permissions::CHANGE_NICKNAME
+    #[deprecated = "Use [`GuildId::edit_current_member`] instead"]
     #[inline]
     pub async fn edit_nickname(
         &self,
         http: impl AsRef<Http>,
         new_nickname: Option<&str>,
     ) -> Result<()> {
-        self.id.edit_nickname(http, new_nickname).await
+        http.as_ref().edit_nickname(self.id, new_nickname, None).await
     }
 
     /// Edits a role, optionally setting its fields.
@@ -1445,6 +1446,25 @@ impl PartialGuild {
         self.id.vanity_url(http).await
     }
 
+    /// Sets the guild's vanity URL code, returning the new code.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission, and the guild must have the `VANITY_URL`
+    /// feature.
+    ///
+    /// # Errors
+    ///
+    /// See [`Guild::set_vanity_url`].
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    /// [`Guild::set_vanity_url`]: crate::model::guild::Guild::set_vanity_url
+    pub async fn set_vanity_url(&self, cache_http: impl CacheHttp, code: &str) -> Result<String> {
+        if !self.features.iter().any(|f| f == "VANITY_URL") {
+            return Err(Error::Model(ModelError::MissingGuildFeature("VANITY_URL")));
+        }
+
+        self.id.set_vanity_url(cache_http, code).await
+    }
+
     /// Retrieves the guild's webhooks.
     ///
     /// **Note**: Requires the [Manage Webhooks] permission.
@@ -6,8 +6,8 @@ use crate::builder::EditRole;
 #[cfg(all(feature = "cache", feature = "model"))]
 use crate::cache::Cache;
 #[cfg(feature = "model")]
-use crate::http::Http;
-#[cfg(all(feature = "cache", feature = "model"))]
+use crate::http::{CacheHttp, Http};
+#[cfg(feature = "model")]
 use crate::internal::prelude::*;
 use crate::model::prelude::*;
 use crate::model::utils::is_false;
@@ -174,9 +174,31 @@ impl PartialOrd for Role {
 
 #[cfg(feature = "model")]
 impl RoleId {
+    /// Gets the [`Role`] from the given guild, checking the cache first if it's available and
+    /// fetching the role over the REST API otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the role is not in the cache and the current user is not in
+    /// the guild, or if the role does not exist.
+    pub async fn to_role(self, cache_http: impl CacheHttp, guild_id: GuildId) -> Result<Role> {
+        #[cfg(feature = "cache")]
+        {
+            if let Some(cache) = cache_http.cache() {
+                if let Some(guild) = cache.guild(guild_id) {
+                    if let Some(role) = guild.roles.get(&self) {
+                        return Ok(role.clone());
+                    }
+                }
+            }
+        }
+
+        cache_http.http().get_guild_role(guild_id, self).await
+    }
+
     /// Tries to find the [`Role`] by its Id in the cache.
     #[cfg(feature = "cache")]
-    #[deprecated = "Use Guild::roles. This performs a loop over the entire cache!"]
+    #[deprecated = "Use RoleId::to_role. This performs a loop over the entire cache!"]
     pub fn to_role_cached(self, cache: impl AsRef<Cache>) -> Option<Role> {
         for guild_entry in cache.as_ref().guilds.iter() {
             let guild = guild_entry.value();
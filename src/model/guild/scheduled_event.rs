@@ -1,3 +1,8 @@
+#[cfg(feature = "model")]
+use futures::stream::Stream;
+
+#[cfg(feature = "model")]
+use crate::http::Http;
 use crate::model::prelude::*;
 
 /// Information about a guild scheduled event.
@@ -47,12 +52,135 @@ pub struct ScheduledEvent {
     /// Number of users interested in the event.
     ///
     /// Only populated if `with_user_count` is set to true provided when calling
-    /// [`GuildId::scheduled_event`] or [`GuildId::scheduled_events`].
+    /// [`GuildId::scheduled_event`] or [`GuildId::scheduled_events`], or after calling
+    /// [`Self::refresh_user_count`].
     pub user_count: Option<u64>,
     /// The hash of the event's cover image, if present.
     pub image: Option<ImageHash>,
 }
 
+#[cfg(feature = "model")]
+impl ScheduledEvent {
+    /// Fetches the event again with its [`Self::user_count`] populated, replacing this instance
+    /// with the fetched event.
+    ///
+    /// Returns the number of interested users.
+    ///
+    /// **Note**: Requires the [View Channel] permission at the guild level.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission, or if the event no longer
+    /// exists.
+    ///
+    /// [View Channel]: Permissions::VIEW_CHANNEL
+    pub async fn refresh_user_count(&mut self, http: impl AsRef<Http>) -> Result<u64> {
+        *self = self.guild_id.scheduled_event(http, self.id, true).await?;
+        Ok(self.user_count.unwrap_or_default())
+    }
+
+    /// Streams over all interested users for the event.
+    ///
+    /// Refer to [`GuildId::scheduled_event_users_iter`] for more information.
+    pub fn users_iter<H: AsRef<Http>>(
+        &self,
+        http: H,
+        with_member: bool,
+    ) -> impl Stream<Item = Result<ScheduledEventUser>> {
+        self.guild_id.scheduled_event_users_iter(http, self.id, with_member)
+    }
+}
+
+#[cfg(feature = "ical")]
+impl ScheduledEvent {
+    /// Formats the event as an iCalendar ([RFC 5545]) `VEVENT` component, e.g. to mirror it to an
+    /// external calendar. The result must be wrapped in a `VCALENDAR` by the caller.
+    ///
+    /// The event's link on Discord is used as its `URL`, and the location of [`External`] events
+    /// as its `LOCATION`. Lines are terminated with CRLF and folded after 75 octets.
+    ///
+    /// **Note**: Recurrence rules are not part of [`ScheduledEvent`], so no `RRULE` is emitted.
+    ///
+    /// [RFC 5545]: https://datatracker.ietf.org/doc/html/rfc5545#section-3.6.1
+    /// [`External`]: ScheduledEventType::External
+    #[must_use]
+    pub fn to_ical_event(&self) -> String {
+        let mut out = String::new();
+        push_ical_line(&mut out, "BEGIN", "VEVENT");
+        push_ical_line(&mut out, "UID", &format!("{}@discord.com", self.id));
+        push_ical_line(&mut out, "DTSTAMP", &ical_date_time(self.id.created_at()));
+        push_ical_line(&mut out, "DTSTART", &ical_date_time(self.start_time));
+        if let Some(end_time) = self.end_time {
+            push_ical_line(&mut out, "DTEND", &ical_date_time(end_time));
+        }
+        push_ical_line(&mut out, "SUMMARY", &ical_text(&self.name));
+        if let Some(description) = &self.description {
+            push_ical_line(&mut out, "DESCRIPTION", &ical_text(description));
+        }
+        if let Some(location) = self.metadata.as_ref().and_then(|m| m.location.as_deref()) {
+            push_ical_line(&mut out, "LOCATION", &ical_text(location));
+        }
+        let url = format!("https://discord.com/events/{}/{}", self.guild_id, self.id);
+        push_ical_line(&mut out, "URL", &url);
+        let status = match self.status {
+            ScheduledEventStatus::Scheduled
+            | ScheduledEventStatus::Active
+            | ScheduledEventStatus::Completed => Some("CONFIRMED"),
+            ScheduledEventStatus::Canceled => Some("CANCELLED"),
+            _ => None,
+        };
+        if let Some(status) = status {
+            push_ical_line(&mut out, "STATUS", status);
+        }
+        push_ical_line(&mut out, "END", "VEVENT");
+        out
+    }
+}
+
+/// Appends a content line, folding it after every 75 octets.
+#[cfg(feature = "ical")]
+fn push_ical_line(out: &mut String, name: &str, value: &str) {
+    let mut len = 0;
+    for c in name.chars().chain(std::iter::once(':')).chain(value.chars()) {
+        if len + c.len_utf8() > 75 {
+            out.push_str("\r\n ");
+            len = 1;
+        }
+        out.push(c);
+        len += c.len_utf8();
+    }
+    out.push_str("\r\n");
+}
+
+/// Escapes a `TEXT` property value.
+#[cfg(feature = "ical")]
+fn ical_text(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' | ';' | ',' => {
+                out.push('\\');
+                out.push(c);
+            },
+            '\n' => out.push_str("\\n"),
+            '\r' => {},
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// Formats a timestamp as a UTC `DATE-TIME`, e.g. `20231114T221320Z`.
+#[cfg(feature = "ical")]
+fn ical_date_time(timestamp: Timestamp) -> String {
+    // Normalizes the offset to UTC and drops fractional seconds.
+    let utc = Timestamp::from_unix_timestamp(timestamp.unix_timestamp()).unwrap_or(timestamp);
+    // The RFC 3339 form starts with `YYYY-MM-DDTHH:MM:SS`.
+    let rfc3339 = utc.to_rfc3339().unwrap_or_default();
+    let date_time: String = rfc3339.chars().take(19).filter(|c| !matches!(c, '-' | ':')).collect();
+    format!("{date_time}Z")
+}
+
 enum_number! {
     /// [Discord docs](https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-object-guild-scheduled-event-status).
     #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
@@ -115,3 +243,57 @@ enum_number! {
         _ => Unknown(u8),
     }
 }
+
+#[cfg(test)]
+#[cfg(feature = "ical")]
+mod tests {
+    use super::*;
+    use crate::json::{self, json};
+
+    #[test]
+    fn ical_event() {
+        let mut event: ScheduledEvent = json::from_value(json!({
+            "id": "1",
+            "guild_id": "2",
+            "channel_id": null,
+            "name": "Movie night; bring snacks, please",
+            "description": "Line one\nLine two",
+            "scheduled_start_time": "2023-11-14T22:13:20Z",
+            "scheduled_end_time": "2024-02-29T23:59:59Z",
+            "privacy_level": 2,
+            "status": 4,
+            "entity_type": 3,
+            "entity_metadata": { "location": "Somewhere" },
+        }))
+        .unwrap();
+
+        assert_eq!(
+            event.to_ical_event(),
+            "BEGIN:VEVENT\r\n\
+             UID:1@discord.com\r\n\
+             DTSTAMP:20150101T000000Z\r\n\
+             DTSTART:20231114T221320Z\r\n\
+             DTEND:20240229T235959Z\r\n\
+             SUMMARY:Movie night\\; bring snacks\\, please\r\n\
+             DESCRIPTION:Line one\\nLine two\r\n\
+             LOCATION:Somewhere\r\n\
+             URL:https://discord.com/events/2/1\r\n\
+             STATUS:CANCELLED\r\n\
+             END:VEVENT\r\n"
+        );
+
+        event.status = ScheduledEventStatus::Completed;
+        assert!(event.to_ical_event().contains("\r\nSTATUS:CONFIRMED\r\n"));
+
+        event.start_time = Timestamp::parse("1969-12-31T23:59:59.250-01:00").unwrap();
+        assert!(event.to_ical_event().contains("\r\nDTSTART:19700101T005959Z\r\n"));
+
+        event.name = "a".repeat(80);
+        let ical = event.to_ical_event();
+        assert!(ical.split("\r\n").all(|line| line.len() <= 75));
+        let summary = ical.split("\r\n").skip_while(|l| !l.starts_with("SUMMARY")).take(2);
+        let summary: Vec<_> = summary.collect();
+        assert_eq!(summary[0].len(), 75);
+        assert_eq!(summary[1], format!(" {}", "a".repeat(13)));
+    }
+}
@@ -0,0 +1,284 @@
+#[cfg(feature = "model")]
+use crate::builder::EditGuildTemplate;
+#[cfg(feature = "model")]
+use crate::http::{CacheHttp, Http};
+#[cfg(feature = "model")]
+use crate::internal::prelude::*;
+use crate::model::prelude::*;
+
+/// A template from which new guilds can be created, copying the channels, roles and settings of
+/// the guild it was created from.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/guild-template#guild-template-object).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct GuildTemplate {
+    /// The template code, which is its unique Id.
+    pub code: String,
+    /// The name of the template.
+    pub name: String,
+    /// The description of the template, if any.
+    pub description: Option<String>,
+    /// The number of times the template has been used.
+    pub usage_count: u64,
+    /// The Id of the user who created the template.
+    pub creator_id: UserId,
+    /// The user who created the template.
+    pub creator: User,
+    /// When the template was created.
+    pub created_at: Timestamp,
+    /// When the template was last synced to its source guild.
+    pub updated_at: Timestamp,
+    /// The Id of the guild the template was created from.
+    pub source_guild_id: GuildId,
+    /// A snapshot of the source guild at the time of the last sync.
+    pub serialized_source_guild: TemplateGuild,
+    /// Whether the source guild has changed since the last sync.
+    pub is_dirty: Option<bool>,
+}
+
+#[cfg(feature = "model")]
+impl GuildTemplate {
+    /// Syncs the template to the current state of its source guild.
+    ///
+    /// Refer to [`GuildId::sync_template`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    pub async fn sync(&mut self, http: impl AsRef<Http>) -> Result<()> {
+        *self = self.source_guild_id.sync_template(http, &self.code).await?;
+        Ok(())
+    }
+
+    /// Edits the template.
+    ///
+    /// Refer to [`GuildId::edit_template`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission or if invalid data is given.
+    pub async fn edit(
+        &mut self,
+        cache_http: impl CacheHttp,
+        builder: EditGuildTemplate,
+    ) -> Result<()> {
+        *self = self.source_guild_id.edit_template(cache_http, &self.code, builder).await?;
+        Ok(())
+    }
+
+    /// Deletes the template.
+    ///
+    /// Refer to [`GuildId::delete_template`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    pub async fn delete(&self, http: impl AsRef<Http>) -> Result<GuildTemplate> {
+        self.source_guild_id.delete_template(http, &self.code).await
+    }
+}
+
+/// The snapshot of a guild stored in a [`GuildTemplate`].
+///
+/// Ids of roles and channels in the snapshot are placeholders, which are only used to refer to
+/// each other within the template, rather than actual Discord Ids.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/guild-template#guild-template-object-example-guild-template-object).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct TemplateGuild {
+    /// The name of the guild.
+    pub name: String,
+    /// The description of the guild, if any.
+    pub description: Option<String>,
+    /// The hash of the guild's icon, if any.
+    pub icon_hash: Option<ImageHash>,
+    /// The verification level required for members.
+    pub verification_level: VerificationLevel,
+    /// The default notification level for messages.
+    pub default_message_notifications: DefaultMessageNotificationLevel,
+    /// The level of explicit content filtering.
+    pub explicit_content_filter: ExplicitContentFilter,
+    /// The preferred locale of the guild.
+    pub preferred_locale: String,
+    /// The placeholder Id of the AFK channel, if any.
+    pub afk_channel_id: Option<u64>,
+    /// The time after which voice users are moved to the AFK channel.
+    pub afk_timeout: AfkTimeout,
+    /// The placeholder Id of the channel system messages are sent to, if any.
+    pub system_channel_id: Option<u64>,
+    /// The system messages which are suppressed.
+    pub system_channel_flags: SystemChannelFlags,
+    /// The roles of the guild, including `@everyone` with the placeholder Id `0`.
+    pub roles: Vec<TemplateRole>,
+    /// The channels of the guild, including categories.
+    pub channels: Vec<TemplateChannel>,
+}
+
+/// A role in a [`TemplateGuild`].
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/guild-template#guild-template-object-example-guild-template-object).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct TemplateRole {
+    /// The placeholder Id of the role.
+    pub id: u64,
+    /// The name of the role.
+    pub name: String,
+    /// The permissions of the role.
+    pub permissions: Permissions,
+    /// The colour of the role.
+    #[serde(rename = "color")]
+    pub colour: Colour,
+    /// Whether the role is shown separately in the member list.
+    pub hoist: bool,
+    /// Whether the role can be mentioned by everyone.
+    pub mentionable: bool,
+}
+
+/// A channel in a [`TemplateGuild`].
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/guild-template#guild-template-object-example-guild-template-object).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct TemplateChannel {
+    /// The placeholder Id of the channel.
+    pub id: u64,
+    /// The type of the channel.
+    #[serde(rename = "type")]
+    pub kind: ChannelType,
+    /// The name of the channel.
+    pub name: String,
+    /// The sorting position of the channel.
+    pub position: u16,
+    /// The topic of the channel, if any.
+    pub topic: Option<String>,
+    /// The bitrate of a voice channel.
+    pub bitrate: Option<u32>,
+    /// The maximum number of users in a voice channel, `0` meaning unlimited.
+    pub user_limit: Option<u32>,
+    /// Whether the channel is age-restricted.
+    #[serde(default)]
+    pub nsfw: bool,
+    /// The slowmode delay of the channel in seconds.
+    pub rate_limit_per_user: Option<u16>,
+    /// The placeholder Id of the category the channel is in, if any.
+    pub parent_id: Option<u64>,
+    /// The permission overwrites of the channel.
+    #[serde(default)]
+    pub permission_overwrites: Vec<TemplatePermissionOverwrite>,
+}
+
+/// A permission overwrite in a [`TemplateChannel`].
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/guild-template#guild-template-object-example-guild-template-object).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct TemplatePermissionOverwrite {
+    /// The placeholder Id of the role the overwrite applies to.
+    pub id: u64,
+    /// The type of the overwrite target, always `0` for roles.
+    #[serde(rename = "type")]
+    pub kind: u8,
+    /// The permissions explicitly allowed.
+    pub allow: Permissions,
+    /// The permissions explicitly denied.
+    pub deny: Permissions,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json::{self, json};
+
+    #[test]
+    fn deserialize_template() {
+        // Split up to stay within the recursion limit of the json! macro.
+        let channels = json!([
+            {
+                "name": "Text Channels",
+                "position": 1,
+                "topic": null,
+                "bitrate": 64000,
+                "user_limit": 0,
+                "nsfw": false,
+                "rate_limit_per_user": 0,
+                "parent_id": null,
+                "permission_overwrites": [],
+                "id": 1,
+                "type": 4,
+            },
+            {
+                "name": "general",
+                "position": 1,
+                "topic": null,
+                "bitrate": 64000,
+                "user_limit": 0,
+                "nsfw": false,
+                "rate_limit_per_user": 0,
+                "parent_id": 1,
+                "permission_overwrites": [
+                    {"id": 0, "type": 0, "allow": "0", "deny": "2048"},
+                ],
+                "id": 2,
+                "type": 0,
+            },
+        ]);
+        let template: GuildTemplate = json::from_value(json!({
+            "code": "hgM48av5Q69A",
+            "name": "Friends & Family",
+            "description": null,
+            "usage_count": 49605,
+            "creator_id": "132837293881950208",
+            "creator": {
+                "id": "132837293881950208",
+                "username": "hoges",
+                "avatar": "79b0d7c7a48e6d5c9bf0ab4a0dd83f5f",
+                "discriminator": "0",
+                "public_flags": 0,
+            },
+            "created_at": "2020-04-02T21:10:38+00:00",
+            "updated_at": "2020-05-01T17:57:38+00:00",
+            "source_guild_id": "678070694164299796",
+            "serialized_source_guild": {
+                "name": "Friends & Family",
+                "description": null,
+                "region": "us-west",
+                "verification_level": 0,
+                "default_message_notifications": 0,
+                "explicit_content_filter": 0,
+                "preferred_locale": "en-US",
+                "afk_timeout": 300,
+                "roles": [{
+                    "id": 0,
+                    "name": "@everyone",
+                    "permissions": 104324689,
+                    "color": 0,
+                    "hoist": false,
+                    "mentionable": false,
+                }],
+                "channels": channels,
+                "afk_channel_id": null,
+                "system_channel_id": 2,
+                "system_channel_flags": 0,
+                "icon_hash": null,
+            },
+            "is_dirty": null,
+        }))
+        .unwrap();
+
+        let guild = &template.serialized_source_guild;
+        assert_eq!(template.usage_count, 49605);
+        assert_eq!(guild.system_channel_id, Some(2));
+        assert_eq!(guild.roles[0].permissions.bits(), 104324689);
+        assert_eq!(guild.channels[0].kind, ChannelType::Category);
+        assert_eq!(guild.channels[1].parent_id, Some(1));
+        assert_eq!(guild.channels[1].permission_overwrites[0].deny, Permissions::SEND_MESSAGES);
+    }
+}
@@ -216,6 +216,11 @@ pub struct StickerPackId(#[serde(with = "snowflake")] NonZeroU64);
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
 pub struct StickerPackBannerId(#[serde(with = "snowflake")] NonZeroU64);
 
+/// An identifier for a soundboard sound.
+#[repr(packed)]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
+pub struct SoundId(#[serde(with = "snowflake")] NonZeroU64);
+
 /// An identifier for a SKU.
 #[repr(packed)]
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
@@ -258,6 +263,16 @@ pub struct StageInstanceId(#[serde(with = "snowflake")] NonZeroU64);
 #[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord, Deserialize, Serialize)]
 pub struct ForumTagId(#[serde(with = "snowflake")] NonZeroU64);
 
+/// An identifier for an onboarding prompt.
+#[repr(packed)]
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord, Deserialize, Serialize)]
+pub struct OnboardingPromptId(#[serde(with = "snowflake")] NonZeroU64);
+
+/// An identifier for an option of an onboarding prompt.
+#[repr(packed)]
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord, Deserialize, Serialize)]
+pub struct PromptOptionId(#[serde(with = "snowflake")] NonZeroU64);
+
 /// An identifier for an entitlement.
 #[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord, Deserialize, Serialize)]
 pub struct EntitlementId(#[serde(with = "snowflake")] pub NonZeroU64);
@@ -276,6 +291,7 @@ id_u64! {
     StickerId;
     StickerPackId;
     StickerPackBannerId;
+    SoundId;
     SkuId;
     UserId;
     WebhookId;
@@ -289,6 +305,8 @@ id_u64! {
     RuleId;
     ForumTagId;
     EntitlementId;
+    OnboardingPromptId;
+    PromptOptionId;
 }
 
 /// An identifier for a Shard.
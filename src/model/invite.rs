@@ -2,7 +2,7 @@
 
 use super::prelude::*;
 #[cfg(feature = "model")]
-use crate::builder::CreateInvite;
+use crate::builder::{Builder, CreateInvite, GetInvite};
 #[cfg(all(feature = "cache", feature = "model"))]
 use crate::cache::Cache;
 #[cfg(feature = "model")]
@@ -144,6 +144,17 @@ impl Invite {
         http.as_ref().get_invite(invite, member_counts, expiration, event_id).await
     }
 
+    /// Gets information about an invite, such as a preview of its guild. See [`GetInvite`] for
+    /// the available options.
+    ///
+    /// # Errors
+    ///
+    /// May return an [`Error::Http`] if the invite is invalid. Can also return an [`Error::Json`]
+    /// if there is an error deserializing the API response.
+    pub async fn get_with(cache_http: impl CacheHttp, builder: GetInvite<'_>) -> Result<Invite> {
+        builder.execute(cache_http, ()).await
+    }
+
     /// Returns a URL to use for the invite.
     ///
     /// # Examples
@@ -115,6 +115,42 @@ impl fmt::Display for Mention {
     }
 }
 
+/// A mention of an application command, which is displayed as a link that fills in the command.
+///
+/// The name includes the names of the subcommand group and subcommand, if any, separated by
+/// spaces. A [`CommandMention`] is created using [`Command::mention`],
+/// [`Command::mention_subcommand`] or [`CommandId::mention_named`].
+///
+/// # Examples
+///
+/// ```
+/// # use serenity::model::id::CommandId;
+/// let mention = CommandId::new(1).mention_named("config set");
+/// assert_eq!(mention.to_string(), "</config set:1>");
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct CommandMention {
+    /// The Id of the top-level command.
+    pub id: CommandId,
+    /// The name of the command, followed by the subcommand group and subcommand names, if any.
+    pub name: String,
+}
+
+impl fmt::Display for CommandMention {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "</{}:{}>", self.name, self.id)
+    }
+}
+
+#[cfg(all(feature = "model", feature = "utils"))]
+impl FromStr for CommandMention {
+    type Err = MentionParseError;
+
+    fn from_str(s: &str) -> StdResult<Self, Self::Err> {
+        utils::parse_command_mention(s).ok_or(MentionParseError::InvalidMention)
+    }
+}
+
 #[cfg(all(feature = "model", feature = "utils"))]
 #[derive(Debug)]
 pub enum MentionParseError {
@@ -214,4 +250,30 @@ mod test {
         assert_eq!(user.mention().to_string(), "<@6>");
         assert_eq!(user.id.mention().to_string(), "<@6>");
     }
+
+    #[test]
+    fn test_command_mention() {
+        let command: Command = crate::json::from_value(crate::json::json!({
+            "id": "5",
+            "application_id": "1",
+            "type": 1,
+            "name": "config",
+            "description": "Configures the bot",
+            "version": "1",
+        }))
+        .unwrap();
+
+        let base = command.mention();
+        let subcommand = command.mention_subcommand(&["set"]);
+        let group = command.mention_subcommand(&["channel", "set"]);
+        assert_eq!(base.to_string(), "</config:5>");
+        assert_eq!(subcommand.to_string(), "</config set:5>");
+        assert_eq!(group.to_string(), "</config channel set:5>");
+        assert_eq!(CommandId::new(5).mention_named("config set"), subcommand);
+
+        #[cfg(feature = "model")]
+        for mention in [base, subcommand, group] {
+            assert_eq!(mention.to_string().parse::<CommandMention>().unwrap(), mention);
+        }
+    }
 }
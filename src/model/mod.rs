@@ -34,6 +34,7 @@ pub mod mention;
 pub mod misc;
 pub mod monetization;
 pub mod permissions;
+pub mod soundboard;
 pub mod sticker;
 pub mod timestamp;
 pub mod user;
@@ -44,7 +45,7 @@ pub mod webhook;
 pub use serenity_voice_model as voice_gateway;
 
 pub use self::colour::{Color, Colour};
-pub use self::error::Error as ModelError;
+pub use self::error::{Error as ModelError, ImageError, ImageFormat};
 pub use self::permissions::Permissions;
 pub use self::timestamp::Timestamp;
 
@@ -93,6 +94,7 @@ pub mod prelude {
         misc::*,
         monetization::*,
         permissions::*,
+        soundboard::*,
         sticker::*,
         user::*,
         voice::*,
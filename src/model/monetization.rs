@@ -1,7 +1,7 @@
 #[cfg(feature = "model")]
 use crate::builder::{Builder as _, GetEntitlements};
 #[cfg(feature = "model")]
-use crate::http::CacheHttp;
+use crate::http::{CacheHttp, Http};
 use crate::model::prelude::*;
 
 /// A premium offering that can be made available to an application's users and guilds.
@@ -46,6 +46,10 @@ enum_number! {
     #[serde(from = "u8", into = "u8")]
     #[non_exhaustive]
     pub enum SkuKind {
+        /// A durable one-time purchase.
+        Durable = 2,
+        /// A consumable one-time purchase.
+        Consumable = 3,
         /// Represents a recurring subscription.
         Subscription = 5,
         /// A system-generated group for each SKU created of type [`SkuKind::Subscription`].
@@ -98,6 +102,8 @@ pub struct Entitlement {
     pub ends_at: Option<Timestamp>,
     /// The ID of the guild that is granted access to the SKU.
     pub guild_id: Option<GuildId>,
+    /// For consumable items, whether the entitlement has been consumed.
+    pub consumed: Option<bool>,
 }
 
 impl Entitlement {
@@ -122,6 +128,17 @@ impl Entitlement {
     ) -> Result<Vec<Entitlement>> {
         builder.execute(cache_http, ()).await
     }
+
+    /// Marks this entitlement as consumed. Only applies to one-time purchases of
+    /// [`SkuKind::Consumable`] SKUs.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the entitlement is not consumable or was already consumed.
+    #[cfg(feature = "model")]
+    pub async fn consume(&self, http: impl AsRef<Http>) -> Result<()> {
+        http.as_ref().consume_entitlement(self.id).await
+    }
 }
 
 enum_number! {
@@ -133,6 +150,20 @@ enum_number! {
     #[serde(from = "u8", into = "u8")]
     #[non_exhaustive]
     pub enum EntitlementKind {
+        /// Entitlement was purchased by a user.
+        Purchase = 1,
+        /// Entitlement for a Discord Nitro subscription.
+        PremiumSubscription = 2,
+        /// Entitlement was gifted by a developer.
+        DeveloperGift = 3,
+        /// Entitlement was purchased by a developer in application test mode.
+        TestModePurchase = 4,
+        /// Entitlement was granted when the SKU was free.
+        FreePurchase = 5,
+        /// Entitlement was gifted by another user.
+        UserGift = 6,
+        /// Entitlement was claimed by a user for free as a Nitro subscriber.
+        PremiumPurchase = 7,
         /// Entitlement was purchased as an app subscription.
         ApplicationSubscription = 8,
         _ => Unknown(u8),
@@ -143,3 +174,28 @@ pub enum EntitlementOwner {
     Guild(GuildId),
     User(UserId),
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json::{from_value, json};
+
+    #[test]
+    fn consumable_entitlement() {
+        let entitlement: Entitlement = from_value(json!({
+            "id": "1019653849998299136",
+            "sku_id": "1019475255913222144",
+            "application_id": "1019370614521200640",
+            "user_id": "771129655544643584",
+            "type": 1,
+            "deleted": false,
+            "consumed": false,
+            "guild_id": null,
+        }))
+        .unwrap();
+
+        assert!(matches!(entitlement.kind, EntitlementKind::Purchase));
+        assert_eq!(entitlement.consumed, Some(false));
+        assert!(entitlement.starts_at.is_none());
+    }
+}
@@ -0,0 +1,67 @@
+//! Models for soundboard sounds, which can be played in voice channels.
+
+use crate::model::prelude::*;
+
+/// A sound which can be played in voice channels with the soundboard.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/soundboard#soundboard-sound-object).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct SoundboardSound {
+    /// The unique ID given to this sound.
+    pub sound_id: SoundId,
+    /// The name of the sound.
+    pub name: String,
+    /// The volume of the sound, from 0 to 1.
+    pub volume: f64,
+    /// The Id of the custom emoji shown with the sound, if any.
+    pub emoji_id: Option<EmojiId>,
+    /// The unicode character of the standard emoji shown with the sound, if any.
+    pub emoji_name: Option<String>,
+    /// Id of the guild that owns this sound, or [`None`] for the default sounds.
+    pub guild_id: Option<GuildId>,
+    /// Whether the sound can be used, may be false due to loss of Server Boosts.
+    #[serde(default)]
+    pub available: bool,
+    /// User that uploaded the sound. This will be `None` if the current user does not have
+    /// either the [Create Guild Expressions] nor the [Manage Guild Expressions] permission.
+    ///
+    /// [Create Guild Expressions]: Permissions::CREATE_GUILD_EXPRESSIONS
+    /// [Manage Guild Expressions]: Permissions::MANAGE_GUILD_EXPRESSIONS
+    pub user: Option<User>,
+}
+
+#[cfg(feature = "model")]
+impl SoundboardSound {
+    /// Retrieves the URL to the sound file.
+    #[must_use]
+    pub fn url(&self) -> String {
+        cdn!("/soundboard-sounds/{}", self.sound_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json::{from_value, json};
+
+    #[test]
+    fn default_sound() {
+        let sound: SoundboardSound = from_value(json!({
+            "name": "quack",
+            "sound_id": "1",
+            "volume": 1.0,
+            "emoji_id": null,
+            "emoji_name": "🦆",
+            "available": true,
+        }))
+        .unwrap();
+
+        assert_eq!(sound.sound_id, SoundId::new(1));
+        assert!(sound.guild_id.is_none());
+        assert_eq!(sound.emoji_name.as_deref(), Some("🦆"));
+        #[cfg(feature = "model")]
+        assert_eq!(sound.url(), "https://cdn.discordapp.com/soundboard-sounds/1");
+    }
+}
@@ -14,7 +14,7 @@ use crate::builder::{Builder, CreateMessage, EditProfile};
 #[cfg(all(feature = "cache", feature = "model"))]
 use crate::cache::{Cache, UserRef};
 #[cfg(feature = "collector")]
-use crate::collector::{MessageCollector, ReactionCollector};
+use crate::collector::{MessageCollector, ReactionCollector, VoiceStateCollector};
 #[cfg(feature = "collector")]
 use crate::gateway::ShardMessenger;
 #[cfg(feature = "model")]
@@ -767,6 +767,43 @@ impl UserId {
 
         Ok(user)
     }
+
+    /// Returns a builder which can be awaited to obtain the voice state of this user once they
+    /// connect to a voice channel in the given guild.
+    ///
+    /// Voice state updates of the user in a voice channel, such as muting or moving to another
+    /// channel, are collected as well. Use [`Cache::voice_channel_of`] beforehand to check whether
+    /// the user is already connected.
+    ///
+    /// Requires the [`GUILD_VOICE_STATES`] intent.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::gateway::ShardMessenger;
+    /// # use serenity::model::id::{GuildId, UserId};
+    /// # async fn example_(shard: &ShardMessenger, user_id: UserId, guild_id: GuildId) {
+    /// use std::time::Duration;
+    ///
+    /// let joined = user_id.await_voice_join(shard, guild_id).timeout(Duration::from_secs(60));
+    /// if let Some(state) = joined.await {
+    ///     println!("{user_id} joined {:?}", state.channel_id);
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// [`GUILD_VOICE_STATES`]: GatewayIntents::GUILD_VOICE_STATES
+    #[cfg(feature = "collector")]
+    pub fn await_voice_join(
+        self,
+        shard_messenger: impl AsRef<ShardMessenger>,
+        guild_id: impl Into<GuildId>,
+    ) -> VoiceStateCollector {
+        VoiceStateCollector::new(shard_messenger)
+            .user_id(self)
+            .guild_id(guild_id.into())
+            .filter(|state| state.channel_id.is_some())
+    }
 }
 
 impl From<Member> for UserId {
@@ -1,9 +1,11 @@
 use std::fmt::{self as fmt, Write};
 use std::ops::Add;
 
+use super::{FormattedTimestamp, FormattedTimestampStyle};
 use crate::model::guild::Emoji;
-use crate::model::id::{ChannelId, RoleId, UserId};
+use crate::model::id::{ChannelId, CommandId, RoleId, UserId};
 use crate::model::mention::Mentionable;
+use crate::model::Timestamp;
 
 /// The Message Builder is an ergonomic utility to easily build a message, by adding text and
 /// mentioning mentionable structs.
@@ -153,6 +155,32 @@ impl MessageBuilder {
         self
     }
 
+    /// Displays the given emoji in the built message, removing characters from its name that are
+    /// not allowed in emoji names and would break the formatting.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use serenity::json::{json, from_value};
+    /// # use serenity::model::guild::Emoji;
+    /// # use serenity::model::id::EmojiId;
+    /// # use serenity::utils::MessageBuilder;
+    ///
+    /// # let emoji = from_value::<Emoji>(json!({
+    /// #     "id": EmojiId::new(302516740095606785),
+    /// #     "name": "smug>Face",
+    /// # })).unwrap();
+    ///
+    /// let message = MessageBuilder::new().emoji_safe(&emoji).build();
+    ///
+    /// assert_eq!(message, "<:smugFace:302516740095606785>");
+    /// ```
+    pub fn emoji_safe(&mut self, emoji: &Emoji) -> &mut Self {
+        self.0.push_str(if emoji.animated { "<a:" } else { "<:" });
+        self.0.extend(emoji.name.chars().filter(|c| c.is_alphanumeric() || *c == '_'));
+        self.push_(&format_args!(":{}>", emoji.id))
+    }
+
     /// Mentions something that implements the [`Mentionable`] trait.
     pub fn mention<M: Mentionable>(&mut self, item: &M) -> &mut Self {
         self.push_(&item.mention());
@@ -353,6 +381,54 @@ impl MessageBuilder {
         self
     }
 
+    /// Pushes a clickable mention of a slash command to the content.
+    ///
+    /// The `name` is the name of the command, which may be followed by the names of a subcommand
+    /// group and subcommand, separated by spaces.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::model::id::CommandId;
+    /// use serenity::utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new()
+    ///     .push("Use ")
+    ///     .push_command_mention("tag get", CommandId::new(1))
+    ///     .build();
+    ///
+    /// assert_eq!(content, "Use </tag get:1>");
+    /// ```
+    pub fn push_command_mention(&mut self, name: &str, id: impl Into<CommandId>) -> &mut Self {
+        self.push_(&format_args!("</{name}:{}>", id.into()))
+    }
+
+    /// Pushes a timestamp to the content, which is displayed in the reader's timezone and locale.
+    ///
+    /// Refer to [`FormattedTimestamp`] for more information on how this is formatted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::model::Timestamp;
+    /// use serenity::utils::{FormattedTimestampStyle, MessageBuilder};
+    ///
+    /// let timestamp = Timestamp::from_unix_timestamp(1700000000).unwrap();
+    /// let content = MessageBuilder::new()
+    ///     .push("Ends ")
+    ///     .push_timestamp(timestamp, FormattedTimestampStyle::RelativeTime)
+    ///     .build();
+    ///
+    /// assert_eq!(content, "Ends <t:1700000000:R>");
+    /// ```
+    pub fn push_timestamp(
+        &mut self,
+        timestamp: Timestamp,
+        style: FormattedTimestampStyle,
+    ) -> &mut Self {
+        self.push_(&FormattedTimestamp::new(timestamp, Some(style)))
+    }
+
     /// Pushes the given text with a newline appended to the content.
     ///
     /// # Examples
@@ -640,6 +716,22 @@ impl MessageBuilder {
         self
     }
 
+    /// Pushes a clickable mention of a slash command to the content, removing characters from the
+    /// name that are not allowed in command names and would break the formatting.
+    ///
+    /// See [`Self::push_command_mention`] for details.
+    pub fn push_command_mention_safe(
+        &mut self,
+        name: &str,
+        id: impl Into<CommandId>,
+    ) -> &mut Self {
+        let name: String = name
+            .chars()
+            .filter(|c| c.is_alphanumeric() || matches!(c, '-' | '_' | ' '))
+            .collect();
+        self.push_command_mention(&name, id)
+    }
+
     /// Pushes text with a newline appended to the content normalizing content.
     ///
     /// # Examples
@@ -1164,6 +1256,7 @@ mod test {
     use super::ContentModifier::{Bold, Code, Italic, Spoiler};
     use super::MessageBuilder;
     use crate::model::prelude::*;
+    use crate::utils::FormattedTimestampStyle;
 
     macro_rules! gen {
         ($($fn:ident => [$($text:expr => $expected:expr),+]),+) => ({
@@ -1217,6 +1310,50 @@ mod test {
         assert_eq!(content_emoji, "<:Rohrkatze:32>");
     }
 
+    #[test]
+    fn command_mentions() {
+        let content = MessageBuilder::new()
+            .push_command_mention("ping", CommandId::new(1))
+            .push_command_mention("tag get", CommandId::new(2))
+            .build();
+        assert_eq!(content, "</ping:1></tag get:2>");
+
+        let content = MessageBuilder::new()
+            .push_command_mention_safe("b*a:d>_c-md", CommandId::new(3))
+            .build();
+        assert_eq!(content, "</bad_c-md:3>");
+    }
+
+    #[test]
+    fn timestamps() {
+        let timestamp = Timestamp::from_unix_timestamp(1700000000).unwrap();
+        let content = MessageBuilder::new()
+            .push_timestamp(timestamp, FormattedTimestampStyle::ShortDate)
+            .push(" ")
+            .push_timestamp(timestamp, FormattedTimestampStyle::LongDateTime)
+            .build();
+        assert_eq!(content, "<t:1700000000:d> <t:1700000000:F>");
+    }
+
+    #[test]
+    fn safe_emojis() {
+        let mut emoji = Emoji {
+            animated: true,
+            available: true,
+            id: EmojiId::new(32),
+            name: "Rohr:katze>".to_string(),
+            managed: false,
+            require_colons: true,
+            roles: vec![],
+            user: None,
+        };
+        assert_eq!(MessageBuilder::new().emoji_safe(&emoji).build(), "<a:Rohrkatze:32>");
+
+        emoji.animated = false;
+        emoji.name = "Rohr_katze".to_string();
+        assert_eq!(MessageBuilder::new().emoji_safe(&emoji).build(), "<:Rohr_katze:32>");
+    }
+
     #[test]
     fn content() {
         let content = Bold + Italic + Code + "Fun!";
@@ -0,0 +1,136 @@
+use super::{
+    parse_channel_mention,
+    parse_command_mention,
+    parse_emoji,
+    parse_role_mention,
+    parse_user_mention,
+    FormattedTimestamp,
+};
+use crate::constants::MESSAGE_CODE_LIMIT;
+
+/// Returns the length of message content as counted by Discord against the
+/// [`MESSAGE_CODE_LIMIT`], which is the number of Unicode scalar values.
+///
+/// Mentions and custom emojis count with their full raw length, e.g. `<:ferris:1234>` counts as
+/// 14 rather than 1, even though it is rendered as a single emoji.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity::utils::message_len;
+///
+/// assert_eq!(message_len("héllo"), 5);
+/// assert_eq!(message_len("<@1234>"), 7);
+/// ```
+///
+/// [`MESSAGE_CODE_LIMIT`]: crate::constants::MESSAGE_CODE_LIMIT
+#[must_use]
+pub fn message_len(content: &str) -> usize {
+    content.chars().count()
+}
+
+/// Whether the content fits within the [`MESSAGE_CODE_LIMIT`] of a single message.
+///
+/// [`MESSAGE_CODE_LIMIT`]: crate::constants::MESSAGE_CODE_LIMIT
+#[must_use]
+pub fn fits_in_message(content: &str) -> bool {
+    message_len(content) <= MESSAGE_CODE_LIMIT
+}
+
+/// Truncates the content to at most `limit` characters, as counted by [`message_len`].
+///
+/// The content is never cut inside of a character, mention, custom emoji or formatted timestamp;
+/// such a token is left out entirely if it doesn't fit.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity::utils::truncate_message;
+///
+/// assert_eq!(truncate_message("hello <@1234>", 10), "hello ");
+/// assert_eq!(truncate_message("hello", 10), "hello");
+/// ```
+#[must_use]
+pub fn truncate_message(content: &str, limit: usize) -> &str {
+    let Some((cut, _)) = content.char_indices().nth(limit) else {
+        return content;
+    };
+
+    // A token starts at the last `<` before the cut, unless it was already closed.
+    let Some(start) = content[..cut].rfind('<') else { return &content[..cut] };
+    if content[start..cut].contains('>') {
+        return &content[..cut];
+    }
+
+    match content[cut..].find('>') {
+        Some(end) if is_token(&content[start..=cut + end]) => &content[..start],
+        _ => &content[..cut],
+    }
+}
+
+fn is_token(s: &str) -> bool {
+    parse_user_mention(s).is_some()
+        || parse_role_mention(s).is_some()
+        || parse_channel_mention(s).is_some()
+        || parse_command_mention(s).is_some()
+        || parse_emoji(s).is_some()
+        || s.parse::<FormattedTimestamp>().is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fits() {
+        assert!(fits_in_message(&"ä".repeat(2000)));
+        assert!(!fits_in_message(&"a".repeat(2001)));
+    }
+
+    #[test]
+    fn truncate() {
+        assert_eq!(truncate_message("", 0), "");
+        assert_eq!(truncate_message("abc", 0), "");
+        assert_eq!(truncate_message("äöü", 2), "äö");
+        assert_eq!(truncate_message("a <:ferris:1234> b", 5), "a ");
+        assert_eq!(truncate_message("a <:ferris:1234> b", 16), "a <:ferris:1234>");
+        assert_eq!(truncate_message("a <a:ferris:1234>", 16), "a ");
+        assert_eq!(truncate_message("</cmd sub:1234>", 5), "");
+        assert_eq!(truncate_message("<t:1700000000:R>", 5), "");
+        assert_eq!(truncate_message("<@!1> <#2>", 8), "<@!1> ");
+        // Angle brackets which aren't part of a token can be cut.
+        assert_eq!(truncate_message("a <b c> d", 4), "a <b");
+        assert_eq!(truncate_message("a < b > c", 5), "a < b");
+    }
+
+    #[test]
+    fn truncate_generated() {
+        const PARTS: &[&str] =
+            &["a", "ß", "🦀", " ", "<@1234>", "<@&56>", "<#789>", "<:ferris:1234>", "<", ">"];
+        const TOKENS: &[&str] = &["<@1234>", "<@&56>", "<#789>", "<:ferris:1234>"];
+
+        // A simple linear congruential generator keeps the test deterministic.
+        let mut state = 42_u64;
+        let mut next = |n: usize| {
+            state = state.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1);
+            (state >> 33) as usize % n
+        };
+
+        for _ in 0..500 {
+            let content: String = (0..next(20)).map(|_| PARTS[next(PARTS.len())]).collect();
+            for limit in 0..=message_len(&content) + 1 {
+                let truncated = truncate_message(&content, limit);
+                assert!(content.starts_with(truncated));
+                assert!(message_len(truncated) <= limit);
+
+                // Every token is either kept entirely or removed entirely.
+                for token in TOKENS {
+                    for (i, _) in content.match_indices(token) {
+                        let end = i + token.len();
+                        assert!(end <= truncated.len() || i >= truncated.len(), "{content:?}");
+                    }
+                }
+            }
+        }
+    }
+}
@@ -8,6 +8,7 @@ mod content_safe;
 mod custom_message;
 mod formatted_timestamp;
 mod message_builder;
+mod message_len;
 #[cfg(feature = "collector")]
 mod quick_modal;
 
@@ -26,6 +27,7 @@ use url::Url;
 
 pub use self::custom_message::CustomMessage;
 pub use self::message_builder::{Content, ContentModifier, EmbedMessageBuilding, MessageBuilder};
+pub use self::message_len::{fits_in_message, message_len, truncate_message};
 #[doc(inline)]
 pub use self::token::validate as validate_token;
 #[cfg(all(feature = "cache", feature = "model"))]
@@ -61,20 +63,37 @@ use crate::model::prelude::*;
 /// assert_eq!(utils::parse_invite(url), "0cDvIgU2voY8RSYL");
 /// ```
 ///
+/// The `www.`, `ptb.` and `canary.` subdomains and the legacy `discordapp.com` domain are
+/// supported as well. Trailing slashes, query strings such as `?event=...`, and fragments are
+/// removed from URLs.
+///
 /// [`RichInvite`]: crate::model::invite::RichInvite
 #[must_use]
 pub fn parse_invite(code: &str) -> &str {
-    let code = code.trim_start_matches("http://").trim_start_matches("https://");
-    let lower = code.to_lowercase();
-    if lower.starts_with("discord.gg/") {
-        &code[11..]
-    } else if lower.starts_with("discord.com/invite/") {
-        &code[19..]
-    } else {
-        code
+    let code = code.trim();
+    let code = strip_prefix_ignore_case(code, "http://")
+        .or_else(|| strip_prefix_ignore_case(code, "https://"))
+        .unwrap_or(code);
+
+    let host = ["www.", "ptb.", "canary."]
+        .into_iter()
+        .find_map(|subdomain| strip_prefix_ignore_case(code, subdomain))
+        .unwrap_or(code);
+    let path = ["discord.gg/", "discord.com/invite/", "discordapp.com/invite/"]
+        .into_iter()
+        .find_map(|prefix| strip_prefix_ignore_case(host, prefix));
+
+    match path {
+        Some(path) => path.split(['/', '?', '#']).next().unwrap_or(path),
+        None => code,
     }
 }
 
+fn strip_prefix_ignore_case<'a>(s: &'a str, prefix: &str) -> Option<&'a str> {
+    let head = s.get(..prefix.len())?;
+    head.eq_ignore_ascii_case(prefix).then(|| &s[prefix.len()..])
+}
+
 /// Retrieves the username and discriminator out of a user tag (`name#discrim`).
 /// In order to accomodate next gen Discord usernames, this will also accept `name` style tags.
 ///
@@ -235,6 +254,38 @@ pub fn parse_channel_mention(mention: &str) -> Option<ChannelId> {
     }
 }
 
+/// Retrieves the command Id and name from an application command mention.
+///
+/// If the mention is invalid, then [`None`] is returned.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity::model::id::CommandId;
+/// use serenity::utils::parse_command_mention;
+///
+/// let mention = parse_command_mention("</config set:1234>").unwrap();
+/// assert_eq!(mention.id, CommandId::new(1234));
+/// assert_eq!(mention.name, "config set");
+///
+/// assert!(parse_command_mention("</config:>").is_none());
+/// assert!(parse_command_mention("</config  set:1234>").is_none());
+/// ```
+#[must_use]
+pub fn parse_command_mention(mention: &str) -> Option<CommandMention> {
+    let inner = mention.strip_prefix("</")?.strip_suffix('>')?;
+    let (name, id) = inner.rsplit_once(':')?;
+
+    if name.split(' ').count() > 3 || name.split(' ').any(str::is_empty) {
+        return None;
+    }
+
+    Some(CommandMention {
+        id: id.parse().ok()?,
+        name: name.to_string(),
+    })
+}
+
 #[deprecated = "use `utils::parse_channel_mention` instead"]
 pub fn parse_channel(mention: impl AsRef<str>) -> Option<ChannelId> {
     parse_channel_mention(mention.as_ref())
@@ -420,13 +471,15 @@ pub fn parse_webhook(url: &Url) -> Option<(WebhookId, &str)> {
     Some((webhook_id.parse().ok()?, token))
 }
 
+/// Checks the current user's permissions in a guild using the cache, if prechecks are enabled in
+/// the cache settings and the guild is cached.
 #[cfg(all(feature = "cache", feature = "model"))]
 pub(crate) fn user_has_guild_perms(
     cache_http: impl CacheHttp,
     guild_id: GuildId,
     permissions: Permissions,
 ) -> Result<()> {
-    if let Some(cache) = cache_http.cache() {
+    if let Some(cache) = cache_http.cache().filter(|c| c.settings().precheck_permissions) {
         if let Some(guild) = cache.guild(guild_id) {
             guild.require_perms(cache, permissions)?;
         }
@@ -435,7 +488,8 @@ pub(crate) fn user_has_guild_perms(
 }
 
 /// Tries to find a user's permissions using the cache. Unlike [`user_has_perms`], this function
-/// will return `true` even when the permissions are not in the cache.
+/// will return `true` even when the permissions are not in the cache, or if prechecks are disabled
+/// in the cache settings.
 #[cfg(all(feature = "cache", feature = "model"))]
 #[inline]
 pub(crate) fn user_has_perms_cache(
@@ -443,6 +497,10 @@ pub(crate) fn user_has_perms_cache(
     channel_id: ChannelId,
     required_permissions: Permissions,
 ) -> Result<()> {
+    if !cache.as_ref().settings().precheck_permissions {
+        return Ok(());
+    }
+
     match user_perms(cache, channel_id) {
         Ok(perms) => {
             if perms.contains(required_permissions) {
@@ -514,6 +572,16 @@ mod test {
         assert_eq!(parse_invite("https://discord.com/invite/abc"), "abc");
         assert_eq!(parse_invite("http://discord.com/invite/abc"), "abc");
         assert_eq!(parse_invite("discord.com/invite/abc"), "abc");
+        assert_eq!(parse_invite("HTTPS://Discord.com/Invite/abc"), "abc");
+        assert_eq!(parse_invite("https://www.discord.gg/abc"), "abc");
+        assert_eq!(parse_invite("https://canary.discord.com/invite/abc"), "abc");
+        assert_eq!(parse_invite("https://ptb.discordapp.com/invite/abc/"), "abc");
+        assert_eq!(parse_invite("https://discord.gg/abc?event=123"), "abc");
+        assert_eq!(parse_invite("  discord.gg/abc#top "), "abc");
+        assert_eq!(parse_invite("abc"), "abc");
+        assert_eq!(parse_invite("https://example.com/abc"), "example.com/abc");
+        assert_eq!(parse_invite("https://discord.gg/ä"), "ä");
+        assert_eq!(parse_invite("ä"), "ä");
     }
 
     #[test]
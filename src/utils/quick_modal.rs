@@ -12,7 +12,22 @@ use crate::model::prelude::*;
 #[cfg(feature = "collector")]
 pub struct QuickModalResponse {
     pub interaction: ModalInteraction,
+    /// The submitted values, in the order the fields were added to the [`CreateQuickModal`].
+    ///
+    /// Fields the user left empty are represented by an empty string.
     pub inputs: Vec<String>,
+    /// The submitted values paired with the key of their field, in the order the fields were
+    /// added. See [`CreateQuickModal::field`] for how keys are chosen.
+    pub fields: Vec<(String, String)>,
+}
+
+#[cfg(feature = "collector")]
+impl QuickModalResponse {
+    /// Returns the value submitted for the field with the given key, if there is such a field.
+    #[must_use]
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.fields.iter().find(|(k, _)| k == key).map(|(_, v)| v.as_str())
+    }
 }
 
 /// Convenience builder to create a modal, wait for the user to submit and parse the response.
@@ -26,8 +41,9 @@ pub struct QuickModalResponse {
 ///     .short_field("Last name")
 ///     .paragraph_field("Hobbies and interests");
 /// let response = interaction.quick_modal(ctx, modal).await?;
-/// let inputs = response.unwrap().inputs;
-/// let (first_name, last_name, hobbies) = (&inputs[0], &inputs[1], &inputs[2]);
+/// let response = response.unwrap();
+/// let (first_name, last_name) = (&response.inputs[0], &response.inputs[1]);
+/// let hobbies = response.get("Hobbies and interests");
 /// # Ok(())
 /// # }
 /// ```
@@ -37,6 +53,7 @@ pub struct CreateQuickModal {
     title: String,
     timeout: Option<std::time::Duration>,
     input_texts: Vec<CreateInputText>,
+    keys: Vec<String>,
 }
 
 #[cfg(feature = "collector")]
@@ -46,6 +63,7 @@ impl CreateQuickModal {
             title: title.into(),
             timeout: None,
             input_texts: Vec::new(),
+            keys: Vec::new(),
         }
     }
 
@@ -62,23 +80,51 @@ impl CreateQuickModal {
     ///
     /// As the `custom_id` field of [`CreateInputText`], just supply an empty string. All custom
     /// IDs are overwritten by [`CreateQuickModal`] when sending the modal.
-    pub fn field(mut self, input_text: CreateInputText) -> Self {
+    ///
+    /// The field's value is keyed by its position (`"0"`, `"1"`, ...) in
+    /// [`QuickModalResponse::fields`]. Use [`Self::field_with_id`] to choose a key instead.
+    pub fn field(self, input_text: CreateInputText) -> Self {
+        let key = self.input_texts.len().to_string();
+        self.field_with_id(key, input_text)
+    }
+
+    /// Adds an input text field whose value is keyed by `id` in [`QuickModalResponse::fields`].
+    ///
+    /// See [`Self::field`].
+    pub fn field_with_id(mut self, id: impl Into<String>, input_text: CreateInputText) -> Self {
+        self.keys.push(id.into());
         self.input_texts.push(input_text);
         self
     }
 
-    /// Convenience method to add a single-line input text field.
+    /// Convenience method to add a single-line input text field, keyed by its label.
     ///
-    /// Wraps [`Self::field`].
+    /// Wraps [`Self::field_with_id`].
     pub fn short_field(self, label: impl Into<String>) -> Self {
-        self.field(CreateInputText::new(InputTextStyle::Short, label, ""))
+        let label = label.into();
+        self.short_field_with_id(label.clone(), label)
+    }
+
+    /// Convenience method to add a single-line input text field, keyed by `id`.
+    ///
+    /// Wraps [`Self::field_with_id`].
+    pub fn short_field_with_id(self, id: impl Into<String>, label: impl Into<String>) -> Self {
+        self.field_with_id(id, CreateInputText::new(InputTextStyle::Short, label, ""))
     }
 
-    /// Convenience method to add a multi-line input text field.
+    /// Convenience method to add a multi-line input text field, keyed by its label.
     ///
-    /// Wraps [`Self::field`].
+    /// Wraps [`Self::field_with_id`].
     pub fn paragraph_field(self, label: impl Into<String>) -> Self {
-        self.field(CreateInputText::new(InputTextStyle::Paragraph, label, ""))
+        let label = label.into();
+        self.paragraph_field_with_id(label.clone(), label)
+    }
+
+    /// Convenience method to add a multi-line input text field, keyed by `id`.
+    ///
+    /// Wraps [`Self::field_with_id`].
+    pub fn paragraph_field_with_id(self, id: impl Into<String>, label: impl Into<String>) -> Self {
+        self.field_with_id(id, CreateInputText::new(InputTextStyle::Paragraph, label, ""))
     }
 
     /// # Errors
@@ -116,33 +162,70 @@ impl CreateQuickModal {
 
         let Some(modal_interaction) = modal_interaction else { return Ok(None) };
 
-        let inputs = modal_interaction
-            .data
-            .components
-            .iter()
-            .filter_map(|row| match row.components.first() {
-                Some(ActionRowComponent::InputText(text)) => {
-                    if let Some(value) = &text.value {
-                        Some(value.clone())
-                    } else {
-                        tracing::warn!("input text value was empty in modal response");
-                        None
-                    }
-                },
-                Some(other) => {
-                    tracing::warn!("expected input text in modal response, got {:?}", other);
-                    None
-                },
-                None => {
-                    tracing::warn!("empty action row");
-                    None
-                },
-            })
-            .collect();
+        let fields = parse_fields(self.keys, &modal_interaction.data);
+        let inputs = fields.iter().map(|(_, value)| value.clone()).collect();
 
         Ok(Some(QuickModalResponse {
             inputs,
+            fields,
             interaction: modal_interaction,
         }))
     }
 }
+
+/// Pairs each key with the value submitted for the input text at the same position.
+///
+/// Input texts are identified by the index [`CreateQuickModal::execute`] put in their custom ID,
+/// so the order of the submitted components doesn't matter. Values that are missing, e.g. because
+/// the user left an optional field empty, become an empty string.
+#[cfg(feature = "collector")]
+fn parse_fields(keys: Vec<String>, data: &ModalInteractionData) -> Vec<(String, String)> {
+    let mut values = vec![String::new(); keys.len()];
+    for component in data.components.iter().flat_map(|row| &row.components) {
+        let ActionRowComponent::InputText(text) = component else {
+            tracing::warn!("expected input text in modal response, got {:?}", component);
+            continue;
+        };
+        if let Some(value) = text.custom_id.parse::<usize>().ok().and_then(|i| values.get_mut(i)) {
+            *value = text.value.clone().unwrap_or_default();
+        } else {
+            tracing::warn!("unknown input text {} in modal response", text.custom_id);
+        }
+    }
+    keys.into_iter().zip(values).collect()
+}
+
+#[cfg(test)]
+#[cfg(feature = "collector")]
+mod tests {
+    use super::*;
+    use crate::json::{from_value, json};
+
+    #[test]
+    fn parse_fields_by_key() {
+        let modal = CreateQuickModal::new("About you")
+            .short_field("First name")
+            .short_field_with_id("last", "Last name")
+            .paragraph_field("Hobbies")
+            .field(CreateInputText::new(InputTextStyle::Short, "Age", ""));
+
+        let data: ModalInteractionData = from_value(json!({
+            "custom_id": "1",
+            "components": [
+                {"type": 1, "components": [{"type": 4, "custom_id": "1", "value": "Doe"}]},
+                {"type": 1, "components": [{"type": 4, "custom_id": "0", "value": "Jane"}]},
+                {"type": 1, "components": [{"type": 4, "custom_id": "2", "value": ""}]},
+                {"type": 1, "components": [{"type": 4, "custom_id": "3"}]},
+            ],
+        }))
+        .unwrap();
+
+        let response_fields = parse_fields(modal.keys, &data);
+        assert_eq!(response_fields, vec![
+            ("First name".to_string(), "Jane".to_string()),
+            ("last".to_string(), "Doe".to_string()),
+            ("Hobbies".to_string(), String::new()),
+            ("3".to_string(), String::new()),
+        ]);
+    }
+}
@@ -0,0 +1,34 @@
+//! Deserializes recorded gateway payloads from `tests/fixtures/events`, to catch regressions in
+//! the deserialization of real events.
+//!
+//! Each fixture is a full dispatch payload, named after its event, e.g. `MESSAGE_CREATE.json`.
+//! Add a fixture whenever an event fails to deserialize in the wild.
+
+use std::fs;
+use std::path::Path;
+
+use serenity::json::from_str;
+use serenity::model::event::GatewayEvent;
+
+#[test]
+fn deserialize_event_fixtures() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/events");
+
+    let mut count = 0;
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        let name = path.file_stem().unwrap().to_str().unwrap().to_string();
+        let payload = fs::read_to_string(&path).unwrap();
+
+        let event: GatewayEvent =
+            from_str(payload).unwrap_or_else(|why| panic!("{name} failed to deserialize: {why}"));
+        let GatewayEvent::Dispatch(_, event) = event else {
+            panic!("{name} is not a dispatch");
+        };
+        assert_eq!(event.name().as_deref(), Some(name.as_str()), "{name} has the wrong type");
+
+        count += 1;
+    }
+
+    assert!(count > 0, "no fixtures found");
+}
@@ -0,0 +1,211 @@
+use std::error::Error as StdError;
+use std::fmt;
+
+use crate::opcode::Opcode;
+
+/// A representation of the binary messages of the voice gateway, used by the DAVE protocol to
+/// exchange MLS messages.
+///
+/// Messages sent by the server are prefixed with a big-endian `u16` sequence number, followed by
+/// the opcode and the payload. Messages sent by the client omit the sequence number.
+///
+/// MLS payloads are kept in their raw, TLS-encoded form, to be handed to an MLS implementation.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum BinaryEvent {
+    /// Credential and public key of the MLS external sender, sent by the server.
+    MlsExternalSender(Vec<u8>),
+    /// MLS key package of a pending group member, sent by the client.
+    MlsKeyPackage(Vec<u8>),
+    /// MLS proposals to be appended or revoked, sent by the server.
+    ///
+    /// The first byte of the payload is the operation type, where `0` appends and `1` revokes.
+    MlsProposals(Vec<u8>),
+    /// MLS commit, optionally followed by an MLS welcome, sent by the client.
+    MlsCommitWelcome(Vec<u8>),
+    /// MLS commit to be processed for an upcoming transition, sent by the server.
+    MlsAnnounceCommitTransition {
+        /// ID of the transition the commit belongs to.
+        transition_id: u16,
+        /// The MLS commit message.
+        commit: Vec<u8>,
+    },
+    /// MLS welcome to the group for an upcoming transition, sent by the server.
+    MlsWelcome {
+        /// ID of the transition the welcome belongs to.
+        transition_id: u16,
+        /// The MLS welcome message.
+        welcome: Vec<u8>,
+    },
+}
+
+impl BinaryEvent {
+    /// Returns the opcode of this event.
+    #[must_use]
+    pub fn kind(&self) -> Opcode {
+        match self {
+            Self::MlsExternalSender(_) => Opcode::DaveMlsExternalSender,
+            Self::MlsKeyPackage(_) => Opcode::DaveMlsKeyPackage,
+            Self::MlsProposals(_) => Opcode::DaveMlsProposals,
+            Self::MlsCommitWelcome(_) => Opcode::DaveMlsCommitWelcome,
+            Self::MlsAnnounceCommitTransition {
+                ..
+            } => Opcode::DaveMlsAnnounceCommitTransition,
+            Self::MlsWelcome {
+                ..
+            } => Opcode::DaveMlsWelcome,
+        }
+    }
+
+    /// Parses a binary frame sent by the server, returning its sequence number and the event.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BinaryEventError::TooShort`] if the frame is missing its header, or
+    /// [`BinaryEventError::UnknownOpcode`] if the opcode is not a binary DAVE opcode.
+    pub fn from_server_frame(frame: &[u8]) -> Result<(u16, Self), BinaryEventError> {
+        match frame {
+            [seq_hi, seq_lo, rest @ ..] => {
+                Ok((u16::from_be_bytes([*seq_hi, *seq_lo]), Self::from_client_frame(rest)?))
+            },
+            _ => Err(BinaryEventError::TooShort),
+        }
+    }
+
+    /// Parses a binary frame sent by the client, which carries no sequence number.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BinaryEventError::TooShort`] if the frame is missing its header, or
+    /// [`BinaryEventError::UnknownOpcode`] if the opcode is not a binary DAVE opcode.
+    pub fn from_client_frame(frame: &[u8]) -> Result<Self, BinaryEventError> {
+        let (&op, payload) = frame.split_first().ok_or(BinaryEventError::TooShort)?;
+
+        let with_transition = |payload: &[u8]| match payload {
+            [id_hi, id_lo, rest @ ..] => Ok((u16::from_be_bytes([*id_hi, *id_lo]), rest.to_vec())),
+            _ => Err(BinaryEventError::TooShort),
+        };
+
+        Ok(match op {
+            25 => Self::MlsExternalSender(payload.to_vec()),
+            26 => Self::MlsKeyPackage(payload.to_vec()),
+            27 => Self::MlsProposals(payload.to_vec()),
+            28 => Self::MlsCommitWelcome(payload.to_vec()),
+            29 => {
+                let (transition_id, commit) = with_transition(payload)?;
+                Self::MlsAnnounceCommitTransition {
+                    transition_id,
+                    commit,
+                }
+            },
+            30 => {
+                let (transition_id, welcome) = with_transition(payload)?;
+                Self::MlsWelcome {
+                    transition_id,
+                    welcome,
+                }
+            },
+            other => return Err(BinaryEventError::UnknownOpcode(other)),
+        })
+    }
+
+    /// Encodes this event as a binary frame to be sent by the client.
+    #[must_use]
+    pub fn to_client_frame(&self) -> Vec<u8> {
+        let mut frame = vec![self.kind() as u8];
+
+        match self {
+            Self::MlsExternalSender(payload)
+            | Self::MlsKeyPackage(payload)
+            | Self::MlsProposals(payload)
+            | Self::MlsCommitWelcome(payload) => frame.extend_from_slice(payload),
+            Self::MlsAnnounceCommitTransition {
+                transition_id,
+                commit: payload,
+            }
+            | Self::MlsWelcome {
+                transition_id,
+                welcome: payload,
+            } => {
+                frame.extend_from_slice(&transition_id.to_be_bytes());
+                frame.extend_from_slice(payload);
+            },
+        }
+
+        frame
+    }
+
+    /// Encodes this event as a binary frame sent by the server, with the given sequence number.
+    #[must_use]
+    pub fn to_server_frame(&self, seq: u16) -> Vec<u8> {
+        let mut frame = seq.to_be_bytes().to_vec();
+        frame.extend(self.to_client_frame());
+        frame
+    }
+}
+
+/// An error encountered while parsing a [`BinaryEvent`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum BinaryEventError {
+    /// The frame ended before its header or transition ID was complete.
+    TooShort,
+    /// The opcode is not one of the binary DAVE opcodes.
+    UnknownOpcode(u8),
+}
+
+impl fmt::Display for BinaryEventError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooShort => f.write_str("Binary frame is too short"),
+            Self::UnknownOpcode(op) => write!(f, "Unknown binary opcode {op}"),
+        }
+    }
+}
+
+impl StdError for BinaryEventError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn server_frames() {
+        // Sequence number 258, external sender package.
+        let frame = [0x01, 0x02, 25, 0xAA, 0xBB];
+        let (seq, event) = BinaryEvent::from_server_frame(&frame).unwrap();
+        assert_eq!(seq, 258);
+        assert_eq!(event, BinaryEvent::MlsExternalSender(vec![0xAA, 0xBB]));
+        assert_eq!(event.to_server_frame(seq), frame);
+
+        // Sequence number 3, welcome for transition 7.
+        let frame = [0x00, 0x03, 30, 0x00, 0x07, 0x01, 0x02, 0x03];
+        let (seq, event) = BinaryEvent::from_server_frame(&frame).unwrap();
+        assert_eq!(seq, 3);
+        assert_eq!(event, BinaryEvent::MlsWelcome {
+            transition_id: 7,
+            welcome: vec![0x01, 0x02, 0x03],
+        });
+        assert_eq!(event.kind(), Opcode::DaveMlsWelcome);
+        assert_eq!(event.to_server_frame(seq), frame);
+    }
+
+    #[test]
+    fn client_frames() {
+        let event = BinaryEvent::MlsKeyPackage(vec![0x01, 0x00, 0x01]);
+        let frame = event.to_client_frame();
+        assert_eq!(frame, [26, 0x01, 0x00, 0x01]);
+        assert_eq!(BinaryEvent::from_client_frame(&frame), Ok(event));
+    }
+
+    #[test]
+    fn invalid_frames() {
+        assert_eq!(BinaryEvent::from_server_frame(&[0x00]), Err(BinaryEventError::TooShort));
+        assert_eq!(BinaryEvent::from_client_frame(&[]), Err(BinaryEventError::TooShort));
+        assert_eq!(BinaryEvent::from_client_frame(&[29, 0x00]), Err(BinaryEventError::TooShort));
+        assert_eq!(
+            BinaryEvent::from_client_frame(&[21, 0x00]),
+            Err(BinaryEventError::UnknownOpcode(21))
+        );
+    }
+}
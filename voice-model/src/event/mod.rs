@@ -3,10 +3,11 @@ mod from;
 mod tests;
 
 use serde::de::value::U8Deserializer;
-use serde::de::{Deserializer, Error as DeError, IntoDeserializer, MapAccess, Unexpected, Visitor};
+use serde::de::{Deserializer, Error as DeError, IntoDeserializer, MapAccess, Visitor};
 use serde::ser::{SerializeStruct, Serializer};
 use serde::{Deserialize, Serialize};
 use serde_json::value::RawValue;
+use serde_json::Value;
 
 use crate::opcode::Opcode;
 use crate::payload::*;
@@ -40,12 +41,28 @@ pub enum Event {
     ClientConnect(ClientConnect),
     /// Status update in the current channel, indicating that a user has disconnected.
     ClientDisconnect(ClientDisconnect),
+    /// Announces an upcoming downgrade from the DAVE protocol.
+    DavePrepareTransition(DavePrepareTransition),
+    /// Instructs the client to execute a previously announced transition.
+    DaveExecuteTransition(DaveExecuteTransition),
+    /// Sent by the client to acknowledge an upcoming transition.
+    DaveTransitionReady(DaveTransitionReady),
+    /// Announces an upcoming DAVE protocol version or MLS group change.
+    DavePrepareEpoch(DavePrepareEpoch),
+    /// Sent by the client to flag an invalid MLS commit or welcome.
+    DaveMlsInvalidCommitWelcome(DaveMlsInvalidCommitWelcome),
+    /// A message with an opcode unknown to this library.
+    ///
+    /// Messages with opcodes that are only sent as binary frames, see [`Opcode::is_binary`], will
+    /// also be deserialised as this variant if they are encountered as JSON.
+    Unknown(UnknownEvent),
 }
 
 impl Event {
-    pub fn kind(&self) -> Opcode {
+    /// Returns the opcode of this event, or [`None`] for [`Event::Unknown`].
+    pub fn try_kind(&self) -> Option<Opcode> {
         use Event::*;
-        match self {
+        Some(match self {
             Identify(_) => Opcode::Identify,
             SelectProtocol(_) => Opcode::SelectProtocol,
             Ready(_) => Opcode::Ready,
@@ -58,6 +75,31 @@ impl Event {
             Resumed => Opcode::Resumed,
             ClientConnect(_) => Opcode::ClientConnect,
             ClientDisconnect(_) => Opcode::ClientDisconnect,
+            DavePrepareTransition(_) => Opcode::DavePrepareTransition,
+            DaveExecuteTransition(_) => Opcode::DaveExecuteTransition,
+            DaveTransitionReady(_) => Opcode::DaveTransitionReady,
+            DavePrepareEpoch(_) => Opcode::DavePrepareEpoch,
+            DaveMlsInvalidCommitWelcome(_) => Opcode::DaveMlsInvalidCommitWelcome,
+            Unknown(_) => return None,
+        })
+    }
+
+    /// Returns the opcode of this event.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the event is an [`Event::Unknown`].
+    #[deprecated = "Use `try_kind`, which doesn't panic on unknown events"]
+    pub fn kind(&self) -> Opcode {
+        self.try_kind().expect("Unknown events have no known opcode.")
+    }
+
+    /// Returns the raw opcode of this event.
+    pub fn raw_kind(&self) -> u8 {
+        match (self, self.try_kind()) {
+            (Event::Unknown(e), _) => e.op,
+            (_, Some(op)) => op as u8,
+            (_, None) => unreachable!("only unknown events have no opcode"),
         }
     }
 }
@@ -69,7 +111,7 @@ impl Serialize for Event {
     {
         let mut s = serializer.serialize_struct("Event", 2)?;
 
-        s.serialize_field("op", &self.kind())?;
+        s.serialize_field("op", &self.raw_kind())?;
 
         use Event::*;
         match self {
@@ -85,6 +127,12 @@ impl Serialize for Event {
             Resumed => s.serialize_field("d", &None::<()>)?,
             ClientConnect(e) => s.serialize_field("d", e)?,
             ClientDisconnect(e) => s.serialize_field("d", e)?,
+            DavePrepareTransition(e) => s.serialize_field("d", e)?,
+            DaveExecuteTransition(e) => s.serialize_field("d", e)?,
+            DaveTransitionReady(e) => s.serialize_field("d", e)?,
+            DavePrepareEpoch(e) => s.serialize_field("d", e)?,
+            DaveMlsInvalidCommitWelcome(e) => s.serialize_field("d", e)?,
+            Unknown(e) => s.serialize_field("d", &e.d)?,
         }
 
         s.end()
@@ -112,37 +160,63 @@ impl<'de> Visitor<'de> for EventVisitor {
                 Some("op") => {
                     let raw = map.next_value::<u8>()?;
                     let des: U8Deserializer<A::Error> = raw.into_deserializer();
-                    let valid_op = Opcode::deserialize(des).map_err(|_| {
-                        DeError::invalid_value(
-                            Unexpected::Unsigned(raw.into()),
-                            &"opcode in [0--9] + [12--13]",
-                        )
-                    })?;
-                    op = Some(valid_op);
+                    // Unknown and binary-only opcodes are kept as `Err(raw)`, to be parsed as
+                    // `Event::Unknown`.
+                    let parsed: Result<Opcode, A::Error> = Opcode::deserialize(des);
+                    op = Some(match parsed {
+                        Ok(op) if !op.is_binary() => Ok(op),
+                        _ => Err(raw),
+                    });
                 },
                 // Idea: Op comes first, but missing it is not failure.
                 // So, if order correct then we don't need to pass the RawValue back out.
                 Some("d") => match op {
-                    Some(Opcode::Identify) => return Ok(map.next_value::<Identify>()?.into()),
-                    Some(Opcode::SelectProtocol) =>
+                    Some(Ok(Opcode::Identify)) => return Ok(map.next_value::<Identify>()?.into()),
+                    Some(Ok(Opcode::SelectProtocol)) =>
                         return Ok(map.next_value::<SelectProtocol>()?.into()),
-                    Some(Opcode::Ready) => return Ok(map.next_value::<Ready>()?.into()),
-                    Some(Opcode::Heartbeat) => return Ok(map.next_value::<Heartbeat>()?.into()),
-                    Some(Opcode::HeartbeatAck) =>
+                    Some(Ok(Opcode::Ready)) => return Ok(map.next_value::<Ready>()?.into()),
+                    Some(Ok(Opcode::Heartbeat)) => return Ok(map.next_value::<Heartbeat>()?.into()),
+                    Some(Ok(Opcode::HeartbeatAck)) =>
                         return Ok(map.next_value::<HeartbeatAck>()?.into()),
-                    Some(Opcode::SessionDescription) =>
+                    Some(Ok(Opcode::SessionDescription)) =>
                         return Ok(map.next_value::<SessionDescription>()?.into()),
-                    Some(Opcode::Speaking) => return Ok(map.next_value::<Speaking>()?.into()),
-                    Some(Opcode::Resume) => return Ok(map.next_value::<Resume>()?.into()),
-                    Some(Opcode::Hello) => return Ok(map.next_value::<Hello>()?.into()),
-                    Some(Opcode::Resumed) => {
+                    Some(Ok(Opcode::Speaking)) => return Ok(map.next_value::<Speaking>()?.into()),
+                    Some(Ok(Opcode::Resume)) => return Ok(map.next_value::<Resume>()?.into()),
+                    Some(Ok(Opcode::Hello)) => return Ok(map.next_value::<Hello>()?.into()),
+                    Some(Ok(Opcode::Resumed)) => {
                         let _ = map.next_value::<Option<()>>()?;
                         return Ok(Event::Resumed);
                     },
-                    Some(Opcode::ClientConnect) =>
+                    Some(Ok(Opcode::ClientConnect)) =>
                         return Ok(map.next_value::<ClientConnect>()?.into()),
-                    Some(Opcode::ClientDisconnect) =>
+                    Some(Ok(Opcode::ClientDisconnect)) =>
                         return Ok(map.next_value::<ClientDisconnect>()?.into()),
+                    Some(Ok(Opcode::DavePrepareTransition)) =>
+                        return Ok(map.next_value::<DavePrepareTransition>()?.into()),
+                    Some(Ok(Opcode::DaveExecuteTransition)) =>
+                        return Ok(map.next_value::<DaveExecuteTransition>()?.into()),
+                    Some(Ok(Opcode::DaveTransitionReady)) =>
+                        return Ok(map.next_value::<DaveTransitionReady>()?.into()),
+                    Some(Ok(Opcode::DavePrepareEpoch)) =>
+                        return Ok(map.next_value::<DavePrepareEpoch>()?.into()),
+                    Some(Ok(Opcode::DaveMlsInvalidCommitWelcome)) =>
+                        return Ok(map.next_value::<DaveMlsInvalidCommitWelcome>()?.into()),
+                    Some(Ok(
+                        Opcode::DaveMlsExternalSender
+                        | Opcode::DaveMlsKeyPackage
+                        | Opcode::DaveMlsProposals
+                        | Opcode::DaveMlsCommitWelcome
+                        | Opcode::DaveMlsAnnounceCommitTransition
+                        | Opcode::DaveMlsWelcome,
+                    )) => unreachable!("binary opcodes are parsed as unknown"),
+                    Some(Err(op)) => {
+                        let d = map.next_value::<Value>()?;
+                        return Ok(UnknownEvent {
+                            op,
+                            d,
+                        }
+                        .into());
+                    },
                     None => {
                         d = Some(map.next_value::<&RawValue>()?);
                     },
@@ -164,6 +238,20 @@ impl<'de> Visitor<'de> for EventVisitor {
         let d = d.expect("Struct body known to exist if loop has been escaped.").get();
         let op = op.expect("Struct variant known to exist if loop has been escaped.");
 
+        let op = match op {
+            Ok(op) => op,
+            Err(op) =>
+                return serde_json::from_str::<Value>(d)
+                    .map(|d| {
+                        UnknownEvent {
+                            op,
+                            d,
+                        }
+                        .into()
+                    })
+                    .map_err(DeError::custom),
+        };
+
         (match op {
             Opcode::Identify => serde_json::from_str::<Identify>(d).map(Into::into),
             Opcode::SelectProtocol => serde_json::from_str::<SelectProtocol>(d).map(Into::into),
@@ -178,6 +266,21 @@ impl<'de> Visitor<'de> for EventVisitor {
             Opcode::Resumed => Ok(Event::Resumed),
             Opcode::ClientConnect => serde_json::from_str::<ClientConnect>(d).map(Into::into),
             Opcode::ClientDisconnect => serde_json::from_str::<ClientDisconnect>(d).map(Into::into),
+            Opcode::DavePrepareTransition =>
+                serde_json::from_str::<DavePrepareTransition>(d).map(Into::into),
+            Opcode::DaveExecuteTransition =>
+                serde_json::from_str::<DaveExecuteTransition>(d).map(Into::into),
+            Opcode::DaveTransitionReady =>
+                serde_json::from_str::<DaveTransitionReady>(d).map(Into::into),
+            Opcode::DavePrepareEpoch => serde_json::from_str::<DavePrepareEpoch>(d).map(Into::into),
+            Opcode::DaveMlsInvalidCommitWelcome =>
+                serde_json::from_str::<DaveMlsInvalidCommitWelcome>(d).map(Into::into),
+            Opcode::DaveMlsExternalSender
+            | Opcode::DaveMlsKeyPackage
+            | Opcode::DaveMlsProposals
+            | Opcode::DaveMlsCommitWelcome
+            | Opcode::DaveMlsAnnounceCommitTransition
+            | Opcode::DaveMlsWelcome => unreachable!("binary opcodes are parsed as unknown"),
         })
         .map_err(DeError::custom)
     }
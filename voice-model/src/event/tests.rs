@@ -644,3 +644,129 @@ fn serialize_client_disconnect() {
         Token::StructEnd,
     ]);
 }
+
+#[test]
+fn deserialize_dave_prepare_transition_json() {
+    let json_data = r#"{
+        "op": 21,
+        "d": {
+            "transition_id": 7,
+            "protocol_version": 0
+        }
+    }"#;
+
+    let event = serde_json::from_str(json_data);
+
+    let transition = DavePrepareTransition {
+        transition_id: 7,
+        protocol_version: 0,
+    };
+
+    assert!(matches!(event, Ok(Event::DavePrepareTransition(i)) if i == transition));
+}
+
+#[test]
+fn deserialize_dave_execute_transition_json() {
+    let json_data = r#"{"op": 22, "d": {"transition_id": 7}}"#;
+
+    let event = serde_json::from_str(json_data);
+
+    let transition = DaveExecuteTransition {
+        transition_id: 7,
+    };
+
+    assert!(matches!(event, Ok(Event::DaveExecuteTransition(i)) if i == transition));
+}
+
+#[test]
+fn deserialize_dave_prepare_epoch_json() {
+    let json_data = r#"{
+        "d": {
+            "protocol_version": 1,
+            "epoch": 1
+        },
+        "op": 24
+    }"#;
+
+    let event = serde_json::from_str(json_data);
+
+    let epoch = DavePrepareEpoch {
+        epoch: 1,
+        protocol_version: 1,
+    };
+
+    assert!(matches!(event, Ok(Event::DavePrepareEpoch(i)) if i == epoch));
+}
+
+#[test]
+fn deserialize_unknown_json() {
+    let json_data = r#"{"op": 42, "d": {"some_field": [1, 2]}}"#;
+    let event = serde_json::from_str(json_data);
+    assert!(matches!(
+        event,
+        Ok(Event::Unknown(UnknownEvent { op: 42, d })) if d["some_field"][1] == 2
+    ));
+
+    // Body before opcode.
+    let json_data = r#"{"d": null, "op": 42}"#;
+    let event = serde_json::from_str(json_data);
+    assert!(matches!(event, Ok(Event::Unknown(UnknownEvent { op: 42, d })) if d.is_null()));
+
+    // Binary-only opcodes are not expected as JSON.
+    let json_data = r#"{"op": 25, "d": {}}"#;
+    let event: Event = serde_json::from_str(json_data).unwrap();
+    assert!(event.try_kind().is_none());
+    assert_eq!(event.raw_kind(), Opcode::DaveMlsExternalSender as u8);
+}
+
+#[test]
+fn serialize_dave_transition_ready() {
+    let value: Event = DaveTransitionReady {
+        transition_id: 7,
+    }
+    .into();
+
+    serde_test::assert_ser_tokens(&value, &[
+        Token::Struct {
+            name: "Event",
+            len: 2,
+        },
+        Token::Str("op"),
+        Token::U8(Opcode::DaveTransitionReady as u8),
+        Token::Str("d"),
+        Token::Struct {
+            name: "DaveTransitionReady",
+            len: 1,
+        },
+        Token::Str("transition_id"),
+        Token::U16(7),
+        Token::StructEnd,
+        Token::StructEnd,
+    ]);
+}
+
+#[test]
+fn serialize_dave_mls_invalid_commit_welcome() {
+    let value: Event = DaveMlsInvalidCommitWelcome {
+        transition_id: 3,
+    }
+    .into();
+
+    serde_test::assert_ser_tokens(&value, &[
+        Token::Struct {
+            name: "Event",
+            len: 2,
+        },
+        Token::Str("op"),
+        Token::U8(Opcode::DaveMlsInvalidCommitWelcome as u8),
+        Token::Str("d"),
+        Token::Struct {
+            name: "DaveMlsInvalidCommitWelcome",
+            len: 1,
+        },
+        Token::Str("transition_id"),
+        Token::U16(3),
+        Token::StructEnd,
+        Token::StructEnd,
+    ]);
+}
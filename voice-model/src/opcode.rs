@@ -33,4 +33,38 @@ pub enum Opcode {
     ClientConnect = 12,
     /// Message indicating that another user has disconnected from the voice channel.
     ClientDisconnect = 13,
+    /// A downgrade from the DAVE protocol is upcoming.
+    DavePrepareTransition = 21,
+    /// Execute a previously announced protocol transition.
+    DaveExecuteTransition = 22,
+    /// Acknowledges readiness for a previously announced protocol transition.
+    DaveTransitionReady = 23,
+    /// A DAVE protocol version or group change is upcoming.
+    DavePrepareEpoch = 24,
+    /// Binary message containing the credential and public key of the MLS external sender.
+    DaveMlsExternalSender = 25,
+    /// Binary message containing the MLS key package of a pending group member.
+    DaveMlsKeyPackage = 26,
+    /// Binary message containing MLS proposals to be appended or revoked.
+    DaveMlsProposals = 27,
+    /// Binary message containing an MLS commit, optionally with an MLS welcome.
+    DaveMlsCommitWelcome = 28,
+    /// Binary message containing the MLS commit to be processed for an upcoming transition.
+    DaveMlsAnnounceCommitTransition = 29,
+    /// Binary message containing the MLS welcome to the group for an upcoming transition.
+    DaveMlsWelcome = 30,
+    /// Flags an invalid MLS commit or welcome, requesting to be re-added to the group.
+    DaveMlsInvalidCommitWelcome = 31,
+}
+
+impl Opcode {
+    /// Whether messages with this opcode are sent as binary websocket frames, rather than JSON.
+    ///
+    /// See [`BinaryEvent`] for (de)serialising these.
+    ///
+    /// [`BinaryEvent`]: crate::BinaryEvent
+    #[must_use]
+    pub fn is_binary(self) -> bool {
+        (Self::DaveMlsExternalSender as u8..=Self::DaveMlsWelcome as u8).contains(&(self as u8))
+    }
 }
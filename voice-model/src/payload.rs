@@ -3,6 +3,7 @@
 use std::net::IpAddr;
 
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
 use crate::id::*;
 use crate::protocol_data::ProtocolData;
@@ -30,6 +31,48 @@ pub struct ClientDisconnect {
     pub user_id: UserId,
 }
 
+/// Announces an upcoming downgrade from the DAVE protocol.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Deserialize, Serialize)]
+pub struct DavePrepareTransition {
+    /// ID of the transition, to be acknowledged with a [`DaveTransitionReady`].
+    pub transition_id: u16,
+    /// The protocol version being transitioned to, where `0` disables end-to-end encryption.
+    pub protocol_version: u16,
+}
+
+/// Instructs the client to execute a previously announced transition.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Deserialize, Serialize)]
+pub struct DaveExecuteTransition {
+    /// ID of the transition to execute.
+    pub transition_id: u16,
+}
+
+/// Sent by the client to acknowledge that it is ready for a previously announced transition.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Deserialize, Serialize)]
+pub struct DaveTransitionReady {
+    /// ID of the transition the client is ready for.
+    pub transition_id: u16,
+}
+
+/// Announces an upcoming DAVE protocol version or MLS group change.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Deserialize, Serialize)]
+pub struct DavePrepareEpoch {
+    /// The upcoming MLS epoch.
+    ///
+    /// An epoch of `1` indicates that a new MLS group is being created.
+    pub epoch: u64,
+    /// The protocol version used from this epoch onwards.
+    pub protocol_version: u16,
+}
+
+/// Sent by the client when it received an invalid MLS commit or welcome, asking to be removed
+/// from and re-added to the MLS group.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Deserialize, Serialize)]
+pub struct DaveMlsInvalidCommitWelcome {
+    /// ID of the transition containing the invalid commit or welcome.
+    pub transition_id: u16,
+}
+
 /// Used to keep the websocket connection alive.
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Deserialize, Serialize)]
 #[serde(transparent)]
@@ -132,3 +175,16 @@ pub struct Speaking {
     /// Used alongside the SSRC to map individual packets to their sender.
     pub user_id: Option<UserId>,
 }
+
+
+/// A voice gateway message with an opcode unknown to this library.
+///
+/// Discord regularly adds new opcodes to the voice gateway, these are preserved rather than
+/// failing to deserialise the whole message.
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+pub struct UnknownEvent {
+    /// The raw opcode of the message.
+    pub op: u8,
+    /// The raw body of the message.
+    pub d: Value,
+}